@@ -0,0 +1,689 @@
+//! Checking decoded payloads against a [`Schema`].
+
+use std::fmt;
+
+use anyhow::{bail, ensure, Context, Result};
+use musli::schema::{Enum, Name, Schema, Struct};
+use musli::value::{Number, Value};
+use musli::wire::Node;
+
+use crate::Wire;
+
+/// Checks the structure of a wire payload against a schema.
+///
+/// The structure of each type in the wire format depends on the options it
+/// was encoded with, so those have to be provided.
+pub(crate) struct WireChecker<'a> {
+    pub(crate) wire: &'a dyn Wire,
+    pub(crate) fixed_integers: bool,
+    /// The number of bytes used by lengths if they have a fixed width.
+    pub(crate) length_width: Option<usize>,
+}
+
+impl WireChecker<'_> {
+    pub(crate) fn check(&self, node: &Node, schema: &Schema) -> Result<()> {
+        match schema {
+            Schema::Unit => {
+                let items = self.sequence(node, expected(schema))?;
+                ensure!(
+                    items.is_empty(),
+                    "Expected unit, but found {}",
+                    describe_node(node)
+                );
+            }
+            Schema::Bool => {
+                let Node::Continuation { value: 0 | 1, .. } = node else {
+                    bail!("Expected bool, but found {}", describe_node(node));
+                };
+            }
+            Schema::Char => {
+                let value = self.integer(node, schema, 4)?;
+
+                ensure!(
+                    char::from_u32(value as u32).is_some(),
+                    "Expected char, but found the invalid character {value:#x}"
+                );
+            }
+            Schema::U8 | Schema::I8 => {
+                self.integer(node, schema, 1)?;
+            }
+            Schema::U16 | Schema::I16 => {
+                self.integer(node, schema, 2)?;
+            }
+            Schema::U32 | Schema::I32 | Schema::F32 => {
+                self.integer(node, schema, 4)?;
+            }
+            Schema::U64 | Schema::I64 | Schema::F64 => {
+                self.integer(node, schema, 8)?;
+            }
+            Schema::U128 | Schema::I128 => {
+                self.integer(node, schema, 16)?;
+            }
+            Schema::Usize | Schema::Isize => match (node, self.length_width) {
+                (Node::Continuation { value, .. }, None) => {
+                    ensure!(
+                        *value <= u128::from(u64::MAX),
+                        "Expected {}, but {value} is out of bounds",
+                        expected(schema)
+                    );
+                }
+                (Node::Prefix { bytes, .. }, Some(width)) => {
+                    ensure!(
+                        bytes.len() == width,
+                        "Expected a {width}-byte {}, but found {} bytes",
+                        expected(schema),
+                        bytes.len()
+                    );
+                }
+                _ => bail!(
+                    "Expected {}, but found {}",
+                    expected(schema),
+                    describe_node(node)
+                ),
+            },
+            Schema::String => {
+                let bytes = self.prefix(node, expected(schema))?;
+                std::str::from_utf8(bytes).context("Expected string, but it is not UTF-8")?;
+            }
+            Schema::Bytes => {
+                self.prefix(node, expected(schema))?;
+            }
+            Schema::Option(some) => match self.sequence(node, expected(schema))? {
+                [] => {}
+                [value] => self.check(value, some)?,
+                _ => bail!("Expected option, but found {}", describe_node(node)),
+            },
+            Schema::Sequence(item) => {
+                for (index, value) in self.sequence(node, expected(schema))?.iter().enumerate() {
+                    self.check(value, item)
+                        .with_context(|| format!("In item {index}"))?;
+                }
+            }
+            Schema::Tuple(items) => {
+                let values = self.sequence(node, expected(schema))?;
+
+                ensure!(
+                    values.len() == items.len(),
+                    "Expected tuple of {} items, but found {}",
+                    items.len(),
+                    describe_node(node)
+                );
+
+                for (index, (value, item)) in values.iter().zip(items).enumerate() {
+                    self.check(value, item)
+                        .with_context(|| format!("In item {index}"))?;
+                }
+            }
+            Schema::Map(entry) => {
+                let (key, value) = &**entry;
+
+                for (index, pair) in self
+                    .entries(node, expected(schema))?
+                    .chunks_exact(2)
+                    .enumerate()
+                {
+                    self.check(&pair[0], key)
+                        .with_context(|| format!("In key of entry {index}"))?;
+                    self.check(&pair[1], value)
+                        .with_context(|| format!("In value of entry {index}"))?;
+                }
+            }
+            Schema::Struct(st) => self.check_struct(node, st)?,
+            Schema::Enum(en) => self.check_enum(node, en)?,
+            _ => bail!("Unsupported schema {schema:?}"),
+        }
+
+        Ok(())
+    }
+
+    fn check_struct(&self, node: &Node, st: &Struct) -> Result<()> {
+        if st.transparent {
+            let [field] = &st.fields[..] else {
+                bail!(
+                    "Transparent struct `{}` must have exactly one field",
+                    st.name
+                );
+            };
+
+            return self
+                .check(node, &field.schema)
+                .with_context(|| format!("In field `{}` of `{}`", field.name, st.name));
+        }
+
+        if st.packed {
+            let Node::Prefix { bytes, .. } = node else {
+                bail!(
+                    "Expected packed struct `{}`, but found {}",
+                    st.name,
+                    describe_node(node)
+                );
+            };
+
+            let mut offset = 0;
+
+            for field in &st.fields {
+                let context = || format!("In field `{}` of `{}`", field.name, st.name);
+                let (value, len) = self
+                    .wire
+                    .inspect_packed(&bytes[offset..])
+                    .with_context(context)?;
+                self.check(&value, &field.schema).with_context(context)?;
+                offset += len;
+            }
+
+            ensure!(
+                offset == bytes.len(),
+                "Packed struct `{}` has {} trailing byte(s)",
+                st.name,
+                bytes.len() - offset
+            );
+
+            return Ok(());
+        }
+
+        let entries = self.entries(node, format_args!("struct `{}`", st.name))?;
+
+        let mut seen = vec![false; st.fields.len()];
+
+        for pair in entries.chunks_exact(2) {
+            // Unknown fields are skipped when decoding.
+            let Some(index) = st
+                .fields
+                .iter()
+                .position(|f| self.is_name(&pair[0], &f.name))
+            else {
+                continue;
+            };
+
+            let field = &st.fields[index];
+
+            ensure!(
+                !seen[index],
+                "Field `{}` of `{}` is present more than once",
+                field.name,
+                st.name
+            );
+
+            seen[index] = true;
+
+            self.check(&pair[1], &field.schema)
+                .with_context(|| format!("In field `{}` of `{}`", field.name, st.name))?;
+        }
+
+        for (field, seen) in st.fields.iter().zip(seen) {
+            ensure!(
+                seen || field.default,
+                "Missing field `{}` of `{}`",
+                field.name,
+                st.name
+            );
+        }
+
+        Ok(())
+    }
+
+    fn check_enum(&self, node: &Node, en: &Enum) -> Result<()> {
+        let Node::Sequence { items, .. } = node else {
+            bail!(
+                "Expected enum `{}`, but found {}",
+                en.name,
+                describe_node(node)
+            );
+        };
+
+        let [tag, body] = &items[..] else {
+            bail!(
+                "Expected enum `{}`, but found {}",
+                en.name,
+                describe_node(node)
+            );
+        };
+
+        let Some(variant) = en.variants.iter().find(|v| self.is_name(tag, &v.name)) else {
+            // Unknown variants are decoded into the fallback variant, if there
+            // is one.
+            ensure!(
+                en.fallback.is_some(),
+                "Unknown variant {} of `{}`",
+                describe_node(tag),
+                en.name
+            );
+
+            return Ok(());
+        };
+
+        self.check_struct(body, &variant.body)
+            .with_context(|| format!("In variant `{}` of `{}`", variant.name, en.name))
+    }
+
+    /// Test if the node is the encoded name of a field or variant.
+    fn is_name(&self, node: &Node, name: &Name) -> bool {
+        match (node, name) {
+            (Node::Prefix { bytes, .. }, Name::String(name)) => bytes == name.as_bytes(),
+            (Node::Continuation { value, .. }, Name::Index(index)) => {
+                self.length_width.is_none() && *value == *index as u128
+            }
+            (Node::Prefix { bytes, .. }, Name::Index(index)) => {
+                self.length_width == Some(bytes.len()) && native(bytes) == *index as u128
+            }
+            _ => false,
+        }
+    }
+
+    /// Check that the node is an integer of the given byte width, returning
+    /// its raw value.
+    fn integer(&self, node: &Node, schema: &Schema, width: usize) -> Result<u128> {
+        match node {
+            Node::Continuation { value, .. } if !self.fixed_integers => {
+                // Signed integers are zigzag-encoded, which maps them onto the
+                // range of the unsigned integer of the same width.
+                ensure!(
+                    width == 16 || *value >> (width * 8) == 0,
+                    "Expected {}, but {value} is out of bounds",
+                    expected(schema)
+                );
+
+                Ok(*value)
+            }
+            Node::Prefix { bytes, .. } if self.fixed_integers => {
+                ensure!(
+                    bytes.len() == width,
+                    "Expected a {width}-byte {}, but found {} bytes",
+                    expected(schema),
+                    bytes.len()
+                );
+
+                Ok(native(bytes))
+            }
+            _ => bail!(
+                "Expected {}, but found {}",
+                expected(schema),
+                describe_node(node)
+            ),
+        }
+    }
+
+    fn prefix<'n>(&self, node: &'n Node, what: impl fmt::Display) -> Result<&'n [u8]> {
+        let Node::Prefix { bytes, .. } = node else {
+            bail!("Expected {what}, but found {}", describe_node(node));
+        };
+
+        Ok(bytes)
+    }
+
+    fn sequence<'n>(&self, node: &'n Node, what: impl fmt::Display) -> Result<&'n [Node]> {
+        match node {
+            Node::Sequence { items, .. } | Node::Stream { items } => Ok(items),
+            _ => bail!("Expected {what}, but found {}", describe_node(node)),
+        }
+    }
+
+    /// Get the entries of a map, which are encoded as a sequence of
+    /// alternating keys and values.
+    fn entries<'n>(&self, node: &'n Node, what: impl fmt::Display) -> Result<&'n [Node]> {
+        let items = self.sequence(node, &what)?;
+
+        ensure!(
+            items.len() % 2 == 0,
+            "Expected {what}, but found a sequence with an odd number of items"
+        );
+
+        Ok(items)
+    }
+}
+
+/// Checks a dynamic value decoded from a self-descriptive format against a
+/// schema.
+pub(crate) struct ValueChecker {
+    /// JSON can't represent all types in the data model, so they are decoded
+    /// as something else. Like map keys which are always strings.
+    pub(crate) json: bool,
+}
+
+impl ValueChecker {
+    pub(crate) fn check(&self, value: &Value, schema: &Schema) -> Result<()> {
+        match (schema, value) {
+            (Schema::Unit, Value::Unit) => {}
+            (Schema::Bool, Value::Bool(..)) => {}
+            (Schema::Char, Value::Char(..)) => {}
+            (Schema::Char, Value::String(string)) if self.json && string.chars().count() == 1 => {}
+            (Schema::F32 | Schema::F64, Value::Number(..)) => {}
+            (_, Value::Number(number)) if is_integer(schema) => {
+                ensure!(
+                    fits(schema, number),
+                    "Expected {}, but found {number:?}",
+                    expected(schema)
+                );
+            }
+            (_, Value::String(string)) if self.json && is_integer(schema) => {
+                // Numerical map keys are encoded as strings.
+                ensure!(
+                    string.parse::<i128>().is_ok() || string.parse::<u128>().is_ok(),
+                    "Expected {}, but found string {:?}",
+                    expected(schema),
+                    &**string
+                );
+            }
+            (Schema::String, Value::String(..)) => {}
+            (Schema::Bytes, Value::Bytes(..)) => {}
+            (Schema::Bytes, Value::Sequence(items)) if self.json => {
+                for (index, item) in items.iter().enumerate() {
+                    self.check(item, &Schema::U8)
+                        .with_context(|| format!("In byte {index}"))?;
+                }
+            }
+            (Schema::Option(..), Value::Option(None)) => {}
+            (Schema::Option(some), Value::Option(Some(value))) => self.check(value, some)?,
+            // JSON has no way to tell `Some` apart from the value it contains.
+            (Schema::Option(..), Value::Unit) if self.json => {}
+            (Schema::Option(some), value) if self.json => self.check(value, some)?,
+            (Schema::Sequence(item), Value::Sequence(values)) => {
+                for (index, value) in values.iter().enumerate() {
+                    self.check(value, item)
+                        .with_context(|| format!("In item {index}"))?;
+                }
+            }
+            (Schema::Tuple(items), Value::Sequence(values)) => {
+                ensure!(
+                    values.len() == items.len(),
+                    "Expected tuple of {} items, but found a sequence of {}",
+                    items.len(),
+                    values.len()
+                );
+
+                for (index, (value, item)) in values.iter().zip(items).enumerate() {
+                    self.check(value, item)
+                        .with_context(|| format!("In item {index}"))?;
+                }
+            }
+            (Schema::Map(entry), Value::Map(entries)) => {
+                let (key_schema, value_schema) = &**entry;
+
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    self.check(key, key_schema)
+                        .with_context(|| format!("In key of entry {index}"))?;
+                    self.check(value, value_schema)
+                        .with_context(|| format!("In value of entry {index}"))?;
+                }
+            }
+            (Schema::Struct(st), value) => self.check_struct(value, st)?,
+            (Schema::Enum(en), value) => self.check_enum(value, en)?,
+            (schema, value) => bail!(
+                "Expected {}, but found {}",
+                expected(schema),
+                describe_value(value)
+            ),
+        }
+
+        Ok(())
+    }
+
+    fn check_struct(&self, value: &Value, st: &Struct) -> Result<()> {
+        if st.transparent {
+            let [field] = &st.fields[..] else {
+                bail!(
+                    "Transparent struct `{}` must have exactly one field",
+                    st.name
+                );
+            };
+
+            return self
+                .check(value, &field.schema)
+                .with_context(|| format!("In field `{}` of `{}`", field.name, st.name));
+        }
+
+        if st.packed {
+            match value {
+                Value::Sequence(values) => {
+                    ensure!(
+                        values.len() == st.fields.len(),
+                        "Expected packed struct `{}` with {} fields, but found {}",
+                        st.name,
+                        st.fields.len(),
+                        values.len()
+                    );
+
+                    for (value, field) in values.iter().zip(&st.fields) {
+                        self.check(value, &field.schema).with_context(|| {
+                            format!("In field `{}` of `{}`", field.name, st.name)
+                        })?;
+                    }
+                }
+                // Formats which store packs as raw bytes can't be checked any
+                // further without knowing how they were encoded.
+                Value::Bytes(..) => {}
+                value => bail!(
+                    "Expected packed struct `{}`, but found {}",
+                    st.name,
+                    describe_value(value)
+                ),
+            }
+
+            return Ok(());
+        }
+
+        let Value::Map(entries) = value else {
+            bail!(
+                "Expected struct `{}`, but found {}",
+                st.name,
+                describe_value(value)
+            );
+        };
+
+        let mut seen = vec![false; st.fields.len()];
+
+        for (key, value) in entries {
+            // Unknown fields are skipped when decoding.
+            let Some(index) = st.fields.iter().position(|f| self.is_name(key, &f.name)) else {
+                continue;
+            };
+
+            let field = &st.fields[index];
+
+            ensure!(
+                !seen[index],
+                "Field `{}` of `{}` is present more than once",
+                field.name,
+                st.name
+            );
+
+            seen[index] = true;
+
+            self.check(value, &field.schema)
+                .with_context(|| format!("In field `{}` of `{}`", field.name, st.name))?;
+        }
+
+        for (field, seen) in st.fields.iter().zip(seen) {
+            ensure!(
+                seen || field.default,
+                "Missing field `{}` of `{}`",
+                field.name,
+                st.name
+            );
+        }
+
+        Ok(())
+    }
+
+    fn check_enum(&self, value: &Value, en: &Enum) -> Result<()> {
+        let (tag, body) = match value {
+            Value::Variant(variant) => (&variant.0, &variant.1),
+            // JSON encodes variants as a map with a single entry.
+            Value::Map(entries) if self.json && entries.len() == 1 => {
+                (&entries[0].0, &entries[0].1)
+            }
+            value => bail!(
+                "Expected enum `{}`, but found {}",
+                en.name,
+                describe_value(value)
+            ),
+        };
+
+        let Some(variant) = en.variants.iter().find(|v| self.is_name(tag, &v.name)) else {
+            ensure!(
+                en.fallback.is_some(),
+                "Unknown variant {} of `{}`",
+                describe_value(tag),
+                en.name
+            );
+
+            return Ok(());
+        };
+
+        self.check_struct(body, &variant.body)
+            .with_context(|| format!("In variant `{}` of `{}`", variant.name, en.name))
+    }
+
+    /// Test if the value is the name of a field or variant.
+    fn is_name(&self, value: &Value, name: &Name) -> bool {
+        match (value, name) {
+            (Value::String(string), Name::String(name)) => **string == **name,
+            (Value::String(string), Name::Index(index)) => {
+                self.json && string.parse::<usize>().ok() == Some(*index)
+            }
+            (Value::Number(number), Name::Index(index)) => {
+                fits(&Schema::Usize, number) && integer(number) == Some(*index as i128)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Decode an integer stored with a fixed width in the native byte order.
+fn native(bytes: &[u8]) -> u128 {
+    let mut value = [0; 16];
+
+    if cfg!(target_endian = "little") {
+        value[..bytes.len()].copy_from_slice(bytes);
+    } else {
+        value[16 - bytes.len()..].copy_from_slice(bytes);
+    }
+
+    u128::from_ne_bytes(value)
+}
+
+fn is_integer(schema: &Schema) -> bool {
+    matches!(
+        schema,
+        Schema::U8
+            | Schema::U16
+            | Schema::U32
+            | Schema::U64
+            | Schema::U128
+            | Schema::I8
+            | Schema::I16
+            | Schema::I32
+            | Schema::I64
+            | Schema::I128
+            | Schema::Usize
+            | Schema::Isize
+    )
+}
+
+/// Get the value of an integer number, if it is one which fits in an `i128`.
+fn integer(number: &Number) -> Option<i128> {
+    Some(match *number {
+        Number::U8(n) => n.into(),
+        Number::U16(n) => n.into(),
+        Number::U32(n) => n.into(),
+        Number::U64(n) => n.into(),
+        Number::U128(n) => i128::try_from(n).ok()?,
+        Number::I8(n) => n.into(),
+        Number::I16(n) => n.into(),
+        Number::I32(n) => n.into(),
+        Number::I64(n) => n.into(),
+        Number::I128(n) => n,
+        Number::Usize(n) => n as i128,
+        Number::Isize(n) => n as i128,
+        _ => return None,
+    })
+}
+
+/// Test if an integer number fits in the integer type described by the
+/// schema.
+fn fits(schema: &Schema, number: &Number) -> bool {
+    macro_rules! fits {
+        ($ty:ty) => {
+            match (*number, integer(number)) {
+                (Number::U128(n), _) => <$ty>::try_from(n).is_ok(),
+                (_, Some(n)) => <$ty>::try_from(n).is_ok(),
+                _ => false,
+            }
+        };
+    }
+
+    match schema {
+        Schema::U8 => fits!(u8),
+        Schema::U16 => fits!(u16),
+        Schema::U32 => fits!(u32),
+        Schema::U64 => fits!(u64),
+        Schema::U128 => fits!(u128),
+        Schema::I8 => fits!(i8),
+        Schema::I16 => fits!(i16),
+        Schema::I32 => fits!(i32),
+        Schema::I64 => fits!(i64),
+        Schema::I128 => fits!(i128),
+        Schema::Usize => fits!(usize),
+        Schema::Isize => fits!(isize),
+        _ => false,
+    }
+}
+
+/// A description of what a schema expects, used in error messages.
+fn expected(schema: &Schema) -> String {
+    match schema {
+        Schema::Unit => String::from("unit"),
+        Schema::Bool => String::from("bool"),
+        Schema::Char => String::from("char"),
+        Schema::U8 => String::from("u8"),
+        Schema::U16 => String::from("u16"),
+        Schema::U32 => String::from("u32"),
+        Schema::U64 => String::from("u64"),
+        Schema::U128 => String::from("u128"),
+        Schema::I8 => String::from("i8"),
+        Schema::I16 => String::from("i16"),
+        Schema::I32 => String::from("i32"),
+        Schema::I64 => String::from("i64"),
+        Schema::I128 => String::from("i128"),
+        Schema::Usize => String::from("usize"),
+        Schema::Isize => String::from("isize"),
+        Schema::F32 => String::from("f32"),
+        Schema::F64 => String::from("f64"),
+        Schema::String => String::from("string"),
+        Schema::Bytes => String::from("bytes"),
+        Schema::Option(..) => String::from("option"),
+        Schema::Sequence(..) => String::from("sequence"),
+        Schema::Tuple(..) => String::from("tuple"),
+        Schema::Map(..) => String::from("map"),
+        Schema::Struct(st) => format!("struct `{}`", st.name),
+        Schema::Enum(en) => format!("enum `{}`", en.name),
+        _ => String::from("an unknown type"),
+    }
+}
+
+fn describe_node(node: &Node) -> String {
+    match node {
+        Node::Prefix { bytes, .. } => format!("prefix({})", bytes.len()),
+        Node::Sequence { items, .. } => format!("sequence({})", items.len()),
+        Node::Stream { items } => format!("stream({})", items.len()),
+        Node::Extension { id, .. } => format!("extension({id})"),
+        Node::Continuation { value, .. } => format!("continuation {value}"),
+        _ => String::from("an unknown node"),
+    }
+}
+
+fn describe_value(value: &Value) -> String {
+    match value {
+        Value::Unit => String::from("unit"),
+        Value::Bool(..) => String::from("bool"),
+        Value::Char(..) => String::from("char"),
+        Value::Number(number) => format!("{number:?}"),
+        Value::Bytes(bytes) => format!("bytes of length {}", bytes.len()),
+        Value::String(string) => format!("string {:?}", &**string),
+        Value::Sequence(items) => format!("sequence of {} items", items.len()),
+        Value::Map(entries) => format!("map of {} entries", entries.len()),
+        Value::Variant(..) => String::from("variant"),
+        Value::Option(..) => String::from("option"),
+        _ => String::from("an unknown value"),
+    }
+}
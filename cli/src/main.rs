@@ -0,0 +1,463 @@
+mod check;
+
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, ensure, Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use musli::options::{self, Integer, Options, Width};
+use musli::schema::Schema;
+use musli::value::Value;
+use musli::wire::{Checksum, Encoding, Header, Node};
+
+/// The number of bytes printed per line in a hexdump.
+const HEX_WIDTH: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// The `musli::wire` format.
+    Wire,
+    /// The `musli::descriptive` format.
+    Descriptive,
+    /// The `musli::json` format.
+    Json,
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Format::Wire => write!(f, "wire"),
+            Format::Descriptive => write!(f, "descriptive"),
+            Format::Json => write!(f, "json"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum LengthWidth {
+    #[value(name = "8")]
+    U8,
+    #[value(name = "16")]
+    U16,
+    #[value(name = "32")]
+    U32,
+    #[value(name = "64")]
+    U64,
+}
+
+impl LengthWidth {
+    /// The number of bytes used by a length of this width.
+    fn bytes(self) -> usize {
+        match self {
+            LengthWidth::U8 => 1,
+            LengthWidth::U16 => 2,
+            LengthWidth::U32 => 4,
+            LengthWidth::U64 => 8,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ChecksumKind {
+    /// A CRC-32 checksum following the payload.
+    Crc32,
+}
+
+/// Options which have to match the ones the wire payload was encoded with.
+///
+/// Fixed-width numbers use the native byte order.
+#[derive(clap::Args)]
+struct WireArgs {
+    /// Integers are encoded with a fixed width.
+    #[arg(long)]
+    fixed_integers: bool,
+    /// Lengths are encoded with a fixed width of the given number of bits.
+    #[arg(long, value_name = "BITS")]
+    fixed_lengths: Option<LengthWidth>,
+    /// The payload is followed by a checksum.
+    #[arg(long)]
+    checksum: Option<ChecksumKind>,
+    /// The payload starts with a header with the given four magic bytes.
+    #[arg(long, value_name = "MAGIC")]
+    header: Option<String>,
+    /// The version stored in the header.
+    #[arg(long, default_value_t = 0, requires = "header")]
+    header_version: u32,
+}
+
+impl WireArgs {
+    /// Construct the wire encoding described by the arguments.
+    fn encoding(&self) -> Result<Box<dyn Wire>> {
+        macro_rules! lengths {
+            ($integer:ident) => {
+                match self.fixed_lengths {
+                    None => self.configure::<{ wire_options(Integer::$integer, None) }>(),
+                    Some(LengthWidth::U8) => {
+                        self.configure::<{ wire_options(Integer::$integer, Some(Width::U8)) }>()
+                    }
+                    Some(LengthWidth::U16) => {
+                        self.configure::<{ wire_options(Integer::$integer, Some(Width::U16)) }>()
+                    }
+                    Some(LengthWidth::U32) => {
+                        self.configure::<{ wire_options(Integer::$integer, Some(Width::U32)) }>()
+                    }
+                    Some(LengthWidth::U64) => {
+                        self.configure::<{ wire_options(Integer::$integer, Some(Width::U64)) }>()
+                    }
+                }
+            };
+        }
+
+        if self.fixed_integers {
+            lengths!(Fixed)
+        } else {
+            lengths!(Variable)
+        }
+    }
+
+    fn configure<const OPT: Options>(&self) -> Result<Box<dyn Wire>> {
+        let mut encoding = Encoding::new().with_options::<OPT>();
+
+        if let Some(ChecksumKind::Crc32) = self.checksum {
+            encoding = encoding.with_checksum(Checksum::Crc32);
+        }
+
+        if let Some(magic) = &self.header {
+            let Ok(magic) = <[u8; 4]>::try_from(magic.as_bytes()) else {
+                bail!("Header magic `{magic}` must be exactly four bytes");
+            };
+
+            encoding = encoding.with_header(Header::new(magic, self.header_version));
+        }
+
+        Ok(Box::new(encoding))
+    }
+}
+
+const fn wire_options(integer: Integer, length_width: Option<Width>) -> Options {
+    let builder = options::new().with_integer(integer);
+
+    match length_width {
+        Some(width) => builder.with_length_width(width).build(),
+        None => builder.build(),
+    }
+}
+
+/// A wire encoding whose options have been picked at runtime.
+trait Wire {
+    /// Inspect the payload at the start of `bytes`, returning it together with
+    /// the number of bytes consumed.
+    fn inspect_payload(&self, bytes: &[u8]) -> Result<(Node, usize)>;
+
+    /// Inspect a value inside of a pack, which unlike a payload doesn't have a
+    /// header or checksum.
+    fn inspect_packed(&self, bytes: &[u8]) -> Result<(Node, usize)>;
+
+    /// Encode a dynamic value.
+    fn encode(&self, value: &Value) -> Result<Vec<u8>>;
+}
+
+impl<const OPT: Options> Wire for Encoding<OPT> {
+    fn inspect_payload(&self, bytes: &[u8]) -> Result<(Node, usize)> {
+        Ok(self.inspect_partial(bytes)?)
+    }
+
+    fn inspect_packed(&self, bytes: &[u8]) -> Result<(Node, usize)> {
+        Ok(Encoding::new()
+            .with_options::<OPT>()
+            .inspect_partial(bytes)?)
+    }
+
+    fn encode(&self, value: &Value) -> Result<Vec<u8>> {
+        Ok(self.to_vec(value)?)
+    }
+}
+
+#[derive(Parser)]
+struct ArgsDump {
+    /// The format of the payload.
+    #[arg(short = 'f', long)]
+    format: Format,
+    #[command(flatten)]
+    wire: WireArgs,
+    /// Don't print a hexdump of the payload.
+    #[arg(long)]
+    no_hex: bool,
+    /// Path to the payload, or `-` to read from stdin.
+    path: PathBuf,
+}
+
+#[derive(Parser)]
+struct ArgsValidate {
+    /// The format of the payload.
+    #[arg(short = 'f', long)]
+    format: Format,
+    #[command(flatten)]
+    wire: WireArgs,
+    /// Path to a `musli::schema::Schema` encoded as JSON which the payload
+    /// has to match.
+    #[arg(long)]
+    schema: Option<PathBuf>,
+    /// Permit trailing data after the first complete value.
+    #[arg(long)]
+    allow_trailing: bool,
+    /// Path to the payload, or `-` to read from stdin.
+    path: PathBuf,
+}
+
+#[derive(Parser)]
+struct ArgsConvert {
+    /// The format of the input payload, either `descriptive` or `json`.
+    ///
+    /// The `wire` format is not self-descriptive, so it can only be converted
+    /// into and not from.
+    #[arg(long, value_parser = parse_source)]
+    from: Format,
+    /// The format to convert the payload into.
+    #[arg(long)]
+    to: Format,
+    /// Options used when converting into the wire format.
+    #[command(flatten)]
+    wire: WireArgs,
+    /// Write output to the given path instead of stdout.
+    #[arg(short = 'o', long)]
+    output: Option<PathBuf>,
+    /// Ignore trailing data after the first complete value.
+    #[arg(long)]
+    allow_trailing: bool,
+    /// Path to the payload, or `-` to read from stdin.
+    path: PathBuf,
+}
+
+/// Parse the format of a payload which is decoded into a dynamic value.
+fn parse_source(s: &str) -> Result<Format, String> {
+    match Format::from_str(s, false)? {
+        Format::Wire => Err(String::from(
+            "the wire format is not self-descriptive and can't be converted from, use `descriptive` or `json`",
+        )),
+        format => Ok(format),
+    }
+}
+
+#[derive(Subcommand)]
+enum Cmd {
+    /// Print a hexdump and the decoded structure of a payload.
+    Dump(ArgsDump),
+    /// Check that a payload is well-formed, and optionally that it matches a
+    /// schema.
+    Validate(ArgsValidate),
+    /// Convert a payload between formats.
+    Convert(ArgsConvert),
+}
+
+#[derive(Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Cmd,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    match args.command {
+        Cmd::Dump(args) => dump(&args),
+        Cmd::Validate(args) => validate(&args),
+        Cmd::Convert(args) => convert(&args),
+    }
+}
+
+fn dump(args: &ArgsDump) -> Result<()> {
+    let bytes = read_input(&args.path)?;
+    let mut out = String::new();
+
+    if !args.no_hex {
+        hexdump(&mut out, &bytes)?;
+        out.push('\n');
+    }
+
+    match args.format {
+        Format::Wire => {
+            let wire = args.wire.encoding()?;
+            let mut offset = 0;
+
+            while offset < bytes.len() {
+                let (node, len) = wire
+                    .inspect_payload(&bytes[offset..])
+                    .with_context(|| format!("Payload at offset {offset}"))?;
+
+                writeln!(out, "{offset:08x}: {node}")?;
+                offset += len;
+            }
+        }
+        format => {
+            let (value, rest) = decode_value(format, &bytes)?;
+            writeln!(out, "{value:#?}")?;
+
+            if !rest.is_empty() {
+                writeln!(out, "{} trailing byte(s)", rest.len())?;
+            }
+        }
+    }
+
+    io::stdout().write_all(out.as_bytes())?;
+    Ok(())
+}
+
+fn validate(args: &ArgsValidate) -> Result<()> {
+    let bytes = read_input(&args.path)?;
+
+    let schema = match &args.schema {
+        Some(path) => Some(read_schema(path)?),
+        None => None,
+    };
+
+    let remaining = match args.format {
+        Format::Wire => {
+            let wire = args.wire.encoding()?;
+            let (node, len) = wire.inspect_payload(&bytes)?;
+
+            if let Some(schema) = &schema {
+                let checker = check::WireChecker {
+                    wire: &*wire,
+                    fixed_integers: args.wire.fixed_integers,
+                    length_width: args.wire.fixed_lengths.map(LengthWidth::bytes),
+                };
+
+                checker.check(&node, schema)?;
+            }
+
+            bytes.len() - len
+        }
+        format => {
+            let (value, rest) = decode_value(format, &bytes)?;
+
+            if let Some(schema) = &schema {
+                let checker = check::ValueChecker {
+                    json: format == Format::Json,
+                };
+
+                checker.check(&value, schema)?;
+            }
+
+            rest.len()
+        }
+    };
+
+    ensure!(
+        args.allow_trailing || remaining == 0,
+        "{}: {remaining} trailing byte(s) after value at offset {}",
+        args.path.display(),
+        bytes.len() - remaining
+    );
+
+    println!("{}: valid {} payload", args.path.display(), args.format);
+    Ok(())
+}
+
+fn convert(args: &ArgsConvert) -> Result<()> {
+    let bytes = read_input(&args.path)?;
+    let (value, rest) = decode_value(args.from, &bytes)?;
+
+    ensure!(
+        args.allow_trailing || rest.is_empty(),
+        "{}: {} trailing byte(s) after value at offset {}",
+        args.path.display(),
+        rest.len(),
+        bytes.len() - rest.len()
+    );
+
+    let output = match args.to {
+        Format::Wire => args.wire.encoding()?.encode(&value)?,
+        Format::Descriptive => musli::descriptive::to_vec(&value)?,
+        Format::Json => {
+            let mut string = musli::json::to_string(&value)?;
+            string.push('\n');
+            string.into_bytes()
+        }
+    };
+
+    match &args.output {
+        Some(path) => {
+            fs::write(path, output).with_context(|| path.display().to_string())?;
+        }
+        None => {
+            io::stdout().write_all(&output)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Read a schema encoded as JSON from the given path.
+fn read_schema(path: &Path) -> Result<Schema> {
+    let bytes = fs::read(path).with_context(|| path.display().to_string())?;
+    let schema = musli::json::from_slice(&bytes).with_context(|| path.display().to_string())?;
+    Ok(schema)
+}
+
+/// Read the input at the given path, where `-` indicates stdin.
+fn read_input(path: &Path) -> Result<Vec<u8>> {
+    if path == Path::new("-") {
+        let mut bytes = Vec::new();
+        io::stdin().read_to_end(&mut bytes)?;
+        return Ok(bytes);
+    }
+
+    fs::read(path).with_context(|| path.display().to_string())
+}
+
+/// Decode a dynamic value from a self-descriptive format, returning the
+/// trailing bytes.
+fn decode_value(format: Format, bytes: &[u8]) -> Result<(Value, &[u8])> {
+    let mut slice = bytes;
+
+    let value = match format {
+        Format::Wire => {
+            bail!("The wire format is not self-descriptive and cannot be decoded into a value")
+        }
+        Format::Descriptive => musli::descriptive::decode(&mut slice)?,
+        Format::Json => {
+            let string = std::str::from_utf8(bytes).context("JSON payload is not UTF-8")?;
+            let value = musli::json::from_str(string)?;
+            slice = &[];
+            value
+        }
+    };
+
+    Ok((value, slice))
+}
+
+/// Write a hexdump of the given bytes.
+fn hexdump<O>(out: &mut O, bytes: &[u8]) -> fmt::Result
+where
+    O: fmt::Write,
+{
+    for (n, chunk) in bytes.chunks(HEX_WIDTH).enumerate() {
+        write!(out, "{:08x}  ", n * HEX_WIDTH)?;
+
+        for index in 0..HEX_WIDTH {
+            match chunk.get(index) {
+                Some(b) => write!(out, "{b:02x} ")?,
+                None => write!(out, "   ")?,
+            }
+        }
+
+        write!(out, " |")?;
+
+        for &b in chunk {
+            let c = if b.is_ascii_graphic() || b == b' ' {
+                b as char
+            } else {
+                '.'
+            };
+
+            out.write_char(c)?;
+        }
+
+        writeln!(out, "|")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::Context;
+
+/// Lend out the allocator of a context for a value to allocate through.
+///
+/// This is what allows values which borrow from an allocator, such as
+/// [`musli::alloc::Vec`], to be decoded. A [`Decode`] implementation with an
+/// allocator `A` requires the context to implement `LendAlloc<A>`, which is
+/// the case for any context whose [`Context::Allocator`] is a reference.
+///
+/// Every context lends out `()`, which is what implementations that don't
+/// allocate through the context use.
+///
+/// [`musli::alloc::Vec`]: https://docs.rs/musli/latest/musli/alloc/struct.Vec.html
+/// [`Decode`]: crate::Decode
+pub trait LendAlloc<A> {
+    /// Lend out the allocator.
+    fn lend_alloc(&self) -> A;
+}
+
+impl<C> LendAlloc<()> for C
+where
+    C: ?Sized,
+{
+    #[inline(always)]
+    fn lend_alloc(&self) {}
+}
+
+impl<'a, C, A> LendAlloc<&'a A> for C
+where
+    C: ?Sized + Context<Allocator = &'a A>,
+    A: ?Sized,
+{
+    #[inline(always)]
+    fn lend_alloc(&self) -> &'a A {
+        *self.alloc()
+    }
+}
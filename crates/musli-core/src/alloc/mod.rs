@@ -4,6 +4,10 @@ mod allocator;
 #[doc(inline)]
 pub use self::allocator::Allocator;
 
+mod lend_alloc;
+#[doc(inline)]
+pub use self::lend_alloc::LendAlloc;
+
 mod raw_vec;
 #[doc(inline)]
 pub use self::raw_vec::RawVec;
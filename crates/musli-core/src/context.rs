@@ -217,6 +217,21 @@ pub trait Context {
         self.message(format_args!("Invalid field tag `{field}`"))
     }
 
+    /// Report that a field with a tag which isn't recognized by the type
+    /// `name` is about to be skipped over.
+    ///
+    /// This can be used to emit telemetry when peers send fields that aren't
+    /// understood. Fields of types which deny unknown fields or collect them
+    /// through `#[musli(unknown_fields)]` are not reported, but if the decoder
+    /// refuses to skip the field it is reported before the resulting error.
+    #[allow(unused_variables)]
+    #[inline(always)]
+    fn skipped_field<T>(&self, name: &'static str, tag: &T)
+    where
+        T: ?Sized + fmt::Debug,
+    {
+    }
+
     /// Missing variant field required to decode.
     #[allow(unused_variables)]
     #[inline(always)]
@@ -234,6 +249,24 @@ pub trait Context {
         self.message(format_args!("Missing variant tag"))
     }
 
+    /// Indicate that a value didn't match any variant of an untagged enum.
+    #[allow(unused_variables)]
+    #[inline(always)]
+    fn no_matching_variant(&self, name: &'static str) -> Self::Error {
+        self.message(format_args!("Value did not match any variant"))
+    }
+
+    /// Encountered a version of a `#[musli(version)]` container which can't be
+    /// decoded.
+    #[allow(unused_variables)]
+    #[inline(always)]
+    fn unsupported_version<T>(&self, name: &'static str, version: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.message(format_args!("Unsupported version {version:?}"))
+    }
+
     /// Encountered an unsupported variant field.
     #[allow(unused_variables)]
     #[inline(always)]
@@ -259,6 +292,61 @@ pub trait Context {
         self.message("Failed to allocate")
     }
 
+    /// Test if the context accumulates more than one error.
+    ///
+    /// If this returns `true`, decoders keep going after errors which don't
+    /// leave the input in an undefined state, such as missing or unknown
+    /// struct fields, so that all of them can be reported at once. The first
+    /// error is returned once the affected value has been fully decoded, and
+    /// is marked through [`recoverable`] so that an enclosing struct can keep
+    /// going as well.
+    ///
+    /// Errors which leave the input in an undefined state, such as a value of
+    /// the wrong type, still stop decoding.
+    ///
+    /// [`recoverable`]: Context::recoverable
+    #[inline(always)]
+    fn accumulates_errors(&self) -> bool {
+        false
+    }
+
+    /// Mark the error which is about to be returned as recoverable.
+    ///
+    /// This is called by decoders which accumulate errors once the value which
+    /// failed to decode has been consumed in its entirety.
+    #[inline(always)]
+    fn recoverable(&self) {}
+
+    /// Test if the last error was marked through [`recoverable`], clearing the
+    /// mark.
+    ///
+    /// [`recoverable`]: Context::recoverable
+    #[inline(always)]
+    fn take_recoverable(&self) -> bool {
+        false
+    }
+
+    /// Indicate that we're about to decode a nested value.
+    ///
+    /// Contexts which limit how deeply values can be nested return an error
+    /// here once the limit has been reached, which prevents hostile input from
+    /// overflowing the stack. This is called by decoders whenever they enter a
+    /// sequence, map or variant, and by decoders which recurse on their own,
+    /// like when skipping over values.
+    ///
+    /// Every successful call will be matched with a corresponding call to
+    /// [`leave_depth`].
+    ///
+    /// [`leave_depth`]: Context::leave_depth
+    #[inline(always)]
+    fn enter_depth(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Trace that we've left the last nested value that was entered.
+    #[inline(always)]
+    fn leave_depth(&self) {}
+
     /// Indicate that we've entered a struct with the given `name`.
     ///
     /// The `name` variable corresponds to the identifiers of the struct.
@@ -18,4 +18,16 @@ pub trait AsDecoder {
 
     /// Borrow self as a new decoder.
     fn as_decoder(&self) -> Result<Self::Decoder<'_>, <Self::Cx as Context>::Error>;
+
+    /// Construct a new buffer which, if this is a map, only contains the
+    /// entries with keys that `retain` returns `true` for.
+    ///
+    /// This is used by `#[musli(flatten)]` fields, so that they don't see the
+    /// fields of the struct they are flattened into.
+    fn retain_entries<'this, F>(
+        &'this self,
+        retain: F,
+    ) -> Result<<Self::Decoder<'this> as Decoder<'this>>::DecodeBuffer, <Self::Cx as Context>::Error>
+    where
+        F: FnMut(Self::Decoder<'this>) -> Result<bool, <Self::Cx as Context>::Error>;
 }
@@ -1,4 +1,11 @@
+#[cfg(feature = "alloc")]
+use rust_alloc::vec::Vec;
+
+use crate::alloc::LendAlloc;
+
 use super::Decoder;
+#[cfg(feature = "alloc")]
+use super::PodVec;
 
 /// Trait governing how types are decoded.
 ///
@@ -37,9 +44,92 @@ use super::Decoder;
 ///     }
 /// }
 /// ```
-pub trait Decode<'de, M>: Sized {
+///
+/// # Allocating through the context
+///
+/// The `A` parameter is the allocator which the decoded value allocates
+/// through, which is lent out by the context through [`LendAlloc`]. This is
+/// `()` for everything but collections like [`musli::alloc::Vec`], which
+/// borrow from an allocator and can therefore only be decoded using a context
+/// holding a reference to one.
+///
+/// [`musli::alloc::Vec`]: https://docs.rs/musli/latest/musli/alloc/struct.Vec.html
+pub trait Decode<'de, M, A = ()>: Sized {
     /// Decode the given input.
     fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
     where
-        D: Decoder<'de, Mode = M>;
+        D: Decoder<'de, Mode = M>,
+        D::Cx: LendAlloc<A>;
+
+    /// Decode the given input into an existing value.
+    ///
+    /// This allows implementations to reuse allocations held by `place`, such
+    /// as the capacity of a `Vec` or a `String`, which avoids allocating
+    /// when the same kind of value is decoded over and over again. The default
+    /// implementation decodes a new value and replaces `place` with it, while
+    /// deriving [`Decode`] on a struct decodes each of its fields in place.
+    ///
+    /// If decoding fails, `place` is left in a valid but unspecified state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Decode, Decoder};
+    ///
+    /// struct Buffer {
+    ///     data: Vec<u8>,
+    /// }
+    ///
+    /// impl<'de, M> Decode<'de, M> for Buffer {
+    ///     fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    ///     where
+    ///         D: Decoder<'de, Mode = M>,
+    ///     {
+    ///         Ok(Self {
+    ///             data: Decode::decode(cx, decoder)?,
+    ///         })
+    ///     }
+    ///
+    ///     fn decode_into<D>(cx: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+    ///     where
+    ///         D: Decoder<'de, Mode = M>,
+    ///     {
+    ///         Decode::decode_into(cx, decoder, &mut place.data)
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn decode_into<D>(cx: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+        D::Cx: LendAlloc<A>,
+    {
+        *place = Self::decode(cx, decoder)?;
+        Ok(())
+    }
+
+    /// Whether errors marked through [`Context::recoverable`] by this
+    /// implementation leave the decoder past the end of the value.
+    ///
+    /// This is set by derived implementations for structs, and allows an
+    /// enclosing struct to keep decoding after such an error when the context
+    /// accumulates errors.
+    ///
+    /// [`Context::recoverable`]: crate::Context::recoverable
+    #[doc(hidden)]
+    const RECOVERABLE: bool = false;
+
+    /// Coerce a vector of this type into a vector of plain-old-data.
+    ///
+    /// This is only implemented for primitive numbers, and allows vectors of
+    /// them to be read in bulk through [`SequenceDecoder::try_decode_pod_into`].
+    ///
+    /// [`SequenceDecoder::try_decode_pod_into`]: crate::de::SequenceDecoder::try_decode_pod_into
+    #[cfg(feature = "alloc")]
+    #[doc(hidden)]
+    #[inline]
+    fn as_pod_vec(vec: &mut Vec<Self>) -> Option<PodVec<'_>> {
+        _ = vec;
+        None
+    }
 }
@@ -8,7 +8,7 @@ use crate::Context;
 
 use super::{
     AsDecoder, Decode, DecodeUnsized, DecodeUnsizedBytes, EntriesDecoder, MapDecoder,
-    SequenceDecoder, Skip, UnsizedVisitor, VariantDecoder, Visitor,
+    SequenceDecoder, Skip, TypeHint, UnsizedVisitor, VariantDecoder, Visitor,
 };
 
 /// Trait governing the implementation of a decoder.
@@ -30,15 +30,19 @@ pub trait Decoder<'de>: Sized {
     /// Decoder returned by [`Decoder::decode_option`].
     type DecodeSome: Decoder<'de, Cx = Self::Cx, Error = Self::Error, Mode = Self::Mode>;
     /// Decoder used by [`Decoder::decode_pack`].
-    type DecodePack: SequenceDecoder<'de, Cx = Self::Cx>;
-    /// Decoder returned by [`Decoder::decode_sequence`].
-    type DecodeSequence: SequenceDecoder<'de, Cx = Self::Cx>;
-    /// Decoder returned by [`Decoder::decode_map`].
-    type DecodeMap: MapDecoder<'de, Cx = Self::Cx>;
-    /// Decoder returned by [`Decoder::decode_map_entries`].
-    type DecodeMapEntries: EntriesDecoder<'de, Cx = Self::Cx>;
+    ///
+    /// Like the other decoders which are lent out to a callback, it's
+    /// parameterized over the lifetime `'this` it is lent out for, so that it
+    /// can borrow from state which only lives for the duration of the call.
+    type DecodePack<'this>: SequenceDecoder<'de, Cx = Self::Cx>;
+    /// Decoder used by [`Decoder::decode_sequence`].
+    type DecodeSequence<'this>: SequenceDecoder<'de, Cx = Self::Cx>;
+    /// Decoder used by [`Decoder::decode_map`].
+    type DecodeMap<'this>: MapDecoder<'de, Cx = Self::Cx>;
+    /// Decoder used by [`Decoder::decode_map_entries`].
+    type DecodeMapEntries<'this>: EntriesDecoder<'de, Cx = Self::Cx>;
     /// Decoder used by [`Decoder::decode_variant`].
-    type DecodeVariant: VariantDecoder<'de, Cx = Self::Cx>;
+    type DecodeVariant<'this>: VariantDecoder<'de, Cx = Self::Cx>;
 
     /// This is a type argument used to hint to any future implementor that they
     /// should be using the [`#[musli::decoder]`][musli::decoder] attribute
@@ -119,6 +123,50 @@ pub trait Decoder<'de>: Sized {
         F: FnOnce(&T) -> Result<O, <Self::Cx as Context>::Error>;
 
     /// Skip over the current next value.
+    ///
+    /// This is supported by every self-descriptive format, which includes
+    /// `wire`, `descriptive`, `json` and decoding from a `Value`. Formats which
+    /// don't carry enough information to determine the extent of a value, like
+    /// `storage`, error instead. Use [`Decoder::try_skip`] to detect this
+    /// without raising an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Context, Decode, Decoder};
+    /// use musli::de::{EntryDecoder, MapDecoder};
+    ///
+    /// /// Only decodes the `id` field of a map, ignoring everything else.
+    /// struct Id(u32);
+    ///
+    /// impl<'de, M> Decode<'de, M> for Id {
+    ///     fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    ///     where
+    ///         D: Decoder<'de, Mode = M>,
+    ///     {
+    ///         decoder.decode_map(|map| {
+    ///             let mut id = None;
+    ///
+    ///             while let Some(mut entry) = map.decode_entry()? {
+    ///                 let found = entry.decode_key()?.decode_unsized(|key: &str| {
+    ///                     Ok(key == "id")
+    ///                 })?;
+    ///
+    ///                 if found {
+    ///                     id = Some(entry.decode_value()?.decode()?);
+    ///                 } else {
+    ///                     entry.decode_value()?.skip()?;
+    ///                 }
+    ///             }
+    ///
+    ///             match id {
+    ///                 Some(id) => Ok(Id(id)),
+    ///                 None => Err(cx.message("Missing id")),
+    ///             }
+    ///         })
+    ///     }
+    /// }
+    /// ```
     #[inline]
     fn skip(self) -> Result<(), <Self::Cx as Context>::Error> {
         Err(self.cx().message(format_args!(
@@ -134,6 +182,47 @@ pub trait Decoder<'de>: Sized {
         Ok(Skip::Unsupported)
     }
 
+    /// Report what kind of value is next, without consuming it.
+    ///
+    /// Self-descriptive formats like `descriptive` and `json`, as well as
+    /// decoding from a `Value`, report the kind of the next value. Other
+    /// formats return [`TypeHint::Unknown`].
+    ///
+    /// Note that since some formats can't distinguish between certain kinds of
+    /// values, the hint only indicates which method on the decoder is
+    /// appropriate to call. JSON for example reports `null` as
+    /// [`TypeHint::Empty`], even if it is used to encode an absent optional
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Context, Decode, Decoder};
+    /// use musli::de::TypeHint;
+    ///
+    /// enum Id {
+    ///     Number(u64),
+    ///     Name(String),
+    /// }
+    ///
+    /// impl<'de, M> Decode<'de, M> for Id {
+    ///     fn decode<D>(cx: &D::Cx, mut decoder: D) -> Result<Self, D::Error>
+    ///     where
+    ///         D: Decoder<'de, Mode = M>,
+    ///     {
+    ///         match decoder.type_hint()? {
+    ///             TypeHint::Number => Ok(Id::Number(decoder.decode()?)),
+    ///             TypeHint::String(..) => Ok(Id::Name(decoder.decode()?)),
+    ///             hint => Err(cx.message(format_args!("Expected number or string, found {hint}"))),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn type_hint(&mut self) -> Result<TypeHint, <Self::Cx as Context>::Error> {
+        Ok(TypeHint::Unknown)
+    }
+
     /// Buffer the current decoder into a buffer that can be used multiple times.
     ///
     /// Buffering a decoder is necessary when additional introspection is needed
@@ -182,6 +271,8 @@ pub trait Decoder<'de>: Sized {
     ///                 if found {
     ///                     break Ok(e.decode_value()?.decode()?);
     ///                 }
+    ///
+    ///                 e.decode_value()?.skip()?;
     ///             }
     ///         })?;
     ///
@@ -935,6 +1026,19 @@ pub trait Decoder<'de>: Sized {
         )))
     }
 
+    /// Decode a fixed-length array of bytes into `out`.
+    ///
+    /// This behaves like [`Decoder::decode_array`] with `N` being the length of
+    /// `out`, and is used where the length of the array is only known at
+    /// runtime, such as when decoding through a type-erased decoder.
+    #[inline]
+    fn decode_array_slice(self, out: &mut [u8]) -> Result<(), <Self::Cx as Context>::Error> {
+        Err(self.cx().message(expecting::unsupported_type(
+            &expecting::Array,
+            ExpectingWrapper::new(&self),
+        )))
+    }
+
     /// Decode a sequence of bytes whos length is encoded in the payload.
     ///
     /// # Examples
@@ -1159,7 +1263,7 @@ pub trait Decoder<'de>: Sized {
     #[inline]
     fn decode_pack<F, O>(self, f: F) -> Result<O, <Self::Cx as Context>::Error>
     where
-        F: FnOnce(&mut Self::DecodePack) -> Result<O, <Self::Cx as Context>::Error>,
+        F: FnOnce(&mut Self::DecodePack<'_>) -> Result<O, <Self::Cx as Context>::Error>,
     {
         Err(self.cx().message(expecting::unsupported_type(
             &expecting::Pack,
@@ -1243,7 +1347,7 @@ pub trait Decoder<'de>: Sized {
     #[inline]
     fn decode_sequence<F, O>(self, f: F) -> Result<O, <Self::Cx as Context>::Error>
     where
-        F: FnOnce(&mut Self::DecodeSequence) -> Result<O, <Self::Cx as Context>::Error>,
+        F: FnOnce(&mut Self::DecodeSequence<'_>) -> Result<O, <Self::Cx as Context>::Error>,
     {
         Err(self.cx().message(expecting::unsupported_type(
             &expecting::UnsizedSequence,
@@ -1281,7 +1385,7 @@ pub trait Decoder<'de>: Sized {
         f: F,
     ) -> Result<O, <Self::Cx as Context>::Error>
     where
-        F: FnOnce(&mut Self::DecodeSequence) -> Result<O, <Self::Cx as Context>::Error>,
+        F: FnOnce(&mut Self::DecodeSequence<'_>) -> Result<O, <Self::Cx as Context>::Error>,
     {
         self.decode_sequence(f)
     }
@@ -1316,7 +1420,7 @@ pub trait Decoder<'de>: Sized {
     /// ```
     fn decode_map<F, O>(self, f: F) -> Result<O, <Self::Cx as Context>::Error>
     where
-        F: FnOnce(&mut Self::DecodeMap) -> Result<O, <Self::Cx as Context>::Error>,
+        F: FnOnce(&mut Self::DecodeMap<'_>) -> Result<O, <Self::Cx as Context>::Error>,
     {
         Err(self.cx().message(expecting::unsupported_type(
             &expecting::UnsizedMap,
@@ -1396,7 +1500,7 @@ pub trait Decoder<'de>: Sized {
     #[inline]
     fn decode_map_hint<F, O>(self, _: &MapHint, f: F) -> Result<O, <Self::Cx as Context>::Error>
     where
-        F: FnOnce(&mut Self::DecodeMap) -> Result<O, <Self::Cx as Context>::Error>,
+        F: FnOnce(&mut Self::DecodeMap<'_>) -> Result<O, <Self::Cx as Context>::Error>,
     {
         self.decode_map(f)
     }
@@ -1408,7 +1512,7 @@ pub trait Decoder<'de>: Sized {
     #[inline]
     fn decode_map_entries<F, O>(self, f: F) -> Result<O, <Self::Cx as Context>::Error>
     where
-        F: FnOnce(&mut Self::DecodeMapEntries) -> Result<O, <Self::Cx as Context>::Error>,
+        F: FnOnce(&mut Self::DecodeMapEntries<'_>) -> Result<O, <Self::Cx as Context>::Error>,
     {
         Err(self.cx().message(expecting::unsupported_type(
             &expecting::MapEntries,
@@ -1450,7 +1554,7 @@ pub trait Decoder<'de>: Sized {
     #[inline]
     fn decode_variant<F, O>(self, f: F) -> Result<O, <Self::Cx as Context>::Error>
     where
-        F: FnOnce(&mut Self::DecodeVariant) -> Result<O, <Self::Cx as Context>::Error>,
+        F: FnOnce(&mut Self::DecodeVariant<'_>) -> Result<O, <Self::Cx as Context>::Error>,
     {
         Err(self.cx().message(expecting::unsupported_type(
             &expecting::Variant,
@@ -1470,6 +1574,56 @@ pub trait Decoder<'de>: Sized {
         )))
     }
 
+    /// Decode a value which was encoded as an application-defined extension
+    /// with the given type `id` through [`Encoder::encode_extension`].
+    ///
+    /// Formats which support extensions check that the value is marked with
+    /// the expected type id. Other formats decode the value as-is.
+    ///
+    /// [`Encoder::encode_extension`]: crate::Encoder::encode_extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Decode, Decoder};
+    ///
+    /// /// A decimal number with four fractional digits.
+    /// struct Decimal(i64);
+    ///
+    /// impl<'de, M> Decode<'de, M> for Decimal {
+    ///     fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    ///     where
+    ///         D: Decoder<'de, Mode = M>,
+    ///     {
+    ///         Ok(Decimal(decoder.decode_extension(1)?))
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn decode_extension<T>(self, id: u32) -> Result<T, <Self::Cx as Context>::Error>
+    where
+        T: Decode<'de, Self::Mode>,
+    {
+        self.decode()
+    }
+
+    /// Decode the raw encoded representation of the next value.
+    ///
+    /// The visitor is handed the bytes which make up the value in the format
+    /// being decoded, without interpreting them. This allows the value to be
+    /// decoded on demand at a later point. It's only supported by binary
+    /// formats which can tell where a value ends without knowing its type.
+    #[inline]
+    fn decode_raw<V>(self, visitor: V) -> Result<V::Ok, <Self::Cx as Context>::Error>
+    where
+        V: UnsizedVisitor<'de, Self::Cx, [u8]>,
+    {
+        Err(self.cx().message(expecting::unsupported_type(
+            &expecting::RawValue,
+            ExpectingWrapper::new(&self),
+        )))
+    }
+
     /// Decode dynamically through a [`Visitor`].
     #[inline]
     fn decode_any<V>(self, visitor: V) -> Result<V::Ok, <Self::Cx as Context>::Error>
@@ -1,6 +1,6 @@
 use crate::Context;
 
-use super::{Decode, Decoder, EntriesDecoder, EntryDecoder, SizeHint};
+use super::{Decode, Decoder, EntriesDecoder, EntryDecoder, MapEntries, SizeHint};
 
 /// Trait governing how to decode a sequence of pairs.
 pub trait MapDecoder<'de>: Sized {
@@ -47,4 +47,97 @@ pub trait MapDecoder<'de>: Sized {
         let value = entry.decode_value()?.decode()?;
         Ok(Some((key, value)))
     }
+
+    /// Construct an iterator which lazily decodes the remaining entries of the
+    /// map as `(K, V)` pairs.
+    ///
+    /// Iteration stops after the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use musli::{Decode, Decoder};
+    /// use musli::de::MapDecoder;
+    ///
+    /// struct Scores(BTreeMap<String, u32>);
+    ///
+    /// impl<'de, M> Decode<'de, M> for Scores {
+    ///     fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    ///     where
+    ///         D: Decoder<'de, Mode = M>,
+    ///     {
+    ///         decoder.decode_map(|map| {
+    ///             let scores = map.entries().collect::<Result<_, _>>()?;
+    ///             Ok(Scores(scores))
+    ///         })
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn entries<K, V>(&mut self) -> MapEntries<'_, 'de, Self, K, V>
+    where
+        K: Decode<'de, <Self::Cx as Context>::Mode>,
+        V: Decode<'de, <Self::Cx as Context>::Mode>,
+    {
+        MapEntries::new(self)
+    }
+
+    /// Decode each remaining entry by decoding its key as `K` and passing it
+    /// to `f` along with the decoder for its value.
+    ///
+    /// The value decoder must be consumed by the callback, which makes it
+    /// possible to decode values of different types depending on the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Decode, Decoder};
+    /// use musli::de::MapDecoder;
+    ///
+    /// #[derive(Default)]
+    /// struct Settings {
+    ///     name: String,
+    ///     retries: u32,
+    /// }
+    ///
+    /// impl<'de, M> Decode<'de, M> for Settings {
+    ///     fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    ///     where
+    ///         D: Decoder<'de, Mode = M>,
+    ///     {
+    ///         decoder.decode_map(|map| {
+    ///             let mut settings = Settings::default();
+    ///
+    ///             map.decode_map_fn(|key: String, value| {
+    ///                 match key.as_str() {
+    ///                     "name" => settings.name = value.decode()?,
+    ///                     "retries" => settings.retries = value.decode()?,
+    ///                     _ => value.skip()?,
+    ///                 }
+    ///
+    ///                 Ok(())
+    ///             })?;
+    ///
+    ///             Ok(settings)
+    ///         })
+    ///     }
+    /// }
+    /// ```
+    fn decode_map_fn<K, F>(&mut self, mut f: F) -> Result<(), <Self::Cx as Context>::Error>
+    where
+        K: Decode<'de, <Self::Cx as Context>::Mode>,
+        F: for<'a> FnMut(
+            K,
+            <Self::DecodeEntry<'a> as EntryDecoder<'de>>::DecodeValue,
+        ) -> Result<(), <Self::Cx as Context>::Error>,
+    {
+        while let Some(mut entry) = self.decode_entry()? {
+            let key = entry.decode_key()?.decode()?;
+            f(key, entry.decode_value()?)?;
+        }
+
+        Ok(())
+    }
 }
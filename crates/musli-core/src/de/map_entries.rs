@@ -0,0 +1,93 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::Context;
+
+use super::{Decode, MapDecoder};
+
+/// An iterator over the remaining entries of a map.
+///
+/// See [`MapDecoder::entries`].
+pub struct MapEntries<'a, 'de, D, K, V>
+where
+    D: ?Sized,
+{
+    decoder: &'a mut D,
+    done: bool,
+    #[allow(clippy::type_complexity)]
+    _marker: PhantomData<fn(&'de ()) -> (K, V)>,
+}
+
+impl<'a, 'de, D, K, V> MapEntries<'a, 'de, D, K, V>
+where
+    D: ?Sized,
+{
+    #[inline]
+    pub(crate) fn new(decoder: &'a mut D) -> Self {
+        Self {
+            decoder,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'de, D, K, V> Iterator for MapEntries<'a, 'de, D, K, V>
+where
+    D: MapDecoder<'de>,
+    K: Decode<'de, <D::Cx as Context>::Mode>,
+    V: Decode<'de, <D::Cx as Context>::Mode>,
+{
+    type Item = Result<(K, V), <D::Cx as Context>::Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.decoder.entry() {
+            Ok(Some(entry)) => Some(Ok(entry)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        match self.decoder.size_hint().into_option() {
+            Some(n) => (0, Some(n)),
+            None => (0, None),
+        }
+    }
+}
+
+impl<'a, 'de, D, K, V> core::iter::FusedIterator for MapEntries<'a, 'de, D, K, V>
+where
+    D: MapDecoder<'de>,
+    K: Decode<'de, <D::Cx as Context>::Mode>,
+    V: Decode<'de, <D::Cx as Context>::Mode>,
+{
+}
+
+impl<'a, 'de, D, K, V> fmt::Debug for MapEntries<'a, 'de, D, K, V>
+where
+    D: ?Sized,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapEntries")
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
@@ -57,15 +57,30 @@ pub use self::entry_decoder::EntryDecoder;
 mod map_decoder;
 pub use self::map_decoder::MapDecoder;
 
+mod map_entries;
+pub use self::map_entries::MapEntries;
+
+mod pod_slice_mut;
+pub use self::pod_slice_mut::PodSliceMut;
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub use self::pod_slice_mut::PodVec;
+
 mod sequence_decoder;
 pub use self::sequence_decoder::SequenceDecoder;
 
+mod sequence_iter;
+pub use self::sequence_iter::SequenceIter;
+
 mod size_hint;
 pub use self::size_hint::SizeHint;
 
 mod skip;
 pub use self::skip::Skip;
 
+mod type_hint;
+pub use self::type_hint::TypeHint;
+
 mod unsized_visitor;
 pub use self::unsized_visitor::UnsizedVisitor;
 
@@ -77,7 +92,31 @@ pub use self::visitor::Visitor;
 
 /// Decode to an owned value.
 ///
-/// This is a simpler bound to use than `for<'de> Decode<'de, M>`.
+/// This is a simpler bound to use than `for<'de> Decode<'de, M>`, and is
+/// implemented for every type which can be decoded without borrowing from the
+/// input. It's useful for APIs which decode out of a buffer that doesn't
+/// outlive the call, such as one which is filled from a stream.
+///
+/// # Examples
+///
+/// ```
+/// use musli::de::DecodeOwned;
+/// use musli::mode::Binary;
+/// use musli::wire::Error;
+///
+/// fn decode_chunk<T>(chunk: &[u8]) -> Result<T, Error>
+/// where
+///     T: DecodeOwned<Binary>,
+/// {
+///     let buffer = chunk.to_vec();
+///     musli::wire::from_slice(&buffer)
+/// }
+///
+/// let bytes = musli::wire::to_vec(&String::from("hello"))?;
+/// let value: String = decode_chunk(&bytes)?;
+/// assert_eq!(value, "hello");
+/// # Ok::<(), Error>(())
+/// ```
 pub trait DecodeOwned<M>: for<'de> Decode<'de, M> {}
 
 impl<M, D> DecodeOwned<M> for D where D: for<'de> Decode<'de, M> {}
@@ -0,0 +1,147 @@
+use core::mem::{size_of, size_of_val};
+use core::slice;
+
+#[cfg(feature = "alloc")]
+use rust_alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::Context;
+
+#[cfg(feature = "alloc")]
+use super::SequenceDecoder;
+
+macro_rules! pod_slice_mut {
+    ($($variant:ident($ty:ty)),* $(,)?) => {
+        /// A mutable slice of plain-old-data which is being decoded from a
+        /// sequence.
+        ///
+        /// This is passed to [`SequenceDecoder::try_decode_pod_into`], which
+        /// formats can override to read the whole slice in bulk.
+        ///
+        /// Like [`PodSlice`], it is an enum over slices of each primitive
+        /// number so that [`SequenceDecoder`] can be implemented by formats
+        /// outside of this crate and used through a trait object.
+        ///
+        /// [`SequenceDecoder::try_decode_pod_into`]: crate::de::SequenceDecoder::try_decode_pod_into
+        /// [`PodSlice`]: crate::en::PodSlice
+        /// [`SequenceDecoder`]: crate::de::SequenceDecoder
+        #[derive(Debug)]
+        #[non_exhaustive]
+        pub enum PodSliceMut<'a> {
+            $(
+                #[doc = concat!("A slice of `", stringify!($ty), "`.")]
+                $variant(&'a mut [$ty]),
+            )*
+        }
+
+        impl<'a> PodSliceMut<'a> {
+            /// Get the number of elements in the slice.
+            #[inline]
+            pub fn len(&self) -> usize {
+                match self {
+                    $(Self::$variant(slice) => slice.len(),)*
+                }
+            }
+
+            /// Test if the slice is empty.
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Get the size in bytes of a single element.
+            #[inline]
+            pub fn width(&self) -> usize {
+                match self {
+                    $(Self::$variant(..) => size_of::<$ty>(),)*
+                }
+            }
+
+            /// Access the in-memory representation of the slice, with every
+            /// element in native byte order.
+            #[inline]
+            pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+                match self {
+                    $(
+                        Self::$variant(slice) => {
+                            // SAFETY: Primitive numbers have no padding and
+                            // every bit pattern is valid for them.
+                            unsafe {
+                                let len = size_of_val(*slice);
+                                slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), len)
+                            }
+                        }
+                    )*
+                }
+            }
+        }
+
+        /// A vector of plain-old-data which is being decoded from a sequence.
+        #[cfg(feature = "alloc")]
+        #[doc(hidden)]
+        #[non_exhaustive]
+        pub enum PodVec<'a> {
+            $($variant(&'a mut Vec<$ty>),)*
+        }
+
+        #[cfg(feature = "alloc")]
+        impl PodVec<'_> {
+            /// Clear the vector, retaining its capacity.
+            #[inline]
+            pub(crate) fn clear(&mut self) {
+                match self {
+                    $(Self::$variant(vec) => vec.clear(),)*
+                }
+            }
+
+            /// Decode `len` elements from the sequence into the vector in bulk,
+            /// returning `false` if the sequence can't be decoded in bulk.
+            ///
+            /// The length comes from the input, so the vector is grown in
+            /// bounded steps as data is being read.
+            #[inline]
+            pub(crate) fn try_decode_from<'de, S>(
+                self,
+                seq: &mut S,
+                len: usize,
+            ) -> Result<bool, <S::Cx as Context>::Error>
+            where
+                S: SequenceDecoder<'de>,
+            {
+                match self {
+                    $(
+                        Self::$variant(vec) => {
+                            while vec.len() < len {
+                                let start = vec.len();
+                                let n = crate::internal::size_hint::cautious(Some(len - start));
+                                vec.resize(start + n, Default::default());
+
+                                if !seq.try_decode_pod_into(PodSliceMut::$variant(&mut vec[start..]))? {
+                                    vec.truncate(start);
+                                    return Ok(false);
+                                }
+                            }
+                        }
+                    )*
+                }
+
+                Ok(true)
+            }
+        }
+    };
+}
+
+pod_slice_mut! {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    F32(f32),
+    F64(f64),
+}
@@ -1,6 +1,6 @@
 use crate::Context;
 
-use super::{Decode, Decoder, SizeHint};
+use super::{Decode, Decoder, PodSliceMut, SequenceIter, SizeHint};
 
 /// Trait governing how to decode a sequence.
 pub trait SequenceDecoder<'de>: Sized {
@@ -35,6 +35,25 @@ pub trait SequenceDecoder<'de>: Sized {
         &mut self,
     ) -> Result<Option<Self::DecodeNext<'_>>, <Self::Cx as Context>::Error>;
 
+    /// Try to decode the next `out.len()` elements of the sequence into a
+    /// slice of plain-old-data in bulk.
+    ///
+    /// Formats which can read the whole slice at once, such as when numbers
+    /// are encoded using their in-memory representation, override this and
+    /// return `true`. Otherwise `false` is returned without anything being
+    /// read, and the caller decodes each element individually.
+    ///
+    /// Whether a slice is decoded in bulk must only depend on the type of its
+    /// elements and on how the format is configured.
+    #[inline]
+    fn try_decode_pod_into(
+        &mut self,
+        out: PodSliceMut<'_>,
+    ) -> Result<bool, <Self::Cx as Context>::Error> {
+        _ = out;
+        Ok(false)
+    }
+
     /// Decode the next element of the given type, erroring in case it's absent.
     #[inline]
     fn next<T>(&mut self) -> Result<T, <Self::Cx as Context>::Error>
@@ -58,4 +77,45 @@ pub trait SequenceDecoder<'de>: Sized {
 
         Ok(Some(decoder.decode()?))
     }
+
+    /// Construct an iterator which lazily decodes the remaining elements of
+    /// the sequence as `T`.
+    ///
+    /// This avoids having to collect a large sequence into a collection before
+    /// it can be processed. Iteration stops after the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Decode, Decoder};
+    /// use musli::de::SequenceDecoder;
+    ///
+    /// struct Sum(u64);
+    ///
+    /// impl<'de, M> Decode<'de, M> for Sum {
+    ///     #[inline]
+    ///     fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    ///     where
+    ///         D: Decoder<'de, Mode = M>,
+    ///     {
+    ///         decoder.decode_sequence(|seq| {
+    ///             let mut sum = 0;
+    ///
+    ///             for value in seq.iter::<u32>() {
+    ///                 sum += u64::from(value?);
+    ///             }
+    ///
+    ///             Ok(Sum(sum))
+    ///         })
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn iter<T>(&mut self) -> SequenceIter<'_, 'de, Self, T>
+    where
+        Self: Sized,
+        T: Decode<'de, <Self::Cx as Context>::Mode>,
+    {
+        SequenceIter::new(self)
+    }
 }
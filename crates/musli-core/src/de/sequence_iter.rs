@@ -0,0 +1,90 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::Context;
+
+use super::{Decode, SequenceDecoder};
+
+/// An iterator over the remaining elements of a sequence.
+///
+/// See [`SequenceDecoder::iter`].
+pub struct SequenceIter<'a, 'de, D, T>
+where
+    D: ?Sized,
+{
+    decoder: &'a mut D,
+    done: bool,
+    _marker: PhantomData<fn(&'de ()) -> T>,
+}
+
+impl<'a, 'de, D, T> SequenceIter<'a, 'de, D, T>
+where
+    D: ?Sized,
+{
+    #[inline]
+    pub(crate) fn new(decoder: &'a mut D) -> Self {
+        Self {
+            decoder,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'de, D, T> Iterator for SequenceIter<'a, 'de, D, T>
+where
+    D: SequenceDecoder<'de>,
+    T: Decode<'de, <D::Cx as Context>::Mode>,
+{
+    type Item = Result<T, <D::Cx as Context>::Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.decoder.try_next() {
+            Ok(Some(value)) => Some(Ok(value)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        match self.decoder.size_hint().into_option() {
+            Some(n) => (0, Some(n)),
+            None => (0, None),
+        }
+    }
+}
+
+impl<'a, 'de, D, T> core::iter::FusedIterator for SequenceIter<'a, 'de, D, T>
+where
+    D: SequenceDecoder<'de>,
+    T: Decode<'de, <D::Cx as Context>::Mode>,
+{
+}
+
+impl<'a, 'de, D, T> fmt::Debug for SequenceIter<'a, 'de, D, T>
+where
+    D: ?Sized,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SequenceIter")
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
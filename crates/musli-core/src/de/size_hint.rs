@@ -1,6 +1,6 @@
 use core::fmt;
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 enum SizeHintKind {
     /// The length isn't known.
     #[default]
@@ -10,7 +10,7 @@ enum SizeHintKind {
 }
 
 /// A length hint.
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct SizeHint {
     kind: SizeHintKind,
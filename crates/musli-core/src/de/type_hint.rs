@@ -0,0 +1,54 @@
+use core::fmt;
+
+use super::SizeHint;
+
+/// A hint for what kind of value is next in a self-descriptive format.
+///
+/// This is returned by [`Decoder::type_hint`], and allows a decoder to be
+/// dispatched over without resorting to trial and error.
+///
+/// [`Decoder::type_hint`]: super::Decoder::type_hint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TypeHint {
+    /// The kind of the next value is not known.
+    Unknown,
+    /// A unit type or an empty value.
+    Empty,
+    /// A boolean.
+    Bool,
+    /// A character.
+    Char,
+    /// A number.
+    Number,
+    /// A string with the given length.
+    String(SizeHint),
+    /// A byte array with the given length.
+    Bytes(SizeHint),
+    /// An optional value.
+    Option,
+    /// A sequence with the given length.
+    Sequence(SizeHint),
+    /// A map with the given length.
+    Map(SizeHint),
+    /// A variant.
+    Variant,
+}
+
+impl fmt::Display for TypeHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeHint::Unknown => write!(f, "unknown"),
+            TypeHint::Empty => write!(f, "empty"),
+            TypeHint::Bool => write!(f, "bool"),
+            TypeHint::Char => write!(f, "char"),
+            TypeHint::Number => write!(f, "number"),
+            TypeHint::String(size) => write!(f, "string with {size}"),
+            TypeHint::Bytes(size) => write!(f, "bytes with {size}"),
+            TypeHint::Option => write!(f, "option"),
+            TypeHint::Sequence(size) => write!(f, "sequence with {size}"),
+            TypeHint::Map(size) => write!(f, "map with {size}"),
+            TypeHint::Variant => write!(f, "variant"),
+        }
+    }
+}
@@ -182,6 +182,28 @@ pub trait Visitor<'de, C: ?Sized + Context>: Sized {
         )))
     }
 
+    /// Indicates that the visited type is a number which can't be represented
+    /// by any of the native numeric types without losing precision, such as a
+    /// very large integer or a decimal with many significant digits.
+    ///
+    /// The number is provided in the textual representation used by the
+    /// format, like `-123456789012345678901234567890123456789012` or
+    /// `0.1000000000000000000000000000000000000000001`. Override this to
+    /// capture it in an arbitrary precision type.
+    ///
+    /// By default the number is parsed as an `f64` and passed to
+    /// [`Visitor::visit_f64`].
+    #[inline]
+    fn visit_big_number(self, cx: &C, number: &str) -> Result<Self::Ok, C::Error> {
+        match number.parse::<f64>() {
+            Ok(value) => self.visit_f64(cx, value),
+            Err(..) => Err(cx.message(expecting::unsupported_type(
+                &expecting::Number,
+                ExpectingWrapper::new(&self),
+            ))),
+        }
+    }
+
     /// Indicates that the visited type is an optional type.
     #[inline]
     fn visit_option<D>(self, cx: &C, _: Option<D>) -> Result<Self::Ok, C::Error>
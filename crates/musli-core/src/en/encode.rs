@@ -1,4 +1,4 @@
-use crate::en::Encoder;
+use crate::en::{Encoder, PodSlice};
 
 /// Trait governing how types are encoded.
 ///
@@ -40,6 +40,23 @@ pub trait Encode<M> {
     fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
     where
         E: Encoder<Mode = M>;
+
+    /// Coerce a slice of this type into a slice of plain-old-data.
+    ///
+    /// This is only implemented for primitive numbers, and allows slices and
+    /// vectors of them to be written in bulk through
+    /// [`SequenceEncoder::try_push_pod`].
+    ///
+    /// [`SequenceEncoder::try_push_pod`]: crate::en::SequenceEncoder::try_push_pod
+    #[doc(hidden)]
+    #[inline]
+    fn as_pod_slice(slice: &[Self]) -> Option<PodSlice<'_>>
+    where
+        Self: Sized,
+    {
+        _ = slice;
+        None
+    }
 }
 
 impl<T, M> Encode<M> for &T
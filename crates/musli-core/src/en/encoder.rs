@@ -2,6 +2,7 @@
 
 use core::fmt;
 
+use crate::de::SizeHint;
 use crate::expecting::{self, Expecting};
 use crate::hint::{MapHint, SequenceHint};
 use crate::Context;
@@ -778,6 +779,19 @@ pub trait Encoder: Sized {
         )))
     }
 
+    /// Encode a fixed-length array of bytes.
+    ///
+    /// This behaves like [`Encoder::encode_array`] with `N` being the length of
+    /// `array`, and is used where the length of the array is only known at
+    /// runtime, such as when encoding through a type-erased encoder.
+    #[inline]
+    fn encode_array_slice(self, array: &[u8]) -> Result<Self::Ok, <Self::Cx as Context>::Error> {
+        Err(self.cx().message(expecting::unsupported_type(
+            &expecting::Array,
+            ExpectingWrapper::new(&self),
+        )))
+    }
+
     /// Encode a sequence of bytes.
     ///
     /// # Examples
@@ -1262,6 +1276,87 @@ pub trait Encoder: Sized {
         seq.finish_sequence()
     }
 
+    /// Encode a sequence whose length isn't known up front.
+    ///
+    /// Formats which support this mark the end of the sequence once
+    /// [`SequenceEncoder::finish_sequence`] is called instead of prefixing it
+    /// with its length. Other formats raise an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Encode, Encoder};
+    /// use musli::en::SequenceEncoder;
+    /// # struct Evens { data: Vec<u32> }
+    ///
+    /// impl<M> Encode<M> for Evens {
+    ///     fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    ///     where
+    ///         E: Encoder<Mode = M>,
+    ///     {
+    ///         let mut seq = encoder.encode_unsized_sequence()?;
+    ///
+    ///         for n in self.data.iter().filter(|n| *n % 2 == 0) {
+    ///             seq.push(n)?;
+    ///         }
+    ///
+    ///         seq.finish_sequence()
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn encode_unsized_sequence(self) -> Result<Self::EncodeSequence, <Self::Cx as Context>::Error> {
+        Err(self.cx().message(expecting::unsupported_type(
+            &expecting::SequenceWith(SizeHint::any()),
+            ExpectingWrapper::new(&self),
+        )))
+    }
+
+    /// Encode a sequence from an iterator.
+    ///
+    /// If the iterator reports an exact [`Iterator::size_hint`] the sequence
+    /// is encoded like one with a known length, otherwise it's encoded
+    /// through [`Encoder::encode_unsized_sequence`]. This means that iterators
+    /// which don't know their length can be encoded without collecting them
+    /// first in formats which support it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Encode, Encoder};
+    /// # struct Evens { data: Vec<u32> }
+    ///
+    /// impl<M> Encode<M> for Evens {
+    ///     fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    ///     where
+    ///         E: Encoder<Mode = M>,
+    ///     {
+    ///         encoder.encode_sequence_iter(self.data.iter().filter(|n| *n % 2 == 0))
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn encode_sequence_iter<I>(self, iter: I) -> Result<Self::Ok, <Self::Cx as Context>::Error>
+    where
+        I: IntoIterator,
+        I::Item: Encode<Self::Mode>,
+    {
+        let iter = iter.into_iter();
+
+        let mut seq = match iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => {
+                self.encode_sequence(&SequenceHint::with_size(lower))?
+            }
+            _ => self.encode_unsized_sequence()?,
+        };
+
+        for item in iter {
+            seq.push(item)?;
+        }
+
+        seq.finish_sequence()
+    }
+
     /// Encode a map with a known length `len`.
     ///
     /// # Examples
@@ -1310,6 +1405,9 @@ pub trait Encoder: Sized {
 
     /// Encode a map using a closure.
     ///
+    /// Structs are encoded as maps keyed by field, so this is also the helper
+    /// to use when encoding a struct manually.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1395,6 +1493,53 @@ pub trait Encoder: Sized {
         )))
     }
 
+    /// Encode a map through pairs using a closure.
+    ///
+    /// This ensures that [`EntriesEncoder::finish_entries`] is called once the
+    /// closure returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Encode, Encoder};
+    /// use musli::en::EntriesEncoder;
+    /// use musli::hint::MapHint;
+    ///
+    /// struct Struct {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// impl<M> Encode<M> for Struct {
+    ///     fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    ///     where
+    ///         E: Encoder,
+    ///     {
+    ///         let hint = MapHint::with_size(2);
+    ///
+    ///         encoder.encode_map_entries_fn(&hint, |m| {
+    ///             m.insert_entry("name", &self.name)?;
+    ///             m.encode_entry_key()?.encode("age")?;
+    ///             m.encode_entry_value()?.encode(self.age)?;
+    ///             Ok(())
+    ///         })
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn encode_map_entries_fn<F>(
+        self,
+        hint: &MapHint,
+        f: F,
+    ) -> Result<Self::Ok, <Self::Cx as Context>::Error>
+    where
+        F: FnOnce(&mut Self::EncodeMapEntries) -> Result<(), <Self::Cx as Context>::Error>,
+    {
+        let mut entries = self.encode_map_entries(hint)?;
+        f(&mut entries)?;
+        entries.finish_entries()
+    }
+
     /// Encode a variant.
     ///
     /// # Examples
@@ -1697,6 +1842,46 @@ pub trait Encoder: Sized {
             ExpectingWrapper::new(&self),
         )))
     }
+
+    /// Encode a value as an application-defined extension with the given type
+    /// `id`.
+    ///
+    /// Formats which support extensions mark the value with its type id, so
+    /// that generic tooling can tell it apart from other values and skip over
+    /// it without knowing what it is. Other formats encode the value as-is.
+    ///
+    /// The value can be decoded again with [`Decoder::decode_extension`].
+    ///
+    /// [`Decoder::decode_extension`]: crate::Decoder::decode_extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Encode, Encoder};
+    ///
+    /// /// A decimal number with four fractional digits.
+    /// struct Decimal(i64);
+    ///
+    /// impl<M> Encode<M> for Decimal {
+    ///     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    ///     where
+    ///         E: Encoder<Mode = M>,
+    ///     {
+    ///         encoder.encode_extension(1, &self.0)
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn encode_extension<T>(
+        self,
+        id: u32,
+        value: &T,
+    ) -> Result<Self::Ok, <Self::Cx as Context>::Error>
+    where
+        T: ?Sized + Encode<Self::Mode>,
+    {
+        self.encode(value)
+    }
 }
 
 #[repr(transparent)]
@@ -48,6 +48,9 @@ pub use self::entry_encoder::EntryEncoder;
 mod map_encoder;
 pub use self::map_encoder::MapEncoder;
 
+mod pod_slice;
+pub use self::pod_slice::PodSlice;
+
 mod sequence_encoder;
 pub use self::sequence_encoder::SequenceEncoder;
 
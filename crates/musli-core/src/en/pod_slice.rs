@@ -0,0 +1,85 @@
+use core::mem::{size_of, size_of_val};
+use core::slice;
+
+macro_rules! pod_slice {
+    ($($variant:ident($ty:ty)),* $(,)?) => {
+        /// A slice of plain-old-data which is being encoded as a sequence.
+        ///
+        /// This is passed to [`SequenceEncoder::try_push_pod`], which formats
+        /// can override to write the whole slice in bulk.
+        ///
+        /// It is an enum over slices of each primitive number rather than a
+        /// generic parameter, since a format needs to know the type of the
+        /// elements to decide how to write them, and since a generic method
+        /// would prevent [`SequenceEncoder`] from being used through a trait
+        /// object. It's public so that formats outside of this crate can
+        /// implement the bulk path as well.
+        ///
+        /// [`SequenceEncoder::try_push_pod`]: crate::en::SequenceEncoder::try_push_pod
+        /// [`SequenceEncoder`]: crate::en::SequenceEncoder
+        #[derive(Debug, Clone, Copy)]
+        #[non_exhaustive]
+        pub enum PodSlice<'a> {
+            $(
+                #[doc = concat!("A slice of `", stringify!($ty), "`.")]
+                $variant(&'a [$ty]),
+            )*
+        }
+
+        impl<'a> PodSlice<'a> {
+            /// Get the number of elements in the slice.
+            #[inline]
+            pub fn len(&self) -> usize {
+                match self {
+                    $(Self::$variant(slice) => slice.len(),)*
+                }
+            }
+
+            /// Test if the slice is empty.
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Get the size in bytes of a single element.
+            #[inline]
+            pub fn width(&self) -> usize {
+                match self {
+                    $(Self::$variant(..) => size_of::<$ty>(),)*
+                }
+            }
+
+            /// Access the in-memory representation of the slice, with every
+            /// element in native byte order.
+            #[inline]
+            pub fn as_bytes(&self) -> &'a [u8] {
+                match *self {
+                    $(
+                        Self::$variant(slice) => {
+                            // SAFETY: Primitive numbers have no padding, so
+                            // every byte of the slice is initialized.
+                            unsafe {
+                                slice::from_raw_parts(slice.as_ptr().cast(), size_of_val(slice))
+                            }
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+pod_slice! {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    F32(f32),
+    F64(f64),
+}
@@ -1,6 +1,6 @@
 use crate::Context;
 
-use super::{Encode, Encoder};
+use super::{Encode, Encoder, PodSlice};
 
 /// Trait governing how to encode a sequence.
 pub trait SequenceEncoder {
@@ -34,4 +34,19 @@ pub trait SequenceEncoder {
         self.encode_next()?.encode(value)?;
         Ok(())
     }
+
+    /// Try to push a slice of plain-old-data into the sequence in bulk.
+    ///
+    /// Formats which can write the whole slice at once, such as when numbers
+    /// are encoded using their in-memory representation, override this and
+    /// return `true`. Otherwise `false` is returned without anything being
+    /// written, and the caller pushes each element individually.
+    ///
+    /// Whether a slice is pushed in bulk must only depend on the type of its
+    /// elements and on how the format is configured.
+    #[inline]
+    fn try_push_pod(&mut self, pod: PodSlice<'_>) -> Result<bool, <Self::Cx as Context>::Error> {
+        _ = pod;
+        Ok(false)
+    }
 }
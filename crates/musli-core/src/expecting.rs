@@ -147,4 +147,5 @@ expect! {
     pub(crate) SequenceVariant("sequence variant");
     pub(crate) Variant("variant");
     pub(crate) AnyValue("a value");
+    pub(crate) RawValue("raw value");
 }
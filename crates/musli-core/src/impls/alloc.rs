@@ -81,28 +81,45 @@ impl<'de, M> Decode<'de, M> for String {
 
         decoder.decode_string(Visitor)
     }
-}
 
-impl<'de, M> Decode<'de, M> for Box<str> {
     #[inline]
-    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    fn decode_into<D>(_: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
     where
         D: Decoder<'de, Mode = M>,
     {
-        Ok(decoder.decode::<String>()?.into())
-    }
-}
+        struct Visitor<'a>(&'a mut String);
 
-impl<'de, M, T> Decode<'de, M> for Box<[T]>
-where
-    T: Decode<'de, M>,
-{
-    #[inline]
-    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
-    where
-        D: Decoder<'de, Mode = M>,
-    {
-        Ok(decoder.decode::<Vec<T>>()?.into())
+        impl<'de, C> UnsizedVisitor<'de, C, str> for Visitor<'_>
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = ();
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "string")
+            }
+
+            #[inline]
+            fn visit_owned(self, _: &C, value: String) -> Result<Self::Ok, C::Error> {
+                *self.0 = value;
+                Ok(())
+            }
+
+            #[inline]
+            fn visit_borrowed(self, cx: &C, string: &'de str) -> Result<Self::Ok, C::Error> {
+                self.visit_ref(cx, string)
+            }
+
+            #[inline]
+            fn visit_ref(self, _: &C, string: &str) -> Result<Self::Ok, C::Error> {
+                self.0.clear();
+                self.0.push_str(string);
+                Ok(())
+            }
+        }
+
+        decoder.decode_string(Visitor(place))
     }
 }
 
@@ -111,7 +128,7 @@ macro_rules! cow {
         $encode:ident :: $encode_fn:ident,
         $decode:ident :: $decode_fn:ident,
         $ty:ty, $source:ty,
-        $decode_method:ident, $cx:pat,
+        $decode_method:ident, $cx:pat, $expecting:literal,
         |$owned:ident| $owned_expr:expr,
         |$borrowed:ident| $borrowed_expr:expr,
         |$reference:ident| $reference_expr:expr $(,)?
@@ -142,7 +159,7 @@ macro_rules! cow {
 
                     #[inline]
                     fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                        write!(f, "a string")
+                        write!(f, $expecting)
                     }
 
                     #[inline]
@@ -182,7 +199,7 @@ macro_rules! cow {
 cow! {
     Encode::encode,
     Decode::decode,
-    str, str, decode_string, _,
+    str, str, decode_string, _, "a string",
     |owned| Cow::Owned(owned),
     |borrowed| Cow::Borrowed(borrowed),
     |reference| Cow::Owned(reference.to_owned())
@@ -191,7 +208,7 @@ cow! {
 cow! {
     Encode::encode,
     Decode::decode,
-    CStr, [u8], decode_bytes, cx,
+    CStr, [u8], decode_bytes, cx, "a cstring",
     |owned| Cow::Owned(CString::from_vec_with_nul(owned).map_err(cx.map())?),
     |borrowed| Cow::Borrowed(CStr::from_bytes_with_nul(borrowed).map_err(cx.map())?),
     |reference| Cow::Owned(CStr::from_bytes_with_nul(reference).map_err(cx.map())?.to_owned())
@@ -200,7 +217,7 @@ cow! {
 cow! {
     EncodeBytes::encode_bytes,
     DecodeBytes::decode_bytes,
-    [u8], [u8], decode_bytes, _,
+    [u8], [u8], decode_bytes, _, "bytes",
     |owned| Cow::Owned(owned),
     |borrowed| Cow::Borrowed(borrowed),
     |reference| Cow::Owned(reference.to_owned())
@@ -219,7 +236,6 @@ macro_rules! sequence {
         impl<M, T $(, $extra)*> Encode<M> for $ty<T $(, $extra)*>
         where
             T: Encode<M>,
-            $($extra: $extra_bound0 $(+ $extra_bound)*),*
         {
             #[inline]
             fn encode<E>(&self, $cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
@@ -269,13 +285,33 @@ macro_rules! sequence {
                     Ok(out)
                 })
             }
+
+            #[inline]
+            fn decode_into<D>($cx: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+            where
+                D: Decoder<'de, Mode = M>,
+            {
+                place.clear();
+
+                decoder.decode_sequence(|$access| {
+                    let mut index = 0;
+
+                    while let Some(value) = $access.try_decode_next()? {
+                        $cx.enter_sequence_index(index);
+                        place.$insert(T::decode($cx, value)?);
+                        $cx.leave_sequence_index();
+                        index = index.wrapping_add(1);
+                    }
+
+                    Ok(())
+                })
+            }
         }
 
         $(#[$($meta)*])*
         impl<M, T $(, $extra)*> EncodePacked<M> for $ty<T $(, $extra)*>
         where
             T: Encode<M>,
-            $($extra: $extra_bound0 $(+ $extra_bound)*),*
         {
             #[inline]
             fn encode_packed<E>(&self, $cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
@@ -299,13 +335,123 @@ macro_rules! sequence {
     }
 }
 
-sequence!(
-    cx,
-    Vec<T>,
-    push,
-    seq,
-    Vec::with_capacity(size_hint::cautious(seq.size_hint()))
-);
+impl<M, T> Encode<M> for Vec<T>
+where
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        super::encode_slice(cx, encoder, self)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Vec<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_sequence(|seq| {
+            let mut out = Vec::new();
+
+            // A vector of plain-old-data with a known length is decoded in
+            // bulk if the format supports it.
+            if let Some(len) = seq.size_hint().into_option() {
+                if let Some(pod) = T::as_pod_vec(&mut out) {
+                    if pod.try_decode_from(seq, len)? {
+                        return Ok(out);
+                    }
+                }
+            }
+
+            out.reserve(size_hint::cautious(seq.size_hint()));
+            let mut index = out.len();
+
+            while let Some(value) = seq.try_decode_next()? {
+                cx.enter_sequence_index(index);
+                out.push(T::decode(cx, value)?);
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(out)
+        })
+    }
+
+    #[inline]
+    fn decode_into<D>(cx: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_sequence(|seq| {
+            let mut index = 0;
+
+            if let Some(len) = seq.size_hint().into_option() {
+                if let Some(mut pod) = T::as_pod_vec(place) {
+                    pod.clear();
+
+                    if pod.try_decode_from(seq, len)? {
+                        return Ok(());
+                    }
+
+                    index = place.len();
+                }
+            }
+
+            // Existing elements are decoded in place, so that any allocations
+            // they hold are reused as well.
+
+            while let Some(value) = seq.try_decode_next()? {
+                cx.enter_sequence_index(index);
+
+                match place.get_mut(index) {
+                    Some(existing) => T::decode_into(cx, value, existing)?,
+                    None => place.push(T::decode(cx, value)?),
+                }
+
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            place.truncate(index);
+            Ok(())
+        })
+    }
+}
+
+impl<M, T> EncodePacked<M> for Vec<T>
+where
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode_packed<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_pack_fn(|pack| {
+            if let Some(pod) = T::as_pod_slice(self) {
+                if pack.try_push_pod(pod)? {
+                    return Ok(());
+                }
+            }
+
+            for (index, value) in self.iter().enumerate() {
+                cx.enter_sequence_index(index);
+                pack.push(value)?;
+                cx.leave_sequence_index();
+            }
+
+            Ok(())
+        })
+    }
+}
+
 sequence!(
     cx,
     VecDeque<T>,
@@ -344,7 +490,6 @@ macro_rules! map {
         where
             K: Encode<M>,
             V: Encode<M>,
-            $($extra: $extra_bound0 $(+ $extra_bound)*),*
         {
             #[inline]
             fn encode<E>(&self, $cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
@@ -368,7 +513,6 @@ macro_rules! map {
         where
             K: fmt::Display + Encode<M>,
             V: Encode<M>,
-            $($extra: $extra_bound0 $(+ $extra_bound)*),*
         {
             #[inline]
             fn trace_encode<E>(&self, $cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
@@ -415,6 +559,22 @@ macro_rules! map {
                     Ok(out)
                 })
             }
+
+            #[inline]
+            fn decode_into<D>(_: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+            where
+                D: Decoder<'de, Mode = M>,
+            {
+                place.clear();
+
+                decoder.decode_map(|$access| {
+                    while let Some((key, value)) = $access.entry()? {
+                        place.insert(key, value);
+                    }
+
+                    Ok(())
+                })
+            }
         }
 
         $(#[$($meta)*])*
@@ -538,6 +698,39 @@ macro_rules! smart_pointer {
                 }
             }
 
+            impl<'de, M> Decode<'de, M> for $ty<str> {
+                #[inline]
+                fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+                where
+                    D: Decoder<'de, Mode = M>,
+                {
+                    Ok($ty::from(decoder.decode::<String>()?))
+                }
+            }
+
+            impl<'de, M, T> Decode<'de, M> for $ty<[T]>
+            where
+                T: Decode<'de, M>,
+            {
+                #[inline]
+                fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+                where
+                    D: Decoder<'de, Mode = M>,
+                {
+                    Ok($ty::from(decoder.decode::<Vec<T>>()?))
+                }
+            }
+
+            impl<M> EncodeBytes<M> for $ty<[u8]> {
+                #[inline]
+                fn encode_bytes<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+                where
+                    E: Encoder<Mode = M>,
+                {
+                    encoder.encode_bytes(self.as_ref())
+                }
+            }
+
             impl<'de, M> DecodeBytes<'de, M> for $ty<[u8]> {
                 #[inline]
                 fn decode_bytes<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
@@ -587,6 +780,13 @@ macro_rules! smart_pointer {
 
 smart_pointer!(Box, Arc, Rc);
 
+/// Operating system strings are not guaranteed to be valid UTF-8, so they are
+/// encoded as a variant tagged by the platform they were produced on. On Unix
+/// the data is the raw bytes of the string, and on Windows it is the UTF-16
+/// code units in little-endian order.
+///
+/// Decoding a string produced on a different platform results in an error,
+/// rather than lossily converting it.
 #[cfg(all(feature = "std", any(unix, windows)))]
 #[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", any(unix, windows)))))]
 impl<M> Encode<M> for OsStr
@@ -733,6 +933,8 @@ where
     }
 }
 
+/// Paths are encoded in the same way as [`OsStr`], so non-UTF-8 paths are
+/// preserved exactly.
 #[cfg(all(feature = "std", any(unix, windows)))]
 #[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", any(unix, windows)))))]
 impl<M> Encode<M> for Path
@@ -788,16 +990,6 @@ impl<M> EncodeBytes<M> for Vec<u8> {
     }
 }
 
-impl<M> EncodeBytes<M> for Box<[u8]> {
-    #[inline]
-    fn encode_bytes<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
-    where
-        E: Encoder<Mode = M>,
-    {
-        encoder.encode_bytes(self.as_ref())
-    }
-}
-
 impl<'de, M> DecodeBytes<'de, M> for Vec<u8> {
     #[inline]
     fn decode_bytes<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
@@ -0,0 +1,87 @@
+//! Implementations for the [`bytes`] crate.
+//!
+//! [`Bytes`] and [`BytesMut`] are encoded as bytes. When decoding into
+//! [`Bytes`], an owned buffer handed out by the decoder is converted without
+//! copying.
+//!
+//! [`bytes`]: https://docs.rs/bytes
+
+use core::fmt;
+
+use bytes::{Bytes, BytesMut};
+use rust_alloc::vec::Vec;
+
+use crate::de::{Decode, DecodeBytes, Decoder, UnsizedVisitor};
+use crate::en::{Encode, EncodeBytes, Encoder};
+use crate::Context;
+
+struct Visitor;
+
+impl<'de, C> UnsizedVisitor<'de, C, [u8]> for Visitor
+where
+    C: ?Sized + Context,
+{
+    type Ok = Bytes;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bytes")
+    }
+
+    #[inline]
+    fn visit_owned(self, _: &C, bytes: Vec<u8>) -> Result<Self::Ok, C::Error> {
+        Ok(Bytes::from(bytes))
+    }
+
+    #[inline]
+    fn visit_ref(self, _: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+        Ok(Bytes::copy_from_slice(bytes))
+    }
+}
+
+macro_rules! bytes {
+    ($ty:ty, $from:expr) => {
+        impl<M> Encode<M> for $ty {
+            #[inline]
+            fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+            where
+                E: Encoder<Mode = M>,
+            {
+                encoder.encode_bytes(self)
+            }
+        }
+
+        impl<M> EncodeBytes<M> for $ty {
+            #[inline]
+            fn encode_bytes<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+            where
+                E: Encoder<Mode = M>,
+            {
+                encoder.encode_bytes(self)
+            }
+        }
+
+        impl<'de, M> Decode<'de, M> for $ty {
+            #[inline]
+            fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+            where
+                D: Decoder<'de, Mode = M>,
+            {
+                decoder.decode_bytes(Visitor).map($from)
+            }
+        }
+
+        impl<'de, M> DecodeBytes<'de, M> for $ty {
+            #[inline]
+            fn decode_bytes<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+            where
+                D: Decoder<'de, Mode = M>,
+            {
+                decoder.decode_bytes(Visitor).map($from)
+            }
+        }
+    };
+}
+
+bytes!(Bytes, |bytes| bytes);
+bytes!(BytesMut, |bytes: Bytes| BytesMut::from(&bytes[..]));
@@ -1,24 +1,32 @@
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 mod alloc;
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+mod bytes;
 #[cfg(feature = "std")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 mod net;
 mod range;
+mod time;
 mod tuples;
 
 use core::ffi::CStr;
 use core::num::{
     NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
-    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
 };
 use core::{fmt, marker};
 
+#[cfg(feature = "alloc")]
+use crate::de::PodVec;
 use crate::de::{
     Decode, DecodeBytes, DecodePacked, DecodeUnsized, DecodeUnsizedBytes, Decoder, SequenceDecoder,
     UnsizedVisitor, VariantDecoder,
 };
-use crate::en::{Encode, EncodeBytes, EncodePacked, Encoder, SequenceEncoder, VariantEncoder};
+use crate::en::{
+    Encode, EncodeBytes, EncodePacked, Encoder, PodSlice, SequenceEncoder, VariantEncoder,
+};
 use crate::hint::SequenceHint;
 use crate::Context;
 
@@ -31,6 +39,33 @@ enum PlatformTag {
     Windows,
 }
 
+/// Encode a slice as a sequence, letting the format write it in bulk if it is
+/// a slice of plain-old-data.
+#[inline]
+pub(crate) fn encode_slice<M, T, E>(cx: &E::Cx, encoder: E, slice: &[T]) -> Result<E::Ok, E::Error>
+where
+    T: Encode<M>,
+    E: Encoder<Mode = M>,
+{
+    let hint = SequenceHint::with_size(slice.len());
+
+    encoder.encode_sequence_fn(&hint, |seq| {
+        if let Some(pod) = T::as_pod_slice(slice) {
+            if seq.try_push_pod(pod)? {
+                return Ok(());
+            }
+        }
+
+        for (index, value) in slice.iter().enumerate() {
+            cx.enter_sequence_index(index);
+            seq.push(value)?;
+            cx.leave_sequence_index();
+        }
+
+        Ok(())
+    })
+}
+
 impl<M> Encode<M> for () {
     #[inline]
     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
@@ -75,6 +110,20 @@ impl<'de, M, T> Decode<'de, M> for marker::PhantomData<T> {
 macro_rules! atomic_impl {
     ($size:literal $(, $ty:ident)*) => {
         $(
+            /// Encodes the value loaded with [`Ordering::Relaxed`].
+            ///
+            /// [`Ordering::Relaxed`]: core::sync::atomic::Ordering::Relaxed
+            #[cfg(target_has_atomic = $size)]
+            impl<M> Encode<M> for core::sync::atomic::$ty {
+                #[inline]
+                fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+                where
+                    E: Encoder<Mode = M>,
+                {
+                    self.load(core::sync::atomic::Ordering::Relaxed).encode(cx, encoder)
+                }
+            }
+
             #[cfg(target_has_atomic = $size)]
             impl<'de, M> Decode<'de, M> for core::sync::atomic::$ty {
                 fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
@@ -111,14 +160,18 @@ macro_rules! non_zero {
             where
                 D: Decoder<'de>,
             {
+                let mark = cx.mark();
                 let value = decoder.decode()?;
 
                 match Self::new(value) {
                     Some(value) => Ok(value),
-                    None => Err(cx.message(NonZeroUnsupportedValue {
-                        type_name: stringify!($ty),
-                        value,
-                    })),
+                    None => Err(cx.marked_message(
+                        mark,
+                        NonZeroUnsupportedValue {
+                            type_name: stringify!($ty),
+                            value,
+                        },
+                    )),
                 }
             }
         }
@@ -161,19 +214,11 @@ where
     T: Encode<M>,
 {
     #[inline]
-    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
     where
         E: Encoder<Mode = M>,
     {
-        let hint = SequenceHint::with_size(N);
-
-        encoder.encode_sequence_fn(&hint, |seq| {
-            for value in self.iter() {
-                seq.push(value)?;
-            }
-
-            Ok(())
-        })
+        encode_slice(cx, encoder, self)
     }
 }
 
@@ -192,6 +237,13 @@ where
             let mut array = crate::internal::FixedVec::new();
 
             while let Some(item) = seq.try_decode_next()? {
+                if array.len() == N {
+                    return Err(cx.marked_message(
+                        mark,
+                        format_args!("Array has more than the expected {N} number of elements"),
+                    ));
+                }
+
                 array.try_push(item.decode()?).map_err(cx.map())?;
             }
 
@@ -252,7 +304,7 @@ where
 }
 
 macro_rules! impl_number {
-    ($ty:ty, $read:ident, $write:ident) => {
+    ($ty:ty, $read:ident, $write:ident $(, $pod:ident)?) => {
         impl<M> Encode<M> for $ty {
             #[inline]
             fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
@@ -261,6 +313,13 @@ macro_rules! impl_number {
             {
                 encoder.$write(*self)
             }
+
+            $(
+                #[inline]
+                fn as_pod_slice(slice: &[Self]) -> Option<PodSlice<'_>> {
+                    Some(PodSlice::$pod(slice))
+                }
+            )?
         }
 
         impl<'de, M> Decode<'de, M> for $ty {
@@ -271,6 +330,14 @@ macro_rules! impl_number {
             {
                 decoder.$read()
             }
+
+            $(
+                #[cfg(feature = "alloc")]
+                #[inline]
+                fn as_pod_vec(vec: &mut rust_alloc::vec::Vec<Self>) -> Option<PodVec<'_>> {
+                    Some(PodVec::$pod(vec))
+                }
+            )?
         }
     };
 }
@@ -317,18 +384,18 @@ impl<'de, M> Decode<'de, M> for char {
 
 impl_number!(usize, decode_usize, encode_usize);
 impl_number!(isize, decode_isize, encode_isize);
-impl_number!(u8, decode_u8, encode_u8);
-impl_number!(u16, decode_u16, encode_u16);
-impl_number!(u32, decode_u32, encode_u32);
-impl_number!(u64, decode_u64, encode_u64);
-impl_number!(u128, decode_u128, encode_u128);
-impl_number!(i8, decode_i8, encode_i8);
-impl_number!(i16, decode_i16, encode_i16);
-impl_number!(i32, decode_i32, encode_i32);
-impl_number!(i64, decode_i64, encode_i64);
-impl_number!(i128, decode_i128, encode_i128);
-impl_number!(f32, decode_f32, encode_f32);
-impl_number!(f64, decode_f64, encode_f64);
+impl_number!(u8, decode_u8, encode_u8, U8);
+impl_number!(u16, decode_u16, encode_u16, U16);
+impl_number!(u32, decode_u32, encode_u32, U32);
+impl_number!(u64, decode_u64, encode_u64, U64);
+impl_number!(u128, decode_u128, encode_u128, U128);
+impl_number!(i8, decode_i8, encode_i8, I8);
+impl_number!(i16, decode_i16, encode_i16, I16);
+impl_number!(i32, decode_i32, encode_i32, I32);
+impl_number!(i64, decode_i64, encode_i64, I64);
+impl_number!(i128, decode_i128, encode_i128, I128);
+impl_number!(f32, decode_f32, encode_f32, F32);
+impl_number!(f64, decode_f64, encode_f64, F64);
 
 impl<M> Encode<M> for str {
     #[inline]
@@ -404,24 +471,12 @@ impl<M, T> Encode<M> for [T]
 where
     T: Encode<M>,
 {
+    #[inline]
     fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
     where
         E: Encoder<Mode = M>,
     {
-        let hint = SequenceHint::with_size(self.len());
-
-        encoder.encode_sequence_fn(&hint, |seq| {
-            let mut index = 0;
-
-            for value in self {
-                cx.enter_sequence_index(index);
-                seq.encode_next()?.encode(value)?;
-                cx.leave_sequence_index();
-                index = index.wrapping_add(index);
-            }
-
-            Ok(())
-        })
+        encode_slice(cx, encoder, self)
     }
 }
 
@@ -516,6 +571,20 @@ where
             Ok(None)
         }
     }
+
+    #[inline]
+    fn decode_into<D>(cx: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        match (decoder.decode_option()?, place.as_mut()) {
+            (Some(decoder), Some(existing)) => T::decode_into(cx, decoder, existing)?,
+            (Some(decoder), None) => *place = Some(decoder.decode()?),
+            (None, _) => *place = None,
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Encode, Decode)]
@@ -593,6 +662,32 @@ where
     }
 }
 
+impl<T, M> Encode<M> for Saturating<T>
+where
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.0.encode(cx, encoder)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Saturating<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        Ok(Saturating(decoder.decode()?))
+    }
+}
+
 impl<M> Encode<M> for CStr {
     #[inline]
     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
@@ -1,3 +1,14 @@
+//! Implementations for network address types.
+//!
+//! Addresses have a compact representation in [`Binary`] mode, where an
+//! [`Ipv4Addr`] is its 4 octets and an [`Ipv6Addr`] is its 16 octets. Socket
+//! addresses are packed together with their port, and for [`SocketAddrV6`]
+//! the flow information and scope identifier. [`IpAddr`] and [`SocketAddr`]
+//! are encoded as a variant tagged by the address family.
+//!
+//! In [`Text`] mode addresses are instead encoded as their standard string
+//! representation, such as `"127.0.0.1"` or `"[::1]:8080"`.
+
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
 use std::str::FromStr;
 
@@ -26,7 +37,7 @@ impl Encode<Binary> for Ipv4Addr {
     #[inline]
     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
     where
-        E: Encoder,
+        E: Encoder<Mode = Binary>,
     {
         encoder.encode_array(&self.octets())
     }
@@ -36,7 +47,7 @@ impl Encode<Text> for Ipv4Addr {
     #[inline]
     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
     where
-        E: Encoder,
+        E: Encoder<Mode = Text>,
     {
         encoder.collect_string(self)
     }
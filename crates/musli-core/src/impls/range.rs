@@ -1,9 +1,20 @@
-use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
-use crate::en::SequenceEncoder;
+use crate::de::VariantDecoder;
+use crate::en::{SequenceEncoder, VariantEncoder};
 use crate::hint::SequenceHint;
+use crate::mode::Text;
 use crate::{Decode, Decoder, Encode, Encoder};
 
+#[derive(Encode, Decode)]
+#[musli(crate)]
+#[musli(mode = Text, name_all = "kebab-case")]
+enum BoundTag {
+    Included,
+    Excluded,
+    Unbounded,
+}
+
 macro_rules! implement {
     ($ty:ident $(<$type:ident>)? { $($field:ident),* }, $count:expr) => {
         impl<M, $($type)*> Encode<M> for $ty $(<$type>)*
@@ -83,3 +94,48 @@ implement!(RangeFrom<T> { start }, 1);
 implement!(RangeTo<T> { end }, 1);
 implement!(RangeToInclusive<T> { end }, 1);
 implement_new!(RangeInclusive { start, end }, 2);
+
+impl<M, T> Encode<M> for Bound<T>
+where
+    BoundTag: Encode<M>,
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let variant = encoder.encode_variant()?;
+
+        match self {
+            Bound::Included(value) => variant.insert_variant(BoundTag::Included, value),
+            Bound::Excluded(value) => variant.insert_variant(BoundTag::Excluded, value),
+            Bound::Unbounded => variant.insert_variant(BoundTag::Unbounded, ()),
+        }
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Bound<T>
+where
+    BoundTag: Decode<'de, M>,
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_variant(|variant| {
+            let tag = variant.decode_tag()?.decode()?;
+
+            Ok(match tag {
+                BoundTag::Included => Bound::Included(variant.decode_value()?.decode()?),
+                BoundTag::Excluded => Bound::Excluded(variant.decode_value()?.decode()?),
+                BoundTag::Unbounded => {
+                    variant.decode_value()?.decode::<()>()?;
+                    Bound::Unbounded
+                }
+            })
+        })
+    }
+}
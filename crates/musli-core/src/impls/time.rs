@@ -0,0 +1,154 @@
+//! Implementations for time types.
+//!
+//! A [`Duration`] is encoded as a sequence of two elements, the number of
+//! whole seconds as a `u64` followed by the number of nanoseconds as a `u32`.
+//!
+//! A [`SystemTime`] is encoded relative to the [`UNIX_EPOCH`] as a sequence of
+//! two elements, the number of whole seconds as an `i64` followed by the number
+//! of nanoseconds as a `u32`. Times before the epoch have a negative number of
+//! seconds, with the nanoseconds always counting forward in time. This is the
+//! same representation as a POSIX `timespec`.
+//!
+//! [`SystemTime`]: std::time::SystemTime
+//! [`UNIX_EPOCH`]: std::time::UNIX_EPOCH
+
+use core::fmt;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::en::SequenceEncoder;
+use crate::hint::SequenceHint;
+use crate::{Context, Decode, Decoder, Encode, Encoder};
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+static HINT: SequenceHint = SequenceHint::with_size(2);
+
+impl<M> Encode<M> for Duration {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_sequence_fn(&HINT, |seq| {
+            seq.encode_next()?.encode(self.as_secs())?;
+            seq.encode_next()?.encode(self.subsec_nanos())?;
+            Ok(())
+        })
+    }
+}
+
+impl<'de, M> Decode<'de, M> for Duration {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        let mark = cx.mark();
+        let (secs, nanos): (u64, u32) = decoder.decode()?;
+
+        if nanos >= NANOS_PER_SEC {
+            return Err(cx.marked_message(mark, InvalidNanos(nanos)));
+        }
+
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl<M> Encode<M> for SystemTime {
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let (secs, nanos) = match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => {
+                let Ok(secs) = i64::try_from(after.as_secs()) else {
+                    return Err(cx.message(SystemTimeOutOfRange));
+                };
+
+                (secs, after.subsec_nanos())
+            }
+            Err(error) => {
+                let before = error.duration();
+
+                let Some(secs) = i64::try_from(before.as_secs())
+                    .ok()
+                    .and_then(i64::checked_neg)
+                else {
+                    return Err(cx.message(SystemTimeOutOfRange));
+                };
+
+                match before.subsec_nanos() {
+                    0 => (secs, 0),
+                    nanos => {
+                        let Some(secs) = secs.checked_sub(1) else {
+                            return Err(cx.message(SystemTimeOutOfRange));
+                        };
+
+                        (secs, NANOS_PER_SEC - nanos)
+                    }
+                }
+            }
+        };
+
+        encoder.encode_sequence_fn(&HINT, |seq| {
+            seq.encode_next()?.encode(secs)?;
+            seq.encode_next()?.encode(nanos)?;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl<'de, M> Decode<'de, M> for SystemTime {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        let mark = cx.mark();
+        let (secs, nanos): (i64, u32) = decoder.decode()?;
+
+        if nanos >= NANOS_PER_SEC {
+            return Err(cx.marked_message(mark, InvalidNanos(nanos)));
+        }
+
+        let time = if secs >= 0 {
+            UNIX_EPOCH.checked_add(Duration::new(secs.unsigned_abs(), nanos))
+        } else {
+            UNIX_EPOCH
+                .checked_sub(Duration::from_secs(secs.unsigned_abs()))
+                .and_then(|time| time.checked_add(Duration::from_nanos(nanos.into())))
+        };
+
+        match time {
+            Some(time) => Ok(time),
+            None => Err(cx.marked_message(mark, SystemTimeOutOfRange)),
+        }
+    }
+}
+
+struct InvalidNanos(u32);
+
+impl fmt::Display for InvalidNanos {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Nanoseconds `{}` out of range", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+struct SystemTimeOutOfRange;
+
+#[cfg(feature = "std")]
+impl fmt::Display for SystemTimeOutOfRange {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "System time out of range")
+    }
+}
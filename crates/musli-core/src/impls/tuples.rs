@@ -1,4 +1,4 @@
-//! Implementations for variously lengthed tuples.
+//! Implementations for tuples with up to 16 elements.
 
 use crate::de::{Decode, DecodePacked, Decoder, SequenceDecoder};
 use crate::en::{Encode, EncodePacked, Encoder, SequenceEncoder};
@@ -0,0 +1,162 @@
+//! Helpers used by `#[musli(flatten)]` fields.
+//!
+//! A flattened field is encoded by letting it write its entries straight into
+//! the map of the containing struct. Since the size of that map has to be
+//! known up front, [`FlattenLen`] is first used to find out how many entries
+//! the flattened field produces without encoding any of them.
+
+use core::fmt;
+
+use crate::en::{Encode, Encoder, MapEncoder};
+use crate::hint::MapHint;
+use crate::Context;
+
+/// Encoder which captures the number of entries a flattened value will encode.
+pub struct FlattenLen<'a, C: ?Sized> {
+    cx: &'a C,
+}
+
+impl<'a, C: ?Sized> FlattenLen<'a, C> {
+    /// Construct a new length encoder.
+    #[inline]
+    pub fn new(cx: &'a C) -> Self {
+        Self { cx }
+    }
+}
+
+#[crate::encoder(crate)]
+impl<C> Encoder for FlattenLen<'_, C>
+where
+    C: ?Sized + Context,
+{
+    type Cx = C;
+    type Error = C::Error;
+    type Ok = usize;
+    type Mode = C::Mode;
+
+    #[inline]
+    fn cx(&self) -> &Self::Cx {
+        self.cx
+    }
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a value which can be flattened into a map")
+    }
+
+    #[inline]
+    fn encode<T>(self, value: T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Encode<Self::Mode>,
+    {
+        value.encode(self.cx, self)
+    }
+
+    #[inline]
+    fn encode_map_fn<F>(self, hint: &MapHint, _: F) -> Result<Self::Ok, C::Error>
+    where
+        F: FnOnce(&mut Self::EncodeMap) -> Result<(), C::Error>,
+    {
+        Ok(hint.size)
+    }
+
+    #[inline]
+    fn encode_map_entries_fn<F>(self, hint: &MapHint, _: F) -> Result<Self::Ok, C::Error>
+    where
+        F: FnOnce(&mut Self::EncodeMapEntries) -> Result<(), C::Error>,
+    {
+        Ok(hint.size)
+    }
+}
+
+/// Encoder which writes the entries of a flattened value into the map of the
+/// containing struct.
+///
+/// The map is owned by the encoder, and once the flattened value is done
+/// encoding it is handed over to `finish`, which either encodes the next
+/// flattened field or finishes the map.
+pub struct FlattenEncoder<'a, M, F>
+where
+    M: MapEncoder,
+{
+    cx: &'a M::Cx,
+    map: M,
+    finish: F,
+}
+
+impl<'a, M, F> FlattenEncoder<'a, M, F>
+where
+    M: MapEncoder,
+    F: FnOnce(M) -> Result<M::Ok, <M::Cx as Context>::Error>,
+{
+    /// Construct a new flattening encoder around the given map.
+    #[inline]
+    pub fn new(cx: &'a M::Cx, map: M, finish: F) -> Self {
+        Self { cx, map, finish }
+    }
+}
+
+#[crate::encoder(crate)]
+impl<M, F> Encoder for FlattenEncoder<'_, M, F>
+where
+    M: MapEncoder,
+    F: FnOnce(M) -> Result<M::Ok, <M::Cx as Context>::Error>,
+{
+    type Cx = M::Cx;
+    type Error = <M::Cx as Context>::Error;
+    type Ok = M::Ok;
+    type Mode = <M::Cx as Context>::Mode;
+    type EncodeMap = FlattenMap<M, F>;
+
+    #[inline]
+    fn cx(&self) -> &Self::Cx {
+        self.cx
+    }
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a value which can be flattened into a map")
+    }
+
+    #[inline]
+    fn encode<T>(self, value: T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Encode<Self::Mode>,
+    {
+        value.encode(self.cx, self)
+    }
+
+    #[inline]
+    fn encode_map(self, _: &MapHint) -> Result<Self::EncodeMap, Self::Error> {
+        Ok(FlattenMap {
+            map: self.map,
+            finish: self.finish,
+        })
+    }
+}
+
+/// Map encoder which forwards entries to the map of the containing struct.
+pub struct FlattenMap<M, F> {
+    map: M,
+    finish: F,
+}
+
+impl<M, F> MapEncoder for FlattenMap<M, F>
+where
+    M: MapEncoder,
+    F: FnOnce(M) -> Result<M::Ok, <M::Cx as Context>::Error>,
+{
+    type Cx = M::Cx;
+    type Ok = M::Ok;
+    type EncodeEntry<'this> = M::EncodeEntry<'this> where Self: 'this;
+
+    #[inline]
+    fn encode_entry(&mut self) -> Result<Self::EncodeEntry<'_>, <Self::Cx as Context>::Error> {
+        self.map.encode_entry()
+    }
+
+    #[inline]
+    fn finish_map(self) -> Result<Self::Ok, <Self::Cx as Context>::Error> {
+        (self.finish)(self.map)
+    }
+}
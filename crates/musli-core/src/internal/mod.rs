@@ -1,3 +1,6 @@
 pub(crate) mod fixed;
+pub(crate) mod flatten;
+pub(crate) mod remote;
 pub(crate) mod size_hint;
+pub(crate) mod untagged;
 pub(crate) use self::fixed::FixedVec;
@@ -0,0 +1,25 @@
+//! Helpers used by `#[musli(remote = <path>)]` containers.
+//!
+//! A remote derive generates implementations of these traits for the remote
+//! type, parameterized over the local mirror type `R` so that they are not
+//! rejected by the orphan rules. The mirror type then gets inherent `encode`
+//! and `decode` functions which can be used with `#[musli(with = ..)]`.
+
+use crate::de::Decoder;
+use crate::en::Encoder;
+
+/// Encode a remote type through the mirror type `R`.
+pub trait EncodeRemote<M, R> {
+    /// Encode the given output.
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>;
+}
+
+/// Decode a remote type through the mirror type `R`.
+pub trait DecodeRemote<'de, M, R>: Sized {
+    /// Decode the given input.
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>;
+}
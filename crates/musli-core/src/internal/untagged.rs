@@ -0,0 +1,106 @@
+//! Helpers used by `#[musli(untagged)]` enums.
+//!
+//! An untagged enum is decoded by buffering the value and then trying each
+//! variant in order until one of them decodes successfully. Errors raised
+//! while trying a variant are not interesting to the caller, so they are
+//! discarded by decoding through a [`TryContext`].
+//!
+//! The exception are errors which would be raised by any variant, such as the
+//! value being nested too deeply or an allocation failing. These are kept and
+//! returned instead of trying the next variant.
+
+use core::cell::Cell;
+use core::fmt;
+
+use crate::no_std;
+use crate::Context;
+
+/// A context used while trying to decode a single variant of an untagged enum.
+///
+/// Errors are reduced to `()` and never reach the wrapped context, except for
+/// fatal errors which are stored so that they can be retrieved through
+/// [`TryContext::take_fatal`].
+pub struct TryContext<'a, C: ?Sized + Context> {
+    cx: &'a C,
+    fatal: Cell<Option<C::Error>>,
+}
+
+impl<'a, C: ?Sized + Context> TryContext<'a, C> {
+    /// Construct a new context wrapping `cx`.
+    #[inline]
+    pub fn new(cx: &'a C) -> Self {
+        Self {
+            cx,
+            fatal: Cell::new(None),
+        }
+    }
+
+    /// Take the fatal error raised while trying a variant, if any.
+    #[inline]
+    pub fn take_fatal(&self) -> Option<C::Error> {
+        self.fatal.take()
+    }
+
+    #[inline]
+    fn fatal(&self, error: C::Error) {
+        self.fatal.set(Some(error));
+    }
+}
+
+impl<C> Context for TryContext<'_, C>
+where
+    C: ?Sized + Context,
+{
+    type Mode = C::Mode;
+    type Error = ();
+    type Mark = C::Mark;
+    type Allocator = C::Allocator;
+    type String<'this> = C::String<'this> where Self: 'this;
+
+    #[inline]
+    fn clear(&self) {}
+
+    #[inline]
+    fn alloc(&self) -> &Self::Allocator {
+        self.cx.alloc()
+    }
+
+    #[inline]
+    fn collect_string<T>(&self, value: &T) -> Result<Self::String<'_>, Self::Error>
+    where
+        T: ?Sized + fmt::Display,
+    {
+        self.cx
+            .collect_string(value)
+            .map_err(|error| self.fatal(error))
+    }
+
+    #[inline]
+    fn custom<T>(&self, _: T) -> Self::Error
+    where
+        T: 'static + Send + Sync + no_std::Error,
+    {
+    }
+
+    #[inline]
+    fn message<T>(&self, _: T) -> Self::Error
+    where
+        T: fmt::Display,
+    {
+    }
+
+    #[inline]
+    fn alloc_failed(&self) -> Self::Error {
+        self.fatal(self.cx.alloc_failed());
+    }
+
+    #[inline]
+    fn enter_depth(&self) -> Result<(), Self::Error> {
+        self.cx.enter_depth().map_err(|error| self.fatal(error))
+    }
+
+    #[inline]
+    fn leave_depth(&self) {
+        self.cx.leave_depth();
+    }
+}
@@ -33,6 +33,7 @@ pub use self::en::{Encode, Encoder};
 pub mod hint;
 pub mod mode;
 pub mod no_std;
+pub mod reflect;
 
 mod expecting;
 mod impls;
@@ -185,12 +186,18 @@ pub use musli_macros::visitor;
 #[doc(hidden)]
 pub mod __priv {
     use crate::context::Context;
-    use crate::de::{Decoder, EntryDecoder};
+    use crate::de::{Decode, Decoder, EntryDecoder};
 
+    pub use ::core::clone::Clone;
+    pub use ::core::convert::{From, Into};
     pub use ::core::fmt;
     pub use ::core::option::Option;
     pub use ::core::result::Result;
+    pub use ::core::stringify;
 
+    pub use crate::internal::flatten::{FlattenEncoder, FlattenLen};
+    pub use crate::internal::remote::{DecodeRemote, EncodeRemote};
+    pub use crate::internal::untagged::TryContext;
     pub use crate::never::Never;
 
     #[inline(always)]
@@ -219,6 +226,36 @@ pub mod __priv {
         skip(decoder.decode_value()?)
     }
 
+    /// Construct the key of an unknown field which is collected into
+    /// `collection`.
+    ///
+    /// The collection is only used to infer the type of the key.
+    #[inline(always)]
+    pub fn unknown_field_key<C, K, V, T>(_: &C, key: T) -> K
+    where
+        C: IntoIterator<Item = (K, V)>,
+        K: From<T>,
+    {
+        K::from(key)
+    }
+
+    /// Decode the value of an unknown field and insert it into `collection`.
+    #[inline(always)]
+    pub fn decode_unknown_field<'de, C, K, V, D>(
+        collection: &mut C,
+        key: K,
+        decoder: D,
+    ) -> Result<(), <D::Cx as Context>::Error>
+    where
+        C: IntoIterator<Item = (K, V)> + Extend<(K, V)>,
+        V: Decode<'de, <D::Cx as Context>::Mode>,
+        D: EntryDecoder<'de>,
+    {
+        let value = decoder.decode_value()?.decode::<V>()?;
+        collection.extend(Option::Some((key, value)));
+        Ok(())
+    }
+
     pub use Option::{None, Some};
     pub use Result::{Err, Ok};
 }
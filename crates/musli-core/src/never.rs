@@ -101,12 +101,12 @@ impl<'de, C: ?Sized + Context> Decoder<'de> for Never<(), C> {
     where
         U: 'this + Context;
     type DecodeBuffer = Self;
-    type DecodePack = Self;
-    type DecodeSequence = Self;
-    type DecodeMapEntries = Self;
+    type DecodePack<'this> = Self;
+    type DecodeSequence<'this> = Self;
+    type DecodeMapEntries<'this> = Self;
     type DecodeSome = Self;
-    type DecodeMap = Self;
-    type DecodeVariant = Self;
+    type DecodeMap<'this> = Self;
+    type DecodeVariant<'this> = Self;
     type __UseMusliDecoderAttributeMacro = ();
 
     #[inline]
@@ -162,6 +162,14 @@ impl<C: ?Sized + Context> AsDecoder for Never<(), C> {
     fn as_decoder(&self) -> Result<Self::Decoder<'_>, C::Error> {
         match self._never {}
     }
+
+    #[inline]
+    fn retain_entries<'this, F>(&'this self, _: F) -> Result<Self, C::Error>
+    where
+        F: FnMut(Self::Decoder<'this>) -> Result<bool, C::Error>,
+    {
+        match self._never {}
+    }
 }
 
 impl<'de, C: ?Sized + Context> EntriesDecoder<'de> for Never<(), C> {
@@ -255,7 +263,7 @@ impl<'de, C: ?Sized + Context> SequenceDecoder<'de> for Never<(), C> {
     }
 }
 
-impl<C: ?Sized + Context, O: 'static> Encoder for Never<O, C> {
+impl<C: ?Sized + Context, O> Encoder for Never<O, C> {
     type Cx = C;
     type Error = C::Error;
     type Ok = O;
@@ -310,7 +318,7 @@ where
     }
 }
 
-impl<O: 'static, C: ?Sized + Context> SequenceEncoder for Never<O, C> {
+impl<O, C: ?Sized + Context> SequenceEncoder for Never<O, C> {
     type Cx = C;
     type Ok = O;
     type EncodeNext<'this> = Self where Self: 'this;
@@ -326,7 +334,7 @@ impl<O: 'static, C: ?Sized + Context> SequenceEncoder for Never<O, C> {
     }
 }
 
-impl<O: 'static, C: ?Sized + Context> MapEncoder for Never<O, C> {
+impl<O, C: ?Sized + Context> MapEncoder for Never<O, C> {
     type Cx = C;
     type Ok = O;
     type EncodeEntry<'this> = Self where Self: 'this;
@@ -341,7 +349,7 @@ impl<O: 'static, C: ?Sized + Context> MapEncoder for Never<O, C> {
     }
 }
 
-impl<O: 'static, C: ?Sized + Context> EntryEncoder for Never<O, C> {
+impl<O, C: ?Sized + Context> EntryEncoder for Never<O, C> {
     type Cx = C;
     type Ok = O;
     type EncodeKey<'this> = Self where Self: 'this;
@@ -363,7 +371,7 @@ impl<O: 'static, C: ?Sized + Context> EntryEncoder for Never<O, C> {
     }
 }
 
-impl<O: 'static, C: ?Sized + Context> EntriesEncoder for Never<O, C> {
+impl<O, C: ?Sized + Context> EntriesEncoder for Never<O, C> {
     type Cx = C;
     type Ok = O;
     type EncodeEntryKey<'this> = Self where Self: 'this;
@@ -385,7 +393,7 @@ impl<O: 'static, C: ?Sized + Context> EntriesEncoder for Never<O, C> {
     }
 }
 
-impl<O: 'static, C: ?Sized + Context> VariantEncoder for Never<O, C> {
+impl<O, C: ?Sized + Context> VariantEncoder for Never<O, C> {
     type Cx = C;
     type Ok = O;
     type EncodeTag<'this> = Self where Self: 'this;
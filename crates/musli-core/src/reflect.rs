@@ -0,0 +1,127 @@
+//! Reflection metadata for derived types.
+//!
+//! Types which derive [`Encode`] with `#[musli(reflect)]` implement
+//! [`Reflect`], which provides a constant table describing their fields and
+//! variants. This can be used by schema exporters and debugging tools to
+//! inspect a type at runtime.
+//!
+//! ```
+//! use musli::Encode;
+//! use musli::reflect::{MetadataKind, Reflect};
+//!
+//! #[derive(Encode)]
+//! #[musli(reflect)]
+//! struct Person {
+//!     name: String,
+//!     age: Option<u32>,
+//! }
+//!
+//! let metadata = Person::METADATA;
+//! assert_eq!(metadata.name, "Person");
+//!
+//! let MetadataKind::Struct { fields } = metadata.kind else {
+//!     panic!("expected a struct");
+//! };
+//!
+//! assert_eq!(fields[1].name, "age");
+//! assert_eq!(fields[1].index, 1);
+//! assert_eq!(fields[1].ty, "Option<u32>");
+//! ```
+//!
+//! [`Encode`]: crate::Encode
+
+/// Trait implemented by types which provide reflection [`Metadata`].
+///
+/// This is implemented by deriving [`Encode`] with `#[musli(reflect)]`.
+///
+/// [`Encode`]: crate::Encode
+pub trait Reflect {
+    /// The metadata of the type.
+    const METADATA: &'static Metadata;
+}
+
+/// The reflection metadata of a type.
+#[non_exhaustive]
+pub struct Metadata {
+    /// The name of the type.
+    pub name: &'static str,
+    /// The kind of the type.
+    pub kind: MetadataKind,
+}
+
+impl Metadata {
+    /// Construct metadata for a struct with the given fields.
+    #[inline]
+    pub const fn new_struct(name: &'static str, fields: &'static [FieldMetadata]) -> Self {
+        Self {
+            name,
+            kind: MetadataKind::Struct { fields },
+        }
+    }
+
+    /// Construct metadata for an enum with the given variants.
+    #[inline]
+    pub const fn new_enum(name: &'static str, variants: &'static [VariantMetadata]) -> Self {
+        Self {
+            name,
+            kind: MetadataKind::Enum { variants },
+        }
+    }
+}
+
+/// The kind of a type described by [`Metadata`].
+#[non_exhaustive]
+pub enum MetadataKind {
+    /// The type is a struct.
+    Struct {
+        /// The fields of the struct.
+        fields: &'static [FieldMetadata],
+    },
+    /// The type is an enum.
+    Enum {
+        /// The variants of the enum.
+        variants: &'static [VariantMetadata],
+    },
+}
+
+/// The reflection metadata of a field.
+#[non_exhaustive]
+pub struct FieldMetadata {
+    /// The name of the field, or its index if it's a tuple field.
+    pub name: &'static str,
+    /// The index of the field in its declaration.
+    pub index: usize,
+    /// The type of the field as it's written in the declaration.
+    pub ty: &'static str,
+}
+
+impl FieldMetadata {
+    /// Construct metadata for a field.
+    #[inline]
+    pub const fn new(name: &'static str, index: usize, ty: &'static str) -> Self {
+        Self { name, index, ty }
+    }
+}
+
+/// The reflection metadata of an enum variant.
+#[non_exhaustive]
+pub struct VariantMetadata {
+    /// The name of the variant.
+    pub name: &'static str,
+    /// The index of the variant in its declaration.
+    pub index: usize,
+    /// The fields of the variant.
+    pub fields: &'static [FieldMetadata],
+}
+
+impl VariantMetadata {
+    /// Construct metadata for a variant.
+    #[inline]
+    pub const fn new(name: &'static str, index: usize, fields: &'static [FieldMetadata]) -> Self {
+        Self {
+            name,
+            index,
+            fields,
+        }
+    }
+}
@@ -4,10 +4,10 @@ use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::Token;
 
-use crate::expander::{NameMethod, StructKind};
+use crate::expander::{Expander, NameMethod, StructKind};
 use crate::internals::apply;
 use crate::internals::attr::{EnumTagging, Packing};
-use crate::internals::build::{Body, Build, BuildData, Enum, Field, Variant};
+use crate::internals::build::{Body, Build, BuildData, Enum, Field, Variant, Version};
 use crate::internals::tokens::Tokens;
 use crate::internals::Result;
 
@@ -36,22 +36,63 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
         trace_body: true,
     };
 
-    let body = match &e.data {
-        BuildData::Struct(st) => decode_struct(&cx, &e, st)?,
-        BuildData::Enum(en) => decode_enum(&cx, &e, en)?,
+    let place_var = e.cx.ident("place");
+
+    // Decoding into an existing value is only supported for plain structs,
+    // everything else falls back to replacing the value.
+    let decode_into = match (&e.data, e.from_type, e.remote) {
+        (BuildData::Struct(st), None, None) if e.version.is_none() && e.validate.is_none() => {
+            decode_struct_into(&cx, &e, st, &place_var)?
+        }
+        _ => None,
+    };
+
+    // Structs which are decoded from a map consume all of it before returning
+    // an error which they've marked as recoverable.
+    let recoverable = match (&e.data, e.from_type, e.remote) {
+        (BuildData::Struct(st), None, None)
+            if e.version.is_none()
+                && matches!(st.packing, Packing::Tagged)
+                && !matches!(st.kind, StructKind::Empty) =>
+        {
+            Some(quote! {
+                const RECOVERABLE: bool = true;
+            })
+        }
+        _ => None,
     };
 
+    let mut body = match (&e.data, e.from_type) {
+        (_, Some(ty)) => decode_from(&cx, &e, ty),
+        (BuildData::Struct(st), None) => decode_struct(&cx, &e, st)?,
+        (BuildData::Enum(en), None) => decode_enum(&cx, &e, en)?,
+    };
+
+    if let Some(version) = &e.version {
+        body = decode_versioned(&cx, &e, version, body);
+    }
+
+    if let Some(validate) = e.validate {
+        body = decode_validated(&cx, &e, validate, &d_param, body);
+    }
+
     if e.cx.has_errors() {
         return Err(());
     }
 
-    // Figure out which lifetime to use for what. We use the first lifetime in
-    // the type (if any is available) as the decoder lifetime. Else we generate
-    // a new anonymous lifetime `'de` to use for the `Decode` impl.
+    // Figure out which lifetime to use for what. We use the lifetime borrowed
+    // by `#[musli(borrow)]` fields or the first lifetime in the type (if any is
+    // available) as the decoder lifetime. Else we generate a new anonymous
+    // lifetime `'de` to use for the `Decode` impl.
     let mut generics = e.input.generics.clone();
     let type_ident = &e.input.ident;
 
-    let (lt, exists) = if let Some(existing) = generics.lifetimes().next() {
+    let borrowed = e
+        .borrow
+        .as_ref()
+        .and_then(|lt| generics.lifetimes().find(|p| p.lifetime == *lt));
+
+    let (lt, exists) = if let Some(existing) = borrowed.or(generics.lifetimes().next()) {
         (existing.clone(), true)
     } else {
         let lt = syn::LifetimeParam::new(syn::Lifetime::new("'de", e.input.span()));
@@ -65,12 +106,13 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
     let Tokens {
         context_t,
         result,
+        decode_remote_t,
         decode_t,
         decoder_t,
         ..
     } = e.tokens;
 
-    if !e.bounds.is_empty() && !e.decode_bounds.is_empty() {
+    if !e.bounds.is_empty() || !e.decode_bounds.is_empty() {
         generics.make_where_clause().predicates.extend(
             e.bounds
                 .iter()
@@ -90,11 +132,32 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
 
     let mode_ident = e.expansion.mode_path(e.tokens).as_path();
 
+    let decode_into = decode_into.map(|body| {
+        quote! {
+            #[inline]
+            fn decode_into<#d_param>(#ctx_var: &#d_param::Cx, #root_decoder_var: #d_param, #place_var: &mut Self) -> #result<(), <#d_param::Cx as #context_t>::Error>
+            where
+                #d_param: #decoder_t<#lt, Mode = #mode_ident>,
+            {
+                #result_ok(#body)
+            }
+        }
+    });
+
+    let impl_for = match e.remote {
+        Some(remote) => {
+            quote!(#decode_remote_t<#lt, #mode_ident, #type_ident #type_generics> for #remote #type_generics)
+        }
+        None => quote!(#decode_t<#lt, #mode_ident> for #type_ident #type_generics),
+    };
+
     Ok(quote! {
         const _: () = {
             #[automatically_derived]
             #(#attributes)*
-            impl #impl_generics #decode_t<#lt, #mode_ident> for #type_ident #type_generics #where_clause {
+            impl #impl_generics #impl_for #where_clause {
+                #recoverable
+
                 #[inline]
                 fn decode<#d_param>(#ctx_var: &#d_param::Cx, #root_decoder_var: #d_param) -> #result<Self, <#d_param::Cx as #context_t>::Error>
                 where
@@ -102,11 +165,170 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
                 {
                     #body
                 }
+
+                #decode_into
             }
         };
     })
 }
 
+/// Expand the inherent `decode` function of a `#[musli(remote = ..)]` mirror
+/// type, which is what `#[musli(with = ..)]` calls.
+pub(crate) fn expand_remote(e: &Expander<'_>, remote: &syn::Path) -> TokenStream {
+    let Tokens {
+        context_t,
+        decode_remote_t,
+        decoder_t,
+        result,
+        ..
+    } = &e.tokens;
+
+    let type_ident = &e.input.ident;
+    let vis = &e.input.vis;
+    let d_param = e.cx.type_with_span("D", Span::call_site());
+
+    // The lifetime is constrained through the `DecodeRemote` bound, so a fresh
+    // hygienic one is used to not conflict with the ones of the type.
+    let lt = syn::Lifetime::new("'de", Span::mixed_site());
+
+    let value_var = e.cx.ident("value");
+    let rebuild = e.expand_remote_rebuild(&value_var);
+
+    let (impl_generics, type_generics, where_clause) = e.input.generics.split_for_impl();
+
+    quote! {
+        const _: () = {
+            #[automatically_derived]
+            impl #impl_generics #type_ident #type_generics #where_clause {
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn decode<#lt, #d_param>(cx: &#d_param::Cx, decoder: #d_param) -> #result<#remote #type_generics, <#d_param::Cx as #context_t>::Error>
+                where
+                    #d_param: #decoder_t<#lt>,
+                    #remote #type_generics: #decode_remote_t<#lt, <#d_param as #decoder_t<#lt>>::Mode, Self>,
+                {
+                    let _ = |#value_var: Self| -> Self { #rebuild };
+                    #decode_remote_t::<#lt, <#d_param as #decoder_t<#lt>>::Mode, Self>::decode(cx, decoder)
+                }
+            }
+        };
+    }
+}
+
+/// Decode a versioned container, upgrading from older versions if necessary.
+fn decode_versioned(
+    cx: &Ctxt<'_>,
+    b: &Build<'_>,
+    version: &Version<'_>,
+    body: TokenStream,
+) -> TokenStream {
+    let Ctxt {
+        ctx_var,
+        decoder_var,
+        ..
+    } = *cx;
+
+    let Tokens {
+        context_t,
+        decoder_t,
+        result_err,
+        result_ok,
+        variant_decoder_t,
+        ..
+    } = b.tokens;
+
+    let decode_t_decode = &b.decode_t_decode;
+
+    let variant_decoder_var = b.cx.ident("variant_decoder");
+    let version_var = b.cx.ident("version");
+    let type_name = b.data.name();
+    let current = version.version;
+
+    let upgrades = version.upgrades.iter().map(|(from, path)| {
+        quote! {
+            #from => {
+                let #decoder_var = #variant_decoder_t::decode_value(#variant_decoder_var)?;
+                #result_ok(#path(#decode_t_decode(#ctx_var, #decoder_var)?))
+            }
+        }
+    });
+
+    quote! {
+        #decoder_t::decode_variant(#decoder_var, move |#variant_decoder_var| {
+            let #version_var: u32 = {
+                let #decoder_var = #variant_decoder_t::decode_tag(#variant_decoder_var)?;
+                #decode_t_decode(#ctx_var, #decoder_var)?
+            };
+
+            match #version_var {
+                #current => {
+                    let #decoder_var = #variant_decoder_t::decode_value(#variant_decoder_var)?;
+                    #body
+                }
+                #(#upgrades,)*
+                #version_var => #result_err(#context_t::unsupported_version(#ctx_var, #type_name, &#version_var)),
+            }
+        })
+    }
+}
+
+/// Decode a proxy type and convert it into the value.
+fn decode_from(cx: &Ctxt<'_>, b: &Build<'_>, ty: &syn::Type) -> TokenStream {
+    let Ctxt {
+        ctx_var,
+        decoder_var,
+        ..
+    } = *cx;
+
+    let Tokens {
+        from_t, result_ok, ..
+    } = b.tokens;
+
+    let decode_t_decode = &b.decode_t_decode;
+    let proxy_var = b.cx.ident("proxy");
+
+    quote! {
+        let #proxy_var: #ty = #decode_t_decode(#ctx_var, #decoder_var)?;
+        #result_ok(#from_t::from(#proxy_var))
+    }
+}
+
+/// Run the validation function over the decoded value.
+fn decode_validated(
+    cx: &Ctxt<'_>,
+    b: &Build<'_>,
+    validate: &syn::Path,
+    d_param: &syn::Ident,
+    body: TokenStream,
+) -> TokenStream {
+    let Ctxt { ctx_var, .. } = *cx;
+
+    let Tokens {
+        context_t,
+        result,
+        result_err,
+        result_ok,
+        ..
+    } = b.tokens;
+
+    let output_var = b.cx.ident("output");
+    let error_var = b.cx.ident("error");
+
+    // The body is wrapped in a closure since it might return early.
+    quote! {
+        #[allow(clippy::redundant_closure_call)]
+        let #output_var = (move || -> #result<Self, <#d_param::Cx as #context_t>::Error> {
+            #body
+        })()?;
+
+        if let #result_err(#error_var) = #validate(&#output_var) {
+            return #result_err(#context_t::message(#ctx_var, #error_var));
+        }
+
+        #result_ok(#output_var)
+    }
+}
+
 fn decode_struct(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream> {
     let Tokens { result_ok, .. } = b.tokens;
 
@@ -114,12 +336,34 @@ fn decode_struct(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStr
         (_, Packing::Transparent) => decode_transparent(cx, b, st)?,
         (_, Packing::Packed) => decode_packed(cx, b, st)?,
         (StructKind::Empty, _) => decode_empty(cx, b, st)?,
-        (_, Packing::Tagged) => decode_tagged(cx, b, st, None)?,
+        (_, Packing::Tagged) => decode_tagged(cx, b, st, None, None)?,
     };
 
     Ok(quote!(#result_ok({ #body })))
 }
 
+/// Decode a struct into an existing value, if it's supported.
+fn decode_struct_into(
+    cx: &Ctxt<'_>,
+    b: &Build<'_>,
+    st: &Body<'_>,
+    place_var: &Ident,
+) -> Result<Option<TokenStream>> {
+    if !matches!(st.packing, Packing::Tagged) || matches!(st.kind, StructKind::Empty) {
+        return Ok(None);
+    }
+
+    if st
+        .all_fields
+        .iter()
+        .any(|f| f.flatten.is_some() || f.unknown_fields.is_some())
+    {
+        return Ok(None);
+    }
+
+    Ok(Some(decode_tagged(cx, b, st, None, Some(place_var))?))
+}
+
 fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
     let Ctxt {
         ctx_var,
@@ -143,15 +387,13 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
         map_decoder_t,
         struct_field_decoder_t,
         map_hint,
+        result,
+        try_context,
         variant_decoder_t,
+        pack_decoder_t,
         ..
     } = b.tokens;
 
-    if let Some(&(span, Packing::Packed)) = en.packing_span {
-        b.decode_packed_enum_diagnostics(span);
-        return Err(());
-    }
-
     let type_name = en.name;
 
     // Trying to decode an uninhabitable type.
@@ -171,6 +413,7 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
     let buf_type = b.cx.type_with_span("B", Span::call_site());
     let outcome_var = b.cx.ident("outcome");
     let output_var = b.cx.ident("output");
+    let buffer_var = b.cx.ident("buffer");
     let struct_decoder_var = b.cx.ident("struct_decoder");
     let struct_hint_static = b.cx.ident("STRUCT_HINT");
     let struct_var = b.cx.ident("st");
@@ -179,6 +422,8 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
     let variant_tag_var = b.cx.ident("variant_tag");
     let tag_static = b.cx.ident("TAG");
     let content_static = b.cx.ident("CONTENT");
+    let try_cx_var = b.cx.ident("try_cx");
+    let error_var = b.cx.ident("error");
 
     let mut output_arms = Vec::new();
 
@@ -204,7 +449,13 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
     match en.name_method {
         NameMethod::Value => {
             for v in &en.variants {
-                let arm = output_arm(v.pattern, &v.name, &binding_var);
+                let arm = output_arm(
+                    v.pattern,
+                    &v.name,
+                    &v.aliases,
+                    en.case_insensitive,
+                    &binding_var,
+                );
                 output_arms.push((v, arm, &v.name));
             }
 
@@ -220,8 +471,16 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
             let output_type = b.cx.type_with_span("VariantTag", en.span);
 
             for v in &en.variants {
-                let (pat, variant) =
-                    unsized_arm(b, v.span, v.index, &v.name, v.pattern, &output_type);
+                let (pat, variant) = unsized_arm(
+                    b,
+                    v.span,
+                    v.index,
+                    &v.name,
+                    v.pattern,
+                    &v.aliases,
+                    en.case_insensitive,
+                    &output_type,
+                );
 
                 output_arms.push((v, OutputArm { pat, cond: None }, &v.name));
                 variants.push(variant);
@@ -284,7 +543,13 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
 
             for v in &en.variants {
                 let path = &v.st.path;
-                let pat = output_arm(v.pattern, &v.name, &binding_var);
+                let pat = output_arm(
+                    v.pattern,
+                    &v.name,
+                    &v.aliases,
+                    en.case_insensitive,
+                    &binding_var,
+                );
                 arms.push(quote!(#pat => #result_ok(#path {})));
             }
 
@@ -320,6 +585,83 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                 }
             }
         }
+        EnumTagging::Default if en.enum_packing == Packing::Packed => {
+            let pack_var = b.cx.ident("pack");
+
+            let arms = output_arms.iter().map(|(v, pat, tag_value)| {
+                let name = &v.st.name;
+                let decode = decode_packed_fields(cx, b, &v.st, &pack_var);
+
+                let enter = cx.trace.then(|| {
+                    let (tag_decl, formatted_tag) = en.name_format(&tag_static, tag_value);
+
+                    quote! {
+                        #tag_decl
+                        #context_t::enter_variant(#ctx_var, #name, #formatted_tag);
+                    }
+                });
+
+                let leave = cx.trace.then(|| {
+                    quote! {
+                        #context_t::leave_variant(#ctx_var);
+                    }
+                });
+
+                quote! {
+                    #pat => {
+                        #enter
+                        let #output_var = #decode;
+                        #leave
+                        #output_var
+                    }
+                }
+            });
+
+            // Fallback variants are not supported since the fields of an
+            // unknown variant can't be skipped.
+            let invalid = quote! {
+                return #result_err(#context_t::invalid_variant_tag(#ctx_var, #type_name, &#variant_tag_var))
+            };
+
+            let fallback = match en.name_method {
+                NameMethod::Value => quote!(_ => #invalid),
+                NameMethod::Unsized(..) => quote!(#option_none => { #invalid }),
+            };
+
+            let enter = cx.trace.then(|| {
+                quote! {
+                    #context_t::enter_enum(#ctx_var, #type_name);
+                }
+            });
+
+            let leave = cx.trace.then(|| {
+                quote! {
+                    #context_t::leave_enum(#ctx_var);
+                }
+            });
+
+            Ok(quote! {{
+                #output_enum
+                #enter
+
+                let #output_var = #decoder_t::decode_pack(#decoder_var, move |#pack_var| {
+                    let #variant_tag_var: #name_type = {
+                        let mut #variant_decoder_var = #pack_decoder_t::decode_next(#pack_var)?;
+                        #decode_name?
+                    };
+
+                    let #output_var = match #variant_tag_var {
+                        #(#arms,)*
+                        #fallback
+                    };
+
+                    #result_ok(#output_var)
+                })?;
+
+                #leave
+                Ok(#output_var)
+            }})
+        }
         EnumTagging::Default => {
             let arms = output_arms.iter().flat_map(|(v, pat, tag_value)| {
                 let name = &v.st.name;
@@ -441,6 +783,8 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                                 break #struct_field_decoder_t::decode_value(#entry_var)?;
                             }
                             #field_var => {
+                                #context_t::skipped_field(#ctx_var, #type_name, &#field_var);
+
                                 if #skip_field(#entry_var)? {
                                     return #result_err(#context_t::invalid_field_tag(#ctx_var, #type_name, &#field_var));
                                 }
@@ -477,6 +821,8 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                                 break #struct_field_decoder_t::decode_value(#entry_var)?;
                             }
                             #outcome_type::Skip(#field_name) => {
+                                #context_t::skipped_field(#ctx_var, #type_name, #field_name.as_ref());
+
                                 if #skip_field(#entry_var)? {
                                     return #result_err(#context_t::invalid_field_string_tag(#ctx_var, #type_name, #field_name));
                                 }
@@ -599,6 +945,8 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                                 });
                             }
                             #field_var => {
+                                #context_t::skipped_field(#ctx_var, #type_name, &#field_var);
+
                                 if #skip_field(#entry_var)? {
                                     return #result_err(#context_t::invalid_field_tag(#ctx_var, #type_name, &#field_var));
                                 }
@@ -635,7 +983,7 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                             }
                             #outcome_type::Content => {
                                 let #option_some(#variant_tag_var) = #name_var else {
-                                    return #result_err(#context_t::invalid_field_tag(#ctx_var, #type_name, &#tag));
+                                    return #result_err(#context_t::missing_adjacent_tag(#ctx_var, #type_name, &#content));
                                 };
 
                                 let #body_decoder_var = #struct_field_decoder_t::decode_value(#entry_var)?;
@@ -646,6 +994,8 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                                 });
                             }
                             #outcome_type::Skip(#field_name) => {
+                                #context_t::skipped_field(#ctx_var, #type_name, #field_name.as_ref());
+
                                 if #skip_field(#entry_var)? {
                                     return #result_err(#context_t::invalid_field_string_tag(#ctx_var, #type_name, #field_name));
                                 }
@@ -698,6 +1048,87 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                 })?
             }})
         }
+        EnumTagging::Untagged => {
+            // Each variant is tried against the buffered value with a context
+            // that swallows errors, so that a failed attempt doesn't leave an
+            // error behind in the real context.
+            let try_cx = Ctxt {
+                ctx_var: &try_cx_var,
+                decoder_var: &buffer_decoder_var,
+                trace: false,
+                trace_body: false,
+                ..*cx
+            };
+
+            let enter = cx.trace.then(|| {
+                quote! {
+                    #context_t::enter_enum(#ctx_var, #type_name);
+                }
+            });
+
+            let leave = cx.trace.then(|| {
+                quote! {
+                    #context_t::leave_enum(#ctx_var);
+                }
+            });
+
+            let mut attempts = Vec::with_capacity(en.variants.len());
+
+            for v in &en.variants {
+                let path = &v.st.path;
+
+                let decode = match (v.st.kind, v.st.packing) {
+                    (_, Packing::Transparent) => decode_transparent(&try_cx, b, &v.st)?,
+                    (_, Packing::Packed) => decode_packed(&try_cx, b, &v.st)?,
+                    (StructKind::Empty, _) => quote! {{
+                        #decoder_t::decode_empty(#buffer_decoder_var)?;
+                        #path {}
+                    }},
+                    (_, Packing::Tagged) => decode_tagged(&try_cx, b, &v.st, None, None)?,
+                };
+
+                attempts.push(quote! {
+                    let #buffer_decoder_var = #as_decoder_t::as_decoder(&#buffer_var)?;
+                    let #buffer_decoder_var = #decoder_t::with_context(#buffer_decoder_var, #try_cx_var)?;
+
+                    #[allow(clippy::redundant_closure_call)]
+                    let #outcome_var = (move || -> #result<Self, ()> {
+                        #result_ok(#decode)
+                    })();
+
+                    if let #result_ok(#output_var) = #outcome_var {
+                        #leave
+                        return #result_ok(#output_var);
+                    }
+
+                    if let #option_some(#error_var) = #try_context::take_fatal(#try_cx_var) {
+                        return #result_err(#error_var);
+                    }
+                });
+            }
+
+            // A fallback variant absorbs any value which no other variant
+            // matched.
+            let no_match = match en.fallback {
+                Some(ident) => quote! {
+                    #leave
+                    #result_ok(Self::#ident {})
+                },
+                None => quote! {
+                    #result_err(#context_t::no_matching_variant(#ctx_var, #type_name))
+                },
+            };
+
+            Ok(quote! {{
+                #enter
+                let #buffer_var = #decoder_t::decode_buffer(#decoder_var)?;
+                let #try_cx_var = &#try_context::new(#ctx_var);
+
+                #(#attempts)*
+
+                #no_match
+            }})
+        }
     }
 }
 
@@ -718,7 +1149,7 @@ fn decode_variant(
         (_, Packing::Transparent) => decode_transparent(&cx, b, &v.st)?,
         (_, Packing::Packed) => decode_packed(&cx, b, &v.st)?,
         (StructKind::Empty, _) => decode_empty(&cx, b, &v.st)?,
-        (_, Packing::Tagged) => decode_tagged(&cx, b, &v.st, Some(variant_tag))?,
+        (_, Packing::Tagged) => decode_tagged(&cx, b, &v.st, Some(variant_tag), None)?,
     })
 }
 
@@ -773,6 +1204,7 @@ fn decode_tagged(
     b: &Build<'_>,
     st: &Body<'_>,
     variant_tag: Option<&Ident>,
+    place_var: Option<&Ident>,
 ) -> Result<TokenStream> {
     let Ctxt {
         ctx_var,
@@ -782,7 +1214,9 @@ fn decode_tagged(
     } = *cx;
 
     let Tokens {
+        as_decoder_t,
         context_t,
+        decode_unknown_field,
         decoder_t,
         default_function,
         fmt,
@@ -795,34 +1229,51 @@ fn decode_tagged(
         map_decoder_t,
         struct_field_decoder_t,
         map_hint,
+        unknown_field_key,
         ..
     } = b.tokens;
 
+    let buffer_var = b.cx.ident("buffer");
     let struct_decoder_var = b.cx.ident("struct_decoder");
     let struct_hint_static = b.cx.ident("STRUCT_HINT");
     let type_decoder_var = b.cx.ident("type_decoder");
     let value_var = b.cx.ident("value");
     let binding_var = b.cx.ident("value");
+    let unknown_key_var = b.cx.ident("unknown_key");
 
     let type_name = &st.name;
 
     let mut assigns = Punctuated::<_, Token![,]>::new();
+    let mut place_assigns = Vec::new();
 
     let mut fields_with = Vec::new();
+    let mut flattened = Vec::new();
+    let mut unknown_fields = None;
+    let mut missing = Vec::new();
+    let mut failed = Vec::new();
+    let first_error_var = b.cx.ident("first_error");
+    let error_var = b.cx.ident("error");
+    let decoded_var = b.cx.ident("decoded");
+    let output_var = b.cx.ident("output");
+
+    // Whether errors are collected into `first_error` when accumulating them,
+    // instead of being returned immediately.
+    let mut accumulate = st.deny_unknown_fields.is_some();
 
     for f in &st.all_fields {
         let tag = &f.name;
         let var = &f.var;
         let decode_path = &f.decode_path.1;
 
-        let expr = match &f.skip {
-            Some(span) => {
-                let ty = f.ty;
-
-                match &f.default_attr {
-                    Some((_, Some(path))) => syn::Expr::Verbatim(quote_spanned!(*span => #path())),
-                    _ => syn::Expr::Verbatim(quote_spanned!(*span => #default_function::<#ty>())),
-                }
+        let expr = match f.skip {
+            Some(span) => skipped_field(b, f, span),
+            None if f.flatten.is_some() => {
+                flattened.push((var, decode_path));
+                syn::Expr::Verbatim(quote!(#var))
+            }
+            None if f.unknown_fields.is_some() => {
+                unknown_fields = Some(&**f);
+                syn::Expr::Verbatim(quote!(#var))
             }
             None => {
                 let formatted_tag = match &st.name_format_with {
@@ -853,8 +1304,54 @@ fn decode_tagged(
                     }
                 });
 
-                let decode = quote! {
-                    #var = #option_some(#decode_path(#ctx_var, #struct_decoder_var)?);
+                // Tracks whether a required field failed to decode, so that it
+                // isn't also reported as missing.
+                let failed_var =
+                    (f.decode_into_path.is_some() && f.default_attr.is_none()).then(|| {
+                        b.cx.ident_with_span(&var.to_string(), var.span(), "_failed")
+                    });
+
+                let decode = match (place_var, &f.decode_into_path) {
+                    (Some(place_var), Some(path)) => {
+                        let member = &f.member;
+
+                        recover_field(
+                            cx,
+                            b,
+                            f,
+                            quote!(#path(#ctx_var, #struct_decoder_var, &mut #place_var.#member)),
+                            quote!(()),
+                            quote!(#var = true;),
+                            failed_var.as_ref(),
+                        )
+                    }
+                    (Some(place_var), None) => {
+                        let member = &f.member;
+
+                        quote! {
+                            #place_var.#member = #decode_path(#ctx_var, #struct_decoder_var)?;
+                            #var = true;
+                        }
+                    }
+                    (None, Some(..)) => recover_field(
+                        cx,
+                        b,
+                        f,
+                        quote!(#decode_path(#ctx_var, #struct_decoder_var)),
+                        quote!(#decoded_var),
+                        quote!(#var = #option_some(#decoded_var);),
+                        failed_var.as_ref(),
+                    ),
+                    (None, None) => quote! {
+                        #var = #option_some(#decode_path(#ctx_var, #struct_decoder_var)?);
+                    },
+                };
+
+                accumulate |= f.decode_into_path.is_some();
+
+                let is_missing = match place_var {
+                    Some(..) => quote!(!#var),
+                    None => quote!(#var.is_none()),
                 };
 
                 fields_with.push((f, decode, (enter, leave)));
@@ -862,13 +1359,39 @@ fn decode_tagged(
                 let fallback = match f.default_attr {
                     Some((span, None)) => quote_spanned!(span => #default_function()),
                     Some((_, Some(path))) => quote!(#path()),
-                    None => quote! {
-                        return #result_err(#context_t::expected_tag(#ctx_var, #type_name, &#tag))
-                    },
+                    None => {
+                        let not_failed = failed_var.as_ref().map(|failed_var| {
+                            failed.push(quote!(let mut #failed_var = false;));
+                            quote!(&& !#failed_var)
+                        });
+
+                        missing.push(quote! {
+                            if #is_missing #not_failed {
+                                let #error_var = #context_t::expected_tag(#ctx_var, #type_name, &#tag);
+                                #first_error_var.get_or_insert(#error_var);
+                            }
+                        });
+
+                        quote! {
+                            return #result_err(#context_t::expected_tag(#ctx_var, #type_name, &#tag))
+                        }
+                    }
                 };
 
                 let var = &f.var;
 
+                if let Some(place_var) = place_var {
+                    let member = &f.member;
+
+                    place_assigns.push(quote! {
+                        if !#var {
+                            #place_var.#member = #fallback;
+                        }
+                    });
+
+                    continue;
+                }
+
                 syn::Expr::Verbatim(quote! {
                     match #var {
                         #option_some(#var) => #var,
@@ -878,6 +1401,12 @@ fn decode_tagged(
             }
         };
 
+        if let Some(place_var) = place_var {
+            let member = &f.member;
+            place_assigns.push(quote!(#place_var.#member = #expr;));
+            continue;
+        }
+
         assigns.push(syn::FieldValue {
             attrs: Vec::new(),
             member: f.member.clone(),
@@ -887,6 +1416,10 @@ fn decode_tagged(
     }
 
     let decode_tag;
+    // Decodes the tag without reporting it, which is used to tell which
+    // entries belong to the fields of this struct.
+    let quiet_decode_tag;
+    let mut parent_names = Vec::new();
     let mut output_enum = quote!();
 
     let unsupported = match variant_tag {
@@ -898,12 +1431,38 @@ fn decode_tagged(
         },
     };
 
-    let skip_field = quote! {
-        if #skip_field(#struct_decoder_var)? {
-            return #result_err(#unsupported);
-        }
+    // Names which are decoded by visiting them are only available while being
+    // visited, so skipped fields are reported there instead.
+    let report_skipped = match st.name_method {
+        NameMethod::Value => Some(quote! {
+            #context_t::skipped_field(#ctx_var, #type_name, &#name_var);
+        }),
+        NameMethod::Unsized(..) => None,
     };
 
+    // Unknown fields which are denied are skipped over when accumulating
+    // errors.
+    let skip_field = match st.deny_unknown_fields {
+        Some(..) => quote! {
+            let #error_var = #unsupported;
+
+            if !#context_t::accumulates_errors(#ctx_var) || #skip_field(#struct_decoder_var)? {
+                return #result_err(#error_var);
+            }
+
+            #first_error_var.get_or_insert(#error_var);
+        },
+        None => quote! {
+            #report_skipped
+
+            if #skip_field(#struct_decoder_var)? {
+                return #result_err(#unsupported);
+            }
+        },
+    };
+
+    let mut declare_unknown = None;
+
     let body;
     let name_type: syn::Type;
 
@@ -912,7 +1471,16 @@ fn decode_tagged(
             let mut arms = Vec::with_capacity(fields_with.len());
 
             for (f, decode, (enter, leave)) in fields_with {
-                let arm = output_arm(f.pattern, &f.name, &binding_var);
+                let arm = output_arm(
+                    f.pattern,
+                    &f.name,
+                    &f.aliases,
+                    st.case_insensitive,
+                    &binding_var,
+                );
+
+                let arm = quote!(#arm);
+                parent_names.push(arm.clone());
 
                 arms.push(quote! {
                     #arm => {
@@ -924,7 +1492,16 @@ fn decode_tagged(
                 });
             }
 
-            body = quote!(match #name_var { #(#arms,)* _ => { #skip_field } });
+            // Unknown fields are collected instead of skipped.
+            let unknown = match unknown_fields {
+                Some(Field { var, .. }) => quote! {
+                    let #unknown_key_var = #unknown_field_key(&#var, #name_var);
+                    #decode_unknown_field(&mut #var, #unknown_key_var, #struct_decoder_var)?;
+                },
+                None => skip_field,
+            };
+
+            body = quote!(match #name_var { #(#arms,)* _ => { #unknown } });
 
             let decode_t_decode = &b.decode_t_decode;
 
@@ -932,6 +1509,8 @@ fn decode_tagged(
                 #decode_t_decode(#ctx_var, #struct_decoder_var)?
             };
 
+            quiet_decode_tag = decode_tag.clone();
+
             name_type = st.name_type.clone();
         }
         NameMethod::Unsized(method) => {
@@ -942,13 +1521,55 @@ fn decode_tagged(
             let mut name_arms = Vec::with_capacity(fields_with.len());
 
             for (f, decode, trace) in fields_with {
-                let (name_pat, name_variant) =
-                    unsized_arm(b, f.span, f.index, &f.name, f.pattern, &output_type);
+                let (name_pat, name_variant) = unsized_arm(
+                    b,
+                    f.span,
+                    f.index,
+                    &f.name,
+                    f.pattern,
+                    &f.aliases,
+                    st.case_insensitive,
+                    &output_type,
+                );
 
                 outputs.push(name_variant);
+                parent_names.push(quote!(#name_pat));
                 name_arms.push((name_pat, decode, trace));
             }
 
+            // The name of an unknown field is only available while it is
+            // being visited, so its key is captured there and used to collect
+            // the field instead of skipping it.
+            let capture_unknown = unknown_fields.map(|Field { var, .. }| {
+                quote! {
+                    #unknown_key_var = #option_some(#unknown_field_key(&#var, #value_var));
+                }
+            });
+
+            let report_unknown = match (unknown_fields.is_none(), st.deny_unknown_fields.is_none())
+            {
+                (true, true) => Some(quote! {
+                    #context_t::skipped_field(#ctx_var, #type_name, #value_var);
+                }),
+                _ => None,
+            };
+
+            let unknown = match unknown_fields {
+                Some(Field { var, .. }) => {
+                    declare_unknown = Some(quote!(let mut #unknown_key_var = #option_none;));
+
+                    quote! {
+                        match #unknown_key_var {
+                            #option_some(#unknown_key_var) => {
+                                #decode_unknown_field(&mut #var, #unknown_key_var, #struct_decoder_var)?;
+                            }
+                            #option_none => { #skip_field }
+                        }
+                    }
+                }
+                None => skip_field,
+            };
+
             if !name_arms.is_empty() {
                 let arms = name_arms
                     .into_iter()
@@ -964,22 +1585,34 @@ fn decode_tagged(
                     });
 
                 body = quote! {
-                    match #name_var { #(#arms,)* #name_var => { #skip_field } }
+                    match #name_var { #(#arms,)* #name_var => { #unknown } }
                 }
             } else {
-                body = skip_field;
+                body = unknown;
             }
 
             let arms = outputs.iter().map(|o| o.as_arm(&binding_var, option_some));
+            let quiet_arms = arms.clone();
 
             let visit_type = &st.name_type;
             let method = method.as_method_name();
 
+            quiet_decode_tag = quote! {
+                #decoder_t::#method(#struct_decoder_var, |#value_var: &#visit_type| {
+                    #result_ok(match #value_var {
+                        #(#quiet_arms,)*
+                        _ => #option_none,
+                    })
+                })?
+            };
+
             decode_tag = quote! {
                 #decoder_t::#method(#struct_decoder_var, |#value_var: &#visit_type| {
                     #result_ok(match #value_var {
                         #(#arms,)*
                         #value_var => {
+                            #capture_unknown
+                            #report_unknown
                             #option_none
                         }
                     })
@@ -1011,14 +1644,67 @@ fn decode_tagged(
         }
     }
 
-    let path = &st.path;
-    let fields_len = st.unskipped_fields.len();
+    let output = match place_var {
+        Some(..) => quote!(#(#place_assigns)* #result_ok(())),
+        None => {
+            let path = &st.path;
+            quote!(#result_ok(#path { #assigns }))
+        }
+    };
+
+    let fields_len =
+        st.unskipped_fields.len() - flattened.len() - usize::from(unknown_fields.is_some());
 
     let decls = st
         .unskipped_fields
         .iter()
         .map(|f| &**f)
-        .map(|Field { var, ty, .. }| quote!(let mut #var: #option<#ty> = #option_none;));
+        .filter(|f| f.flatten.is_none())
+        .map(|f| {
+            let Field { var, ty, .. } = f;
+
+            if f.unknown_fields.is_some() {
+                quote!(let mut #var: #ty = #default_function();)
+            } else if place_var.is_some() {
+                quote!(let mut #var = false;)
+            } else {
+                quote!(let mut #var: #option<#ty> = #option_none;)
+            }
+        });
+
+    // Flattened fields are decoded from a buffered copy of the map without
+    // the entries which belong to this struct, after which the remaining
+    // fields are decoded from it.
+    let decode_flattened = flattened.iter().map(|(var, decode_path)| {
+        if parent_names.is_empty() {
+            return quote! {
+                let #var = {
+                    let #decoder_var = #as_decoder_t::as_decoder(&#buffer_var)?;
+                    #decode_path(#ctx_var, #decoder_var)?
+                };
+            };
+        }
+
+        quote! {
+            let #var = {
+                let #buffer_var = #as_decoder_t::retain_entries(&#buffer_var, |#struct_decoder_var| {
+                    let #name_var: #name_type = #quiet_decode_tag;
+                    #result_ok(match #name_var { #(#parent_names => false,)* _ => true })
+                })?;
+
+                let #decoder_var = #as_decoder_t::as_decoder(&#buffer_var)?;
+                #decode_path(#ctx_var, #decoder_var)?
+            };
+        }
+    });
+
+    let buffer = (!flattened.is_empty()).then(|| {
+        quote! {
+            let #buffer_var = #decoder_t::decode_buffer(#decoder_var)?;
+            #(#decode_flattened)*
+            let #decoder_var = #as_decoder_t::as_decoder(&#buffer_var)?;
+        }
+    });
 
     let enter = (cx.trace && cx.trace_body).then(|| {
         quote! {
@@ -1032,16 +1718,44 @@ fn decode_tagged(
         }
     });
 
-    Ok(quote! {{
-        #output_enum
-        #(#decls)*
+    // When the context accumulates errors, every missing field is reported
+    // and the first error is returned once the map has been fully decoded.
+    // It's marked as recoverable, so that an enclosing struct can keep going.
+    let declare_errors = accumulate.then(|| {
+        quote! {
+            let mut #first_error_var = #option_none;
+            #(#failed)*
+        }
+    });
 
-        #enter
+    let check_missing = (!missing.is_empty()).then(|| {
+        quote! {
+            if #context_t::accumulates_errors(#ctx_var) {
+                #(#missing)*
+            }
+        }
+    });
 
-        static #struct_hint_static: #map_hint = #map_hint::with_size(#fields_len);
+    let return_error = accumulate.then(|| {
+        quote! {
+            if let #option_some(#error_var) = #first_error_var {
+                return #result_ok(#result_err(#error_var));
+            }
+        }
+    });
 
+    let output = match accumulate {
+        true => quote!(#result_ok(#output)),
+        false => output,
+    };
+
+    let decode_map = quote! {
         #decoder_t::decode_map_hint(#decoder_var, &#struct_hint_static, move |#type_decoder_var| {
+            #declare_errors
+
             while let #option_some(mut #struct_decoder_var) = #map_decoder_t::decode_entry(#type_decoder_var)? {
+                #declare_unknown
+
                 let #name_var: #name_type = {
                     let #struct_decoder_var = #struct_field_decoder_t::decode_key(&mut #struct_decoder_var)?;
                     #decode_tag
@@ -1050,12 +1764,84 @@ fn decode_tagged(
                 #body
             }
 
+            #check_missing
             #leave
-            #result_ok(#path { #assigns })
+            #return_error
+            #output
         })?
+    };
+
+    let decode_map = match accumulate {
+        true => quote! {
+            match #decode_map {
+                #result_ok(#output_var) => #output_var,
+                #result_err(#error_var) => {
+                    #context_t::recoverable(#ctx_var);
+                    return #result_err(#error_var);
+                }
+            }
+        },
+        false => decode_map,
+    };
+
+    Ok(quote! {{
+        #output_enum
+        #(#decls)*
+
+        #enter
+        #buffer
+
+        static #struct_hint_static: #map_hint = #map_hint::with_size(#fields_len);
+
+        #decode_map
     }})
 }
 
+/// Decode a field through `call`, keeping track of errors which the field type
+/// has marked as recoverable instead of returning them.
+fn recover_field(
+    cx: &Ctxt<'_>,
+    b: &Build<'_>,
+    f: &Field<'_>,
+    call: TokenStream,
+    pattern: TokenStream,
+    store: TokenStream,
+    failed_var: Option<&Ident>,
+) -> TokenStream {
+    let Tokens {
+        context_t,
+        decode_t,
+        result_err,
+        result_ok,
+        ..
+    } = b.tokens;
+
+    let Ctxt { ctx_var, .. } = *cx;
+
+    let error_var = b.cx.ident("error");
+    let first_error_var = b.cx.ident("first_error");
+
+    let ty = f.ty;
+    let mode_ident = b.expansion.mode_path(b.tokens).as_path();
+    let failed = failed_var.map(|failed_var| quote!(#failed_var = true;));
+
+    quote! {
+        match #call {
+            #result_ok(#pattern) => {
+                #store
+            }
+            #result_err(#error_var) => {
+                if !(#context_t::take_recoverable(#ctx_var) && <#ty as #decode_t<#mode_ident>>::RECOVERABLE) {
+                    return #result_err(#error_var);
+                }
+
+                #failed
+                #first_error_var.get_or_insert(#error_var);
+            }
+        }
+    }
+}
+
 /// Decode a transparent value.
 fn decode_transparent(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream> {
     let Ctxt {
@@ -1068,12 +1854,27 @@ fn decode_transparent(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<Tok
 
     let Tokens { context_t, .. } = b.tokens;
 
-    let f = &st.unskipped_fields[0];
-
     let type_name = &st.name;
     let path = &st.path;
-    let decode_path = &f.decode_path.1;
-    let member = &f.member;
+
+    let mut assigns = Punctuated::<_, Token![,]>::new();
+
+    for f in &st.all_fields {
+        let expr = match f.skip {
+            Some(span) => skipped_field(b, f, span),
+            None => {
+                let decode_path = &f.decode_path.1;
+                syn::Expr::Verbatim(quote!(#decode_path(#ctx_var, #decoder_var)?))
+            }
+        };
+
+        assigns.push(syn::FieldValue {
+            attrs: Vec::new(),
+            member: f.member.clone(),
+            colon_token: Some(<Token![:]>::default()),
+            expr,
+        });
+    }
 
     let enter = (cx.trace && cx.trace_body).then(|| {
         quote! {
@@ -1090,15 +1891,27 @@ fn decode_transparent(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<Tok
     Ok(quote! {{
         #enter
 
-        let #output_var = #path {
-            #member: #decode_path(#ctx_var, #decoder_var)?
-        };
+        let #output_var = #path { #assigns };
 
         #leave
         #output_var
     }})
 }
 
+/// The expression used to initialize a skipped field.
+fn skipped_field(b: &Build<'_>, f: &Field<'_>, span: Span) -> syn::Expr {
+    let Tokens {
+        default_function, ..
+    } = b.tokens;
+
+    let ty = f.ty;
+
+    match &f.default_attr {
+        Some((_, Some(path))) => syn::Expr::Verbatim(quote_spanned!(span => #path())),
+        _ => syn::Expr::Verbatim(quote_spanned!(span => #default_function::<#ty>())),
+    }
+}
+
 /// Decode something packed.
 fn decode_packed(cx: &Ctxt<'_>, b: &Build<'_>, st_: &Body<'_>) -> Result<TokenStream> {
     let Ctxt {
@@ -1110,12 +1923,50 @@ fn decode_packed(cx: &Ctxt<'_>, b: &Build<'_>, st_: &Body<'_>) -> Result<TokenSt
     let Tokens {
         context_t,
         decoder_t,
-        pack_decoder_t,
         ..
     } = b.tokens;
 
     let type_name = &st_.name;
     let output_var = b.cx.ident("output");
+
+    let enter = (cx.trace && cx.trace_body).then(|| {
+        quote! {
+            #context_t::enter_struct(#ctx_var, #type_name);
+        }
+    });
+
+    let leave = (cx.trace && cx.trace_body).then(|| {
+        quote! {
+            #context_t::leave_struct(#ctx_var);
+        }
+    });
+
+    let pack = b.cx.ident("pack");
+    let decode = decode_packed_fields(cx, b, st_, &pack);
+
+    Ok(quote! {{
+        #enter
+
+        let #output_var = #decoder_t::decode_pack(#decoder_var, move |#pack| {
+            Ok(#decode)
+        })?;
+
+        #leave
+        #output_var
+    }})
+}
+
+/// Construct the body from fields decoded one after another from `pack`.
+fn decode_packed_fields(
+    cx: &Ctxt<'_>,
+    b: &Build<'_>,
+    st_: &Body<'_>,
+    pack: &syn::Ident,
+) -> TokenStream {
+    let Ctxt { ctx_var, .. } = *cx;
+
+    let Tokens { pack_decoder_t, .. } = b.tokens;
+
     let field_decoder = b.cx.ident("field_decoder");
 
     let mut assign = Vec::new();
@@ -1139,32 +1990,10 @@ fn decode_packed(cx: &Ctxt<'_>, b: &Build<'_>, st_: &Body<'_>) -> Result<TokenSt
         });
     }
 
-    let enter = (cx.trace && cx.trace_body).then(|| {
-        quote! {
-            #context_t::enter_struct(#ctx_var, #type_name);
-        }
-    });
-
-    let leave = (cx.trace && cx.trace_body).then(|| {
-        quote! {
-            #context_t::leave_struct(#ctx_var);
-        }
-    });
-
-    let pack = b.cx.ident("pack");
-    let assign = apply::iter(assign, &pack);
+    let assign = apply::iter(assign, pack);
     let path = &st_.path;
 
-    Ok(quote! {{
-        #enter
-
-        let #output_var = #decoder_t::decode_pack(#decoder_var, move |#pack| {
-            Ok(#path { #(#assign),* })
-        })?;
-
-        #leave
-        #output_var
-    }})
+    quote!(#path { #(#assign),* })
 }
 
 /// Output type used when indirectly encoding a variant or field as type which
@@ -1178,6 +2007,10 @@ pub(crate) struct NameVariant<'a> {
     name: &'a syn::Expr,
     /// The pattern being matched.
     pattern: Option<&'a syn::Pat>,
+    /// Aliases which are matched in addition to the tag.
+    aliases: &'a [&'a syn::Expr],
+    /// Match the tag without regard to ASCII case.
+    case_insensitive: bool,
 }
 
 impl NameVariant<'_> {
@@ -1189,19 +2022,20 @@ impl NameVariant<'_> {
             path: self.path.clone(),
         });
 
-        let arm = output_arm(self.pattern, self.name, binding_var);
+        let arm = output_arm(
+            self.pattern,
+            self.name,
+            self.aliases,
+            self.case_insensitive,
+            binding_var,
+        );
 
         syn::Arm {
             attrs: Vec::new(),
             pat: arm.pat,
-            guard: arm.cond.map(|_| {
-                let name = self.name;
-
-                (
-                    <syn::Token![if]>::default(),
-                    syn::parse_quote!(*#binding_var == #name),
-                )
-            }),
+            guard: arm
+                .cond
+                .map(|cond| (<syn::Token![if]>::default(), Box::new(cond.expr()))),
             fat_arrow_token: <Token![=>]>::default(),
             body: Box::new(build_call(option_some, [body])),
             comma: None,
@@ -1240,12 +2074,15 @@ pub(crate) fn build_reference(expr: syn::Expr) -> syn::Expr {
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn unsized_arm<'a>(
     b: &Build<'_>,
     span: Span,
     index: usize,
     name: &'a syn::Expr,
     pattern: Option<&'a syn::Pat>,
+    aliases: &'a [&'a syn::Expr],
+    case_insensitive: bool,
     output: &Ident,
 ) -> (syn::Pat, NameVariant<'a>) {
     let variant = b.cx.type_with_span(format_args!("Variant{}", index), span);
@@ -1258,37 +2095,43 @@ fn unsized_arm<'a>(
         variant,
         name,
         pattern,
+        aliases,
+        case_insensitive,
     };
 
     let option_some = &b.tokens.option_some;
     (syn::parse_quote!(#option_some(#path)), output)
 }
 
+/// A condition testing that a binding matches any of the given names.
 struct Condition<'a> {
-    if_: syn::Token![if],
-    star: syn::Token![*],
     ident: &'a syn::Ident,
-    equals: syn::Token![==],
-    expr: &'a syn::Expr,
+    names: Vec<&'a syn::Expr>,
+    case_insensitive: bool,
 }
 
-impl ToTokens for Condition<'_> {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        self.if_.to_tokens(tokens);
-        self.star.to_tokens(tokens);
-        self.ident.to_tokens(tokens);
-        self.equals.to_tokens(tokens);
-        self.expr.to_tokens(tokens);
+impl Condition<'_> {
+    /// The expression being tested.
+    fn expr(&self) -> syn::Expr {
+        let ident = self.ident;
+
+        let mut tests = self.names.iter().map(|name| {
+            if self.case_insensitive {
+                quote!(#ident.eq_ignore_ascii_case(#name))
+            } else {
+                quote!(*#ident == #name)
+            }
+        });
+
+        let first = tests.next().unwrap_or_else(|| quote!(false));
+        syn::parse_quote!(#first #(|| #tests)*)
     }
 }
 
-fn condition<'a>(ident: &'a syn::Ident, expr: &'a syn::Expr) -> Condition<'a> {
-    Condition {
-        if_: <syn::Token![if]>::default(),
-        star: <syn::Token![*]>::default(),
-        ident,
-        equals: <syn::Token![==]>::default(),
-        expr,
+impl ToTokens for Condition<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        <syn::Token![if]>::default().to_tokens(tokens);
+        self.expr().to_tokens(tokens);
     }
 }
 
@@ -1305,6 +2148,8 @@ fn ref_pattern(ident: &syn::Ident) -> syn::Pat {
 fn output_arm<'a>(
     pat: Option<&'a syn::Pat>,
     name: &'a syn::Expr,
+    aliases: &[&'a syn::Expr],
+    case_insensitive: bool,
     binding: &'a syn::Ident,
 ) -> OutputArm<'a> {
     if let Some(pat) = pat {
@@ -1314,13 +2159,35 @@ fn output_arm<'a>(
         };
     }
 
-    if let Some(pat) = expr_to_pat(name) {
-        return OutputArm { pat, cond: None };
+    let names = [name].into_iter().chain(aliases.iter().copied());
+
+    if !case_insensitive {
+        if let Some(cases) = names
+            .clone()
+            .map(expr_to_pat)
+            .collect::<Option<Punctuated<syn::Pat, Token![|]>>>()
+        {
+            let pat = if cases.len() == 1 {
+                cases.into_iter().next().unwrap()
+            } else {
+                syn::Pat::Or(syn::PatOr {
+                    attrs: Vec::new(),
+                    leading_vert: None,
+                    cases,
+                })
+            };
+
+            return OutputArm { pat, cond: None };
+        }
     }
 
     OutputArm {
         pat: ref_pattern(binding),
-        cond: Some(condition(binding, name)),
+        cond: Some(Condition {
+            ident: binding,
+            names: names.collect(),
+            case_insensitive,
+        }),
     }
 }
 
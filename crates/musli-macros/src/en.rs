@@ -3,8 +3,9 @@ use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::Token;
 
+use crate::expander::{Expander, StructKind};
 use crate::internals::attr::{EnumTagging, Packing};
-use crate::internals::build::{Body, Build, BuildData, Enum, Variant};
+use crate::internals::build::{Body, Build, BuildData, Enum, Variant, Version};
 use crate::internals::tokens::Tokens;
 use crate::internals::Result;
 
@@ -31,17 +32,23 @@ pub(crate) fn expand_insert_entry(e: Build<'_>) -> Result<TokenStream> {
     };
 
     let Tokens {
+        encode_remote_t,
         encode_t,
         encoder_t,
         result,
         ..
     } = e.tokens;
 
-    let body = match &e.data {
-        BuildData::Struct(st) => encode_map(&cx, &e, st)?,
-        BuildData::Enum(en) => encode_enum(&cx, &e, en)?,
+    let mut body = match (&e.data, e.into_type) {
+        (_, Some(ty)) => encode_into(&cx, &e, ty),
+        (BuildData::Struct(st), None) => encode_map(&cx, &e, st)?,
+        (BuildData::Enum(en), None) => encode_enum(&cx, &e, en)?,
     };
 
+    if let Some(version) = &e.version {
+        body = encode_versioned(&cx, &e, version, body);
+    }
+
     if e.cx.has_errors() {
         return Err(());
     }
@@ -67,11 +74,18 @@ pub(crate) fn expand_insert_entry(e: Build<'_>) -> Result<TokenStream> {
 
     let mode_ident = e.expansion.mode_path(e.tokens).as_path();
 
+    let impl_for = match e.remote {
+        Some(remote) => {
+            quote!(#encode_remote_t<#mode_ident, #type_ident #type_generics> for #remote #type_generics)
+        }
+        None => quote!(#encode_t<#mode_ident> for #type_ident #type_generics),
+    };
+
     Ok(quote! {
         const _: () = {
             #[automatically_derived]
             #(#attributes)*
-            impl #impl_generics #encode_t<#mode_ident> for #type_ident #type_generics #where_clause {
+            impl #impl_generics #impl_for #where_clause {
                 #[inline]
                 fn encode<#e_param>(&self, #ctx_var: &#e_param::Cx, #encoder_var: #e_param) -> #result<<#e_param as #encoder_t>::Ok, <#e_param as #encoder_t>::Error>
                 where
@@ -84,6 +98,65 @@ pub(crate) fn expand_insert_entry(e: Build<'_>) -> Result<TokenStream> {
     })
 }
 
+/// Expand the inherent `encode` function of a `#[musli(remote = ..)]` mirror
+/// type, which is what `#[musli(with = ..)]` calls.
+pub(crate) fn expand_remote(e: &Expander<'_>, remote: &syn::Path) -> TokenStream {
+    let Tokens {
+        encode_remote_t,
+        encoder_t,
+        result,
+        ..
+    } = &e.tokens;
+
+    let type_ident = &e.input.ident;
+    let vis = &e.input.vis;
+    let e_param = e.cx.type_with_span("E", Span::call_site());
+
+    let value_var = e.cx.ident("value");
+    let rebuild = e.expand_remote_rebuild(&value_var);
+
+    let (impl_generics, type_generics, where_clause) = e.input.generics.split_for_impl();
+
+    quote! {
+        const _: () = {
+            #[automatically_derived]
+            impl #impl_generics #type_ident #type_generics #where_clause {
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn encode<#e_param>(value: &#remote #type_generics, cx: &#e_param::Cx, encoder: #e_param) -> #result<<#e_param as #encoder_t>::Ok, <#e_param as #encoder_t>::Error>
+                where
+                    #e_param: #encoder_t,
+                    #remote #type_generics: #encode_remote_t<<#e_param as #encoder_t>::Mode, Self>,
+                {
+                    let _ = |#value_var: Self| -> Self { #rebuild };
+                    #encode_remote_t::<<#e_param as #encoder_t>::Mode, Self>::encode(value, cx, encoder)
+                }
+            }
+        };
+    }
+}
+
+/// Encode by converting a clone of the value into a proxy type.
+fn encode_into(cx: &Ctxt<'_>, b: &Build<'_>, ty: &syn::Type) -> TokenStream {
+    let Ctxt {
+        ctx_var,
+        encoder_var,
+        ..
+    } = *cx;
+
+    let Tokens {
+        clone_t, into_t, ..
+    } = b.tokens;
+
+    let encode_t_encode = &b.encode_t_encode;
+    let proxy_var = b.cx.ident("proxy");
+
+    quote! {
+        let #proxy_var: #ty = #into_t::into(#clone_t::clone(self));
+        #encode_t_encode(&#proxy_var, #ctx_var, #encoder_var)
+    }
+}
+
 /// Encode a struct.
 fn encode_map(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream> {
     let Ctxt {
@@ -95,12 +168,16 @@ fn encode_map(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream
     let Tokens {
         context_t,
         encoder_t,
+        flatten_encoder,
+        flatten_len,
+        map_encoder_t,
         result_ok,
         ..
     } = b.tokens;
 
     let pack_var = b.cx.ident("pack");
     let output_var = b.cx.ident("output");
+    let map_var = b.cx.ident("map");
 
     let (encoders, tests) = insert_fields(cx, b, st, &pack_var)?;
 
@@ -131,20 +208,77 @@ fn encode_map(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream
         }
         Packing::Tagged => {
             let decls = tests.iter().map(|t| &t.decl);
-            let (build_hint, hint) = length_test(st.unskipped_fields.len(), &tests).build(b);
 
-            encode = quote! {{
-                #enter
-                #(#decls)*
-                #build_hint
+            // Collected unknown fields are written back out as entries of
+            // the struct, just like a flattened field.
+            let flattened = st
+                .unskipped_fields
+                .iter()
+                .filter(|f| f.flatten.is_some() || f.unknown_fields.is_some())
+                .collect::<Vec<_>>();
+
+            let mut len = length_test(st.unskipped_fields.len() - flattened.len(), &tests);
+
+            // The number of entries in a flattened field is only known once
+            // we've asked it.
+            for f in &flattened {
+                let access = &f.self_access;
+                let encode_path = &f.encode_path.1;
+
+                len.kind = LengthTestKind::Dynamic;
+                len.expressions
+                    .push(quote!(#encode_path(#access, #ctx_var, #flatten_len::new(#ctx_var))?));
+            }
 
-                let #output_var = #encoder_t::encode_map_fn(#encoder_var, &#hint, move |#encoder_var| {
-                    #(#encoders)*
-                    #result_ok(())
-                })?;
-                #leave
-                #output_var
-            }};
+            let (build_hint, hint) = len.build(b);
+
+            if let Some((first, rest)) = flattened.split_first() {
+                // Each flattened field takes ownership of the map and hands it
+                // over to the next one, where the last one finishes it.
+                let mut finish = quote!(#map_encoder_t::finish_map);
+
+                for f in rest.iter().rev() {
+                    let access = &f.self_access;
+                    let encode_path = &f.encode_path.1;
+
+                    finish = quote! {
+                        move |#map_var| #encode_path(#access, #ctx_var, #flatten_encoder::new(#ctx_var, #map_var, #finish))
+                    };
+                }
+
+                let access = &first.self_access;
+                let encode_path = &first.encode_path.1;
+
+                encode = quote! {{
+                    #enter
+                    #(#decls)*
+                    #build_hint
+
+                    let mut #map_var = #encoder_t::encode_map(#encoder_var, &#hint)?;
+
+                    {
+                        let #encoder_var = &mut #map_var;
+                        #(#encoders)*
+                    }
+
+                    let #output_var = #encode_path(#access, #ctx_var, #flatten_encoder::new(#ctx_var, #map_var, #finish))?;
+                    #leave
+                    #output_var
+                }};
+            } else {
+                encode = quote! {{
+                    #enter
+                    #(#decls)*
+                    #build_hint
+
+                    let #output_var = #encoder_t::encode_map_fn(#encoder_var, &#hint, move |#encoder_var| {
+                        #(#encoders)*
+                        #result_ok(())
+                    })?;
+                    #leave
+                    #output_var
+                }};
+            }
         }
         Packing::Packed => {
             let decls = tests.iter().map(|t| &t.decl);
@@ -165,6 +299,44 @@ fn encode_map(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream
     Ok(quote!(#result_ok(#encode)))
 }
 
+/// Encode a versioned container as a variant, where the tag is the version.
+fn encode_versioned(
+    cx: &Ctxt<'_>,
+    b: &Build<'_>,
+    version: &Version<'_>,
+    body: TokenStream,
+) -> TokenStream {
+    let Ctxt {
+        ctx_var,
+        encoder_var,
+        ..
+    } = *cx;
+
+    let Tokens {
+        encoder_t,
+        result,
+        variant_encoder_t,
+        ..
+    } = b.tokens;
+
+    let encode_t_encode = &b.encode_t_encode;
+
+    let variant_encoder = b.cx.ident("variant_encoder");
+    let tag_encoder = b.cx.ident("tag_encoder");
+    let version_static = b.cx.ident("VERSION");
+    let version = version.version;
+
+    quote! {
+        #encoder_t::encode_variant_fn(#encoder_var, move |#variant_encoder| {
+            static #version_static: u32 = #version;
+            let #tag_encoder = #variant_encoder_t::encode_tag(#variant_encoder)?;
+            #encode_t_encode(&#version_static, #ctx_var, #tag_encoder)?;
+            let #encoder_var = #variant_encoder_t::encode_data(#variant_encoder)?;
+            #result::map(#body, |_| ())
+        })
+    }
+}
+
 struct FieldTest<'st> {
     decl: syn::Stmt,
     var: &'st syn::Ident,
@@ -204,6 +376,11 @@ fn insert_fields<'st>(
     let mut tests = Vec::with_capacity(st.all_fields.len());
 
     for f in &st.unskipped_fields {
+        // Flattened fields are encoded separately by the containing struct.
+        if f.flatten.is_some() || f.unknown_fields.is_some() {
+            continue;
+        }
+
         let encode_path = &f.encode_path.1;
         let access = &f.self_access;
         let name = &f.name;
@@ -308,11 +485,20 @@ fn encode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum<'_>) -> Result<TokenStrea
         variants.push(quote!(#pattern => #encode));
     }
 
+    let skipped = en.skipped.iter().map(|ident| {
+        let message = format!("Cannot encode skipped variant {}::{ident}", b.input.ident);
+        quote!(Self::#ident { .. } => return #result_err(#context_t::message(#ctx_var, #message)))
+    });
+
+    let skipped = skipped.collect::<Vec<_>>();
+
     // Special case: uninhabitable types.
-    Ok(if variants.is_empty() {
+    Ok(if variants.is_empty() && skipped.is_empty() {
         quote!(#result_err(#context_t::uninhabitable(#ctx_var, #type_name)))
+    } else if variants.is_empty() {
+        quote!(match self { #(#skipped),* })
     } else {
-        quote!(#result_ok(match self { #(#variants),* }))
+        quote!(#result_ok(match self { #(#variants,)* #(#skipped,)* }))
     })
 }
 
@@ -336,11 +522,14 @@ fn encode_variant(
     let Tokens {
         context_t,
         encoder_t,
+        flatten_encoder,
+        flatten_len,
         result_ok,
         map_encoder_t,
         map_entry_encoder_t,
         variant_encoder_t,
         map_hint,
+        sequence_encoder_t,
         ..
     } = b.tokens;
 
@@ -366,8 +555,34 @@ fn encode_variant(
                 #encode_t_encode(&#name_static, #ctx_var, #encoder_var)?
             }};
         }
-        EnumTagging::Default => {
+        EnumTagging::Default if en.enum_packing == Packing::Packed => {
+            let encode_t_encode = &b.encode_t_encode;
+            let name = &v.name;
+            let static_type = en.static_type();
+            let decls = tests.iter().map(|t| &t.decl);
+
+            // The tag is the first element of the pack, followed by the fields
+            // of the variant.
+            encode = quote! {{
+                #encoder_t::encode_pack_fn(#encoder_var, move |#pack_var| {
+                    static #name_static: #static_type = #name;
+                    let #tag_encoder = #sequence_encoder_t::encode_next(#pack_var)?;
+                    #encode_t_encode(&#name_static, #ctx_var, #tag_encoder)?;
+                    #(#decls)*
+                    #(#encoders)*
+                    #result_ok(())
+                })?
+            }};
+        }
+        EnumTagging::Default | EnumTagging::Untagged => {
             match v.st.packing {
+                // Untagged unit variants are encoded as empty values, since
+                // there is nothing else to tell them apart by.
+                _ if matches!(en.enum_tagging, EnumTagging::Untagged)
+                    && matches!(v.st.kind, StructKind::Empty) =>
+                {
+                    encode = quote!(#encoder_t::encode_empty(#encoder_var)?);
+                }
                 Packing::Transparent => {
                     let f = &v.st.unskipped_fields[0];
 
@@ -403,7 +618,7 @@ fn encode_variant(
                 }
             }
 
-            if let Packing::Tagged = en.enum_packing {
+            if let (EnumTagging::Default, Packing::Tagged) = (&en.enum_tagging, en.enum_packing) {
                 let encode_t_encode = &b.encode_t_encode;
                 let name = &v.name;
                 let static_type = en.static_type();
@@ -422,6 +637,39 @@ fn encode_variant(
                 }};
             }
         }
+        EnumTagging::Internal { tag } if v.st.packing == Packing::Transparent => {
+            let name = &v.name;
+            let static_type = en.static_type();
+
+            let f = &v.st.unskipped_fields[0];
+            let access = &f.self_access;
+            let encode_path = &f.encode_path.1;
+            let map_var = b.cx.ident("map");
+
+            // The entries of the wrapped value are flattened into the same map
+            // as the tag.
+            let mut len = LengthTest {
+                kind: LengthTestKind::Dynamic,
+                expressions: Punctuated::new(),
+            };
+
+            len.expressions.push(quote!(1));
+            len.expressions
+                .push(quote!(#encode_path(#access, #ctx_var, #flatten_len::new(#ctx_var))?));
+
+            let (build_hint, hint) = len.build(b);
+
+            encode = quote! {{
+                #build_hint
+
+                static #tag_static: #static_type = #tag;
+                static #name_static: #static_type = #name;
+
+                let mut #map_var = #encoder_t::encode_map(#encoder_var, &#hint)?;
+                #map_encoder_t::insert_entry(&mut #map_var, #tag_static, #name_static)?;
+                #encode_path(#access, #ctx_var, #flatten_encoder::new(#ctx_var, #map_var, #map_encoder_t::finish_map))?
+            }};
+        }
         EnumTagging::Internal { tag } => {
             let name = &v.name;
 
@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 
 use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
 use syn::spanned::Spanned;
 
 use crate::internals::attr::{self, ModeIdent, ModeKind, TypeAttr};
@@ -64,6 +65,7 @@ pub(crate) struct VariantData<'a> {
     pub(crate) index: usize,
     pub(crate) attr: attr::VariantAttr,
     pub(crate) ident: &'a syn::Ident,
+    pub(crate) discriminant: Option<&'a syn::Expr>,
     pub(crate) fields: Vec<FieldData<'a>>,
     pub(crate) kind: StructKind,
 }
@@ -137,6 +139,7 @@ impl<'a> Expander<'a> {
                         name: syn::LitStr::new(&variant.ident.to_string(), variant.ident.span()),
                         attr: attr::variant_attrs(&cx, &variant.attrs),
                         ident: &variant.ident,
+                        discriminant: variant.discriminant.as_ref().map(|(_, expr)| expr),
                         fields: fields(&cx, &variant.fields),
                         kind: match &variant.fields {
                             syn::Fields::Unit => StructKind::Empty,
@@ -223,9 +226,114 @@ impl<'a> Expander<'a> {
             out.extend(crate::en::expand_insert_entry(build)?);
         }
 
+        if let Some(remote) = self.type_attr.remote_type() {
+            out.extend(crate::en::expand_remote(self, remote));
+        }
+
+        if self.type_attr.is_reflect() {
+            out.extend(self.expand_reflect());
+        }
+
         Ok(out)
     }
 
+    /// Expand the `Reflect` implementation requested with `#[musli(reflect)]`.
+    fn expand_reflect(&self) -> TokenStream {
+        let Tokens {
+            reflect_field,
+            reflect_metadata,
+            reflect_t,
+            reflect_variant,
+            stringify,
+            ..
+        } = &self.tokens;
+
+        let fields = |fields: &[FieldData<'_>]| {
+            let fields = fields.iter().map(|f| {
+                let name = match f.ident {
+                    Some(ident) => ident.to_string(),
+                    None => f.index.to_string(),
+                };
+
+                let index = f.index;
+                let ty = f.ty;
+                quote!(#reflect_field::new(#name, #index, #stringify!(#ty)))
+            });
+
+            quote!(&[#(#fields),*])
+        };
+
+        let name = self.input.ident.to_string();
+
+        let metadata = match &self.data {
+            Data::Struct(st) => {
+                let fields = fields(&st.fields);
+                quote!(#reflect_metadata::new_struct(#name, #fields))
+            }
+            Data::Enum(en) => {
+                let variants = en.variants.iter().map(|v| {
+                    let name = v.ident.to_string();
+                    let index = v.index;
+                    let fields = fields(&v.fields);
+                    quote!(#reflect_variant::new(#name, #index, #fields))
+                });
+
+                quote!(#reflect_metadata::new_enum(#name, &[#(#variants),*]))
+            }
+            Data::Union => return TokenStream::new(),
+        };
+
+        let type_ident = &self.input.ident;
+        let (impl_generics, type_generics, where_clause) = self.input.generics.split_for_impl();
+
+        quote! {
+            const _: () = {
+                #[automatically_derived]
+                impl #impl_generics #reflect_t for #type_ident #type_generics #where_clause {
+                    const METADATA: &'static #reflect_metadata = &#metadata;
+                }
+            };
+        }
+    }
+
+    /// Expand an expression which destructures and rebuilds a value of a
+    /// `#[musli(remote = ..)]` mirror type.
+    ///
+    /// The mirror type is never constructed or read by itself, so this is used
+    /// to prevent dead code warnings for it.
+    pub(crate) fn expand_remote_rebuild(&self, value: &syn::Ident) -> TokenStream {
+        fn rebuild(path: TokenStream, fields: &[FieldData<'_>]) -> TokenStream {
+            let fields = fields.iter().map(|f| {
+                let var = format_ident!("v{}", f.index);
+
+                let member = match f.ident {
+                    Some(ident) => syn::Member::Named(ident.clone()),
+                    None => syn::Member::Unnamed(syn::Index::from(f.index)),
+                };
+
+                quote!(#member: #var)
+            });
+
+            let fields = fields.collect::<Vec<_>>();
+            quote!(#path { #(#fields,)* } => #path { #(#fields,)* })
+        }
+
+        let arms = match &self.data {
+            Data::Struct(st) => vec![rebuild(quote!(Self), &st.fields)],
+            Data::Enum(en) => en
+                .variants
+                .iter()
+                .map(|v| {
+                    let ident = v.ident;
+                    rebuild(quote!(Self::#ident), &v.fields)
+                })
+                .collect(),
+            Data::Union => Vec::new(),
+        };
+
+        quote!(match #value { #(#arms,)* })
+    }
+
     /// Expand Decode implementation.
     pub(crate) fn expand_decode(&self) -> Result<TokenStream> {
         let modes = self.cx.modes();
@@ -237,6 +345,10 @@ impl<'a> Expander<'a> {
             out.extend(crate::de::expand_decode_entry(build)?);
         }
 
+        if let Some(remote) = self.type_attr.remote_type() {
+            out.extend(crate::de::expand_remote(self, remote));
+        }
+
         Ok(out)
     }
 }
@@ -256,6 +368,7 @@ pub(crate) fn expand_name(
     taggable: &dyn Taggable,
     mode: Mode<'_>,
     name_all: NameAll,
+    name_type: &syn::Type,
     ident: Option<&syn::Ident>,
 ) -> syn::Expr {
     let lit = 'out: {
@@ -269,7 +382,10 @@ pub(crate) fn expand_name(
             }
         }
 
-        usize_suffixed(taggable.index(), taggable.span()).into()
+        match integer_suffix(name_type) {
+            Some(suffix) => integer_suffixed(taggable.index(), suffix, taggable.span()).into(),
+            None => usize_suffixed(taggable.index(), taggable.span()).into(),
+        }
     };
 
     syn::Expr::Lit(syn::ExprLit {
@@ -278,12 +394,33 @@ pub(crate) fn expand_name(
     })
 }
 
+/// Primitive integer types.
+pub(crate) const INTEGERS: [&str; 12] = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
 /// Ensure that the given integer is usize-suffixed so that it is treated as the
 /// appropriate type.
 pub(crate) fn usize_suffixed(index: usize, span: Span) -> syn::LitInt {
     syn::LitInt::new(&format!("{}usize", index), span)
 }
 
+/// Construct an integer literal with the given suffix.
+fn integer_suffixed(index: usize, suffix: &str, span: Span) -> syn::LitInt {
+    syn::LitInt::new(&format!("{}{}", index, suffix), span)
+}
+
+/// Get the literal suffix to use for index names if the name type is a
+/// primitive integer, such as with `#[musli(name_type = u16)]`.
+fn integer_suffix(ty: &syn::Type) -> Option<&'static str> {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+
+    let ident = path.get_ident()?;
+    INTEGERS.into_iter().find(|integer| ident == integer)
+}
+
 impl Taggable for FieldData<'_> {
     fn span(&self) -> Span {
         self.span
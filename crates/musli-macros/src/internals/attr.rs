@@ -63,6 +63,9 @@ pub(crate) enum EnumTagging<'a> {
         tag: &'a syn::Expr,
         content: &'a syn::Expr,
     },
+    /// The variant is not tagged, and is determined by trying each variant in
+    /// order when decoding.
+    Untagged,
 }
 
 /// If the type is tagged or not.
@@ -207,13 +210,33 @@ layer! {
         tag: syn::Expr,
         /// If `#[musli(content = <expr>)]` is specified.
         content: syn::Expr,
+        /// If `#[musli(untagged)]` is specified.
+        untagged: (),
+        /// If `#[musli(deny_unknown_fields)]` is specified.
+        deny_unknown_fields: (),
+        /// If `#[musli(case_insensitive)]` is specified.
+        case_insensitive: (),
         /// `#[musli(packed)]` or `#[musli(transparent)]`.
         packing: Packing,
+        /// `#[musli(version = <int>)]`.
+        version: syn::LitInt,
+        /// `#[musli(validate = <path>)]`.
+        validate: syn::Path,
+        /// `#[musli(from = <type>)]`.
+        from_type: syn::Type,
+        /// `#[musli(into = <type>)]`.
+        into_type: syn::Type,
+        /// `#[musli(remote = <path>)]`.
+        remote: syn::Path,
+        /// If `#[musli(reflect)]` is specified.
+        reflect: (),
         @multiple
         /// Bounds in a where predicate.
         bounds: syn::WherePredicate,
         /// Bounds to require for a `Decode` implementation.
         decode_bounds: syn::WherePredicate,
+        /// `#[musli(upgrade = { <int> => <path>, .. })]`.
+        upgrades: (syn::LitInt, syn::Path),
     }
 }
 
@@ -225,13 +248,18 @@ impl TypeAttr {
     }
 
     pub(crate) fn enum_tagging_span(&self, mode: Mode<'_>) -> Option<Span> {
-        let tag = self.tag(mode);
-        let content = self.content(mode);
-        Some(tag.or(content)?.0)
+        let tag = self.tag(mode).map(|&(span, _)| span);
+        let content = self.content(mode).map(|&(span, _)| span);
+        let untagged = self.untagged(mode).map(|&(span, _)| span);
+        tag.or(content).or(untagged)
     }
 
     /// Indicates the state of enum tagging.
     pub(crate) fn enum_tagging(&self, mode: Mode<'_>) -> Option<EnumTagging<'_>> {
+        if self.untagged(mode).is_some() {
+            return Some(EnumTagging::Untagged);
+        }
+
         let (_, tag) = self.tag(mode)?;
 
         Some(match self.content(mode) {
@@ -240,6 +268,16 @@ impl TypeAttr {
         })
     }
 
+    /// Get the remote type being implemented for, if any.
+    pub(crate) fn remote_type(&self) -> Option<&syn::Path> {
+        self.root.remote.any.as_ref().map(|(_, path)| path)
+    }
+
+    /// Test if reflection metadata should be generated.
+    pub(crate) fn is_reflect(&self) -> bool {
+        self.root.reflect.any.is_some()
+    }
+
     /// Get the configured crate, or fallback to default.
     pub(crate) fn crate_or_default(&self, default: &str) -> syn::Path {
         if let Some((_, krate)) = self.root.krate.any.as_ref() {
@@ -296,6 +334,24 @@ pub(crate) fn type_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> TypeAttr {
                 return Ok(());
             }
 
+            // #[musli(untagged)]
+            if meta.path.is_ident("untagged") {
+                new.untagged.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(deny_unknown_fields)]
+            if meta.path.is_ident("deny_unknown_fields") {
+                new.deny_unknown_fields.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(case_insensitive)]
+            if meta.path.is_ident("case_insensitive") {
+                new.case_insensitive.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
             // #[musli(crate = <path>)]
             if meta.path.is_ident("crate") {
                 let path = if meta.input.parse::<Option<Token![=]>>()?.is_some() {
@@ -337,6 +393,54 @@ pub(crate) fn type_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> TypeAttr {
                 return Ok(());
             }
 
+            // #[musli(from = <type>)]
+            if meta.path.is_ident("from") {
+                meta.input.parse::<Token![=]>()?;
+                new.from_type.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
+            // #[musli(into = <type>)]
+            if meta.path.is_ident("into") {
+                meta.input.parse::<Token![=]>()?;
+                new.into_type.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
+            // #[musli(reflect)]
+            if meta.path.is_ident("reflect") {
+                new.reflect.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(remote = <path>)]
+            if meta.path.is_ident("remote") {
+                meta.input.parse::<Token![=]>()?;
+                new.remote.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
+            // #[musli(validate = <path>)]
+            if meta.path.is_ident("validate") {
+                meta.input.parse::<Token![=]>()?;
+                new.validate.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
+            // #[musli(version = <int>)]
+            if meta.path.is_ident("version") {
+                meta.input.parse::<Token![=]>()?;
+                new.version.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
+            // #[musli(upgrade = { <int> => <path>, .. })]
+            if meta.path.is_ident("upgrade") {
+                meta.input.parse::<Token![=]>()?;
+                parse_upgrades(&meta, &mut new.upgrades)?;
+                return Ok(());
+            }
+
             // #[musli(packed)]
             if meta.path.is_ident("packed") {
                 new.packing.push((meta.path.span(), Packing::Packed));
@@ -349,6 +453,13 @@ pub(crate) fn type_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> TypeAttr {
                 return Ok(());
             }
 
+            if meta.path.is_ident("rename_all") {
+                return Err(syn::Error::new_spanned(
+                    meta.path,
+                    "#[musli(rename_all = ..)] has been changed to #[musli(name_all = ..)]",
+                ));
+            }
+
             // #[musli(name_all = "..")]
             if meta.path.is_ident("name_all") {
                 new.name_all
@@ -444,6 +555,29 @@ fn parse_bounds(
     Ok(())
 }
 
+fn parse_upgrades(
+    meta: &syn::meta::ParseNestedMeta,
+    out: &mut Vec<(Span, (syn::LitInt, syn::Path))>,
+) -> syn::Result<()> {
+    let content;
+    syn::braced!(content in meta.input);
+
+    let upgrades = content.parse_terminated(
+        |input| {
+            let version: syn::LitInt = input.parse()?;
+            input.parse::<Token![=>]>()?;
+            Ok((version, input.parse::<syn::Path>()?))
+        },
+        Token![,],
+    )?;
+
+    for (version, path) in upgrades {
+        out.push((version.span(), (version, path)));
+    }
+
+    Ok(())
+}
+
 layer! {
     VariantAttr, VariantLayerNew, VariantLayer {
         /// `#[musli(name_type)]`.
@@ -462,7 +596,11 @@ layer! {
         packing: Packing,
         /// `#[musli(default)]`.
         default_variant: (),
+        /// `#[musli(skip)]`.
+        skip: (),
         @multiple
+        /// `#[musli(alias = ..)]`.
+        aliases: syn::Expr,
     }
 }
 
@@ -542,12 +680,32 @@ pub(crate) fn variant_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> VariantAttr
                 return Ok(());
             }
 
+            // #[musli(alias = <expr>)]
+            if meta.path.is_ident("alias") {
+                meta.input.parse::<Token![=]>()?;
+                new.aliases.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
+            if meta.path.is_ident("other") {
+                return Err(syn::Error::new_spanned(
+                    meta.path,
+                    "#[musli(other)] is not supported, use #[musli(default)] to mark the fallback variant",
+                ));
+            }
+
             // #[musli(default)]
             if meta.path.is_ident("default") {
                 new.default_variant.push((meta.path.span(), ()));
                 return Ok(());
             }
 
+            // #[musli(skip)]
+            if meta.path.is_ident("skip") {
+                new.skip.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
             // #[musli(packed)]
             if meta.path.is_ident("packed") {
                 new.packing.push((meta.path.span(), Packing::Packed));
@@ -560,6 +718,13 @@ pub(crate) fn variant_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> VariantAttr
                 return Ok(());
             }
 
+            if meta.path.is_ident("rename_all") {
+                return Err(syn::Error::new_spanned(
+                    meta.path,
+                    "#[musli(rename_all = ..)] has been changed to #[musli(name_all = ..)]",
+                ));
+            }
+
             // #[musli(name_all = "..")]
             if meta.path.is_ident("name_all") {
                 new.name_all
@@ -610,7 +775,7 @@ pub(crate) enum FieldEncoding {
 
 layer! {
     Field, FieldNew, FieldLayer {
-        /// Module to use when decoding.
+        /// Path to use when encoding.
         encode_path: syn::Path,
         /// Path to use when decoding.
         decode_path: syn::Path,
@@ -624,9 +789,19 @@ layer! {
         is_default: Option<syn::Path>,
         /// Use a default value for the field if it's not available.
         skip: (),
+        /// Encode and decode the entries of the field as if they were part of
+        /// the containing struct.
+        flatten: (),
+        /// Collect fields which are not recognized by the containing struct
+        /// into this field.
+        unknown_fields: (),
+        /// Borrow the field from the lifetime used by the decoder.
+        borrow: (),
         /// Field encoding to use.
         encoding: FieldEncoding,
         @multiple
+        /// `#[musli(alias = ..)]`.
+        aliases: syn::Expr,
     }
 }
 
@@ -644,6 +819,21 @@ impl Field {
         }
     }
 
+    /// Expand decoding of the given field into an existing value, which is
+    /// only available when the field uses the default encoding.
+    pub(crate) fn decode_into_path_expanded(&self, mode: Mode<'_>) -> Option<syn::Path> {
+        if self.decode_path(mode).is_some() {
+            return None;
+        }
+
+        let field_encoding = self.encoding(mode).map(|&(_, e)| e).unwrap_or_default();
+
+        match field_encoding {
+            FieldEncoding::Default => Some(mode.decode_t_decode_into()),
+            _ => None,
+        }
+    }
+
     /// Expand decode of the given field.
     pub(crate) fn decode_path_expanded(&self, mode: Mode<'_>, span: Span) -> (Span, syn::Path) {
         let decode_path = self.decode_path(mode);
@@ -723,6 +913,22 @@ pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
                 return Ok(());
             }
 
+            // #[musli(encode_with = <path>)]
+            if meta.path.is_ident("encode_with") {
+                meta.input.parse::<Token![=]>()?;
+                let path = meta.input.parse::<syn::Path>()?;
+                new.encode_path.push((path.span(), path));
+                return Ok(());
+            }
+
+            // #[musli(decode_with = <path>)]
+            if meta.path.is_ident("decode_with") {
+                meta.input.parse::<Token![=]>()?;
+                let path = meta.input.parse::<syn::Path>()?;
+                new.decode_path.push((path.span(), path));
+                return Ok(());
+            }
+
             // #[musli(skip_encoding_if = <path>)]
             if meta.path.is_ident("skip_encoding_if") {
                 meta.input.parse::<Token![=]>()?;
@@ -753,6 +959,13 @@ pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
                 return Ok(());
             }
 
+            // #[musli(alias = <expr>)]
+            if meta.path.is_ident("alias") {
+                meta.input.parse::<Token![=]>()?;
+                new.aliases.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
             // #[musli(default)]
             if meta.path.is_ident("default") {
                 if meta.input.parse::<Option<Token![=]>>()?.is_some() {
@@ -771,6 +984,24 @@ pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
                 return Ok(());
             }
 
+            // #[musli(flatten)]
+            if meta.path.is_ident("flatten") {
+                new.flatten.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(unknown_fields)]
+            if meta.path.is_ident("unknown_fields") {
+                new.unknown_fields.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(borrow)]
+            if meta.path.is_ident("borrow") {
+                new.borrow.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
             // #[musli(trace)]
             if meta.path.is_ident("trace") {
                 new.encoding.push((meta.path.span(), FieldEncoding::Trace));
@@ -27,6 +27,14 @@ pub(crate) struct Build<'a> {
     pub(crate) decode_t_decode: syn::Path,
     pub(crate) encode_t_encode: syn::Path,
     pub(crate) enum_tagging_span: Option<Span>,
+    pub(crate) version: Option<Version<'a>>,
+    pub(crate) validate: Option<&'a syn::Path>,
+    pub(crate) from_type: Option<&'a syn::Type>,
+    pub(crate) into_type: Option<&'a syn::Type>,
+    /// The remote type which implementations are generated for.
+    pub(crate) remote: Option<&'a syn::Path>,
+    /// The lifetime which `#[musli(borrow)]` fields borrow from.
+    pub(crate) borrow: Option<syn::Lifetime>,
 }
 
 impl Build<'_> {
@@ -39,15 +47,6 @@ impl Build<'_> {
         );
     }
 
-    /// Emit diagnostics indicating that we tried to implement decode for a
-    /// packed enum.
-    pub(crate) fn decode_packed_enum_diagnostics(&self, span: Span) {
-        self.cx.error_span(
-            span,
-            format_args!("#[{ATTR}(packed)] cannot be used to decode enums"),
-        );
-    }
-
     /// Emit diagnostics indicating that we tried to use a `#[musli(default)]`
     /// annotation on a packed container.
     pub(crate) fn packed_default_diagnostics(&self, span: Span) {
@@ -74,7 +73,7 @@ impl Build<'_> {
                     self.cx.error_span(
                         span,
                         format_args!(
-                            "#[{ATTR}(tag)] and #[{ATTR}(content)] are only supported on enums"
+                            "#[{ATTR}(tag)], #[{ATTR}(content)] and #[{ATTR}(untagged)] are only supported on enums"
                         ),
                     );
 
@@ -94,6 +93,24 @@ pub(crate) enum BuildData<'a> {
     Enum(Enum<'a>),
 }
 
+impl BuildData<'_> {
+    /// The name of the container.
+    pub(crate) fn name(&self) -> &syn::LitStr {
+        match self {
+            BuildData::Struct(st) => st.name,
+            BuildData::Enum(en) => en.name,
+        }
+    }
+}
+
+/// The version of a container as specified through `#[musli(version)]`.
+pub(crate) struct Version<'a> {
+    /// The current version of the container.
+    pub(crate) version: u32,
+    /// Functions used to upgrade from older versions.
+    pub(crate) upgrades: Vec<(u32, &'a syn::Path)>,
+}
+
 pub(crate) struct Body<'a> {
     pub(crate) span: Span,
     pub(crate) name: &'a syn::LitStr,
@@ -105,6 +122,11 @@ pub(crate) struct Body<'a> {
     pub(crate) packing: Packing,
     pub(crate) kind: StructKind,
     pub(crate) path: syn::Path,
+    /// Raise an error when an unknown field is encountered instead of
+    /// skipping it.
+    pub(crate) deny_unknown_fields: Option<Span>,
+    /// Match field names without regard to ASCII case.
+    pub(crate) case_insensitive: bool,
 }
 
 impl Body<'_> {
@@ -112,6 +134,75 @@ impl Body<'_> {
         if self.packing == Packing::Transparent && !matches!(&self.unskipped_fields[..], [_]) {
             cx.transparent_diagnostics(self.span, &self.unskipped_fields);
         }
+
+        if self.deny_unknown_fields.is_some() {
+            for f in &self.unskipped_fields {
+                if let Some(span) = f.flatten {
+                    cx.error_span(
+                        span,
+                        format_args!(
+                            "#[{ATTR}(flatten)] fields cannot be used in a container with #[{ATTR}(deny_unknown_fields)]"
+                        ),
+                    );
+                }
+
+                if let Some(span) = f.unknown_fields {
+                    cx.error_span(
+                        span,
+                        format_args!(
+                            "#[{ATTR}(unknown_fields)] fields cannot be used in a container with #[{ATTR}(deny_unknown_fields)]"
+                        ),
+                    );
+                }
+            }
+        }
+
+        if self.packing != Packing::Tagged {
+            for f in &self.unskipped_fields {
+                if let Some(span) = f.flatten {
+                    cx.error_span(
+                        span,
+                        format_args!(
+                            "#[{ATTR}(flatten)] fields cannot be used in a packed or transparent container"
+                        ),
+                    );
+                }
+
+                if let Some(span) = f.unknown_fields {
+                    cx.error_span(
+                        span,
+                        format_args!(
+                            "#[{ATTR}(unknown_fields)] fields cannot be used in a packed or transparent container"
+                        ),
+                    );
+                }
+            }
+        }
+
+        let mut unknown_fields = self
+            .unskipped_fields
+            .iter()
+            .filter_map(|f| f.unknown_fields);
+
+        if unknown_fields.next().is_some() {
+            for span in unknown_fields {
+                cx.error_span(
+                    span,
+                    format_args!("#[{ATTR}(unknown_fields)] can only be used on one field"),
+                );
+            }
+
+            for f in &self.unskipped_fields {
+                if let Some(span) = f.flatten {
+                    cx.error_span(
+                        span,
+                        format_args!(
+                            "#[{ATTR}(flatten)] fields cannot be used in a container with an #[{ATTR}(unknown_fields)] field"
+                        ),
+                    );
+                }
+            }
+        }
     }
 
     pub(crate) fn name_format(&self, value: &syn::Expr) -> syn::Expr {
@@ -140,11 +231,15 @@ pub(crate) struct Enum<'a> {
     pub(crate) enum_tagging: EnumTagging<'a>,
     pub(crate) enum_packing: Packing,
     pub(crate) variants: Vec<Variant<'a>>,
+    /// Variants marked with `#[musli(skip)]`, which can't be encoded.
+    pub(crate) skipped: Vec<&'a syn::Ident>,
     pub(crate) fallback: Option<&'a syn::Ident>,
     pub(crate) name_type: syn::Type,
     pub(crate) name_method: NameMethod,
     pub(crate) name_format_with: Option<&'a (Span, syn::Path)>,
     pub(crate) packing_span: Option<&'a (Span, Packing)>,
+    /// Match variant names without regard to ASCII case.
+    pub(crate) case_insensitive: bool,
 }
 
 impl Enum<'_> {
@@ -184,6 +279,7 @@ pub(crate) struct Variant<'a> {
     pub(crate) index: usize,
     pub(crate) name: syn::Expr,
     pub(crate) pattern: Option<&'a syn::Pat>,
+    pub(crate) aliases: Vec<&'a syn::Expr>,
     pub(crate) st: Body<'a>,
     pub(crate) patterns: Punctuated<syn::FieldPat, Token![,]>,
 }
@@ -193,12 +289,22 @@ pub(crate) struct Field<'a> {
     pub(crate) index: usize,
     pub(crate) encode_path: (Span, syn::Path),
     pub(crate) decode_path: (Span, syn::Path),
+    /// Path used to decode the field into an existing value, if supported.
+    pub(crate) decode_into_path: Option<syn::Path>,
     pub(crate) name: syn::Expr,
     pub(crate) pattern: Option<&'a syn::Pat>,
+    pub(crate) aliases: Vec<&'a syn::Expr>,
     /// Skip field entirely and always initialize with the specified expresion,
     /// or default value through `default_attr`.
     pub(crate) skip: Option<Span>,
     pub(crate) skip_encoding_if: Option<&'a (Span, syn::Path)>,
+    /// Encode and decode the entries of the field as part of the containing
+    /// struct.
+    pub(crate) flatten: Option<Span>,
+    /// Collect fields which are unknown to the containing struct.
+    pub(crate) unknown_fields: Option<Span>,
+    /// Borrow the field from the lifetime of the decoder.
+    pub(crate) borrow: Option<Span>,
     /// Fill with default value, if missing.
     pub(crate) default_attr: Option<(Span, Option<&'a syn::Path>)>,
     pub(crate) self_access: syn::Expr,
@@ -227,6 +333,9 @@ pub(crate) fn setup<'a>(
         }
     };
 
+    let version = setup_version(e, mode);
+    let borrow = setup_borrow(e, &data);
+
     if e.cx.has_errors() {
         return Err(());
     }
@@ -242,9 +351,164 @@ pub(crate) fn setup<'a>(
         decode_t_decode: mode.decode_t_decode(FieldEncoding::Default),
         encode_t_encode: mode.encode_t_encode(FieldEncoding::Default),
         enum_tagging_span: e.type_attr.enum_tagging_span(mode),
+        version,
+        validate: e.type_attr.validate(mode).map(|(_, path)| path),
+        from_type: e.type_attr.from_type(mode).map(|(_, ty)| ty),
+        into_type: e.type_attr.into_type(mode).map(|(_, ty)| ty),
+        remote: e.type_attr.remote_type(),
+        borrow,
     })
 }
 
+fn setup_version<'a>(e: &'a Expander, mode: Mode<'_>) -> Option<Version<'a>> {
+    let upgrades = e.type_attr.upgrades(mode);
+
+    let Some((_, version)) = e.type_attr.version(mode) else {
+        for (span, _) in upgrades {
+            e.cx.error_span(
+                *span,
+                format_args!("#[{ATTR}(upgrade)] requires #[{ATTR}(version)] to be specified"),
+            );
+        }
+
+        return None;
+    };
+
+    let version = match version.base10_parse::<u32>() {
+        Ok(version) => version,
+        Err(error) => {
+            e.cx.syn_error(error);
+            return None;
+        }
+    };
+
+    let mut output = Vec::with_capacity(upgrades.len());
+
+    for (span, (from, path)) in upgrades {
+        let from = match from.base10_parse::<u32>() {
+            Ok(from) => from,
+            Err(error) => {
+                e.cx.syn_error(error);
+                continue;
+            }
+        };
+
+        if from >= version {
+            e.cx.error_span(
+                *span,
+                format_args!("#[{ATTR}(upgrade)] from version {from} must be older than the current version {version}"),
+            );
+        } else if output.iter().any(|&(v, _)| v == from) {
+            e.cx.error_span(
+                *span,
+                format_args!("#[{ATTR}(upgrade)] from version {from} is specified multiple times"),
+            );
+        } else {
+            output.push((from, path));
+        }
+    }
+
+    Some(Version {
+        version,
+        upgrades: output,
+    })
+}
+
+/// Find the lifetime that `#[musli(borrow)]` fields borrow from, which is used
+/// as the lifetime of the decoder.
+fn setup_borrow(e: &Expander, data: &BuildData<'_>) -> Option<syn::Lifetime> {
+    let fields: Vec<&Field<'_>> = match data {
+        BuildData::Struct(st) => st.all_fields.iter().map(|f| &**f).collect(),
+        BuildData::Enum(en) => en
+            .variants
+            .iter()
+            .flat_map(|v| v.st.all_fields.iter().map(|f| &**f))
+            .collect(),
+    };
+
+    let mut borrow = None::<syn::Lifetime>;
+
+    for f in fields {
+        let Some(span) = f.borrow else {
+            continue;
+        };
+
+        let mut lifetimes = Vec::new();
+        type_lifetimes(f.ty, &mut lifetimes);
+
+        if lifetimes.is_empty() {
+            e.cx.error_span(
+                span,
+                format_args!(
+                    "#[{ATTR}(borrow)] requires a field type with a lifetime to borrow from"
+                ),
+            );
+
+            continue;
+        }
+
+        for lt in lifetimes {
+            match &borrow {
+                Some(existing) if *existing != *lt => {
+                    e.cx.error_span(
+                        span,
+                        format_args!("#[{ATTR}(borrow)] fields must all borrow from the same lifetime, found both `{existing}` and `{lt}`"),
+                    );
+                }
+                Some(..) => {}
+                None => {
+                    if !e.input.generics.lifetimes().any(|p| p.lifetime == *lt) {
+                        e.cx.error_span(
+                            span,
+                            format_args!("#[{ATTR}(borrow)] can only borrow from a lifetime parameter of the type, but `{lt}` is not"),
+                        );
+                    }
+
+                    borrow = Some(lt.clone());
+                }
+            }
+        }
+    }
+
+    borrow
+}
+
+/// Collect all lifetimes mentioned in a type.
+fn type_lifetimes<'a>(ty: &'a syn::Type, out: &mut Vec<&'a syn::Lifetime>) {
+    match ty {
+        syn::Type::Reference(ty) => {
+            out.extend(&ty.lifetime);
+            type_lifetimes(&ty.elem, out);
+        }
+        syn::Type::Array(ty) => type_lifetimes(&ty.elem, out),
+        syn::Type::Slice(ty) => type_lifetimes(&ty.elem, out),
+        syn::Type::Group(ty) => type_lifetimes(&ty.elem, out),
+        syn::Type::Paren(ty) => type_lifetimes(&ty.elem, out),
+        syn::Type::Ptr(ty) => type_lifetimes(&ty.elem, out),
+        syn::Type::Tuple(ty) => {
+            for elem in &ty.elems {
+                type_lifetimes(elem, out);
+            }
+        }
+        syn::Type::Path(ty) => {
+            for segment in &ty.path.segments {
+                let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                    continue;
+                };
+
+                for arg in &args.args {
+                    match arg {
+                        syn::GenericArgument::Lifetime(lt) => out.push(lt),
+                        syn::GenericArgument::Type(ty) => type_lifetimes(ty, out),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
 fn setup_struct<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a StructData<'a>) -> Body<'a> {
     let mut unskipped_fields = Vec::with_capacity(data.fields.len());
     let mut all_fields = Vec::with_capacity(data.fields.len());
@@ -255,6 +519,13 @@ fn setup_struct<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a StructData<'a>) -
         .map(|&(_, p)| p)
         .unwrap_or_default();
 
+    if let Some(&(span, NameAll::Discriminant)) = e.type_attr.name_all(mode) {
+        e.cx.error_span(
+            span,
+            format_args!("#[{ATTR}(name_all = \"discriminant\")] is only supported on enums"),
+        );
+    }
+
     let (name_all, name_type, name_method) = match data.kind {
         StructKind::Indexed(..) if e.type_attr.is_name_type_ambiguous(mode) => {
             let name_all = NameAll::Index;
@@ -271,7 +542,7 @@ fn setup_struct<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a StructData<'a>) -
     let path = syn::Path::from(syn::Ident::new("Self", e.input.ident.span()));
 
     for f in &data.fields {
-        let field = Rc::new(setup_field(e, mode, f, name_all, packing, None));
+        let field = Rc::new(setup_field(e, mode, f, name_all, &name_type, packing, None));
 
         if field.skip.is_none() {
             unskipped_fields.push(field.clone());
@@ -291,6 +562,11 @@ fn setup_struct<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a StructData<'a>) -
         packing,
         kind: data.kind,
         path,
+        deny_unknown_fields: e
+            .type_attr
+            .deny_unknown_fields(mode)
+            .map(|&(span, ())| span),
+        case_insensitive: is_case_insensitive(e, mode, name_method),
     };
 
     body.validate(&e.cx);
@@ -318,7 +594,40 @@ fn setup_enum<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a EnumData<'a>) -> En
         }
     };
 
-    if !matches!(enum_tagging, EnumTagging::Default | EnumTagging::Empty) {
+    if let (EnumTagging::Untagged, Some((span, _))) = (
+        &enum_tagging,
+        e.type_attr.tag(mode).or(e.type_attr.content(mode)),
+    ) {
+        e.cx.error_span(
+            *span,
+            format_args!(
+                "#[{ATTR}(untagged)] cannot be combined with #[{ATTR}(tag)] or #[{ATTR}(content)]"
+            ),
+        );
+    }
+
+    if let (EnumTagging::Untagged, Some(&(span, Packing::Packed | Packing::Transparent))) =
+        (&enum_tagging, packing_span)
+    {
+        e.cx.error_span(
+            span,
+            format_args!("#[{ATTR}(untagged)] cannot be combined with #[{ATTR}(packed)] or #[{ATTR}(transparent)]"),
+        );
+    }
+
+    if let (EnumTagging::Internal { .. }, Some(&(span, ()))) =
+        (&enum_tagging, e.type_attr.deny_unknown_fields(mode))
+    {
+        e.cx.error_span(
+            span,
+            format_args!("#[{ATTR}(deny_unknown_fields)] cannot be combined with #[{ATTR}(tag)] unless #[{ATTR}(content)] is also specified"),
+        );
+    }
+
+    if matches!(
+        enum_tagging,
+        EnumTagging::Internal { .. } | EnumTagging::Adjacent { .. }
+    ) {
         match packing_span {
             Some((_, Packing::Tagged)) => (),
             Some(&(span, Packing::Packed)) => {
@@ -337,15 +646,55 @@ fn setup_enum<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a EnumData<'a>) -> En
         .map(|&(_, p)| p)
         .unwrap_or_default();
 
-    let (_, name_type, name_method) = split_name(
-        mode.kind,
-        e.type_attr.name_type(mode),
-        e.type_attr.name_all(mode),
-        e.type_attr.name_method(mode),
-    );
+    let (name_all, name_type, name_method) = split_enum_name(e, mode);
+
+    let mut discriminants = match name_all {
+        NameAll::Discriminant => discriminants(data),
+        _ => Vec::new(),
+    }
+    .into_iter();
+
+    let mut skipped = Vec::new();
 
     for v in &data.variants {
-        variants.push(setup_variant(e, mode, v, &mut fallback));
+        let discriminant = discriminants.next();
+
+        if let Some(&(span, ())) = v.attr.skip(mode) {
+            if v.attr.default_variant(mode).is_some() {
+                e.cx.error_span(
+                    span,
+                    format_args!("#[{ATTR}(skip)] cannot be used on a #[{ATTR}(default)] variant"),
+                );
+            }
+
+            skipped.push(v.ident);
+            continue;
+        }
+
+        variants.push(setup_variant(e, mode, v, discriminant, &mut fallback));
+    }
+
+    // Variants of a packed enum are encoded as a tag followed by their fields
+    // in the same pack. There's no way to skip over the fields of an unknown
+    // variant, so no fallback can be supported.
+    if let (EnumTagging::Default, Packing::Packed) = (&enum_tagging, enum_packing) {
+        for v in &data.variants {
+            if let Some(&(span, Packing::Transparent)) = v.attr.packing(mode) {
+                e.cx.error_span(
+                    span,
+                    format_args!("#[{ATTR}(transparent)] variants cannot be used in a #[{ATTR}(packed)] enum"),
+                );
+            }
+
+            if let Some(&(span, ())) = v.attr.default_variant(mode) {
+                e.cx.error_span(
+                    span,
+                    format_args!(
+                        "#[{ATTR}(default)] variants cannot be used in a #[{ATTR}(packed)] enum"
+                    ),
+                );
+            }
+        }
     }
 
     Enum {
@@ -354,18 +703,27 @@ fn setup_enum<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a EnumData<'a>) -> En
         enum_tagging,
         enum_packing,
         variants,
+        skipped,
         fallback,
         name_type,
         name_method,
         name_format_with: e.type_attr.name_format_with(mode),
         packing_span,
+        case_insensitive: is_case_insensitive(e, mode, name_method),
     }
 }
 
+/// Names are only matched without regard to case if they are decoded as
+/// strings.
+fn is_case_insensitive(e: &Expander, mode: Mode<'_>, name_method: NameMethod) -> bool {
+    e.type_attr.case_insensitive(mode).is_some() && matches!(name_method, NameMethod::Unsized(..))
+}
+
 fn setup_variant<'a>(
     e: &'a Expander<'_>,
     mode: Mode<'_>,
     data: &'a VariantData<'a>,
+    discriminant: Option<syn::Expr>,
     fallback: &mut Option<&'a syn::Ident>,
 ) -> Variant<'a> {
     let mut unskipped_fields = Vec::with_capacity(data.fields.len());
@@ -378,6 +736,13 @@ fn setup_variant<'a>(
         .map(|&(_, v)| v)
         .unwrap_or_default();
 
+    if let Some(&(span, NameAll::Discriminant)) = data.attr.name_all(mode) {
+        e.cx.error_span(
+            span,
+            format_args!("#[{ATTR}(name_all = \"discriminant\")] is only supported on enums"),
+        );
+    }
+
     let (name_all, name_type, name_method) = match data.kind {
         StructKind::Indexed(..) if data.attr.is_name_type_ambiguous(mode) => {
             let name_all = NameAll::Index;
@@ -391,16 +756,15 @@ fn setup_variant<'a>(
         ),
     };
 
-    let (type_name_all, _, _) = split_name(
-        mode.kind,
-        e.type_attr.name_type(mode),
-        e.type_attr.name_all(mode),
-        e.type_attr.name_method(mode),
-    );
+    let (type_name_all, type_name_type, _) = split_enum_name(e, mode);
 
-    let name = expander::expand_name(data, mode, type_name_all, Some(data.ident));
+    let name = match discriminant {
+        Some(discriminant) if data.attr.name(mode).is_none() => discriminant,
+        _ => expander::expand_name(data, mode, type_name_all, &type_name_type, Some(data.ident)),
+    };
 
     let pattern = data.attr.pattern(mode).map(|(_, p)| p);
+    let aliases = setup_aliases(e, pattern, data.attr.aliases(mode));
 
     let mut path = syn::Path::from(syn::Ident::new("Self", data.span));
     path.segments.push(data.ident.clone().into());
@@ -429,10 +793,25 @@ fn setup_variant<'a>(
             mode,
             f,
             name_all,
+            &name_type,
             variant_packing,
             Some(&mut patterns),
         ));
 
+        if let Some(span) = field.flatten {
+            e.cx.error_span(
+                span,
+                format_args!("#[{ATTR}(flatten)] is only supported on fields in structs"),
+            );
+        }
+
+        if let Some(span) = field.unknown_fields {
+            e.cx.error_span(
+                span,
+                format_args!("#[{ATTR}(unknown_fields)] is only supported on fields in structs"),
+            );
+        }
+
         if field.skip.is_none() {
             unskipped_fields.push(field.clone());
         }
@@ -451,6 +830,11 @@ fn setup_variant<'a>(
         name_method,
         name_format_with: data.attr.name_format_with(mode),
         path,
+        deny_unknown_fields: e
+            .type_attr
+            .deny_unknown_fields(mode)
+            .map(|&(span, ())| span),
+        case_insensitive: is_case_insensitive(e, mode, name_method),
     };
 
     st.validate(&e.cx);
@@ -460,6 +844,7 @@ fn setup_variant<'a>(
         index: data.index,
         name,
         pattern,
+        aliases,
         patterns,
         st,
     }
@@ -470,17 +855,48 @@ fn setup_field<'a>(
     mode: Mode<'_>,
     data: &'a FieldData<'a>,
     name_all: NameAll,
+    name_type: &syn::Type,
     packing: Packing,
     patterns: Option<&mut Punctuated<syn::FieldPat, Token![,]>>,
 ) -> Field<'a> {
     let encode_path = data.attr.encode_path_expanded(mode, data.span);
     let decode_path = data.attr.decode_path_expanded(mode, data.span);
+    let decode_into_path = data.attr.decode_into_path_expanded(mode);
 
-    let name = expander::expand_name(data, mode, name_all, data.ident);
+    let name = expander::expand_name(data, mode, name_all, name_type, data.ident);
     let pattern = data.attr.pattern(mode).map(|(_, p)| p);
+    let aliases = setup_aliases(e, pattern, data.attr.aliases(mode));
 
     let skip = data.attr.skip(mode).map(|&(s, ())| s);
     let skip_encoding_if = data.attr.skip_encoding_if(mode);
+    let flatten = data.attr.flatten(mode).map(|&(s, ())| s);
+
+    let unknown_fields = data.attr.unknown_fields(mode).map(|&(s, ())| s);
+    let borrow = data.attr.borrow(mode).map(|&(s, ())| s);
+
+    if let (Some(span), Some(..)) = (flatten, skip_encoding_if) {
+        e.cx.error_span(
+            span,
+            format_args!("#[{ATTR}(flatten)] cannot be combined with #[{ATTR}(skip_encoding_if)]"),
+        );
+    }
+
+    if let (Some(span), Some(..)) = (unknown_fields, skip_encoding_if) {
+        e.cx.error_span(
+            span,
+            format_args!(
+                "#[{ATTR}(unknown_fields)] cannot be combined with #[{ATTR}(skip_encoding_if)]"
+            ),
+        );
+    }
+
+    if let (Some(span), Some(..)) = (unknown_fields, flatten) {
+        e.cx.error_span(
+            span,
+            format_args!("#[{ATTR}(unknown_fields)] cannot be combined with #[{ATTR}(flatten)]"),
+        );
+    }
+
     let default_attr = data
         .attr
         .is_default(mode)
@@ -567,10 +983,15 @@ fn setup_field<'a>(
         index: data.index,
         encode_path,
         decode_path,
+        decode_into_path,
         name,
         pattern,
+        aliases,
         skip,
         skip_encoding_if,
+        flatten,
+        unknown_fields,
+        borrow,
         default_attr,
         self_access,
         member,
@@ -580,6 +1001,24 @@ fn setup_field<'a>(
     }
 }
 
+/// Collect the aliases of a field or variant.
+fn setup_aliases<'a>(
+    e: &Expander,
+    pattern: Option<&syn::Pat>,
+    aliases: &'a [(Span, syn::Expr)],
+) -> Vec<&'a syn::Expr> {
+    if pattern.is_some() {
+        for (span, _) in aliases {
+            e.cx.error_span(
+                *span,
+                format_args!("#[{ATTR}(alias)] cannot be combined with #[{ATTR}(pattern)]"),
+            );
+        }
+    }
+
+    aliases.iter().map(|(_, alias)| alias).collect()
+}
+
 fn split_name(
     kind: Option<&ModeKind>,
     name_type: Option<&(Span, syn::Type)>,
@@ -606,6 +1045,107 @@ fn split_name(
     (name_all, name_type.clone(), name_method)
 }
 
+/// Split the names used by an enum.
+///
+/// Variants which are named by their discriminant use the integer
+/// representation of the enum as their name type unless one is specified.
+fn split_enum_name(e: &Expander, mode: Mode<'_>) -> (NameAll, syn::Type, NameMethod) {
+    let (name_all, name_type, name_method) = split_name(
+        mode.kind,
+        e.type_attr.name_type(mode),
+        e.type_attr.name_all(mode),
+        e.type_attr.name_method(mode),
+    );
+
+    if let (NameAll::Discriminant, None) = (name_all, e.type_attr.name_type(mode)) {
+        if let Some(repr) = repr_type(&e.input.attrs) {
+            return (name_all, repr, name_method);
+        }
+    }
+
+    (name_all, name_type, name_method)
+}
+
+/// Find the integer type used in a `#[repr(..)]` attribute.
+fn repr_type(attrs: &[syn::Attribute]) -> Option<syn::Type> {
+    let mut repr = None;
+
+    for attr in attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+
+        // Other representations, like `C`, can be ignored.
+        let _ = attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if expander::INTEGERS.iter().any(|integer| ident == integer) {
+                    repr = Some(syn::Type::Path(syn::TypePath {
+                        qself: None,
+                        path: ident.clone().into(),
+                    }));
+                }
+            }
+
+            Ok(())
+        });
+    }
+
+    repr
+}
+
+/// Compute the discriminant of every variant.
+///
+/// Like the compiler, a variant without an explicit discriminant has the
+/// discriminant of the previous variant plus one.
+fn discriminants(data: &EnumData<'_>) -> Vec<syn::Expr> {
+    let mut output = Vec::with_capacity(data.variants.len());
+    let mut base = None;
+    let mut offset = 0u128;
+
+    for v in &data.variants {
+        if let Some(expr) = v.discriminant {
+            base = Some(expr);
+            offset = 0;
+        }
+
+        let literal = |value: u128| {
+            syn::Expr::Lit(syn::ExprLit {
+                attrs: Vec::new(),
+                lit: syn::LitInt::new(&value.to_string(), v.span).into(),
+            })
+        };
+
+        let expr = match base {
+            None => literal(offset),
+            Some(expr) => match int_literal(expr) {
+                Some(value) => literal(value.saturating_add(offset)),
+                None if offset == 0 => expr.clone(),
+                None => {
+                    let offset = syn::LitInt::new(&offset.to_string(), v.span);
+                    syn::parse_quote!((#expr) + #offset)
+                }
+            },
+        };
+
+        output.push(expr);
+        offset += 1;
+    }
+
+    output
+}
+
+fn int_literal(expr: &syn::Expr) -> Option<u128> {
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(lit),
+        ..
+    }) = expr
+    else {
+        return None;
+    };
+
+    lit.base10_parse().ok()
+}
+
 fn determine_name_method(ty: &syn::Type) -> (NameMethod, Option<NameAll>) {
     match ty {
         syn::Type::Path(syn::TypePath { qself: None, path }) if path.is_ident("str") => {
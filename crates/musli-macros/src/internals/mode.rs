@@ -86,6 +86,24 @@ impl<'a> Mode<'a> {
 
         decode_t
     }
+
+    /// Construct a typed call which decodes into an existing value.
+    pub(crate) fn decode_t_decode_into(&self) -> syn::Path {
+        let mut decode_t = self.tokens.decode_t.clone();
+
+        if let Some(segment) = decode_t.segments.last_mut() {
+            add_mode_argument(&self.mode_path, segment);
+        }
+
+        decode_t
+            .segments
+            .push(syn::PathSegment::from(syn::Ident::new(
+                "decode_into",
+                decode_t.span(),
+            )));
+
+        decode_t
+    }
 }
 
 fn add_mode_argument(moded_ident: &ModePath<'_>, last: &mut syn::PathSegment) {
@@ -11,6 +11,8 @@ pub(crate) enum NameAll {
     Index,
     /// Fields are named by their original name.
     Name,
+    /// Variants are named by their discriminant.
+    Discriminant,
     PascalCase,
     CamelCase,
     SnakeCase,
@@ -23,6 +25,7 @@ impl NameAll {
     pub(crate) const ALL: &'static [Self] = &[
         Self::Index,
         Self::Name,
+        Self::Discriminant,
         Self::PascalCase,
         Self::CamelCase,
         Self::SnakeCase,
@@ -34,13 +37,14 @@ impl NameAll {
     pub(crate) fn ty(&self) -> syn::Type {
         match self {
             NameAll::Index => syn::parse_quote! { usize },
+            NameAll::Discriminant => syn::parse_quote! { isize },
             _ => syn::parse_quote! { str },
         }
     }
 
     pub(crate) fn name_method(&self) -> NameMethod {
         match self {
-            NameAll::Index => NameMethod::Value,
+            NameAll::Index | NameAll::Discriminant => NameMethod::Value,
             _ => NameMethod::Unsized(UnsizedMethod::Default),
         }
     }
@@ -49,6 +53,7 @@ impl NameAll {
         match input {
             "index" => Some(Self::Index),
             "name" => Some(Self::Name),
+            "discriminant" => Some(Self::Discriminant),
             "PascalCase" => Some(Self::PascalCase),
             "camelCase" => Some(Self::CamelCase),
             "snake_case" => Some(Self::SnakeCase),
@@ -62,7 +67,7 @@ impl NameAll {
     /// Apply the given rename to the input string.
     pub(crate) fn apply(&self, input: &str) -> Option<String> {
         let feed: fn(output: &mut String, open: bool, count: usize, c: char) = match self {
-            Self::Index => return None,
+            Self::Index | Self::Discriminant => return None,
             Self::Name => return Some(input.to_string()),
             Self::PascalCase => |output, open, _, c| {
                 if open {
@@ -137,6 +142,7 @@ impl fmt::Display for NameAll {
         match self {
             Self::Index => write!(f, "index"),
             Self::Name => write!(f, "name"),
+            Self::Discriminant => write!(f, "discriminant"),
             Self::PascalCase => write!(f, "PascalCase"),
             Self::CamelCase => write!(f, "camelCase"),
             Self::SnakeCase => write!(f, "snake_case"),
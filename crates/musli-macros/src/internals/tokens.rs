@@ -2,17 +2,25 @@ use proc_macro2::Span;
 
 pub(crate) struct Tokens {
     pub(crate) as_decoder_t: syn::Path,
+    pub(crate) clone_t: syn::Path,
     pub(crate) context_t: syn::Path,
     pub(crate) decode_bytes_t: syn::Path,
     pub(crate) decode_packed_t: syn::Path,
+    pub(crate) decode_remote_t: syn::Path,
     pub(crate) decode_t: syn::Path,
+    pub(crate) decode_unknown_field: syn::Path,
     pub(crate) decoder_t: syn::Path,
     pub(crate) default_function: syn::Path,
     pub(crate) encode_bytes_t: syn::Path,
     pub(crate) encode_packed_t: syn::Path,
+    pub(crate) encode_remote_t: syn::Path,
     pub(crate) encode_t: syn::Path,
     pub(crate) encoder_t: syn::Path,
+    pub(crate) flatten_encoder: syn::Path,
+    pub(crate) flatten_len: syn::Path,
     pub(crate) fmt: syn::Path,
+    pub(crate) from_t: syn::Path,
+    pub(crate) into_t: syn::Path,
     pub(crate) map_decoder_t: syn::Path,
     pub(crate) map_encoder_t: syn::Path,
     pub(crate) map_entry_encoder_t: syn::Path,
@@ -21,15 +29,22 @@ pub(crate) struct Tokens {
     pub(crate) option_some: syn::Path,
     pub(crate) option: syn::Path,
     pub(crate) pack_decoder_t: syn::Path,
+    pub(crate) reflect_field: syn::Path,
+    pub(crate) reflect_metadata: syn::Path,
+    pub(crate) reflect_t: syn::Path,
+    pub(crate) reflect_variant: syn::Path,
     pub(crate) result_err: syn::Path,
     pub(crate) result_ok: syn::Path,
     pub(crate) result: syn::Path,
     pub(crate) sequence_encoder_t: syn::Path,
     pub(crate) skip_field: syn::Path,
     pub(crate) skip: syn::Path,
+    pub(crate) stringify: syn::Path,
     pub(crate) struct_field_decoder_t: syn::Path,
     pub(crate) trace_decode_t: syn::Path,
     pub(crate) trace_encode_t: syn::Path,
+    pub(crate) try_context: syn::Path,
+    pub(crate) unknown_field_key: syn::Path,
     pub(crate) variant_decoder_t: syn::Path,
     pub(crate) variant_encoder_t: syn::Path,
     pub(crate) prefix: syn::Path,
@@ -39,17 +54,25 @@ impl Tokens {
     pub(crate) fn new(span: Span, prefix: syn::Path) -> Self {
         Self {
             as_decoder_t: path(span, &prefix, ["de", "AsDecoder"]),
+            clone_t: path(span, &prefix, ["__priv", "Clone"]),
             context_t: path(span, &prefix, ["Context"]),
             decode_bytes_t: path(span, &prefix, ["de", "DecodeBytes"]),
             decode_packed_t: path(span, &prefix, ["de", "DecodePacked"]),
+            decode_remote_t: path(span, &prefix, ["__priv", "DecodeRemote"]),
             decode_t: path(span, &prefix, ["de", "Decode"]),
+            decode_unknown_field: path(span, &prefix, ["__priv", "decode_unknown_field"]),
             decoder_t: path(span, &prefix, ["de", "Decoder"]),
             default_function: path(span, &prefix, ["__priv", "default"]),
             encode_bytes_t: path(span, &prefix, ["en", "EncodeBytes"]),
             encode_packed_t: path(span, &prefix, ["en", "EncodePacked"]),
+            encode_remote_t: path(span, &prefix, ["__priv", "EncodeRemote"]),
             encode_t: path(span, &prefix, ["en", "Encode"]),
             encoder_t: path(span, &prefix, ["en", "Encoder"]),
+            flatten_encoder: path(span, &prefix, ["__priv", "FlattenEncoder"]),
+            flatten_len: path(span, &prefix, ["__priv", "FlattenLen"]),
             fmt: path(span, &prefix, ["__priv", "fmt"]),
+            from_t: path(span, &prefix, ["__priv", "From"]),
+            into_t: path(span, &prefix, ["__priv", "Into"]),
             map_decoder_t: path(span, &prefix, ["de", "MapDecoder"]),
             map_encoder_t: path(span, &prefix, ["en", "MapEncoder"]),
             map_entry_encoder_t: path(span, &prefix, ["en", "EntryEncoder"]),
@@ -58,15 +81,22 @@ impl Tokens {
             option_some: path(span, &prefix, ["__priv", "Some"]),
             option: path(span, &prefix, ["__priv", "Option"]),
             pack_decoder_t: path(span, &prefix, ["de", "SequenceDecoder"]),
+            reflect_field: path(span, &prefix, ["reflect", "FieldMetadata"]),
+            reflect_metadata: path(span, &prefix, ["reflect", "Metadata"]),
+            reflect_t: path(span, &prefix, ["reflect", "Reflect"]),
+            reflect_variant: path(span, &prefix, ["reflect", "VariantMetadata"]),
             result_err: path(span, &prefix, ["__priv", "Err"]),
             result_ok: path(span, &prefix, ["__priv", "Ok"]),
             result: path(span, &prefix, ["__priv", "Result"]),
             sequence_encoder_t: path(span, &prefix, ["en", "SequenceEncoder"]),
             skip_field: path(span, &prefix, ["__priv", "skip_field"]),
             skip: path(span, &prefix, ["__priv", "skip"]),
+            stringify: path(span, &prefix, ["__priv", "stringify"]),
             struct_field_decoder_t: path(span, &prefix, ["de", "EntryDecoder"]),
             trace_decode_t: path(span, &prefix, ["de", "DecodeTrace"]),
             trace_encode_t: path(span, &prefix, ["en", "EncodeTrace"]),
+            try_context: path(span, &prefix, ["__priv", "TryContext"]),
+            unknown_field_key: path(span, &prefix, ["__priv", "unknown_field_key"]),
             variant_decoder_t: path(span, &prefix, ["de", "VariantDecoder"]),
             variant_encoder_t: path(span, &prefix, ["en", "VariantEncoder"]),
             prefix,
@@ -20,6 +20,9 @@ pub(super) enum Ty {
 pub(super) enum Extra {
     /// `type Type = Never;`
     None,
+    /// `type Type<'this> = Never;`, for decoders which are lent out to a
+    /// callback.
+    Lent,
     /// `type Error = <Self::Cx as Context>::Error;`
     Error,
     /// `type Mode = <Self::Cx as Context>::Mode;`
@@ -54,11 +57,11 @@ pub(super) const DECODER_TYPES: &[(&str, Extra)] = &[
     ("WithContext", Extra::Context),
     ("DecodeBuffer", Extra::None),
     ("DecodeSome", Extra::None),
-    ("DecodePack", Extra::None),
-    ("DecodeSequence", Extra::None),
-    ("DecodeMap", Extra::None),
-    ("DecodeMapEntries", Extra::None),
-    ("DecodeVariant", Extra::None),
+    ("DecodePack", Extra::Lent),
+    ("DecodeSequence", Extra::Lent),
+    ("DecodeMap", Extra::Lent),
+    ("DecodeMapEntries", Extra::Lent),
+    ("DecodeVariant", Extra::Lent),
 ];
 
 pub(super) const DECODER_FNS: &[(&str, Fn)] = &[
@@ -319,6 +322,28 @@ impl Types {
                         where_clause: Some(where_clause),
                     };
                 }
+                Extra::Lent => {
+                    let mut params = Punctuated::default();
+
+                    params.push(syn::GenericParam::Lifetime(syn::LifetimeParam {
+                        attrs: Vec::new(),
+                        lifetime: syn::Lifetime::new("'this", Span::call_site()),
+                        colon_token: None,
+                        bounds: Punctuated::default(),
+                    }));
+
+                    ty = syn::Type::Path(syn::TypePath {
+                        qself: None,
+                        path: self.never_type(crate_path, argument, extra, kind)?,
+                    });
+
+                    generics = syn::Generics {
+                        lt_token: Some(<Token![<]>::default()),
+                        params,
+                        gt_token: Some(<Token![>]>::default()),
+                        where_clause: None,
+                    };
+                }
                 _ => {
                     ty = syn::Type::Path(syn::TypePath {
                         qself: None,
@@ -426,7 +451,7 @@ impl Types {
                     let u_param = syn::Ident::new(U_PARAM, Span::call_site());
                     args.push(syn::parse_quote!(#u_param));
                 }
-                Extra::None => match kind {
+                Extra::None | Extra::Lent => match kind {
                     Kind::SelfCx => {
                         args.push(syn::parse_quote!(Self::Cx));
                     }
@@ -78,7 +78,7 @@
 mod tests;
 
 #[doc(inline)]
-pub use musli_core::alloc::{Allocator, RawVec};
+pub use musli_core::alloc::{Allocator, LendAlloc, RawVec};
 
 #[cfg(feature = "alloc")]
 mod system;
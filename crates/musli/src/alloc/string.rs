@@ -2,10 +2,12 @@ use core::fmt;
 use core::ops::Deref;
 use core::str;
 
+use crate::de::{Decode, Decoder, UnsizedVisitor};
+use crate::en::{Encode, Encoder};
 use crate::fixed::CapacityError;
 use crate::Context;
 
-use super::{Allocator, Vec};
+use super::{Allocator, LendAlloc, Vec};
 
 /// Wrapper around a buffer that is guaranteed to be a valid utf-8 string.
 pub struct String<'a, A>
@@ -124,3 +126,62 @@ where
         self.as_str()
     }
 }
+
+impl<M, A> Encode<M> for String<'_, A>
+where
+    A: ?Sized + Allocator,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.as_str().encode(cx, encoder)
+    }
+}
+
+/// Decode a string which allocates through the allocator of the context.
+///
+/// This requires a context holding a reference to the allocator, such as the
+/// one used by `from_slice_in`.
+impl<'de, 'a, M, A> Decode<'de, M, &'a A> for String<'a, A>
+where
+    A: 'a + ?Sized + Allocator,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+        D::Cx: LendAlloc<&'a A>,
+    {
+        struct Visitor<'a, A>(&'a A)
+        where
+            A: ?Sized;
+
+        impl<'de, 'a, C, A> UnsizedVisitor<'de, C, str> for Visitor<'a, A>
+        where
+            C: ?Sized + Context,
+            A: 'a + ?Sized + Allocator,
+        {
+            type Ok = String<'a, A>;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "string")
+            }
+
+            #[inline]
+            fn visit_ref(self, cx: &C, string: &str) -> Result<Self::Ok, C::Error> {
+                let mut out = String::new_in(self.0);
+
+                if out.try_push_str(string).is_err() {
+                    return Err(cx.alloc_failed());
+                }
+
+                Ok(out)
+            }
+        }
+
+        decoder.decode_string(Visitor(cx.lend_alloc()))
+    }
+}
@@ -5,7 +5,11 @@ use core::ops::{Deref, DerefMut};
 use core::ptr;
 use core::slice;
 
-use super::{Allocator, RawVec};
+use crate::de::{Decode, Decoder, SequenceDecoder};
+use crate::en::{Encode, Encoder};
+use crate::Context;
+
+use super::{Allocator, LendAlloc, RawVec};
 
 /// A vector backed by an [`Allocator`].
 pub struct Vec<'a, T, A>
@@ -211,6 +215,25 @@ where
         unsafe { slice::from_raw_parts(self.buf.as_ptr(), self.len) }
     }
 
+    /// Get the initialized part of the buffer as a mutable slice.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli::alloc::Vec;
+    ///
+    /// musli::alloc::default!(|alloc| {
+    ///     let mut a = Vec::new_in(alloc);
+    ///     a.write(b"Hello");
+    ///     a.as_mut_slice()[0] = b'J';
+    ///     assert_eq!(a.as_slice(), b"Jello");
+    /// });
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: We know that the buffer is initialized up to `self.len`.
+        unsafe { slice::from_raw_parts_mut(self.buf.as_mut_ptr(), self.len) }
+    }
+
     #[inline]
     fn into_raw_parts(self) -> (A::RawVec<'a, T>, usize) {
         let this = ManuallyDrop::new(self);
@@ -357,6 +380,55 @@ where
     }
 }
 
+impl<M, T, A> Encode<M> for Vec<'_, T, A>
+where
+    A: ?Sized + Allocator,
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.as_slice().encode(cx, encoder)
+    }
+}
+
+/// Decode a vector which allocates through the allocator of the context.
+///
+/// This requires a context holding a reference to the allocator, such as the
+/// one used by `from_slice_in`.
+impl<'de, 'a, M, T, A> Decode<'de, M, &'a A> for Vec<'a, T, A>
+where
+    A: 'a + ?Sized + Allocator,
+    T: 'a + Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+        D::Cx: LendAlloc<&'a A>,
+    {
+        decoder.decode_sequence(|seq| {
+            let mut out = Vec::new_in(cx.lend_alloc());
+            let mut index = 0usize;
+
+            while let Some(value) = seq.try_decode_next()? {
+                cx.enter_sequence_index(index);
+
+                if !out.push(T::decode(cx, value)?) {
+                    return Err(cx.alloc_failed());
+                }
+
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(out)
+        })
+    }
+}
+
 impl<'a, T, A> Drop for Vec<'a, T, A>
 where
     A: 'a + ?Sized + Allocator,
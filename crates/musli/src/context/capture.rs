@@ -8,7 +8,7 @@ use crate::alloc::{System, SYSTEM};
 use crate::no_std;
 use crate::Context;
 
-use super::{ContextError, ErrorMarker};
+use super::{ContextError, Depth, ErrorMarker};
 
 /// A simple non-diagnostical capturing context.
 pub struct Capture<M, E, A>
@@ -17,6 +17,7 @@ where
 {
     alloc: A,
     error: UnsafeCell<Option<E>>,
+    depth: Depth,
     _marker: PhantomData<M>,
 }
 
@@ -41,10 +42,21 @@ where
         Self {
             alloc,
             error: UnsafeCell::new(None),
+            depth: Depth::new(),
             _marker: PhantomData,
         }
     }
 
+    /// Configure the maximum depth of nested values which can be decoded.
+    ///
+    /// Every sequence, map or variant counts as one level of nesting, and
+    /// decoding values which are nested deeper than this results in an error
+    /// instead of recursing further. Defaults to 128.
+    pub fn max_depth(&mut self, max_depth: usize) -> &mut Self {
+        self.depth.set_max(max_depth);
+        self
+    }
+
     /// Construct an error or panic.
     pub fn unwrap(self) -> E {
         let Some(error) = self.error.into_inner() else {
@@ -74,6 +86,8 @@ where
         unsafe {
             (*self.error.get()) = None;
         }
+
+        self.depth.clear();
     }
 
     #[inline]
@@ -116,6 +130,16 @@ where
 
         ErrorMarker
     }
+
+    #[inline]
+    fn enter_depth(&self) -> Result<(), Self::Error> {
+        self.depth.enter().map_err(|error| self.message(error))
+    }
+
+    #[inline]
+    fn leave_depth(&self) {
+        self.depth.leave();
+    }
 }
 
 #[cfg(feature = "alloc")]
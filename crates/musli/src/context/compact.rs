@@ -0,0 +1,386 @@
+use core::any::Any;
+use core::cell::Cell;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::alloc::{self, Allocator, String};
+use crate::int::continuation::ContinuationOverflow;
+#[cfg(feature = "alloc")]
+use crate::alloc::{System, SYSTEM};
+use crate::no_std;
+use crate::reader::SliceUnderflow;
+use crate::Context;
+
+use super::Depth;
+
+/// A context which reports errors as a compact [`CompactError`], consisting
+/// of an [`ErrorCode`] and the byte offset at which the error occurred.
+///
+/// No error message is ever formatted and nothing is allocated when an error
+/// is raised, which makes this suitable for environments without an allocator
+/// and for workloads where errors are expected, such as speculative parsing.
+///
+/// # Examples
+///
+/// ```
+/// use musli::context::{Compact, ErrorCode};
+///
+/// musli::alloc::default!(|alloc| {
+///     let cx = Compact::with_alloc(alloc);
+///     let encoding = musli::storage::Encoding::new();
+///     let error = encoding.from_slice_with::<_, (u32, u32)>(&cx, &[1]).unwrap_err();
+///     assert_eq!(error.code(), ErrorCode::UnexpectedEnd);
+///     assert_eq!(error.offset(), 1);
+/// });
+/// ```
+pub struct Compact<M, A> {
+    alloc: A,
+    position: Cell<usize>,
+    depth: Depth,
+    _marker: PhantomData<M>,
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl<M> Compact<M, &'static System> {
+    /// Construct a new compact context using the [`System`] allocator.
+    pub fn new() -> Self {
+        Self::with_alloc(&SYSTEM)
+    }
+}
+
+impl<M, A> Compact<M, A> {
+    /// Construct a new compact context with a custom allocator.
+    pub fn with_alloc(alloc: A) -> Self {
+        Self {
+            alloc,
+            position: Cell::new(0),
+            depth: Depth::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure the maximum depth of nested values which can be decoded.
+    ///
+    /// Every sequence, map or variant counts as one level of nesting, and
+    /// decoding values which are nested deeper than this results in an error
+    /// instead of recursing further. Defaults to 128.
+    pub fn max_depth(&mut self, max_depth: usize) -> &mut Self {
+        self.depth.set_max(max_depth);
+        self
+    }
+
+    #[inline]
+    fn error(&self, code: ErrorCode) -> CompactError {
+        self.marked_error(self.position.get(), code)
+    }
+
+    #[inline]
+    fn marked_error(&self, offset: usize, code: ErrorCode) -> CompactError {
+        CompactError { code, offset }
+    }
+}
+
+impl<M, A> Context for Compact<M, A>
+where
+    M: 'static,
+    A: Allocator,
+{
+    type Mode = M;
+    type Error = CompactError;
+    type Mark = usize;
+    type Allocator = A;
+    type String<'this> = String<'this, A> where Self: 'this;
+
+    #[inline]
+    fn clear(&self) {
+        self.position.set(0);
+        self.depth.clear();
+    }
+
+    #[inline]
+    fn alloc(&self) -> &Self::Allocator {
+        &self.alloc
+    }
+
+    #[inline]
+    fn collect_string<T>(&self, value: &T) -> Result<Self::String<'_>, Self::Error>
+    where
+        T: ?Sized + fmt::Display,
+    {
+        alloc::collect_string(self, value)
+    }
+
+    #[inline]
+    fn custom<T>(&self, error: T) -> Self::Error
+    where
+        T: 'static + Send + Sync + no_std::Error,
+    {
+        self.error(ErrorCode::of(&error))
+    }
+
+    #[inline]
+    fn message<T>(&self, _: T) -> Self::Error
+    where
+        T: fmt::Display,
+    {
+        self.error(ErrorCode::Message)
+    }
+
+    #[inline]
+    fn marked_message<T>(&self, mark: Self::Mark, _: T) -> Self::Error
+    where
+        T: fmt::Display,
+    {
+        self.marked_error(mark, ErrorCode::Message)
+    }
+
+    #[inline]
+    fn marked_custom<T>(&self, mark: Self::Mark, error: T) -> Self::Error
+    where
+        T: 'static + Send + Sync + no_std::Error,
+    {
+        self.marked_error(mark, ErrorCode::of(&error))
+    }
+
+    #[inline]
+    fn advance(&self, n: usize) {
+        self.position.set(self.position.get().wrapping_add(n));
+    }
+
+    #[inline]
+    fn mark(&self) -> Self::Mark {
+        self.position.get()
+    }
+
+    #[inline]
+    fn invalid_variant_tag<T>(&self, _: &'static str, _: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.error(ErrorCode::InvalidVariantTag)
+    }
+
+    #[inline]
+    fn expected_tag<T>(&self, _: &'static str, _: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.error(ErrorCode::ExpectedTag)
+    }
+
+    #[inline]
+    fn uninhabitable(&self, _: &'static str) -> Self::Error {
+        self.error(ErrorCode::Uninhabitable)
+    }
+
+    #[inline]
+    fn invalid_field_tag<T>(&self, _: &'static str, _: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.error(ErrorCode::InvalidFieldTag)
+    }
+
+    #[inline]
+    fn expected_field_adjacent<T, C>(&self, _: &'static str, _: &T, _: &C) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+        C: ?Sized + fmt::Debug,
+    {
+        self.error(ErrorCode::ExpectedFieldAdjacent)
+    }
+
+    #[inline]
+    fn missing_adjacent_tag<T>(&self, _: &'static str, _: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.error(ErrorCode::MissingAdjacentTag)
+    }
+
+    #[inline]
+    fn invalid_field_string_tag(&self, _: &'static str, _: Self::String<'_>) -> Self::Error {
+        self.error(ErrorCode::InvalidFieldTag)
+    }
+
+    #[inline]
+    fn missing_variant_field<T>(&self, _: &'static str, _: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.error(ErrorCode::MissingVariantField)
+    }
+
+    #[inline]
+    fn missing_variant_tag(&self, _: &'static str) -> Self::Error {
+        self.error(ErrorCode::MissingVariantTag)
+    }
+
+    #[inline]
+    fn no_matching_variant(&self, _: &'static str) -> Self::Error {
+        self.error(ErrorCode::NoMatchingVariant)
+    }
+
+    #[inline]
+    fn unsupported_version<T>(&self, _: &'static str, _: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.error(ErrorCode::UnsupportedVersion)
+    }
+
+    #[inline]
+    fn invalid_variant_field_tag<V, T>(&self, _: &'static str, _: &V, _: &T) -> Self::Error
+    where
+        V: ?Sized + fmt::Debug,
+        T: ?Sized + fmt::Debug,
+    {
+        self.error(ErrorCode::InvalidVariantFieldTag)
+    }
+
+    #[inline]
+    fn alloc_failed(&self) -> Self::Error {
+        self.error(ErrorCode::AllocFailed)
+    }
+
+    #[inline]
+    fn enter_depth(&self) -> Result<(), Self::Error> {
+        self.depth
+            .enter()
+            .map_err(|_| self.error(ErrorCode::DepthExceeded))
+    }
+
+    #[inline]
+    fn leave_depth(&self) {
+        self.depth.leave();
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+impl<M> Default for Compact<M, &'static System> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The kind of error reported by the [`Compact`] context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// A format specific error, which would otherwise have been reported as a
+    /// message.
+    Message,
+    /// A custom error raised by a type being encoded or decoded.
+    Custom,
+    /// The input ended unexpectedly.
+    UnexpectedEnd,
+    /// An invalid variant tag was encountered.
+    InvalidVariantTag,
+    /// A tag was expected.
+    ExpectedTag,
+    /// Tried to decode an uninhabitable type.
+    Uninhabitable,
+    /// An invalid field tag was encountered.
+    InvalidFieldTag,
+    /// An adjacent tag or content field was expected.
+    ExpectedFieldAdjacent,
+    /// The adjacent tag was missing.
+    MissingAdjacentTag,
+    /// A field required to decode a variant was missing.
+    MissingVariantField,
+    /// The variant tag couldn't be determined.
+    MissingVariantTag,
+    /// A value didn't match any variant of an untagged enum.
+    NoMatchingVariant,
+    /// The version of a versioned container isn't supported.
+    UnsupportedVersion,
+    /// An invalid field tag was encountered in a variant.
+    InvalidVariantFieldTag,
+    /// An allocation failed.
+    AllocFailed,
+    /// Values were nested deeper than the maximum depth.
+    DepthExceeded,
+    /// A continuation-encoded number was longer than permitted.
+    ContinuationOverflow,
+}
+
+impl ErrorCode {
+    /// Classify a custom error.
+    fn of<T>(error: &T) -> Self
+    where
+        T: 'static,
+    {
+        if (error as &dyn Any).is::<SliceUnderflow>() {
+            return ErrorCode::UnexpectedEnd;
+        }
+
+        if (error as &dyn Any).is::<ContinuationOverflow>() {
+            return ErrorCode::ContinuationOverflow;
+        }
+
+        ErrorCode::Custom
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            ErrorCode::Message => "format error",
+            ErrorCode::Custom => "custom error",
+            ErrorCode::UnexpectedEnd => "unexpected end of input",
+            ErrorCode::InvalidVariantTag => "invalid variant tag",
+            ErrorCode::ExpectedTag => "expected tag",
+            ErrorCode::Uninhabitable => "cannot decode uninhabitable types",
+            ErrorCode::InvalidFieldTag => "invalid field tag",
+            ErrorCode::ExpectedFieldAdjacent => "expected adjacent field",
+            ErrorCode::MissingAdjacentTag => "missing adjacent tag",
+            ErrorCode::MissingVariantField => "missing variant field",
+            ErrorCode::MissingVariantTag => "missing variant tag",
+            ErrorCode::NoMatchingVariant => "no matching variant",
+            ErrorCode::UnsupportedVersion => "unsupported version",
+            ErrorCode::InvalidVariantFieldTag => "invalid variant field tag",
+            ErrorCode::AllocFailed => "failed to allocate",
+            ErrorCode::DepthExceeded => "maximum depth exceeded",
+            ErrorCode::ContinuationOverflow => "continuation sequence too long",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// An error reported by the [`Compact`] context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompactError {
+    code: ErrorCode,
+    offset: usize,
+}
+
+impl CompactError {
+    /// The kind of error that occurred.
+    #[inline]
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    /// The byte offset at which the error occurred.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for CompactError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at byte {})", self.code, self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CompactError {}
@@ -12,7 +12,7 @@ use crate::alloc::System;
 use crate::alloc::{self, Allocator, String, Vec};
 use crate::Context;
 
-use super::{Access, ErrorMarker, Shared};
+use super::{Access, Depth, ErrorMarker, Shared};
 
 /// The default context which uses an allocator to track the location of errors.
 ///
@@ -29,11 +29,17 @@ where
 {
     alloc: &'a A,
     mark: Cell<usize>,
-    errors: UnsafeCell<Vec<'a, (Range<usize>, String<'a, A>), A>>,
+    errors: UnsafeCell<Vec<'a, (Range<usize>, Option<String<'a, A>>, String<'a, A>), A>>,
     path: UnsafeCell<Vec<'a, Step<'a, A>, A>>,
     // How many elements of `path` we've gone over capacity.
     cap: Cell<usize>,
+    // How many errors couldn't be recorded due to running out of memory.
+    dropped: Cell<usize>,
+    depth: Depth,
     include_type: bool,
+    accumulate: bool,
+    // Whether the last error was marked as recoverable.
+    recoverable: Cell<bool>,
     access: Access,
     _marker: PhantomData<M>,
 }
@@ -74,7 +80,11 @@ where
             errors: UnsafeCell::new(errors),
             path: UnsafeCell::new(path),
             cap: Cell::new(0),
+            dropped: Cell::new(0),
+            depth: Depth::new(),
             include_type: false,
+            accumulate: false,
+            recoverable: Cell::new(false),
             access: Access::new(),
             _marker: PhantomData,
         }
@@ -87,13 +97,73 @@ where
         self
     }
 
+    /// Configure the context to accumulate errors.
+    ///
+    /// Decoding will keep going after errors which can be recovered from, such
+    /// as missing or unknown fields, including in nested structs, so that
+    /// everything wrong with the input is reported at once. Since the path
+    /// being decoded changes as decoding proceeds, each error captures the path
+    /// it was raised at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{context, Decode};
+    /// use musli::json::Encoding;
+    ///
+    /// const ENCODING: Encoding = Encoding::new();
+    ///
+    /// #[derive(Decode)]
+    /// #[musli(name_all = "name")]
+    /// struct Config {
+    ///     name: String,
+    ///     port: u16,
+    ///     host: String,
+    /// }
+    ///
+    /// let mut cx = context::new();
+    /// cx.accumulate();
+    ///
+    /// let result: Result<Config, _> = ENCODING.from_str_with(&cx, r#"{"name": "server"}"#);
+    /// assert!(result.is_err());
+    /// assert_eq!(cx.errors().count(), 2);
+    /// ```
+    pub fn accumulate(&mut self) -> &mut Self {
+        self.accumulate = true;
+        self
+    }
+
+    /// Configure the maximum depth of nested values which can be decoded.
+    ///
+    /// Every sequence, map or variant counts as one level of nesting, and
+    /// decoding values which are nested deeper than this results in an error
+    /// instead of recursing further. Defaults to 128.
+    pub fn max_depth(&mut self, max_depth: usize) -> &mut Self {
+        self.depth.set_max(max_depth);
+        self
+    }
+
     /// Generate a line-separated report of all collected errors.
     pub fn report(&self) -> Report<'_, 'a, A> {
         Report {
             errors: self.errors(),
+            dropped: self.dropped.get(),
         }
     }
 
+    /// The number of errors which couldn't be recorded because the allocator
+    /// ran out of memory.
+    ///
+    /// This can happen when the context is backed by a fixed-size buffer, such
+    /// as a [`Slice`] allocator over an [`ArrayBuffer`], which is too small
+    /// to hold all diagnostics.
+    ///
+    /// [`Slice`]: crate::alloc::Slice
+    /// [`ArrayBuffer`]: crate::alloc::ArrayBuffer
+    pub fn dropped(&self) -> usize {
+        self.dropped.get()
+    }
+
     /// Iterate over all collected errors.
     pub fn errors(&self) -> Errors<'_, 'a, A> {
         let access = self.access.shared();
@@ -106,13 +176,34 @@ where
         }
     }
 
+    /// Record an error with the given message.
+    fn record<T>(&self, range: Range<usize>, message: T)
+    where
+        T: fmt::Display,
+    {
+        match self.format_string(message) {
+            Some(string) => self.push_error(range, string),
+            None => self.dropped.set(self.dropped.get() + 1),
+        }
+    }
+
     /// Push an error into the collection.
     fn push_error(&self, range: Range<usize>, error: String<'a, A>) {
         let _access = self.access.exclusive();
 
+        let path = if self.accumulate {
+            // SAFETY: We've checked that we have exclusive access just above.
+            let path = unsafe { (*self.path.get()).as_slice() };
+            self.format_string(FormatPath::new(path, self.cap.get()))
+        } else {
+            None
+        };
+
         // SAFETY: We've checked that we have exclusive access just above.
-        unsafe {
-            _ = (*self.errors.get()).push((range, error));
+        let errors = unsafe { &mut (*self.errors.get()) };
+
+        if !errors.push((range, path, error)) {
+            self.dropped.set(self.dropped.get() + 1);
         }
     }
 
@@ -169,6 +260,10 @@ where
     #[inline]
     fn clear(&self) {
         self.mark.set(0);
+        self.cap.set(0);
+        self.dropped.set(0);
+        self.recoverable.set(false);
+        self.depth.clear();
         let _access = self.access.exclusive();
 
         // SAFETY: We have acquired exclusive access just above.
@@ -196,9 +291,7 @@ where
     where
         T: 'static + Send + Sync + fmt::Display + fmt::Debug,
     {
-        if let Some(string) = self.format_string(message) {
-            self.push_error(self.mark.get()..self.mark.get(), string);
-        }
+        self.record(self.mark.get()..self.mark.get(), message);
 
         ErrorMarker
     }
@@ -208,9 +301,7 @@ where
     where
         T: fmt::Display,
     {
-        if let Some(string) = self.format_string(message) {
-            self.push_error(self.mark.get()..self.mark.get(), string);
-        }
+        self.record(self.mark.get()..self.mark.get(), message);
 
         ErrorMarker
     }
@@ -220,9 +311,7 @@ where
     where
         T: fmt::Display,
     {
-        if let Some(string) = self.format_string(message) {
-            self.push_error(mark..self.mark.get(), string);
-        }
+        self.record(mark..self.mark.get(), message);
 
         ErrorMarker
     }
@@ -232,9 +321,7 @@ where
     where
         T: 'static + Send + Sync + fmt::Display + fmt::Debug,
     {
-        if let Some(string) = self.format_string(message) {
-            self.push_error(mark..self.mark.get(), string);
-        }
+        self.record(mark..self.mark.get(), message);
 
         ErrorMarker
     }
@@ -244,11 +331,36 @@ where
         self.mark.get()
     }
 
+    #[inline]
+    fn accumulates_errors(&self) -> bool {
+        self.accumulate
+    }
+
+    #[inline]
+    fn recoverable(&self) {
+        self.recoverable.set(self.accumulate);
+    }
+
+    #[inline]
+    fn take_recoverable(&self) -> bool {
+        self.recoverable.replace(false)
+    }
+
     #[inline]
     fn advance(&self, n: usize) {
         self.mark.set(self.mark.get().wrapping_add(n));
     }
 
+    #[inline]
+    fn enter_depth(&self) -> Result<(), Self::Error> {
+        self.depth.enter().map_err(|error| self.message(error))
+    }
+
+    #[inline]
+    fn leave_depth(&self) {
+        self.depth.leave();
+    }
+
     #[inline]
     fn enter_named_field<T>(&self, name: &'static str, _: &T)
     where
@@ -323,8 +435,11 @@ where
     where
         T: fmt::Display,
     {
-        if let Some(string) = self.format_string(field) {
-            self.push_path(Step::Key(string));
+        match self.format_string(field) {
+            Some(string) => self.push_path(Step::Key(string)),
+            // Count the step as capped, so that it's balanced by the
+            // corresponding call to `leave_map_key`.
+            None => self.cap.set(self.cap.get() + 1),
         }
     }
 
@@ -340,6 +455,7 @@ where
     A: 'a + ?Sized + Allocator,
 {
     errors: Errors<'b, 'a, A>,
+    dropped: usize,
 }
 
 impl<'b, 'a, A> fmt::Display for Report<'b, 'a, A>
@@ -351,6 +467,12 @@ where
             writeln!(f, "{error}")?;
         }
 
+        match self.dropped {
+            0 => {}
+            1 => writeln!(f, "*one error dropped due to running out of memory*")?,
+            n => writeln!(f, "*{n} errors dropped due to running out of memory*")?,
+        }
+
         Ok(())
     }
 }
@@ -364,7 +486,7 @@ where
 {
     path: &'b [Step<'a, A>],
     cap: usize,
-    errors: slice::Iter<'b, (Range<usize>, String<'a, A>)>,
+    errors: slice::Iter<'b, (Range<usize>, Option<String<'a, A>>, String<'a, A>)>,
     _access: Shared<'b>,
 }
 
@@ -376,8 +498,15 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let (range, error) = self.errors.next()?;
-        Some(Error::new(self.path, self.cap, range.clone(), error))
+        let (range, captured, error) = self.errors.next()?;
+
+        Some(Error::new(
+            self.path,
+            self.cap,
+            captured.as_deref(),
+            range.clone(),
+            error,
+        ))
     }
 }
 
@@ -402,6 +531,7 @@ where
 {
     path: &'b [Step<'a, A>],
     cap: usize,
+    captured: Option<&'b str>,
     range: Range<usize>,
     error: &'b str,
 }
@@ -410,14 +540,29 @@ impl<'b, 'a, A> Error<'b, 'a, A>
 where
     A: 'a + ?Sized + Allocator,
 {
-    fn new(path: &'b [Step<'a, A>], cap: usize, range: Range<usize>, error: &'b str) -> Self {
+    fn new(
+        path: &'b [Step<'a, A>],
+        cap: usize,
+        captured: Option<&'b str>,
+        range: Range<usize>,
+        error: &'b str,
+    ) -> Self {
         Self {
             path,
             cap,
+            captured,
             range,
             error,
         }
     }
+
+    /// The range of bytes in the input that the error refers to.
+    ///
+    /// If the element which caused the error couldn't be determined, this is
+    /// an empty range at the position where the error was raised.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
 }
 
 impl<'a, A> fmt::Display for Error<'_, 'a, A>
@@ -427,6 +572,11 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let path = FormatPath::new(self.path, self.cap);
 
+        let path: &dyn fmt::Display = match &self.captured {
+            Some(captured) => captured,
+            None => &path,
+        };
+
         if self.range.start != 0 || self.range.end != 0 {
             if self.range.start == self.range.end {
                 write!(f, "{path}: {} (at byte {})", self.error, self.range.start)?;
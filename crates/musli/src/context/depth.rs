@@ -0,0 +1,83 @@
+use core::cell::Cell;
+use core::fmt;
+
+use crate::Context;
+
+/// The default maximum number of nested sequences, maps and variants which can
+/// be decoded.
+pub(crate) const DEFAULT_MAX_DEPTH: usize = 128;
+
+/// Decode a nested sequence, map or variant through `f`, counting it towards
+/// the maximum depth of the context.
+#[inline]
+pub(crate) fn nested<C, O>(cx: &C, f: impl FnOnce() -> Result<O, C::Error>) -> Result<O, C::Error>
+where
+    C: ?Sized + Context,
+{
+    cx.enter_depth()?;
+    let result = f();
+    cx.leave_depth();
+    result
+}
+
+/// Tracks how deeply nested the value being decoded is.
+pub(crate) struct Depth {
+    current: Cell<usize>,
+    max: usize,
+}
+
+impl Depth {
+    pub(crate) const fn new() -> Self {
+        Self {
+            current: Cell::new(0),
+            max: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    /// Set the maximum depth.
+    #[inline]
+    pub(crate) fn set_max(&mut self, max: usize) {
+        self.max = max;
+    }
+
+    /// Enter a nested value, returning the error to report if the maximum
+    /// depth has been reached.
+    #[inline]
+    pub(crate) fn enter(&self) -> Result<(), DepthExceeded> {
+        let current = self.current.get();
+
+        if current >= self.max {
+            return Err(DepthExceeded { max: self.max });
+        }
+
+        self.current.set(current + 1);
+        Ok(())
+    }
+
+    /// Leave a nested value.
+    #[inline]
+    pub(crate) fn leave(&self) {
+        self.current.set(self.current.get().saturating_sub(1));
+    }
+
+    #[inline]
+    pub(crate) fn clear(&self) {
+        self.current.set(0);
+    }
+}
+
+/// Error raised when the maximum depth has been reached.
+pub(crate) struct DepthExceeded {
+    max: usize,
+}
+
+impl fmt::Display for DepthExceeded {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Values are nested deeper than the maximum of {}",
+            self.max
+        )
+    }
+}
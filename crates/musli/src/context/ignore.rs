@@ -9,13 +9,14 @@ use crate::alloc::{System, SYSTEM};
 use crate::mode::Binary;
 use crate::Context;
 
-use super::{ContextError, ErrorMarker};
+use super::{ContextError, Depth, ErrorMarker};
 
 /// A simple non-diagnostical capturing context which ignores the error and
 /// loses all information about it (except that it happened).
 pub struct Ignore<M, E, A> {
     alloc: A,
     error: Cell<bool>,
+    depth: Depth,
     _marker: PhantomData<(M, E)>,
 }
 
@@ -37,9 +38,20 @@ impl<M, E, A> Ignore<M, E, A> {
         Self {
             alloc,
             error: Cell::new(false),
+            depth: Depth::new(),
             _marker: PhantomData,
         }
     }
+
+    /// Configure the maximum depth of nested values which can be decoded.
+    ///
+    /// Every sequence, map or variant counts as one level of nesting, and
+    /// decoding values which are nested deeper than this results in an error
+    /// instead of recursing further. Defaults to 128.
+    pub fn max_depth(&mut self, max_depth: usize) -> &mut Self {
+        self.depth.set_max(max_depth);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -76,7 +88,9 @@ where
     type String<'this> = String<'this, A> where Self: 'this;
 
     #[inline]
-    fn clear(&self) {}
+    fn clear(&self) {
+        self.depth.clear();
+    }
 
     #[inline]
     fn alloc(&self) -> &Self::Allocator {
@@ -108,6 +122,16 @@ where
         self.error.set(true);
         ErrorMarker
     }
+
+    #[inline]
+    fn enter_depth(&self) -> Result<(), Self::Error> {
+        self.depth.enter().map_err(|error| self.message(error))
+    }
+
+    #[inline]
+    fn leave_depth(&self) {
+        self.depth.leave();
+    }
 }
 
 #[cfg(feature = "alloc")]
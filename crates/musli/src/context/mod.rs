@@ -1,10 +1,49 @@
 //! [`Context`] implementations.
 //!
+//! Contexts which collect diagnostics, like [`DefaultContext`], allocate the
+//! memory they need for error messages and traced paths from an [`Allocator`].
+//! On targets without a global allocator, this can be a [`Slice`] allocator over
+//! a fixed buffer. If the buffer runs out, diagnostics are dropped instead of
+//! failing, and the number of dropped errors is reported through
+//! [`DefaultContext::dropped`].
+//!
+//! ```
+//! use musli::alloc::{ArrayBuffer, Slice};
+//! use musli::context;
+//! use musli::{Decode, Encode};
+//!
+//! #[derive(Decode, Encode)]
+//! struct Sensor {
+//!     id: u32,
+//!     reading: u64,
+//! }
+//!
+//! let mut buf = ArrayBuffer::<512>::with_size();
+//! let alloc = Slice::new(&mut buf);
+//! let cx = context::with_alloc(&alloc);
+//!
+//! let encoding = musli::storage::Encoding::new();
+//! let bytes = encoding.to_fixed_bytes_with::<_, 64, _>(&cx, &Sensor { id: 1, reading: 1000 })?;
+//!
+//! // Decoding a truncated payload fails, and the error is recorded in the
+//! // fixed buffer.
+//! let result = encoding.from_slice_with::<_, Sensor>(&cx, &bytes[..2]);
+//! assert!(result.is_err());
+//! assert!(cx.errors().next().is_some());
+//! assert_eq!(cx.dropped(), 0);
+//! # Ok::<(), musli::context::ErrorMarker>(())
+//! ```
+//!
 //! [`Context`]: crate::Context
+//! [`Slice`]: crate::alloc::Slice
 
 mod access;
 use self::access::{Access, Shared};
 
+mod depth;
+pub(crate) use self::depth::nested;
+use self::depth::Depth;
+
 mod error_marker;
 #[doc(inline)]
 pub use self::error_marker::ErrorMarker;
@@ -29,6 +68,10 @@ mod ignore;
 #[doc(inline)]
 pub use self::ignore::Ignore;
 
+mod compact;
+#[doc(inline)]
+pub use self::compact::{Compact, CompactError, ErrorCode};
+
 use crate::alloc::Allocator;
 #[cfg(feature = "alloc")]
 use crate::alloc::System;
@@ -9,9 +9,9 @@ use crate::mode::Binary;
 use crate::no_std;
 use crate::Context;
 
-use super::ContextError;
 #[cfg(test)]
 use super::ErrorMarker;
+use super::{ContextError, Depth};
 
 /// A simple non-diagnostical capturing context which simply emits the original
 /// error.
@@ -23,6 +23,7 @@ where
     E: ContextError,
 {
     alloc: A,
+    depth: Depth,
     _marker: PhantomData<(M, E)>,
 }
 
@@ -46,9 +47,20 @@ where
     pub fn with_alloc(alloc: A) -> Self {
         Self {
             alloc,
+            depth: Depth::new(),
             _marker: PhantomData,
         }
     }
+
+    /// Configure the maximum depth of nested values which can be decoded.
+    ///
+    /// Every sequence, map or variant counts as one level of nesting, and
+    /// decoding values which are nested deeper than this results in an error
+    /// instead of recursing further. Defaults to 128.
+    pub fn max_depth(&mut self, max_depth: usize) -> &mut Self {
+        self.depth.set_max(max_depth);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -72,7 +84,9 @@ where
     type String<'this> = String<'this, A> where Self: 'this;
 
     #[inline]
-    fn clear(&self) {}
+    fn clear(&self) {
+        self.depth.clear();
+    }
 
     #[inline]
     fn alloc(&self) -> &Self::Allocator {
@@ -102,6 +116,16 @@ where
     {
         E::message(message)
     }
+
+    #[inline]
+    fn enter_depth(&self) -> Result<(), Self::Error> {
+        self.depth.enter().map_err(E::message)
+    }
+
+    #[inline]
+    fn leave_depth(&self) {
+        self.depth.leave();
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -19,6 +19,7 @@
 #[doc(inline)]
 pub use musli_core::de::{
     AsDecoder, Decode, DecodeBytes, DecodeOwned, DecodePacked, DecodeTrace, DecodeUnsized,
-    DecodeUnsizedBytes, Decoder, EntriesDecoder, EntryDecoder, MapDecoder, SequenceDecoder,
-    SizeHint, Skip, UnsizedVisitor, VariantDecoder, Visitor,
+    DecodeUnsizedBytes, Decoder, EntriesDecoder, EntryDecoder, MapDecoder, MapEntries, PodSliceMut,
+    SequenceDecoder, SequenceIter, SizeHint, Skip, TypeHint, UnsizedVisitor, VariantDecoder,
+    Visitor,
 };
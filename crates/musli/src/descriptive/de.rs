@@ -4,9 +4,10 @@ use core::mem::take;
 #[cfg(feature = "alloc")]
 use rust_alloc::vec::Vec;
 
+use crate::context::nested;
 use crate::de::{
     Decode, DecodeUnsized, Decoder, EntriesDecoder, EntryDecoder, MapDecoder, SequenceDecoder,
-    SizeHint, Skip, UnsizedVisitor, VariantDecoder, Visitor,
+    SizeHint, Skip, TypeHint, UnsizedVisitor, VariantDecoder, Visitor,
 };
 use crate::int::continuation as c;
 #[cfg(feature = "value")]
@@ -61,6 +62,7 @@ where
         let mut remaining = 1;
 
         while remaining > 0 {
+            let mark = self.cx.mark();
             let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
 
             match tag.kind() {
@@ -99,7 +101,7 @@ where
                 kind => {
                     return Err(self
                         .cx
-                        .message(format_args!("Cannot skip over kind {kind:?}")));
+                        .marked_message(mark, format_args!("Cannot skip over kind {kind:?}")));
                 }
             }
 
@@ -225,12 +227,12 @@ where
     type WithContext<'this, U> = SelfDecoder<'this, R, OPT, U> where U: 'this + Context;
     #[cfg(feature = "value")]
     type DecodeBuffer = crate::value::AsValueDecoder<'a, BUFFER_OPTIONS, C>;
-    type DecodePack = SelfDecoder<'a, Limit<R>, OPT, C>;
+    type DecodePack<'this> = SelfDecoder<'a, Limit<R>, OPT, C>;
     type DecodeSome = Self;
-    type DecodeSequence = RemainingSelfDecoder<'a, R, OPT, C>;
-    type DecodeMap = RemainingSelfDecoder<'a, R, OPT, C>;
-    type DecodeMapEntries = RemainingSelfDecoder<'a, R, OPT, C>;
-    type DecodeVariant = Self;
+    type DecodeSequence<'this> = RemainingSelfDecoder<'a, R, OPT, C>;
+    type DecodeMap<'this> = RemainingSelfDecoder<'a, R, OPT, C>;
+    type DecodeMapEntries<'this> = RemainingSelfDecoder<'a, R, OPT, C>;
+    type DecodeVariant<'this> = Self;
 
     #[inline]
     fn cx(&self) -> &C {
@@ -278,6 +280,37 @@ where
         Ok(Skip::Skipped)
     }
 
+    #[inline]
+    fn type_hint(&mut self) -> Result<TypeHint, C::Error> {
+        let Some(tag) = self.reader.peek().map(Tag::from_byte) else {
+            return Ok(TypeHint::Unknown);
+        };
+
+        let size = tag
+            .data()
+            .map(|d| SizeHint::exact(d as usize))
+            .unwrap_or_default();
+
+        let hint = match tag.kind() {
+            Kind::Number => TypeHint::Number,
+            Kind::Sequence => TypeHint::Sequence(size),
+            Kind::Map => TypeHint::Map(size),
+            Kind::Bytes => TypeHint::Bytes(size),
+            Kind::String => TypeHint::String(size),
+            Kind::Mark => match tag.mark() {
+                Mark::True | Mark::False => TypeHint::Bool,
+                Mark::Variant => TypeHint::Variant,
+                Mark::Some | Mark::None => TypeHint::Option,
+                Mark::Char => TypeHint::Char,
+                Mark::Unit => TypeHint::Empty,
+                _ => TypeHint::Unknown,
+            },
+            _ => TypeHint::Unknown,
+        };
+
+        Ok(hint)
+    }
+
     #[cfg(feature = "value")]
     #[inline]
     fn decode_buffer(self) -> Result<Self::DecodeBuffer, C::Error> {
@@ -294,12 +327,12 @@ where
     #[inline]
     fn decode_pack<F, O>(mut self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodePack) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodePack<'_>) -> Result<O, C::Error>,
     {
         let pos = self.cx.mark();
         let len = self.decode_pack_length(pos)?;
         let mut decoder = SelfDecoder::new(self.cx, self.reader.limit(len));
-        let output = f(&mut decoder)?;
+        let output = nested(self.cx, || f(&mut decoder))?;
         decoder.end()?;
         Ok(output)
     }
@@ -321,6 +354,25 @@ where
         self.reader.read_array(self.cx)
     }
 
+    #[inline]
+    fn decode_array_slice(mut self, out: &mut [u8]) -> Result<(), C::Error> {
+        let pos = self.cx.mark();
+        let len = self.decode_prefix(Kind::Bytes, pos)?;
+
+        if len != out.len() {
+            let expected = out.len();
+
+            return Err(self.cx.marked_message(
+                pos,
+                format_args! {
+                    "Bad length, got {len} but expect {expected}"
+                },
+            ));
+        }
+
+        self.reader.read(self.cx, out)
+    }
+
     #[inline]
     fn decode_bytes<V>(mut self, visitor: V) -> Result<V::Ok, C::Error>
     where
@@ -336,9 +388,9 @@ where
     where
         V: UnsizedVisitor<'de, C, str>,
     {
-        struct Visitor<V>(V);
+        struct Visitor<V, M>(V, M);
 
-        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<V>
+        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<V, C::Mark>
         where
             C: ?Sized + Context,
             V: UnsizedVisitor<'de, C, str>,
@@ -353,26 +405,29 @@ where
             #[cfg(feature = "alloc")]
             #[inline]
             fn visit_owned(self, cx: &C, bytes: Vec<u8>) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8_owned(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8_owned(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_owned(cx, string)
             }
 
             #[inline]
             fn visit_borrowed(self, cx: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_borrowed(cx, string)
             }
 
             #[inline]
             fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_ref(cx, string)
             }
         }
 
         let pos = self.cx.mark();
         let len = self.decode_prefix(Kind::String, pos)?;
-        self.reader.read_bytes(self.cx, len, Visitor(visitor))
+        self.reader.read_bytes(self.cx, len, Visitor(visitor, pos))
     }
 
     #[inline]
@@ -580,10 +635,11 @@ where
     #[inline]
     fn decode_sequence<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeSequence) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeSequence<'_>) -> Result<O, C::Error>,
     {
+        let cx = self.cx;
         let mut decoder = self.shared_decode_sequence()?;
-        let output = f(&mut decoder)?;
+        let output = nested(cx, || f(&mut decoder))?;
         decoder.skip_sequence_remaining()?;
         Ok(output)
     }
@@ -591,10 +647,11 @@ where
     #[inline]
     fn decode_map<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeMap) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeMap<'_>) -> Result<O, C::Error>,
     {
+        let cx = self.cx;
         let mut decoder = self.shared_decode_map()?;
-        let output = f(&mut decoder)?;
+        let output = nested(cx, || f(&mut decoder))?;
         decoder.skip_map_remaining()?;
         Ok(output)
     }
@@ -602,10 +659,11 @@ where
     #[inline]
     fn decode_map_entries<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeMapEntries) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeMapEntries<'_>) -> Result<O, C::Error>,
     {
+        let cx = self.cx;
         let mut decoder = self.shared_decode_map()?;
-        let output = f(&mut decoder)?;
+        let output = nested(cx, || f(&mut decoder))?;
         decoder.skip_map_remaining()?;
         Ok(output)
     }
@@ -613,20 +671,24 @@ where
     #[inline]
     fn decode_variant<F, O>(mut self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeVariant) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeVariant<'_>) -> Result<O, C::Error>,
     {
         const VARIANT: Tag = Tag::from_mark(Mark::Variant);
 
+        let mark = self.cx.mark();
         let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
 
         if tag != VARIANT {
-            return Err(self.cx.message(Expected {
-                expected: Kind::Mark,
-                actual: tag,
-            }));
+            return Err(self.cx.marked_message(
+                mark,
+                Expected {
+                    expected: Kind::Mark,
+                    actual: tag,
+                },
+            ));
         }
 
-        f(&mut self)
+        nested(self.cx, || f(&mut self))
     }
 
     #[inline]
@@ -700,13 +762,13 @@ where
             }
             Kind::Sequence => {
                 let mut sequence = self.shared_decode_sequence()?;
-                let output = visitor.visit_sequence(cx, &mut sequence)?;
+                let output = nested(cx, || visitor.visit_sequence(cx, &mut sequence))?;
                 sequence.skip_sequence_remaining()?;
                 Ok(output)
             }
             Kind::Map => {
                 let mut map = self.shared_decode_map()?;
-                let output = visitor.visit_map(cx, &mut map)?;
+                let output = nested(cx, || visitor.visit_map(cx, &mut map))?;
                 map.skip_map_remaining()?;
                 Ok(output)
             }
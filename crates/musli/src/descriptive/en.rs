@@ -1,7 +1,7 @@
 use core::fmt;
 
 use crate::en::{
-    Encoder, EntriesEncoder, EntryEncoder, MapEncoder, SequenceEncoder, VariantEncoder,
+    Encoder, EntriesEncoder, EntryEncoder, MapEncoder, PodSlice, SequenceEncoder, VariantEncoder,
 };
 use crate::hint::{MapHint, SequenceHint};
 use crate::int::continuation as c;
@@ -117,6 +117,11 @@ where
         self.encode_bytes(array)
     }
 
+    #[inline]
+    fn encode_array_slice(self, array: &[u8]) -> Result<Self::Ok, C::Error> {
+        self.encode_bytes(array)
+    }
+
     #[inline]
     fn encode_bytes(mut self, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
         encode_prefix::<_, _, OPT>(self.cx, self.writer.borrow_mut(), Kind::Bytes, bytes.len())?;
@@ -338,6 +343,16 @@ where
         Ok(StorageEncoder::new(self.cx, &mut self.buffer))
     }
 
+    #[inline]
+    fn try_push_pod(&mut self, pod: PodSlice<'_>) -> Result<bool, C::Error> {
+        if pod.width() != 1 && !crate::options::is_native_fixed::<OPT>() {
+            return Ok(false);
+        }
+
+        self.buffer.write_bytes(self.cx, pod.as_bytes())?;
+        Ok(true)
+    }
+
     #[inline]
     fn finish_sequence(mut self) -> Result<Self::Ok, C::Error> {
         let buffer = self.buffer.into_inner();
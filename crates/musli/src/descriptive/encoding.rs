@@ -123,6 +123,17 @@ where
         SelfDecoder::<_, OPT, _>::new,
         IntoReader::into_reader,
     );
+
+    crate::macros::async_encoding_impls!(M, descriptive);
+    crate::macros::reader_encoding_impls!(M, descriptive);
+}
+
+impl<const OPT: Options, A, B> crate::DynMode<Encoding<OPT, A>, Encoding<OPT, B>>
+where
+    A: 'static,
+    B: 'static,
+{
+    crate::macros::dyn_mode_impls!(A, B, IntoReader);
 }
 
 impl<const OPT: Options, M> Clone for Encoding<OPT, M> {
@@ -133,3 +144,17 @@ impl<const OPT: Options, M> Clone for Encoding<OPT, M> {
 }
 
 impl<const OPT: Options, M> Copy for Encoding<OPT, M> {}
+
+impl<const OPT: Options, M> crate::transcode::Source for Encoding<OPT, M>
+where
+    M: 'static,
+{
+    #[inline]
+    fn transcode<E>(self, cx: &E::Cx, bytes: &[u8], encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: crate::Encoder,
+    {
+        let decoder = SelfDecoder::<_, OPT, _>::new(cx, IntoReader::into_reader(bytes));
+        crate::transcode::transcode(decoder, encoder)
+    }
+}
@@ -27,6 +27,7 @@ where
     R: Reader<'de>,
     T: Unsigned + TryFrom<T::Signed>,
 {
+    let mark = cx.mark();
     let (value, kind): (T, NumberKind) = decode_typed(cx, reader)?;
 
     match kind {
@@ -34,16 +35,16 @@ where
             let value = zig::decode(value);
 
             let Ok(value) = T::try_from(value) else {
-                return Err(cx.message(format_args!("Unsigned value outside of signed range")));
+                return Err(cx.marked_message(mark, "Unsigned value outside of signed range"));
             };
 
             Ok(value)
         }
         NumberKind::Unsigned | NumberKind::Float => Ok(value),
-        kind => Err(cx.message(format_args!(
-            "Expected signed or unsigned number, got {:?}",
-            kind
-        ))),
+        kind => Err(cx.marked_message(
+            mark,
+            format_args!("Expected signed or unsigned number, got {:?}", kind),
+        )),
     }
 }
 
@@ -65,10 +66,14 @@ where
     R: Reader<'de>,
     T: Unsigned,
 {
+    let mark = cx.mark();
     let tag = Tag::from_byte(reader.read_byte(cx)?);
 
     if tag.kind() != Kind::Number {
-        return Err(cx.message(format_args!("Expected {:?}, got {tag:?}", Kind::Number)));
+        return Err(cx.marked_message(
+            mark,
+            format_args!("Expected {:?}, got {tag:?}", Kind::Number),
+        ));
     }
 
     let kind = tag.number_kind();
@@ -97,20 +102,21 @@ where
     R: Reader<'de>,
     T: Signed + TryFrom<<T as Signed>::Unsigned>,
 {
+    let mark = cx.mark();
     let (value, kind): (T::Unsigned, NumberKind) = decode_typed(cx, reader)?;
 
     match kind {
         NumberKind::Signed => Ok(zig::decode(value)),
         NumberKind::Unsigned => {
             let Ok(value) = T::try_from(value) else {
-                return Err(cx.message(format_args!("Unsigned value outside of signed range")));
+                return Err(cx.marked_message(mark, "Unsigned value outside of signed range"));
             };
 
             Ok(value)
         }
-        kind => Err(cx.message(format_args!(
-            "Expected signed or unsigned number, got {:?}",
-            kind
-        ))),
+        kind => Err(cx.marked_message(
+            mark,
+            format_args!("Expected signed or unsigned number, got {:?}", kind),
+        )),
     }
 }
@@ -110,13 +110,16 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 #[doc(inline)]
-pub use self::encoding::to_vec;
+pub use self::encoding::{to_vec, to_vec_in};
 #[cfg(feature = "std")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 #[doc(inline)]
 pub use self::encoding::to_writer;
 #[doc(inline)]
-pub use self::encoding::{decode, encode, from_slice, to_fixed_bytes, Encoding, DEFAULT, OPTIONS};
+pub use self::encoding::{
+    decode, decode_into, encode, encoded_len, from_slice, to_fixed_bytes, Encoding, DEFAULT,
+    OPTIONS,
+};
 #[doc(inline)]
 pub use self::error::Error;
 
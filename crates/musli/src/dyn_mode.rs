@@ -0,0 +1,51 @@
+//! Selecting between two modes at runtime.
+//!
+//! Modes are types, so which one is used is normally fixed at compile time.
+//! [`DynMode`] holds one of two encodings of the same format which only differ
+//! in their mode, and dispatches to whichever one was picked at runtime. A
+//! value being encoded or decoded has to support both modes.
+//!
+//! ```
+//! use musli::{Decode, DynMode, Encode};
+//! use musli::json::Encoding;
+//! # use musli::json::Error;
+//!
+//! enum Compact {}
+//!
+//! #[derive(Debug, PartialEq, Decode, Encode)]
+//! #[musli(mode = Compact, packed)]
+//! struct Point {
+//!     x: u32,
+//!     y: u32,
+//! }
+//!
+//! fn encoding(verbose: bool) -> DynMode<Encoding<Compact>, Encoding> {
+//!     if verbose {
+//!         DynMode::Second(Encoding::new())
+//!     } else {
+//!         DynMode::First(Encoding::new().with_mode())
+//!     }
+//! }
+//!
+//! let point = Point { x: 1, y: 2 };
+//!
+//! let compact = encoding(false).to_vec(&point)?;
+//! assert_eq!(compact, b"[1,2]");
+//! assert_eq!(encoding(false).from_slice::<Point>(&compact)?, point);
+//!
+//! let verbose = encoding(true).to_vec(&point)?;
+//! assert_eq!(verbose, br#"{"x":1,"y":2}"#);
+//! assert_eq!(encoding(true).from_slice::<Point>(&verbose)?, point);
+//! # Ok::<(), Error>(())
+//! ```
+
+/// One of two encodings selected at runtime.
+///
+/// See the [module level documentation][self].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynMode<A, B> {
+    /// Use the first encoding.
+    First(A),
+    /// Use the second encoding.
+    Second(B),
+}
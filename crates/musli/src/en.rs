@@ -19,5 +19,5 @@
 #[doc(inline)]
 pub use musli_core::en::{
     Encode, EncodeBytes, EncodePacked, EncodeTrace, Encoder, EntriesEncoder, EntryEncoder,
-    MapEncoder, SequenceEncoder, VariantEncoder,
+    MapEncoder, PodSlice, SequenceEncoder, VariantEncoder,
 };
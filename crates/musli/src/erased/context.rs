@@ -0,0 +1,621 @@
+use core::cell::RefCell;
+use core::fmt;
+
+use rust_alloc::boxed::Box;
+
+use crate::alloc::{self, String, System};
+use crate::context::ErrorMarker;
+use crate::no_std;
+use crate::Context;
+
+/// The context used by type-erased encoders and decoders.
+///
+/// Diagnostics reported to this context are forwarded to the context that the
+/// encoder or decoder was erased from, and the error that it produces is
+/// returned by [`erase_encoder`] or [`erase_decoder`] once the callback
+/// returns.
+///
+/// [`erase_encoder`]: super::erase_encoder
+/// [`erase_decoder`]: super::erase_decoder
+pub struct DynContext<'a, M> {
+    inner: &'a (dyn ErasedContext<M> + 'a),
+}
+
+impl<'a, M> DynContext<'a, M> {
+    #[inline]
+    pub(super) fn new(inner: &'a (dyn ErasedContext<M> + 'a)) -> Self {
+        Self { inner }
+    }
+}
+
+impl<M> Context for DynContext<'_, M>
+where
+    M: 'static,
+{
+    type Mode = M;
+    type Error = ErrorMarker;
+    type Mark = ();
+    type Allocator = System;
+    type String<'this> = String<'this, System>
+    where
+        Self: 'this;
+
+    #[inline]
+    fn clear(&self) {
+        self.inner.clear();
+    }
+
+    #[inline]
+    fn alloc(&self) -> &Self::Allocator {
+        &alloc::SYSTEM
+    }
+
+    #[inline]
+    fn collect_string<T>(&self, value: &T) -> Result<Self::String<'_>, Self::Error>
+    where
+        T: ?Sized + fmt::Display,
+    {
+        alloc::collect_string(self, value)
+    }
+
+    #[inline]
+    fn custom<T>(&self, error: T) -> Self::Error
+    where
+        T: 'static + Send + Sync + no_std::Error,
+    {
+        self.inner.custom(Box::new(error))
+    }
+
+    #[inline]
+    fn message<T>(&self, message: T) -> Self::Error
+    where
+        T: fmt::Display,
+    {
+        self.inner.message(&message)
+    }
+
+    #[inline]
+    fn advance(&self, n: usize) {
+        self.inner.advance(n);
+    }
+
+    #[inline]
+    fn invalid_variant_tag<T>(&self, name: &'static str, tag: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.inner.invalid_variant_tag(name, &tag)
+    }
+
+    #[inline]
+    fn expected_tag<T>(&self, name: &'static str, tag: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.inner.expected_tag(name, &tag)
+    }
+
+    #[inline]
+    fn uninhabitable(&self, name: &'static str) -> Self::Error {
+        self.inner.uninhabitable(name)
+    }
+
+    #[inline]
+    fn invalid_field_tag<T>(&self, name: &'static str, tag: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.inner.invalid_field_tag(name, &tag)
+    }
+
+    #[inline]
+    fn skipped_field<T>(&self, name: &'static str, tag: &T)
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.inner.skipped_field(name, &tag);
+    }
+
+    #[inline]
+    fn expected_field_adjacent<T, C>(&self, name: &'static str, tag: &T, content: &C) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+        C: ?Sized + fmt::Debug,
+    {
+        self.inner.expected_field_adjacent(name, &tag, &content)
+    }
+
+    #[inline]
+    fn missing_adjacent_tag<T>(&self, name: &'static str, tag: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.inner.missing_adjacent_tag(name, &tag)
+    }
+
+    #[inline]
+    fn missing_variant_field<T>(&self, name: &'static str, tag: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.inner.missing_variant_field(name, &tag)
+    }
+
+    #[inline]
+    fn missing_variant_tag(&self, name: &'static str) -> Self::Error {
+        self.inner.missing_variant_tag(name)
+    }
+
+    #[inline]
+    fn no_matching_variant(&self, name: &'static str) -> Self::Error {
+        self.inner.no_matching_variant(name)
+    }
+
+    #[inline]
+    fn unsupported_version<T>(&self, name: &'static str, version: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.inner.unsupported_version(name, &version)
+    }
+
+    #[inline]
+    fn invalid_variant_field_tag<V, T>(
+        &self,
+        name: &'static str,
+        variant: &V,
+        tag: &T,
+    ) -> Self::Error
+    where
+        V: ?Sized + fmt::Debug,
+        T: ?Sized + fmt::Debug,
+    {
+        self.inner.invalid_variant_field_tag(name, &variant, &tag)
+    }
+
+    #[inline]
+    fn alloc_failed(&self) -> Self::Error {
+        self.inner.alloc_failed()
+    }
+
+    #[inline]
+    fn accumulates_errors(&self) -> bool {
+        self.inner.accumulates_errors()
+    }
+
+    #[inline]
+    fn recoverable(&self) {
+        self.inner.recoverable();
+    }
+
+    #[inline]
+    fn take_recoverable(&self) -> bool {
+        self.inner.take_recoverable()
+    }
+
+    #[inline]
+    fn enter_depth(&self) -> Result<(), Self::Error> {
+        self.inner.enter_depth()
+    }
+
+    #[inline]
+    fn leave_depth(&self) {
+        self.inner.leave_depth();
+    }
+
+    #[inline]
+    fn enter_struct(&self, name: &'static str) {
+        self.inner.enter_struct(name);
+    }
+
+    #[inline]
+    fn leave_struct(&self) {
+        self.inner.leave_struct();
+    }
+
+    #[inline]
+    fn enter_enum(&self, name: &'static str) {
+        self.inner.enter_enum(name);
+    }
+
+    #[inline]
+    fn leave_enum(&self) {
+        self.inner.leave_enum();
+    }
+
+    #[inline]
+    fn enter_named_field<T>(&self, name: &'static str, tag: &T)
+    where
+        T: ?Sized + fmt::Display,
+    {
+        self.inner.enter_named_field(name, &tag);
+    }
+
+    #[inline]
+    fn enter_unnamed_field<T>(&self, index: u32, name: &T)
+    where
+        T: ?Sized + fmt::Display,
+    {
+        self.inner.enter_unnamed_field(index, &name);
+    }
+
+    #[inline]
+    fn leave_field(&self) {
+        self.inner.leave_field();
+    }
+
+    #[inline]
+    fn enter_variant<T>(&self, name: &'static str, tag: T)
+    where
+        T: fmt::Display,
+    {
+        self.inner.enter_variant(name, &tag);
+    }
+
+    #[inline]
+    fn leave_variant(&self) {
+        self.inner.leave_variant();
+    }
+
+    #[inline]
+    fn enter_map_key<T>(&self, field: T)
+    where
+        T: fmt::Display,
+    {
+        self.inner.enter_map_key(&field);
+    }
+
+    #[inline]
+    fn leave_map_key(&self) {
+        self.inner.leave_map_key();
+    }
+
+    #[inline]
+    fn enter_sequence_index(&self, index: usize) {
+        self.inner.enter_sequence_index(index);
+    }
+
+    #[inline]
+    fn leave_sequence_index(&self) {
+        self.inner.leave_sequence_index();
+    }
+}
+
+/// Object-safe subset of [`Context`] which [`DynContext`] forwards to.
+pub(super) trait ErasedContext<M> {
+    fn clear(&self);
+
+    fn custom(&self, error: Box<dyn no_std::Error + Send + Sync>) -> ErrorMarker;
+
+    fn message(&self, message: &dyn fmt::Display) -> ErrorMarker;
+
+    fn advance(&self, n: usize);
+
+    fn invalid_variant_tag(&self, name: &'static str, tag: &dyn fmt::Debug) -> ErrorMarker;
+
+    fn expected_tag(&self, name: &'static str, tag: &dyn fmt::Debug) -> ErrorMarker;
+
+    fn uninhabitable(&self, name: &'static str) -> ErrorMarker;
+
+    fn invalid_field_tag(&self, name: &'static str, tag: &dyn fmt::Debug) -> ErrorMarker;
+
+    fn skipped_field(&self, name: &'static str, tag: &dyn fmt::Debug);
+
+    fn expected_field_adjacent(
+        &self,
+        name: &'static str,
+        tag: &dyn fmt::Debug,
+        content: &dyn fmt::Debug,
+    ) -> ErrorMarker;
+
+    fn missing_adjacent_tag(&self, name: &'static str, tag: &dyn fmt::Debug) -> ErrorMarker;
+
+    fn missing_variant_field(&self, name: &'static str, tag: &dyn fmt::Debug) -> ErrorMarker;
+
+    fn missing_variant_tag(&self, name: &'static str) -> ErrorMarker;
+
+    fn no_matching_variant(&self, name: &'static str) -> ErrorMarker;
+
+    fn unsupported_version(&self, name: &'static str, version: &dyn fmt::Debug) -> ErrorMarker;
+
+    fn invalid_variant_field_tag(
+        &self,
+        name: &'static str,
+        variant: &dyn fmt::Debug,
+        tag: &dyn fmt::Debug,
+    ) -> ErrorMarker;
+
+    fn alloc_failed(&self) -> ErrorMarker;
+
+    fn accumulates_errors(&self) -> bool;
+
+    fn recoverable(&self);
+
+    fn take_recoverable(&self) -> bool;
+
+    fn enter_depth(&self) -> Result<(), ErrorMarker>;
+
+    fn leave_depth(&self);
+
+    fn enter_struct(&self, name: &'static str);
+
+    fn leave_struct(&self);
+
+    fn enter_enum(&self, name: &'static str);
+
+    fn leave_enum(&self);
+
+    fn enter_named_field(&self, name: &'static str, tag: &dyn fmt::Display);
+
+    fn enter_unnamed_field(&self, index: u32, name: &dyn fmt::Display);
+
+    fn leave_field(&self);
+
+    fn enter_variant(&self, name: &'static str, tag: &dyn fmt::Display);
+
+    fn leave_variant(&self);
+
+    fn enter_map_key(&self, field: &dyn fmt::Display);
+
+    fn leave_map_key(&self);
+
+    fn enter_sequence_index(&self, index: usize);
+
+    fn leave_sequence_index(&self);
+}
+
+/// Forwards diagnostics to a concrete context, holding on to the error it
+/// produced until it's restored.
+pub(super) struct Bridge<'a, C>
+where
+    C: ?Sized + Context,
+{
+    cx: &'a C,
+    error: RefCell<Option<C::Error>>,
+}
+
+impl<'a, C> Bridge<'a, C>
+where
+    C: ?Sized + Context,
+{
+    #[inline]
+    pub(super) fn new(cx: &'a C) -> Self {
+        Self {
+            cx,
+            error: RefCell::new(None),
+        }
+    }
+
+    /// Take the error which was captured when erased encoding or decoding
+    /// failed.
+    pub(super) fn restore(&self) -> C::Error {
+        match self.error.borrow_mut().take() {
+            Some(error) => error,
+            None => self
+                .cx
+                .message("error during type-erased encoding or decoding (no information)"),
+        }
+    }
+
+    #[inline]
+    fn capture(&self, error: C::Error) -> ErrorMarker {
+        *self.error.borrow_mut() = Some(error);
+        ErrorMarker
+    }
+}
+
+impl<C> ErasedContext<C::Mode> for Bridge<'_, C>
+where
+    C: ?Sized + Context,
+{
+    #[inline]
+    fn clear(&self) {
+        self.cx.clear();
+        *self.error.borrow_mut() = None;
+    }
+
+    #[inline]
+    fn custom(&self, error: Box<dyn no_std::Error + Send + Sync>) -> ErrorMarker {
+        self.capture(self.cx.custom(CustomError(error)))
+    }
+
+    #[inline]
+    fn message(&self, message: &dyn fmt::Display) -> ErrorMarker {
+        self.capture(self.cx.message(message))
+    }
+
+    #[inline]
+    fn advance(&self, n: usize) {
+        self.cx.advance(n);
+    }
+
+    #[inline]
+    fn invalid_variant_tag(&self, name: &'static str, tag: &dyn fmt::Debug) -> ErrorMarker {
+        self.capture(self.cx.invalid_variant_tag(name, tag))
+    }
+
+    #[inline]
+    fn expected_tag(&self, name: &'static str, tag: &dyn fmt::Debug) -> ErrorMarker {
+        self.capture(self.cx.expected_tag(name, tag))
+    }
+
+    #[inline]
+    fn uninhabitable(&self, name: &'static str) -> ErrorMarker {
+        self.capture(self.cx.uninhabitable(name))
+    }
+
+    #[inline]
+    fn invalid_field_tag(&self, name: &'static str, tag: &dyn fmt::Debug) -> ErrorMarker {
+        self.capture(self.cx.invalid_field_tag(name, tag))
+    }
+
+    #[inline]
+    fn skipped_field(&self, name: &'static str, tag: &dyn fmt::Debug) {
+        self.cx.skipped_field(name, tag);
+    }
+
+    #[inline]
+    fn expected_field_adjacent(
+        &self,
+        name: &'static str,
+        tag: &dyn fmt::Debug,
+        content: &dyn fmt::Debug,
+    ) -> ErrorMarker {
+        self.capture(self.cx.expected_field_adjacent(name, tag, content))
+    }
+
+    #[inline]
+    fn missing_adjacent_tag(&self, name: &'static str, tag: &dyn fmt::Debug) -> ErrorMarker {
+        self.capture(self.cx.missing_adjacent_tag(name, tag))
+    }
+
+    #[inline]
+    fn missing_variant_field(&self, name: &'static str, tag: &dyn fmt::Debug) -> ErrorMarker {
+        self.capture(self.cx.missing_variant_field(name, tag))
+    }
+
+    #[inline]
+    fn missing_variant_tag(&self, name: &'static str) -> ErrorMarker {
+        self.capture(self.cx.missing_variant_tag(name))
+    }
+
+    #[inline]
+    fn no_matching_variant(&self, name: &'static str) -> ErrorMarker {
+        self.capture(self.cx.no_matching_variant(name))
+    }
+
+    #[inline]
+    fn unsupported_version(&self, name: &'static str, version: &dyn fmt::Debug) -> ErrorMarker {
+        self.capture(self.cx.unsupported_version(name, version))
+    }
+
+    #[inline]
+    fn invalid_variant_field_tag(
+        &self,
+        name: &'static str,
+        variant: &dyn fmt::Debug,
+        tag: &dyn fmt::Debug,
+    ) -> ErrorMarker {
+        self.capture(self.cx.invalid_variant_field_tag(name, variant, tag))
+    }
+
+    #[inline]
+    fn alloc_failed(&self) -> ErrorMarker {
+        self.capture(self.cx.alloc_failed())
+    }
+
+    #[inline]
+    fn accumulates_errors(&self) -> bool {
+        self.cx.accumulates_errors()
+    }
+
+    #[inline]
+    fn recoverable(&self) {
+        self.cx.recoverable();
+    }
+
+    #[inline]
+    fn take_recoverable(&self) -> bool {
+        self.cx.take_recoverable()
+    }
+
+    #[inline]
+    fn enter_depth(&self) -> Result<(), ErrorMarker> {
+        self.cx.enter_depth().map_err(|error| self.capture(error))
+    }
+
+    #[inline]
+    fn leave_depth(&self) {
+        self.cx.leave_depth();
+    }
+
+    #[inline]
+    fn enter_struct(&self, name: &'static str) {
+        self.cx.enter_struct(name);
+    }
+
+    #[inline]
+    fn leave_struct(&self) {
+        self.cx.leave_struct();
+    }
+
+    #[inline]
+    fn enter_enum(&self, name: &'static str) {
+        self.cx.enter_enum(name);
+    }
+
+    #[inline]
+    fn leave_enum(&self) {
+        self.cx.leave_enum();
+    }
+
+    #[inline]
+    fn enter_named_field(&self, name: &'static str, tag: &dyn fmt::Display) {
+        self.cx.enter_named_field(name, tag);
+    }
+
+    #[inline]
+    fn enter_unnamed_field(&self, index: u32, name: &dyn fmt::Display) {
+        self.cx.enter_unnamed_field(index, name);
+    }
+
+    #[inline]
+    fn leave_field(&self) {
+        self.cx.leave_field();
+    }
+
+    #[inline]
+    fn enter_variant(&self, name: &'static str, tag: &dyn fmt::Display) {
+        self.cx.enter_variant(name, tag);
+    }
+
+    #[inline]
+    fn leave_variant(&self) {
+        self.cx.leave_variant();
+    }
+
+    #[inline]
+    fn enter_map_key(&self, field: &dyn fmt::Display) {
+        self.cx.enter_map_key(field);
+    }
+
+    #[inline]
+    fn leave_map_key(&self) {
+        self.cx.leave_map_key();
+    }
+
+    #[inline]
+    fn enter_sequence_index(&self, index: usize) {
+        self.cx.enter_sequence_index(index);
+    }
+
+    #[inline]
+    fn leave_sequence_index(&self) {
+        self.cx.leave_sequence_index();
+    }
+}
+
+/// A custom error reported through a [`DynContext`].
+struct CustomError(Box<dyn no_std::Error + Send + Sync>);
+
+impl fmt::Debug for CustomError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for CustomError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CustomError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.0.source()
+    }
+}
@@ -0,0 +1,1852 @@
+use core::fmt;
+
+use rust_alloc::borrow::ToOwned;
+use rust_alloc::boxed::Box;
+
+use crate::context::ErrorMarker;
+use crate::de::{
+    Decoder, EntriesDecoder, EntryDecoder, MapDecoder, PodSliceMut, SequenceDecoder, SizeHint,
+    Skip, TypeHint, UnsizedVisitor, VariantDecoder, Visitor,
+};
+use crate::hint::{MapHint, SequenceHint};
+use crate::Context;
+
+use super::DynContext;
+
+/// A type-erased [`Decoder`].
+///
+/// See the [module level documentation][super].
+pub struct DynDecoder<'a, 'cx, 'de, M>
+where
+    'cx: 'a,
+    'de: 'a,
+{
+    cx: &'cx DynContext<'cx, M>,
+    inner: Box<dyn ErasedDecoder<'a, 'cx, 'de, M> + 'a>,
+}
+
+impl<'a, 'cx, 'de, M> DynDecoder<'a, 'cx, 'de, M>
+where
+    'cx: 'a,
+    'de: 'a,
+    M: 'static,
+{
+    /// Erase the type of the given decoder.
+    pub(super) fn erase<D>(cx: &'cx DynContext<'cx, M>, decoder: D) -> Self
+    where
+        D: 'a + Decoder<'de, Cx = DynContext<'cx, M>, Error = ErrorMarker, Mode = M>,
+    {
+        Self {
+            cx,
+            inner: Box::new(DecoderAdapter { decoder }),
+        }
+    }
+}
+
+/// Call a method on an erased decoder which lends a nested decoder to a
+/// callback, and pass the nested decoder on to `$f` wrapped in `$wrapper`.
+macro_rules! lend {
+    ($this:ident, $f:ident, $wrapper:ident, $method:ident($($arg:expr),*)) => {{
+        let cx = $this.cx;
+        let mut f = Some($f);
+        let mut output = None;
+
+        $this.inner.$method($($arg,)* &mut |inner| {
+            let Some(f) = f.take() else {
+                return Err(cx.message("Type-erased decoder callback called more than once"));
+            };
+
+            output = Some(f(&mut $wrapper { cx, inner })?);
+            Ok(())
+        })?;
+
+        match output {
+            Some(output) => Ok(output),
+            None => Err(cx.message("Type-erased decoder callback was not called")),
+        }
+    }};
+}
+
+#[crate::decoder(crate)]
+impl<'a, 'cx, 'de, M> Decoder<'de> for DynDecoder<'a, 'cx, 'de, M>
+where
+    'cx: 'a,
+    'de: 'a,
+    M: 'static,
+{
+    type Cx = DynContext<'cx, M>;
+    type Error = ErrorMarker;
+    type Mode = M;
+    type DecodeSome = Self;
+    type DecodePack<'this> = DynSequenceDecoder<'this, 'cx, 'de, M>;
+    type DecodeSequence<'this> = DynSequenceDecoder<'this, 'cx, 'de, M>;
+    type DecodeMap<'this> = DynMapDecoder<'this, 'cx, 'de, M>;
+    type DecodeMapEntries<'this> = DynEntriesDecoder<'this, 'cx, 'de, M>;
+    type DecodeVariant<'this> = DynVariantDecoder<'this, 'cx, 'de, M>;
+
+    #[inline]
+    fn cx(&self) -> &Self::Cx {
+        self.cx
+    }
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    #[inline]
+    fn skip(self) -> Result<(), ErrorMarker> {
+        self.inner.skip()
+    }
+
+    #[inline]
+    fn try_skip(self) -> Result<Skip, ErrorMarker> {
+        self.inner.try_skip()
+    }
+
+    #[inline]
+    fn type_hint(&mut self) -> Result<TypeHint, ErrorMarker> {
+        self.inner.type_hint()
+    }
+
+    #[inline]
+    fn decode_empty(self) -> Result<(), ErrorMarker> {
+        self.inner.decode_empty()
+    }
+
+    #[inline]
+    fn decode_bool(self) -> Result<bool, ErrorMarker> {
+        self.inner.decode_bool()
+    }
+
+    #[inline]
+    fn decode_char(self) -> Result<char, ErrorMarker> {
+        self.inner.decode_char()
+    }
+
+    #[inline]
+    fn decode_u8(self) -> Result<u8, ErrorMarker> {
+        self.inner.decode_u8()
+    }
+
+    #[inline]
+    fn decode_u16(self) -> Result<u16, ErrorMarker> {
+        self.inner.decode_u16()
+    }
+
+    #[inline]
+    fn decode_u32(self) -> Result<u32, ErrorMarker> {
+        self.inner.decode_u32()
+    }
+
+    #[inline]
+    fn decode_u64(self) -> Result<u64, ErrorMarker> {
+        self.inner.decode_u64()
+    }
+
+    #[inline]
+    fn decode_u128(self) -> Result<u128, ErrorMarker> {
+        self.inner.decode_u128()
+    }
+
+    #[inline]
+    fn decode_i8(self) -> Result<i8, ErrorMarker> {
+        self.inner.decode_i8()
+    }
+
+    #[inline]
+    fn decode_i16(self) -> Result<i16, ErrorMarker> {
+        self.inner.decode_i16()
+    }
+
+    #[inline]
+    fn decode_i32(self) -> Result<i32, ErrorMarker> {
+        self.inner.decode_i32()
+    }
+
+    #[inline]
+    fn decode_i64(self) -> Result<i64, ErrorMarker> {
+        self.inner.decode_i64()
+    }
+
+    #[inline]
+    fn decode_i128(self) -> Result<i128, ErrorMarker> {
+        self.inner.decode_i128()
+    }
+
+    #[inline]
+    fn decode_usize(self) -> Result<usize, ErrorMarker> {
+        self.inner.decode_usize()
+    }
+
+    #[inline]
+    fn decode_isize(self) -> Result<isize, ErrorMarker> {
+        self.inner.decode_isize()
+    }
+
+    #[inline]
+    fn decode_f32(self) -> Result<f32, ErrorMarker> {
+        self.inner.decode_f32()
+    }
+
+    #[inline]
+    fn decode_f64(self) -> Result<f64, ErrorMarker> {
+        self.inner.decode_f64()
+    }
+
+    #[inline]
+    fn decode_array<const N: usize>(self) -> Result<[u8; N], ErrorMarker> {
+        let mut out = [0; N];
+        self.inner.decode_array(&mut out)?;
+        Ok(out)
+    }
+
+    #[inline]
+    fn decode_array_slice(self, out: &mut [u8]) -> Result<(), ErrorMarker> {
+        self.inner.decode_array(out)
+    }
+
+    #[inline]
+    fn decode_bytes<V>(self, visitor: V) -> Result<V::Ok, ErrorMarker>
+    where
+        V: UnsizedVisitor<'de, Self::Cx, [u8]>,
+    {
+        let mut slot = UnsizedVisitorSlot::new(self.cx, visitor);
+        self.inner.decode_bytes(&mut slot)?;
+        slot.finish()
+    }
+
+    #[inline]
+    fn decode_string<V>(self, visitor: V) -> Result<V::Ok, ErrorMarker>
+    where
+        V: UnsizedVisitor<'de, Self::Cx, str>,
+    {
+        let mut slot = UnsizedVisitorSlot::new(self.cx, visitor);
+        self.inner.decode_string(&mut slot)?;
+        slot.finish()
+    }
+
+    #[inline]
+    fn decode_option(self) -> Result<Option<Self::DecodeSome>, ErrorMarker> {
+        let Some(inner) = self.inner.decode_option()? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self { cx: self.cx, inner }))
+    }
+
+    #[inline]
+    fn decode_pack<F, O>(self, f: F) -> Result<O, ErrorMarker>
+    where
+        F: FnOnce(&mut Self::DecodePack<'_>) -> Result<O, ErrorMarker>,
+    {
+        lend!(self, f, DynSequenceDecoder, decode_pack())
+    }
+
+    #[inline]
+    fn decode_sequence<F, O>(self, f: F) -> Result<O, ErrorMarker>
+    where
+        F: FnOnce(&mut Self::DecodeSequence<'_>) -> Result<O, ErrorMarker>,
+    {
+        lend!(self, f, DynSequenceDecoder, decode_sequence(None))
+    }
+
+    #[inline]
+    fn decode_sequence_hint<F, O>(self, hint: &SequenceHint, f: F) -> Result<O, ErrorMarker>
+    where
+        F: FnOnce(&mut Self::DecodeSequence<'_>) -> Result<O, ErrorMarker>,
+    {
+        lend!(self, f, DynSequenceDecoder, decode_sequence(Some(hint)))
+    }
+
+    #[inline]
+    fn decode_map<F, O>(self, f: F) -> Result<O, ErrorMarker>
+    where
+        F: FnOnce(&mut Self::DecodeMap<'_>) -> Result<O, ErrorMarker>,
+    {
+        lend!(self, f, DynMapDecoder, decode_map(None))
+    }
+
+    #[inline]
+    fn decode_map_hint<F, O>(self, hint: &MapHint, f: F) -> Result<O, ErrorMarker>
+    where
+        F: FnOnce(&mut Self::DecodeMap<'_>) -> Result<O, ErrorMarker>,
+    {
+        lend!(self, f, DynMapDecoder, decode_map(Some(hint)))
+    }
+
+    #[inline]
+    fn decode_map_entries<F, O>(self, f: F) -> Result<O, ErrorMarker>
+    where
+        F: FnOnce(&mut Self::DecodeMapEntries<'_>) -> Result<O, ErrorMarker>,
+    {
+        lend!(self, f, DynEntriesDecoder, decode_map_entries())
+    }
+
+    #[inline]
+    fn decode_variant<F, O>(self, f: F) -> Result<O, ErrorMarker>
+    where
+        F: FnOnce(&mut Self::DecodeVariant<'_>) -> Result<O, ErrorMarker>,
+    {
+        lend!(self, f, DynVariantDecoder, decode_variant())
+    }
+
+    #[inline]
+    fn decode_number<V>(self, visitor: V) -> Result<V::Ok, ErrorMarker>
+    where
+        V: Visitor<'de, Self::Cx>,
+    {
+        let mut slot = VisitorSlot::new(self.cx, visitor);
+        self.inner.decode_number(&mut slot)?;
+        slot.finish()
+    }
+
+    #[inline]
+    fn decode_any<V>(self, visitor: V) -> Result<V::Ok, ErrorMarker>
+    where
+        V: Visitor<'de, Self::Cx>,
+    {
+        let mut slot = VisitorSlot::new(self.cx, visitor);
+        self.inner.decode_any(&mut slot)?;
+        slot.finish()
+    }
+}
+
+/// A type-erased [`SequenceDecoder`].
+///
+/// See the [module level documentation][super].
+pub struct DynSequenceDecoder<'a, 'cx, 'de, M> {
+    cx: &'cx DynContext<'cx, M>,
+    inner: Box<dyn ErasedSequenceDecoder<'cx, 'de, M> + 'a>,
+}
+
+impl<'cx, 'de, M> SequenceDecoder<'de> for DynSequenceDecoder<'_, 'cx, 'de, M>
+where
+    M: 'static,
+{
+    type Cx = DynContext<'cx, M>;
+    type DecodeNext<'this> = DynDecoder<'this, 'cx, 'de, M> where Self: 'this;
+
+    #[inline]
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn decode_next(&mut self) -> Result<Self::DecodeNext<'_>, ErrorMarker> {
+        let inner = self.inner.decode_next()?;
+        Ok(DynDecoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn try_decode_next(&mut self) -> Result<Option<Self::DecodeNext<'_>>, ErrorMarker> {
+        let Some(inner) = self.inner.try_decode_next()? else {
+            return Ok(None);
+        };
+
+        Ok(Some(DynDecoder { cx: self.cx, inner }))
+    }
+
+    #[inline]
+    fn try_decode_pod_into(&mut self, out: PodSliceMut<'_>) -> Result<bool, ErrorMarker> {
+        self.inner.try_decode_pod_into(out)
+    }
+}
+
+/// A type-erased [`MapDecoder`].
+///
+/// See the [module level documentation][super].
+pub struct DynMapDecoder<'a, 'cx, 'de, M> {
+    cx: &'cx DynContext<'cx, M>,
+    inner: Box<dyn ErasedMapDecoder<'cx, 'de, M> + 'a>,
+}
+
+impl<'cx, 'de, M> MapDecoder<'de> for DynMapDecoder<'_, 'cx, 'de, M>
+where
+    M: 'static,
+{
+    type Cx = DynContext<'cx, M>;
+    type DecodeEntry<'this> = DynEntryDecoder<'this, 'cx, 'de, M> where Self: 'this;
+    type DecodeRemainingEntries<'this> = DynEntriesDecoder<'this, 'cx, 'de, M> where Self: 'this;
+
+    #[inline]
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn decode_entry(&mut self) -> Result<Option<Self::DecodeEntry<'_>>, ErrorMarker> {
+        let Some(inner) = self.inner.decode_entry()? else {
+            return Ok(None);
+        };
+
+        Ok(Some(DynEntryDecoder { cx: self.cx, inner }))
+    }
+
+    #[inline]
+    fn decode_remaining_entries(
+        &mut self,
+    ) -> Result<Self::DecodeRemainingEntries<'_>, ErrorMarker> {
+        let inner = self.inner.decode_remaining_entries()?;
+        Ok(DynEntriesDecoder { cx: self.cx, inner })
+    }
+}
+
+/// A type-erased [`EntryDecoder`].
+///
+/// See the [module level documentation][super].
+pub struct DynEntryDecoder<'a, 'cx, 'de, M> {
+    cx: &'cx DynContext<'cx, M>,
+    inner: Box<dyn ErasedEntryDecoder<'a, 'cx, 'de, M> + 'a>,
+}
+
+impl<'a, 'cx, 'de, M> EntryDecoder<'de> for DynEntryDecoder<'a, 'cx, 'de, M>
+where
+    'cx: 'a,
+    'de: 'a,
+    M: 'static,
+{
+    type Cx = DynContext<'cx, M>;
+    type DecodeKey<'this> = DynDecoder<'this, 'cx, 'de, M> where Self: 'this;
+    type DecodeValue = DynDecoder<'a, 'cx, 'de, M>;
+
+    #[inline]
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn decode_key(&mut self) -> Result<Self::DecodeKey<'_>, ErrorMarker> {
+        let inner = self.inner.decode_key()?;
+        Ok(DynDecoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn decode_value(self) -> Result<Self::DecodeValue, ErrorMarker> {
+        let inner = self.inner.decode_value()?;
+        Ok(DynDecoder { cx: self.cx, inner })
+    }
+}
+
+/// A type-erased [`EntriesDecoder`].
+///
+/// See the [module level documentation][super].
+pub struct DynEntriesDecoder<'a, 'cx, 'de, M> {
+    cx: &'cx DynContext<'cx, M>,
+    inner: Box<dyn ErasedEntriesDecoder<'cx, 'de, M> + 'a>,
+}
+
+impl<'cx, 'de, M> EntriesDecoder<'de> for DynEntriesDecoder<'_, 'cx, 'de, M>
+where
+    M: 'static,
+{
+    type Cx = DynContext<'cx, M>;
+    type DecodeEntryKey<'this> = DynDecoder<'this, 'cx, 'de, M> where Self: 'this;
+    type DecodeEntryValue<'this> = DynDecoder<'this, 'cx, 'de, M> where Self: 'this;
+
+    #[inline]
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn decode_entry_key(&mut self) -> Result<Option<Self::DecodeEntryKey<'_>>, ErrorMarker> {
+        let Some(inner) = self.inner.decode_entry_key()? else {
+            return Ok(None);
+        };
+
+        Ok(Some(DynDecoder { cx: self.cx, inner }))
+    }
+
+    #[inline]
+    fn decode_entry_value(&mut self) -> Result<Self::DecodeEntryValue<'_>, ErrorMarker> {
+        let inner = self.inner.decode_entry_value()?;
+        Ok(DynDecoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn end_entries(self) -> Result<(), ErrorMarker> {
+        self.inner.end_entries()
+    }
+}
+
+/// A type-erased [`VariantDecoder`].
+///
+/// See the [module level documentation][super].
+pub struct DynVariantDecoder<'a, 'cx, 'de, M> {
+    cx: &'cx DynContext<'cx, M>,
+    inner: Box<dyn ErasedVariantDecoder<'cx, 'de, M> + 'a>,
+}
+
+impl<'cx, 'de, M> VariantDecoder<'de> for DynVariantDecoder<'_, 'cx, 'de, M>
+where
+    M: 'static,
+{
+    type Cx = DynContext<'cx, M>;
+    type DecodeTag<'this> = DynDecoder<'this, 'cx, 'de, M> where Self: 'this;
+    type DecodeValue<'this> = DynDecoder<'this, 'cx, 'de, M> where Self: 'this;
+
+    #[inline]
+    fn decode_tag(&mut self) -> Result<Self::DecodeTag<'_>, ErrorMarker> {
+        let inner = self.inner.decode_tag()?;
+        Ok(DynDecoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn decode_value(&mut self) -> Result<Self::DecodeValue<'_>, ErrorMarker> {
+        let inner = self.inner.decode_value()?;
+        Ok(DynDecoder { cx: self.cx, inner })
+    }
+}
+
+/// Object-safe version of [`Decoder`].
+///
+/// Methods which lend out a nested decoder take a callback which is called
+/// with the erased nested decoder, and visitors are passed in as mutable
+/// references which keep track of the value they produce.
+trait ErasedDecoder<'a, 'cx, 'de, M> {
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    fn skip(self: Box<Self>) -> Result<(), ErrorMarker>;
+
+    fn try_skip(self: Box<Self>) -> Result<Skip, ErrorMarker>;
+
+    fn type_hint(&mut self) -> Result<TypeHint, ErrorMarker>;
+
+    fn decode_empty(self: Box<Self>) -> Result<(), ErrorMarker>;
+
+    fn decode_bool(self: Box<Self>) -> Result<bool, ErrorMarker>;
+
+    fn decode_char(self: Box<Self>) -> Result<char, ErrorMarker>;
+
+    fn decode_u8(self: Box<Self>) -> Result<u8, ErrorMarker>;
+
+    fn decode_u16(self: Box<Self>) -> Result<u16, ErrorMarker>;
+
+    fn decode_u32(self: Box<Self>) -> Result<u32, ErrorMarker>;
+
+    fn decode_u64(self: Box<Self>) -> Result<u64, ErrorMarker>;
+
+    fn decode_u128(self: Box<Self>) -> Result<u128, ErrorMarker>;
+
+    fn decode_i8(self: Box<Self>) -> Result<i8, ErrorMarker>;
+
+    fn decode_i16(self: Box<Self>) -> Result<i16, ErrorMarker>;
+
+    fn decode_i32(self: Box<Self>) -> Result<i32, ErrorMarker>;
+
+    fn decode_i64(self: Box<Self>) -> Result<i64, ErrorMarker>;
+
+    fn decode_i128(self: Box<Self>) -> Result<i128, ErrorMarker>;
+
+    fn decode_usize(self: Box<Self>) -> Result<usize, ErrorMarker>;
+
+    fn decode_isize(self: Box<Self>) -> Result<isize, ErrorMarker>;
+
+    fn decode_f32(self: Box<Self>) -> Result<f32, ErrorMarker>;
+
+    fn decode_f64(self: Box<Self>) -> Result<f64, ErrorMarker>;
+
+    fn decode_array(self: Box<Self>, out: &mut [u8]) -> Result<(), ErrorMarker>;
+
+    fn decode_bytes(
+        self: Box<Self>,
+        visitor: &mut dyn ErasedUnsizedVisitor<'de, [u8]>,
+    ) -> Result<(), ErrorMarker>;
+
+    fn decode_string(
+        self: Box<Self>,
+        visitor: &mut dyn ErasedUnsizedVisitor<'de, str>,
+    ) -> Result<(), ErrorMarker>;
+
+    fn decode_option(
+        self: Box<Self>,
+    ) -> Result<Option<Box<dyn ErasedDecoder<'a, 'cx, 'de, M> + 'a>>, ErrorMarker>;
+
+    fn decode_pack(
+        self: Box<Self>,
+        f: &mut dyn FnMut(
+            Box<dyn ErasedSequenceDecoder<'cx, 'de, M> + '_>,
+        ) -> Result<(), ErrorMarker>,
+    ) -> Result<(), ErrorMarker>;
+
+    fn decode_sequence(
+        self: Box<Self>,
+        hint: Option<&SequenceHint>,
+        f: &mut dyn FnMut(
+            Box<dyn ErasedSequenceDecoder<'cx, 'de, M> + '_>,
+        ) -> Result<(), ErrorMarker>,
+    ) -> Result<(), ErrorMarker>;
+
+    fn decode_map(
+        self: Box<Self>,
+        hint: Option<&MapHint>,
+        f: &mut dyn FnMut(Box<dyn ErasedMapDecoder<'cx, 'de, M> + '_>) -> Result<(), ErrorMarker>,
+    ) -> Result<(), ErrorMarker>;
+
+    #[allow(clippy::type_complexity)]
+    fn decode_map_entries(
+        self: Box<Self>,
+        f: &mut dyn FnMut(
+            Box<dyn ErasedEntriesDecoder<'cx, 'de, M> + '_>,
+        ) -> Result<(), ErrorMarker>,
+    ) -> Result<(), ErrorMarker>;
+
+    fn decode_variant(
+        self: Box<Self>,
+        f: &mut dyn FnMut(
+            Box<dyn ErasedVariantDecoder<'cx, 'de, M> + '_>,
+        ) -> Result<(), ErrorMarker>,
+    ) -> Result<(), ErrorMarker>;
+
+    fn decode_number(
+        self: Box<Self>,
+        visitor: &mut dyn ErasedVisitor<'cx, 'de, M>,
+    ) -> Result<(), ErrorMarker>;
+
+    fn decode_any(
+        self: Box<Self>,
+        visitor: &mut dyn ErasedVisitor<'cx, 'de, M>,
+    ) -> Result<(), ErrorMarker>;
+}
+
+trait ErasedSequenceDecoder<'cx, 'de, M> {
+    fn size_hint(&self) -> SizeHint;
+
+    fn decode_next<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this;
+
+    #[allow(clippy::type_complexity)]
+    fn try_decode_next<'this>(
+        &'this mut self,
+    ) -> Result<Option<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>>, ErrorMarker>
+    where
+        'de: 'this;
+
+    fn try_decode_pod_into(&mut self, out: PodSliceMut<'_>) -> Result<bool, ErrorMarker>;
+}
+
+trait ErasedMapDecoder<'cx, 'de, M> {
+    fn size_hint(&self) -> SizeHint;
+
+    #[allow(clippy::type_complexity)]
+    fn decode_entry<'this>(
+        &'this mut self,
+    ) -> Result<Option<Box<dyn ErasedEntryDecoder<'this, 'cx, 'de, M> + 'this>>, ErrorMarker>
+    where
+        'de: 'this;
+
+    fn decode_remaining_entries<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedEntriesDecoder<'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this;
+}
+
+trait ErasedEntryDecoder<'a, 'cx, 'de, M> {
+    fn size_hint(&self) -> SizeHint;
+
+    fn decode_key<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this;
+
+    fn decode_value(
+        self: Box<Self>,
+    ) -> Result<Box<dyn ErasedDecoder<'a, 'cx, 'de, M> + 'a>, ErrorMarker>;
+}
+
+trait ErasedEntriesDecoder<'cx, 'de, M> {
+    fn size_hint(&self) -> SizeHint;
+
+    #[allow(clippy::type_complexity)]
+    fn decode_entry_key<'this>(
+        &'this mut self,
+    ) -> Result<Option<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>>, ErrorMarker>
+    where
+        'de: 'this;
+
+    fn decode_entry_value<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this;
+
+    fn end_entries(self: Box<Self>) -> Result<(), ErrorMarker>;
+}
+
+trait ErasedVariantDecoder<'cx, 'de, M> {
+    fn decode_tag<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this;
+
+    fn decode_value<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this;
+}
+
+/// Object-safe version of [`Visitor`].
+///
+/// The value produced by the visitor is stored in the visitor itself.
+trait ErasedVisitor<'cx, 'de, M> {
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    fn visit_empty(&mut self) -> Result<(), ErrorMarker>;
+
+    fn visit_bool(&mut self, value: bool) -> Result<(), ErrorMarker>;
+
+    fn visit_char(&mut self, value: char) -> Result<(), ErrorMarker>;
+
+    fn visit_u8(&mut self, value: u8) -> Result<(), ErrorMarker>;
+
+    fn visit_u16(&mut self, value: u16) -> Result<(), ErrorMarker>;
+
+    fn visit_u32(&mut self, value: u32) -> Result<(), ErrorMarker>;
+
+    fn visit_u64(&mut self, value: u64) -> Result<(), ErrorMarker>;
+
+    fn visit_u128(&mut self, value: u128) -> Result<(), ErrorMarker>;
+
+    fn visit_i8(&mut self, value: i8) -> Result<(), ErrorMarker>;
+
+    fn visit_i16(&mut self, value: i16) -> Result<(), ErrorMarker>;
+
+    fn visit_i32(&mut self, value: i32) -> Result<(), ErrorMarker>;
+
+    fn visit_i64(&mut self, value: i64) -> Result<(), ErrorMarker>;
+
+    fn visit_i128(&mut self, value: i128) -> Result<(), ErrorMarker>;
+
+    fn visit_usize(&mut self, value: usize) -> Result<(), ErrorMarker>;
+
+    fn visit_isize(&mut self, value: isize) -> Result<(), ErrorMarker>;
+
+    fn visit_f32(&mut self, value: f32) -> Result<(), ErrorMarker>;
+
+    fn visit_f64(&mut self, value: f64) -> Result<(), ErrorMarker>;
+    fn visit_big_number(&mut self, number: &str) -> Result<(), ErrorMarker>;
+
+    fn visit_option<'x>(
+        &mut self,
+        decoder: Option<Box<dyn ErasedDecoder<'x, 'cx, 'de, M> + 'x>>,
+    ) -> Result<(), ErrorMarker>
+    where
+        'cx: 'x,
+        'de: 'x;
+
+    fn visit_sequence(
+        &mut self,
+        decoder: Box<dyn ErasedSequenceDecoder<'cx, 'de, M> + '_>,
+    ) -> Result<(), ErrorMarker>;
+
+    fn visit_map(
+        &mut self,
+        decoder: Box<dyn ErasedMapDecoder<'cx, 'de, M> + '_>,
+    ) -> Result<(), ErrorMarker>;
+
+    fn visit_string(
+        &mut self,
+        hint: SizeHint,
+    ) -> Result<&mut dyn ErasedUnsizedVisitor<'de, str>, ErrorMarker>;
+
+    fn visit_bytes(
+        &mut self,
+        hint: SizeHint,
+    ) -> Result<&mut dyn ErasedUnsizedVisitor<'de, [u8]>, ErrorMarker>;
+
+    fn visit_variant(
+        &mut self,
+        decoder: Box<dyn ErasedVariantDecoder<'cx, 'de, M> + '_>,
+    ) -> Result<(), ErrorMarker>;
+
+    fn visit_unknown<'x>(
+        &mut self,
+        decoder: Box<dyn ErasedDecoder<'x, 'cx, 'de, M> + 'x>,
+    ) -> Result<(), ErrorMarker>
+    where
+        'cx: 'x,
+        'de: 'x;
+}
+
+/// Object-safe version of [`UnsizedVisitor`].
+trait ErasedUnsizedVisitor<'de, T>
+where
+    T: ?Sized + ToOwned,
+{
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    fn visit_owned(&mut self, value: T::Owned) -> Result<(), ErrorMarker>;
+
+    fn visit_borrowed(&mut self, value: &'de T) -> Result<(), ErrorMarker>;
+
+    fn visit_ref(&mut self, value: &T) -> Result<(), ErrorMarker>;
+}
+
+/// Adapts a concrete decoder to [`ErasedDecoder`].
+struct DecoderAdapter<D> {
+    decoder: D,
+}
+
+impl<'a, 'cx, 'de, M, D> ErasedDecoder<'a, 'cx, 'de, M> for DecoderAdapter<D>
+where
+    'de: 'a,
+    M: 'static,
+    D: 'a + Decoder<'de, Cx = DynContext<'cx, M>, Error = ErrorMarker, Mode = M>,
+{
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.decoder.expecting(f)
+    }
+
+    #[inline]
+    fn skip(self: Box<Self>) -> Result<(), ErrorMarker> {
+        self.decoder.skip()
+    }
+
+    #[inline]
+    fn try_skip(self: Box<Self>) -> Result<Skip, ErrorMarker> {
+        self.decoder.try_skip()
+    }
+
+    #[inline]
+    fn type_hint(&mut self) -> Result<TypeHint, ErrorMarker> {
+        self.decoder.type_hint()
+    }
+
+    #[inline]
+    fn decode_empty(self: Box<Self>) -> Result<(), ErrorMarker> {
+        self.decoder.decode_empty()
+    }
+
+    #[inline]
+    fn decode_bool(self: Box<Self>) -> Result<bool, ErrorMarker> {
+        self.decoder.decode_bool()
+    }
+
+    #[inline]
+    fn decode_char(self: Box<Self>) -> Result<char, ErrorMarker> {
+        self.decoder.decode_char()
+    }
+
+    #[inline]
+    fn decode_u8(self: Box<Self>) -> Result<u8, ErrorMarker> {
+        self.decoder.decode_u8()
+    }
+
+    #[inline]
+    fn decode_u16(self: Box<Self>) -> Result<u16, ErrorMarker> {
+        self.decoder.decode_u16()
+    }
+
+    #[inline]
+    fn decode_u32(self: Box<Self>) -> Result<u32, ErrorMarker> {
+        self.decoder.decode_u32()
+    }
+
+    #[inline]
+    fn decode_u64(self: Box<Self>) -> Result<u64, ErrorMarker> {
+        self.decoder.decode_u64()
+    }
+
+    #[inline]
+    fn decode_u128(self: Box<Self>) -> Result<u128, ErrorMarker> {
+        self.decoder.decode_u128()
+    }
+
+    #[inline]
+    fn decode_i8(self: Box<Self>) -> Result<i8, ErrorMarker> {
+        self.decoder.decode_i8()
+    }
+
+    #[inline]
+    fn decode_i16(self: Box<Self>) -> Result<i16, ErrorMarker> {
+        self.decoder.decode_i16()
+    }
+
+    #[inline]
+    fn decode_i32(self: Box<Self>) -> Result<i32, ErrorMarker> {
+        self.decoder.decode_i32()
+    }
+
+    #[inline]
+    fn decode_i64(self: Box<Self>) -> Result<i64, ErrorMarker> {
+        self.decoder.decode_i64()
+    }
+
+    #[inline]
+    fn decode_i128(self: Box<Self>) -> Result<i128, ErrorMarker> {
+        self.decoder.decode_i128()
+    }
+
+    #[inline]
+    fn decode_usize(self: Box<Self>) -> Result<usize, ErrorMarker> {
+        self.decoder.decode_usize()
+    }
+
+    #[inline]
+    fn decode_isize(self: Box<Self>) -> Result<isize, ErrorMarker> {
+        self.decoder.decode_isize()
+    }
+
+    #[inline]
+    fn decode_f32(self: Box<Self>) -> Result<f32, ErrorMarker> {
+        self.decoder.decode_f32()
+    }
+
+    #[inline]
+    fn decode_f64(self: Box<Self>) -> Result<f64, ErrorMarker> {
+        self.decoder.decode_f64()
+    }
+
+    #[inline]
+    fn decode_array(self: Box<Self>, out: &mut [u8]) -> Result<(), ErrorMarker> {
+        self.decoder.decode_array_slice(out)
+    }
+
+    #[inline]
+    fn decode_bytes(
+        self: Box<Self>,
+        visitor: &mut dyn ErasedUnsizedVisitor<'de, [u8]>,
+    ) -> Result<(), ErrorMarker> {
+        self.decoder
+            .decode_bytes(UnsizedVisitorAdapter::<'_, '_, '_, M, [u8]>::new(visitor))
+    }
+
+    #[inline]
+    fn decode_string(
+        self: Box<Self>,
+        visitor: &mut dyn ErasedUnsizedVisitor<'de, str>,
+    ) -> Result<(), ErrorMarker> {
+        self.decoder
+            .decode_string(UnsizedVisitorAdapter::<'_, '_, '_, M, str>::new(visitor))
+    }
+
+    #[inline]
+    fn decode_option(
+        self: Box<Self>,
+    ) -> Result<Option<Box<dyn ErasedDecoder<'a, 'cx, 'de, M> + 'a>>, ErrorMarker> {
+        let Some(decoder) = self.decoder.decode_option()? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Box::new(DecoderAdapter { decoder })))
+    }
+
+    #[inline]
+    fn decode_pack(
+        self: Box<Self>,
+        f: &mut dyn FnMut(
+            Box<dyn ErasedSequenceDecoder<'cx, 'de, M> + '_>,
+        ) -> Result<(), ErrorMarker>,
+    ) -> Result<(), ErrorMarker> {
+        self.decoder
+            .decode_pack(|decoder| f(Box::new(SequenceDecoderAdapter { decoder })))
+    }
+
+    #[inline]
+    fn decode_sequence(
+        self: Box<Self>,
+        hint: Option<&SequenceHint>,
+        f: &mut dyn FnMut(
+            Box<dyn ErasedSequenceDecoder<'cx, 'de, M> + '_>,
+        ) -> Result<(), ErrorMarker>,
+    ) -> Result<(), ErrorMarker> {
+        let f =
+            |decoder: &mut D::DecodeSequence<'_>| f(Box::new(SequenceDecoderAdapter { decoder }));
+
+        match hint {
+            Some(hint) => self.decoder.decode_sequence_hint(hint, f),
+            None => self.decoder.decode_sequence(f),
+        }
+    }
+
+    #[inline]
+    fn decode_map(
+        self: Box<Self>,
+        hint: Option<&MapHint>,
+        f: &mut dyn FnMut(Box<dyn ErasedMapDecoder<'cx, 'de, M> + '_>) -> Result<(), ErrorMarker>,
+    ) -> Result<(), ErrorMarker> {
+        let f = |decoder: &mut D::DecodeMap<'_>| f(Box::new(MapDecoderAdapter { decoder }));
+
+        match hint {
+            Some(hint) => self.decoder.decode_map_hint(hint, f),
+            None => self.decoder.decode_map(f),
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn decode_map_entries(
+        self: Box<Self>,
+        f: &mut dyn FnMut(
+            Box<dyn ErasedEntriesDecoder<'cx, 'de, M> + '_>,
+        ) -> Result<(), ErrorMarker>,
+    ) -> Result<(), ErrorMarker> {
+        self.decoder
+            .decode_map_entries(|decoder| f(Box::new(BorrowedEntriesDecoderAdapter { decoder })))
+    }
+
+    #[inline]
+    fn decode_variant(
+        self: Box<Self>,
+        f: &mut dyn FnMut(
+            Box<dyn ErasedVariantDecoder<'cx, 'de, M> + '_>,
+        ) -> Result<(), ErrorMarker>,
+    ) -> Result<(), ErrorMarker> {
+        self.decoder
+            .decode_variant(|decoder| f(Box::new(VariantDecoderAdapter { decoder })))
+    }
+
+    #[inline]
+    fn decode_number(
+        self: Box<Self>,
+        visitor: &mut dyn ErasedVisitor<'cx, 'de, M>,
+    ) -> Result<(), ErrorMarker> {
+        self.decoder.decode_number(VisitorAdapter { visitor })
+    }
+
+    #[inline]
+    fn decode_any(
+        self: Box<Self>,
+        visitor: &mut dyn ErasedVisitor<'cx, 'de, M>,
+    ) -> Result<(), ErrorMarker> {
+        self.decoder.decode_any(VisitorAdapter { visitor })
+    }
+}
+
+struct SequenceDecoderAdapter<'s, D> {
+    decoder: &'s mut D,
+}
+
+impl<'cx, 'de, M, D> ErasedSequenceDecoder<'cx, 'de, M> for SequenceDecoderAdapter<'_, D>
+where
+    M: 'static,
+    D: SequenceDecoder<'de, Cx = DynContext<'cx, M>>,
+{
+    #[inline]
+    fn size_hint(&self) -> SizeHint {
+        self.decoder.size_hint()
+    }
+
+    #[inline]
+    fn decode_next<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this,
+    {
+        let decoder = self.decoder.decode_next()?;
+        Ok(Box::new(DecoderAdapter { decoder }))
+    }
+
+    #[inline]
+    fn try_decode_next<'this>(
+        &'this mut self,
+    ) -> Result<Option<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>>, ErrorMarker>
+    where
+        'de: 'this,
+    {
+        let Some(decoder) = self.decoder.try_decode_next()? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Box::new(DecoderAdapter { decoder })))
+    }
+
+    #[inline]
+    fn try_decode_pod_into(&mut self, out: PodSliceMut<'_>) -> Result<bool, ErrorMarker> {
+        self.decoder.try_decode_pod_into(out)
+    }
+}
+
+struct MapDecoderAdapter<'s, D> {
+    decoder: &'s mut D,
+}
+
+impl<'cx, 'de, M, D> ErasedMapDecoder<'cx, 'de, M> for MapDecoderAdapter<'_, D>
+where
+    M: 'static,
+    D: MapDecoder<'de, Cx = DynContext<'cx, M>>,
+{
+    #[inline]
+    fn size_hint(&self) -> SizeHint {
+        self.decoder.size_hint()
+    }
+
+    #[inline]
+    fn decode_entry<'this>(
+        &'this mut self,
+    ) -> Result<Option<Box<dyn ErasedEntryDecoder<'this, 'cx, 'de, M> + 'this>>, ErrorMarker>
+    where
+        'de: 'this,
+    {
+        let Some(decoder) = self.decoder.decode_entry()? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Box::new(EntryDecoderAdapter { decoder })))
+    }
+
+    #[inline]
+    fn decode_remaining_entries<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedEntriesDecoder<'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this,
+    {
+        let decoder = self.decoder.decode_remaining_entries()?;
+        Ok(Box::new(EntriesDecoderAdapter { decoder }))
+    }
+}
+
+struct EntryDecoderAdapter<D> {
+    decoder: D,
+}
+
+impl<'a, 'cx, 'de, M, D> ErasedEntryDecoder<'a, 'cx, 'de, M> for EntryDecoderAdapter<D>
+where
+    'de: 'a,
+    M: 'static,
+    D: 'a + EntryDecoder<'de, Cx = DynContext<'cx, M>>,
+{
+    #[inline]
+    fn size_hint(&self) -> SizeHint {
+        self.decoder.size_hint()
+    }
+
+    #[inline]
+    fn decode_key<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this,
+    {
+        let decoder = self.decoder.decode_key()?;
+        Ok(Box::new(DecoderAdapter { decoder }))
+    }
+
+    #[inline]
+    fn decode_value(
+        self: Box<Self>,
+    ) -> Result<Box<dyn ErasedDecoder<'a, 'cx, 'de, M> + 'a>, ErrorMarker> {
+        let decoder = self.decoder.decode_value()?;
+        Ok(Box::new(DecoderAdapter { decoder }))
+    }
+}
+
+struct EntriesDecoderAdapter<D> {
+    decoder: D,
+}
+
+impl<'cx, 'de, M, D> ErasedEntriesDecoder<'cx, 'de, M> for EntriesDecoderAdapter<D>
+where
+    M: 'static,
+    D: EntriesDecoder<'de, Cx = DynContext<'cx, M>>,
+{
+    #[inline]
+    fn size_hint(&self) -> SizeHint {
+        self.decoder.size_hint()
+    }
+
+    #[inline]
+    fn decode_entry_key<'this>(
+        &'this mut self,
+    ) -> Result<Option<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>>, ErrorMarker>
+    where
+        'de: 'this,
+    {
+        let Some(decoder) = self.decoder.decode_entry_key()? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Box::new(DecoderAdapter { decoder })))
+    }
+
+    #[inline]
+    fn decode_entry_value<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this,
+    {
+        let decoder = self.decoder.decode_entry_value()?;
+        Ok(Box::new(DecoderAdapter { decoder }))
+    }
+
+    #[inline]
+    fn end_entries(self: Box<Self>) -> Result<(), ErrorMarker> {
+        self.decoder.end_entries()
+    }
+}
+
+/// Entries decoder lent out by [`Decoder::decode_map_entries`], which is ended
+/// by the decoder which lent it out.
+struct BorrowedEntriesDecoderAdapter<'s, D> {
+    decoder: &'s mut D,
+}
+
+impl<'cx, 'de, M, D> ErasedEntriesDecoder<'cx, 'de, M> for BorrowedEntriesDecoderAdapter<'_, D>
+where
+    M: 'static,
+    D: EntriesDecoder<'de, Cx = DynContext<'cx, M>>,
+{
+    #[inline]
+    fn size_hint(&self) -> SizeHint {
+        self.decoder.size_hint()
+    }
+
+    #[inline]
+    fn decode_entry_key<'this>(
+        &'this mut self,
+    ) -> Result<Option<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>>, ErrorMarker>
+    where
+        'de: 'this,
+    {
+        let Some(decoder) = self.decoder.decode_entry_key()? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Box::new(DecoderAdapter { decoder })))
+    }
+
+    #[inline]
+    fn decode_entry_value<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this,
+    {
+        let decoder = self.decoder.decode_entry_value()?;
+        Ok(Box::new(DecoderAdapter { decoder }))
+    }
+
+    #[inline]
+    fn end_entries(self: Box<Self>) -> Result<(), ErrorMarker> {
+        Ok(())
+    }
+}
+
+struct VariantDecoderAdapter<'s, D> {
+    decoder: &'s mut D,
+}
+
+impl<'cx, 'de, M, D> ErasedVariantDecoder<'cx, 'de, M> for VariantDecoderAdapter<'_, D>
+where
+    M: 'static,
+    D: VariantDecoder<'de, Cx = DynContext<'cx, M>>,
+{
+    #[inline]
+    fn decode_tag<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this,
+    {
+        let decoder = self.decoder.decode_tag()?;
+        Ok(Box::new(DecoderAdapter { decoder }))
+    }
+
+    #[inline]
+    fn decode_value<'this>(
+        &'this mut self,
+    ) -> Result<Box<dyn ErasedDecoder<'this, 'cx, 'de, M> + 'this>, ErrorMarker>
+    where
+        'de: 'this,
+    {
+        let decoder = self.decoder.decode_value()?;
+        Ok(Box::new(DecoderAdapter { decoder }))
+    }
+}
+
+/// Adapts an erased visitor to [`Visitor`].
+struct VisitorAdapter<'v, 'cx, 'de, M> {
+    visitor: &'v mut dyn ErasedVisitor<'cx, 'de, M>,
+}
+
+#[crate::visitor(crate)]
+impl<'v, 'cx, 'de, M> Visitor<'de, DynContext<'cx, M>> for VisitorAdapter<'v, 'cx, 'de, M>
+where
+    M: 'static,
+{
+    type Ok = ();
+    type String = UnsizedVisitorAdapter<'v, 'cx, 'de, M, str>;
+    type Bytes = UnsizedVisitorAdapter<'v, 'cx, 'de, M, [u8]>;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.visitor.expecting(f)
+    }
+
+    #[inline]
+    fn visit_empty(self, _: &DynContext<'cx, M>) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_empty()
+    }
+
+    #[inline]
+    fn visit_bool(self, _: &DynContext<'cx, M>, value: bool) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_bool(value)
+    }
+
+    #[inline]
+    fn visit_char(self, _: &DynContext<'cx, M>, value: char) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_char(value)
+    }
+
+    #[inline]
+    fn visit_u8(self, _: &DynContext<'cx, M>, value: u8) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_u8(value)
+    }
+
+    #[inline]
+    fn visit_u16(self, _: &DynContext<'cx, M>, value: u16) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_u16(value)
+    }
+
+    #[inline]
+    fn visit_u32(self, _: &DynContext<'cx, M>, value: u32) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_u32(value)
+    }
+
+    #[inline]
+    fn visit_u64(self, _: &DynContext<'cx, M>, value: u64) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_u64(value)
+    }
+
+    #[inline]
+    fn visit_u128(self, _: &DynContext<'cx, M>, value: u128) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_u128(value)
+    }
+
+    #[inline]
+    fn visit_i8(self, _: &DynContext<'cx, M>, value: i8) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_i8(value)
+    }
+
+    #[inline]
+    fn visit_i16(self, _: &DynContext<'cx, M>, value: i16) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_i16(value)
+    }
+
+    #[inline]
+    fn visit_i32(self, _: &DynContext<'cx, M>, value: i32) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_i32(value)
+    }
+
+    #[inline]
+    fn visit_i64(self, _: &DynContext<'cx, M>, value: i64) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_i64(value)
+    }
+
+    #[inline]
+    fn visit_i128(self, _: &DynContext<'cx, M>, value: i128) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_i128(value)
+    }
+
+    #[inline]
+    fn visit_usize(self, _: &DynContext<'cx, M>, value: usize) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_usize(value)
+    }
+
+    #[inline]
+    fn visit_isize(self, _: &DynContext<'cx, M>, value: isize) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_isize(value)
+    }
+
+    #[inline]
+    fn visit_f32(self, _: &DynContext<'cx, M>, value: f32) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_f32(value)
+    }
+
+    #[inline]
+    fn visit_f64(self, _: &DynContext<'cx, M>, value: f64) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_f64(value)
+    }
+    #[inline]
+    fn visit_big_number(
+        self,
+        _: &DynContext<'cx, M>,
+        number: &str,
+    ) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_big_number(number)
+    }
+
+    #[inline]
+    fn visit_option<D>(
+        self,
+        _: &DynContext<'cx, M>,
+        decoder: Option<D>,
+    ) -> Result<Self::Ok, ErrorMarker>
+    where
+        D: Decoder<'de, Cx = DynContext<'cx, M>, Error = ErrorMarker, Mode = M>,
+    {
+        let decoder = decoder.map(|decoder| {
+            Box::new(DecoderAdapter { decoder }) as Box<dyn ErasedDecoder<'_, 'cx, 'de, M> + '_>
+        });
+
+        self.visitor.visit_option(decoder)
+    }
+
+    #[inline]
+    fn visit_sequence<D>(
+        self,
+        _: &DynContext<'cx, M>,
+        decoder: &mut D,
+    ) -> Result<Self::Ok, ErrorMarker>
+    where
+        D: SequenceDecoder<'de, Cx = DynContext<'cx, M>>,
+    {
+        self.visitor
+            .visit_sequence(Box::new(SequenceDecoderAdapter { decoder }))
+    }
+
+    #[inline]
+    fn visit_map<D>(self, _: &DynContext<'cx, M>, decoder: &mut D) -> Result<Self::Ok, ErrorMarker>
+    where
+        D: MapDecoder<'de, Cx = DynContext<'cx, M>>,
+    {
+        self.visitor
+            .visit_map(Box::new(MapDecoderAdapter { decoder }))
+    }
+
+    #[inline]
+    fn visit_string(
+        self,
+        _: &DynContext<'cx, M>,
+        hint: SizeHint,
+    ) -> Result<Self::String, ErrorMarker> {
+        Ok(UnsizedVisitorAdapter::new(self.visitor.visit_string(hint)?))
+    }
+
+    #[inline]
+    fn visit_bytes(
+        self,
+        _: &DynContext<'cx, M>,
+        hint: SizeHint,
+    ) -> Result<Self::Bytes, ErrorMarker> {
+        Ok(UnsizedVisitorAdapter::new(self.visitor.visit_bytes(hint)?))
+    }
+
+    #[inline]
+    fn visit_variant<D>(
+        self,
+        _: &DynContext<'cx, M>,
+        decoder: &mut D,
+    ) -> Result<Self::Ok, ErrorMarker>
+    where
+        D: VariantDecoder<'de, Cx = DynContext<'cx, M>>,
+    {
+        self.visitor
+            .visit_variant(Box::new(VariantDecoderAdapter { decoder }))
+    }
+
+    #[inline]
+    fn visit_unknown<D>(self, _: &DynContext<'cx, M>, decoder: D) -> Result<Self::Ok, ErrorMarker>
+    where
+        D: Decoder<'de, Cx = DynContext<'cx, M>, Error = ErrorMarker, Mode = M>,
+    {
+        self.visitor
+            .visit_unknown(Box::new(DecoderAdapter { decoder }))
+    }
+}
+
+/// Adapts an erased unsized visitor to [`UnsizedVisitor`].
+struct UnsizedVisitorAdapter<'v, 'cx, 'de, M, T>
+where
+    T: ?Sized + ToOwned,
+{
+    visitor: &'v mut dyn ErasedUnsizedVisitor<'de, T>,
+    _marker: core::marker::PhantomData<&'cx DynContext<'cx, M>>,
+}
+
+impl<'v, 'cx, 'de, M, T> UnsizedVisitorAdapter<'v, 'cx, 'de, M, T>
+where
+    T: ?Sized + ToOwned,
+{
+    #[inline]
+    fn new(visitor: &'v mut dyn ErasedUnsizedVisitor<'de, T>) -> Self {
+        Self {
+            visitor,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'cx, 'de, M, T> UnsizedVisitor<'de, DynContext<'cx, M>, T>
+    for UnsizedVisitorAdapter<'_, 'cx, 'de, M, T>
+where
+    M: 'static,
+    T: ?Sized + ToOwned,
+{
+    type Ok = ();
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.visitor.expecting(f)
+    }
+
+    #[inline]
+    fn visit_owned(self, _: &DynContext<'cx, M>, value: T::Owned) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_owned(value)
+    }
+
+    #[inline]
+    fn visit_borrowed(
+        self,
+        _: &DynContext<'cx, M>,
+        value: &'de T,
+    ) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_borrowed(value)
+    }
+
+    #[inline]
+    fn visit_ref(self, _: &DynContext<'cx, M>, value: &T) -> Result<Self::Ok, ErrorMarker> {
+        self.visitor.visit_ref(value)
+    }
+}
+
+/// Holds a concrete [`Visitor`] behind [`ErasedVisitor`], and the value it
+/// produces once it has been used.
+struct VisitorSlot<'cx, 'de, M, V>
+where
+    M: 'static,
+    V: Visitor<'de, DynContext<'cx, M>>,
+{
+    cx: &'cx DynContext<'cx, M>,
+    visitor: Option<V>,
+    string: Option<UnsizedVisitorSlot<'cx, M, V::String, V::Ok>>,
+    bytes: Option<UnsizedVisitorSlot<'cx, M, V::Bytes, V::Ok>>,
+    ok: Option<V::Ok>,
+}
+
+impl<'cx, 'de, M, V> VisitorSlot<'cx, 'de, M, V>
+where
+    M: 'static,
+    V: Visitor<'de, DynContext<'cx, M>>,
+{
+    #[inline]
+    fn new(cx: &'cx DynContext<'cx, M>, visitor: V) -> Self {
+        Self {
+            cx,
+            visitor: Some(visitor),
+            string: None,
+            bytes: None,
+            ok: None,
+        }
+    }
+
+    #[inline]
+    fn take(&mut self) -> Result<V, ErrorMarker> {
+        match self.visitor.take() {
+            Some(visitor) => Ok(visitor),
+            None => Err(self.cx.message("Type-erased visitor was already used")),
+        }
+    }
+
+    #[inline]
+    fn store(&mut self, ok: V::Ok) -> Result<(), ErrorMarker> {
+        self.ok = Some(ok);
+        Ok(())
+    }
+
+    #[inline]
+    fn finish(self) -> Result<V::Ok, ErrorMarker> {
+        if let Some(ok) = self.ok {
+            return Ok(ok);
+        }
+
+        if let Some(string) = self.string {
+            return string.finish();
+        }
+
+        if let Some(bytes) = self.bytes {
+            return bytes.finish();
+        }
+
+        Err(self.cx.message("Type-erased visitor was not used"))
+    }
+}
+
+impl<'cx, 'de, M, V> ErasedVisitor<'cx, 'de, M> for VisitorSlot<'cx, 'de, M, V>
+where
+    M: 'static,
+    V: Visitor<'de, DynContext<'cx, M>>,
+{
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.visitor {
+            Some(visitor) => visitor.expecting(f),
+            None => f.write_str("a visitor which has already been used"),
+        }
+    }
+
+    #[inline]
+    fn visit_empty(&mut self) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_empty(self.cx)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_bool(&mut self, value: bool) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_bool(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_char(&mut self, value: char) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_char(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_u8(&mut self, value: u8) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_u8(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_u16(&mut self, value: u16) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_u16(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_u32(&mut self, value: u32) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_u32(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_u64(&mut self, value: u64) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_u64(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_u128(&mut self, value: u128) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_u128(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_i8(&mut self, value: i8) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_i8(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_i16(&mut self, value: i16) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_i16(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_i32(&mut self, value: i32) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_i32(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_i64(&mut self, value: i64) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_i64(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_i128(&mut self, value: i128) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_i128(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_usize(&mut self, value: usize) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_usize(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_isize(&mut self, value: isize) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_isize(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_f32(&mut self, value: f32) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_f32(self.cx, value)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_f64(&mut self, value: f64) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_f64(self.cx, value)?;
+        self.store(ok)
+    }
+    #[inline]
+    fn visit_big_number(&mut self, number: &str) -> Result<(), ErrorMarker> {
+        let ok = self.take()?.visit_big_number(self.cx, number)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_option<'x>(
+        &mut self,
+        decoder: Option<Box<dyn ErasedDecoder<'x, 'cx, 'de, M> + 'x>>,
+    ) -> Result<(), ErrorMarker>
+    where
+        'cx: 'x,
+        'de: 'x,
+    {
+        let cx = self.cx;
+        let decoder = decoder.map(|inner| DynDecoder { cx, inner });
+        let ok = self.take()?.visit_option(cx, decoder)?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_sequence(
+        &mut self,
+        inner: Box<dyn ErasedSequenceDecoder<'cx, 'de, M> + '_>,
+    ) -> Result<(), ErrorMarker> {
+        let cx = self.cx;
+        let ok = self
+            .take()?
+            .visit_sequence(cx, &mut DynSequenceDecoder { cx, inner })?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_map(
+        &mut self,
+        inner: Box<dyn ErasedMapDecoder<'cx, 'de, M> + '_>,
+    ) -> Result<(), ErrorMarker> {
+        let cx = self.cx;
+        let ok = self
+            .take()?
+            .visit_map(cx, &mut DynMapDecoder { cx, inner })?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_string(
+        &mut self,
+        hint: SizeHint,
+    ) -> Result<&mut dyn ErasedUnsizedVisitor<'de, str>, ErrorMarker> {
+        let visitor = self.take()?.visit_string(self.cx, hint)?;
+        Ok(self
+            .string
+            .insert(UnsizedVisitorSlot::new(self.cx, visitor)))
+    }
+
+    #[inline]
+    fn visit_bytes(
+        &mut self,
+        hint: SizeHint,
+    ) -> Result<&mut dyn ErasedUnsizedVisitor<'de, [u8]>, ErrorMarker> {
+        let visitor = self.take()?.visit_bytes(self.cx, hint)?;
+        Ok(self.bytes.insert(UnsizedVisitorSlot::new(self.cx, visitor)))
+    }
+
+    #[inline]
+    fn visit_variant(
+        &mut self,
+        inner: Box<dyn ErasedVariantDecoder<'cx, 'de, M> + '_>,
+    ) -> Result<(), ErrorMarker> {
+        let cx = self.cx;
+        let ok = self
+            .take()?
+            .visit_variant(cx, &mut DynVariantDecoder { cx, inner })?;
+        self.store(ok)
+    }
+
+    #[inline]
+    fn visit_unknown<'x>(
+        &mut self,
+        inner: Box<dyn ErasedDecoder<'x, 'cx, 'de, M> + 'x>,
+    ) -> Result<(), ErrorMarker>
+    where
+        'cx: 'x,
+        'de: 'x,
+    {
+        let cx = self.cx;
+        let ok = self.take()?.visit_unknown(cx, DynDecoder { cx, inner })?;
+        self.store(ok)
+    }
+}
+
+/// Holds a concrete [`UnsizedVisitor`] behind [`ErasedUnsizedVisitor`], and
+/// the value it produces once it has been used.
+struct UnsizedVisitorSlot<'cx, M, V, O> {
+    cx: &'cx DynContext<'cx, M>,
+    visitor: Option<V>,
+    ok: Option<O>,
+}
+
+impl<'cx, M, V, O> UnsizedVisitorSlot<'cx, M, V, O>
+where
+    M: 'static,
+{
+    #[inline]
+    fn new(cx: &'cx DynContext<'cx, M>, visitor: V) -> Self {
+        Self {
+            cx,
+            visitor: Some(visitor),
+            ok: None,
+        }
+    }
+
+    #[inline]
+    fn take(&mut self) -> Result<V, ErrorMarker> {
+        match self.visitor.take() {
+            Some(visitor) => Ok(visitor),
+            None => Err(self.cx.message("Type-erased visitor was already used")),
+        }
+    }
+
+    #[inline]
+    fn finish(self) -> Result<O, ErrorMarker> {
+        match self.ok {
+            Some(ok) => Ok(ok),
+            None => Err(self.cx.message("Type-erased visitor was not used")),
+        }
+    }
+}
+
+impl<'cx, 'de, M, V, O, T> ErasedUnsizedVisitor<'de, T> for UnsizedVisitorSlot<'cx, M, V, O>
+where
+    M: 'static,
+    V: UnsizedVisitor<'de, DynContext<'cx, M>, T, Ok = O>,
+    T: ?Sized + ToOwned,
+{
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.visitor {
+            Some(visitor) => visitor.expecting(f),
+            None => f.write_str("a visitor which has already been used"),
+        }
+    }
+
+    #[inline]
+    fn visit_owned(&mut self, value: T::Owned) -> Result<(), ErrorMarker> {
+        self.ok = Some(self.take()?.visit_owned(self.cx, value)?);
+        Ok(())
+    }
+
+    #[inline]
+    fn visit_borrowed(&mut self, value: &'de T) -> Result<(), ErrorMarker> {
+        self.ok = Some(self.take()?.visit_borrowed(self.cx, value)?);
+        Ok(())
+    }
+
+    #[inline]
+    fn visit_ref(&mut self, value: &T) -> Result<(), ErrorMarker> {
+        self.ok = Some(self.take()?.visit_ref(self.cx, value)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,1000 @@
+use core::cell::Cell;
+use core::fmt;
+
+use rust_alloc::boxed::Box;
+use rust_alloc::vec::Vec;
+
+use crate::context::ErrorMarker;
+use crate::en::{
+    Encode, Encoder, EntriesEncoder, EntryEncoder, MapEncoder, PodSlice, SequenceEncoder,
+    VariantEncoder,
+};
+use crate::hint::{MapHint, SequenceHint};
+
+use super::{DynContext, DynEncode};
+
+/// A type-erased [`Encoder`].
+///
+/// See the [module level documentation][super].
+pub struct DynEncoder<'a, 'cx, M> {
+    cx: &'cx DynContext<'cx, M>,
+    inner: Box<dyn ErasedEncoder<'a, 'cx, M> + 'a>,
+}
+
+impl<'a, 'cx, M> DynEncoder<'a, 'cx, M>
+where
+    M: 'static,
+{
+    /// Erase the type of the given encoder, storing the value it produces in
+    /// `ok`.
+    pub(super) fn erase<E>(
+        cx: &'cx DynContext<'cx, M>,
+        encoder: E,
+        ok: &'a Cell<Option<E::Ok>>,
+    ) -> Self
+    where
+        E: 'a + Encoder<Cx = DynContext<'cx, M>, Error = ErrorMarker, Mode = M>,
+    {
+        Self {
+            cx,
+            inner: Box::new(EncoderAdapter { encoder, ok }),
+        }
+    }
+}
+
+#[crate::encoder(crate)]
+impl<'a, 'cx, M> Encoder for DynEncoder<'a, 'cx, M>
+where
+    M: 'static,
+{
+    type Cx = DynContext<'cx, M>;
+    type Error = ErrorMarker;
+    type Ok = ();
+    type Mode = M;
+    type EncodePack = DynSequenceEncoder<'a, 'cx, M>;
+    type EncodeSome = Self;
+    type EncodeSequence = DynSequenceEncoder<'a, 'cx, M>;
+    type EncodeMap = DynMapEncoder<'a, 'cx, M>;
+    type EncodeMapEntries = DynEntriesEncoder<'a, 'cx, M>;
+    type EncodeVariant = DynVariantEncoder<'a, 'cx, M>;
+    type EncodeSequenceVariant = DynSequenceEncoder<'a, 'cx, M>;
+    type EncodeMapVariant = DynMapEncoder<'a, 'cx, M>;
+
+    #[inline]
+    fn cx(&self) -> &Self::Cx {
+        self.cx
+    }
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    #[inline]
+    fn encode<T>(self, value: T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Encode<Self::Mode>,
+    {
+        value.encode(self.cx, self)
+    }
+
+    #[inline]
+    fn encode_empty(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_empty()
+    }
+
+    #[inline]
+    fn encode_bool(self, value: bool) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_bool(value)
+    }
+
+    #[inline]
+    fn encode_char(self, value: char) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_char(value)
+    }
+
+    #[inline]
+    fn encode_u8(self, value: u8) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_u8(value)
+    }
+
+    #[inline]
+    fn encode_u16(self, value: u16) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_u16(value)
+    }
+
+    #[inline]
+    fn encode_u32(self, value: u32) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_u32(value)
+    }
+
+    #[inline]
+    fn encode_u64(self, value: u64) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_u64(value)
+    }
+
+    #[inline]
+    fn encode_u128(self, value: u128) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_u128(value)
+    }
+
+    #[inline]
+    fn encode_i8(self, value: i8) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_i8(value)
+    }
+
+    #[inline]
+    fn encode_i16(self, value: i16) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_i16(value)
+    }
+
+    #[inline]
+    fn encode_i32(self, value: i32) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_i32(value)
+    }
+
+    #[inline]
+    fn encode_i64(self, value: i64) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_i64(value)
+    }
+
+    #[inline]
+    fn encode_i128(self, value: i128) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_i128(value)
+    }
+
+    #[inline]
+    fn encode_usize(self, value: usize) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_usize(value)
+    }
+
+    #[inline]
+    fn encode_isize(self, value: isize) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_isize(value)
+    }
+
+    #[inline]
+    fn encode_f32(self, value: f32) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_f32(value)
+    }
+
+    #[inline]
+    fn encode_f64(self, value: f64) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_f64(value)
+    }
+
+    #[inline]
+    fn encode_array<const N: usize>(self, array: &[u8; N]) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_array(array)
+    }
+
+    #[inline]
+    fn encode_array_slice(self, array: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_array(array)
+    }
+
+    #[inline]
+    fn encode_bytes(self, bytes: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_bytes(bytes)
+    }
+
+    #[inline]
+    fn encode_bytes_vectored<I>(self, len: usize, vectors: I) -> Result<Self::Ok, Self::Error>
+    where
+        I: IntoIterator<Item: AsRef<[u8]>>,
+    {
+        let vectors = vectors.into_iter().collect::<Vec<_>>();
+        let vectors = vectors.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        self.inner.encode_bytes_vectored(len, &vectors)
+    }
+
+    #[inline]
+    fn encode_string(self, string: &str) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_string(string)
+    }
+
+    #[inline]
+    fn collect_string<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + fmt::Display,
+    {
+        self.inner.collect_string(&value)
+    }
+
+    #[inline]
+    fn encode_some(self) -> Result<Self::EncodeSome, Self::Error> {
+        let inner = self.inner.encode_some()?;
+        Ok(Self { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn encode_none(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.encode_none()
+    }
+
+    #[inline]
+    fn encode_pack(self) -> Result<Self::EncodePack, Self::Error> {
+        let inner = self.inner.encode_pack()?;
+        Ok(DynSequenceEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn encode_sequence(self, hint: &SequenceHint) -> Result<Self::EncodeSequence, Self::Error> {
+        let inner = self.inner.encode_sequence(hint)?;
+        Ok(DynSequenceEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn encode_unsized_sequence(self) -> Result<Self::EncodeSequence, Self::Error> {
+        let inner = self.inner.encode_unsized_sequence()?;
+        Ok(DynSequenceEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn encode_map(self, hint: &MapHint) -> Result<Self::EncodeMap, Self::Error> {
+        let inner = self.inner.encode_map(hint)?;
+        Ok(DynMapEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn encode_map_entries(self, hint: &MapHint) -> Result<Self::EncodeMapEntries, Self::Error> {
+        let inner = self.inner.encode_map_entries(hint)?;
+        Ok(DynEntriesEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn encode_variant(self) -> Result<Self::EncodeVariant, Self::Error> {
+        let inner = self.inner.encode_variant()?;
+        Ok(DynVariantEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn encode_unit_variant<T>(self, tag: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Encode<Self::Mode>,
+    {
+        self.inner.encode_unit_variant(&tag)
+    }
+
+    #[inline]
+    fn encode_sequence_variant<T>(
+        self,
+        tag: &T,
+        hint: &SequenceHint,
+    ) -> Result<Self::EncodeSequenceVariant, Self::Error>
+    where
+        T: ?Sized + Encode<Self::Mode>,
+    {
+        let inner = self.inner.encode_sequence_variant(&tag, hint)?;
+        Ok(DynSequenceEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn encode_map_variant<T>(
+        self,
+        tag: &T,
+        hint: &MapHint,
+    ) -> Result<Self::EncodeMapVariant, Self::Error>
+    where
+        T: ?Sized + Encode<Self::Mode>,
+    {
+        let inner = self.inner.encode_map_variant(&tag, hint)?;
+        Ok(DynMapEncoder { cx: self.cx, inner })
+    }
+}
+
+/// A type-erased [`SequenceEncoder`].
+///
+/// See the [module level documentation][super].
+pub struct DynSequenceEncoder<'a, 'cx, M> {
+    cx: &'cx DynContext<'cx, M>,
+    inner: Box<dyn ErasedSequenceEncoder<'cx, M> + 'a>,
+}
+
+impl<'cx, M> SequenceEncoder for DynSequenceEncoder<'_, 'cx, M>
+where
+    M: 'static,
+{
+    type Cx = DynContext<'cx, M>;
+    type Ok = ();
+    type EncodeNext<'this> = DynEncoder<'this, 'cx, M> where Self: 'this;
+
+    #[inline]
+    fn encode_next(&mut self) -> Result<Self::EncodeNext<'_>, ErrorMarker> {
+        let inner = self.inner.encode_next()?;
+        Ok(DynEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn finish_sequence(self) -> Result<Self::Ok, ErrorMarker> {
+        self.inner.finish_sequence()
+    }
+
+    #[inline]
+    fn try_push_pod(&mut self, pod: PodSlice<'_>) -> Result<bool, ErrorMarker> {
+        self.inner.try_push_pod(pod)
+    }
+}
+
+/// A type-erased [`MapEncoder`].
+///
+/// See the [module level documentation][super].
+pub struct DynMapEncoder<'a, 'cx, M> {
+    cx: &'cx DynContext<'cx, M>,
+    inner: Box<dyn ErasedMapEncoder<'cx, M> + 'a>,
+}
+
+impl<'cx, M> MapEncoder for DynMapEncoder<'_, 'cx, M>
+where
+    M: 'static,
+{
+    type Cx = DynContext<'cx, M>;
+    type Ok = ();
+    type EncodeEntry<'this> = DynEntryEncoder<'this, 'cx, M> where Self: 'this;
+
+    #[inline]
+    fn encode_entry(&mut self) -> Result<Self::EncodeEntry<'_>, ErrorMarker> {
+        let inner = self.inner.encode_entry()?;
+        Ok(DynEntryEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn finish_map(self) -> Result<Self::Ok, ErrorMarker> {
+        self.inner.finish_map()
+    }
+}
+
+/// A type-erased [`EntryEncoder`].
+///
+/// See the [module level documentation][super].
+pub struct DynEntryEncoder<'a, 'cx, M> {
+    cx: &'cx DynContext<'cx, M>,
+    inner: Box<dyn ErasedEntryEncoder<'cx, M> + 'a>,
+}
+
+impl<'cx, M> EntryEncoder for DynEntryEncoder<'_, 'cx, M>
+where
+    M: 'static,
+{
+    type Cx = DynContext<'cx, M>;
+    type Ok = ();
+    type EncodeKey<'this> = DynEncoder<'this, 'cx, M> where Self: 'this;
+    type EncodeValue<'this> = DynEncoder<'this, 'cx, M> where Self: 'this;
+
+    #[inline]
+    fn encode_key(&mut self) -> Result<Self::EncodeKey<'_>, ErrorMarker> {
+        let inner = self.inner.encode_key()?;
+        Ok(DynEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn encode_value(&mut self) -> Result<Self::EncodeValue<'_>, ErrorMarker> {
+        let inner = self.inner.encode_value()?;
+        Ok(DynEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn finish_entry(self) -> Result<Self::Ok, ErrorMarker> {
+        self.inner.finish_entry()
+    }
+}
+
+/// A type-erased [`EntriesEncoder`].
+///
+/// See the [module level documentation][super].
+pub struct DynEntriesEncoder<'a, 'cx, M> {
+    cx: &'cx DynContext<'cx, M>,
+    inner: Box<dyn ErasedEntriesEncoder<'cx, M> + 'a>,
+}
+
+impl<'cx, M> EntriesEncoder for DynEntriesEncoder<'_, 'cx, M>
+where
+    M: 'static,
+{
+    type Cx = DynContext<'cx, M>;
+    type Ok = ();
+    type EncodeEntryKey<'this> = DynEncoder<'this, 'cx, M> where Self: 'this;
+    type EncodeEntryValue<'this> = DynEncoder<'this, 'cx, M> where Self: 'this;
+
+    #[inline]
+    fn encode_entry_key(&mut self) -> Result<Self::EncodeEntryKey<'_>, ErrorMarker> {
+        let inner = self.inner.encode_entry_key()?;
+        Ok(DynEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn encode_entry_value(&mut self) -> Result<Self::EncodeEntryValue<'_>, ErrorMarker> {
+        let inner = self.inner.encode_entry_value()?;
+        Ok(DynEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn finish_entries(self) -> Result<Self::Ok, ErrorMarker> {
+        self.inner.finish_entries()
+    }
+}
+
+/// A type-erased [`VariantEncoder`].
+///
+/// See the [module level documentation][super].
+pub struct DynVariantEncoder<'a, 'cx, M> {
+    cx: &'cx DynContext<'cx, M>,
+    inner: Box<dyn ErasedVariantEncoder<'cx, M> + 'a>,
+}
+
+impl<'cx, M> VariantEncoder for DynVariantEncoder<'_, 'cx, M>
+where
+    M: 'static,
+{
+    type Cx = DynContext<'cx, M>;
+    type Ok = ();
+    type EncodeTag<'this> = DynEncoder<'this, 'cx, M> where Self: 'this;
+    type EncodeData<'this> = DynEncoder<'this, 'cx, M> where Self: 'this;
+
+    #[inline]
+    fn encode_tag(&mut self) -> Result<Self::EncodeTag<'_>, ErrorMarker> {
+        let inner = self.inner.encode_tag()?;
+        Ok(DynEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn encode_data(&mut self) -> Result<Self::EncodeData<'_>, ErrorMarker> {
+        let inner = self.inner.encode_data()?;
+        Ok(DynEncoder { cx: self.cx, inner })
+    }
+
+    #[inline]
+    fn finish_variant(self) -> Result<Self::Ok, ErrorMarker> {
+        self.inner.finish_variant()
+    }
+}
+
+/// Object-safe version of [`Encoder`].
+///
+/// Methods which consume the encoder take `self` by box, and any value
+/// produced by the underlying encoder is stored in the slot which was passed to
+/// [`DynEncoder::erase`].
+trait ErasedEncoder<'a, 'cx, M> {
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    fn encode_empty(self: Box<Self>) -> Result<(), ErrorMarker>;
+
+    fn encode_bool(self: Box<Self>, value: bool) -> Result<(), ErrorMarker>;
+
+    fn encode_char(self: Box<Self>, value: char) -> Result<(), ErrorMarker>;
+
+    fn encode_u8(self: Box<Self>, value: u8) -> Result<(), ErrorMarker>;
+
+    fn encode_u16(self: Box<Self>, value: u16) -> Result<(), ErrorMarker>;
+
+    fn encode_u32(self: Box<Self>, value: u32) -> Result<(), ErrorMarker>;
+
+    fn encode_u64(self: Box<Self>, value: u64) -> Result<(), ErrorMarker>;
+
+    fn encode_u128(self: Box<Self>, value: u128) -> Result<(), ErrorMarker>;
+
+    fn encode_i8(self: Box<Self>, value: i8) -> Result<(), ErrorMarker>;
+
+    fn encode_i16(self: Box<Self>, value: i16) -> Result<(), ErrorMarker>;
+
+    fn encode_i32(self: Box<Self>, value: i32) -> Result<(), ErrorMarker>;
+
+    fn encode_i64(self: Box<Self>, value: i64) -> Result<(), ErrorMarker>;
+
+    fn encode_i128(self: Box<Self>, value: i128) -> Result<(), ErrorMarker>;
+
+    fn encode_usize(self: Box<Self>, value: usize) -> Result<(), ErrorMarker>;
+
+    fn encode_isize(self: Box<Self>, value: isize) -> Result<(), ErrorMarker>;
+
+    fn encode_f32(self: Box<Self>, value: f32) -> Result<(), ErrorMarker>;
+
+    fn encode_f64(self: Box<Self>, value: f64) -> Result<(), ErrorMarker>;
+
+    fn encode_array(self: Box<Self>, array: &[u8]) -> Result<(), ErrorMarker>;
+
+    fn encode_bytes(self: Box<Self>, bytes: &[u8]) -> Result<(), ErrorMarker>;
+
+    fn encode_bytes_vectored(
+        self: Box<Self>,
+        len: usize,
+        vectors: &[&[u8]],
+    ) -> Result<(), ErrorMarker>;
+
+    fn encode_string(self: Box<Self>, string: &str) -> Result<(), ErrorMarker>;
+
+    fn collect_string(self: Box<Self>, value: &dyn fmt::Display) -> Result<(), ErrorMarker>;
+
+    fn encode_some(self: Box<Self>)
+        -> Result<Box<dyn ErasedEncoder<'a, 'cx, M> + 'a>, ErrorMarker>;
+
+    fn encode_none(self: Box<Self>) -> Result<(), ErrorMarker>;
+
+    fn encode_pack(
+        self: Box<Self>,
+    ) -> Result<Box<dyn ErasedSequenceEncoder<'cx, M> + 'a>, ErrorMarker>;
+
+    fn encode_sequence(
+        self: Box<Self>,
+        hint: &SequenceHint,
+    ) -> Result<Box<dyn ErasedSequenceEncoder<'cx, M> + 'a>, ErrorMarker>;
+
+    fn encode_unsized_sequence(
+        self: Box<Self>,
+    ) -> Result<Box<dyn ErasedSequenceEncoder<'cx, M> + 'a>, ErrorMarker>;
+
+    fn encode_map(
+        self: Box<Self>,
+        hint: &MapHint,
+    ) -> Result<Box<dyn ErasedMapEncoder<'cx, M> + 'a>, ErrorMarker>;
+
+    fn encode_map_entries(
+        self: Box<Self>,
+        hint: &MapHint,
+    ) -> Result<Box<dyn ErasedEntriesEncoder<'cx, M> + 'a>, ErrorMarker>;
+
+    fn encode_variant(
+        self: Box<Self>,
+    ) -> Result<Box<dyn ErasedVariantEncoder<'cx, M> + 'a>, ErrorMarker>;
+
+    fn encode_unit_variant(self: Box<Self>, tag: &dyn DynEncode<M>) -> Result<(), ErrorMarker>;
+
+    fn encode_sequence_variant(
+        self: Box<Self>,
+        tag: &dyn DynEncode<M>,
+        hint: &SequenceHint,
+    ) -> Result<Box<dyn ErasedSequenceEncoder<'cx, M> + 'a>, ErrorMarker>;
+
+    fn encode_map_variant(
+        self: Box<Self>,
+        tag: &dyn DynEncode<M>,
+        hint: &MapHint,
+    ) -> Result<Box<dyn ErasedMapEncoder<'cx, M> + 'a>, ErrorMarker>;
+}
+
+trait ErasedSequenceEncoder<'cx, M> {
+    fn encode_next(&mut self) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker>;
+
+    fn finish_sequence(self: Box<Self>) -> Result<(), ErrorMarker>;
+
+    fn try_push_pod(&mut self, pod: PodSlice<'_>) -> Result<bool, ErrorMarker>;
+}
+
+trait ErasedMapEncoder<'cx, M> {
+    fn encode_entry(&mut self) -> Result<Box<dyn ErasedEntryEncoder<'cx, M> + '_>, ErrorMarker>;
+
+    fn finish_map(self: Box<Self>) -> Result<(), ErrorMarker>;
+}
+
+trait ErasedEntryEncoder<'cx, M> {
+    fn encode_key(&mut self) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker>;
+
+    fn encode_value(&mut self) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker>;
+
+    fn finish_entry(self: Box<Self>) -> Result<(), ErrorMarker>;
+}
+
+trait ErasedEntriesEncoder<'cx, M> {
+    fn encode_entry_key(&mut self) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker>;
+
+    fn encode_entry_value(
+        &mut self,
+    ) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker>;
+
+    fn finish_entries(self: Box<Self>) -> Result<(), ErrorMarker>;
+}
+
+trait ErasedVariantEncoder<'cx, M> {
+    fn encode_tag(&mut self) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker>;
+
+    fn encode_data(&mut self) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker>;
+
+    fn finish_variant(self: Box<Self>) -> Result<(), ErrorMarker>;
+}
+
+/// Adapts a concrete encoder to [`ErasedEncoder`].
+struct EncoderAdapter<'o, E, O> {
+    encoder: E,
+    ok: &'o Cell<Option<O>>,
+}
+
+impl<'o, E, O> EncoderAdapter<'o, E, O> {
+    /// Consume the encoder with `f`, storing the value it produces.
+    #[inline]
+    fn store<F>(self, f: F) -> Result<(), ErrorMarker>
+    where
+        F: FnOnce(E) -> Result<O, ErrorMarker>,
+    {
+        self.ok.set(Some(f(self.encoder)?));
+        Ok(())
+    }
+}
+
+impl<'a, 'o, 'cx, M, E, O> ErasedEncoder<'a, 'cx, M> for EncoderAdapter<'o, E, O>
+where
+    'o: 'a,
+    M: 'static,
+    E: 'a + Encoder<Cx = DynContext<'cx, M>, Ok = O, Error = ErrorMarker, Mode = M>,
+    O: 'a,
+{
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.encoder.expecting(f)
+    }
+
+    #[inline]
+    fn encode_empty(self: Box<Self>) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_empty())
+    }
+
+    #[inline]
+    fn encode_bool(self: Box<Self>, value: bool) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_bool(value))
+    }
+
+    #[inline]
+    fn encode_char(self: Box<Self>, value: char) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_char(value))
+    }
+
+    #[inline]
+    fn encode_u8(self: Box<Self>, value: u8) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_u8(value))
+    }
+
+    #[inline]
+    fn encode_u16(self: Box<Self>, value: u16) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_u16(value))
+    }
+
+    #[inline]
+    fn encode_u32(self: Box<Self>, value: u32) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_u32(value))
+    }
+
+    #[inline]
+    fn encode_u64(self: Box<Self>, value: u64) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_u64(value))
+    }
+
+    #[inline]
+    fn encode_u128(self: Box<Self>, value: u128) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_u128(value))
+    }
+
+    #[inline]
+    fn encode_i8(self: Box<Self>, value: i8) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_i8(value))
+    }
+
+    #[inline]
+    fn encode_i16(self: Box<Self>, value: i16) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_i16(value))
+    }
+
+    #[inline]
+    fn encode_i32(self: Box<Self>, value: i32) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_i32(value))
+    }
+
+    #[inline]
+    fn encode_i64(self: Box<Self>, value: i64) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_i64(value))
+    }
+
+    #[inline]
+    fn encode_i128(self: Box<Self>, value: i128) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_i128(value))
+    }
+
+    #[inline]
+    fn encode_usize(self: Box<Self>, value: usize) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_usize(value))
+    }
+
+    #[inline]
+    fn encode_isize(self: Box<Self>, value: isize) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_isize(value))
+    }
+
+    #[inline]
+    fn encode_f32(self: Box<Self>, value: f32) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_f32(value))
+    }
+
+    #[inline]
+    fn encode_f64(self: Box<Self>, value: f64) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_f64(value))
+    }
+
+    #[inline]
+    fn encode_array(self: Box<Self>, array: &[u8]) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_array_slice(array))
+    }
+
+    #[inline]
+    fn encode_bytes(self: Box<Self>, bytes: &[u8]) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_bytes(bytes))
+    }
+
+    #[inline]
+    fn encode_bytes_vectored(
+        self: Box<Self>,
+        len: usize,
+        vectors: &[&[u8]],
+    ) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_bytes_vectored(len, vectors))
+    }
+
+    #[inline]
+    fn encode_string(self: Box<Self>, string: &str) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_string(string))
+    }
+
+    #[inline]
+    fn collect_string(self: Box<Self>, value: &dyn fmt::Display) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.collect_string(value))
+    }
+
+    #[inline]
+    fn encode_some(
+        self: Box<Self>,
+    ) -> Result<Box<dyn ErasedEncoder<'a, 'cx, M> + 'a>, ErrorMarker> {
+        let Self { encoder, ok } = *self;
+        let encoder = encoder.encode_some()?;
+        Ok(Box::new(EncoderAdapter { encoder, ok }))
+    }
+
+    #[inline]
+    fn encode_none(self: Box<Self>) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_none())
+    }
+
+    #[inline]
+    fn encode_pack(
+        self: Box<Self>,
+    ) -> Result<Box<dyn ErasedSequenceEncoder<'cx, M> + 'a>, ErrorMarker> {
+        let Self { encoder, ok } = *self;
+        let encoder = encoder.encode_pack()?;
+        Ok(Box::new(SequenceEncoderAdapter { encoder, ok }))
+    }
+
+    #[inline]
+    fn encode_sequence(
+        self: Box<Self>,
+        hint: &SequenceHint,
+    ) -> Result<Box<dyn ErasedSequenceEncoder<'cx, M> + 'a>, ErrorMarker> {
+        let Self { encoder, ok } = *self;
+        let encoder = encoder.encode_sequence(hint)?;
+        Ok(Box::new(SequenceEncoderAdapter { encoder, ok }))
+    }
+
+    #[inline]
+    fn encode_unsized_sequence(
+        self: Box<Self>,
+    ) -> Result<Box<dyn ErasedSequenceEncoder<'cx, M> + 'a>, ErrorMarker> {
+        let Self { encoder, ok } = *self;
+        let encoder = encoder.encode_unsized_sequence()?;
+        Ok(Box::new(SequenceEncoderAdapter { encoder, ok }))
+    }
+
+    #[inline]
+    fn encode_map(
+        self: Box<Self>,
+        hint: &MapHint,
+    ) -> Result<Box<dyn ErasedMapEncoder<'cx, M> + 'a>, ErrorMarker> {
+        let Self { encoder, ok } = *self;
+        let encoder = encoder.encode_map(hint)?;
+        Ok(Box::new(MapEncoderAdapter { encoder, ok }))
+    }
+
+    #[inline]
+    fn encode_map_entries(
+        self: Box<Self>,
+        hint: &MapHint,
+    ) -> Result<Box<dyn ErasedEntriesEncoder<'cx, M> + 'a>, ErrorMarker> {
+        let Self { encoder, ok } = *self;
+        let encoder = encoder.encode_map_entries(hint)?;
+        Ok(Box::new(EntriesEncoderAdapter { encoder, ok }))
+    }
+
+    #[inline]
+    fn encode_variant(
+        self: Box<Self>,
+    ) -> Result<Box<dyn ErasedVariantEncoder<'cx, M> + 'a>, ErrorMarker> {
+        let Self { encoder, ok } = *self;
+        let encoder = encoder.encode_variant()?;
+        Ok(Box::new(VariantEncoderAdapter { encoder, ok }))
+    }
+
+    #[inline]
+    fn encode_unit_variant(self: Box<Self>, tag: &dyn DynEncode<M>) -> Result<(), ErrorMarker> {
+        self.store(|encoder| encoder.encode_unit_variant(tag))
+    }
+
+    #[inline]
+    fn encode_sequence_variant(
+        self: Box<Self>,
+        tag: &dyn DynEncode<M>,
+        hint: &SequenceHint,
+    ) -> Result<Box<dyn ErasedSequenceEncoder<'cx, M> + 'a>, ErrorMarker> {
+        let Self { encoder, ok } = *self;
+        let encoder = encoder.encode_sequence_variant(tag, hint)?;
+        Ok(Box::new(SequenceEncoderAdapter { encoder, ok }))
+    }
+
+    #[inline]
+    fn encode_map_variant(
+        self: Box<Self>,
+        tag: &dyn DynEncode<M>,
+        hint: &MapHint,
+    ) -> Result<Box<dyn ErasedMapEncoder<'cx, M> + 'a>, ErrorMarker> {
+        let Self { encoder, ok } = *self;
+        let encoder = encoder.encode_map_variant(tag, hint)?;
+        Ok(Box::new(MapEncoderAdapter { encoder, ok }))
+    }
+}
+
+struct SequenceEncoderAdapter<'o, E, O> {
+    encoder: E,
+    ok: &'o Cell<Option<O>>,
+}
+
+impl<'o, 'cx, M, E, O> ErasedSequenceEncoder<'cx, M> for SequenceEncoderAdapter<'o, E, O>
+where
+    M: 'static,
+    E: SequenceEncoder<Cx = DynContext<'cx, M>, Ok = O>,
+{
+    #[inline]
+    fn encode_next(&mut self) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker> {
+        let encoder = self.encoder.encode_next()?;
+        Ok(Box::new(EncoderAdapter {
+            encoder,
+            ok: self.ok,
+        }))
+    }
+
+    #[inline]
+    fn finish_sequence(self: Box<Self>) -> Result<(), ErrorMarker> {
+        self.ok.set(Some(self.encoder.finish_sequence()?));
+        Ok(())
+    }
+
+    #[inline]
+    fn try_push_pod(&mut self, pod: PodSlice<'_>) -> Result<bool, ErrorMarker> {
+        self.encoder.try_push_pod(pod)
+    }
+}
+
+struct MapEncoderAdapter<'o, E, O> {
+    encoder: E,
+    ok: &'o Cell<Option<O>>,
+}
+
+impl<'o, 'cx, M, E, O> ErasedMapEncoder<'cx, M> for MapEncoderAdapter<'o, E, O>
+where
+    M: 'static,
+    E: MapEncoder<Cx = DynContext<'cx, M>, Ok = O>,
+{
+    #[inline]
+    fn encode_entry(&mut self) -> Result<Box<dyn ErasedEntryEncoder<'cx, M> + '_>, ErrorMarker> {
+        let encoder = self.encoder.encode_entry()?;
+        Ok(Box::new(EntryEncoderAdapter {
+            encoder,
+            ok: self.ok,
+        }))
+    }
+
+    #[inline]
+    fn finish_map(self: Box<Self>) -> Result<(), ErrorMarker> {
+        self.ok.set(Some(self.encoder.finish_map()?));
+        Ok(())
+    }
+}
+
+struct EntryEncoderAdapter<'o, E, O> {
+    encoder: E,
+    ok: &'o Cell<Option<O>>,
+}
+
+impl<'o, 'cx, M, E, O> ErasedEntryEncoder<'cx, M> for EntryEncoderAdapter<'o, E, O>
+where
+    M: 'static,
+    E: EntryEncoder<Cx = DynContext<'cx, M>, Ok = O>,
+{
+    #[inline]
+    fn encode_key(&mut self) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker> {
+        let encoder = self.encoder.encode_key()?;
+        Ok(Box::new(EncoderAdapter {
+            encoder,
+            ok: self.ok,
+        }))
+    }
+
+    #[inline]
+    fn encode_value(&mut self) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker> {
+        let encoder = self.encoder.encode_value()?;
+        Ok(Box::new(EncoderAdapter {
+            encoder,
+            ok: self.ok,
+        }))
+    }
+
+    #[inline]
+    fn finish_entry(self: Box<Self>) -> Result<(), ErrorMarker> {
+        self.ok.set(Some(self.encoder.finish_entry()?));
+        Ok(())
+    }
+}
+
+struct EntriesEncoderAdapter<'o, E, O> {
+    encoder: E,
+    ok: &'o Cell<Option<O>>,
+}
+
+impl<'o, 'cx, M, E, O> ErasedEntriesEncoder<'cx, M> for EntriesEncoderAdapter<'o, E, O>
+where
+    M: 'static,
+    E: EntriesEncoder<Cx = DynContext<'cx, M>, Ok = O>,
+{
+    #[inline]
+    fn encode_entry_key(&mut self) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker> {
+        let encoder = self.encoder.encode_entry_key()?;
+        Ok(Box::new(EncoderAdapter {
+            encoder,
+            ok: self.ok,
+        }))
+    }
+
+    #[inline]
+    fn encode_entry_value(
+        &mut self,
+    ) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker> {
+        let encoder = self.encoder.encode_entry_value()?;
+        Ok(Box::new(EncoderAdapter {
+            encoder,
+            ok: self.ok,
+        }))
+    }
+
+    #[inline]
+    fn finish_entries(self: Box<Self>) -> Result<(), ErrorMarker> {
+        self.ok.set(Some(self.encoder.finish_entries()?));
+        Ok(())
+    }
+}
+
+struct VariantEncoderAdapter<'o, E, O> {
+    encoder: E,
+    ok: &'o Cell<Option<O>>,
+}
+
+impl<'o, 'cx, M, E, O> ErasedVariantEncoder<'cx, M> for VariantEncoderAdapter<'o, E, O>
+where
+    M: 'static,
+    E: VariantEncoder<Cx = DynContext<'cx, M>, Ok = O>,
+{
+    #[inline]
+    fn encode_tag(&mut self) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker> {
+        let encoder = self.encoder.encode_tag()?;
+        Ok(Box::new(EncoderAdapter {
+            encoder,
+            ok: self.ok,
+        }))
+    }
+
+    #[inline]
+    fn encode_data(&mut self) -> Result<Box<dyn ErasedEncoder<'_, 'cx, M> + '_>, ErrorMarker> {
+        let encoder = self.encoder.encode_data()?;
+        Ok(Box::new(EncoderAdapter {
+            encoder,
+            ok: self.ok,
+        }))
+    }
+
+    #[inline]
+    fn finish_variant(self: Box<Self>) -> Result<(), ErrorMarker> {
+        self.ok.set(Some(self.encoder.finish_variant()?));
+        Ok(())
+    }
+}
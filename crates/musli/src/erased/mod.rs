@@ -0,0 +1,229 @@
+//! Type-erased encoders and decoders.
+//!
+//! Encoders and decoders are generic over the format being used, so code which
+//! works with any format has to be generic as well. That doesn't work across
+//! trait objects or in plugin systems, where the code doing the encoding isn't
+//! known when the format is picked. This module provides [`DynEncoder`] and
+//! [`DynDecoder`], which implement [`Encoder`] and [`Decoder`] for any format
+//! without any type parameters other than the mode.
+//!
+//! Every type which implements [`Encode`] also implements [`DynEncode`], so
+//! values of different types can be stored behind the same trait object and
+//! still be encoded with any format:
+//!
+//! ```
+//! use musli::erased::DynEncode;
+//! use musli::mode::Binary;
+//! use musli::Encode;
+//!
+//! #[derive(Encode)]
+//! struct Health {
+//!     current: u32,
+//!     max: u32,
+//! }
+//!
+//! #[derive(Encode)]
+//! struct Name {
+//!     name: String,
+//! }
+//!
+//! let components: Vec<Box<dyn DynEncode<Binary>>> = vec![
+//!     Box::new(Health { current: 10, max: 20 }),
+//!     Box::new(Name { name: String::from("Aristotle") }),
+//! ];
+//!
+//! let storage = musli::storage::to_vec(&components)?;
+//! let wire = musli::wire::to_vec(&components)?;
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Decoders are erased with [`erase_decoder`], which passes a [`DynDecoder`] to
+//! a callback. The callback can be a plain function which doesn't know which
+//! format is being decoded:
+//!
+//! ```
+//! use musli::context::ErrorMarker;
+//! use musli::erased::{self, DynDecoder};
+//! use musli::mode::Binary;
+//! use musli::{Decode, Decoder, Encode};
+//!
+//! #[derive(Debug, PartialEq, Decode, Encode)]
+//! struct Health {
+//!     current: u32,
+//!     max: u32,
+//! }
+//!
+//! fn load(decoder: DynDecoder<'_, '_, '_, Binary>) -> Result<Health, ErrorMarker> {
+//!     decoder.decode()
+//! }
+//!
+//! struct Loaded(Health);
+//!
+//! impl<'de> Decode<'de, Binary> for Loaded {
+//!     fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+//!     where
+//!         D: Decoder<'de, Mode = Binary>,
+//!     {
+//!         erased::erase_decoder(cx, decoder, load).map(Loaded)
+//!     }
+//! }
+//!
+//! let health = Health { current: 10, max: 20 };
+//!
+//! let bytes = musli::wire::to_vec(&health)?;
+//! let Loaded(loaded) = musli::wire::from_slice(&bytes)?;
+//! assert_eq!(loaded, health);
+//! # Ok::<_, musli::wire::Error>(())
+//! ```
+//!
+//! Errors raised while encoding or decoding through the erased types are
+//! reported as an [`ErrorMarker`], and the full error is recovered from the
+//! original context when [`erase_encoder`] or [`erase_decoder`] returns.
+
+#![cfg(feature = "alloc")]
+#![cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+
+mod context;
+use self::context::Bridge;
+#[doc(inline)]
+pub use self::context::DynContext;
+
+mod de;
+#[doc(inline)]
+pub use self::de::{
+    DynDecoder, DynEntriesDecoder, DynEntryDecoder, DynMapDecoder, DynSequenceDecoder,
+    DynVariantDecoder,
+};
+
+mod en;
+#[doc(inline)]
+pub use self::en::{
+    DynEncoder, DynEntriesEncoder, DynEntryEncoder, DynMapEncoder, DynSequenceEncoder,
+    DynVariantEncoder,
+};
+
+use core::cell::Cell;
+
+use crate::context::ErrorMarker;
+use crate::{Context, Decoder, Encode, Encoder};
+
+/// An object-safe version of [`Encode`].
+///
+/// This is implemented for every type which implements [`Encode`], and
+/// `dyn DynEncode<M>` implements [`Encode`] in turn.
+///
+/// See the [module level documentation][self].
+pub trait DynEncode<M> {
+    /// Encode the value into a type-erased encoder.
+    fn encode_dyn(&self, encoder: DynEncoder<'_, '_, M>) -> Result<(), ErrorMarker>;
+}
+
+impl<M, T> DynEncode<M> for T
+where
+    M: 'static,
+    T: ?Sized + Encode<M>,
+{
+    #[inline]
+    fn encode_dyn(&self, encoder: DynEncoder<'_, '_, M>) -> Result<(), ErrorMarker> {
+        encoder.encode(self)
+    }
+}
+
+macro_rules! encode_dyn {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<M> Encode<M> for $ty
+            where
+                M: 'static,
+            {
+                #[inline]
+                fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+                where
+                    E: Encoder<Mode = M>,
+                {
+                    erase_encoder(cx, encoder, |encoder| self.encode_dyn(encoder))
+                }
+            }
+        )*
+    };
+}
+
+encode_dyn! {
+    dyn DynEncode<M> + '_,
+    dyn DynEncode<M> + Send + '_,
+    dyn DynEncode<M> + Sync + '_,
+    dyn DynEncode<M> + Send + Sync + '_,
+}
+
+/// Erase the type of an encoder, passing the erased encoder to `f`.
+///
+/// If encoding fails, the error is reported through `cx` as if the encoder had
+/// been used directly.
+///
+/// # Examples
+///
+/// ```
+/// use musli::erased::{self, DynEncoder};
+/// use musli::mode::Binary;
+/// use musli::{Encode, Encoder};
+///
+/// struct Greeting;
+///
+/// impl Encode<Binary> for Greeting {
+///     fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+///     where
+///         E: Encoder<Mode = Binary>,
+///     {
+///         erased::erase_encoder(cx, encoder, |encoder: DynEncoder<'_, '_, Binary>| {
+///             encoder.encode_string("Hello World")
+///         })
+///     }
+/// }
+///
+/// let bytes = musli::storage::to_vec(&Greeting)?;
+/// let greeting: String = musli::storage::from_slice(&bytes)?;
+/// assert_eq!(greeting, "Hello World");
+/// # Ok::<_, musli::storage::Error>(())
+/// ```
+pub fn erase_encoder<E, F>(cx: &E::Cx, encoder: E, f: F) -> Result<E::Ok, E::Error>
+where
+    E: Encoder,
+    F: FnOnce(DynEncoder<'_, '_, E::Mode>) -> Result<(), ErrorMarker>,
+{
+    let bridge = Bridge::new(cx);
+    let dyn_cx = DynContext::new(&bridge);
+    let ok = Cell::new(None);
+
+    let encoder = encoder.with_context(&dyn_cx)?;
+
+    if f(DynEncoder::erase(&dyn_cx, encoder, &ok)).is_err() {
+        return Err(bridge.restore());
+    }
+
+    match ok.into_inner() {
+        Some(ok) => Ok(ok),
+        None => Err(cx.message("Type-erased encoder was not used")),
+    }
+}
+
+/// Erase the type of a decoder, passing the erased decoder to `f`.
+///
+/// If decoding fails, the error is reported through `cx` as if the decoder had
+/// been used directly.
+///
+/// See the [module level documentation][self] for an example.
+pub fn erase_decoder<'de, D, F, O>(cx: &D::Cx, decoder: D, f: F) -> Result<O, D::Error>
+where
+    D: Decoder<'de>,
+    F: FnOnce(DynDecoder<'_, '_, 'de, D::Mode>) -> Result<O, ErrorMarker>,
+{
+    let bridge = Bridge::new(cx);
+    let dyn_cx = DynContext::new(&bridge);
+
+    let decoder = decoder.with_context(&dyn_cx)?;
+
+    match f(DynDecoder::erase(&dyn_cx, decoder)) {
+        Ok(output) => Ok(output),
+        Err(..) => Err(bridge.restore()),
+    }
+}
@@ -112,8 +112,7 @@
 //! The attributes only apply when implementing the `Encode` trait.
 //!
 //! An example where this is useful is if you want to apply `#[musli(packed)]`
-//! in a different mode, but only for encoding, since decoding packed types is
-//! not supported for enums.
+//! in a different mode, but only for encoding.
 //!
 //! ```
 //! use musli::mode::Binary;
@@ -149,6 +148,49 @@
 //!
 //! <br>
 //!
+//! #### Mode-specific fields
+//!
+//! Since `#[musli(mode)]`, `#[musli(encode_only)]` and `#[musli(decode_only)]`
+//! can be combined with any field attribute, fields can be renamed or skipped
+//! in one mode only. A skipped field which is only skipped when decoding is
+//! effectively *encode-only*, and the other way around.
+//!
+//! This allows for one struct to be used in a compact binary protocol, while
+//! also being written to a more verbose log in the [`Text`] mode:
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! struct Event {
+//!     id: u32,
+//!     #[musli(mode = Text, name = "user-name")]
+//!     user: String,
+//!     // Only part of the log.
+//!     #[musli(mode = Binary, skip)]
+//!     note: String,
+//!     // Written to the log, but never read back from it.
+//!     #[musli(mode = Text, decode_only, skip)]
+//!     session: u32,
+//! }
+//!
+//! let event = Event {
+//!     id: 1,
+//!     user: String::from("Aristotle"),
+//!     note: String::from("Logged in"),
+//!     session: 42,
+//! };
+//!
+//! let log = musli::json::to_string(&event)?;
+//! assert_eq!(log, r#"{"id":1,"user-name":"Aristotle","note":"Logged in","session":42}"#);
+//!
+//! let decoded: Event = musli::json::from_str(&log)?;
+//! assert_eq!(decoded.session, 0);
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! <br>
+//!
 //! ## Container attributes
 //!
 //! Container attributes apply to the container, such as directly on the
@@ -175,11 +217,13 @@
 //!
 //! #### `#[musli(name_all = "..")]`
 //!
-//! Allos for renaming every field in the container. It can take any of the
-//! following values:
+//! Allows for renaming every field in the container. This corresponds to
+//! `#[serde(rename_all = "..")]`, and it can take any of the following values:
 //!
 //! * `index` (default) - the index of the field will be used.
 //! * `name` - the literal name of the field will be used.
+//! * `discriminant` - the discriminant of the variant will be used, this is
+//!   only supported on enums.
 //! * `PascalCase` - the field will be converted to pascal case.
 //! * `camelCase` - the field will be converted to camel case.
 //! * `snake_case` - the field will be converted to snake case.
@@ -229,6 +273,97 @@
 //! }
 //! ```
 //!
+//! With `#[musli(name_all = "discriminant")]` variants are named by their
+//! discriminant, which keeps the encoding aligned with existing definitions in
+//! other languages. The name type is the integer used in `#[repr(..)]`, or
+//! `isize` if there is none. Variants without an explicit discriminant follow
+//! the same rules as the compiler, so they are one larger than the variant
+//! before them.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[repr(u8)]
+//! #[musli(name_all = "discriminant")]
+//! enum Opcode {
+//!     Nop = 0x10,
+//!     Load,
+//!     Store = 0x20,
+//! }
+//!
+//! let data = musli::json::to_string(&[Opcode::Nop, Opcode::Load, Opcode::Store])?;
+//! assert_eq!(data, "[16,17,32]");
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(deny_unknown_fields)]`
+//!
+//! By default fields which are not recognized are skipped when decoding, which
+//! allows for newer versions of a type to add fields. With this attribute an
+//! error is raised instead through [`Context::invalid_field_tag`], which is
+//! useful when silently dropping data is not acceptable.
+//!
+//! If applied to an enum, it applies to the fields of every variant.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode)]
+//! #[musli(name_all = "name")]
+//! struct Version2 {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! #[derive(Debug, Decode)]
+//! #[musli(name_all = "name", deny_unknown_fields)]
+//! struct Version1 {
+//!     name: String,
+//! }
+//!
+//! let data = musli::json::to_vec(&Version2 {
+//!     name: String::from("Aristotle"),
+//!     age: 62,
+//! })?;
+//!
+//! assert!(musli::json::from_slice::<Version1>(&data).is_err());
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! This can't be combined with `#[musli(flatten)]` fields, or with internally
+//! tagged enums since the tag is a field which is unknown to the variant.
+//!
+//! #### `#[musli(case_insensitive)]`
+//!
+//! This causes the names of fields and variants to be matched without regard
+//! to ASCII case when decoding. This only applies to names which are decoded
+//! as strings, and names are still encoded exactly as they are specified.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[musli(name_all = "name", case_insensitive)]
+//! struct Config {
+//!     verbose: bool,
+//!     level: Level,
+//! }
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[musli(name_all = "name", case_insensitive)]
+//! enum Level {
+//!     Debug,
+//!     Info,
+//! }
+//!
+//! let config: Config = musli::json::from_str(r#"{"VERBOSE":true,"Level":"INFO"}"#)?;
+//! assert_eq!(config, Config { verbose: true, level: Level::Info });
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
 //! <br>
 //!
 //! #### `#[musli(transparent)]`
@@ -250,6 +385,23 @@
 //! # Ok::<_, musli::wire::Error>(())
 //! ```
 //!
+//! Fields which are marked with `#[musli(skip)]` don't count, so this can be
+//! used for strongly typed identifiers which carry a marker type:
+//!
+//! ```
+//! use core::marker::PhantomData;
+//!
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(transparent)]
+//! struct Id<T> {
+//!     value: u64,
+//!     #[musli(skip)]
+//!     _marker: PhantomData<T>,
+//! }
+//! ```
+//!
 //! <br>
 //!
 //! #### `#[musli(packed)]`
@@ -286,6 +438,34 @@
 //! # Ok::<_, musli::storage::Error>(())
 //! ```
 //!
+//! When used on an enum, each variant is encoded as a pack containing the
+//! variant tag followed by the fields of the variant. Combined with
+//! `#[musli(name_all = "discriminant")]` this is a compact representation
+//! suitable for simple wire protocols.
+//!
+//! Since the fields of an unknown variant can't be skipped, packed enums can't
+//! have a `#[musli(default)]` fallback variant, and their variants can't be
+//! `#[musli(transparent)]`.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[musli(packed, name_all = "discriminant")]
+//! #[repr(u8)]
+//! enum Command {
+//!     Reset = 1,
+//!     Move { x: u8, y: u8 } = 2,
+//! }
+//!
+//! let data = musli::storage::to_vec(&Command::Move { x: 10, y: 20 })?;
+//! assert_eq!(data.as_slice(), [2, 10, 20]);
+//!
+//! let command: Command = musli::storage::from_slice(&data)?;
+//! assert_eq!(command, Command::Move { x: 10, y: 20 });
+//! # Ok::<_, musli::storage::Error>(())
+//! ```
+//!
 //! <br>
 //!
 //! #### `#[musli(name_type = ..)]`
@@ -330,6 +510,41 @@
 //! }
 //! ```
 //!
+//! If the name type is a primitive integer such as `u16`, fields and variants
+//! which are not explicitly named use an index of that type, which is useful
+//! for keeping tags compact.
+//!
+//! Any type which implements [`Encode`], [`Decode`], [`PartialEq`] and
+//! [`Debug`] can be used as a name, such as an enum which describes the tags
+//! used by a protocol:
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[musli(name_all = "kebab-case")]
+//! enum FieldTag {
+//!     UserName,
+//!     Age,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_type = FieldTag)]
+//! struct User {
+//!     #[musli(name = FieldTag::UserName)]
+//!     name: String,
+//!     #[musli(name = FieldTag::Age)]
+//!     age: u32,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_type = u16)]
+//! struct Compact {
+//!     name: String,
+//!     age: u32,
+//! }
+//! ```
+//!
 //! <br>
 //!
 //! #### `#[musli(name_method = ..)]`
@@ -374,6 +589,287 @@
 //! }
 //! ```
 //!
+//! No bounds are inferred for generic parameters, so parameters which are not
+//! encoded such as the one used in a `PhantomData<T>` don't need any. If the
+//! same bound is sufficient for both implementations, such as when using
+//! [`DecodeOwned`], `#[musli(bound = {..})]` can be used by itself:
+//!
+//! ```
+//! use core::marker::PhantomData;
+//!
+//! use musli::{Decode, Encode};
+//! use musli::de::DecodeOwned;
+//! use musli::mode::Binary;
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(bound = {T: Encode<Binary> + DecodeOwned<Binary>})]
+//! pub struct Shared<T> {
+//!     value: T,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! pub struct Id<T> {
+//!     value: u32,
+//!     marker: PhantomData<T>,
+//! }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(version = <int>)]` and `#[musli(upgrade = {..})]`
+//!
+//! This makes the container versioned. It is encoded as a variant, where the
+//! tag is the version as an `u32` and the value is the container itself.
+//!
+//! Older versions can be decoded by specifying upgrade functions with
+//! `#[musli(upgrade = {<version> => <path>, ..})]`. Each function takes a
+//! single argument which is the data of the older version, and returns the
+//! current type. The argument type should describe the data of the old
+//! version without being versioned itself. Decoding any other version results
+//! in an error.
+//!
+//! Note that adding `#[musli(version)]` to an existing type changes how it is
+//! encoded, so data written without it can't be read back.
+//!
+//! ```
+//! use musli::{Decode, Encode};
+//!
+//! #[derive(Decode)]
+//! struct ConfigV1 {
+//!     name: String,
+//! }
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[musli(version = 2, upgrade = {1 => Config::from_v1})]
+//! struct Config {
+//!     name: String,
+//!     port: u16,
+//! }
+//!
+//! impl Config {
+//!     fn from_v1(old: ConfigV1) -> Self {
+//!         Self {
+//!             name: old.name,
+//!             port: 80,
+//!         }
+//!     }
+//! }
+//!
+//! let config: Config = musli::json::from_str(r#"{"1":{"name":"example"}}"#)?;
+//!
+//! assert_eq!(config, Config {
+//!     name: String::from("example"),
+//!     port: 80,
+//! });
+//!
+//! let json = musli::json::to_string(&config)?;
+//! assert_eq!(json, r#"{"2":{"name":"example","port":80}}"#);
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(validate = <path>)]`
+//!
+//! Call the function at `<path>` with a reference to the value once it has
+//! been decoded. If it returns an error, decoding fails with that error as its
+//! message. The function should have a signature like
+//! `fn(&T) -> Result<(), E>`, where `E` implements [`Display`].
+//!
+//! This can be used to enforce invariants which can't be expressed in the type
+//! itself, like ranges or relationships between fields.
+//!
+//! ```
+//! use musli::{Decode, Encode};
+//!
+//! #[derive(Debug, Encode, Decode)]
+//! #[musli(validate = Range::validate)]
+//! struct Range {
+//!     start: u32,
+//!     end: u32,
+//! }
+//!
+//! impl Range {
+//!     fn validate(&self) -> Result<(), &'static str> {
+//!         if self.start > self.end {
+//!             return Err("start must not be greater than end");
+//!         }
+//!
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let range: Range = musli::json::from_str(r#"{"start":1,"end":2}"#)?;
+//! assert_eq!(range.end, 2);
+//!
+//! let error = musli::json::from_str::<Range>(r#"{"start":2,"end":1}"#).unwrap_err();
+//! assert!(error.to_string().contains("start must not be greater than end"));
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(from = <type>)]` and `#[musli(into = <type>)]`
+//!
+//! With `#[musli(into = <type>)]` the value is encoded by cloning it and
+//! converting it into the given proxy type using [`Into`], which is then
+//! encoded in its place. This requires the type to implement [`Clone`].
+//!
+//! With `#[musli(from = <type>)]` the value is decoded by first decoding the
+//! given proxy type, and then converting it using [`From`].
+//!
+//! This is useful for types whose internal layout shouldn't be reflected in
+//! how they are encoded.
+//!
+//! ```
+//! use musli::{Decode, Encode};
+//!
+//! #[derive(Encode, Decode)]
+//! struct Rgb {
+//!     r: u8,
+//!     g: u8,
+//!     b: u8,
+//! }
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+//! #[musli(from = Rgb, into = Rgb)]
+//! struct Color(u32);
+//!
+//! impl From<Rgb> for Color {
+//!     fn from(rgb: Rgb) -> Self {
+//!         Color(u32::from_be_bytes([0, rgb.r, rgb.g, rgb.b]))
+//!     }
+//! }
+//!
+//! impl From<Color> for Rgb {
+//!     fn from(color: Color) -> Self {
+//!         let [_, r, g, b] = color.0.to_be_bytes();
+//!         Rgb { r, g, b }
+//!     }
+//! }
+//!
+//! let json = musli::json::to_string(&Color(0x102030))?;
+//! assert_eq!(json, r#"{"r":16,"g":32,"b":48}"#);
+//!
+//! let color: Color = musli::json::from_str(&json)?;
+//! assert_eq!(color, Color(0x102030));
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(remote = <path>)]`
+//!
+//! This generates the implementation for a type defined in another crate,
+//! which can't implement [`Encode`] or [`Decode`] itself. The type the
+//! attribute is used on acts as a local mirror of the remote type and must
+//! have the same fields or variants, which also have to be accessible.
+//!
+//! Instead of implementing [`Encode`] and [`Decode`], the mirror type gets
+//! `encode` and `decode` functions which can be used through
+//! [`#[musli(with = <path>)]`](#musliwith--path) on any field which has the
+//! remote type.
+//!
+//! ```
+//! mod other {
+//!     pub struct Point {
+//!         pub x: u32,
+//!         pub y: u32,
+//!     }
+//! }
+//!
+//! use musli::{Decode, Encode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(remote = other::Point)]
+//! struct PointDef {
+//!     x: u32,
+//!     y: u32,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! struct Line {
+//!     #[musli(with = PointDef)]
+//!     from: other::Point,
+//!     #[musli(with = PointDef)]
+//!     to: other::Point,
+//! }
+//!
+//! let line = Line {
+//!     from: other::Point { x: 1, y: 2 },
+//!     to: other::Point { x: 3, y: 4 },
+//! };
+//!
+//! let json = musli::json::to_string(&line)?;
+//! assert_eq!(json, r#"{"from":{"x":1,"y":2},"to":{"x":3,"y":4}}"#);
+//!
+//! let line: Line = musli::json::from_str(&json)?;
+//! assert_eq!(line.to.x, 3);
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(reflect)]`
+//!
+//! This causes the [`Encode`] derive to also implement [`Reflect`], which
+//! provides a constant table with the names, indexes and types of the fields
+//! and variants of the type. The types are provided as strings as they are
+//! written in the declaration.
+//!
+//! ```
+//! use musli::Encode;
+//! use musli::reflect::{MetadataKind, Reflect};
+//!
+//! #[derive(Encode)]
+//! #[musli(reflect)]
+//! enum Message {
+//!     Ping,
+//!     Text { body: String },
+//! }
+//!
+//! let MetadataKind::Enum { variants } = Message::METADATA.kind else {
+//!     panic!("expected an enum");
+//! };
+//!
+//! assert_eq!(variants[1].name, "Text");
+//! assert_eq!(variants[1].fields[0].name, "body");
+//! assert_eq!(variants[1].fields[0].ty, "String");
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(crate = <path>)]`
+//!
+//! This specifies the path to the `musli` crate which the generated
+//! implementations refer to. By default this is `musli`, which requires the
+//! crate to be available under that name.
+//!
+//! This is useful when the derives are used through a facade crate which
+//! re-exports `musli`, or when the dependency has been renamed in
+//! `Cargo.toml`. Using `#[musli(crate)]` without a path is the same as
+//! `#[musli(crate = crate)]`, which is what `musli` itself uses internally.
+//!
+//! ```
+//! mod facade {
+//!     pub use musli;
+//! }
+//!
+//! use facade::musli::{Decode, Encode};
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[musli(crate = facade::musli)]
+//! struct Person {
+//!     name: String,
+//! }
+//!
+//! let person = Person { name: String::from("Aristotle") };
+//! let json = musli::json::to_string(&person)?;
+//! let actual: Person = musli::json::from_str(&json)?;
+//! assert_eq!(actual, person);
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
 //! <br>
 //!
 //! ## Enum attributes
@@ -400,6 +896,48 @@
 //!
 //! <br>
 //!
+//! #### `#[musli(content = ..)]`
+//!
+//! When combined with `#[musli(tag = ..)]` this attribute causes the enum to be
+//! adjacently tagged, where the content of the variant is stored under the
+//! given field next to the tag. See [enum
+//! representations](#enum-representations) for details on this
+//! representation.
+//!
+//! ```
+//! # use musli::{Encode, Decode};
+//! # #[derive(Encode, Decode)] struct Params;
+//! # #[derive(Encode, Decode)] struct Value;
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name", tag = "type", content = "data")]
+//! enum Message {
+//!     Request { id: String, method: String, params: Params },
+//!     Response { id: String, result: Value },
+//! }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(untagged)]`
+//!
+//! This attribute causes the enum to be encoded without any tag, so that the
+//! variant is determined by the shape of the value when decoding. See [enum
+//! representations](#enum-representations) for details on this
+//! representation.
+//!
+//! ```
+//! # use musli::{Encode, Decode};
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name", untagged)]
+//! enum Id {
+//!     Numeric { number: u64 },
+//!     #[musli(transparent)]
+//!     Named(String),
+//! }
+//! ```
+//!
+//! <br>
+//!
 //! ## Variant attributes
 //!
 //! *Variant attributes* are attributes which apply to each individual variant
@@ -460,6 +998,32 @@
 //!
 //! <br>
 //!
+//! #### `#[musli(alias = ..)]`
+//!
+//! An additional name which the variant is decoded from. This can be specified
+//! multiple times, and allows a variant to be renamed while still decoding
+//! data which was encoded using its old name. The variant is always encoded
+//! using its current name.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[musli(name_all = "snake_case")]
+//! enum Shape {
+//!     #[musli(alias = "rectangle", alias = "rect")]
+//!     Square,
+//!     Circle,
+//! }
+//!
+//! let shape: Shape = musli::json::from_str(r#""rect""#)?;
+//! assert_eq!(shape, Shape::Square);
+//! assert_eq!(musli::json::to_string(&shape)?, r#""square""#);
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! <br>
+//!
 //! #### `#[musli(name_all = "..")]`
 //!
 //! Allos for renaming every field in the variant. It can take any of the
@@ -574,7 +1138,10 @@
 //! #### `#[musli(default)]`
 //!
 //! This defines the variant that will be used in case no other variant matches.
-//! Only one such variant can be defined.
+//! Only one such variant can be defined, and it must be empty. This
+//! corresponds to `#[serde(other)]` and allows older versions of a protocol to
+//! decode variants which were added later. Any data associated with the
+//! unknown variant is skipped.
 //!
 //! ```
 //! use musli::{Encode, Decode};
@@ -587,6 +1154,51 @@
 //!     #[musli(default)]
 //!     Unknown,
 //! }
+//!
+//! #[derive(Encode)]
+//! #[musli(name_all = "kebab-case")]
+//! enum NewAnimal {
+//!     #[musli(name_all = "name")]
+//!     Bird { wings: u32 },
+//! }
+//!
+//! let data = musli::json::to_vec(&NewAnimal::Bird { wings: 2 })?;
+//! let animal: Animal = musli::json::from_slice(&data)?;
+//! assert_eq!(animal, Animal::Unknown);
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! In an `#[musli(untagged)]` enum, the fallback variant is used for any value
+//! which none of the other variants could decode.
+//!
+//! <br>
+//!
+//! #### `#[musli(skip)]`
+//!
+//! This excludes the variant from encoding and decoding. Trying to encode a
+//! skipped variant results in an error, and decoding never produces it since
+//! it's treated like any other unknown variant.
+//!
+//! This is useful for variants which are only used internally.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+//! #[musli(name_all = "kebab-case")]
+//! enum State {
+//!     Idle,
+//!     Running,
+//!     #[musli(skip)]
+//!     Poisoned,
+//! }
+//!
+//! let json = musli::json::to_string(&State::Running)?;
+//! assert_eq!(json, r#""running""#);
+//!
+//! assert!(musli::json::to_string(&State::Poisoned).is_err());
+//! assert!(musli::json::from_str::<State>(r#""poisoned""#).is_err());
+//! # Ok::<_, musli::json::Error>(())
 //! ```
 //!
 //! <br>
@@ -724,6 +1336,30 @@
 //!
 //! <br>
 //!
+//! #### `#[musli(alias = ..)]`
+//!
+//! An additional name which the field is decoded from. This can be specified
+//! multiple times, and allows a field to be renamed while still decoding data
+//! which was encoded using its old name. The field is always encoded using its
+//! current name.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! struct Person {
+//!     #[musli(alias = "fullname")]
+//!     name: String,
+//! }
+//!
+//! let person: Person = musli::json::from_str(r#"{"fullname":"Aristotle"}"#)?;
+//! assert_eq!(person.name, "Aristotle");
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! <br>
+//!
 //! #### `#[musli(packed)]`
 //!
 //! This specifies that encoding and decoding should happen through the
@@ -781,9 +1417,12 @@
 //! #### `#[musli(with = <path>)]`
 //!
 //! This specifies the path to a module to use instead of the fields default
-//! [`Encode`] or [`Decode`] implementations.
+//! [`Encode`] or [`Decode`] implementations. This makes it possible to encode
+//! types which don't implement [`Encode`] or [`Decode`], like types from other
+//! crates, without wrapping them in a newtype.
 //!
-//! It expects `encode` and `decode` and decodee function to be defined in the path being specified, like this:
+//! It expects an `encode` and a `decode` function to be defined in the module
+//! being specified, like this:
 //!
 //! ```
 //! # mod example {
@@ -916,10 +1555,48 @@
 //!
 //! <br>
 //!
+//! #### `#[musli(encode_with = <path>)]` and `#[musli(decode_with = <path>)]`
+//!
+//! This specifies the path to a function to use instead of the fields default
+//! [`Encode`] or [`Decode`] implementation. Unlike `#[musli(with = <path>)]`
+//! only one direction has to be customized, the other one uses the default
+//! implementation.
+//!
+//! The functions take the same arguments as the `encode` and `decode`
+//! functions used by `#[musli(with = <path>)]`:
+//!
+//! ```
+//! # mod example {
+//! use musli::{Context, Decode, Decoder, Encode};
+//!
+//! #[derive(Decode, Encode)]
+//! struct Config {
+//!     #[musli(decode_with = self::decode_port)]
+//!     port: u16,
+//! }
+//!
+//! fn decode_port<'de, D>(cx: &D::Cx, decoder: D) -> Result<u16, D::Error>
+//! where
+//!     D: Decoder<'de>,
+//! {
+//!     match decoder.decode()? {
+//!         0 => Err(cx.message("Port cannot be zero")),
+//!         port => Ok(port),
+//!     }
+//! }
+//! # }
+//! ```
+//!
+//! <br>
+//!
 //! #### `#[musli(skip_encoding_if = <path>)]`
 //!
 //! This adds a condition to skip encoding a field entirely if the condition is
-//! true. This is very commonly used to skip over encoding `Option<T>` fields.
+//! true. This is very commonly used to skip over encoding `Option<T>` fields or
+//! empty collections, which makes sparse structs smaller when encoded.
+//!
+//! A field which isn't encoded is missing when decoding, so this should
+//! usually be combined with [`#[musli(default)]`][#muslidefault--path].
 //!
 //! ```
 //! use musli::{Encode, Decode};
@@ -927,11 +1604,159 @@
 //! #[derive(Encode, Decode)]
 //! struct Person {
 //!     name: String,
-//!     #[musli(skip_encoding_if = Option::is_none)]
+//!     #[musli(default, skip_encoding_if = Option::is_none)]
 //!     age: Option<u32>,
+//!     #[musli(default, skip_encoding_if = Vec::is_empty)]
+//!     nicknames: Vec<String>,
+//! }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(flatten)]`
+//!
+//! This causes the entries of a field to be encoded as if they were part of the
+//! containing struct, instead of being nested inside of it. The field can be
+//! any type which is encoded as a map, such as another struct. This makes it
+//! possible to share a common set of fields between structs:
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! struct Header {
+//!     id: u32,
+//!     version: u32,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! struct Request {
+//!     #[musli(flatten)]
+//!     header: Header,
+//!     method: String,
+//! }
+//! ```
+//!
+//! In JSON, a `Request` would be represented as:
+//!
+//! ```json
+//! {"method": "...", "id": 1, "version": 2}
+//! ```
+//!
+//! Decoding a struct with flattened fields requires the format to be *self
+//! descriptive*, since the map has to be buffered through
+//! [`Decoder::decode_buffer`] so that every flattened field can pick out the
+//! entries it recognizes.
+//!
+//! A few things to keep in mind:
+//! * Field names must not collide with the names of flattened fields, which is
+//!   why `#[musli(name_all = "name")]` is used above. Otherwise the first field
+//!   of both structs would use the index `0`.
+//! * A flattened map such as `HashMap<String, Value>` is given every entry in
+//!   the containing struct, including the ones which belong to other fields.
+//! * Flattened fields are only supported in structs which are encoded as maps,
+//!   and not in enum variants or `#[musli(packed)]` containers.
+//! * A flattened type cannot itself contain flattened fields.
+//!
+//! <br>
+//!
+//! #### `#[musli(unknown_fields)]`
+//!
+//! This causes fields which are not recognized by the containing struct to be
+//! collected into the annotated field instead of being skipped, and to be
+//! written back out as entries of the struct when it is encoded. This allows a
+//! proxy to pass on messages from newer peers without losing any data.
+//!
+//! The field must be a collection such as `BTreeMap<K, V>` which implements
+//! [`Default`] and [`Extend`], where `K` can be constructed from the name of
+//! the field being decoded. For structs named with strings that is `String`,
+//! and for indexed structs it is `usize`.
+//!
+//! ```
+//! use std::collections::BTreeMap;
+//!
+//! use musli::{Encode, Decode};
+//! use musli::value::Value;
+//!
+//! #[derive(Encode)]
+//! #[musli(name_all = "name")]
+//! struct Version2 {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! struct Proxy {
+//!     name: String,
+//!     #[musli(unknown_fields)]
+//!     unknown: BTreeMap<String, Value>,
+//! }
+//!
+//! let data = musli::json::to_string(&Version2 {
+//!     name: String::from("Aristotle"),
+//!     age: 62,
+//! })?;
+//!
+//! let proxy: Proxy = musli::json::from_str(&data)?;
+//! assert_eq!(proxy.name, "Aristotle");
+//! assert!(proxy.unknown.contains_key("age"));
+//!
+//! assert_eq!(musli::json::to_string(&proxy)?, data);
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! Only one field in a struct can collect unknown fields, and it can't be
+//! combined with `#[musli(flatten)]` fields or with
+//! `#[musli(deny_unknown_fields)]`. Like flattened fields, it is only supported
+//! in structs which are encoded as maps.
+//!
+//! <br>
+//!
+//! #### `#[musli(borrow)]`
+//!
+//! This marks the field as borrowing from the input being decoded, which is
+//! done by using the lifetime of the field type as the lifetime of the decoder.
+//! Without it, the first lifetime parameter of the type is used.
+//!
+//! Fields like `&'de str` and `&'de [u8]` can then only be decoded from readers
+//! which support borrowing, and fields like `Cow<'de, str>` are borrowed
+//! whenever the reader supports it.
+//!
+//! All fields marked with `#[musli(borrow)]` must borrow from the same lifetime
+//! parameter of the type, or else a compile error is raised.
+//!
+//! ```
+//! use std::borrow::Cow;
+//!
+//! use musli::{Decode, Encode};
+//!
+//! #[derive(Encode, Decode)]
+//! struct Message<'a, 'de> {
+//!     #[musli(skip)]
+//!     context: Option<&'a str>,
+//!     #[musli(borrow)]
+//!     name: &'de str,
+//!     #[musli(borrow)]
+//!     body: Cow<'de, str>,
 //! }
+//!
+//! let data = musli::storage::to_vec(&Message {
+//!     context: None,
+//!     name: "greeting",
+//!     body: Cow::Borrowed("Hello World"),
+//! })?;
+//!
+//! let message: Message<'_, '_> = musli::storage::from_slice(&data)?;
+//! assert_eq!(message.name, "greeting");
+//! assert!(matches!(message.body, Cow::Borrowed("Hello World")));
+//! # Ok::<_, musli::storage::Error>(())
 //! ```
 //!
+//! <br>
+//!
 //! #### `#[musli(trace)]`
 //!
 //! This causes the field to use the [`DecodeTrace`] / [`EncodeTrace`] when
@@ -973,8 +1798,9 @@
 //!
 //! * Externally tagged (*default*).
 //! * Internally tagged when `#[musli(tag = ..)]` is specified on the enum.
-//! * Adjacently tagged when both `#[musli(tag = ..)]` and `#[musli(content)]`
-//!   are specified.
+//! * Adjacently tagged when both `#[musli(tag = ..)]` and `#[musli(content =
+//!   ..)]` are specified.
+//! * Untagged when `#[musli(untagged)]` is specified on the enum.
 //!
 //! <br>
 //!
@@ -1034,10 +1860,117 @@
 //! a map for the field corresponding to the `tag`, and then use this to
 //! determine which decoder implementation to call.
 //!
+//! Newtype variants which are marked with `#[musli(transparent)]` have the
+//! entries of the value they wrap encoded next to the tag, which means that the
+//! wrapped value has to be encoded as a map:
+//!
+//! ```
+//! # use musli::{Encode, Decode};
+//! # #[derive(Encode, Decode)] struct Params;
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! struct Request {
+//!     id: String,
+//!     method: String,
+//!     params: Params,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name", tag = "type")]
+//! enum Message {
+//!     #[musli(transparent)]
+//!     Request(Request),
+//! }
+//! ```
+//!
+//! Which in JSON is represented the same way as the `Message::Request`
+//! variant above.
+//!
+//! <br>
+//!
+//! ## Adjacently tagged
+//!
+//! ```
+//! # use musli::{Encode, Decode};
+//! # #[derive(Encode, Decode)] struct Params;
+//! # #[derive(Encode, Decode)] struct Value;
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name", tag = "type", content = "data")]
+//! enum Message {
+//!     Request { id: String, method: String, params: Params },
+//!     Response { id: String, result: Value },
+//! }
+//! ```
+//!
+//! In JSON, the `Message::Request` would be represented as:
+//!
+//! ```json
+//! {"type": "Request", "data": {"id": "...", "method": "...", "params": {...}}}
+//! ```
+//!
+//! Unlike internal tagging this doesn't require the value to be buffered, so
+//! it's supported by any format which can encode maps. As a consequence the
+//! tag has to come before the content when decoding, otherwise an error is
+//! raised through [`Context::missing_adjacent_tag`]. Any other fields are
+//! skipped.
+//!
+//! <br>
+//!
+//! ## Untagged
+//!
+//! ```
+//! # use musli::{Encode, Decode};
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name", untagged)]
+//! enum Value {
+//!     Point { x: i32, y: i32 },
+//!     #[musli(transparent)]
+//!     Number(u32),
+//!     #[musli(transparent)]
+//!     Text(String),
+//!     Nothing,
+//! }
+//! ```
+//!
+//! In JSON, the variants of `Value` would be represented as:
+//!
+//! ```json
+//! {"x": 1, "y": 2}
+//! 42
+//! "hello"
+//! null
+//! ```
+//!
+//! Only the content of the variant is encoded. Unit variants are encoded as
+//! empty values, and newtype variants are best marked with
+//! `#[musli(transparent)]` so that they are encoded as the value they wrap.
+//!
+//! When decoding, the value is buffered through [`Decoder::decode_buffer`] and
+//! each variant is tried in the order in which they are declared. The first
+//! variant that decodes successfully is used, so variants which accept a
+//! subset of the values of another variant should be declared before it. If no
+//! variant matches, an error is raised through
+//! [`Context::no_matching_variant`].
+//!
+//! Like internal tagging, this is only supported by formats which are *self
+//! descriptive*.
+//!
 //! [`Binary`]: crate::mode::Binary
+//! [`Clone`]: core::clone::Clone
+//! [`Context::invalid_field_tag`]: crate::Context::invalid_field_tag
+//! [`Context::missing_adjacent_tag`]: crate::Context::missing_adjacent_tag
+//! [`Context::no_matching_variant`]: crate::Context::no_matching_variant
+//! [`Debug`]: core::fmt::Debug
+//! [`Default`]: core::default::Default
+//! [`Display`]: core::fmt::Display
+//! [`From`]: core::convert::From
+//! [`Into`]: core::convert::Into
+//! [`PartialEq`]: core::cmp::PartialEq
+//! [`Reflect`]: crate::reflect::Reflect
 //! [`Text`]: crate::mode::Text
 //! [`Decode`]: crate::Decode
 //! [`DecodeBytes`]: crate::de::DecodeBytes
+//! [`DecodeOwned`]: crate::de::DecodeOwned
 //! [`DecodePacked`]: crate::de::DecodePacked
 //! [`Decoder::decode_buffer`]: crate::Decoder::decode_buffer
 //! [`Decoder::decode_variant`]: crate::Decoder::decode_variant
@@ -1,6 +1,8 @@
 //! A variable-length 7-bit encoder where each bit indicates if there is a
 //! continuation of the sequence or not.
 
+use core::fmt;
+
 use crate::int;
 use crate::reader::Reader;
 use crate::writer::Writer;
@@ -10,13 +12,26 @@ const MASK_BYTE: u8 = 0b0111_1111;
 const CONT_BYTE: u8 = 0b1000_0000;
 
 /// Decode the given length using variable int encoding.
+#[inline]
+pub fn decode<'de, C, R, T>(cx: &C, r: R) -> Result<T, C::Error>
+where
+    C: ?Sized + Context,
+    R: Reader<'de>,
+    T: int::Unsigned,
+{
+    decode_bounded(cx, r, usize::MAX)
+}
+
+/// Decode the given length using variable int encoding, accepting at most
+/// `max` bytes in the continuation sequence.
 #[inline(never)]
-pub fn decode<'de, C, R, T>(cx: &C, mut r: R) -> Result<T, C::Error>
+pub fn decode_bounded<'de, C, R, T>(cx: &C, mut r: R, max: usize) -> Result<T, C::Error>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
     T: int::Unsigned,
 {
+    let start = cx.mark();
     let mut b = r.read_byte(cx)?;
 
     if b & CONT_BYTE == 0 {
@@ -25,12 +40,18 @@ where
 
     let mut value = T::from_byte(b & MASK_BYTE);
     let mut shift = 0u32;
+    let mut count = 1usize;
 
     while b & CONT_BYTE == CONT_BYTE {
         shift += 7;
+        count += 1;
+
+        if count > max {
+            return Err(cx.marked_custom(start, ContinuationOverflow { max }));
+        }
 
         if shift >= T::BITS {
-            return Err(cx.message("Bits overflow"));
+            return Err(cx.marked_message(start, "Bits overflow"));
         }
 
         b = r.read_byte(cx)?;
@@ -69,3 +90,22 @@ where
 
     Ok(())
 }
+
+/// Error raised when a continuation sequence is longer than permitted.
+#[derive(Debug)]
+pub(crate) struct ContinuationOverflow {
+    max: usize,
+}
+
+impl fmt::Display for ContinuationOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ContinuationOverflow { max } = self;
+        write!(
+            f,
+            "Continuation sequence exceeds the maximum of {max} bytes"
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ContinuationOverflow {}
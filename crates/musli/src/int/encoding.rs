@@ -132,8 +132,25 @@ where
     C: ?Sized + Context,
     R: Reader<'de>,
 {
+    decode_usize_bounded::<C, R, OPT>(cx, reader, usize::MAX)
+}
+
+/// Governs how usize lengths are decoded from a [`Reader`], accepting at most
+/// `max` continuation bytes if they are variably encoded.
+#[inline]
+pub(crate) fn decode_usize_bounded<'de, C, R, const OPT: Options>(
+    cx: &C,
+    reader: R,
+    max: usize,
+) -> Result<usize, C::Error>
+where
+    C: ?Sized + Context,
+    R: Reader<'de>,
+{
+    let start = cx.mark();
+
     match crate::options::length::<OPT>() {
-        crate::options::Integer::Variable => c::decode(cx, reader),
+        crate::options::Integer::Variable => c::decode_bounded(cx, reader, max),
         _ => {
             let bo = crate::options::byteorder::<OPT>();
 
@@ -142,7 +159,7 @@ where
                     let Ok(value) =
                         usize::try_from(<$ty as UnsignedOps>::read_bytes(cx, reader, bo)?)
                     else {
-                        return Err(cx.message("Value type out of bounds for usize"));
+                        return Err(cx.marked_message(start, "Value type out of bounds for usize"));
                     };
 
                     Ok(value)
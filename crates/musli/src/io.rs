@@ -0,0 +1,744 @@
+//! I/O support for binary formats.
+//!
+//! Müsli decoders operate over contiguous byte slices, so this module provides
+//! a thin driver layer on top of them. [`AsyncReader`] pulls bytes out of an
+//! [`AsyncRead`] source until exactly one value can be decoded, and retains
+//! anything which was read past it for the next call. Encoding writes the
+//! value to a buffer before it is asynchronously flushed to an
+//! [`AsyncWrite`]. [`IoReader`] does the same for a blocking [`io::Read`].
+//!
+//! The traits in this module are runtime-agnostic, and types implementing the
+//! tokio I/O traits can be adapted with `Tokio` when the `tokio` feature is
+//! enabled. Each binary encoding exposes `encode_async` and `decode_async` on
+//! its `Encoding` type:
+//!
+//! ```
+//! use musli::{Decode, Encode};
+//! use musli::io::{AsyncRead, AsyncReader, AsyncWrite};
+//! use musli::wire::{Encoding, Error};
+//!
+//! const ENCODING: Encoding = Encoding::new();
+//!
+//! #[derive(Decode, Encode)]
+//! struct Ping {
+//!     id: u32,
+//! }
+//!
+//! async fn pong<R, W>(reader: &mut AsyncReader<R>, writer: W) -> Result<(), Error>
+//! where
+//!     R: AsyncRead + Unpin,
+//!     W: AsyncWrite + Unpin,
+//! {
+//!     let ping: Ping = ENCODING.decode_async(reader).await?;
+//!     ENCODING.encode_async(writer, &Ping { id: ping.id + 1 }).await
+//! }
+//! ```
+
+#![cfg(all(feature = "std", feature = "alloc"))]
+#![cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "alloc"))))]
+
+use core::fmt;
+use core::future::poll_fn;
+use core::pin::Pin;
+#[cfg(feature = "tokio")]
+use core::task::ready;
+use core::task::{Context, Poll};
+
+use rust_alloc::vec::Vec;
+
+use std::io;
+
+use crate::context::ContextError;
+use crate::de::UnsizedVisitor;
+use crate::reader::{Reader, SliceUnderflow};
+
+/// The smallest number of bytes we try to read from the underlying reader at a
+/// time.
+const MIN_READ: usize = 4096;
+
+/// The default maximum number of bytes which are buffered for a single value.
+const DEFAULT_MAX_BUFFERED: usize = 8 * 1024 * 1024;
+
+/// A source of bytes which can be read asynchronously.
+///
+/// This mirrors the `AsyncRead` traits found in async runtimes, and can be
+/// implemented for them through a simple adapter.
+pub trait AsyncRead {
+    /// Attempt to read bytes into `buf`, returning the number of bytes read.
+    ///
+    /// Returning `Ok(0)` for a non-empty buffer indicates that the end of the
+    /// stream has been reached.
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>>;
+}
+
+/// A sink of bytes which can be written to asynchronously.
+pub trait AsyncWrite {
+    /// Attempt to write bytes from `buf`, returning the number of bytes
+    /// written.
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>>;
+
+    /// Attempt to flush any buffered data.
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+}
+
+impl<R> AsyncRead for &mut R
+where
+    R: ?Sized + AsyncRead + Unpin,
+{
+    #[inline]
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut **self).poll_read(cx, buf)
+    }
+}
+
+impl AsyncRead for &[u8] {
+    #[inline]
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let n = buf.len().min(self.len());
+        let (head, tail) = self.split_at(n);
+        buf[..n].copy_from_slice(head);
+        *self = tail;
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl<W> AsyncWrite for &mut W
+where
+    W: ?Sized + AsyncWrite + Unpin,
+{
+    #[inline]
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut **self).poll_write(cx, buf)
+    }
+
+    #[inline]
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut **self).poll_flush(cx)
+    }
+}
+
+impl AsyncWrite for Vec<u8> {
+    #[inline]
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Adapter which implements [`AsyncRead`] and [`AsyncWrite`] for types
+/// implementing the corresponding tokio traits.
+///
+/// # Examples
+///
+/// ```
+/// use musli::{Decode, Encode};
+/// use musli::io::{AsyncReader, Tokio};
+/// use musli::wire::{Encoding, Error};
+///
+/// const ENCODING: Encoding = Encoding::new();
+///
+/// #[derive(Decode, Encode)]
+/// struct Ping {
+///     id: u32,
+/// }
+///
+/// async fn pong<S>(stream: S) -> Result<(), Error>
+/// where
+///     S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+/// {
+///     let mut reader = AsyncReader::new(Tokio::new(stream));
+///
+///     loop {
+///         let ping: Ping = ENCODING.decode_async(&mut reader).await?;
+///         let writer = reader.get_mut();
+///         ENCODING.encode_async(writer, &Ping { id: ping.id + 1 }).await?;
+///     }
+/// }
+/// ```
+#[cfg(feature = "tokio")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "tokio")))]
+pub struct Tokio<T> {
+    inner: T,
+}
+
+#[cfg(feature = "tokio")]
+impl<T> Tokio<T> {
+    /// Wrap a tokio reader or writer.
+    #[inline]
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Access the wrapped value mutably.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Coerce into the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T> AsyncRead for Tokio<T>
+where
+    T: tokio::io::AsyncRead + Unpin,
+{
+    #[inline]
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut buf = tokio::io::ReadBuf::new(buf);
+        ready!(Pin::new(&mut self.inner).poll_read(cx, &mut buf))?;
+        Poll::Ready(Ok(buf.filled().len()))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T> AsyncWrite for Tokio<T>
+where
+    T: tokio::io::AsyncWrite + Unpin,
+{
+    #[inline]
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    #[inline]
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+}
+
+/// A buffered reader which decodes values out of an [`AsyncRead`].
+///
+/// Bytes are read incrementally until a complete value is available. Anything
+/// read past the end of the value is kept around for the next decode, so
+/// multiple values can be read back-to-back from the same stream.
+///
+/// When a value is incomplete, the reader keeps track of how many bytes it
+/// needs to get past the point where decoding stopped. Decoding is only
+/// retried once that many bytes have been buffered, along with anything else
+/// the underlying reader has immediately available. No more than
+/// [`max_buffered`] bytes are buffered for a single value, so a hostile length
+/// prefix results in an error rather than an unbounded allocation.
+///
+/// [`max_buffered`]: Self::max_buffered
+pub struct AsyncReader<R> {
+    reader: R,
+    buffer: Buffer,
+}
+
+impl<R> AsyncReader<R> {
+    /// Construct a new asynchronous reader.
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Buffer::new(),
+        }
+    }
+
+    /// Set the maximum number of bytes which are buffered for a single value.
+    ///
+    /// Values which need more bytes than this to decode are rejected with an
+    /// error, without reading them. Defaults to 8 MiB.
+    #[inline]
+    pub fn with_max_buffered(mut self, max_buffered: usize) -> Self {
+        self.buffer.max = max_buffered;
+        self
+    }
+
+    /// Get the maximum number of bytes which are buffered for a single value.
+    #[inline]
+    pub fn max_buffered(&self) -> usize {
+        self.buffer.max
+    }
+
+    /// Access bytes which have been read but not yet decoded.
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        self.buffer.as_slice()
+    }
+
+    /// Access the underlying reader mutably.
+    ///
+    /// Reading from it directly skips over any bytes which are buffered.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Coerce into the underlying reader, discarding any buffered bytes.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R> AsyncReader<R>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Decode a single value using `decode`.
+    ///
+    /// The callback is retried with more bytes as long as it fails because it
+    /// ran out of input, until the underlying reader reaches its end.
+    pub(crate) async fn decode<T, E, F>(&mut self, mut decode: F) -> Result<T, E>
+    where
+        E: ContextError,
+        F: for<'de> FnMut(&mut Partial<'de>) -> Result<T, E>,
+    {
+        loop {
+            if let Some(result) = self.buffer.decode(&mut decode) {
+                return result;
+            }
+
+            self.buffer.check().map_err(E::custom)?;
+
+            while !self.buffer.is_ready() {
+                let reader = &mut self.reader;
+                let buf = self.buffer.spare();
+                let n = poll_fn(|cx| Pin::new(&mut *reader).poll_read(cx, &mut *buf))
+                    .await
+                    .map_err(E::custom)?;
+                self.buffer.advance(n);
+            }
+
+            // Buffer everything which is immediately available so that
+            // decoding isn't retried for every small read.
+            while !self.buffer.eof && !self.buffer.is_full() {
+                let reader = &mut self.reader;
+                let buf = self.buffer.spare();
+                let poll =
+                    poll_fn(|cx| Poll::Ready(Pin::new(&mut *reader).poll_read(cx, &mut *buf)));
+
+                let Poll::Ready(n) = poll.await else {
+                    break;
+                };
+
+                self.buffer.advance(n.map_err(E::custom)?);
+            }
+        }
+    }
+}
+
+/// A buffered reader which decodes values out of a blocking [`io::Read`].
+///
+/// This is the blocking counterpart to [`AsyncReader`], which allows values to
+/// be decoded from sockets and files without reading all of their contents
+/// into memory first. Anything read past the end of a value is kept around for
+/// the next decode. Like [`AsyncReader`], decoding is only retried once enough
+/// bytes have been read to get past where it last stopped, and no more than
+/// [`max_buffered`] bytes are buffered for a single value.
+///
+/// [`max_buffered`]: Self::max_buffered
+///
+/// # Examples
+///
+/// ```
+/// use musli::{Decode, Encode};
+/// use musli::io::IoReader;
+///
+/// #[derive(Debug, PartialEq, Decode, Encode)]
+/// struct Ping {
+///     id: u32,
+/// }
+///
+/// let mut data = Vec::new();
+/// musli::wire::to_writer(&mut data, &Ping { id: 1 })?;
+/// musli::wire::to_writer(&mut data, &Ping { id: 2 })?;
+///
+/// let mut reader = IoReader::new(&data[..]);
+///
+/// let first: Ping = musli::wire::DEFAULT.decode_reader(&mut reader)?;
+/// let second: Ping = musli::wire::DEFAULT.decode_reader(&mut reader)?;
+///
+/// assert_eq!(first, Ping { id: 1 });
+/// assert_eq!(second, Ping { id: 2 });
+/// # Ok::<_, musli::wire::Error>(())
+/// ```
+pub struct IoReader<R> {
+    reader: R,
+    buffer: Buffer,
+}
+
+impl<R> IoReader<R> {
+    /// Construct a new buffered reader.
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Buffer::new(),
+        }
+    }
+
+    /// Set the maximum number of bytes which are buffered for a single value.
+    ///
+    /// Values which need more bytes than this to decode are rejected with an
+    /// error, without reading them. Defaults to 8 MiB.
+    #[inline]
+    pub fn with_max_buffered(mut self, max_buffered: usize) -> Self {
+        self.buffer.max = max_buffered;
+        self
+    }
+
+    /// Get the maximum number of bytes which are buffered for a single value.
+    #[inline]
+    pub fn max_buffered(&self) -> usize {
+        self.buffer.max
+    }
+
+    /// Access bytes which have been read but not yet decoded.
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        self.buffer.as_slice()
+    }
+
+    /// Access the underlying reader mutably.
+    ///
+    /// Reading from it directly skips over any bytes which are buffered.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Coerce into the underlying reader, discarding any buffered bytes.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R> IoReader<R>
+where
+    R: io::Read,
+{
+    /// Decode a single value using `decode`.
+    ///
+    /// The callback is retried with more bytes as long as it fails because it
+    /// ran out of input, until the underlying reader reaches its end.
+    pub(crate) fn decode<T, E, F>(&mut self, mut decode: F) -> Result<T, E>
+    where
+        E: ContextError,
+        F: for<'de> FnMut(&mut Partial<'de>) -> Result<T, E>,
+    {
+        loop {
+            if let Some(result) = self.buffer.decode(&mut decode) {
+                return result;
+            }
+
+            self.buffer.check().map_err(E::custom)?;
+
+            while !self.buffer.is_ready() {
+                self.fill().map_err(E::custom)?;
+            }
+        }
+    }
+
+    /// Test if the underlying reader has reached its end and every byte read
+    /// from it has been decoded.
+    pub(crate) fn at_end(&mut self) -> io::Result<bool> {
+        while self.buffer.as_slice().is_empty() {
+            if self.buffer.eof {
+                return Ok(true);
+            }
+
+            self.fill()?;
+        }
+
+        Ok(false)
+    }
+
+    /// Read more bytes into the buffer.
+    fn fill(&mut self) -> io::Result<()> {
+        let buf = self.buffer.spare();
+
+        let n = loop {
+            match self.reader.read(buf) {
+                Ok(n) => break n,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        };
+
+        self.buffer.advance(n);
+        Ok(())
+    }
+}
+
+/// The buffer shared by [`AsyncReader`] and [`IoReader`].
+struct Buffer {
+    buf: Vec<u8>,
+    start: usize,
+    end: usize,
+    eof: bool,
+    /// The number of buffered bytes needed before decoding is retried.
+    needed: usize,
+    /// The maximum number of bytes to buffer for a single value.
+    max: usize,
+}
+
+impl Buffer {
+    #[inline]
+    fn new() -> Self {
+        Self {
+            buf: Vec::new(),
+            start: 0,
+            end: 0,
+            eof: false,
+            needed: 0,
+            max: DEFAULT_MAX_BUFFERED,
+        }
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[u8] {
+        &self.buf[self.start..self.end]
+    }
+
+    /// Try to decode a value out of the buffered bytes, returning `None` if
+    /// more bytes need to be read first.
+    fn decode<T, E, F>(&mut self, decode: &mut F) -> Option<Result<T, E>>
+    where
+        F: for<'de> FnMut(&mut Partial<'de>) -> Result<T, E>,
+    {
+        let mut partial = Partial::new(&self.buf[self.start..self.end]);
+        let result = decode(&mut partial);
+        self.needed = 0;
+
+        match result {
+            Ok(value) => {
+                self.start = self.end - partial.remaining.len();
+                Some(Ok(value))
+            }
+            Err(error) => match partial.needed {
+                Some(needed) if !self.eof => {
+                    self.needed = needed;
+                    None
+                }
+                _ => Some(Err(error)),
+            },
+        }
+    }
+
+    /// Test if enough bytes are buffered to retry decoding.
+    #[inline]
+    fn is_ready(&self) -> bool {
+        self.eof || self.end - self.start >= self.needed
+    }
+
+    /// Test if the maximum number of bytes are buffered.
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.end - self.start >= self.max
+    }
+
+    /// Check that the bytes needed to retry decoding fit in the buffer.
+    #[inline]
+    fn check(&self) -> Result<(), BufferLimit> {
+        if self.needed > self.max {
+            return Err(BufferLimit {
+                needed: self.needed,
+                max: self.max,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Get the spare capacity to read into, growing the buffer if necessary.
+    fn spare(&mut self) -> &mut [u8] {
+        if self.start > 0 {
+            self.buf.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+        }
+
+        let len = (self.end + self.end.max(MIN_READ))
+            .max(self.needed)
+            .min(self.max.max(self.end + 1));
+
+        if self.buf.len() < len {
+            self.buf.resize(len, 0);
+        }
+
+        &mut self.buf[self.end..]
+    }
+
+    /// Mark `n` bytes of the spare capacity as read.
+    #[inline]
+    fn advance(&mut self, n: usize) {
+        if n == 0 {
+            self.eof = true;
+        }
+
+        self.end += n;
+    }
+}
+
+/// Error raised when a value needs more bytes than are allowed to be buffered.
+#[derive(Debug)]
+struct BufferLimit {
+    needed: usize,
+    max: usize,
+}
+
+impl fmt::Display for BufferLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let BufferLimit { needed, max } = self;
+
+        write!(
+            f,
+            "Value needs at least {needed} bytes, which is more than the maximum of {max} bytes which may be buffered"
+        )
+    }
+}
+
+impl std::error::Error for BufferLimit {}
+
+/// Write all of `bytes` to the given writer and flush it.
+pub(crate) async fn write_all<W>(mut writer: W, mut bytes: &[u8]) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    while !bytes.is_empty() {
+        let n = poll_fn(|cx| Pin::new(&mut writer).poll_write(cx, bytes)).await?;
+
+        if n == 0 {
+            return Err(io::Error::from(io::ErrorKind::WriteZero));
+        }
+
+        bytes = &bytes[n..];
+    }
+
+    poll_fn(|cx| Pin::new(&mut writer).poll_flush(cx)).await
+}
+
+/// A [`Reader`] over a possibly incomplete slice, which records how many bytes
+/// it would have needed if decoding failed because it ran out of input.
+pub(crate) struct Partial<'de> {
+    len: usize,
+    remaining: &'de [u8],
+    needed: Option<usize>,
+}
+
+impl<'de> Partial<'de> {
+    #[inline]
+    fn new(remaining: &'de [u8]) -> Self {
+        Self {
+            len: remaining.len(),
+            remaining,
+            needed: None,
+        }
+    }
+
+    /// Record that `n` more bytes were needed than are remaining.
+    #[inline]
+    fn underflow(&mut self, n: usize) {
+        let needed = (self.len - self.remaining.len()).saturating_add(n);
+        self.needed = Some(self.needed.map_or(needed, |m| m.max(needed)));
+    }
+
+    #[inline]
+    fn split<C>(&mut self, cx: &C, n: usize) -> Result<&'de [u8], C::Error>
+    where
+        C: ?Sized + crate::Context,
+    {
+        if self.remaining.len() < n {
+            self.underflow(n);
+            return Err(cx.custom(SliceUnderflow::new(n, self.remaining.len())));
+        }
+
+        let (head, tail) = self.remaining.split_at(n);
+        self.remaining = tail;
+        cx.advance(n);
+        Ok(head)
+    }
+}
+
+impl<'de> Reader<'de> for Partial<'de> {
+    type Mut<'this> = &'this mut Self where Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn skip<C>(&mut self, cx: &C, n: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + crate::Context,
+    {
+        self.split(cx, n)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn peek(&mut self) -> Option<u8> {
+        let Some(&b) = self.remaining.first() else {
+            self.underflow(1);
+            return None;
+        };
+
+        Some(b)
+    }
+
+    #[inline]
+    fn read_bytes<C, V>(&mut self, cx: &C, n: usize, visitor: V) -> Result<V::Ok, C::Error>
+    where
+        C: ?Sized + crate::Context,
+        V: UnsizedVisitor<'de, C, [u8]>,
+    {
+        let head = self.split(cx, n)?;
+        visitor.visit_borrowed(cx, head)
+    }
+
+    #[inline]
+    fn read<C>(&mut self, cx: &C, buf: &mut [u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + crate::Context,
+    {
+        let head = self.split(cx, buf.len())?;
+        buf.copy_from_slice(head);
+        Ok(())
+    }
+}
@@ -23,8 +23,10 @@ use core::fmt;
 use core::str;
 
 use crate::alloc::Vec;
+use crate::context::nested;
 use crate::de::{
-    Decode, DecodeUnsized, Decoder, SequenceDecoder, SizeHint, Skip, UnsizedVisitor, Visitor,
+    Decode, DecodeUnsized, Decoder, SequenceDecoder, SizeHint, Skip, TypeHint, UnsizedVisitor,
+    Visitor,
 };
 use crate::hint::{MapHint, SequenceHint};
 #[cfg(feature = "value")]
@@ -114,12 +116,12 @@ where
     type WithContext<'this, U> = JsonDecoder<'this, P, U> where U: 'this + Context;
     #[cfg(feature = "value")]
     type DecodeBuffer = crate::value::AsValueDecoder<'a, BUFFER_OPTIONS, C>;
-    type DecodePack = JsonSequenceDecoder<'a, P, C>;
-    type DecodeSequence = JsonSequenceDecoder<'a, P, C>;
-    type DecodeMap = JsonObjectDecoder<'a, P, C>;
-    type DecodeMapEntries = JsonObjectDecoder<'a, P, C>;
+    type DecodePack<'this> = JsonSequenceDecoder<'a, P, C>;
+    type DecodeSequence<'this> = JsonSequenceDecoder<'a, P, C>;
+    type DecodeMap<'this> = JsonObjectDecoder<'a, P, C>;
+    type DecodeMapEntries<'this> = JsonObjectDecoder<'a, P, C>;
     type DecodeSome = JsonDecoder<'a, P, C>;
-    type DecodeVariant = JsonVariantDecoder<'a, P, C>;
+    type DecodeVariant<'this> = JsonVariantDecoder<'a, P, C>;
 
     #[inline]
     fn cx(&self) -> &Self::Cx {
@@ -167,6 +169,21 @@ where
         Ok(Skip::Skipped)
     }
 
+    #[inline]
+    fn type_hint(&mut self) -> Result<TypeHint, C::Error> {
+        let hint = match self.parser.lex(self.cx) {
+            Token::OpenBrace => TypeHint::Map(SizeHint::any()),
+            Token::OpenBracket => TypeHint::Sequence(SizeHint::any()),
+            Token::String => TypeHint::String(SizeHint::any()),
+            Token::Number => TypeHint::Number,
+            Token::Null => TypeHint::Empty,
+            Token::True | Token::False => TypeHint::Bool,
+            _ => TypeHint::Unknown,
+        };
+
+        Ok(hint)
+    }
+
     #[cfg(feature = "value")]
     #[inline]
     fn decode_buffer(self) -> Result<Self::DecodeBuffer, C::Error> {
@@ -292,31 +309,41 @@ where
 
     #[inline]
     fn decode_array<const N: usize>(self) -> Result<[u8; N], C::Error> {
+        let mut bytes = [0; N];
+        self.decode_array_slice(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn decode_array_slice(self, out: &mut [u8]) -> Result<(), C::Error> {
         let cx = self.cx;
         let mark = cx.mark();
 
         self.decode_sequence(|seq| {
-            let mut bytes = [0; N];
             let mut index = 0;
 
             while let Some(item) = seq.try_decode_next()? {
-                if index <= N {
-                    bytes[index] = item.decode_u8()?;
+                let byte = item.decode_u8()?;
+
+                if let Some(slot) = out.get_mut(index) {
+                    *slot = byte;
                 }
 
                 index += 1;
             }
 
-            if index != N {
+            if index != out.len() {
+                let expected = out.len();
+
                 return Err(cx.marked_message(
                     mark,
                     format_args!(
-                        "Array with length {index} does not have the expected {N} number of elements"
+                        "Array with length {index} does not have the expected {expected} number of elements"
                     ),
                 ));
             }
 
-            Ok(bytes)
+            Ok(())
         })
     }
 
@@ -365,10 +392,10 @@ where
     #[inline]
     fn decode_pack<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodePack) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodePack<'_>) -> Result<O, C::Error>,
     {
         let mut decoder = JsonSequenceDecoder::new(self.cx, None, self.parser)?;
-        let output = f(&mut decoder)?;
+        let output = nested(self.cx, || f(&mut decoder))?;
         decoder.skip_sequence_remaining()?;
         Ok(output)
     }
@@ -376,10 +403,10 @@ where
     #[inline]
     fn decode_sequence<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeSequence) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeSequence<'_>) -> Result<O, C::Error>,
     {
         let mut decoder = JsonSequenceDecoder::new(self.cx, None, self.parser)?;
-        let output = f(&mut decoder)?;
+        let output = nested(self.cx, || f(&mut decoder))?;
         decoder.skip_sequence_remaining()?;
         Ok(output)
     }
@@ -387,10 +414,10 @@ where
     #[inline]
     fn decode_sequence_hint<F, O>(self, hint: &SequenceHint, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeSequence) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeSequence<'_>) -> Result<O, C::Error>,
     {
         let mut decoder = JsonSequenceDecoder::new(self.cx, Some(hint.size), self.parser)?;
-        let output = f(&mut decoder)?;
+        let output = nested(self.cx, || f(&mut decoder))?;
         decoder.skip_sequence_remaining()?;
         Ok(output)
     }
@@ -398,10 +425,10 @@ where
     #[inline]
     fn decode_map<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeMap) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeMap<'_>) -> Result<O, C::Error>,
     {
         let mut decoder = JsonObjectDecoder::new(self.cx, None, self.parser)?;
-        let output = f(&mut decoder)?;
+        let output = nested(self.cx, || f(&mut decoder))?;
         decoder.skip_object_remaining()?;
         Ok(output)
     }
@@ -409,10 +436,10 @@ where
     #[inline]
     fn decode_map_hint<F, O>(self, hint: &MapHint, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeMap) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeMap<'_>) -> Result<O, C::Error>,
     {
         let mut decoder = JsonObjectDecoder::new(self.cx, Some(hint.size), self.parser)?;
-        let output = f(&mut decoder)?;
+        let output = nested(self.cx, || f(&mut decoder))?;
         decoder.skip_object_remaining()?;
         Ok(output)
     }
@@ -420,7 +447,7 @@ where
     #[inline]
     fn decode_map_entries<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeMapEntries) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeMapEntries<'_>) -> Result<O, C::Error>,
     {
         self.decode_map(f)
     }
@@ -428,10 +455,10 @@ where
     #[inline]
     fn decode_variant<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeVariant) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeVariant<'_>) -> Result<O, C::Error>,
     {
         let mut decoder = JsonVariantDecoder::new(self.cx, self.parser)?;
-        let output = f(&mut decoder)?;
+        let output = nested(self.cx, || f(&mut decoder))?;
         decoder.end()?;
         Ok(output)
     }
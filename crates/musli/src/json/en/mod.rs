@@ -202,6 +202,11 @@ where
         self.encode_bytes(bytes)
     }
 
+    #[inline]
+    fn encode_array_slice(self, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+        self.encode_bytes(bytes)
+    }
+
     #[inline]
     fn encode_bytes(mut self, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
         let mut buf = itoa::Buffer::new();
@@ -275,6 +280,11 @@ where
         JsonArrayEncoder::new(self.cx, self.writer)
     }
 
+    #[inline]
+    fn encode_unsized_sequence(self) -> Result<Self::EncodeSequence, C::Error> {
+        JsonArrayEncoder::new(self.cx, self.writer)
+    }
+
     #[inline]
     fn encode_map(self, _: &MapHint) -> Result<Self::EncodeMap, C::Error> {
         JsonObjectEncoder::new(self.cx, self.writer)
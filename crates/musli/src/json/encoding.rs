@@ -267,6 +267,14 @@ where
     }
 }
 
+impl<A, B> crate::DynMode<Encoding<A>, Encoding<B>>
+where
+    A: 'static,
+    B: 'static,
+{
+    crate::macros::dyn_mode_impls!(A, B, IntoParser);
+}
+
 impl<M> Clone for Encoding<M> {
     #[inline]
     fn clone(&self) -> Self {
@@ -275,3 +283,17 @@ impl<M> Clone for Encoding<M> {
 }
 
 impl<M> Copy for Encoding<M> {}
+
+impl<M> crate::transcode::Source for Encoding<M>
+where
+    M: 'static,
+{
+    #[inline]
+    fn transcode<E>(self, cx: &E::Cx, bytes: &[u8], encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: crate::Encoder,
+    {
+        let decoder = JsonDecoder::new(cx, IntoParser::into_parser(bytes));
+        crate::transcode::transcode(decoder, encoder)
+    }
+}
@@ -59,11 +59,14 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 #[doc(inline)]
 pub use self::encoding::to_writer;
 #[doc(inline)]
-pub use self::encoding::{decode, encode, from_slice, from_str, to_fixed_bytes, Encoding, DEFAULT};
+pub use self::encoding::{
+    decode, decode_into, encode, encoded_len, from_slice, from_str, to_fixed_bytes, Encoding,
+    DEFAULT,
+};
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 #[doc(inline)]
-pub use self::encoding::{to_string, to_vec};
+pub use self::encoding::{to_string, to_vec, to_vec_in};
 #[doc(inline)]
 pub use self::error::Error;
 pub use self::parser::Parser;
@@ -261,6 +261,86 @@ where
     Ok(())
 }
 
+/// The number of decimal digits which are always guaranteed to fit in a
+/// 128-bit integer.
+const MAX_DIGITS: usize = 38;
+
+/// Scan a well-formed JSON number at the start of `bytes` without consuming
+/// it, returning its textual representation if it can't be parsed into one of
+/// the native numeric types without overflow.
+///
+/// Numbers which are not well-formed are ignored, so that they produce the
+/// appropriate diagnostics when parsed.
+pub(crate) fn scan_big_number(bytes: &[u8]) -> Option<&str> {
+    let mut n = 0;
+
+    let is_negative = bytes.first() == Some(&b'-');
+
+    if is_negative {
+        n += 1;
+    }
+
+    let start = n;
+    let digits = count_while(bytes, &mut n, is_digit);
+
+    if digits == 0 || (digits > 1 && bytes[start] == b'0') {
+        return None;
+    }
+
+    let integer = &bytes[start..n];
+    let mut plain = true;
+    let mut fraction = 0;
+
+    if bytes.get(n) == Some(&b'.') {
+        n += 1;
+        fraction = count_while(bytes, &mut n, is_digit);
+        plain = false;
+    }
+
+    if matches!(bytes.get(n), Some(b'e' | b'E')) {
+        n += 1;
+
+        if matches!(bytes.get(n), Some(b'-' | b'+')) {
+            n += 1;
+        }
+
+        count_while(bytes, &mut n, is_digit);
+        plain = false;
+    }
+
+    let text = core::str::from_utf8(&bytes[..n]).ok()?;
+
+    let is_big = if digits <= MAX_DIGITS {
+        fraction > MAX_DIGITS
+    } else if digits == MAX_DIGITS + 1 {
+        let integer = core::str::from_utf8(integer).ok()?;
+
+        fraction > MAX_DIGITS
+            || integer.parse::<u128>().is_err()
+            || (plain && is_negative && text.parse::<i128>().is_err())
+    } else {
+        true
+    };
+
+    is_big.then_some(text)
+}
+
+/// Count the number of bytes at `n` matching `m`, advancing past them.
+#[inline]
+fn count_while(bytes: &[u8], n: &mut usize, m: fn(u8) -> bool) -> usize {
+    let start = *n;
+
+    while let Some(&b) = bytes.get(*n) {
+        if !m(b) {
+            break;
+        }
+
+        *n += 1;
+    }
+
+    *n - start
+}
+
 /// Partially parse an unsigned value.
 #[cfg_attr(feature = "parse-full", allow(unused))]
 #[inline(never)]
@@ -131,6 +131,11 @@ impl<'de> Parser<'de> for MutSliceParser<'_, 'de> {
         self.slice.first().copied()
     }
 
+    #[inline]
+    fn remaining(&self) -> &'de [u8] {
+        *self.slice
+    }
+
     fn parse_f32<C>(&mut self, cx: &C) -> Result<f32, C::Error>
     where
         C: ?Sized + Context,
@@ -1,6 +1,6 @@
 use crate::alloc::{Allocator, Vec};
 use crate::de::Visitor;
-use crate::json::parser::integer::decode_signed_full;
+use crate::json::parser::integer::{decode_signed_full, scan_big_number};
 use crate::json::parser::{StringReference, Token};
 use crate::Context;
 
@@ -96,6 +96,10 @@ pub trait Parser<'de>: private::Sealed {
     #[doc(hidden)]
     fn peek(&mut self) -> Option<u8>;
 
+    /// Access the input which has not yet been consumed.
+    #[doc(hidden)]
+    fn remaining(&self) -> &'de [u8];
+
     #[doc(hidden)]
     fn lex<C>(&mut self, cx: &C) -> Token
     where
@@ -149,6 +153,13 @@ pub trait Parser<'de>: private::Sealed {
         C: ?Sized + Context,
         V: Visitor<'de, C>,
     {
+        self.skip_whitespace(cx);
+
+        if let Some(number) = scan_big_number(self.remaining()) {
+            self.skip(cx, number.len())?;
+            return visitor.visit_big_number(cx, number);
+        }
+
         let signed = decode_signed_full::<i128, _, _>(cx, self)?;
 
         if signed.is_negative {
@@ -260,6 +271,11 @@ where
         (**self).peek()
     }
 
+    #[inline(always)]
+    fn remaining(&self) -> &'de [u8] {
+        (**self).remaining()
+    }
+
     #[inline(always)]
     fn lex<C>(&mut self, cx: &C) -> Token
     where
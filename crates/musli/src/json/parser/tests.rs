@@ -4,7 +4,7 @@ use rust_alloc::format;
 
 use crate::context;
 use crate::json::error::Error;
-use crate::json::parser::integer::{parse_signed_full, parse_unsigned_full};
+use crate::json::parser::integer::{parse_signed_full, parse_unsigned_full, scan_big_number};
 use crate::json::parser::SliceParser;
 use crate::mode::Binary;
 
@@ -181,3 +181,32 @@ fn test_decode_signed() {
         test!(isize);
     })
 }
+
+#[test]
+fn test_scan_big_number() {
+    let u128_max = format!("{}", u128::MAX);
+    let i128_min = format!("{}", i128::MIN);
+
+    assert_eq!(scan_big_number(b"0"), None);
+    assert_eq!(scan_big_number(b"-1.5e10"), None);
+    assert_eq!(scan_big_number(u128_max.as_bytes()), None);
+    assert_eq!(scan_big_number(i128_min.as_bytes()), None);
+    assert_eq!(
+        scan_big_number(b"00000000000000000000000000000000000000000"),
+        None
+    );
+    assert_eq!(scan_big_number(b"abc"), None);
+
+    assert_eq!(
+        scan_big_number(b"340282366920938463463374607431768211456,"),
+        Some("340282366920938463463374607431768211456")
+    );
+    assert_eq!(
+        scan_big_number(b"-170141183460469231731687303715884105729]"),
+        Some("-170141183460469231731687303715884105729")
+    );
+    assert_eq!(
+        scan_big_number(b"1.000000000000000000000000000000000000001e5}"),
+        Some("1.000000000000000000000000000000000000001e5")
+    );
+}
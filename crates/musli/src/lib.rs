@@ -38,7 +38,7 @@
 //!
 //! ```toml
 //! [dependencies]
-//! musli = { version = "0.0.123", features = ["storage"] }
+//! musli = { version = "0.0.124", features = ["storage"] }
 //! ```
 //!
 //! <br>
@@ -420,6 +420,8 @@ pub mod en;
 pub use musli_core::hint;
 #[doc(inline)]
 pub use musli_core::mode;
+#[doc(inline)]
+pub use musli_core::reflect;
 
 /// This is an attribute macro that must be used when implementing a
 /// [`Encoder`].
@@ -572,14 +574,26 @@ pub mod context;
 
 pub mod compat;
 
+pub mod schema;
+
+pub mod transcode;
+
+pub mod erased;
+
 pub mod fixed;
 #[doc(inline)]
 pub use self::fixed::FixedBytes;
 
+pub mod dyn_mode;
+#[doc(inline)]
+pub use self::dyn_mode::DynMode;
+
 pub mod options;
 #[doc(inline)]
 pub use self::options::Options;
 
+pub mod io;
+
 pub mod reader;
 #[doc(inline)]
 pub use self::reader::{IntoReader, Reader};
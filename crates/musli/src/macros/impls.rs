@@ -0,0 +1,182 @@
+//! Helper macros for writing manual [`Encode`] implementations.
+//!
+//! [`Encode`]: crate::Encode
+
+/// Encode a struct as a map of name-value pairs.
+///
+/// This expands to the same pair encoding that `#[derive(Encode)]` produces
+/// for a struct, which is useful when writing a manual implementation for a
+/// type whose fields are too dynamic for the derive to handle.
+///
+/// Each name and value is passed to [`MapEncoder::insert_entry`] and must
+/// therefore implement [`Encode`] in the mode being encoded.
+///
+/// # Examples
+///
+/// ```
+/// use musli::{Encode, Encoder};
+///
+/// struct Person {
+///     first: String,
+///     last: String,
+///     age: u32,
+/// }
+///
+/// impl<M> Encode<M> for Person {
+///     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+///     where
+///         E: Encoder<Mode = M>,
+///     {
+///         let name = format!("{} {}", self.first, self.last);
+///
+///         musli::macros::encode_struct!(encoder, {
+///             "name" => &name,
+///             "age" => self.age,
+///         })
+///     }
+/// }
+///
+/// let person = Person {
+///     first: String::from("Aristotle"),
+///     last: String::from("of Stagira"),
+///     age: 61,
+/// };
+///
+/// let json = musli::json::to_string(&person)?;
+/// assert_eq!(json, r#"{"name":"Aristotle of Stagira","age":61}"#);
+/// # Ok::<_, musli::json::Error>(())
+/// ```
+///
+/// [`Encode`]: crate::Encode
+/// [`MapEncoder::insert_entry`]: crate::en::MapEncoder::insert_entry
+#[macro_export]
+macro_rules! encode_struct {
+    ($encoder:expr, { $($name:expr => $value:expr),* $(,)? }) => {{
+        let hint = $crate::hint::MapHint::with_size($crate::macros::__count!($($name)*));
+
+        $crate::en::Encoder::encode_map_fn($encoder, &hint, |map| {
+            $($crate::en::MapEncoder::insert_entry(map, $name, $value)?;)*
+            ::core::result::Result::Ok(())
+        })
+    }};
+}
+
+pub use encode_struct;
+
+/// Encode a struct variant with the given tag as a map of name-value pairs.
+///
+/// This expands to the same variant encoding that `#[derive(Encode)]`
+/// produces for a struct variant in the default tagging mode. Unit and tuple
+/// variants are covered by [`Encoder::encode_unit_variant`] and
+/// [`Encoder::encode_sequence_variant`].
+///
+/// # Examples
+///
+/// ```
+/// use musli::{Encode, Encoder};
+///
+/// enum Shape {
+///     Circle { radius: u32 },
+///     Square { side: u32 },
+/// }
+///
+/// impl<M> Encode<M> for Shape {
+///     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+///     where
+///         E: Encoder<Mode = M>,
+///     {
+///         match self {
+///             Shape::Circle { radius } => musli::macros::encode_variant!(encoder, "Circle", {
+///                 "radius" => radius,
+///             }),
+///             Shape::Square { side } => musli::macros::encode_variant!(encoder, "Square", {
+///                 "side" => side,
+///                 "area" => side * side,
+///             }),
+///         }
+///     }
+/// }
+///
+/// let json = musli::json::to_string(&Shape::Square { side: 2 })?;
+/// assert_eq!(json, r#"{"Square":{"side":2,"area":4}}"#);
+/// # Ok::<_, musli::json::Error>(())
+/// ```
+///
+/// [`Encoder::encode_unit_variant`]: crate::Encoder::encode_unit_variant
+/// [`Encoder::encode_sequence_variant`]: crate::Encoder::encode_sequence_variant
+#[macro_export]
+macro_rules! encode_variant {
+    ($encoder:expr, $tag:expr, { $($name:expr => $value:expr),* $(,)? }) => {{
+        $crate::en::Encoder::encode_variant_fn($encoder, |variant| {
+            $crate::en::Encoder::encode($crate::en::VariantEncoder::encode_tag(variant)?, $tag)?;
+
+            $crate::macros::encode_struct!($crate::en::VariantEncoder::encode_data(variant)?, {
+                $($name => $value),*
+            })?;
+
+            ::core::result::Result::Ok(())
+        })
+    }};
+}
+
+pub use encode_variant;
+
+/// Implement [`Encode`] for a struct-like type in every mode by encoding the
+/// given name-value pairs.
+///
+/// The identifier in `|this|` is bound to `&self` and can be used to access
+/// the value being encoded. See [`encode_struct!`] for how the pairs are
+/// encoded.
+///
+/// # Examples
+///
+/// ```
+/// use musli::macros::impl_struct_encode;
+///
+/// struct Span {
+///     start: u32,
+///     end: u32,
+/// }
+///
+/// impl_struct_encode!(Span => |this| {
+///     "start" => this.start,
+///     "len" => this.end - this.start,
+/// });
+///
+/// let json = musli::json::to_string(&Span { start: 4, end: 10 })?;
+/// assert_eq!(json, r#"{"start":4,"len":6}"#);
+/// # Ok::<_, musli::json::Error>(())
+/// ```
+///
+/// [`Encode`]: crate::Encode
+/// [`encode_struct!`]: crate::macros::encode_struct
+#[macro_export]
+macro_rules! impl_struct_encode {
+    ($ty:ty => |$this:ident| { $($name:expr => $value:expr),* $(,)? }) => {
+        impl<M> $crate::en::Encode<M> for $ty {
+            #[inline]
+            fn encode<E>(&self, _: &E::Cx, encoder: E) -> ::core::result::Result<E::Ok, E::Error>
+            where
+                E: $crate::en::Encoder<Mode = M>,
+            {
+                let $this = self;
+
+                $crate::macros::encode_struct!(encoder, {
+                    $($name => $value),*
+                })
+            }
+        }
+    };
+}
+
+pub use impl_struct_encode;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __count {
+    () => { 0usize };
+    ($head:tt $($tail:tt)*) => { 1usize + $crate::macros::__count!($($tail)*) };
+}
+
+#[doc(hidden)]
+pub use __count;
@@ -79,6 +79,55 @@ macro_rules! bare_encoding {
             $default.to_vec(value)
         }
 
+        /// Encode the given value into the given [`Vec`] using the [`DEFAULT`]
+        /// [`Encoding`].
+        ///
+        /// The vector is cleared before encoding, and its capacity is reused
+        /// so that encoding many values in a row doesn't allocate once the
+        /// vector is large enough.
+        ///
+        /// [`Vec`]: rust_alloc::vec::Vec
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        #[doc = concat!("use musli::", stringify!($what), ";")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// let mut data = Vec::new();
+        ///
+        /// for age in [61, 62] {
+        #[doc = concat!("    ", stringify!($what), "::to_vec_in(&mut data, &Person {")]
+        ///         name: "Aristotle".to_string(),
+        ///         age,
+        ///     })?;
+        ///
+        #[doc = concat!("    let person: Person = ", stringify!($what), "::from_slice(&data[..])?;")]
+        ///     assert_eq!(person.name, "Aristotle");
+        ///     assert_eq!(person.age, age);
+        /// }
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[cfg(feature = "alloc")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+        #[inline]
+        pub fn to_vec_in<T>(
+            vec: &mut rust_alloc::vec::Vec<u8>,
+            value: &T,
+        ) -> Result<(), Error>
+        where
+            T: ?Sized + $crate::Encode<crate::mode::$mode>,
+        {
+            $default.to_vec_in(vec, value)
+        }
+
         /// Encode the given value to a fixed-size bytes using the [`DEFAULT`]
         /// [`Encoding`].
         ///
@@ -111,6 +160,40 @@ macro_rules! bare_encoding {
             $default.to_fixed_bytes::<N, _>(value)
         }
 
+        /// Calculate the exact number of bytes the given value encodes to using
+        /// the [`DEFAULT`] [`Encoding`], without writing anything.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        #[doc = concat!("use musli::", stringify!($what), ";")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// let person = Person {
+        ///     name: "Aristotle".to_string(),
+        ///     age: 61,
+        /// };
+        ///
+        #[doc = concat!("let len = ", stringify!($what), "::encoded_len(&person)?;")]
+        #[doc = concat!("let data = ", stringify!($what), "::to_vec(&person)?;")]
+        /// assert_eq!(len, data.len());
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[inline]
+        pub fn encoded_len<T>(value: &T) -> Result<usize, Error>
+        where
+            T: ?Sized + $crate::Encode<crate::mode::$mode>,
+        {
+            $default.encoded_len(value)
+        }
+
         /// Encode the given value to the given [`Write`] using the [`DEFAULT`]
         /// [`Encoding`].
         ///
@@ -229,6 +312,21 @@ macro_rules! bare_encoding {
         {
             $default.from_slice(bytes)
         }
+
+        /// Decode the given type `T` from the given [`Reader`] into an
+        /// existing value using the [`DEFAULT`] [`Encoding`].
+        ///
+        /// See [`Encoding::decode_into`] for more.
+        ///
+        /// [`Reader`]: crate::Reader
+        #[inline]
+        pub fn decode_into<'de, R, T>(value: &mut T, reader: R) -> Result<(), Error>
+        where
+            R: $reader_trait<'de>,
+            T: $crate::Decode<'de, $mode>,
+        {
+            $default.decode_into(value, reader)
+        }
     };
 }
 
@@ -236,7 +334,37 @@ pub(crate) use bare_encoding;
 
 /// Generate all public encoding helpers.
 macro_rules! encoding_impls {
+    ($mode:ident, $what:ident, self.$encode:ident, self.$decode:ident, $reader_trait:ident :: $into_reader:ident $(,)?) => {
+        $crate::macros::encoding_impls!(@impls $mode, $what, [.$encode], [.$decode], $reader_trait::$into_reader);
+    };
+
     ($mode:ident, $what:ident, $encoder_new:path, $decoder_new:path, $reader_trait:ident :: $into_reader:ident $(,)?) => {
+        $crate::macros::encoding_impls!(@impls $mode, $what, [$encoder_new], [$decoder_new], $reader_trait::$into_reader);
+    };
+
+    // Encode using a method on the encoding, which is responsible for
+    // constructing the encoder.
+    (@encode $this:ident, [.$encode:ident], $cx:ident, $writer:ident, |$encoder:ident| $body:expr) => {
+        $this.$encode($cx, $writer, |$encoder| $body)
+    };
+
+    (@encode $this:ident, [$encoder_new:path], $cx:ident, $writer:ident, |$encoder:ident| $body:expr) => {{
+        let $encoder = $encoder_new($cx, $writer);
+        $body
+    }};
+
+    // Decode using a method on the encoding, which is responsible for
+    // constructing the decoder.
+    (@decode $this:ident, [.$decode:ident], $cx:ident, $reader:ident, |$decoder:ident| $body:expr) => {
+        $this.$decode($cx, $reader, |$decoder| $body)
+    };
+
+    (@decode $this:ident, [$decoder_new:path], $cx:ident, $reader:ident, |$decoder:ident| $body:expr) => {{
+        let $decoder = $decoder_new($cx, $reader);
+        $body
+    }};
+
+    (@impls $mode:ident, $what:ident, [$($encoder:tt)*], [$($decoder:tt)*], $reader_trait:ident :: $into_reader:ident) => {
         /// Encode the given value to the given [`Writer`] using the current
         /// [`Encoding`].
         ///
@@ -322,6 +450,59 @@ macro_rules! encoding_impls {
             Ok(vec)
         }
 
+        /// Encode the given value into the given [`Vec`] using the current
+        /// [`Encoding`].
+        ///
+        /// The vector is cleared before encoding, and its capacity is reused
+        /// so that encoding many values in a row doesn't allocate once the
+        /// vector is large enough.
+        ///
+        /// [`Vec`]: rust_alloc::vec::Vec
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// let mut data = Vec::new();
+        ///
+        /// for age in [61, 62] {
+        ///     ENCODING.to_vec_in(&mut data, &Person {
+        ///         name: "Aristotle".to_string(),
+        ///         age,
+        ///     })?;
+        ///
+        ///     let person: Person = ENCODING.from_slice(&data[..])?;
+        ///     assert_eq!(person.name, "Aristotle");
+        ///     assert_eq!(person.age, age);
+        /// }
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[cfg(feature = "alloc")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+        #[inline]
+        pub fn to_vec_in<T>(
+            self,
+            vec: &mut rust_alloc::vec::Vec<u8>,
+            value: &T,
+        ) -> Result<(), Error>
+        where
+            T: ?Sized + $crate::Encode<$mode>,
+        {
+            vec.clear();
+            self.encode(vec, value)
+        }
+
         /// Encode the given value to a fixed-size bytes using the current
         /// [`Encoding`].
         ///
@@ -364,6 +545,49 @@ macro_rules! encoding_impls {
             })
         }
 
+        /// Calculate the exact number of bytes the given value encodes to using
+        /// the current [`Encoding`], without writing anything.
+        ///
+        /// This can be used to pre-allocate a buffer, or to write a length
+        /// prefix ahead of the value.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// let person = Person {
+        ///     name: "Aristotle".to_string(),
+        ///     age: 61,
+        /// };
+        ///
+        /// let len = ENCODING.encoded_len(&person)?;
+        /// let mut data = Vec::with_capacity(len);
+        /// ENCODING.encode(&mut data, &person)?;
+        /// assert_eq!(data.len(), len);
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[inline]
+        pub fn encoded_len<T>(self, value: &T) -> Result<usize, Error>
+        where
+            T: ?Sized + $crate::Encode<$mode>,
+        {
+            $crate::alloc::default!(|alloc| {
+                let cx = $crate::context::Same::with_alloc(alloc);
+                self.encoded_len_with(&cx, value)
+            })
+        }
+
         /// Encode the given value to the given [`Write`] using the current
         /// [`Encoding`].
         ///
@@ -508,6 +732,89 @@ macro_rules! encoding_impls {
             self.from_slice(string.as_bytes())
         }
 
+        /// Decode the given type `T` from the given slice using the current
+        /// [`Encoding`], allocating through the provided allocator.
+        ///
+        /// This is what allows decoding collections which borrow from an
+        /// allocator, such as [`musli::alloc::Vec`] and
+        /// [`musli::alloc::String`].
+        ///
+        /// [`musli::alloc::Vec`]: crate::alloc::Vec
+        /// [`musli::alloc::String`]: crate::alloc::String
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::alloc::{ArrayBuffer, Slice, Vec};
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// let data = ENCODING.to_vec(&[1u32, 2, 3])?;
+        ///
+        /// let mut buf = ArrayBuffer::new();
+        /// let alloc = Slice::new(&mut buf);
+        ///
+        /// let values: Vec<'_, u32, _> = ENCODING.from_slice_in(&alloc, &data[..])?;
+        /// assert_eq!(values.as_slice(), [1, 2, 3]);
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[inline]
+        pub fn from_slice_in<'de, 'a, A, T>(
+            self,
+            alloc: &'a A,
+            bytes: &'de [u8],
+        ) -> Result<T, Error>
+        where
+            A: ?Sized + $crate::alloc::Allocator,
+            T: $crate::Decode<'de, $mode, &'a A>,
+        {
+            let cx = &$crate::context::Same::with_alloc(alloc);
+            let reader = $reader_trait::$into_reader(bytes);
+            $crate::macros::encoding_impls!(@decode self, [$($decoder)*], cx, reader, |decoder| T::decode(cx, decoder))
+        }
+
+        /// Decode the given type `T` from the given [`Reader`] into an
+        /// existing value using the current [`Encoding`].
+        ///
+        /// This reuses any allocations held by `value`, such as the capacity
+        /// of vectors and strings, which makes it well suited for decoding
+        /// the same kind of message repeatedly. See [`Decode::decode_into`]
+        /// for how this is implemented.
+        ///
+        /// [`Reader`]: crate::Reader
+        /// [`Decode::decode_into`]: crate::Decode::decode_into
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// let mut names = Vec::<String>::with_capacity(16);
+        ///
+        /// let data = ENCODING.to_vec(&["Aristotle", "Plato"])?;
+        /// ENCODING.decode_into(&mut names, &data[..])?;
+        /// assert_eq!(names, ["Aristotle", "Plato"]);
+        /// assert!(names.capacity() >= 16);
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[inline]
+        pub fn decode_into<'de, R, T>(self, value: &mut T, reader: R) -> Result<(), Error>
+        where
+            R: $reader_trait<'de>,
+            T: $crate::Decode<'de, $mode>,
+        {
+            $crate::alloc::default!(|alloc| {
+                let cx = $crate::context::Same::with_alloc(alloc);
+                self.decode_into_with(&cx, value, reader)
+            })
+        }
+
         /// Encode the given value to the given [`Writer`] using the current
         /// [`Encoding`].
         ///
@@ -556,7 +863,7 @@ macro_rules! encoding_impls {
             T: ?Sized + $crate::Encode<C::Mode>,
         {
             cx.clear();
-            T::encode(value, cx, $encoder_new(cx, writer))
+            $crate::macros::encoding_impls!(@encode self, [$($encoder)*], cx, writer, |encoder| T::encode(value, cx, encoder))
         }
 
         /// Encode the given value to a [`Vec`] using the current [`Encoding`].
@@ -613,6 +920,61 @@ macro_rules! encoding_impls {
             Ok(vec)
         }
 
+        /// Encode the given value into the given [`Vec`] using the current
+        /// [`Encoding`].
+        ///
+        /// The vector is cleared before encoding, and its capacity is reused
+        /// so that encoding many values in a row doesn't allocate once the
+        /// vector is large enough.
+        ///
+        /// [`Vec`]: rust_alloc::vec::Vec
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        /// use musli::context::Same;
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// let cx = Same::new();
+        /// let mut data = Vec::new();
+        ///
+        /// ENCODING.to_vec_in_with(&cx, &mut data, &Person {
+        ///     name: "Aristotle".to_string(),
+        ///     age: 61,
+        /// })?;
+        ///
+        /// let person: Person = ENCODING.from_slice_with(&cx, &data[..])?;
+        /// assert_eq!(person.name, "Aristotle");
+        /// assert_eq!(person.age, 61);
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[cfg(feature = "alloc")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+        #[inline]
+        pub fn to_vec_in_with<C, T>(
+            self,
+            cx: &C,
+            vec: &mut rust_alloc::vec::Vec<u8>,
+            value: &T,
+        ) -> Result<(), C::Error>
+        where
+            C: ?Sized + $crate::Context<Mode = $mode>,
+            T: ?Sized + $crate::Encode<C::Mode>,
+        {
+            vec.clear();
+            self.encode_with(cx, vec, value)
+        }
+
         /// Encode the given value to a fixed-size bytes using the current
         /// [`Encoding`].
         ///
@@ -660,6 +1022,53 @@ macro_rules! encoding_impls {
             Ok(bytes)
         }
 
+        /// Calculate the exact number of bytes the given value encodes to using
+        /// the current [`Encoding`] and context `C`, without writing anything.
+        ///
+        /// This is the same as [`Encoding::encoded_len`], but allows for using
+        /// a configurable [`Context`].
+        ///
+        /// [`Context`]: crate::Context
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        /// use musli::context::Same;
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// let cx = Same::new();
+        ///
+        /// let person = Person {
+        ///     name: "Aristotle".to_string(),
+        ///     age: 61,
+        /// };
+        ///
+        /// let len = ENCODING.encoded_len_with(&cx, &person)?;
+        /// let data = ENCODING.to_vec_with(&cx, &person)?;
+        /// assert_eq!(data.len(), len);
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[inline]
+        pub fn encoded_len_with<C, T>(self, cx: &C, value: &T) -> Result<usize, C::Error>
+        where
+            C: ?Sized + $crate::Context<Mode = $mode>,
+            T: ?Sized + $crate::Encode<C::Mode>,
+        {
+            let mut writer = $crate::writer::CountWriter::new();
+            self.encode_with(cx, &mut writer, value)?;
+            Ok(writer.len())
+        }
+
         /// Encode the given value to the given [`Write`] using the current
         /// [`Encoding`] and context `C`.
         ///
@@ -756,7 +1165,32 @@ macro_rules! encoding_impls {
         {
             cx.clear();
             let reader = $reader_trait::$into_reader(reader);
-            T::decode(cx, $decoder_new(cx, reader))
+            $crate::macros::encoding_impls!(@decode self, [$($decoder)*], cx, reader, |decoder| T::decode(cx, decoder))
+        }
+
+        /// Decode the given type `T` from the given [`Reader`] into an
+        /// existing value using the current [`Encoding`].
+        ///
+        /// This is the same as [`Encoding::decode_into`] but allows for using
+        /// a configurable [`Context`].
+        ///
+        /// [`Reader`]: crate::Reader
+        /// [`Context`]: crate::Context
+        #[inline]
+        pub fn decode_into_with<'de, C, R, T>(
+            self,
+            cx: &C,
+            value: &mut T,
+            reader: R,
+        ) -> Result<(), C::Error>
+        where
+            C: ?Sized + $crate::Context<Mode = $mode>,
+            R: $reader_trait<'de>,
+            T: $crate::Decode<'de, C::Mode>,
+        {
+            cx.clear();
+            let reader = $reader_trait::$into_reader(reader);
+            $crate::macros::encoding_impls!(@decode self, [$($decoder)*], cx, reader, |decoder| T::decode_into(cx, decoder, value))
         }
 
         /// Decode the given type `T` from the given slice using the current
@@ -823,7 +1257,291 @@ macro_rules! encoding_impls {
         {
             self.from_slice_with(cx, string.as_bytes())
         }
+
+        /// Construct a value which when encoded transcodes the value stored in
+        /// `bytes` using the current [`Encoding`].
+        ///
+        /// See the [`transcode`] module for more.
+        ///
+        /// [`transcode`]: crate::transcode
+        #[inline]
+        pub fn transcode(self, bytes: &[u8]) -> $crate::transcode::Transcode<'_, Self> {
+            $crate::transcode::Transcode::new(self, bytes)
+        }
     };
 }
 
 pub(crate) use encoding_impls;
+
+/// Generate asynchronous encoding helpers for binary formats.
+macro_rules! async_encoding_impls {
+    ($mode:ident, $what:ident $(,)?) => {
+        /// Encode the given value to the given [`AsyncWrite`] using the
+        /// current [`Encoding`].
+        ///
+        /// The value is encoded into a buffer, which is then written in its
+        /// entirety before the writer is flushed.
+        ///
+        /// [`AsyncWrite`]: crate::io::AsyncWrite
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        /// use musli::io::AsyncWrite;
+        #[doc = concat!("use musli::", stringify!($what), "::{Encoding, Error};")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// async fn send<W>(writer: W, person: &Person) -> Result<(), Error>
+        /// where
+        ///     W: AsyncWrite + Unpin,
+        /// {
+        ///     ENCODING.encode_async(writer, person).await
+        /// }
+        /// ```
+        #[cfg(all(feature = "std", feature = "alloc"))]
+        #[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "alloc"))))]
+        pub async fn encode_async<W, T>(self, writer: W, value: &T) -> Result<(), Error>
+        where
+            W: $crate::io::AsyncWrite + Unpin,
+            T: ?Sized + $crate::Encode<$mode>,
+        {
+            let buf = self.to_vec(value)?;
+            $crate::io::write_all(writer, &buf)
+                .await
+                .map_err($crate::context::ContextError::custom)
+        }
+
+        /// Decode the given type `T` from the given [`AsyncReader`] using the
+        /// current [`Encoding`].
+        ///
+        /// Only as many bytes as are needed to decode the value are consumed
+        /// from the reader, anything else is kept buffered for the next call.
+        ///
+        /// [`AsyncReader`]: crate::io::AsyncReader
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        /// use musli::io::{AsyncRead, AsyncReader};
+        #[doc = concat!("use musli::", stringify!($what), "::{Encoding, Error};")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// async fn receive<R>(reader: &mut AsyncReader<R>) -> Result<Person, Error>
+        /// where
+        ///     R: AsyncRead + Unpin,
+        /// {
+        ///     ENCODING.decode_async(reader).await
+        /// }
+        /// ```
+        #[cfg(all(feature = "std", feature = "alloc"))]
+        #[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "alloc"))))]
+        pub async fn decode_async<R, T>(
+            self,
+            reader: &mut $crate::io::AsyncReader<R>,
+        ) -> Result<T, Error>
+        where
+            R: $crate::io::AsyncRead + Unpin,
+            T: $crate::de::DecodeOwned<$mode>,
+        {
+            reader.decode(|partial| self.decode(partial)).await
+        }
+    };
+}
+
+pub(crate) use async_encoding_impls;
+
+/// Generate helpers for decoding from a blocking [`Read`] for binary formats.
+///
+/// [`Read`]: std::io::Read
+macro_rules! reader_encoding_impls {
+    ($mode:ident, $what:ident $(,)?) => {
+        /// Decode the given type `T` from the given [`Read`] using the current
+        /// [`Encoding`].
+        ///
+        /// The reader is buffered internally, and any bytes which were read
+        /// past the end of the value are discarded. To decode multiple values
+        /// from the same reader, use [`Encoding::decode_reader`] instead.
+        ///
+        /// [`Read`]: std::io::Read
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// let mut data = Vec::new();
+        ///
+        /// ENCODING.to_writer(&mut data, &Person {
+        ///     name: "Aristotle".to_string(),
+        ///     age: 61,
+        /// })?;
+        ///
+        /// let person: Person = ENCODING.from_reader(&data[..])?;
+        /// assert_eq!(person.name, "Aristotle");
+        /// assert_eq!(person.age, 61);
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[cfg(all(feature = "std", feature = "alloc"))]
+        #[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "alloc"))))]
+        #[inline]
+        pub fn from_reader<R, T>(self, reader: R) -> Result<T, Error>
+        where
+            R: std::io::Read,
+            T: $crate::de::DecodeOwned<$mode>,
+        {
+            self.decode_reader(&mut $crate::io::IoReader::new(reader))
+        }
+
+        /// Decode the given type `T` from the given [`IoReader`] using the
+        /// current [`Encoding`].
+        ///
+        /// Only as many bytes as are needed to decode the value are consumed
+        /// from the reader, anything else is kept buffered for the next call.
+        ///
+        /// [`IoReader`]: crate::io::IoReader
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        /// use musli::io::IoReader;
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// let mut data = Vec::new();
+        ///
+        /// for age in [61, 62] {
+        ///     ENCODING.to_writer(&mut data, &Person {
+        ///         name: "Aristotle".to_string(),
+        ///         age,
+        ///     })?;
+        /// }
+        ///
+        /// let mut reader = IoReader::new(&data[..]);
+        ///
+        /// for age in [61, 62] {
+        ///     let person: Person = ENCODING.decode_reader(&mut reader)?;
+        ///     assert_eq!(person.name, "Aristotle");
+        ///     assert_eq!(person.age, age);
+        /// }
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[cfg(all(feature = "std", feature = "alloc"))]
+        #[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "alloc"))))]
+        pub fn decode_reader<R, T>(
+            self,
+            reader: &mut $crate::io::IoReader<R>,
+        ) -> Result<T, Error>
+        where
+            R: std::io::Read,
+            T: $crate::de::DecodeOwned<$mode>,
+        {
+            reader.decode(|partial| self.decode(partial))
+        }
+    };
+}
+
+pub(crate) use reader_encoding_impls;
+
+/// Implement methods on [`DynMode`] for the `Encoding` of a format.
+///
+/// [`DynMode`]: crate::DynMode
+macro_rules! dyn_mode_impls {
+    ($first:ident, $second:ident, $reader_trait:ident $(,)?) => {
+        /// Encode the given value to the given [`Writer`] using the selected
+        /// [`Encoding`].
+        ///
+        /// [`Writer`]: crate::Writer
+        #[inline]
+        pub fn encode<W, T>(self, writer: W, value: &T) -> Result<(), Error>
+        where
+            W: $crate::Writer,
+            T: ?Sized + $crate::Encode<$first> + $crate::Encode<$second>,
+        {
+            match self {
+                $crate::DynMode::First(encoding) => encoding.encode(writer, value),
+                $crate::DynMode::Second(encoding) => encoding.encode(writer, value),
+            }
+        }
+
+        /// Encode the given value to a [`Vec`] using the selected
+        /// [`Encoding`].
+        ///
+        /// [`Vec`]: rust_alloc::vec::Vec
+        #[cfg(feature = "alloc")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+        #[inline]
+        pub fn to_vec<T>(self, value: &T) -> Result<rust_alloc::vec::Vec<u8>, Error>
+        where
+            T: ?Sized + $crate::Encode<$first> + $crate::Encode<$second>,
+        {
+            match self {
+                $crate::DynMode::First(encoding) => encoding.to_vec(value),
+                $crate::DynMode::Second(encoding) => encoding.to_vec(value),
+            }
+        }
+
+        /// Decode the given type `T` from the given reader using the selected
+        /// [`Encoding`].
+        #[inline]
+        pub fn decode<'de, R, T>(self, reader: R) -> Result<T, Error>
+        where
+            R: $reader_trait<'de>,
+            T: $crate::Decode<'de, $first> + $crate::Decode<'de, $second>,
+        {
+            match self {
+                $crate::DynMode::First(encoding) => encoding.decode(reader),
+                $crate::DynMode::Second(encoding) => encoding.decode(reader),
+            }
+        }
+
+        /// Decode the given type `T` from the given slice using the selected
+        /// [`Encoding`].
+        #[inline]
+        pub fn from_slice<'de, T>(self, bytes: &'de [u8]) -> Result<T, Error>
+        where
+            T: $crate::Decode<'de, $first> + $crate::Decode<'de, $second>,
+        {
+            match self {
+                $crate::DynMode::First(encoding) => encoding.from_slice(bytes),
+                $crate::DynMode::Second(encoding) => encoding.from_slice(bytes),
+            }
+        }
+    };
+}
+
+pub(crate) use dyn_mode_impls;
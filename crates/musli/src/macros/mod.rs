@@ -1,5 +1,10 @@
 //! Helper macros for use with Musli.
 
+mod impls;
+#[doc(hidden)]
+pub use self::impls::__count;
+pub use self::impls::{encode_struct, encode_variant, impl_struct_encode};
+
 #[cfg(any(
     feature = "storage",
     feature = "wire",
@@ -13,7 +18,9 @@ mod internal;
     feature = "descriptive",
     feature = "value"
 ))]
-pub(crate) use self::internal::{bare_encoding, encoding_impls};
+pub(crate) use self::internal::{
+    async_encoding_impls, bare_encoding, dyn_mode_impls, encoding_impls, reader_encoding_impls,
+};
 
 #[cfg(all(
     feature = "test",
@@ -25,7 +32,11 @@ pub(crate) use self::internal::{bare_encoding, encoding_impls};
     )
 ))]
 mod test;
+#[cfg(all(feature = "test", feature = "std"))]
+pub use self::test::{__assert_conformance, assert_conformance, assert_snapshot};
 #[cfg(feature = "test")]
-pub use self::test::{__test_extra, __test_matrix, assert_decode_eq, assert_roundtrip_eq, support};
+pub use self::test::{
+    __test_extra, __test_matrix, assert_decode_eq, assert_encoding_eq, assert_roundtrip_eq, support,
+};
 #[cfg(feature = "test")]
-pub(crate) use self::test::{test_fns, test_include_if};
+pub(crate) use self::test::{test_describe, test_fns, test_include_if};
@@ -1,12 +1,34 @@
 //! Helper macros for use with Musli.
 
 macro_rules! test_include_if {
-    (#[musli_value] => $($rest:tt)*) => { $($rest)* };
+    (#[musli_value] $(#[$_option:ident])* => $($rest:tt)*) => { $($rest)* };
+    (#[$_:ident] $(#[$option:ident])* => $($rest:tt)*) => {
+        $crate::macros::test_include_if!($(#[$option])* => $($rest)*)
+    };
     (=> $($_:tt)*) => {};
 }
 
 pub(crate) use test_include_if;
 
+/// Describe encoded bytes, either through the `inspect` function of the format
+/// if the `#[inspect]` option is set, or by decoding them into a dynamic value.
+macro_rules! test_describe {
+    (#[inspect] $(#[$_option:ident])* => $bytes:expr) => {{
+        let node = super::Encoding::new().inspect($bytes).ok()?;
+        Some(rust_alloc::string::ToString::to_string(&node))
+    }};
+    (#[$_:ident] $(#[$option:ident])* => $bytes:expr) => {
+        $crate::macros::test_describe!($(#[$option])* => $bytes)
+    };
+    (=> $bytes:expr) => {{
+        let encoding = super::Encoding::new();
+        let value: $crate::value::Value = encoding.from_slice($bytes).ok()?;
+        Some(rust_alloc::format!("{value:#?}"))
+    }};
+}
+
+pub(crate) use test_describe;
+
 /// Generate test functions which provides rich diagnostics when they fail.
 macro_rules! test_fns {
     ($mode:ident, $what:expr $(, $(#[$option:ident])*)?) => {
@@ -191,6 +213,43 @@ macro_rules! test_fns {
                 }
             })
         }
+
+        /// Decode a value from bytes.
+        #[doc(hidden)]
+        #[track_caller]
+        pub fn from_slice<'de, T, M>(bytes: &'de [u8]) -> T
+        where
+            T: $crate::de::Decode<'de, M>,
+            M: 'static,
+        {
+            const WHAT: &str = $what;
+
+            let encoding = super::Encoding::new().with_mode::<M>();
+
+            use ::core::any::type_name;
+
+            $crate::alloc::default!(|alloc| {
+                let mut cx = $crate::context::with_alloc(alloc);
+                cx.include_type();
+
+                match encoding.from_slice_with(&cx, bytes) {
+                    Ok(value) => value,
+                    Err(..) => {
+                        let error = cx.report();
+                        panic!("{WHAT}: {}: failed to decode:\n{error}", type_name::<T>())
+                    }
+                }
+            })
+        }
+
+        /// Describe the structure of the given bytes.
+        ///
+        /// This returns `None` if the structure could not be determined,
+        /// which is the case for formats which are not self-descriptive.
+        #[doc(hidden)]
+        pub fn describe(bytes: &[u8]) -> Option<rust_alloc::string::String> {
+            $crate::macros::test_describe!($($(#[$option])*)* => bytes)
+        }
     }
 }
 
@@ -215,6 +274,8 @@ pub(crate) use test_fns;
 /// Extra tests can be specified using the `$extra` parameter:
 /// * `json = <expected>` - Assert that the JSON encoding of `$expr` matched
 ///   exactly `$expected`.
+/// * `<format> = <bytes>` - Assert that the encoding of `$expr` using the
+///   given format matches exactly `<bytes>`. See [`assert_encoding_eq!`].
 ///
 /// # Examples
 ///
@@ -283,6 +344,8 @@ pub use assert_roundtrip_eq;
 /// Extra tests can be specified using the `$extra` parameter:
 /// * `json = <expected>` - Assert that the JSON encoding of `$expr` matched
 ///   exactly `$expected`.
+/// * `<format> = <bytes>` - Assert that the encoding of `$expr` using the
+///   given format matches exactly `<bytes>`. See [`assert_encoding_eq!`].
 ///
 /// # Examples
 ///
@@ -347,6 +410,206 @@ macro_rules! assert_decode_eq {
 
 pub use assert_decode_eq;
 
+/// Assert that the encoding of `$expr` using the format `$name` matches
+/// exactly the bytes in `$expected`.
+///
+/// The `$name` parameter is one of `storage`, `wire`, `descriptive` or `json`.
+/// Values are encoded using the binary mode, except for `json` which uses the
+/// text mode.
+///
+/// On failure this prints a hexdump of both the expected and actual bytes with
+/// the first differing offset highlighted. If the structure of the payloads
+/// can be described, such as the tags of the `wire` format or the decoded
+/// value of a self-descriptive format, the lines where they differ are printed
+/// as well.
+///
+/// The same check can be performed as part of [`assert_roundtrip_eq!`] by
+/// passing `<format> = <bytes>` as an extra argument.
+///
+/// # Examples
+///
+/// ```
+/// use musli::{Decode, Encode};
+///
+/// #[derive(Debug, PartialEq, Encode, Decode)]
+/// #[musli(packed)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// musli::macros::assert_encoding_eq!(storage, Point { x: 1, y: 2 }, [1, 2]);
+///
+/// musli::macros::assert_roundtrip_eq! {
+///     full,
+///     Point { x: 1, y: 2 },
+///     storage = [1, 2],
+/// };
+/// ```
+#[cfg_attr(doc_cfg, doc(cfg(feature = "test")))]
+#[macro_export]
+macro_rules! assert_encoding_eq {
+    (json, $expr:expr, $expected:expr $(,)?) => {{
+        let actual = $crate::json::test::to_vec::<_, $crate::mode::Text>($expr);
+        $crate::macros::support::assert_bytes_eq(
+            "json",
+            &actual,
+            &$expected[..],
+            $crate::json::test::describe,
+        );
+    }};
+
+    ($name:ident, $expr:expr, $expected:expr $(,)?) => {{
+        let actual = $crate::$name::test::to_vec::<_, $crate::mode::Binary>($expr);
+        $crate::macros::support::assert_bytes_eq(
+            stringify!($name),
+            &actual,
+            &$expected[..],
+            $crate::$name::test::describe,
+        );
+    }};
+}
+
+pub use assert_encoding_eq;
+
+/// Assert that the encoding of `$expr` using the format `$name` matches a
+/// snapshot stored on disk.
+///
+/// Snapshots are stored in the `tests/snapshots` directory of the crate
+/// invoking the macro as `<snapshot>.<format>.bin`, and should be committed
+/// alongside the test. A missing snapshot is a failure, so that a test can't
+/// silently pass by creating it. Setting the `MUSLI_UPDATE_SNAPSHOTS`
+/// environment variable to `1` creates missing snapshots and overwrites
+/// existing ones instead.
+///
+/// Failures are reported in the same manner as [`assert_encoding_eq!`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use musli::{Decode, Encode};
+///
+/// #[derive(Debug, PartialEq, Encode, Decode)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// musli::macros::assert_snapshot!(wire, "person", Person {
+///     name: String::from("Aristotle"),
+///     age: 61,
+/// });
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "test", feature = "std"))))]
+#[macro_export]
+macro_rules! assert_snapshot {
+    (json, $snapshot:expr, $expr:expr $(,)?) => {{
+        let actual = $crate::json::test::to_vec::<_, $crate::mode::Text>($expr);
+        $crate::macros::support::assert_snapshot(
+            ::core::env!("CARGO_MANIFEST_DIR"),
+            "json",
+            $snapshot,
+            &actual,
+            $crate::json::test::describe,
+        );
+    }};
+
+    ($name:ident, $snapshot:expr, $expr:expr $(,)?) => {{
+        let actual = $crate::$name::test::to_vec::<_, $crate::mode::Binary>($expr);
+        $crate::macros::support::assert_snapshot(
+            ::core::env!("CARGO_MANIFEST_DIR"),
+            stringify!($name),
+            $snapshot,
+            &actual,
+            $crate::$name::test::describe,
+        );
+    }};
+}
+
+#[cfg(feature = "std")]
+pub use assert_snapshot;
+
+/// Assert that `$expr` is decoded from and encoded to a conformance fixture
+/// stored on disk using the format `$name`.
+///
+/// This works like [`assert_snapshot!`], except that fixtures are stored in
+/// the `tests/conformance` directory of the crate invoking the macro as
+/// `<fixture>.<format>.bin`. In addition to checking that encoding `$expr`
+/// still produces exactly the bytes in the fixture, it checks that the bytes
+/// in the fixture still decode into a value equal to `$expr`.
+///
+/// Fixtures are created and updated the same way as snapshots, by setting the
+/// `MUSLI_UPDATE_SNAPSHOTS` environment variable to `1`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use musli::{Decode, Encode};
+///
+/// #[derive(Debug, PartialEq, Encode, Decode)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// musli::macros::assert_conformance!(wire, "person", Person {
+///     name: String::from("Aristotle"),
+///     age: 61,
+/// });
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "test", feature = "std"))))]
+#[macro_export]
+macro_rules! assert_conformance {
+    (json, $fixture:expr, $expr:expr $(,)?) => {{
+        $crate::macros::__assert_conformance!(json, $crate::mode::Text, $fixture, $expr)
+    }};
+
+    ($name:ident, $fixture:expr, $expr:expr $(,)?) => {{
+        $crate::macros::__assert_conformance!($name, $crate::mode::Binary, $fixture, $expr)
+    }};
+}
+
+#[cfg(feature = "std")]
+pub use assert_conformance;
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_conformance {
+    ($name:ident, $mode:ty, $fixture:expr, $expr:expr) => {{
+        let expected = $expr;
+        let actual = $crate::$name::test::to_vec::<_, $mode>(&expected);
+
+        let (path, snapshot) = $crate::macros::support::snapshot_in(
+            ::core::env!("CARGO_MANIFEST_DIR"),
+            "conformance",
+            stringify!($name),
+            $fixture,
+            &actual,
+            $crate::$name::test::describe,
+        );
+
+        let decoded = $crate::macros::support::infer(
+            &expected,
+            $crate::$name::test::from_slice::<_, $mode>(&snapshot),
+        );
+
+        assert_eq!(
+            decoded,
+            expected,
+            "{}: {}: snapshot no longer decodes into the expected value",
+            stringify!($name),
+            path.display()
+        );
+    }};
+}
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use __assert_conformance;
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __test_extra {
@@ -375,6 +638,11 @@ macro_rules! __test_extra {
 
         $crate::macros::__test_extra!($expr $(, $($extra)*)*);
     }};
+
+    ($expr:expr, $name:ident = $bytes_expected:expr $(, $($extra:tt)*)?) => {{
+        $crate::macros::assert_encoding_eq!($name, $expr, $bytes_expected);
+        $crate::macros::__test_extra!($expr $(, $($extra)*)*);
+    }};
 }
 
 pub use __test_extra;
@@ -431,14 +699,263 @@ pub use __test_matrix;
 pub mod support {
     pub use rust_alloc::vec::Vec;
 
+    use core::fmt;
+
+    use rust_alloc::string::String;
+
+    use crate::de::DecodeOwned;
     use crate::mode::Binary;
     use crate::value::{self, Value};
-    use crate::{Decode, Encode};
+    use crate::Encode;
+
+    /// The number of bytes printed per line in a hexdump.
+    const HEX_WIDTH: usize = 16;
+
+    /// Hexdump of a byte slice, which marks the line containing the given
+    /// offset.
+    struct Hex<'a> {
+        bytes: &'a [u8],
+        mark: Option<usize>,
+    }
+
+    impl fmt::Display for Hex<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for (n, chunk) in self.bytes.chunks(HEX_WIDTH).enumerate() {
+                let start = n * HEX_WIDTH;
+                let marked =
+                    matches!(self.mark, Some(at) if (start..start + HEX_WIDTH).contains(&at));
+                let prefix = if marked { '>' } else { ' ' };
+
+                write!(f, "{prefix} {start:08x} ")?;
+
+                for b in chunk {
+                    write!(f, " {b:02x}")?;
+                }
+
+                writeln!(f)?;
+            }
+
+            if self.bytes.len() % HEX_WIDTH == 0 && self.mark == Some(self.bytes.len()) {
+                writeln!(f, "> {:08x}", self.bytes.len())?;
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Format a diff between two encoded payloads.
+    fn diff(
+        what: &str,
+        actual: &[u8],
+        expected: &[u8],
+        describe: fn(&[u8]) -> Option<String>,
+    ) -> String {
+        use core::fmt::Write;
+
+        let at = actual
+            .iter()
+            .zip(expected)
+            .position(|(a, b)| a != b)
+            .unwrap_or(actual.len().min(expected.len()));
+
+        let mut out = String::new();
+
+        _ = writeln!(
+            out,
+            "{what}: encoded bytes do not match expected, first difference at offset {at}"
+        );
+
+        _ = writeln!(out, "Expected ({} bytes):", expected.len());
+        _ = write!(
+            out,
+            "{}",
+            Hex {
+                bytes: expected,
+                mark: Some(at)
+            }
+        );
+        _ = writeln!(out, "Actual ({} bytes):", actual.len());
+        _ = write!(
+            out,
+            "{}",
+            Hex {
+                bytes: actual,
+                mark: Some(at)
+            }
+        );
+
+        if let (Some(expected), Some(actual)) = (describe(expected), describe(actual)) {
+            _ = write!(
+                out,
+                "{}",
+                Lines {
+                    expected: &expected,
+                    actual: &actual,
+                }
+            );
+        }
+
+        out
+    }
+
+    /// A line-by-line diff between the described structure of two payloads,
+    /// showing a few lines of context around where they differ.
+    struct Lines<'a> {
+        expected: &'a str,
+        actual: &'a str,
+    }
+
+    impl fmt::Display for Lines<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            const CONTEXT: usize = 3;
+
+            let expected = self.expected.lines().collect::<Vec<_>>();
+            let actual = self.actual.lines().collect::<Vec<_>>();
+
+            if expected == actual {
+                return writeln!(f, "The structure is the same, only the bytes differ");
+            }
+
+            writeln!(f, "Structure (- expected, + actual):")?;
+
+            let prefix = expected
+                .iter()
+                .zip(&actual)
+                .take_while(|(a, b)| a == b)
+                .count();
+
+            let suffix = expected[prefix..]
+                .iter()
+                .rev()
+                .zip(actual[prefix..].iter().rev())
+                .take_while(|(a, b)| a == b)
+                .count();
+
+            let start = prefix.saturating_sub(CONTEXT);
+
+            if start > 0 {
+                writeln!(f, "  ...")?;
+            }
+
+            for line in &expected[start..prefix] {
+                writeln!(f, "  {line}")?;
+            }
+
+            for line in &expected[prefix..expected.len() - suffix] {
+                writeln!(f, "- {line}")?;
+            }
+
+            for line in &actual[prefix..actual.len() - suffix] {
+                writeln!(f, "+ {line}")?;
+            }
+
+            let end = expected.len() - suffix;
+            let context = (end + CONTEXT).min(expected.len());
+
+            for line in &expected[end..context] {
+                writeln!(f, "  {line}")?;
+            }
+
+            if context < expected.len() {
+                writeln!(f, "  ...")?;
+            }
+
+            Ok(())
+        }
+    }
+
+    #[track_caller]
+    pub fn assert_bytes_eq(
+        what: &str,
+        actual: &[u8],
+        expected: &[u8],
+        describe: fn(&[u8]) -> Option<String>,
+    ) {
+        if actual != expected {
+            panic!("{}", diff(what, actual, expected, describe));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[track_caller]
+    pub fn assert_snapshot(
+        root: &str,
+        what: &str,
+        snapshot: &str,
+        actual: &[u8],
+        describe: fn(&[u8]) -> Option<String>,
+    ) {
+        snapshot_in(root, "snapshots", what, snapshot, actual, describe);
+    }
+
+    /// Assert that `actual` matches the snapshot stored in the given
+    /// directory under `tests`, returning its path and contents.
+    ///
+    /// If the `MUSLI_UPDATE_SNAPSHOTS` environment variable is set to `1`,
+    /// `actual` is written to the snapshot instead.
+    #[cfg(feature = "std")]
+    #[track_caller]
+    pub fn snapshot_in(
+        root: &str,
+        dir: &str,
+        what: &str,
+        snapshot: &str,
+        actual: &[u8],
+        describe: fn(&[u8]) -> Option<String>,
+    ) -> (std::path::PathBuf, Vec<u8>) {
+        use std::env;
+        use std::fs;
+        use std::path::Path;
+
+        let dir = Path::new(root).join("tests").join(dir);
+        let path = dir.join(rust_alloc::format!("{snapshot}.{what}.bin"));
+
+        let update = env::var_os("MUSLI_UPDATE_SNAPSHOTS").is_some_and(|value| value == "1");
+
+        if update {
+            if let Err(error) = fs::create_dir_all(&dir).and_then(|()| fs::write(&path, actual)) {
+                panic!(
+                    "{what}: {}: failed to write snapshot: {error}",
+                    path.display()
+                );
+            }
+
+            return (path, actual.to_vec());
+        }
+
+        let expected = match fs::read(&path) {
+            Ok(expected) => expected,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => panic!(
+                "{what}: {}: snapshot is missing\nRun with MUSLI_UPDATE_SNAPSHOTS=1 to create it",
+                path.display()
+            ),
+            Err(error) => panic!(
+                "{what}: {}: failed to read snapshot: {error}",
+                path.display()
+            ),
+        };
+
+        if actual != expected {
+            panic!(
+                "{}: snapshot does not match\n{}\nRun with MUSLI_UPDATE_SNAPSHOTS=1 to update it",
+                path.display(),
+                diff(what, actual, &expected, describe)
+            );
+        }
+
+        (path, expected)
+    }
+
+    /// Helper to infer the type of a decoded value from an expected value.
+    #[inline(always)]
+    pub fn infer<T>(_: &T, value: T) -> T {
+        value
+    }
 
     #[track_caller]
     pub fn musli_value_rt<T>(expected: T)
     where
-        T: Encode<Binary> + for<'de> Decode<'de, Binary>,
+        T: Encode<Binary> + DecodeOwned<Binary>,
         T: PartialEq + core::fmt::Debug,
     {
         let value: Value = value::encode(&expected).expect("value: Encoding should succeed");
@@ -155,6 +155,14 @@ pub(crate) const fn byteorder<const OPT: Options>() -> ByteOrder {
     }
 }
 
+/// Test if numbers are encoded using their in-memory representation, in which
+/// case slices of them can be copied in bulk.
+#[cfg(any(feature = "storage", feature = "wire", feature = "descriptive"))]
+#[inline(always)]
+pub(crate) const fn is_native_fixed<const OPT: Options>() -> bool {
+    matches!(integer::<OPT>(), Integer::Fixed) && matches!(byteorder::<OPT>(), ByteOrder::NATIVE)
+}
+
 #[cfg(all(feature = "alloc", feature = "value"))]
 #[inline(always)]
 pub(crate) const fn is_map_keys_as_numbers<const OPT: Options>() -> bool {
@@ -272,6 +280,25 @@ pub enum Width {
     U64 = 3,
 }
 
+impl Width {
+    /// The width of `usize` on the current platform.
+    ///
+    /// This is [`U16`], [`U32`] or [`U64`] depending on the target pointer
+    /// width. Note that data encoded with this width is only portable between
+    /// platforms with the same pointer width.
+    ///
+    /// [`U16`]: Width::U16
+    /// [`U32`]: Width::U32
+    /// [`U64`]: Width::U64
+    pub const USIZE: Self = if cfg!(target_pointer_width = "16") {
+        Self::U16
+    } else if cfg!(target_pointer_width = "32") {
+        Self::U32
+    } else {
+        Self::U64
+    };
+}
+
 #[test]
 fn test_builds() {
     macro_rules! assert_or_default {
@@ -380,4 +407,11 @@ fn test_builds() {
             length_width = Width::U64,
         }
     }
+
+    test_case! {
+        self::new().with_length_width(Width::USIZE) => {
+            length = Integer::Fixed,
+            length_width = Width::USIZE,
+        }
+    }
 }
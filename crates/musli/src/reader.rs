@@ -19,6 +19,12 @@ mod sealed {
     impl Sealed for super::SliceReader<'_> {}
     impl<'de, R> Sealed for Limit<R> where R: Reader<'de> {}
     impl<'de, R> Sealed for &mut R where R: ?Sized + Reader<'de> {}
+    #[cfg(all(feature = "std", feature = "alloc"))]
+    impl Sealed for crate::io::Partial<'_> {}
+    #[cfg(feature = "wire")]
+    impl<'de, R> Sealed for crate::wire::checksum::ChecksumReader<R> where R: Reader<'de> {}
+    #[cfg(feature = "bytes")]
+    impl<B> Sealed for super::BufReader<B> where B: bytes::Buf {}
 }
 
 /// Trait governing how a source of bytes is read.
@@ -50,6 +56,16 @@ pub trait Reader<'de>: self::sealed::Sealed {
     /// Peek the next value.
     fn peek(&mut self) -> Option<u8>;
 
+    /// Access the remaining input as a borrowed slice.
+    ///
+    /// This returns `None` unless the reader is backed by a slice, in which
+    /// case formats can use it to capture the raw bytes of the values being
+    /// read.
+    #[inline]
+    fn remaining_slice(&self) -> Option<&'de [u8]> {
+        None
+    }
+
     /// Read a slice into the given buffer.
     #[inline]
     fn read<C>(&mut self, cx: &C, buf: &mut [u8]) -> Result<(), C::Error>
@@ -85,6 +101,10 @@ pub trait Reader<'de>: self::sealed::Sealed {
     }
 
     /// Read a slice out of the current reader.
+    ///
+    /// The context is advanced past the slice before it's passed to the
+    /// visitor, so that errors raised by the visitor can be marked to cover
+    /// it.
     fn read_bytes<C, V>(&mut self, cx: &C, n: usize, visitor: V) -> Result<V::Ok, C::Error>
     where
         C: ?Sized + Context,
@@ -222,9 +242,8 @@ impl<'de> Reader<'de> for &'de [u8] {
 
         let (head, tail) = self.split_at(n);
         *self = tail;
-        let ok = visitor.visit_borrowed(cx, head)?;
         cx.advance(n);
-        Ok(ok)
+        visitor.visit_borrowed(cx, head)
     }
 
     #[inline]
@@ -260,6 +279,11 @@ impl<'de> Reader<'de> for &'de [u8] {
     fn peek(&mut self) -> Option<u8> {
         self.first().copied()
     }
+
+    #[inline]
+    fn remaining_slice(&self) -> Option<&'de [u8]> {
+        Some(self)
+    }
 }
 
 /// Coerce a type into a [`Reader`].
@@ -365,14 +389,10 @@ impl<'de> Reader<'de> for SliceReader<'de> {
     {
         let outcome = bounds_check_add(cx, &self.range, n)?;
 
-        let ok = unsafe {
-            let bytes = slice::from_raw_parts(self.range.start, n);
-            self.range.start = outcome;
-            visitor.visit_borrowed(cx, bytes)?
-        };
-
+        let bytes = unsafe { slice::from_raw_parts(self.range.start, n) };
+        self.range.start = outcome;
         cx.advance(n);
-        Ok(ok)
+        visitor.visit_borrowed(cx, bytes)
     }
 
     #[inline]
@@ -385,6 +405,11 @@ impl<'de> Reader<'de> for SliceReader<'de> {
         unsafe { Some(ptr::read(self.range.start)) }
     }
 
+    #[inline]
+    fn remaining_slice(&self) -> Option<&'de [u8]> {
+        Some(self.as_slice())
+    }
+
     #[inline]
     fn read<C>(&mut self, cx: &C, buf: &mut [u8]) -> Result<(), C::Error>
     where
@@ -491,6 +516,12 @@ where
         }
     }
 
+    #[inline]
+    fn remaining_slice(&self) -> Option<&'de [u8]> {
+        let slice = self.reader.remaining_slice()?;
+        Some(&slice[..self.remaining.min(slice.len())])
+    }
+
     #[inline]
     fn read<C>(&mut self, cx: &C, buf: &mut [u8]) -> Result<(), C::Error>
     where
@@ -554,6 +585,11 @@ where
         (**self).peek()
     }
 
+    #[inline]
+    fn remaining_slice(&self) -> Option<&'de [u8]> {
+        (**self).remaining_slice()
+    }
+
     #[inline]
     fn read<C>(&mut self, cx: &C, buf: &mut [u8]) -> Result<(), C::Error>
     where
@@ -579,6 +615,127 @@ where
     }
 }
 
+/// A [`Reader`] over a [`Buf`] from the [`bytes`] crate, which allows for
+/// decoding from buffers which aren't contiguous.
+///
+/// Bytes are read directly out of the chunks of the buffer where possible,
+/// and only copied when a read spans multiple chunks. Since nothing can be
+/// borrowed from the buffer, this can only be used to decode owned values.
+///
+/// [`Buf`]: bytes::Buf
+/// [`bytes`]: https://docs.rs/bytes
+///
+/// # Examples
+///
+/// ```
+/// use bytes::Buf;
+/// use musli::reader::BufReader;
+///
+/// let first = musli::wire::to_vec(&String::from("Aristotle"))?;
+/// let (head, tail) = first.split_at(4);
+///
+/// let mut buf = head.chain(tail);
+/// let value: String = musli::wire::decode(&mut BufReader::new(&mut buf))?;
+/// assert_eq!(value, "Aristotle");
+/// assert!(!buf.has_remaining());
+/// # Ok::<_, musli::wire::Error>(())
+/// ```
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+pub struct BufReader<B> {
+    buf: B,
+}
+
+#[cfg(feature = "bytes")]
+impl<B> BufReader<B>
+where
+    B: bytes::Buf,
+{
+    /// Construct a new reader around the given buffer.
+    #[inline]
+    pub fn new(buf: B) -> Self {
+        Self { buf }
+    }
+
+    /// Get the underlying buffer.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+
+    #[inline]
+    fn bounds_check<C>(&self, cx: &C, n: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if self.buf.remaining() < n {
+            return Err(cx.custom(SliceUnderflow::new(n, self.buf.remaining())));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'de, B> Reader<'de> for BufReader<B>
+where
+    B: bytes::Buf,
+{
+    type Mut<'this> = &'this mut Self where Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn skip<C>(&mut self, cx: &C, n: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.bounds_check(cx, n)?;
+        self.buf.advance(n);
+        cx.advance(n);
+        Ok(())
+    }
+
+    #[inline]
+    fn read_bytes<C, V>(&mut self, cx: &C, n: usize, visitor: V) -> Result<V::Ok, C::Error>
+    where
+        C: ?Sized + Context,
+        V: UnsizedVisitor<'de, C, [u8]>,
+    {
+        self.bounds_check(cx, n)?;
+        cx.advance(n);
+
+        if let Some(chunk) = self.buf.chunk().get(..n) {
+            let ok = visitor.visit_ref(cx, chunk)?;
+            self.buf.advance(n);
+            return Ok(ok);
+        }
+
+        let mut bytes = rust_alloc::vec![0; n];
+        self.buf.copy_to_slice(&mut bytes);
+        visitor.visit_owned(cx, bytes)
+    }
+
+    #[inline]
+    fn peek(&mut self) -> Option<u8> {
+        self.buf.chunk().first().copied()
+    }
+
+    #[inline]
+    fn read<C>(&mut self, cx: &C, buf: &mut [u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.bounds_check(cx, buf.len())?;
+        self.buf.copy_to_slice(buf);
+        cx.advance(buf.len());
+        Ok(())
+    }
+}
+
 /// Underflow when trying to read from a slice.
 #[derive(Debug)]
 pub(crate) struct SliceUnderflow {
@@ -0,0 +1,446 @@
+use core::fmt;
+
+use rust_alloc::string::String;
+use rust_alloc::vec::Vec;
+
+use super::{Enum, Name, Schema, Struct};
+
+/// The format to check compatibility for.
+///
+/// Compatibility is checked under the assumption that the format uses its
+/// default options, such as variable-length integer encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Format {
+    /// The [`storage`] format.
+    ///
+    /// [`storage`]: crate::storage
+    Storage,
+    /// The [`wire`] format.
+    ///
+    /// [`wire`]: crate::wire
+    Wire,
+    /// The [`descriptive`] format.
+    ///
+    /// [`descriptive`]: crate::descriptive
+    Descriptive,
+    /// The [`json`] format.
+    ///
+    /// [`json`]: crate::json
+    Json,
+}
+
+impl Format {
+    /// Test if the format can skip over unknown fields and tolerates missing
+    /// fields with default values.
+    #[inline]
+    fn is_upgrade_stable(self) -> bool {
+        !matches!(self, Format::Storage)
+    }
+
+    /// Test if the format permits decoding a number into a wider number of
+    /// the same signedness.
+    ///
+    /// Self-descriptive formats check the value of a number rather than the
+    /// width it was encoded with. Storage doesn't describe numbers at all, and
+    /// is commonly configured to encode them with a fixed width.
+    #[inline]
+    fn permits_widening(self) -> bool {
+        !matches!(self, Format::Storage)
+    }
+}
+
+/// The direction in which data is exchanged for an [`Issue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Data encoded with the old schema cannot be decoded with the new schema.
+    Backward,
+    /// Data encoded with the new schema cannot be decoded with the old schema.
+    Forward,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Backward => write!(f, "old data decoded as new"),
+            Direction::Forward => write!(f, "new data decoded as old"),
+        }
+    }
+}
+
+/// The kind of an [`Issue`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IssueKind {
+    /// The type of a value has changed in an incompatible way.
+    TypeChanged {
+        /// The kind of type which is encoded.
+        encoded: &'static str,
+        /// The kind of type which is decoded.
+        decoded: &'static str,
+    },
+    /// A field which doesn't have a default value is missing from the encoded
+    /// data.
+    MissingField(Name),
+    /// The fields of a struct which requires a fixed layout have changed.
+    FieldsChanged,
+    /// A variant is present in the encoded data which the decoder doesn't know
+    /// about, and there is no fallback variant.
+    UnknownVariant(Name),
+    /// The number of elements in a tuple has changed.
+    TupleLength {
+        /// The number of encoded elements.
+        encoded: usize,
+        /// The number of decoded elements.
+        decoded: usize,
+    },
+    /// The container attributes such as `#[musli(packed)]` or
+    /// `#[musli(transparent)]` have changed.
+    LayoutChanged,
+}
+
+impl fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IssueKind::TypeChanged { encoded, decoded } => {
+                write!(f, "encoded {encoded} cannot be decoded as {decoded}")
+            }
+            IssueKind::MissingField(name) => {
+                write!(f, "field `{name}` is missing and has no default")
+            }
+            IssueKind::FieldsChanged => {
+                write!(f, "fields changed in a container with a fixed layout")
+            }
+            IssueKind::UnknownVariant(name) => {
+                write!(f, "variant `{name}` is unknown and there is no fallback")
+            }
+            IssueKind::TupleLength { encoded, decoded } => {
+                write!(
+                    f,
+                    "tuple of length {encoded} cannot be decoded as length {decoded}"
+                )
+            }
+            IssueKind::LayoutChanged => {
+                write!(f, "container layout attributes changed")
+            }
+        }
+    }
+}
+
+/// A single compatibility issue found by [`check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Issue {
+    /// The direction in which the issue occurs.
+    pub direction: Direction,
+    /// The path to the value with the issue, like `Person.name`.
+    pub path: String,
+    /// The kind of issue.
+    pub kind: IssueKind,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} ({})", self.path, self.kind, self.direction)
+    }
+}
+
+/// The outcome of comparing two schemas using [`check`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    issues: Vec<Issue>,
+}
+
+impl Report {
+    /// Test if the two schemas are both backward and forward compatible.
+    #[inline]
+    pub fn is_compatible(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Test if data encoded with the old schema can be decoded with the new
+    /// schema.
+    #[inline]
+    pub fn is_backward_compatible(&self) -> bool {
+        !self
+            .issues
+            .iter()
+            .any(|issue| issue.direction == Direction::Backward)
+    }
+
+    /// Test if data encoded with the new schema can be decoded with the old
+    /// schema.
+    #[inline]
+    pub fn is_forward_compatible(&self) -> bool {
+        !self
+            .issues
+            .iter()
+            .any(|issue| issue.direction == Direction::Forward)
+    }
+
+    /// Get the issues found.
+    #[inline]
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.issues.is_empty() {
+            return write!(f, "compatible");
+        }
+
+        for issue in &self.issues {
+            writeln!(f, "{issue}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Check if the `new` schema is wire-compatible with the `old` schema using
+/// the given [`Format`].
+///
+/// This checks compatibility in both directions, so that data encoded with the
+/// old schema can be decoded with the new one and vice versa. Use
+/// [`Report::is_backward_compatible`] and [`Report::is_forward_compatible`] to
+/// only consider one of the directions.
+///
+/// # Examples
+///
+/// ```
+/// use musli::schema::{self, Enum, Format, Schema, Struct, Variant};
+///
+/// let old = Schema::Enum(Enum::new("Animal", [
+///     Variant::new("Cat", Struct::new("Cat", [])),
+/// ]));
+///
+/// let new = Schema::Enum(Enum::new("Animal", [
+///     Variant::new("Cat", Struct::new("Cat", [])),
+///     Variant::new("Dog", Struct::new("Dog", [])),
+/// ]));
+///
+/// let report = schema::check(Format::Wire, &old, &new);
+///
+/// // Old data can always be decoded since it only contains `Cat`, but old
+/// // decoders don't know what to do with `Dog`.
+/// assert!(report.is_backward_compatible());
+/// assert!(!report.is_forward_compatible());
+/// ```
+pub fn check(format: Format, old: &Schema, new: &Schema) -> Report {
+    let mut cx = Checker {
+        format,
+        direction: Direction::Backward,
+        path: String::new(),
+        issues: Vec::new(),
+    };
+
+    cx.schema(old, new);
+    cx.direction = Direction::Forward;
+    cx.schema(new, old);
+
+    Report { issues: cx.issues }
+}
+
+struct Checker {
+    format: Format,
+    direction: Direction,
+    path: String,
+    issues: Vec<Issue>,
+}
+
+impl Checker {
+    fn issue(&mut self, kind: IssueKind) {
+        self.issues.push(Issue {
+            direction: self.direction,
+            path: self.path.clone(),
+            kind,
+        });
+    }
+
+    /// Run `f` with the given segment appended to the current path.
+    fn with_path<F>(&mut self, segment: fmt::Arguments<'_>, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        use core::fmt::Write;
+
+        let len = self.path.len();
+        _ = self.path.write_fmt(segment);
+        f(self);
+        self.path.truncate(len);
+    }
+
+    /// Run `f` with the path rooted at the named type, unless we are already
+    /// nested inside of another type.
+    fn with_root<F>(&mut self, name: &str, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        if self.path.is_empty() {
+            self.with_path(format_args!("{name}"), f);
+        } else {
+            f(self);
+        }
+    }
+
+    /// Check that data encoded using `encoded` can be decoded using `decoded`.
+    fn schema(&mut self, encoded: &Schema, decoded: &Schema) {
+        match (encoded, decoded) {
+            (Schema::Option(encoded), Schema::Option(decoded)) => {
+                self.with_path(format_args!("?"), |cx| cx.schema(encoded, decoded));
+            }
+            (Schema::Sequence(encoded), Schema::Sequence(decoded)) => {
+                self.with_path(format_args!("[]"), |cx| cx.schema(encoded, decoded));
+            }
+            (Schema::Map(encoded), Schema::Map(decoded)) => {
+                self.with_path(format_args!("{{key}}"), |cx| {
+                    cx.schema(&encoded.0, &decoded.0)
+                });
+                self.with_path(format_args!("{{value}}"), |cx| {
+                    cx.schema(&encoded.1, &decoded.1)
+                });
+            }
+            (Schema::Tuple(encoded), Schema::Tuple(decoded)) => {
+                if encoded.len() != decoded.len() {
+                    self.issue(IssueKind::TupleLength {
+                        encoded: encoded.len(),
+                        decoded: decoded.len(),
+                    });
+                    return;
+                }
+
+                for (index, (encoded, decoded)) in encoded.iter().zip(decoded).enumerate() {
+                    self.with_path(format_args!(".{index}"), |cx| cx.schema(encoded, decoded));
+                }
+            }
+            (Schema::Struct(encoded), Schema::Struct(decoded)) => {
+                self.with_root(&decoded.name, |cx| cx.body(encoded, decoded));
+            }
+            (Schema::Enum(encoded), Schema::Enum(decoded)) => {
+                self.with_root(&decoded.name, |cx| cx.variants(encoded, decoded));
+            }
+            (encoded, decoded) => {
+                if encoded == decoded || self.is_widening(encoded, decoded) {
+                    return;
+                }
+
+                self.issue(IssueKind::TypeChanged {
+                    encoded: encoded.kind(),
+                    decoded: decoded.kind(),
+                });
+            }
+        }
+    }
+
+    fn body(&mut self, encoded: &Struct, decoded: &Struct) {
+        if encoded.packed != decoded.packed || encoded.transparent != decoded.transparent {
+            self.issue(IssueKind::LayoutChanged);
+            return;
+        }
+
+        if !self.format.is_upgrade_stable() || decoded.packed || decoded.transparent {
+            let names_changed = encoded.fields.len() != decoded.fields.len()
+                || encoded
+                    .fields
+                    .iter()
+                    .zip(&decoded.fields)
+                    .any(|(a, b)| a.name != b.name);
+
+            if names_changed {
+                self.issue(IssueKind::FieldsChanged);
+                return;
+            }
+
+            for (encoded, decoded) in encoded.fields.iter().zip(&decoded.fields) {
+                self.with_path(format_args!(".{}", decoded.name), |cx| {
+                    cx.schema(&encoded.schema, &decoded.schema)
+                });
+            }
+
+            return;
+        }
+
+        for field in &decoded.fields {
+            match encoded.fields.iter().find(|f| f.name == field.name) {
+                Some(encoded) => {
+                    self.with_path(format_args!(".{}", field.name), |cx| {
+                        cx.schema(&encoded.schema, &field.schema)
+                    });
+                }
+                None if !field.default => {
+                    self.issue(IssueKind::MissingField(field.name.clone()));
+                }
+                None => {}
+            }
+        }
+    }
+
+    fn variants(&mut self, encoded: &Enum, decoded: &Enum) {
+        for variant in &encoded.variants {
+            match decoded.variants.iter().find(|v| v.name == variant.name) {
+                Some(decoded) => {
+                    self.with_path(format_args!("::{}", variant.name), |cx| {
+                        cx.body(&variant.body, &decoded.body)
+                    });
+                }
+                None if decoded.fallback.is_none() || !self.format.is_upgrade_stable() => {
+                    self.issue(IssueKind::UnknownVariant(variant.name.clone()));
+                }
+                None => {}
+            }
+        }
+    }
+
+    /// Test if decoding `encoded` as `decoded` is a permitted numerical
+    /// widening.
+    fn is_widening(&self, encoded: &Schema, decoded: &Schema) -> bool {
+        if !self.format.permits_widening() {
+            return false;
+        }
+
+        // The wire format encodes `isize` as the bits of a `usize` rather than
+        // zigzag-encoding it like other signed numbers.
+        if self.format == Format::Wire
+            && matches!(encoded, Schema::Isize) != matches!(decoded, Schema::Isize)
+        {
+            return false;
+        }
+
+        match (unsigned_width(encoded), unsigned_width(decoded)) {
+            (Some(a), Some(b)) => return a <= b,
+            (None, None) => {}
+            _ => return false,
+        }
+
+        match (signed_width(encoded), signed_width(decoded)) {
+            (Some(a), Some(b)) => a <= b,
+            _ => false,
+        }
+    }
+}
+
+fn unsigned_width(schema: &Schema) -> Option<u32> {
+    match schema {
+        Schema::U8 => Some(8),
+        Schema::U16 => Some(16),
+        Schema::U32 => Some(32),
+        Schema::U64 => Some(64),
+        Schema::Usize => Some(usize::BITS),
+        Schema::U128 => Some(128),
+        _ => None,
+    }
+}
+
+fn signed_width(schema: &Schema) -> Option<u32> {
+    match schema {
+        Schema::I8 => Some(8),
+        Schema::I16 => Some(16),
+        Schema::I32 => Some(32),
+        Schema::I64 => Some(64),
+        Schema::Isize => Some(isize::BITS),
+        Schema::I128 => Some(128),
+        _ => None,
+    }
+}
@@ -0,0 +1,376 @@
+use core::fmt::{self, Write};
+
+use rust_alloc::collections::BTreeSet;
+use rust_alloc::string::String;
+use rust_alloc::vec::Vec;
+
+use super::{Enum, Field, Name, Schema, Struct};
+
+/// Keywords which need to be escaped when used as identifiers.
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while",
+    "yield",
+];
+
+/// Generate Rust type definitions for every struct and enum in the given
+/// [`Schema`].
+///
+/// The generated types derive [`Encode`] and [`Decode`] with the attributes
+/// needed to produce the same encoding as the type which the schema was
+/// constructed from. Types are emitted in the order in which they are first
+/// encountered, and each named type is only emitted once.
+///
+/// [`Encode`]: crate::Encode
+/// [`Decode`]: crate::Decode
+///
+/// # Examples
+///
+/// ```
+/// use musli::schema::{self, Field, Schema, Struct};
+///
+/// let person = Schema::Struct(Struct::new("Person", [
+///     Field::new("name", Schema::String),
+///     Field::new("age", Schema::Option(Box::new(Schema::U32))).with_default(),
+/// ]));
+///
+/// let code = schema::to_rust(&person);
+///
+/// assert!(code.contains("pub struct Person {"));
+/// assert!(code.contains("#[musli(default)]"));
+/// assert!(code.contains("pub age: Option<u32>,"));
+/// ```
+pub fn to_rust(schema: &Schema) -> String {
+    let mut g = Generator {
+        out: String::new(),
+        seen: BTreeSet::new(),
+        map: false,
+    };
+
+    let mut body = String::new();
+    g.collect(&mut body, schema);
+
+    let mut out = String::new();
+    out.push_str("use musli::{Decode, Encode};\n");
+
+    if g.map {
+        out.push_str("use std::collections::BTreeMap;\n");
+    }
+
+    out.push_str(&body);
+    out
+}
+
+struct Generator {
+    out: String,
+    seen: BTreeSet<String>,
+    map: bool,
+}
+
+impl Generator {
+    /// Walk the schema and emit definitions for all named types.
+    fn collect(&mut self, out: &mut String, schema: &Schema) {
+        match schema {
+            Schema::Option(schema) | Schema::Sequence(schema) => {
+                self.collect(out, schema);
+            }
+            Schema::Tuple(schemas) => {
+                for schema in schemas {
+                    self.collect(out, schema);
+                }
+            }
+            Schema::Map(pair) => {
+                self.map = true;
+                self.collect(out, &pair.0);
+                self.collect(out, &pair.1);
+            }
+            Schema::Struct(st) => {
+                if !self.seen.insert(st.name.clone()) {
+                    return;
+                }
+
+                _ = self.emit_struct(out, st);
+
+                for field in &st.fields {
+                    self.collect(out, &field.schema);
+                }
+            }
+            Schema::Enum(en) => {
+                if !self.seen.insert(en.name.clone()) {
+                    return;
+                }
+
+                _ = self.emit_enum(out, en);
+
+                for variant in &en.variants {
+                    for field in &variant.body.fields {
+                        self.collect(out, &field.schema);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn emit_struct(&mut self, out: &mut String, st: &Struct) -> fmt::Result {
+        writeln!(out)?;
+        writeln!(out, "#[derive(Debug, Clone, PartialEq, Encode, Decode)]")?;
+
+        let mut attrs = Vec::new();
+
+        if let Some(name_type) = name_type(&st.fields) {
+            attrs.push(name_type);
+        }
+
+        if st.packed {
+            attrs.push("packed");
+        }
+
+        if st.transparent {
+            attrs.push("transparent");
+        }
+
+        if !attrs.is_empty() {
+            writeln!(out, "#[musli({})]", attrs.join(", "))?;
+        }
+
+        writeln!(out, "pub struct {} {{", ident(&st.name))?;
+        self.emit_fields(out, &st.fields, "    ", "pub ")?;
+        writeln!(out, "}}")?;
+        Ok(())
+    }
+
+    fn emit_enum(&mut self, out: &mut String, en: &Enum) -> fmt::Result {
+        writeln!(out)?;
+        writeln!(out, "#[derive(Debug, Clone, PartialEq, Encode, Decode)]")?;
+
+        let names = en.variants.iter().map(|v| &v.name).collect::<Vec<_>>();
+
+        if names.iter().all(|n| matches!(n, Name::String(..))) && !names.is_empty() {
+            writeln!(out, "#[musli(name_type = str)]")?;
+        }
+
+        writeln!(out, "pub enum {} {{", ident(&en.name))?;
+
+        for (index, variant) in en.variants.iter().enumerate() {
+            let variant_ident = match &variant.name {
+                Name::String(name) => ident(name),
+                Name::Index(..) => ident(&variant.body.name),
+            };
+
+            let mut attrs = Vec::new();
+
+            if let Some(name) = explicit_name(&variant.name, &variant_ident, index) {
+                attrs.push(name);
+            }
+
+            if let Some(name_type) = name_type(&variant.body.fields) {
+                attrs.push(String::from(name_type));
+            }
+
+            if variant.body.packed {
+                attrs.push(String::from("packed"));
+            }
+
+            if variant.body.transparent {
+                attrs.push(String::from("transparent"));
+            }
+
+            if en.fallback.as_ref() == Some(&variant.name) {
+                attrs.push(String::from("default"));
+            }
+
+            if !attrs.is_empty() {
+                writeln!(out, "    #[musli({})]", attrs.join(", "))?;
+            }
+
+            if variant.body.fields.is_empty() {
+                writeln!(out, "    {variant_ident},")?;
+            } else {
+                writeln!(out, "    {variant_ident} {{")?;
+                self.emit_fields(out, &variant.body.fields, "        ", "")?;
+                writeln!(out, "    }},")?;
+            }
+        }
+
+        writeln!(out, "}}")?;
+        Ok(())
+    }
+
+    fn emit_fields(
+        &mut self,
+        out: &mut String,
+        fields: &[Field],
+        indent: &str,
+        vis: &str,
+    ) -> fmt::Result {
+        for (index, field) in fields.iter().enumerate() {
+            let field_ident = match &field.name {
+                Name::String(name) => snake_ident(name),
+                Name::Index(index) => rust_alloc::format!("field{index}"),
+            };
+
+            let mut attrs = Vec::new();
+
+            if let Some(name) = explicit_name(&field.name, &field_ident, index) {
+                attrs.push(name);
+            }
+
+            if field.default {
+                attrs.push(String::from("default"));
+            }
+
+            if matches!(field.schema, Schema::Bytes) {
+                attrs.push(String::from("bytes"));
+            }
+
+            if !attrs.is_empty() {
+                writeln!(out, "{indent}#[musli({})]", attrs.join(", "))?;
+            }
+
+            self.out.clear();
+            self.ty(&field.schema)?;
+            writeln!(out, "{indent}{vis}{field_ident}: {},", self.out)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the Rust type corresponding to the schema into `self.out`.
+    fn ty(&mut self, schema: &Schema) -> fmt::Result {
+        let name = match schema {
+            Schema::Unit => "()",
+            Schema::Bool => "bool",
+            Schema::Char => "char",
+            Schema::U8 => "u8",
+            Schema::U16 => "u16",
+            Schema::U32 => "u32",
+            Schema::U64 => "u64",
+            Schema::U128 => "u128",
+            Schema::I8 => "i8",
+            Schema::I16 => "i16",
+            Schema::I32 => "i32",
+            Schema::I64 => "i64",
+            Schema::I128 => "i128",
+            Schema::Usize => "usize",
+            Schema::Isize => "isize",
+            Schema::F32 => "f32",
+            Schema::F64 => "f64",
+            Schema::String => "String",
+            Schema::Bytes => "Vec<u8>",
+            Schema::Option(schema) => {
+                self.out.push_str("Option<");
+                self.ty(schema)?;
+                self.out.push('>');
+                return Ok(());
+            }
+            Schema::Sequence(schema) => {
+                self.out.push_str("Vec<");
+                self.ty(schema)?;
+                self.out.push('>');
+                return Ok(());
+            }
+            Schema::Tuple(schemas) => {
+                self.out.push('(');
+
+                for (n, schema) in schemas.iter().enumerate() {
+                    if n > 0 {
+                        self.out.push_str(", ");
+                    }
+
+                    self.ty(schema)?;
+                }
+
+                if schemas.len() == 1 {
+                    self.out.push(',');
+                }
+
+                self.out.push(')');
+                return Ok(());
+            }
+            Schema::Map(pair) => {
+                self.out.push_str("BTreeMap<");
+                self.ty(&pair.0)?;
+                self.out.push_str(", ");
+                self.ty(&pair.1)?;
+                self.out.push('>');
+                return Ok(());
+            }
+            Schema::Struct(st) => {
+                self.out.push_str(&ident(&st.name));
+                return Ok(());
+            }
+            Schema::Enum(en) => {
+                self.out.push_str(&ident(&en.name));
+                return Ok(());
+            }
+        };
+
+        self.out.push_str(name);
+        Ok(())
+    }
+}
+
+/// Determine the container-level `name_type` needed for the given fields.
+fn name_type(fields: &[Field]) -> Option<&'static str> {
+    if !fields.is_empty() && fields.iter().all(|f| matches!(f.name, Name::String(..))) {
+        return Some("name_type = str");
+    }
+
+    None
+}
+
+/// Determine if an explicit `name` attribute is needed, which is the case if
+/// the name would differ from the one derived by default.
+fn explicit_name(name: &Name, ident: &str, index: usize) -> Option<String> {
+    match name {
+        Name::Index(n) if *n != index => Some(rust_alloc::format!("name = {n}")),
+        Name::String(name) if name != ident.trim_start_matches("r#") => {
+            Some(rust_alloc::format!("name = {name:?}"))
+        }
+        _ => None,
+    }
+}
+
+/// Convert a name into a valid identifier.
+fn ident(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' {
+            out.push(c);
+        } else {
+            out.push('_');
+        }
+    }
+
+    if out.is_empty() || out.starts_with(|c: char| c.is_numeric()) {
+        out.insert(0, '_');
+    }
+
+    if KEYWORDS.contains(&out.as_str()) {
+        out.insert_str(0, "r#");
+    }
+
+    out
+}
+
+/// Convert a name into a valid snake case field identifier.
+fn snake_ident(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+
+    for (n, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if n > 0 {
+                out.push('_');
+            }
+
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+
+    ident(&out)
+}
@@ -0,0 +1,216 @@
+use rust_alloc::borrow::{Cow, ToOwned};
+use rust_alloc::boxed::Box;
+use rust_alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use rust_alloc::rc::Rc;
+use rust_alloc::string::String;
+use rust_alloc::sync::Arc;
+use rust_alloc::vec;
+use rust_alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use super::Schema;
+
+/// Trait implemented by types which can describe their own [`Schema`].
+///
+/// # Examples
+///
+/// ```
+/// use musli::schema::{Described, Field, Schema, Struct};
+///
+/// struct Person {
+///     name: String,
+///     age: Option<u32>,
+/// }
+///
+/// impl Described for Person {
+///     fn schema() -> Schema {
+///         Schema::Struct(Struct::new("Person", [
+///             Field::new(0usize, String::schema()),
+///             Field::new(1usize, <Option<u32>>::schema()).with_default(),
+///         ]))
+///     }
+/// }
+///
+/// assert!(matches!(Person::schema(), Schema::Struct(..)));
+/// ```
+pub trait Described {
+    /// Construct the schema of the type.
+    fn schema() -> Schema;
+}
+
+macro_rules! primitive {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl Described for $ty {
+                #[inline]
+                fn schema() -> Schema {
+                    Schema::$variant
+                }
+            }
+        )*
+    };
+}
+
+primitive! {
+    () => Unit,
+    bool => Bool,
+    char => Char,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+    usize => Usize,
+    isize => Isize,
+    f32 => F32,
+    f64 => F64,
+    str => String,
+    String => String,
+}
+
+macro_rules! wrapper {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl<T> Described for $ty<T>
+            where
+                T: ?Sized + Described,
+            {
+                #[inline]
+                fn schema() -> Schema {
+                    T::schema()
+                }
+            }
+        )*
+    };
+}
+
+wrapper!(Box, Rc, Arc);
+
+impl<T> Described for &T
+where
+    T: ?Sized + Described,
+{
+    #[inline]
+    fn schema() -> Schema {
+        T::schema()
+    }
+}
+
+impl<T> Described for Cow<'_, T>
+where
+    T: ?Sized + ToOwned + Described,
+{
+    #[inline]
+    fn schema() -> Schema {
+        T::schema()
+    }
+}
+
+impl<T> Described for Option<T>
+where
+    T: Described,
+{
+    #[inline]
+    fn schema() -> Schema {
+        Schema::Option(Box::new(T::schema()))
+    }
+}
+
+macro_rules! sequence {
+    ($($(#[$meta:meta])* $ty:ident),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            impl<T> Described for $ty<T>
+            where
+                T: Described,
+            {
+                #[inline]
+                fn schema() -> Schema {
+                    Schema::Sequence(Box::new(T::schema()))
+                }
+            }
+        )*
+    };
+}
+
+sequence!(
+    Vec,
+    VecDeque,
+    BTreeSet,
+    #[cfg(feature = "std")]
+    HashSet
+);
+
+impl<T> Described for [T]
+where
+    T: Described,
+{
+    #[inline]
+    fn schema() -> Schema {
+        Schema::Sequence(Box::new(T::schema()))
+    }
+}
+
+impl<T, const N: usize> Described for [T; N]
+where
+    T: Described,
+{
+    #[inline]
+    fn schema() -> Schema {
+        Schema::Tuple(vec![T::schema(); N])
+    }
+}
+
+macro_rules! map {
+    ($($(#[$meta:meta])* $ty:ident),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            impl<K, V> Described for $ty<K, V>
+            where
+                K: Described,
+                V: Described,
+            {
+                #[inline]
+                fn schema() -> Schema {
+                    Schema::Map(Box::new((K::schema(), V::schema())))
+                }
+            }
+        )*
+    };
+}
+
+map!(
+    BTreeMap,
+    #[cfg(feature = "std")]
+    HashMap
+);
+
+macro_rules! tuple {
+    ($($ty:ident),*) => {
+        impl<$($ty,)*> Described for ($($ty,)*)
+        where
+            $($ty: Described,)*
+        {
+            #[inline]
+            fn schema() -> Schema {
+                Schema::Tuple(vec![$($ty::schema(),)*])
+            }
+        }
+    };
+}
+
+tuple!(T0);
+tuple!(T0, T1);
+tuple!(T0, T1, T2);
+tuple!(T0, T1, T2, T3);
+tuple!(T0, T1, T2, T3, T4);
+tuple!(T0, T1, T2, T3, T4, T5);
+tuple!(T0, T1, T2, T3, T4, T5, T6);
+tuple!(T0, T1, T2, T3, T4, T5, T6, T7);
@@ -0,0 +1,319 @@
+//! Type descriptors and compatibility checking.
+//!
+//! A [`Schema`] describes the shape of a type as it is seen by the [Müsli data
+//! model]. Two versions of a schema can be compared using [`check`] to
+//! determine if data encoded using one version can be decoded using the other
+//! for a given [`Format`].
+//!
+//! Schemas can themselves be encoded and decoded, so they can be stored
+//! alongside the data they describe or checked into a repository and compared
+//! as part of continuous integration. Types can provide their schema by
+//! implementing [`Described`], and [`to_rust`] generates Rust type definitions
+//! from a schema for contract-first workflows.
+//!
+//! ```
+//! use musli::schema::{self, Field, Format, Schema, Struct};
+//!
+//! let old = Schema::Struct(Struct::new("Person", [
+//!     Field::new("name", Schema::String),
+//! ]));
+//!
+//! let new = Schema::Struct(Struct::new("Person", [
+//!     Field::new("name", Schema::String),
+//!     Field::new("age", Schema::Option(Box::new(Schema::U32))).with_default(),
+//! ]));
+//!
+//! // Adding a field with a default value is compatible with every format that
+//! // supports missing fields.
+//! assert!(schema::check(Format::Wire, &old, &new).is_compatible());
+//!
+//! // But not with storage, which relies on fields being in a fixed order.
+//! assert!(!schema::check(Format::Storage, &old, &new).is_compatible());
+//! ```
+//!
+//! [Müsli data model]: crate::help::data_model
+
+#![cfg(feature = "alloc")]
+#![cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+
+mod check;
+mod codegen;
+mod described;
+
+#[doc(inline)]
+pub use self::check::{check, Direction, Format, Issue, IssueKind, Report};
+#[doc(inline)]
+pub use self::codegen::to_rust;
+#[doc(inline)]
+pub use self::described::Described;
+
+use core::fmt;
+
+use rust_alloc::boxed::Box;
+use rust_alloc::string::String;
+use rust_alloc::vec::Vec;
+
+use crate::{Decode, Encode};
+
+/// A description of a type in the [Müsli data model].
+///
+/// [Müsli data model]: crate::help::data_model
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[musli(crate)]
+#[non_exhaustive]
+pub enum Schema {
+    /// The unit type `()`.
+    Unit,
+    /// A boolean.
+    Bool,
+    /// A character.
+    Char,
+    /// An unsigned 8-bit integer.
+    U8,
+    /// An unsigned 16-bit integer.
+    U16,
+    /// An unsigned 32-bit integer.
+    U32,
+    /// An unsigned 64-bit integer.
+    U64,
+    /// An unsigned 128-bit integer.
+    U128,
+    /// A signed 8-bit integer.
+    I8,
+    /// A signed 16-bit integer.
+    I16,
+    /// A signed 32-bit integer.
+    I32,
+    /// A signed 64-bit integer.
+    I64,
+    /// A signed 128-bit integer.
+    I128,
+    /// An unsigned pointer-sized integer.
+    Usize,
+    /// A signed pointer-sized integer.
+    Isize,
+    /// A 32-bit float.
+    F32,
+    /// A 64-bit float.
+    F64,
+    /// A string.
+    String,
+    /// A byte array.
+    Bytes,
+    /// An optional value.
+    Option(Box<Schema>),
+    /// A sequence of values.
+    Sequence(Box<Schema>),
+    /// A fixed-size tuple of values.
+    Tuple(Vec<Schema>),
+    /// A map of keys to values.
+    Map(Box<(Schema, Schema)>),
+    /// A struct.
+    Struct(Struct),
+    /// An enum.
+    Enum(Enum),
+}
+
+impl Schema {
+    /// Get a human readable description of the kind of schema.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Schema::Unit => "unit",
+            Schema::Bool => "bool",
+            Schema::Char => "char",
+            Schema::U8 => "u8",
+            Schema::U16 => "u16",
+            Schema::U32 => "u32",
+            Schema::U64 => "u64",
+            Schema::U128 => "u128",
+            Schema::I8 => "i8",
+            Schema::I16 => "i16",
+            Schema::I32 => "i32",
+            Schema::I64 => "i64",
+            Schema::I128 => "i128",
+            Schema::Usize => "usize",
+            Schema::Isize => "isize",
+            Schema::F32 => "f32",
+            Schema::F64 => "f64",
+            Schema::String => "string",
+            Schema::Bytes => "bytes",
+            Schema::Option(..) => "option",
+            Schema::Sequence(..) => "sequence",
+            Schema::Tuple(..) => "tuple",
+            Schema::Map(..) => "map",
+            Schema::Struct(..) => "struct",
+            Schema::Enum(..) => "enum",
+        }
+    }
+}
+
+/// The name of a field or variant.
+///
+/// This corresponds to the `#[musli(name_type)]` of the container.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Encode, Decode)]
+#[musli(crate)]
+#[non_exhaustive]
+pub enum Name {
+    /// A numerical index.
+    Index(usize),
+    /// A string name.
+    String(String),
+}
+
+impl From<usize> for Name {
+    #[inline]
+    fn from(index: usize) -> Self {
+        Name::Index(index)
+    }
+}
+
+impl From<&str> for Name {
+    #[inline]
+    fn from(name: &str) -> Self {
+        Name::String(String::from(name))
+    }
+}
+
+impl From<String> for Name {
+    #[inline]
+    fn from(name: String) -> Self {
+        Name::String(name)
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Name::Index(index) => index.fmt(f),
+            Name::String(name) => name.fmt(f),
+        }
+    }
+}
+
+/// The description of a struct, or the body of an enum variant.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[musli(crate)]
+#[non_exhaustive]
+pub struct Struct {
+    /// The name of the type.
+    pub name: String,
+    /// The fields of the struct, in the order in which they are encoded.
+    pub fields: Vec<Field>,
+    /// If the struct is `#[musli(packed)]`.
+    pub packed: bool,
+    /// If the struct is `#[musli(transparent)]`.
+    pub transparent: bool,
+}
+
+impl Struct {
+    /// Construct a new struct description with the given fields.
+    pub fn new<I>(name: impl Into<String>, fields: I) -> Self
+    where
+        I: IntoIterator<Item = Field>,
+    {
+        Self {
+            name: name.into(),
+            fields: fields.into_iter().collect(),
+            packed: false,
+            transparent: false,
+        }
+    }
+
+    /// Mark the struct as `#[musli(packed)]`.
+    pub fn with_packed(mut self) -> Self {
+        self.packed = true;
+        self
+    }
+
+    /// Mark the struct as `#[musli(transparent)]`.
+    pub fn with_transparent(mut self) -> Self {
+        self.transparent = true;
+        self
+    }
+}
+
+/// The description of a field.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[musli(crate)]
+#[non_exhaustive]
+pub struct Field {
+    /// The name of the field as it is encoded.
+    pub name: Name,
+    /// The schema of the field.
+    pub schema: Schema,
+    /// If the field has a `#[musli(default)]` attribute, and can be omitted
+    /// when decoding.
+    pub default: bool,
+}
+
+impl Field {
+    /// Construct a new field description.
+    pub fn new(name: impl Into<Name>, schema: Schema) -> Self {
+        Self {
+            name: name.into(),
+            schema,
+            default: false,
+        }
+    }
+
+    /// Mark the field as `#[musli(default)]`.
+    pub fn with_default(mut self) -> Self {
+        self.default = true;
+        self
+    }
+}
+
+/// The description of an enum.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[musli(crate)]
+#[non_exhaustive]
+pub struct Enum {
+    /// The name of the type.
+    pub name: String,
+    /// The variants of the enum.
+    pub variants: Vec<Variant>,
+    /// The name of the variant marked as `#[musli(default)]`, which unknown
+    /// variants are decoded into.
+    pub fallback: Option<Name>,
+}
+
+impl Enum {
+    /// Construct a new enum description with the given variants.
+    pub fn new<I>(name: impl Into<String>, variants: I) -> Self
+    where
+        I: IntoIterator<Item = Variant>,
+    {
+        Self {
+            name: name.into(),
+            variants: variants.into_iter().collect(),
+            fallback: None,
+        }
+    }
+
+    /// Set the fallback variant of the enum.
+    pub fn with_fallback(mut self, name: impl Into<Name>) -> Self {
+        self.fallback = Some(name.into());
+        self
+    }
+}
+
+/// The description of an enum variant.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[musli(crate)]
+#[non_exhaustive]
+pub struct Variant {
+    /// The name of the variant as it is encoded.
+    pub name: Name,
+    /// The body of the variant.
+    pub body: Struct,
+}
+
+impl Variant {
+    /// Construct a new variant description.
+    pub fn new(name: impl Into<Name>, body: Struct) -> Self {
+        Self {
+            name: name.into(),
+            body,
+        }
+    }
+}
@@ -39,6 +39,22 @@
 //! }
 //! ```
 //!
+//! Values which are not fields can be wrapped in [`Serde`], which implements
+//! [`Encode`] and [`Decode`] for any serde type. This makes it possible to
+//! write them directly through any Müsli format:
+//!
+//! ```
+//! use musli::serde::Serde;
+//! use url::Url;
+//!
+//! let url = Url::parse("https://example.com")?;
+//!
+//! let bytes = musli::wire::to_vec(&Serde(&url))?;
+//! let Serde(decoded) = musli::wire::from_slice::<Serde<Url>>(&bytes)?;
+//! assert_eq!(decoded, url);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
 //! A compatible Müsli structure would look like this:
 //!
 //! ```
@@ -104,7 +120,7 @@ use self::serializer::Serializer;
 
 use crate::alloc::{self, String};
 use crate::no_std;
-use crate::{Context, Decoder, Encoder};
+use crate::{Context, Decode, Decoder, Encode, Encoder};
 
 struct SerdeContext<'a, C>
 where
@@ -167,6 +183,21 @@ where
         *self.error.borrow_mut() = Some(self.inner.message(message));
         error::SerdeError::Captured
     }
+
+    #[inline]
+    fn enter_depth(&self) -> Result<(), Self::Error> {
+        if let Err(error) = self.inner.enter_depth() {
+            *self.error.borrow_mut() = Some(error);
+            return Err(error::SerdeError::Captured);
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn leave_depth(&self) {
+        self.inner.leave_depth();
+    }
 }
 
 /// Encode the given serde value `T` to the given [Encoder] using the serde
@@ -232,3 +263,62 @@ where
 
     Err(error)
 }
+
+/// Wrapper which implements [`Encode`] and [`Decode`] for any type which
+/// implements [`Serialize`] or [`Deserialize`], using the serde compatibility
+/// layer.
+///
+/// This is the equivalent of using `#[musli(with = musli::serde)]` on a field,
+/// but can be used anywhere a Müsli type is expected.
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Serialize, Deserialize};
+/// use musli::serde::Serde;
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Address {
+///     street: String,
+///     zip: u32,
+/// }
+///
+/// let address = Address {
+///     street: "Main St.".to_string(),
+///     zip: 12345,
+/// };
+///
+/// let bytes = musli::storage::to_vec(&Serde(&address))?;
+/// let Serde(decoded) = musli::storage::from_slice::<Serde<Address>>(&bytes)?;
+/// assert_eq!(decoded, address);
+/// # Ok::<_, musli::storage::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Serde<T>(pub T);
+
+impl<M, T> Encode<M> for Serde<T>
+where
+    T: Serialize,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encode(&self.0, cx, encoder)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Serde<T>
+where
+    T: Deserialize<'de>,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        Ok(Self(decode(cx, decoder)?))
+    }
+}
@@ -4,8 +4,13 @@ use core::fmt;
 use crate::en::{EntriesEncoder, EntryEncoder, MapEncoder, SequenceEncoder, VariantEncoder};
 use crate::hint::{MapHint, SequenceHint};
 use crate::mode::Text;
+#[cfg(all(feature = "value", feature = "alloc"))]
+use crate::value::{self, Value};
 use crate::{Context, Encoder};
 
+#[cfg(all(feature = "value", feature = "alloc"))]
+use rust_alloc::vec::Vec;
+
 use serde::ser::{self, Serialize};
 
 pub struct Serializer<'a, E>
@@ -34,11 +39,11 @@ where
     type Ok = E::Ok;
     type Error = <E::Cx as Context>::Error;
 
-    type SerializeSeq = SerializeSeq<'a, E::EncodeSequence>;
+    type SerializeSeq = SerializeSeqAny<'a, E>;
     type SerializeTuple = SerializeSeq<'a, E::EncodeSequence>;
     type SerializeTupleStruct = SerializeSeq<'a, E::EncodeSequence>;
     type SerializeTupleVariant = SerializeSeq<'a, E::EncodeSequenceVariant>;
-    type SerializeMap = SerializeMap<'a, E::EncodeMapEntries>;
+    type SerializeMap = SerializeMapAny<'a, E>;
     type SerializeStruct = SerializeStruct<'a, E::EncodeMap>;
     type SerializeStructVariant = SerializeStructVariant<'a, E::EncodeMapVariant>;
 
@@ -189,14 +194,22 @@ where
     #[inline]
     fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
         let Some(len) = len else {
+            #[cfg(all(feature = "value", feature = "alloc"))]
+            return Ok(SerializeSeqAny::Buffered {
+                cx: self.cx,
+                encoder: self.encoder,
+                values: Vec::new(),
+            });
+
+            #[cfg(not(all(feature = "value", feature = "alloc")))]
             return Err(ser::Error::custom(
-                "Can only encode sequences with known lengths",
+                "Can only encode sequences with known lengths (enable the value feature to buffer them)",
             ));
         };
 
         let hint = SequenceHint::with_size(len);
         let encoder = self.encoder.encode_sequence(&hint)?;
-        Ok(SerializeSeq::new(self.cx, encoder))
+        Ok(SerializeSeqAny::Sized(SerializeSeq::new(self.cx, encoder)))
     }
 
     #[inline]
@@ -233,14 +246,22 @@ where
     #[inline]
     fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
         let Some(len) = len else {
-            return Err(self
-                .cx
-                .message("Can only serialize maps with known lengths"));
+            #[cfg(all(feature = "value", feature = "alloc"))]
+            return Ok(SerializeMapAny::Buffered {
+                cx: self.cx,
+                encoder: self.encoder,
+                entries: Vec::new(),
+            });
+
+            #[cfg(not(all(feature = "value", feature = "alloc")))]
+            return Err(self.cx.message(
+                "Can only serialize maps with known lengths (enable the value feature to buffer them)",
+            ));
         };
 
         let hint = MapHint::with_size(len);
         let encoder = self.encoder.encode_map_entries(&hint)?;
-        Ok(SerializeMap::new(self.cx, encoder))
+        Ok(SerializeMapAny::Sized(SerializeMap::new(self.cx, encoder)))
     }
 
     #[inline]
@@ -411,6 +432,56 @@ where
     }
 }
 
+/// Sequence serializer which buffers its elements as [`Value`]s if its length
+/// isn't known up front, since every Müsli format requires it.
+pub enum SerializeSeqAny<'a, E>
+where
+    E: Encoder,
+{
+    Sized(SerializeSeq<'a, E::EncodeSequence>),
+    #[cfg(all(feature = "value", feature = "alloc"))]
+    Buffered {
+        cx: &'a E::Cx,
+        encoder: E,
+        values: Vec<Value>,
+    },
+}
+
+impl<'a, E> ser::SerializeSeq for SerializeSeqAny<'a, E>
+where
+    <E::Cx as Context>::Error: ser::Error,
+    E: Encoder,
+{
+    type Ok = E::Ok;
+    type Error = <E::Cx as Context>::Error;
+
+    #[inline]
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + ser::Serialize,
+    {
+        match self {
+            SerializeSeqAny::Sized(seq) => ser::SerializeSeq::serialize_element(seq, value),
+            #[cfg(all(feature = "value", feature = "alloc"))]
+            SerializeSeqAny::Buffered { cx, values, .. } => {
+                values.push(to_value(*cx, value)?);
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        match self {
+            SerializeSeqAny::Sized(seq) => ser::SerializeSeq::end(seq),
+            #[cfg(all(feature = "value", feature = "alloc"))]
+            SerializeSeqAny::Buffered {
+                encoder, values, ..
+            } => encoder.encode(&values),
+        }
+    }
+}
+
 pub struct SerializeMap<'a, E>
 where
     E: EntriesEncoder,
@@ -462,6 +533,99 @@ where
     }
 }
 
+/// Map serializer which buffers its entries as [`Value`]s if its length isn't
+/// known up front, such as when serde flattens structs.
+pub enum SerializeMapAny<'a, E>
+where
+    E: Encoder,
+{
+    Sized(SerializeMap<'a, E::EncodeMapEntries>),
+    #[cfg(all(feature = "value", feature = "alloc"))]
+    Buffered {
+        cx: &'a E::Cx,
+        encoder: E,
+        entries: Vec<(Value, Value)>,
+    },
+}
+
+impl<'a, E> ser::SerializeMap for SerializeMapAny<'a, E>
+where
+    <E::Cx as Context>::Error: ser::Error,
+    E: Encoder,
+{
+    type Ok = E::Ok;
+    type Error = <E::Cx as Context>::Error;
+
+    #[inline]
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + ser::Serialize,
+    {
+        match self {
+            SerializeMapAny::Sized(map) => ser::SerializeMap::serialize_key(map, key),
+            #[cfg(all(feature = "value", feature = "alloc"))]
+            SerializeMapAny::Buffered { cx, entries, .. } => {
+                entries.push((to_value(*cx, key)?, Value::Unit));
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + ser::Serialize,
+    {
+        match self {
+            SerializeMapAny::Sized(map) => ser::SerializeMap::serialize_value(map, value),
+            #[cfg(all(feature = "value", feature = "alloc"))]
+            SerializeMapAny::Buffered { cx, entries, .. } => {
+                let Some((_, slot)) = entries.last_mut() else {
+                    return Err(cx.message("Map value serialized before its key"));
+                };
+
+                *slot = to_value(*cx, value)?;
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        match self {
+            SerializeMapAny::Sized(map) => ser::SerializeMap::end(map),
+            #[cfg(all(feature = "value", feature = "alloc"))]
+            SerializeMapAny::Buffered {
+                encoder, entries, ..
+            } => {
+                let hint = MapHint::with_size(entries.len());
+
+                let mut map = encoder.encode_map_entries(&hint)?;
+
+                for (key, value) in &entries {
+                    map.encode_entry_key()?.encode(key)?;
+                    map.encode_entry_value()?.encode(value)?;
+                }
+
+                map.finish_entries()
+            }
+        }
+    }
+}
+
+/// Serialize a value into a buffered [`Value`].
+#[cfg(all(feature = "value", feature = "alloc"))]
+fn to_value<C, T>(cx: &C, value: &T) -> Result<Value, C::Error>
+where
+    C: ?Sized + Context,
+    C::Error: ser::Error,
+    T: ?Sized + ser::Serialize,
+{
+    let mut output = Value::Unit;
+    value.serialize(Serializer::new(cx, value::encoder(cx, &mut output)))?;
+    Ok(output)
+}
+
 pub struct SerializeStruct<'a, E>
 where
     E: MapEncoder,
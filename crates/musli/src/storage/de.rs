@@ -3,9 +3,10 @@ use core::fmt;
 #[cfg(feature = "alloc")]
 use rust_alloc::vec::Vec;
 
+use crate::context::nested;
 use crate::de::{
-    DecodeUnsized, Decoder, EntriesDecoder, EntryDecoder, MapDecoder, SequenceDecoder, SizeHint,
-    UnsizedVisitor, VariantDecoder,
+    DecodeUnsized, Decoder, EntriesDecoder, EntryDecoder, MapDecoder, PodSliceMut, SequenceDecoder,
+    SizeHint, UnsizedVisitor, VariantDecoder,
 };
 use crate::{Context, Decode, Options, Reader};
 
@@ -44,12 +45,12 @@ where
     type Error = C::Error;
     type Mode = C::Mode;
     type WithContext<'this, U> = StorageDecoder<'this, R, OPT, U> where U: 'this + Context;
-    type DecodePack = Self;
+    type DecodePack<'this> = Self;
     type DecodeSome = Self;
-    type DecodeSequence = LimitedStorageDecoder<'a, R, OPT, C>;
-    type DecodeMap = LimitedStorageDecoder<'a, R, OPT, C>;
-    type DecodeMapEntries = LimitedStorageDecoder<'a, R, OPT, C>;
-    type DecodeVariant = Self;
+    type DecodeSequence<'this> = LimitedStorageDecoder<'a, R, OPT, C>;
+    type DecodeMap<'this> = LimitedStorageDecoder<'a, R, OPT, C>;
+    type DecodeMapEntries<'this> = LimitedStorageDecoder<'a, R, OPT, C>;
+    type DecodeVariant<'this> = Self;
 
     fn cx(&self) -> &C {
         self.cx
@@ -102,9 +103,9 @@ where
     #[inline]
     fn decode_pack<F, O>(mut self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodePack) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodePack<'_>) -> Result<O, C::Error>,
     {
-        f(&mut self)
+        nested(self.cx, || f(&mut self))
     }
 
     #[inline]
@@ -112,6 +113,11 @@ where
         self.reader.read_array(self.cx)
     }
 
+    #[inline]
+    fn decode_array_slice(mut self, out: &mut [u8]) -> Result<(), C::Error> {
+        self.reader.read(self.cx, out)
+    }
+
     #[inline]
     fn decode_bytes<V>(mut self, visitor: V) -> Result<V::Ok, C::Error>
     where
@@ -126,9 +132,9 @@ where
     where
         V: UnsizedVisitor<'de, C, str>,
     {
-        struct Visitor<V>(V);
+        struct Visitor<V, M>(V, M);
 
-        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<V>
+        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<V, C::Mark>
         where
             C: ?Sized + Context,
             V: UnsizedVisitor<'de, C, str>,
@@ -143,24 +149,28 @@ where
             #[cfg(feature = "alloc")]
             #[inline]
             fn visit_owned(self, cx: &C, bytes: Vec<u8>) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8_owned(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8_owned(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_owned(cx, string)
             }
 
             #[inline]
             fn visit_borrowed(self, cx: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_borrowed(cx, string)
             }
 
             #[inline]
             fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_ref(cx, string)
             }
         }
 
-        self.decode_bytes(Visitor(visitor))
+        let mark = self.cx.mark();
+        self.decode_bytes(Visitor(visitor, mark))
     }
 
     #[inline]
@@ -271,11 +281,11 @@ where
     #[inline]
     fn decode_sequence<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeSequence) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeSequence<'_>) -> Result<O, C::Error>,
     {
         let cx = self.cx;
         let mut decoder = LimitedStorageDecoder::new(self.cx, self.reader)?;
-        let output = f(&mut decoder)?;
+        let output = nested(cx, || f(&mut decoder))?;
 
         if decoder.remaining != 0 {
             return Err(cx.message("Caller did not decode all available map entries"));
@@ -287,11 +297,11 @@ where
     #[inline]
     fn decode_map<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeMap) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeMap<'_>) -> Result<O, C::Error>,
     {
         let cx = self.cx;
         let mut decoder = LimitedStorageDecoder::new(self.cx, self.reader)?;
-        let output = f(&mut decoder)?;
+        let output = nested(cx, || f(&mut decoder))?;
 
         if decoder.remaining != 0 {
             return Err(cx.message("Caller did not decode all available map entries"));
@@ -303,7 +313,7 @@ where
     #[inline]
     fn decode_map_entries<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeMapEntries) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeMapEntries<'_>) -> Result<O, C::Error>,
     {
         self.decode_map(f)
     }
@@ -311,9 +321,9 @@ where
     #[inline]
     fn decode_variant<F, O>(mut self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeVariant) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeVariant<'_>) -> Result<O, C::Error>,
     {
-        f(&mut self)
+        nested(self.cx, || f(&mut self))
     }
 }
 
@@ -336,6 +346,16 @@ where
     fn decode_next(&mut self) -> Result<Self::DecodeNext<'_>, C::Error> {
         Ok(StorageDecoder::new(self.cx, self.reader.borrow_mut()))
     }
+
+    #[inline]
+    fn try_decode_pod_into(&mut self, mut out: PodSliceMut<'_>) -> Result<bool, C::Error> {
+        if out.width() != 1 && !crate::options::is_native_fixed::<OPT>() {
+            return Ok(false);
+        }
+
+        self.reader.read(self.cx, out.as_bytes_mut())?;
+        Ok(true)
+    }
 }
 
 impl<'a, 'de, R, const OPT: Options, C> LimitedStorageDecoder<'a, R, OPT, C>
@@ -397,6 +417,21 @@ where
 
         Ok(decoder)
     }
+
+    #[inline]
+    fn try_decode_pod_into(&mut self, mut out: PodSliceMut<'_>) -> Result<bool, C::Error> {
+        if out.width() != 1 && !crate::options::is_native_fixed::<OPT>() {
+            return Ok(false);
+        }
+
+        if out.len() > self.remaining {
+            return Err(self.cx.message("No remaining elements"));
+        }
+
+        self.remaining -= out.len();
+        self.reader.read(self.cx, out.as_bytes_mut())?;
+        Ok(true)
+    }
 }
 
 impl<'a, 'de, R, const OPT: Options, C: ?Sized + Context> MapDecoder<'de>
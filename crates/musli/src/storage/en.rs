@@ -1,7 +1,8 @@
 use core::fmt;
 
 use crate::en::{
-    Encode, Encoder, EntriesEncoder, EntryEncoder, MapEncoder, SequenceEncoder, VariantEncoder,
+    Encode, Encoder, EntriesEncoder, EntryEncoder, MapEncoder, PodSlice, SequenceEncoder,
+    VariantEncoder,
 };
 use crate::hint::{MapHint, SequenceHint};
 use crate::{Context, Options, Writer};
@@ -82,6 +83,11 @@ where
         self.writer.write_bytes(self.cx, array)
     }
 
+    #[inline]
+    fn encode_array_slice(mut self, array: &[u8]) -> Result<Self::Ok, C::Error> {
+        self.writer.write_bytes(self.cx, array)
+    }
+
     #[inline]
     fn encode_bytes(mut self, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
         crate::int::encode_usize::<_, _, OPT>(self.cx, self.writer.borrow_mut(), bytes.len())?;
@@ -281,6 +287,16 @@ where
     fn finish_sequence(self) -> Result<Self::Ok, C::Error> {
         Ok(())
     }
+
+    #[inline]
+    fn try_push_pod(&mut self, pod: PodSlice<'_>) -> Result<bool, C::Error> {
+        if pod.width() != 1 && !crate::options::is_native_fixed::<OPT>() {
+            return Ok(false);
+        }
+
+        self.writer.write_bytes(self.cx, pod.as_bytes())?;
+        Ok(true)
+    }
 }
 
 impl<'a, W, const OPT: Options, C> MapEncoder for StorageEncoder<'a, W, OPT, C>
@@ -0,0 +1,417 @@
+//! Transcoding between formats without going through a Rust type.
+//!
+//! The [`transcode()`] function walks a self-descriptive [`Decoder`] and
+//! forwards everything it encounters into an [`Encoder`]. Every encoding
+//! provides a `transcode` method, which constructs a [`Transcode`] value that
+//! can be encoded with any other encoding:
+//!
+//! ```
+//! use musli::{Decode, Encode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! struct Person {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! let bytes = musli::descriptive::to_vec(&Person {
+//!     name: String::from("Aristotle"),
+//!     age: 61,
+//! })?;
+//!
+//! let json = musli::json::to_string(&musli::descriptive::DEFAULT.transcode(&bytes))?;
+//! assert_eq!(json, r#"{"name":"Aristotle","age":61}"#);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! The source format must be self-descriptive, like [`descriptive`] or
+//! [`json`]. Transcoding from [`storage`] or [`wire`] results in an error, since
+//! they don't retain enough type information to be walked without a schema.
+//!
+//! [`descriptive`]: crate::descriptive
+//! [`json`]: crate::json
+//! [`storage`]: crate::storage
+//! [`wire`]: crate::wire
+
+use core::fmt;
+
+#[cfg(all(feature = "value", feature = "alloc"))]
+use rust_alloc::vec::Vec;
+
+use crate::de::{
+    Decoder, EntryDecoder, MapDecoder, SequenceDecoder, SizeHint, UnsizedVisitor, VariantDecoder,
+    Visitor,
+};
+use crate::en::{EntriesEncoder, SequenceEncoder, VariantEncoder};
+use crate::hint::{MapHint, SequenceHint};
+#[cfg(all(feature = "value", feature = "alloc"))]
+use crate::value::Value;
+use crate::{Context, Encode, Encoder};
+
+/// Transcode the value in `decoder` into `encoder`.
+///
+/// Sequences and maps are streamed directly into the encoder if the decoder
+/// knows their length up front. Otherwise they are buffered, which requires
+/// the `value` feature.
+///
+/// # Examples
+///
+/// ```
+/// use musli::{Encode, Encoder};
+/// use musli::de::AsDecoder;
+/// use musli::options::{self, Options};
+/// use musli::value::Value;
+///
+/// const OPTIONS: Options = options::new().build();
+///
+/// /// Encodes the value by walking its decoder.
+/// struct ThroughDecoder(Value);
+///
+/// impl<M> Encode<M> for ThroughDecoder {
+///     fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+///     where
+///         E: Encoder<Mode = M>,
+///     {
+///         let decoder = self.0.clone().into_value_decoder::<OPTIONS, _>(cx);
+///         musli::transcode::transcode(decoder.as_decoder()?, encoder)
+///     }
+/// }
+///
+/// let value: Value = musli::json::from_str(r#"{"tags": [1, 2, 3]}"#)?;
+/// let json = musli::json::to_string(&ThroughDecoder(value))?;
+/// assert_eq!(json, r#"{"tags":[1,2,3]}"#);
+/// # Ok::<_, musli::json::Error>(())
+/// ```
+pub fn transcode<'de, D, E>(decoder: D, encoder: E) -> Result<E::Ok, D::Error>
+where
+    D: Decoder<'de>,
+    E: Encoder<Cx = D::Cx>,
+{
+    decoder.decode_any(TranscodeVisitor { encoder })
+}
+
+/// An encoding which can act as the source of a [`Transcode`].
+///
+/// This is implemented by the `Encoding` type of every format.
+pub trait Source: Copy {
+    /// Transcode the value stored in `bytes` into `encoder`.
+    fn transcode<E>(self, cx: &E::Cx, bytes: &[u8], encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder;
+}
+
+/// A value which when encoded transcodes the value stored in a byte slice.
+///
+/// This is constructed through the `transcode` method on encodings, see the
+/// [module level documentation][self].
+pub struct Transcode<'de, S> {
+    source: S,
+    bytes: &'de [u8],
+}
+
+impl<'de, S> Transcode<'de, S> {
+    /// Construct a new transcoding value.
+    #[inline]
+    pub fn new(source: S, bytes: &'de [u8]) -> Self {
+        Self { source, bytes }
+    }
+}
+
+impl<M, S> Encode<M> for Transcode<'_, S>
+where
+    S: Source,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.source.transcode(cx, self.bytes, encoder)
+    }
+}
+
+struct TranscodeVisitor<E> {
+    encoder: E,
+}
+
+#[crate::visitor(crate)]
+impl<'de, C, E> Visitor<'de, C> for TranscodeVisitor<E>
+where
+    C: ?Sized + Context,
+    E: Encoder<Cx = C>,
+{
+    type Ok = E::Ok;
+    type String = TranscodeString<E>;
+    type Bytes = TranscodeBytes<E>;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "any value which can be transcoded")
+    }
+
+    #[inline]
+    fn visit_empty(self, _: &C) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_empty()
+    }
+
+    #[inline]
+    fn visit_bool(self, _: &C, value: bool) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_bool(value)
+    }
+
+    #[inline]
+    fn visit_char(self, _: &C, value: char) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_char(value)
+    }
+
+    #[inline]
+    fn visit_u8(self, _: &C, value: u8) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_u8(value)
+    }
+
+    #[inline]
+    fn visit_u16(self, _: &C, value: u16) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_u16(value)
+    }
+
+    #[inline]
+    fn visit_u32(self, _: &C, value: u32) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_u32(value)
+    }
+
+    #[inline]
+    fn visit_u64(self, _: &C, value: u64) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_u64(value)
+    }
+
+    #[inline]
+    fn visit_u128(self, _: &C, value: u128) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_u128(value)
+    }
+
+    #[inline]
+    fn visit_i8(self, _: &C, value: i8) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_i8(value)
+    }
+
+    #[inline]
+    fn visit_i16(self, _: &C, value: i16) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_i16(value)
+    }
+
+    #[inline]
+    fn visit_i32(self, _: &C, value: i32) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_i32(value)
+    }
+
+    #[inline]
+    fn visit_i64(self, _: &C, value: i64) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_i64(value)
+    }
+
+    #[inline]
+    fn visit_i128(self, _: &C, value: i128) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_i128(value)
+    }
+
+    #[inline]
+    fn visit_usize(self, _: &C, value: usize) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_usize(value)
+    }
+
+    #[inline]
+    fn visit_isize(self, _: &C, value: isize) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_isize(value)
+    }
+
+    #[inline]
+    fn visit_f32(self, _: &C, value: f32) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_f32(value)
+    }
+
+    #[inline]
+    fn visit_f64(self, _: &C, value: f64) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_f64(value)
+    }
+
+    #[inline]
+    fn visit_option<D>(self, _: &C, decoder: Option<D>) -> Result<Self::Ok, C::Error>
+    where
+        D: Decoder<'de, Cx = C, Error = C::Error, Mode = C::Mode>,
+    {
+        match decoder {
+            Some(decoder) => transcode(decoder, self.encoder.encode_some()?),
+            None => self.encoder.encode_none(),
+        }
+    }
+
+    #[inline]
+    fn visit_sequence<D>(self, cx: &C, seq: &mut D) -> Result<Self::Ok, C::Error>
+    where
+        D: ?Sized + SequenceDecoder<'de, Cx = C>,
+    {
+        let Some(len) = seq.size_hint().into_option() else {
+            #[cfg(all(feature = "value", feature = "alloc"))]
+            {
+                let mut values = Vec::<Value>::new();
+
+                while let Some(value) = seq.try_next()? {
+                    values.push(value);
+                }
+
+                let hint = SequenceHint::with_size(values.len());
+                let mut out = self.encoder.encode_sequence(&hint)?;
+
+                for value in &values {
+                    out.push(value)?;
+                }
+
+                return out.finish_sequence();
+            }
+
+            #[cfg(not(all(feature = "value", feature = "alloc")))]
+            return Err(
+                cx.message("Transcoding sequences of unknown length requires the value feature")
+            );
+        };
+
+        let hint = SequenceHint::with_size(len);
+        let mut out = self.encoder.encode_sequence(&hint)?;
+        let mut remaining = len;
+
+        while let Some(item) = seq.try_decode_next()? {
+            remaining = match remaining.checked_sub(1) {
+                Some(remaining) => remaining,
+                None => return Err(cx.message("Sequence is longer than its length")),
+            };
+
+            transcode(item, out.encode_next()?)?;
+        }
+
+        if remaining != 0 {
+            return Err(cx.message("Sequence is shorter than its length"));
+        }
+
+        out.finish_sequence()
+    }
+
+    #[inline]
+    fn visit_map<D>(self, cx: &C, map: &mut D) -> Result<Self::Ok, C::Error>
+    where
+        D: ?Sized + MapDecoder<'de, Cx = C>,
+    {
+        let Some(len) = map.size_hint().into_option() else {
+            #[cfg(all(feature = "value", feature = "alloc"))]
+            {
+                let mut entries = Vec::<(Value, Value)>::new();
+
+                while let Some(entry) = map.entry()? {
+                    entries.push(entry);
+                }
+
+                let hint = MapHint::with_size(entries.len());
+                let mut out = self.encoder.encode_map_entries(&hint)?;
+
+                for (key, value) in &entries {
+                    out.encode_entry_key()?.encode(key)?;
+                    out.encode_entry_value()?.encode(value)?;
+                }
+
+                return out.finish_entries();
+            }
+
+            #[cfg(not(all(feature = "value", feature = "alloc")))]
+            return Err(cx.message("Transcoding maps of unknown length requires the value feature"));
+        };
+
+        let hint = MapHint::with_size(len);
+        let mut out = self.encoder.encode_map_entries(&hint)?;
+        let mut remaining = len;
+
+        while let Some(mut entry) = map.decode_entry()? {
+            remaining = match remaining.checked_sub(1) {
+                Some(remaining) => remaining,
+                None => return Err(cx.message("Map is longer than its length")),
+            };
+
+            transcode(entry.decode_key()?, out.encode_entry_key()?)?;
+            transcode(entry.decode_value()?, out.encode_entry_value()?)?;
+        }
+
+        if remaining != 0 {
+            return Err(cx.message("Map is shorter than its length"));
+        }
+
+        out.finish_entries()
+    }
+
+    #[inline]
+    fn visit_string(self, _: &C, _: SizeHint) -> Result<Self::String, C::Error> {
+        Ok(TranscodeString {
+            encoder: self.encoder,
+        })
+    }
+
+    #[inline]
+    fn visit_bytes(self, _: &C, _: SizeHint) -> Result<Self::Bytes, C::Error> {
+        Ok(TranscodeBytes {
+            encoder: self.encoder,
+        })
+    }
+
+    #[inline]
+    fn visit_variant<D>(self, _: &C, variant: &mut D) -> Result<Self::Ok, C::Error>
+    where
+        D: VariantDecoder<'de, Cx = C>,
+    {
+        let mut out = self.encoder.encode_variant()?;
+        transcode(variant.decode_tag()?, out.encode_tag()?)?;
+        transcode(variant.decode_value()?, out.encode_data()?)?;
+        out.finish_variant()
+    }
+}
+
+struct TranscodeString<E> {
+    encoder: E,
+}
+
+impl<'de, C, E> UnsizedVisitor<'de, C, str> for TranscodeString<E>
+where
+    C: ?Sized + Context,
+    E: Encoder<Cx = C>,
+{
+    type Ok = E::Ok;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "string")
+    }
+
+    #[inline]
+    fn visit_ref(self, _: &C, string: &str) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_string(string)
+    }
+}
+
+struct TranscodeBytes<E> {
+    encoder: E,
+}
+
+impl<'de, C, E> UnsizedVisitor<'de, C, [u8]> for TranscodeBytes<E>
+where
+    C: ?Sized + Context,
+    E: Encoder<Cx = C>,
+{
+    type Ok = E::Ok;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bytes")
+    }
+
+    #[inline]
+    fn visit_ref(self, _: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+        self.encoder.encode_bytes(bytes)
+    }
+}
@@ -1,6 +1,8 @@
 use core::fmt;
 use core::slice;
 
+#[cfg(feature = "alloc")]
+use crate::context::nested;
 #[cfg(feature = "alloc")]
 use crate::de::UnsizedVisitor;
 use crate::de::{
@@ -89,11 +91,11 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
     type WithContext<'this, U> = ValueDecoder<'this, 'de, OPT, U> where U: 'this + Context;
     type DecodeBuffer = AsValueDecoder<'a, OPT, C>;
     type DecodeSome = Self;
-    type DecodePack = StorageDecoder<'a, SliceReader<'de>, OPT, C>;
-    type DecodeSequence = IterValueDecoder<'a, 'de, OPT, C>;
-    type DecodeMap = IterValuePairsDecoder<'a, 'de, OPT, C>;
-    type DecodeMapEntries = IterValuePairsDecoder<'a, 'de, OPT, C>;
-    type DecodeVariant = IterValueVariantDecoder<'a, 'de, OPT, C>;
+    type DecodePack<'this> = StorageDecoder<'a, SliceReader<'de>, OPT, C>;
+    type DecodeSequence<'this> = IterValueDecoder<'a, 'de, OPT, C>;
+    type DecodeMap<'this> = IterValuePairsDecoder<'a, 'de, OPT, C>;
+    type DecodeMapEntries<'this> = IterValuePairsDecoder<'a, 'de, OPT, C>;
+    type DecodeVariant<'this> = IterValueVariantDecoder<'a, 'de, OPT, C>;
 
     #[inline]
     fn cx(&self) -> &Self::Cx {
@@ -140,6 +142,32 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
         Ok(Skip::Skipped)
     }
 
+    #[inline]
+    fn type_hint(&mut self) -> Result<crate::de::TypeHint, C::Error> {
+        use crate::de::TypeHint;
+
+        let hint = match self.value {
+            Value::Unit => TypeHint::Empty,
+            Value::Bool(..) => TypeHint::Bool,
+            Value::Char(..) => TypeHint::Char,
+            Value::Number(..) => TypeHint::Number,
+            #[cfg(feature = "alloc")]
+            Value::Bytes(bytes) => TypeHint::Bytes(SizeHint::exact(bytes.len())),
+            #[cfg(feature = "alloc")]
+            Value::String(string) => TypeHint::String(SizeHint::exact(string.len())),
+            #[cfg(feature = "alloc")]
+            Value::Sequence(sequence) => TypeHint::Sequence(SizeHint::exact(sequence.len())),
+            #[cfg(feature = "alloc")]
+            Value::Map(map) => TypeHint::Map(SizeHint::exact(map.len())),
+            #[cfg(feature = "alloc")]
+            Value::Variant(..) => TypeHint::Variant,
+            #[cfg(feature = "alloc")]
+            Value::Option(..) => TypeHint::Option,
+        };
+
+        Ok(hint)
+    }
+
     #[inline]
     fn decode_buffer(self) -> Result<Self::DecodeBuffer, C::Error> {
         Ok(AsValueDecoder::new(self.cx, self.value.clone()))
@@ -238,6 +266,19 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
         })
     }
 
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_array_slice(self, out: &mut [u8]) -> Result<(), C::Error> {
+        ensure!(self, hint, ExpectedBytes(hint), Value::Bytes(bytes) => {
+            if bytes.len() != out.len() {
+                return Err(self.cx.message(ErrorMessage::ArrayOutOfBounds));
+            }
+
+            out.copy_from_slice(bytes);
+            Ok(())
+        })
+    }
+
     #[cfg(feature = "alloc")]
     #[inline]
     fn decode_bytes<V>(self, visitor: V) -> Result<V::Ok, C::Error>
@@ -272,10 +313,10 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
     #[inline]
     fn decode_pack<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodePack) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodePack<'_>) -> Result<O, C::Error>,
     {
         ensure!(self, hint, ExpectedPack(hint), Value::Bytes(pack) => {
-            f(&mut StorageDecoder::new(self.cx, SliceReader::new(pack)))
+            nested(self.cx, || f(&mut StorageDecoder::new(self.cx, SliceReader::new(pack))))
         })
     }
 
@@ -283,10 +324,10 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
     #[inline]
     fn decode_sequence<F, O>(self, f: F) -> Result<O, <Self::Cx as Context>::Error>
     where
-        F: FnOnce(&mut Self::DecodeSequence) -> Result<O, <Self::Cx as Context>::Error>,
+        F: FnOnce(&mut Self::DecodeSequence<'_>) -> Result<O, <Self::Cx as Context>::Error>,
     {
         ensure!(self, hint, ExpectedSequence(hint), Value::Sequence(sequence) => {
-            f(&mut IterValueDecoder::new(self.cx, sequence))
+            nested(self.cx, || f(&mut IterValueDecoder::new(self.cx, sequence)))
         })
     }
 
@@ -294,10 +335,10 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
     #[inline]
     fn decode_sequence_hint<F, O>(self, _: &SequenceHint, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeSequence) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeSequence<'_>) -> Result<O, C::Error>,
     {
         ensure!(self, hint, ExpectedSequence(hint), Value::Sequence(sequence) => {
-            f(&mut IterValueDecoder::new(self.cx, sequence))
+            nested(self.cx, || f(&mut IterValueDecoder::new(self.cx, sequence)))
         })
     }
 
@@ -305,10 +346,10 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
     #[inline]
     fn decode_map<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeMap) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeMap<'_>) -> Result<O, C::Error>,
     {
         ensure!(self, hint, ExpectedMap(hint), Value::Map(st) => {
-            f(&mut IterValuePairsDecoder::new(self.cx, st))
+            nested(self.cx, || f(&mut IterValuePairsDecoder::new(self.cx, st)))
         })
     }
 
@@ -316,7 +357,7 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
     #[inline]
     fn decode_map_entries<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeMapEntries) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeMapEntries<'_>) -> Result<O, C::Error>,
     {
         self.decode_map(f)
     }
@@ -325,10 +366,10 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
     #[inline]
     fn decode_variant<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeVariant) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeVariant<'_>) -> Result<O, C::Error>,
     {
         ensure!(self, hint, ExpectedVariant(hint), Value::Variant(st) => {
-            f(&mut IterValueVariantDecoder::new(self.cx, st))
+            nested(self.cx, || f(&mut IterValueVariantDecoder::new(self.cx, st)))
         })
     }
 
@@ -368,20 +409,26 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
                 visitor.visit_borrowed(self.cx, string)
             }
             #[cfg(feature = "alloc")]
-            Value::Sequence(values) => visitor.visit_sequence(
-                self.cx,
-                &mut IterValueDecoder::<OPT, _>::new(self.cx, values),
-            ),
+            Value::Sequence(values) => nested(self.cx, || {
+                visitor.visit_sequence(
+                    self.cx,
+                    &mut IterValueDecoder::<OPT, _>::new(self.cx, values),
+                )
+            }),
             #[cfg(feature = "alloc")]
-            Value::Map(values) => visitor.visit_map(
-                self.cx,
-                &mut IterValuePairsDecoder::<OPT, _>::new(self.cx, values),
-            ),
+            Value::Map(values) => nested(self.cx, || {
+                visitor.visit_map(
+                    self.cx,
+                    &mut IterValuePairsDecoder::<OPT, _>::new(self.cx, values),
+                )
+            }),
             #[cfg(feature = "alloc")]
-            Value::Variant(variant) => visitor.visit_variant(
-                self.cx,
-                &mut IterValueVariantDecoder::<OPT, _>::new(self.cx, variant),
-            ),
+            Value::Variant(variant) => nested(self.cx, || {
+                visitor.visit_variant(
+                    self.cx,
+                    &mut IterValueVariantDecoder::<OPT, _>::new(self.cx, variant),
+                )
+            }),
             #[cfg(feature = "alloc")]
             Value::Option(option) => visitor.visit_option(
                 self.cx,
@@ -401,6 +448,20 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> AsDecoder for ValueDecode
     fn as_decoder(&self) -> Result<Self::Decoder<'_>, C::Error> {
         Ok(ValueDecoder::new(self.cx, self.value))
     }
+
+    #[inline]
+    fn retain_entries<'this, F>(
+        &'this self,
+        mut retain: F,
+    ) -> Result<AsValueDecoder<'a, OPT, C>, C::Error>
+    where
+        F: FnMut(Self::Decoder<'this>) -> Result<bool, C::Error>,
+    {
+        let value = self
+            .value
+            .retain_entries(|key| retain(ValueDecoder::with_map_key(self.cx, key)))?;
+        Ok(AsValueDecoder::new(self.cx, value))
+    }
 }
 
 /// A decoder over a simple value iterator.
@@ -19,29 +19,67 @@ use crate::{Context, Options};
 use super::value::{Number, Value};
 
 /// Insert a value into the given receiver.
-trait ValueOutput {
+pub trait ValueOutput {
+    /// The receiver which values are ultimately written to.
+    #[cfg(feature = "alloc")]
+    type Base: ValueOutput;
+
     fn write(self, value: Value);
+
+    /// Wrap the receiver so that values written to it are wrapped in an
+    /// optional value which is present.
+    #[cfg(feature = "alloc")]
+    fn into_some(self) -> SomeValueWriter<Self::Base>;
 }
 
 impl ValueOutput for &mut Value {
+    #[cfg(feature = "alloc")]
+    type Base = Self;
+
     #[inline]
     fn write(self, value: Value) {
         *self = value;
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn into_some(self) -> SomeValueWriter<Self::Base> {
+        SomeValueWriter::new(self)
+    }
 }
 
 #[cfg(feature = "alloc")]
 impl ValueOutput for &mut Vec<Value> {
+    type Base = Self;
+
     #[inline]
     fn write(self, value: Value) {
         self.push(value);
     }
+
+    #[inline]
+    fn into_some(self) -> SomeValueWriter<Self::Base> {
+        SomeValueWriter::new(self)
+    }
 }
 
 /// Writer which writes an optional value that is present.
+///
+/// Nested optional values are tracked through `depth` rather than by nesting
+/// writers, so that the type of the encoder stays the same no matter how many
+/// optional values are nested.
 #[cfg(feature = "alloc")]
 pub struct SomeValueWriter<O> {
     output: O,
+    depth: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<O> SomeValueWriter<O> {
+    #[inline]
+    fn new(output: O) -> Self {
+        Self { output, depth: 1 }
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -49,8 +87,22 @@ impl<O> ValueOutput for SomeValueWriter<O>
 where
     O: ValueOutput,
 {
-    fn write(self, value: Value) {
-        self.output.write(Value::Option(Some(Box::new(value))));
+    type Base = O;
+
+    fn write(self, mut value: Value) {
+        for _ in 0..self.depth {
+            value = Value::Option(Some(Box::new(value)));
+        }
+
+        self.output.write(value);
+    }
+
+    #[inline]
+    fn into_some(self) -> SomeValueWriter<Self::Base> {
+        SomeValueWriter {
+            output: self.output,
+            depth: self.depth + 1,
+        }
     }
 }
 
@@ -79,7 +131,7 @@ where
     type Mode = C::Mode;
     type WithContext<'this, U> = ValueEncoder<'this, OPT, O, U> where U: 'this + Context;
     #[cfg(feature = "alloc")]
-    type EncodeSome = ValueEncoder<'a, OPT, SomeValueWriter<O>, C>;
+    type EncodeSome = ValueEncoder<'a, OPT, SomeValueWriter<O::Base>, C>;
     #[cfg(feature = "alloc")]
     type EncodePack = PackValueEncoder<'a, OPT, O, C>;
     #[cfg(feature = "alloc")]
@@ -123,6 +175,7 @@ where
 
     #[inline]
     fn encode_empty(self) -> Result<Self::Ok, C::Error> {
+        self.output.write(Value::Unit);
         Ok(())
     }
 
@@ -225,6 +278,13 @@ where
     #[cfg(feature = "alloc")]
     #[inline]
     fn encode_array<const N: usize>(self, array: &[u8; N]) -> Result<Self::Ok, C::Error> {
+        self.output.write(Value::Bytes(array.as_slice().into()));
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_array_slice(self, array: &[u8]) -> Result<Self::Ok, C::Error> {
         self.output.write(Value::Bytes(array.into()));
         Ok(())
     }
@@ -232,7 +292,7 @@ where
     #[cfg(feature = "alloc")]
     #[inline]
     fn encode_bytes(self, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
-        self.output.write(Value::Bytes(bytes.to_vec()));
+        self.output.write(Value::Bytes(bytes.into()));
         Ok(())
     }
 
@@ -248,7 +308,7 @@ where
             bytes.extend_from_slice(b.as_ref());
         }
 
-        self.output.write(Value::Bytes(bytes));
+        self.output.write(Value::Bytes(bytes.into()));
         Ok(())
     }
 
@@ -271,12 +331,7 @@ where
     #[cfg(feature = "alloc")]
     #[inline]
     fn encode_some(self) -> Result<Self::EncodeSome, C::Error> {
-        Ok(ValueEncoder::new(
-            self.cx,
-            SomeValueWriter {
-                output: self.output,
-            },
-        ))
+        Ok(ValueEncoder::new(self.cx, self.output.into_some()))
     }
 
     #[cfg(feature = "alloc")]
@@ -298,6 +353,12 @@ where
         Ok(SequenceValueEncoder::new(self.cx, self.output))
     }
 
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_unsized_sequence(self) -> Result<Self::EncodeSequence, C::Error> {
+        Ok(SequenceValueEncoder::new(self.cx, self.output))
+    }
+
     #[cfg(feature = "alloc")]
     #[inline]
     fn encode_map(self, _: &MapHint) -> Result<Self::EncodeMap, C::Error> {
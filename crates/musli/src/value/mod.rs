@@ -4,6 +4,25 @@
 //! supported in the [Müsli data model].
 //!
 //! [Müsli data model]: crate::help::data_model
+//!
+//! Any format which is self-descriptive, like [`descriptive`] or [`json`], can
+//! decode into a [`Value`] and encode from one. This is useful when the schema
+//! of the data isn't known at compile time:
+//!
+//! ```
+//! use musli::value::Value;
+//!
+//! let value: Value = musli::json::from_str(r#"{"name": "Aristotle", "tags": [1, 2]}"#)?;
+//! assert_eq!(value.get("name").and_then(Value::as_str), Some("Aristotle"));
+//!
+//! let bytes = musli::descriptive::to_vec(&value)?;
+//! let value2: Value = musli::descriptive::from_slice(&bytes)?;
+//! assert_eq!(value2.get("tags").and_then(Value::as_sequence).map(|s| s.len()), Some(2));
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`descriptive`]: crate::descriptive
+//! [`json`]: crate::json
 
 #![cfg(feature = "value")]
 #![cfg_attr(doc_cfg, doc(cfg(feature = "value")))]
@@ -11,14 +30,19 @@
 mod de;
 mod en;
 mod error;
+#[cfg(feature = "alloc")]
+mod small;
 mod type_hint;
 mod value;
 
 /// Convenient result alias for use with `musli_value`.
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use self::small::{SmallBytes, SmallString};
 #[doc(inline)]
-pub use self::value::{AsValueDecoder, Value};
+pub use self::value::{AsValueDecoder, Number, Value};
 #[doc(inline)]
 pub use error::Error;
 
@@ -45,6 +69,18 @@ where
     })
 }
 
+/// Construct an encoder which stores whatever is encoded into `output`.
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub(crate) fn encoder<'a, C>(
+    cx: &'a C,
+    output: &'a mut Value,
+) -> ValueEncoder<'a, OPTIONS, &'a mut Value, C>
+where
+    C: ?Sized + crate::Context,
+{
+    ValueEncoder::new(cx, output)
+}
+
 /// Decode a [Value] into a type which implements [Decode].
 pub fn decode<'de, T>(value: &'de Value) -> Result<T, Error>
 where
@@ -0,0 +1,356 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem::size_of;
+use core::ops::Deref;
+use core::str;
+
+use rust_alloc::boxed::Box;
+use rust_alloc::string::String;
+use rust_alloc::vec::Vec;
+
+/// The number of bytes which can be stored without allocating.
+///
+/// This is the largest size which keeps the representation as large as a
+/// [`String`], leaving room for the discriminant and the length. So it doesn't
+/// grow the size of a [`Value`].
+///
+/// [`Value`]: super::Value
+const INLINE_CAPACITY: usize = 3 * size_of::<usize>() - 2;
+
+#[derive(Clone)]
+enum Repr {
+    Inline {
+        len: u8,
+        data: [u8; INLINE_CAPACITY],
+    },
+    Heap(Box<[u8]>),
+}
+
+impl Repr {
+    #[inline]
+    fn new(bytes: &[u8]) -> Self {
+        if bytes.len() > INLINE_CAPACITY {
+            return Repr::Heap(bytes.into());
+        }
+
+        let mut data = [0; INLINE_CAPACITY];
+        data[..bytes.len()].copy_from_slice(bytes);
+
+        Repr::Inline {
+            len: bytes.len() as u8,
+            data,
+        }
+    }
+
+    #[inline]
+    fn from_vec(vec: Vec<u8>) -> Self {
+        if vec.len() > INLINE_CAPACITY {
+            return Repr::Heap(vec.into_boxed_slice());
+        }
+
+        Self::new(&vec)
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            Repr::Inline { len, data } => &data[..*len as usize],
+            Repr::Heap(data) => data,
+        }
+    }
+
+    #[inline]
+    fn into_vec(self) -> Vec<u8> {
+        match self {
+            Repr::Inline { len, data } => data[..len as usize].to_vec(),
+            Repr::Heap(data) => data.into_vec(),
+        }
+    }
+}
+
+/// Bytes stored in a [`Value`], which are stored inline without allocating if
+/// they are small enough.
+///
+/// # Examples
+///
+/// ```
+/// use musli::value::SmallBytes;
+///
+/// let bytes = SmallBytes::from(&b"hello"[..]);
+/// assert!(bytes.is_inline());
+/// assert_eq!(bytes.as_slice(), b"hello");
+///
+/// let bytes = SmallBytes::from(vec![0; 128]);
+/// assert!(!bytes.is_inline());
+/// assert_eq!(bytes.len(), 128);
+/// ```
+///
+/// [`Value`]: super::Value
+#[derive(Clone)]
+pub struct SmallBytes {
+    repr: Repr,
+}
+
+impl SmallBytes {
+    /// Construct a new empty byte buffer.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            repr: Repr::Inline {
+                len: 0,
+                data: [0; INLINE_CAPACITY],
+            },
+        }
+    }
+
+    /// Access the bytes as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        self.repr.as_slice()
+    }
+
+    /// Test if the bytes are stored inline, without allocating.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.repr, Repr::Inline { .. })
+    }
+
+    /// Convert into a vector.
+    #[inline]
+    pub fn into_vec(self) -> Vec<u8> {
+        self.repr.into_vec()
+    }
+}
+
+impl Default for SmallBytes {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for SmallBytes {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl AsRef<[u8]> for SmallBytes {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl Borrow<[u8]> for SmallBytes {
+    #[inline]
+    fn borrow(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl From<&[u8]> for SmallBytes {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        Self {
+            repr: Repr::new(bytes),
+        }
+    }
+}
+
+impl From<Vec<u8>> for SmallBytes {
+    #[inline]
+    fn from(bytes: Vec<u8>) -> Self {
+        Self {
+            repr: Repr::from_vec(bytes),
+        }
+    }
+}
+
+impl From<SmallBytes> for Vec<u8> {
+    #[inline]
+    fn from(bytes: SmallBytes) -> Self {
+        bytes.into_vec()
+    }
+}
+
+impl fmt::Debug for SmallBytes {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl PartialEq for SmallBytes {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl Eq for SmallBytes {}
+
+impl PartialEq<[u8]> for SmallBytes {
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl PartialOrd for SmallBytes {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SmallBytes {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl Hash for SmallBytes {
+    #[inline]
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.as_slice().hash(state);
+    }
+}
+
+/// A string stored in a [`Value`], which is stored inline without allocating
+/// if it is small enough.
+///
+/// # Examples
+///
+/// ```
+/// use musli::value::SmallString;
+///
+/// let string = SmallString::from("hello");
+/// assert!(string.is_inline());
+/// assert_eq!(string, "hello");
+///
+/// let string = SmallString::from("x".repeat(128));
+/// assert!(!string.is_inline());
+/// assert_eq!(string.len(), 128);
+/// ```
+///
+/// [`Value`]: super::Value
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SmallString {
+    bytes: SmallBytes,
+}
+
+impl SmallString {
+    /// Construct a new empty string.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            bytes: SmallBytes::new(),
+        }
+    }
+
+    /// Access the string as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The bytes are only ever constructed from valid utf-8.
+        unsafe { str::from_utf8_unchecked(self.bytes.as_slice()) }
+    }
+
+    /// Test if the string is stored inline, without allocating.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.bytes.is_inline()
+    }
+
+    /// Convert into a [`String`].
+    #[inline]
+    pub fn into_string(self) -> String {
+        // SAFETY: The bytes are only ever constructed from valid utf-8.
+        unsafe { String::from_utf8_unchecked(self.bytes.into_vec()) }
+    }
+}
+
+impl Deref for SmallString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SmallString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for SmallString {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for SmallString {
+    #[inline]
+    fn from(string: &str) -> Self {
+        Self {
+            bytes: SmallBytes::from(string.as_bytes()),
+        }
+    }
+}
+
+impl From<String> for SmallString {
+    #[inline]
+    fn from(string: String) -> Self {
+        Self {
+            bytes: SmallBytes::from(string.into_bytes()),
+        }
+    }
+}
+
+impl From<SmallString> for String {
+    #[inline]
+    fn from(string: SmallString) -> Self {
+        string.into_string()
+    }
+}
+
+impl fmt::Debug for SmallString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for SmallString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl PartialEq<str> for SmallString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SmallString {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
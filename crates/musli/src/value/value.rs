@@ -1,6 +1,4 @@
 #[cfg(feature = "alloc")]
-use rust_alloc::borrow::ToOwned;
-#[cfg(feature = "alloc")]
 use rust_alloc::boxed::Box;
 #[cfg(feature = "alloc")]
 use rust_alloc::string::String;
@@ -18,6 +16,8 @@ use crate::en::{MapEncoder, SequenceEncoder, VariantEncoder};
 use crate::{Context, Options};
 
 use super::de::ValueDecoder;
+#[cfg(feature = "alloc")]
+use super::small::{SmallBytes, SmallString};
 use super::type_hint::{NumberHint, TypeHint};
 
 /// A dynamic value capable of representing any [Müsli] type whether it be
@@ -37,10 +37,10 @@ pub enum Value {
     Number(Number),
     /// An array.
     #[cfg(feature = "alloc")]
-    Bytes(Vec<u8>),
+    Bytes(SmallBytes),
     /// A string in a value.
     #[cfg(feature = "alloc")]
-    String(String),
+    String(SmallString),
     /// A unit value.
     #[cfg(feature = "alloc")]
     Sequence(Vec<Value>),
@@ -67,6 +67,150 @@ impl Value {
         AsValueDecoder::new(cx, self)
     }
 
+    /// Test if the value is [`Value::Unit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::value::Value;
+    ///
+    /// assert!(Value::Unit.is_unit());
+    /// assert!(!Value::Bool(true).is_unit());
+    /// ```
+    #[inline]
+    pub fn is_unit(&self) -> bool {
+        matches!(self, Value::Unit)
+    }
+
+    /// Get the value as a boolean, if it is one.
+    #[inline]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a character, if it is one.
+    #[inline]
+    pub fn as_char(&self) -> Option<char> {
+        match self {
+            Value::Char(c) => Some(*c),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a number, if it is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::value::Value;
+    ///
+    /// let value: Value = musli::value::encode(42u32)?;
+    /// assert_eq!(value.as_number().and_then(|n| n.as_u64()), Some(42));
+    /// # Ok::<_, musli::value::Error>(())
+    /// ```
+    #[inline]
+    pub fn as_number(&self) -> Option<&Number> {
+        match self {
+            Value::Number(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a string, if it is one.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(string) => Some(string.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Get the value as bytes, if it is one.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Value::Bytes(bytes) => Some(bytes.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a sequence, if it is one.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn as_sequence(&self) -> Option<&[Value]> {
+        match self {
+            Value::Sequence(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a map, if it is one.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn as_map(&self) -> Option<&[(Value, Value)]> {
+        match self {
+            Value::Map(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    /// Get the tag and the data of a variant, if the value is one.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn as_variant(&self) -> Option<(&Value, &Value)> {
+        match self {
+            Value::Variant(variant) => Some((&variant.0, &variant.1)),
+            _ => None,
+        }
+    }
+
+    /// Look up the value of the map entry whose key is the given string.
+    ///
+    /// This is how fields of structs encoded with string names can be accessed
+    /// once decoded from a format which retains type information, like
+    /// [`descriptive`] or [`json`].
+    ///
+    /// [`descriptive`]: crate::descriptive
+    /// [`json`]: crate::json
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::Encode;
+    /// use musli::value::Value;
+    ///
+    /// #[derive(Encode)]
+    /// #[musli(name_all = "name")]
+    /// struct Person {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// let bytes = musli::descriptive::to_vec(&Person {
+    ///     name: String::from("Aristotle"),
+    ///     age: 61,
+    /// })?;
+    ///
+    /// let value: Value = musli::descriptive::from_slice(&bytes)?;
+    /// assert_eq!(value.get("name").and_then(Value::as_str), Some("Aristotle"));
+    /// assert_eq!(value.get("age").and_then(Value::as_number).and_then(|n| n.as_u64()), Some(61));
+    /// # Ok::<_, musli::descriptive::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        let entries = self.as_map()?;
+
+        entries
+            .iter()
+            .find(|(k, _)| k.as_str() == Some(key))
+            .map(|(_, v)| v)
+    }
+
     /// Get a decoder associated with a value.
     #[inline]
     pub(crate) fn decoder<'a, 'de, const OPT: Options, C: ?Sized>(
@@ -76,6 +220,30 @@ impl Value {
         ValueDecoder::new(cx, self)
     }
 
+    /// Clone the value, and if it is a map only retain the entries with keys
+    /// that `retain` returns `true` for.
+    #[cfg_attr(not(feature = "alloc"), allow(unused_mut, unused_variables))]
+    pub(crate) fn retain_entries<'de, F, E>(&'de self, mut retain: F) -> Result<Value, E>
+    where
+        F: FnMut(&'de Value) -> Result<bool, E>,
+    {
+        match self {
+            #[cfg(feature = "alloc")]
+            Value::Map(entries) => {
+                let mut out = Vec::with_capacity(entries.len());
+
+                for (key, value) in entries {
+                    if retain(key)? {
+                        out.push((key.clone(), value.clone()));
+                    }
+                }
+
+                Ok(Value::Map(out))
+            }
+            value => Ok(value.clone()),
+        }
+    }
+
     /// Get the type hint corresponding to the value.
     pub(crate) fn type_hint(&self) -> TypeHint {
         match self {
@@ -99,6 +267,8 @@ impl Value {
     }
 }
 
+/// A number stored in a [`Value`], which retains the exact type it was
+/// encoded as.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 #[non_exhaustive]
 pub enum Number {
@@ -112,15 +282,15 @@ pub enum Number {
     U64(u64),
     /// `u128`
     U128(u128),
-    /// `u8`
+    /// `i8`
     I8(i8),
-    /// `u16`
+    /// `i16`
     I16(i16),
-    /// `u32`
+    /// `i32`
     I32(i32),
-    /// `u64`
+    /// `i64`
     I64(i64),
-    /// `u128`
+    /// `i128`
     I128(i128),
     /// `usize`
     Usize(usize),
@@ -157,6 +327,74 @@ from!(isize, Isize);
 from!(f32, F32);
 from!(f64, F64);
 
+macro_rules! value_from {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for Value {
+                #[inline]
+                fn from(value: $ty) -> Self {
+                    Value::Number(Number::from(value))
+                }
+            }
+        )*
+    };
+}
+
+value_from!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, usize, isize, f32, f64);
+
+impl From<bool> for Value {
+    #[inline]
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<char> for Value {
+    #[inline]
+    fn from(value: char) -> Self {
+        Value::Char(value)
+    }
+}
+
+impl From<Number> for Value {
+    #[inline]
+    fn from(value: Number) -> Self {
+        Value::Number(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&str> for Value {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Value::String(SmallString::from(value))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<String> for Value {
+    #[inline]
+    fn from(value: String) -> Self {
+        Value::String(SmallString::from(value))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&[u8]> for Value {
+    #[inline]
+    fn from(value: &[u8]) -> Self {
+        Value::Bytes(SmallBytes::from(value))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Vec<Value>> for Value {
+    #[inline]
+    fn from(value: Vec<Value>) -> Self {
+        Value::Sequence(value)
+    }
+}
+
 impl<M> Encode<M> for Number {
     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
     where
@@ -182,6 +420,157 @@ impl<M> Encode<M> for Number {
 }
 
 impl Number {
+    /// Get the number as a `u64`, if it can be losslessly represented as
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::value::Number;
+    ///
+    /// assert_eq!(Number::from(42u8).as_u64(), Some(42));
+    /// assert_eq!(Number::from(-1i32).as_u64(), None);
+    /// assert_eq!(Number::from(1.0f32).as_u64(), None);
+    /// ```
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Number::U8(n) => Some(n.into()),
+            Number::U16(n) => Some(n.into()),
+            Number::U32(n) => Some(n.into()),
+            Number::U64(n) => Some(n),
+            Number::U128(n) => n.try_into().ok(),
+            Number::I8(n) => n.try_into().ok(),
+            Number::I16(n) => n.try_into().ok(),
+            Number::I32(n) => n.try_into().ok(),
+            Number::I64(n) => n.try_into().ok(),
+            Number::I128(n) => n.try_into().ok(),
+            Number::Usize(n) => n.try_into().ok(),
+            Number::Isize(n) => n.try_into().ok(),
+            Number::F32(..) | Number::F64(..) => None,
+        }
+    }
+
+    /// Get the number as an `i64`, if it can be losslessly represented as
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::value::Number;
+    ///
+    /// assert_eq!(Number::from(-1i8).as_i64(), Some(-1));
+    /// assert_eq!(Number::from(u64::MAX).as_i64(), None);
+    /// ```
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            Number::U8(n) => Some(n.into()),
+            Number::U16(n) => Some(n.into()),
+            Number::U32(n) => Some(n.into()),
+            Number::U64(n) => n.try_into().ok(),
+            Number::U128(n) => n.try_into().ok(),
+            Number::I8(n) => Some(n.into()),
+            Number::I16(n) => Some(n.into()),
+            Number::I32(n) => Some(n.into()),
+            Number::I64(n) => Some(n),
+            Number::I128(n) => n.try_into().ok(),
+            Number::Usize(n) => n.try_into().ok(),
+            Number::Isize(n) => n.try_into().ok(),
+            Number::F32(..) | Number::F64(..) => None,
+        }
+    }
+
+    /// Get the number as a `u128`, if it can be losslessly represented as
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::value::Number;
+    ///
+    /// assert_eq!(Number::from(u128::MAX).as_u128(), Some(u128::MAX));
+    /// assert_eq!(Number::from(42u8).as_u128(), Some(42));
+    /// assert_eq!(Number::from(-1i128).as_u128(), None);
+    /// ```
+    pub fn as_u128(&self) -> Option<u128> {
+        match *self {
+            Number::U8(n) => Some(n.into()),
+            Number::U16(n) => Some(n.into()),
+            Number::U32(n) => Some(n.into()),
+            Number::U64(n) => Some(n.into()),
+            Number::U128(n) => Some(n),
+            Number::I8(n) => n.try_into().ok(),
+            Number::I16(n) => n.try_into().ok(),
+            Number::I32(n) => n.try_into().ok(),
+            Number::I64(n) => n.try_into().ok(),
+            Number::I128(n) => n.try_into().ok(),
+            Number::Usize(n) => n.try_into().ok(),
+            Number::Isize(n) => n.try_into().ok(),
+            Number::F32(..) | Number::F64(..) => None,
+        }
+    }
+
+    /// Get the number as an `i128`, if it can be losslessly represented as
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::value::Number;
+    ///
+    /// assert_eq!(Number::from(i128::MIN).as_i128(), Some(i128::MIN));
+    /// assert_eq!(Number::from(u64::MAX).as_i128(), Some(u64::MAX.into()));
+    /// assert_eq!(Number::from(u128::MAX).as_i128(), None);
+    /// ```
+    pub fn as_i128(&self) -> Option<i128> {
+        match *self {
+            Number::U8(n) => Some(n.into()),
+            Number::U16(n) => Some(n.into()),
+            Number::U32(n) => Some(n.into()),
+            Number::U64(n) => Some(n.into()),
+            Number::U128(n) => n.try_into().ok(),
+            Number::I8(n) => Some(n.into()),
+            Number::I16(n) => Some(n.into()),
+            Number::I32(n) => Some(n.into()),
+            Number::I64(n) => Some(n.into()),
+            Number::I128(n) => Some(n),
+            Number::Usize(n) => n.try_into().ok(),
+            Number::Isize(n) => n.try_into().ok(),
+            Number::F32(..) | Number::F64(..) => None,
+        }
+    }
+
+    /// Get the number as an `f64`.
+    ///
+    /// Integers are converted with `as`, which may lose precision for large
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::value::Number;
+    ///
+    /// assert_eq!(Number::from(1.5f32).as_f64(), 1.5);
+    /// assert_eq!(Number::from(42u32).as_f64(), 42.0);
+    /// ```
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            Number::U8(n) => n.into(),
+            Number::U16(n) => n.into(),
+            Number::U32(n) => n.into(),
+            Number::U64(n) => n as f64,
+            Number::U128(n) => n as f64,
+            Number::I8(n) => n.into(),
+            Number::I16(n) => n.into(),
+            Number::I32(n) => n.into(),
+            Number::I64(n) => n as f64,
+            Number::I128(n) => n as f64,
+            Number::Usize(n) => n as f64,
+            Number::Isize(n) => n as f64,
+            Number::F32(n) => n.into(),
+            Number::F64(n) => n,
+        }
+    }
+
     /// Get the type hint for the number.
     pub(crate) fn type_hint(&self) -> NumberHint {
         match self {
@@ -396,12 +785,12 @@ impl<'de, C: ?Sized + Context> UnsizedVisitor<'de, C, [u8]> for BytesVisitor {
     #[cfg(feature = "alloc")]
     #[inline]
     fn visit_owned(self, _: &C, bytes: Vec<u8>) -> Result<Self::Ok, C::Error> {
-        Ok(Value::Bytes(bytes))
+        Ok(Value::Bytes(bytes.into()))
     }
 
     #[inline]
     fn visit_ref(self, _: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
-        Ok(Value::Bytes(bytes.to_vec()))
+        Ok(Value::Bytes(bytes.into()))
     }
 }
 
@@ -419,12 +808,12 @@ impl<'de, C: ?Sized + Context> UnsizedVisitor<'de, C, str> for StringVisitor {
 
     #[inline]
     fn visit_owned(self, _: &C, string: String) -> Result<Self::Ok, C::Error> {
-        Ok(Value::String(string))
+        Ok(Value::String(string.into()))
     }
 
     #[inline]
     fn visit_ref(self, _: &C, string: &str) -> Result<Self::Ok, C::Error> {
-        Ok(Value::String(string.to_owned()))
+        Ok(Value::String(string.into()))
     }
 }
 
@@ -439,9 +828,9 @@ impl<M> Encode<M> for Value {
             Value::Char(c) => encoder.encode_char(*c),
             Value::Number(n) => encoder.encode(n),
             #[cfg(feature = "alloc")]
-            Value::Bytes(bytes) => encoder.encode_bytes(bytes),
+            Value::Bytes(bytes) => encoder.encode_bytes(bytes.as_slice()),
             #[cfg(feature = "alloc")]
-            Value::String(string) => encoder.encode_string(string),
+            Value::String(string) => encoder.encode_string(string.as_str()),
             #[cfg(feature = "alloc")]
             Value::Sequence(values) => {
                 use crate::hint::SequenceHint;
@@ -507,4 +896,15 @@ impl<'a, const OPT: Options, C: ?Sized + Context> AsDecoder for AsValueDecoder<'
     fn as_decoder(&self) -> Result<Self::Decoder<'_>, C::Error> {
         Ok(self.value.decoder(self.cx))
     }
+
+    #[inline]
+    fn retain_entries<'this, F>(&'this self, mut retain: F) -> Result<Self, C::Error>
+    where
+        F: FnMut(Self::Decoder<'this>) -> Result<bool, C::Error>,
+    {
+        let value = self
+            .value
+            .retain_entries(|key| retain(ValueDecoder::with_map_key(self.cx, key)))?;
+        Ok(AsValueDecoder::new(self.cx, value))
+    }
 }
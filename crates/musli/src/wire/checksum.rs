@@ -0,0 +1,368 @@
+use core::fmt;
+
+use crate::alloc::Vec;
+use crate::de::UnsizedVisitor;
+use crate::{Context, Reader, Writer};
+
+/// The size of the checksum trailer in bytes.
+const TRAILER_LEN: usize = 4;
+
+/// A checksum which is appended to encoded messages and verified when they
+/// are decoded.
+///
+/// See [`Encoding::with_checksum`].
+///
+/// [`Encoding::with_checksum`]: super::Encoding::with_checksum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Checksum {
+    /// A CRC-32 checksum using the IEEE polynomial, as used by zlib and
+    /// Ethernet. Stored as a little-endian `u32` after the message.
+    Crc32,
+}
+
+/// The state of a checksum being calculated.
+#[derive(Clone, Copy)]
+struct Hasher {
+    crc: u32,
+}
+
+impl Hasher {
+    #[inline]
+    fn new(checksum: Checksum) -> Self {
+        match checksum {
+            Checksum::Crc32 => Self { crc: !0 },
+        }
+    }
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.crc = CRC32_TABLE[((self.crc ^ b as u32) & 0xff) as usize] ^ (self.crc >> 8);
+        }
+    }
+
+    #[inline]
+    fn finish(self) -> u32 {
+        !self.crc
+    }
+}
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut n = 0;
+
+    while n < 256 {
+        let mut crc = n as u32;
+        let mut k = 0;
+
+        while k < 8 {
+            crc = if crc & 1 == 1 {
+                0xedb88320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+
+            k += 1;
+        }
+
+        table[n] = crc;
+        n += 1;
+    }
+
+    table
+}
+
+/// A writer which calculates the checksum of everything written to it.
+pub(crate) struct ChecksumWriter<W> {
+    writer: W,
+    hasher: Option<Hasher>,
+}
+
+impl<W> ChecksumWriter<W>
+where
+    W: Writer,
+{
+    #[inline]
+    pub(crate) fn new(checksum: Option<Checksum>, writer: W) -> Self {
+        Self {
+            writer,
+            hasher: checksum.map(Hasher::new),
+        }
+    }
+
+    /// Write the checksum trailer.
+    #[inline]
+    pub(crate) fn finish<C>(mut self, cx: &C) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if let Some(hasher) = self.hasher {
+            self.writer
+                .write_bytes(cx, &hasher.finish().to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W> Writer for ChecksumWriter<W>
+where
+    W: Writer,
+{
+    type Mut<'this> = &'this mut Self where Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn extend<C>(&mut self, cx: &C, buffer: Vec<'_, u8, C::Allocator>) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(buffer.as_slice());
+        }
+
+        self.writer.extend(cx, buffer)
+    }
+
+    #[inline]
+    fn write_bytes<C>(&mut self, cx: &C, bytes: &[u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(bytes);
+        }
+
+        self.writer.write_bytes(cx, bytes)
+    }
+
+    #[inline]
+    fn write_byte<C>(&mut self, cx: &C, b: u8) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(&[b]);
+        }
+
+        self.writer.write_byte(cx, b)
+    }
+
+    // Reserved bytes are filled in after they've been written, so they can't
+    // be hashed as they pass through. Refusing to reserve makes the encoder
+    // buffer such content instead.
+    #[inline]
+    fn reserve<C>(&mut self, cx: &C, len: usize) -> Result<Option<usize>, C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if self.hasher.is_some() {
+            return Ok(None);
+        }
+
+        self.writer.reserve(cx, len)
+    }
+
+    #[inline]
+    fn reserved_mut(&mut self, at: usize) -> &mut [u8] {
+        self.writer.reserved_mut(at)
+    }
+}
+
+/// A reader which calculates the checksum of everything read from it.
+pub(crate) struct ChecksumReader<R> {
+    reader: R,
+    hasher: Option<Hasher>,
+}
+
+impl<'de, R> ChecksumReader<R>
+where
+    R: Reader<'de>,
+{
+    #[inline]
+    pub(crate) fn new(checksum: Option<Checksum>, reader: R) -> Self {
+        Self {
+            reader,
+            hasher: checksum.map(Hasher::new),
+        }
+    }
+
+    /// Read the checksum trailer and verify it against what has been read.
+    #[inline]
+    pub(crate) fn finish<C>(mut self, cx: &C) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let Some(hasher) = self.hasher else {
+            return Ok(());
+        };
+
+        let mark = cx.mark();
+        let expected = u32::from_le_bytes(self.reader.read_array::<C, TRAILER_LEN>(cx)?);
+        let actual = hasher.finish();
+
+        if expected != actual {
+            return Err(cx.marked_message(mark, ChecksumMismatch { expected, actual }));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'de, R> Reader<'de> for ChecksumReader<R>
+where
+    R: Reader<'de>,
+{
+    type Mut<'this> = &'this mut Self where Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn skip<C>(&mut self, cx: &C, n: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let Some(hasher) = &mut self.hasher else {
+            return self.reader.skip(cx, n);
+        };
+
+        struct Visitor<'a>(&'a mut Hasher);
+
+        impl<'de, C> UnsizedVisitor<'de, C, [u8]> for Visitor<'_>
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = ();
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "bytes")
+            }
+
+            #[inline]
+            fn visit_ref(self, _: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+                self.0.update(bytes);
+                Ok(())
+            }
+        }
+
+        self.reader.read_bytes(cx, n, Visitor(hasher))
+    }
+
+    #[inline]
+    fn peek(&mut self) -> Option<u8> {
+        self.reader.peek()
+    }
+
+    #[inline]
+    fn remaining_slice(&self) -> Option<&'de [u8]> {
+        self.reader.remaining_slice()
+    }
+
+    #[inline]
+    fn read<C>(&mut self, cx: &C, buf: &mut [u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.reader.read(cx, buf)?;
+
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(buf);
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn read_bytes<C, V>(&mut self, cx: &C, n: usize, visitor: V) -> Result<V::Ok, C::Error>
+    where
+        C: ?Sized + Context,
+        V: UnsizedVisitor<'de, C, [u8]>,
+    {
+        let Some(hasher) = &mut self.hasher else {
+            return self.reader.read_bytes(cx, n, visitor);
+        };
+
+        struct Visitor<'a, V>(&'a mut Hasher, V);
+
+        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<'_, V>
+        where
+            C: ?Sized + Context,
+            V: UnsizedVisitor<'de, C, [u8]>,
+        {
+            type Ok = V::Ok;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.1.expecting(f)
+            }
+
+            #[inline]
+            fn visit_borrowed(self, cx: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
+                self.0.update(bytes);
+                self.1.visit_borrowed(cx, bytes)
+            }
+
+            #[inline]
+            fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+                self.0.update(bytes);
+                self.1.visit_ref(cx, bytes)
+            }
+        }
+
+        self.reader.read_bytes(cx, n, Visitor(hasher, visitor))
+    }
+
+    #[inline]
+    fn read_byte<C>(&mut self, cx: &C) -> Result<u8, C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let b = self.reader.read_byte(cx)?;
+
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(&[b]);
+        }
+
+        Ok(b)
+    }
+
+    #[inline]
+    fn read_array<C, const N: usize>(&mut self, cx: &C) -> Result<[u8; N], C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let array = self.reader.read_array::<C, N>(cx)?;
+
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(&array);
+        }
+
+        Ok(array)
+    }
+}
+
+struct ChecksumMismatch {
+    expected: u32,
+    actual: u32,
+}
+
+impl fmt::Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { expected, actual } = *self;
+        write!(
+            f,
+            "Checksum mismatch, expected {expected:08x} but the message has {actual:08x}"
+        )
+    }
+}
@@ -0,0 +1,172 @@
+//! A length-delimited codec for use with [`tokio-util`].
+//!
+//! [`tokio-util`]: https://docs.rs/tokio-util
+
+use core::fmt;
+use core::marker;
+
+use bytes::{Bytes, BytesMut};
+use tokio_util::codec::{Decoder, Encoder, LengthDelimitedCodec};
+
+use crate::de::DecodeOwned;
+use crate::mode::Binary;
+use crate::{Encode, Options};
+
+use super::encoding::{Encoding, OPTIONS};
+use super::error::Error;
+
+/// A [`Framed`]-compatible codec which length-prefixes wire-encoded messages.
+///
+/// Each message is encoded with the configured [`Encoding`] and prefixed with
+/// its length as a big-endian `u32`, as is done by [`LengthDelimitedCodec`].
+///
+/// [`Framed`]: tokio_util::codec::Framed
+///
+/// # Examples
+///
+/// ```
+/// use bytes::BytesMut;
+/// use musli::{Decode, Encode};
+/// use musli::wire::Codec;
+/// use tokio_util::codec::{Decoder, Encoder};
+///
+/// #[derive(Debug, PartialEq, Decode, Encode)]
+/// struct Ping {
+///     id: u32,
+/// }
+///
+/// let mut codec = Codec::<Ping>::new();
+/// let mut buf = BytesMut::new();
+///
+/// codec.encode(Ping { id: 1 }, &mut buf)?;
+/// codec.encode(&Ping { id: 2 }, &mut buf)?;
+///
+/// assert_eq!(codec.decode(&mut buf)?, Some(Ping { id: 1 }));
+/// assert_eq!(codec.decode(&mut buf)?, Some(Ping { id: 2 }));
+/// assert_eq!(codec.decode(&mut buf)?, None);
+/// # Ok::<_, musli::wire::Error>(())
+/// ```
+pub struct Codec<T, const OPT: Options = OPTIONS, M = Binary>
+where
+    M: 'static,
+{
+    encoding: Encoding<OPT, M>,
+    inner: LengthDelimitedCodec,
+    _marker: marker::PhantomData<fn(T) -> T>,
+}
+
+impl<T> Codec<T> {
+    /// Construct a new codec using the default [`Encoding`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_encoding(Encoding::new())
+    }
+}
+
+impl<T> Default for Codec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const OPT: Options, M> Codec<T, OPT, M>
+where
+    M: 'static,
+{
+    /// Construct a new codec using the given [`Encoding`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::options::{self, Integer, Options};
+    /// use musli::wire::{Codec, Encoding};
+    ///
+    /// const OPTIONS: Options = options::new().with_integer(Integer::Fixed).build();
+    /// const ENCODING: Encoding<OPTIONS> = Encoding::new().with_options();
+    ///
+    /// let codec = Codec::<u32, OPTIONS>::with_encoding(ENCODING);
+    /// ```
+    #[inline]
+    pub fn with_encoding(encoding: Encoding<OPT, M>) -> Self {
+        Self {
+            encoding,
+            inner: LengthDelimitedCodec::new(),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Set the maximum length of a single encoded message.
+    ///
+    /// Messages which are longer than this are rejected both when encoding
+    /// and decoding. Defaults to 8 MiB.
+    #[inline]
+    pub fn with_max_frame_length(mut self, max_frame_length: usize) -> Self {
+        self.inner.set_max_frame_length(max_frame_length);
+        self
+    }
+
+    /// Get the maximum length of a single encoded message.
+    #[inline]
+    pub fn max_frame_length(&self) -> usize {
+        self.inner.max_frame_length()
+    }
+}
+
+impl<T, const OPT: Options, M> Decoder for Codec<T, OPT, M>
+where
+    T: DecodeOwned<M>,
+    M: 'static,
+{
+    type Item = T;
+    type Error = Error;
+
+    #[inline]
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let Some(frame) = self.inner.decode(src)? else {
+            return Ok(None);
+        };
+
+        self.encoding.from_slice(&frame).map(Some)
+    }
+}
+
+impl<T, const OPT: Options, M> Encoder<T> for Codec<T, OPT, M>
+where
+    T: Encode<M>,
+    M: 'static,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        Encoder::encode(self, &item, dst)
+    }
+}
+
+impl<T, const OPT: Options, M> Encoder<&T> for Codec<T, OPT, M>
+where
+    T: Encode<M>,
+    M: 'static,
+{
+    type Error = Error;
+
+    #[inline]
+    fn encode(&mut self, item: &T, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        let bytes = self.encoding.to_vec(item)?;
+        self.inner.encode(Bytes::from(bytes), dst)?;
+        Ok(())
+    }
+}
+
+impl<T, const OPT: Options, M> fmt::Debug for Codec<T, OPT, M>
+where
+    M: 'static,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Codec")
+            .field("max_frame_length", &self.max_frame_length())
+            .finish_non_exhaustive()
+    }
+}
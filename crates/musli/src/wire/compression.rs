@@ -0,0 +1,25 @@
+/// A compression algorithm applied to wire payloads.
+///
+/// See [`Encoding::with_compression`].
+///
+/// [`Encoding::with_compression`]: super::Encoding::with_compression
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Compression {
+    /// Compress using [zstd] at the given level, where `0` selects the
+    /// default level.
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    #[cfg(feature = "zstd")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "zstd")))]
+    Zstd {
+        /// The compression level.
+        level: i32,
+    },
+    /// Compress using the [lz4] frame format.
+    ///
+    /// [lz4]: https://lz4.org/
+    #[cfg(feature = "lz4")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "lz4")))]
+    Lz4,
+}
@@ -1,40 +1,50 @@
 use core::fmt;
 use core::mem::take;
 
+#[cfg(feature = "alloc")]
+use rust_alloc::boxed::Box;
 #[cfg(feature = "alloc")]
 use rust_alloc::vec::Vec;
 
+use crate::context::nested;
 use crate::de::{
-    Decode, DecodeUnsized, Decoder, EntriesDecoder, EntryDecoder, MapDecoder, SequenceDecoder,
-    SizeHint, Skip, UnsizedVisitor, VariantDecoder,
+    Decode, DecodeUnsized, Decoder, EntriesDecoder, EntryDecoder, MapDecoder, PodSliceMut,
+    SequenceDecoder, SizeHint, Skip, UnsizedVisitor, VariantDecoder,
 };
 use crate::hint::{MapHint, SequenceHint};
 use crate::int::continuation as c;
+use crate::options::Integer;
 use crate::reader::Limit;
 use crate::storage::de::StorageDecoder;
 use crate::{Context, Options, Reader};
 
-use super::tag::{Kind, Tag};
+#[cfg(feature = "alloc")]
+use super::inspect::Node;
+use super::limits::Budget;
+#[cfg(feature = "alloc")]
+use super::patch::Span;
+use super::tag::{Kind, Tag, END, EXTENSION, STREAM};
 
 /// A very simple decoder.
-pub struct WireDecoder<'a, R, const OPT: Options, C: ?Sized> {
+pub struct WireDecoder<'a, 'b, R, const OPT: Options, C: ?Sized> {
     cx: &'a C,
+    budget: &'b Budget,
     reader: R,
 }
 
-impl<'a, 'de, R, const OPT: Options, C> WireDecoder<'a, R, OPT, C>
+impl<'a, 'b, 'de, R, const OPT: Options, C> WireDecoder<'a, 'b, R, OPT, C>
 where
     R: Reader<'de>,
     C: ?Sized + Context,
 {
     /// Construct a new fixed width message encoder.
     #[inline]
-    pub(crate) fn new(cx: &'a C, reader: R) -> Self {
-        Self { cx, reader }
+    pub(crate) fn new(cx: &'a C, budget: &'b Budget, reader: R) -> Self {
+        Self { cx, budget, reader }
     }
 }
 
-impl<'a, 'de, R, const OPT: Options, C> WireDecoder<'a, Limit<R>, OPT, C>
+impl<'a, 'b, 'de, R, const OPT: Options, C> WireDecoder<'a, 'b, Limit<R>, OPT, C>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
@@ -49,7 +59,7 @@ where
     }
 }
 
-impl<'a, 'de, R, const OPT: Options, C> WireDecoder<'a, R, OPT, C>
+impl<'a, 'b, 'de, R, const OPT: Options, C> WireDecoder<'a, 'b, R, OPT, C>
 where
     R: Reader<'de>,
     C: ?Sized + Context,
@@ -58,9 +68,33 @@ where
     pub(crate) fn skip_any(mut self) -> Result<(), C::Error> {
         let mut remaining = 1;
 
-        while remaining > 0 {
+        // The number of values remaining in enclosing sequences and the number
+        // of items seen so far for each terminated sequence being skipped.
+        let mut terminated = crate::alloc::Vec::<(usize, usize), _>::new_in(self.cx.alloc());
+
+        loop {
+            if remaining == 0 {
+                let Some((outer, count)) = terminated.as_mut_slice().last_mut() else {
+                    break;
+                };
+
+                let mark = self.cx.mark();
+
+                if self.reader.peek() == Some(END.byte()) {
+                    self.reader.skip(self.cx, 1)?;
+                    remaining = *outer;
+                    terminated.pop();
+                    continue;
+                }
+
+                *count += 1;
+                self.budget.check_sequence(self.cx, mark, *count)?;
+                remaining = 1;
+            }
+
             remaining -= 1;
 
+            let mark = self.cx.mark();
             let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
 
             match tag.kind() {
@@ -68,29 +102,46 @@ where
                     let len = if let Some(len) = tag.data() {
                         len as usize
                     } else {
-                        crate::int::decode_usize::<_, _, OPT>(self.cx, self.reader.borrow_mut())?
+                        self.read_len()?
                     };
 
+                    self.budget.check_element(self.cx, mark, len)?;
                     self.reader.skip(self.cx, len)?;
                 }
                 Kind::Sequence => {
                     let len = if let Some(len) = tag.data() {
                         len as usize
                     } else {
-                        crate::int::decode_usize::<_, _, OPT>(self.cx, self.reader.borrow_mut())?
+                        self.read_len()?
                     };
 
+                    self.budget.check_sequence(self.cx, mark, len)?;
                     remaining += len;
                 }
                 Kind::Continuation => {
                     if tag.data().is_none() {
-                        let _ = c::decode::<_, _, u128>(self.cx, self.reader.borrow_mut())?;
+                        let _ = c::decode_bounded::<_, _, u128>(
+                            self.cx,
+                            self.reader.borrow_mut(),
+                            self.budget.max_continuation(),
+                        )?;
+                    }
+                }
+                Kind::Marker if tag == STREAM => {
+                    if !terminated.push((remaining, 0)) {
+                        return Err(self.cx.alloc_failed());
                     }
+
+                    remaining = 0;
+                }
+                Kind::Marker if tag == EXTENSION => {
+                    self.read_extension_id()?;
+                    remaining += 1;
                 }
                 kind => {
                     return Err(self
                         .cx
-                        .message(format_args!("Cannot skip over kind {kind:?}")));
+                        .marked_message(mark, format_args!("Cannot skip over kind {kind:?}")));
                 }
             }
         }
@@ -98,37 +149,325 @@ where
         Ok(())
     }
 
-    #[inline]
-    fn decode_sequence_len(&mut self) -> Result<usize, C::Error> {
+    /// Inspect the structure of the next value.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn inspect(mut self) -> Result<Node, C::Error> {
+        struct Visitor;
+
+        impl<'de, C> UnsizedVisitor<'de, C, [u8]> for Visitor
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = Vec<u8>;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "bytes")
+            }
+
+            #[inline]
+            fn visit_ref(self, _: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+                Ok(bytes.to_vec())
+            }
+        }
+
+        let mark = self.cx.mark();
         let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
 
         match tag.kind() {
-            Kind::Sequence => Ok(if let Some(len) = tag.data() {
-                len as usize
-            } else {
-                crate::int::decode_usize::<_, _, OPT>(self.cx, self.reader.borrow_mut())?
-            }),
-            _ => Err(self.cx.message(Expected {
-                expected: Kind::Sequence,
-                actual: tag,
-            })),
+            Kind::Prefix => {
+                let len = if let Some(len) = tag.data() {
+                    len as usize
+                } else {
+                    self.read_len()?
+                };
+
+                self.budget.element(self.cx, mark, len)?;
+                let bytes = self.reader.read_bytes(self.cx, len, Visitor)?;
+
+                Ok(Node::Prefix {
+                    embedded: tag.data().is_some(),
+                    bytes,
+                })
+            }
+            Kind::Sequence => {
+                let len = if let Some(len) = tag.data() {
+                    len as usize
+                } else {
+                    self.read_len()?
+                };
+
+                self.budget.sequence(self.cx, mark, len)?;
+
+                let mut items = Vec::with_capacity(len.min(64));
+
+                self.cx.enter_depth()?;
+
+                let result = (0..len).try_for_each(|_| {
+                    let decoder = WireDecoder::<_, OPT, _>::new(
+                        self.cx,
+                        self.budget,
+                        self.reader.borrow_mut(),
+                    );
+                    items.push(decoder.inspect()?);
+                    Ok(())
+                });
+
+                self.cx.leave_depth();
+                result?;
+
+                Ok(Node::Sequence {
+                    embedded: tag.data().is_some(),
+                    items,
+                })
+            }
+            Kind::Continuation => {
+                let value = match tag.data() {
+                    Some(value) => value as u128,
+                    None => c::decode_bounded::<_, _, u128>(
+                        self.cx,
+                        self.reader.borrow_mut(),
+                        self.budget.max_continuation(),
+                    )?,
+                };
+
+                Ok(Node::Continuation {
+                    embedded: tag.data().is_some(),
+                    value,
+                })
+            }
+            Kind::Marker if tag == STREAM => {
+                let mut items = Vec::new();
+
+                self.cx.enter_depth()?;
+
+                let result = loop {
+                    if self.reader.peek() == Some(END.byte()) {
+                        break self.reader.skip(self.cx, 1);
+                    }
+
+                    let mark = self.cx.mark();
+
+                    if let Err(error) = self.budget.sequence_item(self.cx, mark, items.len() + 1) {
+                        break Err(error);
+                    }
+
+                    let decoder = WireDecoder::<_, OPT, _>::new(
+                        self.cx,
+                        self.budget,
+                        self.reader.borrow_mut(),
+                    );
+
+                    match decoder.inspect() {
+                        Ok(item) => items.push(item),
+                        Err(error) => break Err(error),
+                    }
+                };
+
+                self.cx.leave_depth();
+                result?;
+                Ok(Node::Stream { items })
+            }
+            Kind::Marker if tag == EXTENSION => {
+                let id = self.read_extension_id()?;
+
+                self.cx.enter_depth()?;
+                let decoder =
+                    WireDecoder::<_, OPT, _>::new(self.cx, self.budget, self.reader.borrow_mut());
+                let value = decoder.inspect();
+                self.cx.leave_depth();
+
+                Ok(Node::Extension {
+                    id,
+                    value: Box::new(value?),
+                })
+            }
+            kind => Err(self
+                .cx
+                .marked_message(mark, format_args!("Cannot inspect kind {kind:?}"))),
         }
     }
 
+    /// Collect where the next value and every value nested in it are
+    /// located, where `len` is the length of the slice being read from.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn spans(mut self, len: usize) -> Result<Span, C::Error> {
+        let start = self.offset(len)?;
+
+        let tag = self
+            .reader
+            .peek()
+            .map(Tag::from_byte)
+            .filter(|tag| tag.kind() == Kind::Sequence || *tag == STREAM || *tag == EXTENSION);
+
+        let Some(tag) = tag else {
+            WireDecoder::<_, OPT, _>::new(self.cx, self.budget, self.reader.borrow_mut())
+                .skip_any()?;
+            let end = self.offset(len)?;
+            return Ok(Span::leaf(start, end));
+        };
+
+        let mark = self.cx.mark();
+        self.reader.skip(self.cx, 1)?;
+
+        let mut items = Vec::new();
+
+        self.cx.enter_depth()?;
+        let result = self.span_items(tag, mark, len, &mut items);
+        self.cx.leave_depth();
+        let items_start = result?;
+
+        let end = self.offset(len)?;
+
+        Ok(Span {
+            start,
+            items_start,
+            end,
+            items,
+        })
+    }
+
+    /// Collect the spans of the items of a container whose tag has been
+    /// read, returning the offset of the first item.
+    #[cfg(feature = "alloc")]
+    fn span_items(
+        &mut self,
+        tag: Tag,
+        mark: C::Mark,
+        len: usize,
+        items: &mut Vec<Span>,
+    ) -> Result<usize, C::Error> {
+        let count = if tag == STREAM {
+            None
+        } else if tag == EXTENSION {
+            self.read_extension_id()?;
+            Some(1)
+        } else {
+            let count = match tag.data() {
+                Some(count) => count as usize,
+                None => self.read_len()?,
+            };
+
+            self.budget.sequence(self.cx, mark, count)?;
+            Some(count)
+        };
+
+        let items_start = self.offset(len)?;
+
+        let Some(count) = count else {
+            while self.reader.peek() != Some(END.byte()) {
+                let mark = self.cx.mark();
+                self.budget.sequence_item(self.cx, mark, items.len() + 1)?;
+                let decoder =
+                    WireDecoder::<_, OPT, _>::new(self.cx, self.budget, self.reader.borrow_mut());
+                items.push(decoder.spans(len)?);
+            }
+
+            self.reader.skip(self.cx, 1)?;
+            return Ok(items_start);
+        };
+
+        items.reserve(count.min(64));
+
+        for _ in 0..count {
+            let decoder =
+                WireDecoder::<_, OPT, _>::new(self.cx, self.budget, self.reader.borrow_mut());
+            items.push(decoder.spans(len)?);
+        }
+
+        Ok(items_start)
+    }
+
+    /// The offset of the reader in a slice of length `len`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn offset(&self, len: usize) -> Result<usize, C::Error> {
+        let Some(remaining) = self.reader.remaining_slice() else {
+            return Err(self
+                .cx
+                .message("Patches can only be computed between slices"));
+        };
+
+        Ok(len - remaining.len())
+    }
+
+    /// Read the type id which follows an [`EXTENSION`] tag.
+    #[inline]
+    fn read_extension_id(&mut self) -> Result<u32, C::Error> {
+        c::decode_bounded(
+            self.cx,
+            self.reader.borrow_mut(),
+            self.budget.max_continuation(),
+        )
+    }
+
+    #[inline]
+    fn decode_sequence_len(&mut self) -> Result<usize, C::Error> {
+        let mark = self.cx.mark();
+        let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
+
+        let len = match tag.kind() {
+            Kind::Sequence => {
+                if let Some(len) = tag.data() {
+                    len as usize
+                } else {
+                    self.read_len()?
+                }
+            }
+            _ => {
+                return Err(self.cx.marked_message(
+                    mark,
+                    Expected {
+                        expected: Kind::Sequence,
+                        actual: tag,
+                    },
+                ))
+            }
+        };
+
+        self.budget.sequence(self.cx, mark, len)?;
+        Ok(len)
+    }
+
     // Standard function for decoding a pair sequence.
     #[inline]
     fn shared_decode_pair_sequence(
         mut self,
-    ) -> Result<RemainingWireDecoder<'a, R, OPT, C>, C::Error> {
+    ) -> Result<RemainingWireDecoder<'a, 'b, R, OPT, C>, C::Error> {
         let len = self.decode_sequence_len()?;
-        Ok(RemainingWireDecoder::new(self.cx, self.reader, len / 2))
+        Ok(RemainingWireDecoder::new(
+            self.cx,
+            self.budget,
+            self.reader,
+            len / 2,
+        ))
     }
 
-    // Standard function for decoding a pair sequence.
+    // Standard function for decoding a sequence, which might be terminated
+    // instead of length-prefixed.
     #[inline]
-    fn shared_decode_sequence(mut self) -> Result<RemainingWireDecoder<'a, R, OPT, C>, C::Error> {
+    fn shared_decode_sequence(
+        mut self,
+    ) -> Result<RemainingWireDecoder<'a, 'b, R, OPT, C>, C::Error> {
+        if self.reader.peek() == Some(STREAM.byte()) {
+            self.reader.skip(self.cx, 1)?;
+
+            return Ok(RemainingWireDecoder {
+                cx: self.cx,
+                budget: self.budget,
+                reader: self.reader,
+                remaining: 0,
+                terminated: true,
+            });
+        }
+
         let len = self.decode_sequence_len()?;
-        Ok(RemainingWireDecoder::new(self.cx, self.reader, len))
+        Ok(RemainingWireDecoder::new(
+            self.cx,
+            self.budget,
+            self.reader,
+            len,
+        ))
     }
 
     /// Decode the length of a prefix.
@@ -140,13 +479,23 @@ where
             Kind::Prefix => Ok(if let Some(len) = tag.data() {
                 len as usize
             } else {
-                crate::int::decode_usize::<_, _, OPT>(self.cx, self.reader.borrow_mut())?
+                self.read_len()?
             }),
             kind => Err(self
                 .cx
                 .marked_message(start, format_args!("Expected prefix, but got {kind:?}"))),
         }
     }
+
+    /// Read a length which didn't fit in the data of its tag.
+    #[inline]
+    fn read_len(&mut self) -> Result<usize, C::Error> {
+        crate::int::decode_usize_bounded::<_, _, OPT>(
+            self.cx,
+            self.reader.borrow_mut(),
+            self.budget.max_continuation(),
+        )
+    }
 }
 
 /// A length-prefixed decode wrapper.
@@ -154,23 +503,29 @@ where
 /// This simplifies implementing decoders that do not have any special handling
 /// for length-prefixed types.
 #[doc(hidden)]
-pub struct RemainingWireDecoder<'a, R, const OPT: Options, C: ?Sized> {
+pub struct RemainingWireDecoder<'a, 'b, R, const OPT: Options, C: ?Sized> {
     cx: &'a C,
+    budget: &'b Budget,
     reader: R,
+    /// The number of remaining values, or the number of values decoded so far
+    /// if the sequence is terminated by an end marker.
     remaining: usize,
+    terminated: bool,
 }
 
-impl<'a, 'de, R, const OPT: Options, C> RemainingWireDecoder<'a, R, OPT, C>
+impl<'a, 'b, 'de, R, const OPT: Options, C> RemainingWireDecoder<'a, 'b, R, OPT, C>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
 {
     #[inline]
-    fn new(cx: &'a C, reader: R, remaining: usize) -> Self {
+    fn new(cx: &'a C, budget: &'b Budget, reader: R, remaining: usize) -> Self {
         Self {
             cx,
+            budget,
             reader,
             remaining,
+            terminated: false,
         }
     }
 
@@ -203,7 +558,7 @@ where
 }
 
 #[crate::decoder(crate)]
-impl<'a, 'de, R, const OPT: Options, C> Decoder<'de> for WireDecoder<'a, R, OPT, C>
+impl<'a, 'b, 'de, R, const OPT: Options, C> Decoder<'de> for WireDecoder<'a, 'b, R, OPT, C>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
@@ -211,13 +566,13 @@ where
     type Cx = C;
     type Error = C::Error;
     type Mode = C::Mode;
-    type WithContext<'this, U> = WireDecoder<'this, R, OPT, U> where U: 'this + Context;
-    type DecodePack = WireDecoder<'a, Limit<R>, OPT, C>;
+    type WithContext<'this, U> = WireDecoder<'this, 'b, R, OPT, U> where U: 'this + Context;
+    type DecodePack<'this> = WireDecoder<'a, 'b, Limit<R>, OPT, C>;
     type DecodeSome = Self;
-    type DecodeSequence = RemainingWireDecoder<'a, R, OPT, C>;
-    type DecodeMap = RemainingWireDecoder<'a, R, OPT, C>;
-    type DecodeMapEntries = RemainingWireDecoder<'a, R, OPT, C>;
-    type DecodeVariant = Self;
+    type DecodeSequence<'this> = RemainingWireDecoder<'a, 'b, R, OPT, C>;
+    type DecodeMap<'this> = RemainingWireDecoder<'a, 'b, R, OPT, C>;
+    type DecodeMapEntries<'this> = RemainingWireDecoder<'a, 'b, R, OPT, C>;
+    type DecodeVariant<'this> = Self;
 
     #[inline]
     fn cx(&self) -> &C {
@@ -229,7 +584,7 @@ where
     where
         U: Context,
     {
-        Ok(WireDecoder::new(cx, self.reader))
+        Ok(WireDecoder::new(cx, self.budget, self.reader))
     }
 
     #[inline]
@@ -261,6 +616,12 @@ where
 
     #[inline]
     fn try_skip(self) -> Result<Skip, C::Error> {
+        // Reporting that skipping is unsupported causes unknown fields and
+        // variants to be rejected by the derived implementations.
+        if self.budget.is_strict() {
+            return Ok(Skip::Unsupported);
+        }
+
         self.skip()?;
         Ok(Skip::Skipped)
     }
@@ -273,12 +634,13 @@ where
     #[inline]
     fn decode_pack<F, O>(mut self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodePack) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodePack<'_>) -> Result<O, C::Error>,
     {
         let mark = self.cx.mark();
         let len = self.decode_len(mark)?;
-        let mut decoder = WireDecoder::new(self.cx, self.reader.limit(len));
-        let output = f(&mut decoder)?;
+        self.budget.check_element(self.cx, mark, len)?;
+        let mut decoder = WireDecoder::new(self.cx, self.budget, self.reader.limit(len));
+        let output = nested(self.cx, || f(&mut decoder))?;
         decoder.end()?;
         Ok(output)
     }
@@ -301,6 +663,24 @@ where
         self.reader.read_array(self.cx)
     }
 
+    #[inline]
+    fn decode_array_slice(mut self, out: &mut [u8]) -> Result<(), C::Error> {
+        let mark = self.cx.mark();
+        let len = self.decode_len(mark)?;
+
+        if len != out.len() {
+            return Err(self.cx.marked_message(
+                mark,
+                BadLength {
+                    actual: len,
+                    expected: out.len(),
+                },
+            ));
+        }
+
+        self.reader.read(self.cx, out)
+    }
+
     #[inline]
     fn decode_bytes<V>(mut self, visitor: V) -> Result<V::Ok, C::Error>
     where
@@ -308,17 +688,86 @@ where
     {
         let mark = self.cx.mark();
         let len = self.decode_len(mark)?;
+        self.budget.element(self.cx, mark, len)?;
         self.reader.read_bytes(self.cx, len, visitor)
     }
 
+    #[inline]
+    fn decode_extension<T>(mut self, id: u32) -> Result<T, C::Error>
+    where
+        T: Decode<'de, Self::Mode>,
+    {
+        let mark = self.cx.mark();
+        let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
+
+        if tag != EXTENSION {
+            return Err(self
+                .cx
+                .marked_message(mark, ExpectedExtension { actual: tag }));
+        }
+
+        let actual = self.read_extension_id()?;
+
+        if actual != id {
+            return Err(self.cx.marked_message(
+                mark,
+                BadExtension {
+                    actual,
+                    expected: id,
+                },
+            ));
+        }
+
+        self.decode()
+    }
+
+    #[inline]
+    fn decode_raw<V>(mut self, visitor: V) -> Result<V::Ok, C::Error>
+    where
+        V: UnsizedVisitor<'de, C, [u8]>,
+    {
+        let Some(before) = self.reader.remaining_slice() else {
+            return Err(self
+                .cx
+                .message("Raw values can only be decoded from a slice"));
+        };
+
+        let decoder = WireDecoder::<_, OPT, _>::new(self.cx, self.budget, self.reader.borrow_mut());
+        decoder.skip_any()?;
+        let after = self.reader.remaining_slice().map_or(0, <[u8]>::len);
+        visitor.visit_borrowed(self.cx, &before[..before.len() - after])
+    }
+
     #[inline]
     fn decode_string<V>(self, visitor: V) -> Result<V::Ok, C::Error>
     where
         V: UnsizedVisitor<'de, C, str>,
     {
-        struct Visitor<V>(V);
+        struct Visitor<V, M> {
+            visitor: V,
+            mark: M,
+            trusted: bool,
+        }
 
-        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<V>
+        impl<V, M> Visitor<V, M> {
+            #[inline]
+            fn to_str<'s, C>(&self, cx: &C, bytes: &'s [u8]) -> Result<&'s str, C::Error>
+            where
+                C: ?Sized + Context<Mark = M>,
+                M: Copy,
+            {
+                if self.trusted {
+                    // SAFETY: Trusted input is guaranteed by the caller to
+                    // have been produced by an encoder, which only writes
+                    // valid strings.
+                    return Ok(unsafe { core::str::from_utf8_unchecked(bytes) });
+                }
+
+                crate::str::from_utf8(bytes).map_err(|error| cx.marked_custom(self.mark, error))
+            }
+        }
+
+        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<V, C::Mark>
         where
             C: ?Sized + Context,
             V: UnsizedVisitor<'de, C, str>,
@@ -327,30 +776,44 @@ where
 
             #[inline]
             fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                self.0.expecting(f)
+                self.visitor.expecting(f)
             }
 
             #[cfg(feature = "alloc")]
             #[inline]
             fn visit_owned(self, cx: &C, bytes: Vec<u8>) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8_owned(bytes).map_err(cx.map())?;
-                self.0.visit_owned(cx, string)
+                let string = if self.trusted {
+                    // SAFETY: See `Visitor::to_str`.
+                    unsafe { rust_alloc::string::String::from_utf8_unchecked(bytes) }
+                } else {
+                    crate::str::from_utf8_owned(bytes)
+                        .map_err(|error| cx.marked_custom(self.mark, error))?
+                };
+
+                self.visitor.visit_owned(cx, string)
             }
 
             #[inline]
             fn visit_borrowed(self, cx: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
-                self.0.visit_borrowed(cx, string)
+                let string = self.to_str(cx, bytes)?;
+                self.visitor.visit_borrowed(cx, string)
             }
 
             #[inline]
             fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
-                self.0.visit_ref(cx, string)
+                let string = self.to_str(cx, bytes)?;
+                self.visitor.visit_ref(cx, string)
             }
         }
 
-        self.decode_bytes(Visitor(visitor))
+        let mark = self.cx.mark();
+        let trusted = self.budget.is_trusted();
+
+        self.decode_bytes(Visitor {
+            visitor,
+            mark,
+            trusted,
+        })
     }
 
     #[inline]
@@ -358,49 +821,71 @@ where
         const FALSE: Tag = Tag::new(Kind::Continuation, 0);
         const TRUE: Tag = Tag::new(Kind::Continuation, 1);
 
+        let mark = self.cx.mark();
         let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
 
         match tag {
             FALSE => Ok(false),
             TRUE => Ok(true),
-            tag => Err(self.cx.message(BadBoolean { actual: tag })),
+            tag => Err(self.cx.marked_message(mark, BadBoolean { actual: tag })),
         }
     }
 
     #[inline]
     fn decode_char(self) -> Result<char, C::Error> {
         let cx = self.cx;
+        let mark = cx.mark();
         let num = self.decode_u32()?;
 
         match char::from_u32(num) {
             Some(d) => Ok(d),
-            None => Err(cx.message(BadCharacter(num))),
+            None => Err(cx.marked_message(mark, BadCharacter(num))),
         }
     }
 
     #[inline]
     fn decode_u8(self) -> Result<u8, C::Error> {
-        crate::wire::int::decode_unsigned::<_, _, _, OPT>(self.cx, self.reader)
+        crate::wire::int::decode_unsigned::<_, _, _, OPT>(
+            self.cx,
+            self.reader,
+            self.budget.max_continuation(),
+        )
     }
 
     #[inline]
     fn decode_u16(self) -> Result<u16, C::Error> {
-        crate::wire::int::decode_unsigned::<_, _, _, OPT>(self.cx, self.reader)
+        crate::wire::int::decode_unsigned::<_, _, _, OPT>(
+            self.cx,
+            self.reader,
+            self.budget.max_continuation(),
+        )
     }
 
     #[inline]
     fn decode_u32(self) -> Result<u32, C::Error> {
-        crate::wire::int::decode_unsigned::<_, _, _, OPT>(self.cx, self.reader)
+        crate::wire::int::decode_unsigned::<_, _, _, OPT>(
+            self.cx,
+            self.reader,
+            self.budget.max_continuation(),
+        )
     }
 
     #[inline]
     fn decode_u64(self) -> Result<u64, C::Error> {
-        crate::wire::int::decode_unsigned::<_, _, _, OPT>(self.cx, self.reader)
+        crate::wire::int::decode_unsigned::<_, _, _, OPT>(
+            self.cx,
+            self.reader,
+            self.budget.max_continuation(),
+        )
     }
 
     #[inline]
     fn decode_u128(self) -> Result<u128, C::Error> {
-        crate::wire::int::decode_unsigned::<_, _, _, OPT>(self.cx, self.reader)
+        crate::wire::int::decode_unsigned::<_, _, _, OPT>(
+            self.cx,
+            self.reader,
+            self.budget.max_continuation(),
+        )
     }
 
     #[inline]
@@ -410,27 +895,47 @@ where
 
     #[inline]
     fn decode_i16(self) -> Result<i16, C::Error> {
-        crate::wire::int::decode_signed::<_, _, _, OPT>(self.cx, self.reader)
+        crate::wire::int::decode_signed::<_, _, _, OPT>(
+            self.cx,
+            self.reader,
+            self.budget.max_continuation(),
+        )
     }
 
     #[inline]
     fn decode_i32(self) -> Result<i32, C::Error> {
-        crate::wire::int::decode_signed::<_, _, _, OPT>(self.cx, self.reader)
+        crate::wire::int::decode_signed::<_, _, _, OPT>(
+            self.cx,
+            self.reader,
+            self.budget.max_continuation(),
+        )
     }
 
     #[inline]
     fn decode_i64(self) -> Result<i64, C::Error> {
-        crate::wire::int::decode_signed::<_, _, _, OPT>(self.cx, self.reader)
+        crate::wire::int::decode_signed::<_, _, _, OPT>(
+            self.cx,
+            self.reader,
+            self.budget.max_continuation(),
+        )
     }
 
     #[inline]
     fn decode_i128(self) -> Result<i128, C::Error> {
-        crate::wire::int::decode_signed::<_, _, _, OPT>(self.cx, self.reader)
+        crate::wire::int::decode_signed::<_, _, _, OPT>(
+            self.cx,
+            self.reader,
+            self.budget.max_continuation(),
+        )
     }
 
     #[inline]
     fn decode_usize(self) -> Result<usize, C::Error> {
-        crate::wire::int::decode_length::<_, _, OPT>(self.cx, self.reader)
+        crate::wire::int::decode_length::<_, _, OPT>(
+            self.cx,
+            self.reader,
+            self.budget.max_continuation(),
+        )
     }
 
     #[inline]
@@ -460,22 +965,24 @@ where
         const NONE: Tag = Tag::new(Kind::Sequence, 0);
         const SOME: Tag = Tag::new(Kind::Sequence, 1);
 
+        let mark = self.cx.mark();
         let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
 
         match tag {
             NONE => Ok(None),
             SOME => Ok(Some(self)),
-            tag => Err(self.cx.message(ExpectedOption { tag })),
+            tag => Err(self.cx.marked_message(mark, ExpectedOption { tag })),
         }
     }
 
     #[inline]
     fn decode_sequence<F, O>(self, f: F) -> Result<O, <Self::Cx as Context>::Error>
     where
-        F: FnOnce(&mut Self::DecodeSequence) -> Result<O, <Self::Cx as Context>::Error>,
+        F: FnOnce(&mut Self::DecodeSequence<'_>) -> Result<O, <Self::Cx as Context>::Error>,
     {
+        let cx = self.cx;
         let mut decoder = self.shared_decode_sequence()?;
-        let output = f(&mut decoder)?;
+        let output = nested(cx, || f(&mut decoder))?;
         decoder.skip_sequence_remaining()?;
         Ok(output)
     }
@@ -483,7 +990,7 @@ where
     #[inline]
     fn decode_sequence_hint<F, O>(self, _: &SequenceHint, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeSequence) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeSequence<'_>) -> Result<O, C::Error>,
     {
         self.decode_sequence(f)
     }
@@ -491,10 +998,11 @@ where
     #[inline]
     fn decode_map<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeMap) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeMap<'_>) -> Result<O, C::Error>,
     {
+        let cx = self.cx;
         let mut decoder = self.shared_decode_pair_sequence()?;
-        let output = f(&mut decoder)?;
+        let output = nested(cx, || f(&mut decoder))?;
         decoder.skip_remaining_entries()?;
         Ok(output)
     }
@@ -502,7 +1010,7 @@ where
     #[inline]
     fn decode_map_hint<F, O>(self, _: &MapHint, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeMap) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeMap<'_>) -> Result<O, C::Error>,
     {
         self.decode_map(f)
     }
@@ -510,7 +1018,7 @@ where
     #[inline]
     fn decode_map_entries<F, O>(self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeMapEntries) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeMapEntries<'_>) -> Result<O, C::Error>,
     {
         self.decode_map(f)
     }
@@ -518,22 +1026,27 @@ where
     #[inline]
     fn decode_variant<F, O>(mut self, f: F) -> Result<O, C::Error>
     where
-        F: FnOnce(&mut Self::DecodeVariant) -> Result<O, C::Error>,
+        F: FnOnce(&mut Self::DecodeVariant<'_>) -> Result<O, C::Error>,
     {
+        let mark = self.cx.mark();
         let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
 
         if tag != Tag::new(Kind::Sequence, 2) {
-            return Err(self.cx.message(Expected {
-                expected: Kind::Sequence,
-                actual: tag,
-            }));
+            return Err(self.cx.marked_message(
+                mark,
+                Expected {
+                    expected: Kind::Sequence,
+                    actual: tag,
+                },
+            ));
         }
 
-        f(&mut self)
+        nested(self.cx, || f(&mut self))
     }
 }
 
-impl<'a, 'de, R, const OPT: Options, C> SequenceDecoder<'de> for WireDecoder<'a, Limit<R>, OPT, C>
+impl<'a, 'b, 'de, R, const OPT: Options, C> SequenceDecoder<'de>
+    for WireDecoder<'a, 'b, Limit<R>, OPT, C>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
@@ -552,72 +1065,147 @@ where
     }
 }
 
-impl<'a, 'de, R, const OPT: Options, C> SequenceDecoder<'de> for RemainingWireDecoder<'a, R, OPT, C>
+impl<'a, 'b, 'de, R, const OPT: Options, C> SequenceDecoder<'de>
+    for RemainingWireDecoder<'a, 'b, R, OPT, C>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
 {
     type Cx = C;
-    type DecodeNext<'this> = WireDecoder<'a, R::Mut<'this>, OPT, C> where Self: 'this;
+    type DecodeNext<'this> = WireDecoder<'a, 'b, R::Mut<'this>, OPT, C> where Self: 'this;
 
     #[inline]
     fn size_hint(&self) -> SizeHint {
-        SizeHint::exact(self.remaining)
+        if self.terminated {
+            SizeHint::any()
+        } else {
+            SizeHint::exact(self.remaining)
+        }
     }
 
     #[inline]
     fn try_decode_next(&mut self) -> Result<Option<Self::DecodeNext<'_>>, C::Error> {
-        if self.remaining == 0 {
-            return Ok(None);
+        if self.terminated {
+            let mark = self.cx.mark();
+
+            if self.reader.peek() == Some(END.byte()) {
+                self.reader.skip(self.cx, 1)?;
+                self.terminated = false;
+                self.remaining = 0;
+                return Ok(None);
+            }
+
+            self.remaining += 1;
+            self.budget.sequence_item(self.cx, mark, self.remaining)?;
+        } else {
+            if self.remaining == 0 {
+                return Ok(None);
+            }
+
+            self.remaining -= 1;
         }
 
-        self.remaining -= 1;
-        Ok(Some(WireDecoder::new(self.cx, self.reader.borrow_mut())))
+        Ok(Some(WireDecoder::new(
+            self.cx,
+            self.budget,
+            self.reader.borrow_mut(),
+        )))
     }
 
     #[inline]
     fn decode_next(&mut self) -> Result<Self::DecodeNext<'_>, C::Error> {
-        if self.remaining == 0 {
-            return Err(self
-                .cx
-                .message(format_args!("No more tuple elements to decode")));
+        let cx = self.cx;
+
+        let Some(decoder) = self.try_decode_next()? else {
+            return Err(cx.message(format_args!("No more tuple elements to decode")));
+        };
+
+        Ok(decoder)
+    }
+
+    #[inline]
+    fn try_decode_pod_into(&mut self, out: PodSliceMut<'_>) -> Result<bool, C::Error> {
+        use crate::int::zigzag as zig;
+        use crate::wire::int::decode_fixed_slice;
+
+        if self.terminated || !matches!(crate::options::integer::<OPT>(), Integer::Fixed) {
+            return Ok(false);
         }
 
-        self.remaining -= 1;
-        Ok(WireDecoder::new(self.cx, self.reader.borrow_mut()))
+        if out.len() > self.remaining {
+            return Err(self.cx.message("No more tuple elements to decode"));
+        }
+
+        let cx = self.cx;
+        let len = out.len();
+        let reader = self.reader.borrow_mut();
+
+        macro_rules! fixed {
+            ($slice:expr, $map:expr) => {
+                decode_fixed_slice::<_, _, _, _, OPT>(cx, reader, $slice, $map)?
+            };
+        }
+
+        match out {
+            PodSliceMut::U8(slice) => fixed!(slice, |v: u8| v),
+            PodSliceMut::U16(slice) => fixed!(slice, |v: u16| v),
+            PodSliceMut::U32(slice) => fixed!(slice, |v: u32| v),
+            PodSliceMut::U64(slice) => fixed!(slice, |v: u64| v),
+            PodSliceMut::U128(slice) => fixed!(slice, |v: u128| v),
+            PodSliceMut::I8(slice) => fixed!(slice, zig::decode::<u8>),
+            PodSliceMut::I16(slice) => fixed!(slice, zig::decode::<u16>),
+            PodSliceMut::I32(slice) => fixed!(slice, zig::decode::<u32>),
+            PodSliceMut::I64(slice) => fixed!(slice, zig::decode::<u64>),
+            PodSliceMut::I128(slice) => fixed!(slice, zig::decode::<u128>),
+            PodSliceMut::F32(slice) => fixed!(slice, f32::from_bits),
+            PodSliceMut::F64(slice) => fixed!(slice, f64::from_bits),
+            _ => return Ok(false),
+        }
+
+        self.remaining -= len;
+        Ok(true)
     }
 }
 
-impl<'a, 'de, R, const OPT: Options, C> VariantDecoder<'de> for WireDecoder<'a, R, OPT, C>
+impl<'a, 'b, 'de, R, const OPT: Options, C> VariantDecoder<'de> for WireDecoder<'a, 'b, R, OPT, C>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
 {
     type Cx = C;
-    type DecodeTag<'this> = WireDecoder<'a, R::Mut<'this>, OPT, C> where Self: 'this;
-    type DecodeValue<'this> = WireDecoder<'a, R::Mut<'this>, OPT, C> where Self: 'this;
+    type DecodeTag<'this> = WireDecoder<'a, 'b, R::Mut<'this>, OPT, C> where Self: 'this;
+    type DecodeValue<'this> = WireDecoder<'a, 'b, R::Mut<'this>, OPT, C> where Self: 'this;
 
     #[inline]
     fn decode_tag(&mut self) -> Result<Self::DecodeTag<'_>, C::Error> {
-        Ok(WireDecoder::new(self.cx, self.reader.borrow_mut()))
+        Ok(WireDecoder::new(
+            self.cx,
+            self.budget,
+            self.reader.borrow_mut(),
+        ))
     }
 
     #[inline]
     fn decode_value(&mut self) -> Result<Self::DecodeValue<'_>, C::Error> {
-        Ok(WireDecoder::new(self.cx, self.reader.borrow_mut()))
+        Ok(WireDecoder::new(
+            self.cx,
+            self.budget,
+            self.reader.borrow_mut(),
+        ))
     }
 }
 
-impl<'a, 'de, R, const OPT: Options, C> MapDecoder<'de> for RemainingWireDecoder<'a, R, OPT, C>
+impl<'a, 'b, 'de, R, const OPT: Options, C> MapDecoder<'de>
+    for RemainingWireDecoder<'a, 'b, R, OPT, C>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
 {
     type Cx = C;
-    type DecodeEntry<'this> = WireDecoder<'a, R::Mut<'this>, OPT, C>
+    type DecodeEntry<'this> = WireDecoder<'a, 'b, R::Mut<'this>, OPT, C>
     where
         Self: 'this;
-    type DecodeRemainingEntries<'this> = RemainingWireDecoder<'a, R::Mut<'this>, OPT, C> where Self: 'this;
+    type DecodeRemainingEntries<'this> = RemainingWireDecoder<'a, 'b, R::Mut<'this>, OPT, C> where Self: 'this;
 
     #[inline]
     fn size_hint(&self) -> SizeHint {
@@ -631,31 +1219,40 @@ where
         }
 
         self.remaining -= 1;
-        Ok(Some(WireDecoder::new(self.cx, self.reader.borrow_mut())))
+        Ok(Some(WireDecoder::new(
+            self.cx,
+            self.budget,
+            self.reader.borrow_mut(),
+        )))
     }
 
     #[inline]
     fn decode_remaining_entries(&mut self) -> Result<Self::DecodeRemainingEntries<'_>, C::Error> {
         Ok(RemainingWireDecoder::new(
             self.cx,
+            self.budget,
             self.reader.borrow_mut(),
             take(&mut self.remaining),
         ))
     }
 }
 
-impl<'a, 'de, R, const OPT: Options, C> EntryDecoder<'de> for WireDecoder<'a, R, OPT, C>
+impl<'a, 'b, 'de, R, const OPT: Options, C> EntryDecoder<'de> for WireDecoder<'a, 'b, R, OPT, C>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
 {
     type Cx = C;
-    type DecodeKey<'this> = WireDecoder<'a, R::Mut<'this>, OPT, C> where Self: 'this;
+    type DecodeKey<'this> = WireDecoder<'a, 'b, R::Mut<'this>, OPT, C> where Self: 'this;
     type DecodeValue = Self;
 
     #[inline]
     fn decode_key(&mut self) -> Result<Self::DecodeKey<'_>, C::Error> {
-        Ok(WireDecoder::new(self.cx, self.reader.borrow_mut()))
+        Ok(WireDecoder::new(
+            self.cx,
+            self.budget,
+            self.reader.borrow_mut(),
+        ))
     }
 
     #[inline]
@@ -664,16 +1261,17 @@ where
     }
 }
 
-impl<'a, 'de, R, const OPT: Options, C> EntriesDecoder<'de> for RemainingWireDecoder<'a, R, OPT, C>
+impl<'a, 'b, 'de, R, const OPT: Options, C> EntriesDecoder<'de>
+    for RemainingWireDecoder<'a, 'b, R, OPT, C>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
 {
     type Cx = C;
-    type DecodeEntryKey<'this> = WireDecoder<'a, R::Mut<'this>, OPT, C>
+    type DecodeEntryKey<'this> = WireDecoder<'a, 'b, R::Mut<'this>, OPT, C>
     where
         Self: 'this;
-    type DecodeEntryValue<'this> = WireDecoder<'a, R::Mut<'this>, OPT, C>
+    type DecodeEntryValue<'this> = WireDecoder<'a, 'b, R::Mut<'this>, OPT, C>
     where
         Self: 'this;
 
@@ -684,12 +1282,20 @@ where
         }
 
         self.remaining -= 1;
-        Ok(Some(WireDecoder::new(self.cx, self.reader.borrow_mut())))
+        Ok(Some(WireDecoder::new(
+            self.cx,
+            self.budget,
+            self.reader.borrow_mut(),
+        )))
     }
 
     #[inline]
     fn decode_entry_value(&mut self) -> Result<Self::DecodeEntryValue<'_>, C::Error> {
-        Ok(WireDecoder::new(self.cx, self.reader.borrow_mut()))
+        Ok(WireDecoder::new(
+            self.cx,
+            self.budget,
+            self.reader.borrow_mut(),
+        ))
     }
 
     #[inline]
@@ -743,6 +1349,32 @@ impl fmt::Display for ExpectedOption {
     }
 }
 
+struct ExpectedExtension {
+    actual: Tag,
+}
+
+impl fmt::Display for ExpectedExtension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { actual } = *self;
+        write!(f, "Expected extension but was {actual:?}")
+    }
+}
+
+struct BadExtension {
+    actual: u32,
+    expected: u32,
+}
+
+impl fmt::Display for BadExtension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { actual, expected } = *self;
+        write!(
+            f,
+            "Extension type {actual} does not match the expected {expected}"
+        )
+    }
+}
+
 struct BadLength {
     actual: usize,
     expected: usize,
@@ -1,18 +1,27 @@
 use core::fmt;
+use core::mem::size_of;
+
+use crate::alloc::{Allocator, Vec};
 
 use crate::en::{
-    Encode, Encoder, EntriesEncoder, EntryEncoder, MapEncoder, SequenceEncoder, VariantEncoder,
+    Encode, Encoder, EntriesEncoder, EntryEncoder, MapEncoder, PodSlice, SequenceEncoder,
+    VariantEncoder,
 };
 use crate::hint::{MapHint, SequenceHint};
+use crate::int::continuation as c;
+use crate::options::{ByteOrder, Integer};
 use crate::storage::en::StorageEncoder;
 use crate::writer::BufWriter;
 use crate::{Context, Options, Writer};
 
-use super::tag::{Kind, Tag};
+use super::padding::PackPadding;
+use super::tag::{Kind, Tag, DATA_MASK, END, EXTENSION, STREAM};
 
 /// A very simple encoder.
 pub struct WireEncoder<'a, W, const OPT: Options, C: ?Sized> {
     cx: &'a C,
+    canonical: bool,
+    padding: PackPadding,
     writer: W,
 }
 
@@ -22,9 +31,18 @@ where
     C: ?Sized + Context,
 {
     /// Construct a new fixed width message encoder.
+    ///
+    /// If `canonical` is set, the entries of maps are sorted by their encoded
+    /// bytes so that the output doesn't depend on iteration order. The content
+    /// of packs is padded according to `padding`.
     #[inline]
-    pub(crate) fn new(cx: &'a C, writer: W) -> Self {
-        Self { cx, writer }
+    pub(crate) fn new(cx: &'a C, canonical: bool, padding: PackPadding, writer: W) -> Self {
+        Self {
+            cx,
+            canonical,
+            padding,
+            writer,
+        }
     }
 
     #[inline]
@@ -61,22 +79,417 @@ where
     C: ?Sized + Context,
 {
     cx: &'a C,
-    writer: W,
-    buffer: BufWriter<'a, C::Allocator>,
+    padding: PackPadding,
+    writer: PackWriter<'a, W, C::Allocator>,
 }
 
 impl<'a, W, const OPT: Options, C> WireSequenceEncoder<'a, W, OPT, C>
 where
     C: ?Sized + Context,
+    W: Writer,
 {
     /// Construct a new fixed width message encoder.
+    ///
+    /// The pack is buffered until it's too long for its length to be embedded
+    /// in the tag of the prefix. At that point, if lengths are encoded with a
+    /// fixed width and the writer supports it, space for the prefix is
+    /// reserved and the rest of the pack is written directly to the
+    /// underlying writer.
+    ///
+    /// Either way the pack is padded according to `padding` and prefixed with
+    /// its padded length, and the encoding doesn't depend on which writer is
+    /// used.
     #[inline]
-    pub(crate) fn new(cx: &'a C, writer: W) -> Self {
+    pub(crate) fn new(cx: &'a C, padding: PackPadding, writer: W) -> Self {
         Self {
             cx,
+            padding,
+            writer: PackWriter {
+                writer,
+                slot: None,
+                prefix: reserved_prefix_len::<OPT>(),
+                buffer: BufWriter::new(cx.alloc()),
+            },
+        }
+    }
+}
+
+/// The writer for the content of a pack.
+///
+/// This either writes to a buffer which is prefixed with its length once the
+/// pack is finished, or directly to the underlying writer after a reserved
+/// length prefix.
+pub struct PackWriter<'a, W, A>
+where
+    A: 'a + ?Sized + Allocator,
+{
+    writer: W,
+    slot: Option<usize>,
+    /// The length of the prefix to reserve once the buffer no longer fits in
+    /// an embedded length.
+    prefix: Option<usize>,
+    buffer: BufWriter<'a, A>,
+}
+
+impl<'a, W, A> PackWriter<'a, W, A>
+where
+    W: Writer,
+    A: 'a + ?Sized + Allocator,
+{
+    /// Stop buffering if the pack is too long for an embedded length and a
+    /// prefix can be reserved in the underlying writer.
+    #[inline]
+    fn spill<C>(&mut self, cx: &C) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if self.buffer.len() < usize::from(DATA_MASK) {
+            return Ok(());
+        }
+
+        let Some(len) = self.prefix.take() else {
+            return Ok(());
+        };
+
+        let Some(at) = self.writer.reserve(cx, len)? else {
+            return Ok(());
+        };
+
+        self.writer.write_bytes(cx, self.buffer.as_slice())?;
+        self.buffer.clear();
+        self.slot = Some(at);
+        Ok(())
+    }
+}
+
+impl<'a, W, A> Writer for PackWriter<'a, W, A>
+where
+    W: Writer,
+    A: 'a + ?Sized + Allocator,
+{
+    type Mut<'this> = &'this mut Self where Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn extend<C>(&mut self, cx: &C, buffer: Vec<'_, u8, C::Allocator>) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if self.slot.is_some() {
+            return self.writer.extend(cx, buffer);
+        }
+
+        self.buffer.extend(cx, buffer)?;
+        self.spill(cx)
+    }
+
+    #[inline]
+    fn write_bytes<C>(&mut self, cx: &C, bytes: &[u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if self.slot.is_some() {
+            return self.writer.write_bytes(cx, bytes);
+        }
+
+        self.buffer.write_bytes(cx, bytes)?;
+        self.spill(cx)
+    }
+
+    #[inline]
+    fn write_byte<C>(&mut self, cx: &C, b: u8) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if self.slot.is_some() {
+            return self.writer.write_byte(cx, b);
+        }
+
+        self.buffer.write_byte(cx, b)?;
+        self.spill(cx)
+    }
+
+    #[inline]
+    fn reserve<C>(&mut self, cx: &C, len: usize) -> Result<Option<usize>, C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        // Offsets into the buffer would be invalidated once it's spilled.
+        if self.slot.is_some() {
+            self.writer.reserve(cx, len)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    fn reserved_mut(&mut self, at: usize) -> &mut [u8] {
+        if self.slot.is_some() {
+            self.writer.reserved_mut(at)
+        } else {
+            &mut []
+        }
+    }
+}
+
+/// The writer at the root of an encoding.
+///
+/// Sorting the entries of maps in canonical mode requires going back to
+/// modify what has already been written. If the underlying writer doesn't
+/// support that, everything is buffered and written once encoding is
+/// finished.
+pub struct CanonicalWriter<'a, W, A>
+where
+    A: 'a + ?Sized + Allocator,
+{
+    writer: W,
+    buffer: Option<BufWriter<'a, A>>,
+}
+
+impl<'a, W, A> CanonicalWriter<'a, W, A>
+where
+    W: Writer,
+    A: 'a + ?Sized + Allocator,
+{
+    #[inline]
+    pub(crate) fn new<C>(cx: &'a C, canonical: bool, mut writer: W) -> Result<Self, C::Error>
+    where
+        C: ?Sized + Context<Allocator = A>,
+    {
+        let buffer = if canonical && writer.reserve(cx, 0)?.is_none() {
+            Some(BufWriter::new(cx.alloc()))
+        } else {
+            None
+        };
+
+        Ok(Self { writer, buffer })
+    }
+
+    /// Write anything which has been buffered and return the underlying
+    /// writer.
+    #[inline]
+    pub(crate) fn finish<C>(self, cx: &C) -> Result<W, C::Error>
+    where
+        C: ?Sized + Context<Allocator = A>,
+    {
+        let Self { mut writer, buffer } = self;
+
+        if let Some(buffer) = buffer {
+            writer.extend(cx, buffer.into_inner())?;
+        }
+
+        Ok(writer)
+    }
+}
+
+impl<'a, W, A> Writer for CanonicalWriter<'a, W, A>
+where
+    W: Writer,
+    A: 'a + ?Sized + Allocator,
+{
+    type Mut<'this> = &'this mut Self where Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn extend<C>(&mut self, cx: &C, buffer: Vec<'_, u8, C::Allocator>) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        match &mut self.buffer {
+            Some(b) => b.extend(cx, buffer),
+            None => self.writer.extend(cx, buffer),
+        }
+    }
+
+    #[inline]
+    fn write_bytes<C>(&mut self, cx: &C, bytes: &[u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        match &mut self.buffer {
+            Some(b) => b.write_bytes(cx, bytes),
+            None => self.writer.write_bytes(cx, bytes),
+        }
+    }
+
+    #[inline]
+    fn write_byte<C>(&mut self, cx: &C, b: u8) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        match &mut self.buffer {
+            Some(buffer) => buffer.write_byte(cx, b),
+            None => self.writer.write_byte(cx, b),
+        }
+    }
+
+    #[inline]
+    fn reserve<C>(&mut self, cx: &C, len: usize) -> Result<Option<usize>, C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        match &mut self.buffer {
+            Some(b) => b.reserve(cx, len),
+            None => self.writer.reserve(cx, len),
+        }
+    }
+
+    #[inline]
+    fn reserved_mut(&mut self, at: usize) -> &mut [u8] {
+        match &mut self.buffer {
+            Some(b) => b.reserved_mut(at),
+            None => self.writer.reserved_mut(at),
+        }
+    }
+}
+
+/// Encoder for the elements of a sequence.
+pub struct SequenceWireEncoder<'a, W, const OPT: Options, C: ?Sized> {
+    encoder: WireEncoder<'a, W, OPT, C>,
+    /// If the sequence is terminated by an end marker, since its length
+    /// wasn't known up front.
+    terminated: bool,
+}
+
+impl<'a, W, const OPT: Options, C> SequenceWireEncoder<'a, W, OPT, C>
+where
+    C: ?Sized + Context,
+{
+    #[inline]
+    fn new(encoder: WireEncoder<'a, W, OPT, C>, terminated: bool) -> Self {
+        Self {
+            encoder,
+            terminated,
+        }
+    }
+}
+
+/// Encoder for the entries of a map.
+///
+/// In canonical mode the offset of every entry is recorded as it's written.
+/// Once the map is finished the entries are sorted in place by their encoded
+/// bytes. Since keys are self-delimiting, this orders entries by their keys.
+pub struct WireMapEncoder<'a, W, const OPT: Options, C>
+where
+    C: ?Sized + Context,
+{
+    cx: &'a C,
+    canonical: bool,
+    padding: PackPadding,
+    writer: W,
+    sorted: Option<Sorted<'a, C::Allocator>>,
+}
+
+struct Sorted<'a, A>
+where
+    A: 'a + ?Sized + Allocator,
+{
+    /// The offset in the writer where the first entry starts.
+    at: usize,
+    /// Where each entry starts, relative to `at`.
+    starts: Vec<'a, usize, A>,
+}
+
+impl<'a, W, const OPT: Options, C> WireMapEncoder<'a, W, OPT, C>
+where
+    C: ?Sized + Context,
+    W: Writer,
+{
+    #[inline]
+    fn new(
+        cx: &'a C,
+        canonical: bool,
+        padding: PackPadding,
+        mut writer: W,
+    ) -> Result<Self, C::Error> {
+        let sorted = if canonical {
+            let Some(at) = writer.reserve(cx, 0)? else {
+                return Err(cx.message("Canonical maps require a writer which can be modified"));
+            };
+
+            Some(Sorted {
+                at,
+                starts: Vec::new_in(cx.alloc()),
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            cx,
+            canonical,
+            padding,
             writer,
-            buffer: BufWriter::new(cx.alloc()),
+            sorted,
+        })
+    }
+
+    /// Mark the start of a new entry.
+    #[inline]
+    fn start_entry(&mut self) -> Result<(), C::Error> {
+        if let Some(sorted) = &mut self.sorted {
+            let start = self.writer.reserved_mut(sorted.at).len();
+
+            if !sorted.starts.push(start) {
+                return Err(self.cx.alloc_failed());
+            }
         }
+
+        Ok(())
+    }
+
+    /// Sort the entries written so far if the map is canonical.
+    #[inline]
+    fn finish(mut self) -> Result<(), C::Error> {
+        let Some(Sorted { at, starts }) = self.sorted else {
+            return Ok(());
+        };
+
+        let entries = self.writer.reserved_mut(at);
+
+        let mut buffer = Vec::new_in(self.cx.alloc());
+
+        if !buffer.write(entries) {
+            return Err(self.cx.alloc_failed());
+        }
+
+        let buffer = buffer.as_slice();
+        let starts = starts.as_slice();
+
+        let entry = |index: usize| {
+            let end = starts.get(index + 1).copied().unwrap_or(buffer.len());
+            &buffer[starts[index]..end]
+        };
+
+        let mut order = Vec::new_in(self.cx.alloc());
+
+        for index in 0..starts.len() {
+            if !order.push(index) {
+                return Err(self.cx.alloc_failed());
+            }
+        }
+
+        order
+            .as_mut_slice()
+            .sort_unstable_by(|&a, &b| entry(a).cmp(entry(b)));
+
+        let mut offset = 0;
+
+        for &index in order.as_slice() {
+            let entry = entry(index);
+            entries[offset..offset + entry.len()].copy_from_slice(entry);
+            offset += entry.len();
+        }
+
+        Ok(())
     }
 }
 
@@ -93,12 +506,12 @@ where
     type WithContext<'this, U> = WireEncoder<'this, W, OPT, U> where U: 'this + Context;
     type EncodePack = WireSequenceEncoder<'a, W, OPT, C>;
     type EncodeSome = Self;
-    type EncodeSequence = Self;
-    type EncodeMap = Self;
-    type EncodeMapEntries = Self;
+    type EncodeSequence = SequenceWireEncoder<'a, W, OPT, C>;
+    type EncodeMap = WireMapEncoder<'a, W, OPT, C>;
+    type EncodeMapEntries = WireMapEncoder<'a, W, OPT, C>;
     type EncodeVariant = Self;
-    type EncodeSequenceVariant = Self;
-    type EncodeMapVariant = Self;
+    type EncodeSequenceVariant = SequenceWireEncoder<'a, W, OPT, C>;
+    type EncodeMapVariant = WireMapEncoder<'a, W, OPT, C>;
 
     #[inline]
     fn cx(&self) -> &Self::Cx {
@@ -110,7 +523,12 @@ where
     where
         U: Context,
     {
-        Ok(WireEncoder::new(cx, self.writer))
+        Ok(WireEncoder::new(
+            cx,
+            self.canonical,
+            self.padding,
+            self.writer,
+        ))
     }
 
     #[inline]
@@ -135,7 +553,7 @@ where
 
     #[inline]
     fn encode_pack(self) -> Result<Self::EncodePack, C::Error> {
-        Ok(WireSequenceEncoder::new(self.cx, self.writer))
+        Ok(WireSequenceEncoder::new(self.cx, self.padding, self.writer))
     }
 
     #[inline]
@@ -143,6 +561,11 @@ where
         self.encode_bytes(array)
     }
 
+    #[inline]
+    fn encode_array_slice(self, array: &[u8]) -> Result<Self::Ok, C::Error> {
+        self.encode_bytes(array)
+    }
+
     #[inline]
     fn encode_bytes(mut self, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
         encode_prefix::<_, _, OPT>(self.cx, self.writer.borrow_mut(), bytes.len())?;
@@ -257,11 +680,25 @@ where
 
     #[inline]
     fn encode_f32(self, value: f32) -> Result<Self::Ok, C::Error> {
+        // There are many ways to represent NaN, so a canonical encoding
+        // settles on one of them.
+        let value = if self.canonical && value.is_nan() {
+            f32::NAN
+        } else {
+            value
+        };
+
         self.encode_u32(value.to_bits())
     }
 
     #[inline]
     fn encode_f64(self, value: f64) -> Result<Self::Ok, C::Error> {
+        let value = if self.canonical && value.is_nan() {
+            f64::NAN
+        } else {
+            value
+        };
+
         self.encode_u64(value.to_bits())
     }
 
@@ -282,13 +719,29 @@ where
     #[inline]
     fn encode_sequence(mut self, hint: &SequenceHint) -> Result<Self::EncodeSequence, C::Error> {
         self.encode_sequence_len(hint.size)?;
-        Ok(self)
+        Ok(SequenceWireEncoder::new(self, false))
+    }
+
+    #[inline]
+    fn encode_unsized_sequence(mut self) -> Result<Self::EncodeSequence, C::Error> {
+        self.writer.write_byte(self.cx, STREAM.byte())?;
+        Ok(SequenceWireEncoder::new(self, true))
+    }
+
+    #[inline]
+    fn encode_extension<T>(mut self, id: u32, value: &T) -> Result<Self::Ok, C::Error>
+    where
+        T: ?Sized + Encode<Self::Mode>,
+    {
+        self.writer.write_byte(self.cx, EXTENSION.byte())?;
+        c::encode(self.cx, self.writer.borrow_mut(), id)?;
+        self.encode(value)
     }
 
     #[inline]
     fn encode_map(mut self, hint: &MapHint) -> Result<Self::EncodeMap, C::Error> {
         self.encode_map_len(hint.size)?;
-        Ok(self)
+        WireMapEncoder::new(self.cx, self.canonical, self.padding, self.writer)
     }
 
     #[inline]
@@ -314,7 +767,13 @@ where
     {
         self.writer
             .write_byte(self.cx, Tag::new(Kind::Sequence, 2).byte())?;
-        WireEncoder::<_, OPT, _>::new(self.cx, self.writer.borrow_mut()).encode(tag)?;
+        WireEncoder::<_, OPT, _>::new(
+            self.cx,
+            self.canonical,
+            self.padding,
+            self.writer.borrow_mut(),
+        )
+        .encode(tag)?;
         self.encode_sequence(hint)
     }
 
@@ -323,13 +782,19 @@ where
         mut self,
         tag: &T,
         hint: &MapHint,
-    ) -> Result<Self::EncodeSequenceVariant, C::Error>
+    ) -> Result<Self::EncodeMapVariant, C::Error>
     where
         T: ?Sized + Encode<C::Mode>,
     {
         self.writer
             .write_byte(self.cx, Tag::new(Kind::Sequence, 2).byte())?;
-        WireEncoder::<_, OPT, _>::new(self.cx, self.writer.borrow_mut()).encode(tag)?;
+        WireEncoder::<_, OPT, _>::new(
+            self.cx,
+            self.canonical,
+            self.padding,
+            self.writer.borrow_mut(),
+        )
+        .encode(tag)?;
         self.encode_map(hint)
     }
 }
@@ -341,23 +806,56 @@ where
 {
     type Cx = C;
     type Ok = ();
-    type EncodeNext<'this> = StorageEncoder<'a, &'this mut BufWriter<'a, C::Allocator>, OPT, C> where Self: 'this;
+    type EncodeNext<'this> = StorageEncoder<'a, &'this mut PackWriter<'a, W, C::Allocator>, OPT, C> where Self: 'this;
 
     #[inline]
     fn encode_next(&mut self) -> Result<Self::EncodeNext<'_>, C::Error> {
-        Ok(StorageEncoder::new(self.cx, &mut self.buffer))
+        Ok(StorageEncoder::new(self.cx, &mut self.writer))
     }
 
     #[inline]
-    fn finish_sequence(mut self) -> Result<Self::Ok, C::Error> {
-        let buffer = self.buffer.into_inner();
-        encode_prefix::<_, _, OPT>(self.cx, self.writer.borrow_mut(), buffer.len())?;
-        self.writer.extend(self.cx, buffer)?;
-        Ok(())
+    fn try_push_pod(&mut self, pod: PodSlice<'_>) -> Result<bool, C::Error> {
+        if pod.width() != 1 && !crate::options::is_native_fixed::<OPT>() {
+            return Ok(false);
+        }
+
+        self.writer.write_bytes(self.cx, pod.as_bytes())?;
+        Ok(true)
+    }
+
+    #[inline]
+    fn finish_sequence(self) -> Result<Self::Ok, C::Error> {
+        let PackWriter {
+            mut writer,
+            slot,
+            buffer,
+            ..
+        } = self.writer;
+
+        if let Some(at) = slot {
+            let Some(prefix) = reserved_prefix_len::<OPT>() else {
+                return Err(self.cx.message("Length prefix was not reserved"));
+            };
+
+            let len = writer.reserved_mut(at).len().saturating_sub(prefix);
+            write_padding(self.cx, writer.borrow_mut(), self.padding, len)?;
+            return patch_prefix::<_, OPT>(self.cx, writer.reserved_mut(at));
+        }
+
+        let buffer = buffer.into_inner();
+        let len = buffer.len();
+
+        let Some(padded) = self.padding.padded_len(len) else {
+            return Err(self.cx.message("Padded pack length overflow"));
+        };
+
+        encode_prefix::<_, _, OPT>(self.cx, writer.borrow_mut(), padded)?;
+        writer.extend(self.cx, buffer)?;
+        write_padding(self.cx, writer, self.padding, len)
     }
 }
 
-impl<'a, W, const OPT: Options, C> SequenceEncoder for WireEncoder<'a, W, OPT, C>
+impl<'a, W, const OPT: Options, C> SequenceEncoder for SequenceWireEncoder<'a, W, OPT, C>
 where
     C: ?Sized + Context,
     W: Writer,
@@ -368,16 +866,71 @@ where
 
     #[inline]
     fn encode_next(&mut self) -> Result<Self::EncodeNext<'_>, C::Error> {
-        Ok(WireEncoder::new(self.cx, self.writer.borrow_mut()))
+        Ok(WireEncoder::new(
+            self.encoder.cx,
+            self.encoder.canonical,
+            self.encoder.padding,
+            self.encoder.writer.borrow_mut(),
+        ))
     }
 
     #[inline]
-    fn finish_sequence(self) -> Result<Self::Ok, C::Error> {
+    fn try_push_pod(&mut self, pod: PodSlice<'_>) -> Result<bool, C::Error> {
+        use crate::int::zigzag as zig;
+        use crate::wire::int::encode_fixed_slice;
+
+        if !matches!(crate::options::integer::<OPT>(), Integer::Fixed) {
+            return Ok(false);
+        }
+
+        let cx = self.encoder.cx;
+        let canonical = self.encoder.canonical;
+        let writer = self.encoder.writer.borrow_mut();
+
+        macro_rules! fixed {
+            ($slice:expr, $map:expr) => {
+                encode_fixed_slice::<_, _, _, OPT>(cx, writer, $slice.iter().map($map))?
+            };
+        }
+
+        // Canonical encoding settles on one representation of NaN, like
+        // encode_f32 and encode_f64 do.
+        match pod {
+            PodSlice::U8(slice) => fixed!(slice, |&v| v),
+            PodSlice::U16(slice) => fixed!(slice, |&v| v),
+            PodSlice::U32(slice) => fixed!(slice, |&v| v),
+            PodSlice::U64(slice) => fixed!(slice, |&v| v),
+            PodSlice::U128(slice) => fixed!(slice, |&v| v),
+            PodSlice::I8(slice) => fixed!(slice, |&v| zig::encode(v)),
+            PodSlice::I16(slice) => fixed!(slice, |&v| zig::encode(v)),
+            PodSlice::I32(slice) => fixed!(slice, |&v| zig::encode(v)),
+            PodSlice::I64(slice) => fixed!(slice, |&v| zig::encode(v)),
+            PodSlice::I128(slice) => fixed!(slice, |&v| zig::encode(v)),
+            PodSlice::F32(slice) => fixed!(slice, |&v: &f32| {
+                if canonical && v.is_nan() { f32::NAN } else { v }.to_bits()
+            }),
+            PodSlice::F64(slice) => fixed!(slice, |&v: &f64| {
+                if canonical && v.is_nan() { f64::NAN } else { v }.to_bits()
+            }),
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    #[inline]
+    fn finish_sequence(mut self) -> Result<Self::Ok, C::Error> {
+        if self.terminated {
+            self.encoder
+                .writer
+                .write_byte(self.encoder.cx, END.byte())?;
+        }
+
         Ok(())
     }
 }
 
-impl<'a, W, const OPT: Options, C> MapEncoder for WireEncoder<'a, W, OPT, C>
+impl<'a, W, const OPT: Options, C> MapEncoder for WireMapEncoder<'a, W, OPT, C>
 where
     C: ?Sized + Context,
     W: Writer,
@@ -388,16 +941,22 @@ where
 
     #[inline]
     fn encode_entry(&mut self) -> Result<Self::EncodeEntry<'_>, C::Error> {
-        Ok(WireEncoder::new(self.cx, self.writer.borrow_mut()))
+        self.start_entry()?;
+        Ok(WireEncoder::new(
+            self.cx,
+            self.canonical,
+            self.padding,
+            self.writer.borrow_mut(),
+        ))
     }
 
     #[inline]
     fn finish_map(self) -> Result<Self::Ok, C::Error> {
-        Ok(())
+        self.finish()
     }
 }
 
-impl<'a, W, const OPT: Options, C> EntriesEncoder for WireEncoder<'a, W, OPT, C>
+impl<'a, W, const OPT: Options, C> EntriesEncoder for WireMapEncoder<'a, W, OPT, C>
 where
     C: ?Sized + Context,
     W: Writer,
@@ -409,17 +968,28 @@ where
 
     #[inline]
     fn encode_entry_key(&mut self) -> Result<Self::EncodeEntryKey<'_>, C::Error> {
-        Ok(WireEncoder::new(self.cx, self.writer.borrow_mut()))
+        self.start_entry()?;
+        Ok(WireEncoder::new(
+            self.cx,
+            self.canonical,
+            self.padding,
+            self.writer.borrow_mut(),
+        ))
     }
 
     #[inline]
     fn encode_entry_value(&mut self) -> Result<Self::EncodeEntryValue<'_>, C::Error> {
-        Ok(WireEncoder::new(self.cx, self.writer.borrow_mut()))
+        Ok(WireEncoder::new(
+            self.cx,
+            self.canonical,
+            self.padding,
+            self.writer.borrow_mut(),
+        ))
     }
 
     #[inline]
     fn finish_entries(self) -> Result<Self::Ok, C::Error> {
-        Ok(())
+        self.finish()
     }
 }
 
@@ -435,12 +1005,22 @@ where
 
     #[inline]
     fn encode_key(&mut self) -> Result<Self::EncodeKey<'_>, C::Error> {
-        Ok(WireEncoder::new(self.cx, self.writer.borrow_mut()))
+        Ok(WireEncoder::new(
+            self.cx,
+            self.canonical,
+            self.padding,
+            self.writer.borrow_mut(),
+        ))
     }
 
     #[inline]
     fn encode_value(&mut self) -> Result<Self::EncodeValue<'_>, C::Error> {
-        Ok(WireEncoder::new(self.cx, self.writer.borrow_mut()))
+        Ok(WireEncoder::new(
+            self.cx,
+            self.canonical,
+            self.padding,
+            self.writer.borrow_mut(),
+        ))
     }
 
     #[inline]
@@ -461,12 +1041,22 @@ where
 
     #[inline]
     fn encode_tag(&mut self) -> Result<Self::EncodeTag<'_>, C::Error> {
-        Ok(WireEncoder::new(self.cx, self.writer.borrow_mut()))
+        Ok(WireEncoder::new(
+            self.cx,
+            self.canonical,
+            self.padding,
+            self.writer.borrow_mut(),
+        ))
     }
 
     #[inline]
     fn encode_data(&mut self) -> Result<Self::EncodeData<'_>, C::Error> {
-        Ok(WireEncoder::new(self.cx, self.writer.borrow_mut()))
+        Ok(WireEncoder::new(
+            self.cx,
+            self.canonical,
+            self.padding,
+            self.writer.borrow_mut(),
+        ))
     }
 
     #[inline]
@@ -495,3 +1085,88 @@ where
 
     Ok(())
 }
+
+/// Write the zeros which pad a pack with `len` bytes of content.
+#[inline]
+fn write_padding<C, W>(
+    cx: &C,
+    mut writer: W,
+    padding: PackPadding,
+    len: usize,
+) -> Result<(), C::Error>
+where
+    C: ?Sized + Context,
+    W: Writer,
+{
+    const ZEROS: [u8; 64] = [0; 64];
+
+    let Some(padded) = padding.padded_len(len) else {
+        return Err(cx.message("Padded pack length overflow"));
+    };
+
+    let mut remaining = padded - len;
+
+    while remaining > 0 {
+        let n = remaining.min(ZEROS.len());
+        writer.write_bytes(cx, &ZEROS[..n])?;
+        remaining -= n;
+    }
+
+    Ok(())
+}
+
+/// The length of a length prefix which can be reserved before its length is
+/// known.
+///
+/// This is only possible if lengths are encoded with a fixed width.
+#[inline]
+const fn reserved_prefix_len<const OPT: Options>() -> Option<usize> {
+    if !matches!(crate::options::length::<OPT>(), Integer::Fixed) {
+        return None;
+    }
+
+    macro_rules! prefix_len {
+        ($ty:ty) => {
+            Some(1 + size_of::<$ty>())
+        };
+    }
+
+    crate::options::width_arm!(crate::options::length_width::<OPT>(), prefix_len)
+}
+
+/// Fill in a length prefix previously reserved with [`reserved_prefix_len`],
+/// where `reserved` is the prefix followed by everything written after it.
+#[inline]
+fn patch_prefix<C, const OPT: Options>(cx: &C, reserved: &mut [u8]) -> Result<(), C::Error>
+where
+    C: ?Sized + Context,
+{
+    let Some(prefix_len) = reserved_prefix_len::<OPT>() else {
+        return Err(cx.message("Length prefix was not reserved"));
+    };
+
+    let Some(len) = reserved.len().checked_sub(prefix_len) else {
+        return Err(cx.message("Reserved length prefix is missing"));
+    };
+
+    let (prefix, _) = reserved.split_at_mut(prefix_len);
+    prefix[0] = Tag::empty(Kind::Prefix).byte();
+
+    macro_rules! fixed {
+        ($ty:ty) => {{
+            let Ok(len) = <$ty>::try_from(len) else {
+                return Err(cx.message("Size type out of bounds for value type"));
+            };
+
+            let bytes = match crate::options::byteorder::<OPT>() {
+                ByteOrder::Little => len.to_le_bytes(),
+                ByteOrder::Big => len.to_be_bytes(),
+            };
+
+            prefix[1..].copy_from_slice(&bytes);
+        }};
+    }
+
+    crate::options::width_arm!(crate::options::length_width::<OPT>(), fixed);
+    Ok(())
+}
@@ -4,16 +4,38 @@
 use core::marker;
 
 use crate::mode::Binary;
-use crate::options;
-use crate::{IntoReader, Options};
+use crate::options::{self, Width};
+use crate::{Context, Decoder, IntoReader, Options, Reader, Writer};
 
+use super::checksum::{Checksum, ChecksumReader, ChecksumWriter};
+#[cfg(any(feature = "zstd", feature = "lz4"))]
+use super::compression::Compression;
 use super::de::WireDecoder;
-use super::en::WireEncoder;
+use super::en::{CanonicalWriter, WireEncoder};
 use super::error::Error;
+use super::header::Header;
+#[cfg(feature = "alloc")]
+use super::inspect::Node;
+use super::limits::{Budget, Limits};
+use super::padding::PackPadding;
+#[cfg(feature = "alloc")]
+use super::patch::{self, Patch, Span};
+use super::project::project;
+#[cfg(all(feature = "std", feature = "alloc"))]
+use super::stream::DecodeStream;
 
 /// The default flavor used by the [`DEFAULT`] configuration.
 pub const OPTIONS: options::Options = options::new().build();
 
+/// The flavor used by [`Encoding::with_fixed_lengths16`], which encodes
+/// lengths as fixed 16-bit integers.
+pub const FIXED_LENGTHS16: options::Options = options::new().with_length_width(Width::U16).build();
+
+/// The flavor used by [`Encoding::with_fixed_lengths_usize`], which encodes
+/// lengths as fixed integers of the same width as `usize`.
+pub const FIXED_LENGTHS_USIZE: options::Options =
+    options::new().with_length_width(Width::USIZE).build();
+
 /// The default configuration.
 ///
 /// Uses variable-encoded numerical fields and variable-encoded prefix lengths.
@@ -27,11 +49,111 @@ pub const DEFAULT: Encoding = Encoding::new();
 
 crate::macros::bare_encoding!(Binary, DEFAULT, wire, IntoReader);
 
+/// Inspect the structure of a message encoded with the [`DEFAULT`]
+/// configuration.
+///
+/// See [`Encoding::inspect`] for more.
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[inline]
+pub fn inspect(bytes: &[u8]) -> Result<Node, Error> {
+    DEFAULT.inspect(bytes)
+}
+
+/// Compute the patch which turns one message encoded with the [`DEFAULT`]
+/// configuration into another.
+///
+/// See [`Encoding::diff`] for more.
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[inline]
+pub fn diff(old: &[u8], new: &[u8]) -> Result<Patch, Error> {
+    DEFAULT.diff(old, new)
+}
+
+/// Apply a patch computed with [`diff`] to a message encoded with the
+/// [`DEFAULT`] configuration.
+///
+/// See [`Encoding::patch`] for more.
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[inline]
+pub fn patch(old: &[u8], patch: &Patch) -> Result<rust_alloc::vec::Vec<u8>, Error> {
+    DEFAULT.patch(old, patch)
+}
+
+/// Decode values of type `T` which have been encoded back to back with the
+/// [`DEFAULT`] configuration from the given reader.
+///
+/// See [`Encoding::decode_stream`] for more.
+#[cfg(all(feature = "std", feature = "alloc"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "alloc"))))]
+#[inline]
+pub fn decode_stream<R, T>(reader: R) -> DecodeStream<R, T>
+where
+    R: std::io::Read,
+    T: crate::de::DecodeOwned<Binary>,
+{
+    DEFAULT.decode_stream(reader)
+}
+
+/// Decode the given type `T` from the start of the given slice using the
+/// [`DEFAULT`] configuration, returning it together with the number of bytes
+/// which were consumed.
+///
+/// See [`Encoding::from_slice_partial`] for more.
+#[inline]
+pub fn from_slice_partial<'de, T>(bytes: &'de [u8]) -> Result<(T, usize), Error>
+where
+    T: crate::Decode<'de, Binary>,
+{
+    DEFAULT.from_slice_partial(bytes)
+}
+
+/// Decode only the value found by following `path` from a message encoded
+/// with the [`DEFAULT`] configuration.
+///
+/// See [`Encoding::from_slice_path`] for more.
+#[inline]
+pub fn from_slice_path<'de, T, K>(bytes: &'de [u8], path: &[K]) -> Result<Option<T>, Error>
+where
+    T: crate::Decode<'de, Binary>,
+    K: crate::Encode<Binary>,
+{
+    DEFAULT.from_slice_path(bytes, path)
+}
+
+/// Decode the given type `T` from a slice which is trusted to be well-formed
+/// using the [`DEFAULT`] configuration.
+///
+/// See [`Encoding::from_slice_unchecked`] for more.
+///
+/// # Safety
+///
+/// The caller must ensure that `bytes` was produced by encoding a value of
+/// type `T` with the [`DEFAULT`] configuration, or a value which is
+/// compatible with it as described in [`Encoding::from_slice_unchecked`].
+#[inline]
+pub unsafe fn from_slice_unchecked<'de, T>(bytes: &'de [u8]) -> Result<T, Error>
+where
+    T: crate::Decode<'de, Binary>,
+{
+    // SAFETY: Upheld by the caller.
+    unsafe { DEFAULT.from_slice_unchecked(bytes) }
+}
+
 /// Setting up encoding with parameters.
 pub struct Encoding<const OPT: Options = OPTIONS, M = Binary>
 where
     M: 'static,
 {
+    limits: Limits,
+    canonical: bool,
+    padding: PackPadding,
+    checksum: Option<Checksum>,
+    header: Option<Header>,
+    #[cfg(any(feature = "zstd", feature = "lz4"))]
+    compression: Option<Compression>,
     _marker: marker::PhantomData<M>,
 }
 
@@ -78,11 +200,67 @@ impl Encoding<OPTIONS, Binary> {
     /// ```
     pub const fn new() -> Self {
         Encoding {
+            limits: Limits::UNLIMITED,
+            canonical: false,
+            padding: PackPadding::Exact,
+            checksum: None,
+            header: None,
+            #[cfg(any(feature = "zstd", feature = "lz4"))]
+            compression: None,
             _marker: marker::PhantomData,
         }
     }
 }
 
+impl<M> Encoding<OPTIONS, M>
+where
+    M: 'static,
+{
+    /// Encode lengths as fixed 16-bit integers, which is the smallest fixed
+    /// width able to hold the lengths used by most constrained protocols.
+    ///
+    /// Encoding a string, byte array or sequence which is longer than
+    /// [`u16::MAX`] results in an error. This is the same as using the
+    /// [`FIXED_LENGTHS16`] options through [`Encoding::with_options`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::{Encoding, FIXED_LENGTHS16};
+    ///
+    /// const CONFIG: Encoding<FIXED_LENGTHS16> = Encoding::new().with_fixed_lengths16();
+    ///
+    /// let data = CONFIG.to_vec("hello")?;
+    /// assert_eq!(data.len(), 1 + 2 + 5);
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub const fn with_fixed_lengths16(self) -> Encoding<FIXED_LENGTHS16, M> {
+        self.with_options()
+    }
+
+    /// Encode lengths as fixed integers of the same width as `usize` on the
+    /// current platform.
+    ///
+    /// Note that data encoded like this can only be decoded on platforms with
+    /// the same pointer width. This is the same as using the
+    /// [`FIXED_LENGTHS_USIZE`] options through [`Encoding::with_options`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::{Encoding, FIXED_LENGTHS_USIZE};
+    ///
+    /// const CONFIG: Encoding<FIXED_LENGTHS_USIZE> = Encoding::new().with_fixed_lengths_usize();
+    ///
+    /// let data = CONFIG.to_vec("hello")?;
+    /// assert_eq!(data.len(), 1 + core::mem::size_of::<usize>() + 5);
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub const fn with_fixed_lengths_usize(self) -> Encoding<FIXED_LENGTHS_USIZE, M> {
+        self.with_options()
+    }
+}
+
 impl<const OPT: Options, M> Encoding<OPT, M>
 where
     M: 'static,
@@ -100,6 +278,13 @@ where
     /// ```
     pub const fn with_mode<T>(self) -> Encoding<OPT, T> {
         Encoding {
+            limits: self.limits,
+            canonical: self.canonical,
+            padding: self.padding,
+            checksum: self.checksum,
+            header: self.header,
+            #[cfg(any(feature = "zstd", feature = "lz4"))]
+            compression: self.compression,
             _marker: marker::PhantomData,
         }
     }
@@ -117,17 +302,789 @@ where
     /// ```
     pub const fn with_options<const U: Options>(self) -> Encoding<U, M> {
         Encoding {
+            limits: self.limits,
+            canonical: self.canonical,
+            padding: self.padding,
+            checksum: self.checksum,
+            header: self.header,
+            #[cfg(any(feature = "zstd", feature = "lz4"))]
+            compression: self.compression,
             _marker: marker::PhantomData,
         }
     }
 
+    /// Limit the length of any single string or byte array which can be
+    /// decoded.
+    ///
+    /// This protects against untrusted input with a large length prefix, which
+    /// would otherwise cause a large buffer to be allocated when decoding from
+    /// a stream. Defaults to no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::Encoding;
+    ///
+    /// const ENCODING: Encoding = Encoding::new().with_max_element_length(16);
+    ///
+    /// let data = ENCODING.to_vec("Hello World, this is a long string")?;
+    /// assert!(ENCODING.from_slice::<String>(&data).is_err());
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub const fn with_max_element_length(mut self, max_element_length: usize) -> Self {
+        self.limits.max_element_length = max_element_length;
+        self
+    }
+
+    /// Limit the number of items in any single sequence or map which can be
+    /// decoded.
+    ///
+    /// For maps this counts both keys and values. Defaults to no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::Encoding;
+    ///
+    /// const ENCODING: Encoding = Encoding::new().with_max_sequence_count(4);
+    ///
+    /// let data = ENCODING.to_vec(&vec![1u32, 2, 3, 4, 5])?;
+    /// assert!(ENCODING.from_slice::<Vec<u32>>(&data).is_err());
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub const fn with_max_sequence_count(mut self, max_sequence_count: usize) -> Self {
+        self.limits.max_sequence_count = max_sequence_count;
+        self
+    }
+
+    /// Limit the total size of everything which is decoded from a single
+    /// message.
+    ///
+    /// This is the sum of the lengths of all strings and byte arrays, and the
+    /// number of items in all sequences and maps. Defaults to no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::Encoding;
+    ///
+    /// const ENCODING: Encoding = Encoding::new().with_max_allocation(16);
+    ///
+    /// let data = ENCODING.to_vec(&vec![String::from("Hello"), String::from("World")])?;
+    /// assert!(ENCODING.from_slice::<Vec<String>>(&data).is_ok());
+    ///
+    /// let data = ENCODING.to_vec(&vec![String::from("Hello"); 3])?;
+    /// assert!(ENCODING.from_slice::<Vec<String>>(&data).is_err());
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub const fn with_max_allocation(mut self, max_allocation: usize) -> Self {
+        self.limits.max_allocation = max_allocation;
+        self
+    }
+
+    /// Limit the number of bytes in any continuation-encoded number, such as
+    /// a variable-length integer or length prefix.
+    ///
+    /// Continuation sequences are already bounded by the width of the number
+    /// being decoded, but this allows malformed input to be rejected sooner.
+    /// Exceeding the limit raises a dedicated error which is reported as
+    /// [`ErrorCode::ContinuationOverflow`] by the [`Compact`] context.
+    /// Defaults to no limit.
+    ///
+    /// [`ErrorCode::ContinuationOverflow`]: crate::context::ErrorCode::ContinuationOverflow
+    /// [`Compact`]: crate::context::Compact
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::Encoding;
+    ///
+    /// const ENCODING: Encoding = Encoding::new().with_max_continuation_bytes(2);
+    ///
+    /// let data = ENCODING.to_vec(&1000u32)?;
+    /// assert_eq!(ENCODING.from_slice::<u32>(&data)?, 1000);
+    ///
+    /// let data = ENCODING.to_vec(&100_000u32)?;
+    /// assert!(ENCODING.from_slice::<u32>(&data).is_err());
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub const fn with_max_continuation_bytes(mut self, max_continuation_bytes: usize) -> Self {
+        self.limits.max_continuation = max_continuation_bytes;
+        self
+    }
+
+    /// Reject unknown fields and variants when decoding, instead of silently
+    /// skipping over them.
+    ///
+    /// This is useful in deployments where a mismatch between the schema of
+    /// the sender and the receiver should be caught as early as possible.
+    /// Note that this applies to every type being decoded, while
+    /// `#[musli(deny_unknown_fields)]` only applies to the type it's used on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Decode, Encode};
+    /// use musli::wire::Encoding;
+    ///
+    /// const ENCODING: Encoding = Encoding::new();
+    /// const STRICT: Encoding = Encoding::new().with_strict(true);
+    ///
+    /// #[derive(Encode)]
+    /// struct Version2 {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// #[derive(Debug, PartialEq, Decode)]
+    /// struct Version1 {
+    ///     name: String,
+    /// }
+    ///
+    /// let data = ENCODING.to_vec(&Version2 {
+    ///     name: String::from("Aristotle"),
+    ///     age: 61,
+    /// })?;
+    ///
+    /// let value: Version1 = ENCODING.from_slice(&data)?;
+    /// assert_eq!(value.name, "Aristotle");
+    ///
+    /// assert!(STRICT.from_slice::<Version1>(&data).is_err());
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub const fn with_strict(mut self, strict: bool) -> Self {
+        self.limits.strict = strict;
+        self
+    }
+
+    /// Encode values canonically, so that equal values always encode to the
+    /// same bytes.
+    ///
+    /// The wire format already uses minimal variable-length integers, so what
+    /// this changes is that the entries of maps are sorted by their encoded
+    /// keys instead of being written in iteration order, and that every NaN
+    /// is encoded the same way. This makes the output suitable for hashing
+    /// and signing.
+    ///
+    /// Maps are sorted in place once they're finished. Writers which can't be
+    /// modified after the fact, such as those used by `to_writer` or
+    /// `to_fixed_bytes`, instead have the whole value buffered before it's
+    /// written to them.
+    ///
+    /// Sets are encoded as sequences in iteration order, so values which
+    /// contain sets should use an ordered set like [`BTreeSet`] to be
+    /// deterministic.
+    ///
+    /// [`BTreeSet`]: rust_alloc::collections::BTreeSet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use musli::wire::Encoding;
+    ///
+    /// const CANONICAL: Encoding = Encoding::new().with_canonical(true);
+    ///
+    /// let a = HashMap::from([(1u32, "one"), (2, "two"), (3, "three")]);
+    /// let b = HashMap::from([(3u32, "three"), (1, "one"), (2, "two")]);
+    ///
+    /// assert_eq!(CANONICAL.to_vec(&a)?, CANONICAL.to_vec(&b)?);
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub const fn with_canonical(mut self, canonical: bool) -> Self {
+        self.canonical = canonical;
+        self
+    }
+
+    /// Set how the content of packs is padded, which defaults to
+    /// [`PackPadding::Exact`].
+    ///
+    /// Padding packs wastes space, but can be used to keep the size of
+    /// messages stable as the content of packs changes, for example so that
+    /// they can be updated in place. Since decoders skip over padding, this
+    /// only has to be set when encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::{Encoding, PackPadding};
+    /// use musli::{Decode, Encode};
+    ///
+    /// const PADDED: Encoding = Encoding::new().with_pack_padding(PackPadding::Multiple(8));
+    ///
+    /// #[derive(Debug, PartialEq, Encode, Decode)]
+    /// #[musli(packed)]
+    /// struct Point {
+    ///     x: u8,
+    ///     y: u8,
+    ///     z: u8,
+    /// }
+    ///
+    /// let point = Point { x: 1, y: 2, z: 3 };
+    ///
+    /// let data = PADDED.to_vec(&point)?;
+    /// assert_eq!(data.len(), 1 + 8);
+    ///
+    /// let decoded: Point = musli::wire::from_slice(&data)?;
+    /// assert_eq!(decoded, point);
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub const fn with_pack_padding(mut self, padding: PackPadding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Append a checksum to every encoded message, and verify it when
+    /// decoding.
+    ///
+    /// This detects messages which have been corrupted in transit, which would
+    /// otherwise surface as confusing decode errors or, worse, decode into the
+    /// wrong value. Both the encoding and the decoding side must use the same
+    /// checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::{Checksum, Encoding};
+    ///
+    /// const ENCODING: Encoding = Encoding::new().with_checksum(Checksum::Crc32);
+    ///
+    /// let mut data = ENCODING.to_vec(&String::from("Aristotle"))?;
+    /// assert_eq!(ENCODING.from_slice::<String>(&data)?, "Aristotle");
+    ///
+    /// data[1] ^= 0b100;
+    /// assert!(ENCODING.from_slice::<String>(&data).is_err());
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub const fn with_checksum(mut self, checksum: Checksum) -> Self {
+        self.checksum = Some(checksum);
+        self
+    }
+
+    /// Prefix every encoded message with the given [`Header`], and verify
+    /// that decoded messages start with the same header.
+    ///
+    /// This allows an application to detect messages which were encoded with
+    /// a different configuration, and to use [`Header::peek`] to select the
+    /// right configuration for older messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::options::{self, Integer, Options};
+    /// use musli::wire::{Encoding, Header};
+    ///
+    /// const FIXED: Options = options::new().with_integer(Integer::Fixed).build();
+    ///
+    /// const V1: Encoding = Encoding::new().with_header(Header::new(*b"DEMO", 1));
+    /// const V2: Encoding<FIXED> = Encoding::new()
+    ///     .with_options()
+    ///     .with_header(Header::new(*b"DEMO", 2));
+    ///
+    /// let data = V1.to_vec(&42u32)?;
+    /// assert!(V2.from_slice::<u32>(&data).is_err());
+    ///
+    /// let value: u32 = match Header::peek(&data).map(|h| h.version()) {
+    ///     Some(1) => V1.from_slice(&data)?,
+    ///     _ => V2.from_slice(&data)?,
+    /// };
+    ///
+    /// assert_eq!(value, 42);
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub const fn with_header(mut self, header: Header) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    /// Set the compression used by [`Encoding::to_vec_compressed`] and
+    /// [`Encoding::from_slice_compressed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::{Compression, Encoding};
+    ///
+    /// # #[cfg(feature = "zstd")]
+    /// const COMPRESSION: Compression = Compression::Zstd { level: 3 };
+    /// # #[cfg(not(feature = "zstd"))]
+    /// # const COMPRESSION: Compression = Compression::Lz4;
+    /// const ENCODING: Encoding = Encoding::new().with_compression(COMPRESSION);
+    ///
+    /// let text = "Aristotle ".repeat(100);
+    ///
+    /// let data = ENCODING.to_vec_compressed(&text)?;
+    /// assert!(data.len() < text.len());
+    ///
+    /// let decoded: String = ENCODING.from_slice_compressed(&data)?;
+    /// assert_eq!(decoded, text);
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    #[cfg(any(feature = "zstd", feature = "lz4"))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "zstd", feature = "lz4"))))]
+    pub const fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Encode the given value to a compressed [`Vec`] using the compression
+    /// set through [`Encoding::with_compression`].
+    ///
+    /// If no compression has been set, this is the same as
+    /// [`Encoding::to_vec`].
+    ///
+    /// [`Vec`]: rust_alloc::vec::Vec
+    #[cfg(any(feature = "zstd", feature = "lz4"))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "zstd", feature = "lz4"))))]
+    pub fn to_vec_compressed<T>(self, value: &T) -> Result<rust_alloc::vec::Vec<u8>, Error>
+    where
+        T: ?Sized + crate::Encode<M>,
+    {
+        use rust_alloc::vec::Vec;
+
+        match self.compression {
+            None => self.to_vec(value),
+            #[cfg(feature = "zstd")]
+            Some(Compression::Zstd { level }) => {
+                let mut encoder = zstd::Encoder::new(Vec::new(), level)?;
+                self.to_writer(&mut encoder, value)?;
+                Ok(encoder.finish()?)
+            }
+            #[cfg(feature = "lz4")]
+            Some(Compression::Lz4) => {
+                let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+                self.to_writer(&mut encoder, value)?;
+                Ok(encoder.finish().map_err(std::io::Error::from)?)
+            }
+        }
+    }
+
+    /// Decode the given type `T` from a slice compressed with the compression
+    /// set through [`Encoding::with_compression`].
+    ///
+    /// If no compression has been set, this is the same as
+    /// [`Encoding::from_slice`].
+    #[cfg(any(feature = "zstd", feature = "lz4"))]
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "zstd", feature = "lz4"))))]
+    pub fn from_slice_compressed<T>(self, bytes: &[u8]) -> Result<T, Error>
+    where
+        T: crate::de::DecodeOwned<M>,
+    {
+        match self.compression {
+            None => self.from_slice(bytes),
+            #[cfg(feature = "zstd")]
+            Some(Compression::Zstd { .. }) => self.from_reader(zstd::Decoder::new(bytes)?),
+            #[cfg(feature = "lz4")]
+            Some(Compression::Lz4) => self.from_reader(lz4_flex::frame::FrameDecoder::new(bytes)),
+        }
+    }
+
+    /// Inspect the structure of an encoded message without knowing which
+    /// types it was encoded from.
+    ///
+    /// The message is checked against the header, checksum and limits
+    /// configured for this encoding in the same way as when decoding it. Bytes
+    /// following the message are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::{Checksum, Encoding, Node};
+    ///
+    /// const ENCODING: Encoding = Encoding::new().with_checksum(Checksum::Crc32);
+    ///
+    /// let data = ENCODING.to_vec(&vec![1u32, 2, 3])?;
+    ///
+    /// let node = ENCODING.inspect(&data)?;
+    /// assert!(matches!(node, Node::Sequence { items, .. } if items.len() == 3));
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn inspect(self, bytes: &[u8]) -> Result<Node, Error> {
+        crate::alloc::default!(|alloc| {
+            let cx = crate::context::Same::with_alloc(alloc);
+            self.with_decoder(&cx, IntoReader::into_reader(bytes), |decoder| {
+                decoder.inspect()
+            })
+        })
+    }
+
+    /// Inspect the structure of the message at the start of the given slice,
+    /// returning it together with the number of bytes which were consumed.
+    ///
+    /// This is the same as [`Encoding::inspect`], except that the consumed
+    /// length can be used to tell if there are trailing bytes following the
+    /// message in the same way as with [`Encoding::from_slice_partial`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::{Encoding, Node};
+    ///
+    /// const ENCODING: Encoding = Encoding::new();
+    ///
+    /// let mut data = ENCODING.to_vec(&String::from("Aristotle"))?;
+    /// let len = data.len();
+    /// data.extend_from_slice(b"rest");
+    ///
+    /// let (node, n) = ENCODING.inspect_partial(&data)?;
+    /// assert!(matches!(node, Node::Prefix { bytes, .. } if bytes == b"Aristotle"));
+    /// assert_eq!(n, len);
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn inspect_partial(self, bytes: &[u8]) -> Result<(Node, usize), Error> {
+        crate::alloc::default!(|alloc| {
+            let cx = crate::context::Same::<M, Error, _>::with_alloc(alloc);
+            let mut reader = IntoReader::into_reader(bytes);
+            let node = self.with_decoder(&cx, &mut reader, |decoder| decoder.inspect())?;
+            Ok((node, bytes.len() - reader.len()))
+        })
+    }
+
+    /// Decode values of type `T` which have been encoded back to back from the
+    /// given reader, such as a log file or a batch of messages.
+    ///
+    /// The returned iterator only reads as much from the reader as is needed
+    /// to decode the next value. Each value is checked against the header,
+    /// checksum and limits configured for this encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Decode, Encode};
+    /// use musli::wire::Encoding;
+    ///
+    /// const ENCODING: Encoding = Encoding::new();
+    ///
+    /// #[derive(Debug, PartialEq, Encode, Decode)]
+    /// struct Entry {
+    ///     id: u32,
+    ///     message: String,
+    /// }
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// for id in 0..3 {
+    ///     ENCODING.to_writer(&mut log, &Entry { id, message: id.to_string() })?;
+    /// }
+    ///
+    /// let entries = ENCODING
+    ///     .decode_stream::<_, Entry>(&log[..])
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(entries.len(), 3);
+    /// assert_eq!(entries[2], Entry { id: 2, message: String::from("2") });
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    #[cfg(all(feature = "std", feature = "alloc"))]
+    #[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "alloc"))))]
+    #[inline]
+    pub fn decode_stream<R, T>(self, reader: R) -> DecodeStream<R, T, OPT, M>
+    where
+        R: std::io::Read,
+        T: crate::de::DecodeOwned<M>,
+    {
+        DecodeStream::new(self, reader)
+    }
+
+    /// Compute the patch which turns the message `old` into `new`, which
+    /// should be encoded from the same type.
+    ///
+    /// Both messages are checked against the header, checksum and limits
+    /// configured for this encoding. Values which are identical in both
+    /// messages are copied from `old` when the patch is applied, while the
+    /// values which differ are included in the patch. Structs and sequences
+    /// which differ are compared value by value, so a small change to a large
+    /// message results in a small patch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::{Checksum, Encoding};
+    ///
+    /// const ENCODING: Encoding = Encoding::new().with_checksum(Checksum::Crc32);
+    ///
+    /// let mut state = vec![(1u32, String::from("Aristotle")); 100];
+    /// let old = ENCODING.to_vec(&state)?;
+    ///
+    /// state.push((2, String::from("Plato")));
+    /// let new = ENCODING.to_vec(&state)?;
+    ///
+    /// let patch = ENCODING.diff(&old, &new)?;
+    /// assert!(patch.as_bytes().len() < 32);
+    ///
+    /// assert_eq!(ENCODING.patch(&old, &patch)?, new);
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn diff(self, old: &[u8], new: &[u8]) -> Result<Patch, Error> {
+        crate::alloc::default!(|alloc| {
+            let cx = crate::context::Same::<M, Error, _>::with_alloc(alloc);
+            let old_span = self.spans(&cx, old)?;
+            let new_span = self.spans(&cx, new)?;
+            patch::diff(&cx, old, &old_span, new, &new_span)
+        })
+    }
+
+    /// Apply a patch computed with [`Encoding::diff`] to the message `old`,
+    /// returning the new message.
+    ///
+    /// This fails if the patch was computed from a different message, and the
+    /// new message is checked against the header, checksum and limits
+    /// configured for this encoding.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn patch(self, old: &[u8], patch: &Patch) -> Result<rust_alloc::vec::Vec<u8>, Error> {
+        crate::alloc::default!(|alloc| {
+            let cx = crate::context::Same::<M, Error, _>::with_alloc(alloc);
+            let new = patch.apply(&cx, old)?;
+            self.with_decoder(&cx, IntoReader::into_reader(new.as_slice()), |decoder| {
+                decoder.skip_any()
+            })?;
+            Ok(new)
+        })
+    }
+
+    /// Decode the given type `T` from the start of the given slice, returning
+    /// it together with the number of bytes which were consumed.
+    ///
+    /// Unlike [`Encoding::from_slice`] any bytes following the message are
+    /// left alone, which allows multiple messages which have been concatenated
+    /// into one buffer to be decoded one after another. The consumed length
+    /// includes the header and checksum if they are configured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::Encoding;
+    ///
+    /// const ENCODING: Encoding = Encoding::new();
+    ///
+    /// let mut data = ENCODING.to_vec(&String::from("Aristotle"))?;
+    /// data.extend(ENCODING.to_vec(&42u32)?);
+    ///
+    /// let (first, n) = ENCODING.from_slice_partial::<String>(&data)?;
+    /// assert_eq!(first, "Aristotle");
+    ///
+    /// let (second, m) = ENCODING.from_slice_partial::<u32>(&data[n..])?;
+    /// assert_eq!(second, 42);
+    /// assert_eq!(n + m, data.len());
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub fn from_slice_partial<'de, T>(self, bytes: &'de [u8]) -> Result<(T, usize), Error>
+    where
+        T: crate::Decode<'de, M>,
+    {
+        crate::alloc::default!(|alloc| {
+            let cx = crate::context::Same::<M, Error, _>::with_alloc(alloc);
+            let mut reader = IntoReader::into_reader(bytes);
+            let value = self.with_decoder(&cx, &mut reader, |decoder| decoder.decode())?;
+            Ok((value, bytes.len() - reader.len()))
+        })
+    }
+
+    /// Decode only the value found by following `path` through nested
+    /// structs or maps, skipping over everything else in the message.
+    ///
+    /// Each element of the path is matched against the tags of the fields
+    /// being decoded by comparing their encoded bytes, so they have to be of a
+    /// type which encodes in the same way as the tag. By default fields are
+    /// tagged by their index, which is matched by an integer.
+    ///
+    /// Returns `None` if a field in the path doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Decode, Encode};
+    /// use musli::wire::Encoding;
+    ///
+    /// const ENCODING: Encoding = Encoding::new();
+    ///
+    /// #[derive(Encode)]
+    /// #[musli(name_all = "name")]
+    /// struct Header {
+    ///     id: u64,
+    ///     kind: String,
+    /// }
+    ///
+    /// #[derive(Encode)]
+    /// #[musli(name_all = "name")]
+    /// struct Message {
+    ///     header: Header,
+    ///     body: Vec<u8>,
+    /// }
+    ///
+    /// let message = Message {
+    ///     header: Header { id: 42, kind: String::from("upload") },
+    ///     body: vec![0; 1024],
+    /// };
+    ///
+    /// let data = ENCODING.to_vec(&message)?;
+    ///
+    /// let id: Option<u64> = ENCODING.from_slice_path(&data, &["header", "id"])?;
+    /// assert_eq!(id, Some(42));
+    ///
+    /// let missing: Option<u64> = ENCODING.from_slice_path(&data, &["header", "size"])?;
+    /// assert_eq!(missing, None);
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub fn from_slice_path<'de, T, K>(
+        self,
+        bytes: &'de [u8],
+        path: &[K],
+    ) -> Result<Option<T>, Error>
+    where
+        T: crate::Decode<'de, M>,
+        K: crate::Encode<M>,
+    {
+        crate::alloc::default!(|alloc| {
+            let cx = crate::context::Same::with_alloc(alloc);
+            self.with_decoder(&cx, IntoReader::into_reader(bytes), |decoder| {
+                project::<_, _, _, OPT>(&cx, decoder, self.canonical, self.padding, path)
+            })
+        })
+    }
+
+    /// Decode the given type `T` from a slice which is trusted to be
+    /// well-formed, skipping validation to improve throughput.
+    ///
+    /// No [limits] are applied, the [checksum] is not verified and strings are
+    /// not checked to be valid UTF-8. The [header] is still verified since
+    /// it's needed to locate the message, and malformed input can still cause
+    /// an error to be returned since reads remain bounds checked.
+    ///
+    /// [limits]: Encoding::with_max_allocation
+    /// [checksum]: Encoding::with_checksum
+    /// [header]: Encoding::with_header
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` was produced by encoding a value of
+    /// type `T` with an encoding that has the same options as this one, such
+    /// as data which has been authenticated or which was produced locally.
+    /// Values of other types may only be decoded if every string in `T`
+    /// corresponds to a string in the encoded value, like a `&str` field
+    /// decoded from a `String` field. Truncated input is fine, since reads
+    /// remain bounds checked.
+    ///
+    /// Decoding a string which is not valid UTF-8 is undefined behavior. Note
+    /// that the wire format encodes strings and bytes in the same way, so
+    /// decoding a string from an encoded `Vec<u8>` or `&[u8]` is only sound if
+    /// it happens to hold valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::Encoding;
+    ///
+    /// const ENCODING: Encoding = Encoding::new();
+    ///
+    /// let data = ENCODING.to_vec(&vec![String::from("Aristotle")])?;
+    ///
+    /// // SAFETY: The data was encoded above.
+    /// let value: Vec<String> = unsafe { ENCODING.from_slice_unchecked(&data)? };
+    /// assert_eq!(value, ["Aristotle"]);
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub unsafe fn from_slice_unchecked<'de, T>(self, bytes: &'de [u8]) -> Result<T, Error>
+    where
+        T: crate::Decode<'de, M>,
+    {
+        crate::alloc::default!(|alloc| {
+            let cx = crate::context::Same::with_alloc(alloc);
+            // SAFETY: The caller guarantees that the input is trusted.
+            let budget = unsafe { Budget::trusted() };
+            let mut reader = IntoReader::into_reader(bytes);
+
+            if let Some(header) = &self.header {
+                header.verify(&cx, &mut reader)?;
+            }
+
+            WireDecoder::<_, OPT, _>::new(&cx, &budget, &mut reader).decode()
+        })
+    }
+
     crate::macros::encoding_impls!(
         M,
         wire,
-        WireEncoder::<_, OPT, _>::new,
-        WireDecoder::<_, OPT, _>::new,
+        self.with_encoder,
+        self.with_decoder,
         IntoReader::into_reader,
     );
+
+    crate::macros::async_encoding_impls!(M, wire);
+    crate::macros::reader_encoding_impls!(M, wire);
+
+    #[inline]
+    fn with_encoder<C, W, F>(self, cx: &C, writer: W, f: F) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context<Mode = M>,
+        W: Writer,
+        F: FnOnce(
+            WireEncoder<'_, &mut CanonicalWriter<'_, ChecksumWriter<W>, C::Allocator>, OPT, C>,
+        ) -> Result<(), C::Error>,
+    {
+        let mut writer = ChecksumWriter::new(self.checksum, writer);
+
+        if let Some(header) = &self.header {
+            header.write(cx, &mut writer)?;
+        }
+
+        let mut writer = CanonicalWriter::new(cx, self.canonical, writer)?;
+        f(WireEncoder::new(
+            cx,
+            self.canonical,
+            self.padding,
+            &mut writer,
+        ))?;
+        writer.finish(cx)?.finish(cx)
+    }
+
+    #[inline]
+    fn with_decoder<'de, C, R, F, O>(self, cx: &C, reader: R, f: F) -> Result<O, C::Error>
+    where
+        C: ?Sized + Context<Mode = M>,
+        R: Reader<'de>,
+        F: FnOnce(WireDecoder<'_, '_, &mut ChecksumReader<R>, OPT, C>) -> Result<O, C::Error>,
+    {
+        let budget = Budget::new(self.limits);
+        let mut reader = ChecksumReader::new(self.checksum, reader);
+
+        if let Some(header) = &self.header {
+            header.verify(cx, &mut reader)?;
+        }
+
+        let value = f(WireDecoder::new(cx, &budget, &mut reader))?;
+        reader.finish(cx)?;
+        Ok(value)
+    }
+
+    /// Collect where the values in a message are located.
+    #[cfg(feature = "alloc")]
+    fn spans<C>(self, cx: &C, bytes: &[u8]) -> Result<Span, C::Error>
+    where
+        C: ?Sized + Context<Mode = M>,
+    {
+        let value = self.with_decoder(cx, IntoReader::into_reader(bytes), |decoder| {
+            decoder.spans(bytes.len())
+        })?;
+
+        Ok(Span::message(bytes.len(), value))
+    }
+}
+
+impl<const OPT: Options, A, B> crate::DynMode<Encoding<OPT, A>, Encoding<OPT, B>>
+where
+    A: 'static,
+    B: 'static,
+{
+    crate::macros::dyn_mode_impls!(A, B, IntoReader);
 }
 
 impl<const OPT: Options, M> Clone for Encoding<OPT, M> {
@@ -138,3 +1095,26 @@ impl<const OPT: Options, M> Clone for Encoding<OPT, M> {
 }
 
 impl<const OPT: Options, M> Copy for Encoding<OPT, M> {}
+
+impl<const OPT: Options, M> crate::transcode::Source for Encoding<OPT, M>
+where
+    M: 'static,
+{
+    #[inline]
+    fn transcode<E>(self, cx: &E::Cx, bytes: &[u8], encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: crate::Encoder,
+    {
+        let budget = Budget::new(self.limits);
+        let mut reader = ChecksumReader::new(self.checksum, IntoReader::into_reader(bytes));
+
+        if let Some(header) = &self.header {
+            header.verify(cx, &mut reader)?;
+        }
+
+        let decoder = WireDecoder::<_, OPT, _>::new(cx, &budget, &mut reader);
+        let ok = crate::transcode::transcode(decoder, encoder)?;
+        reader.finish(cx)?;
+        Ok(ok)
+    }
+}
@@ -42,6 +42,14 @@ impl fmt::Display for ErrorImpl {
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    #[inline]
+    fn from(error: std::io::Error) -> Self {
+        Self::custom(error)
+    }
+}
+
 impl ContextError for Error {
     #[inline]
     fn custom<T>(error: T) -> Self
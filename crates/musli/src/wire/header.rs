@@ -0,0 +1,152 @@
+use core::fmt;
+
+use crate::{Context, Reader, Writer};
+
+/// The size of an encoded header in bytes.
+const HEADER_LEN: usize = 8;
+
+/// A header which identifies the format of a wire message.
+///
+/// The header consists of four magic bytes which identify the application,
+/// followed by a version which should be changed whenever the configuration
+/// of the [`Encoding`] changes in an incompatible way, such as switching
+/// between fixed and variable-length integers.
+///
+/// See [`Encoding::with_header`].
+///
+/// [`Encoding`]: super::Encoding
+/// [`Encoding::with_header`]: super::Encoding::with_header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    magic: [u8; 4],
+    version: u32,
+}
+
+impl Header {
+    /// Construct a new header with the given magic bytes and version.
+    #[inline]
+    pub const fn new(magic: [u8; 4], version: u32) -> Self {
+        Self { magic, version }
+    }
+
+    /// The magic bytes of the header.
+    #[inline]
+    pub const fn magic(&self) -> [u8; 4] {
+        self.magic
+    }
+
+    /// The version of the header.
+    #[inline]
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Read the header at the start of an encoded message without decoding
+    /// the rest of it.
+    ///
+    /// This can be used to select which [`Encoding`] to decode a message
+    /// with. Returns `None` if the message is too short to contain a header.
+    ///
+    /// [`Encoding`]: super::Encoding
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::wire::{Encoding, Header};
+    ///
+    /// const ENCODING: Encoding = Encoding::new().with_header(Header::new(*b"DEMO", 2));
+    ///
+    /// let data = ENCODING.to_vec(&42u32)?;
+    ///
+    /// let header = Header::peek(&data).unwrap();
+    /// assert_eq!(header.magic(), *b"DEMO");
+    /// assert_eq!(header.version(), 2);
+    /// # Ok::<_, musli::wire::Error>(())
+    /// ```
+    pub fn peek(bytes: &[u8]) -> Option<Self> {
+        let bytes = bytes.get(..HEADER_LEN)?;
+        let (magic, version) = bytes.split_at(4);
+
+        Some(Self {
+            magic: magic.try_into().ok()?,
+            version: u32::from_le_bytes(version.try_into().ok()?),
+        })
+    }
+
+    /// Write the header.
+    #[inline]
+    pub(crate) fn write<C, W>(&self, cx: &C, mut writer: W) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+        W: Writer,
+    {
+        writer.write_bytes(cx, &self.magic)?;
+        writer.write_bytes(cx, &self.version.to_le_bytes())
+    }
+
+    /// Read a header and verify that it matches this one.
+    #[inline]
+    pub(crate) fn verify<'de, C, R>(&self, cx: &C, mut reader: R) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+        R: Reader<'de>,
+    {
+        let mark = cx.mark();
+        let magic = reader.read_array::<C, 4>(cx)?;
+
+        if magic != self.magic {
+            return Err(cx.marked_message(
+                mark,
+                HeaderMismatch::Magic {
+                    expected: self.magic,
+                    actual: magic,
+                },
+            ));
+        }
+
+        let mark = cx.mark();
+        let version = u32::from_le_bytes(reader.read_array::<C, 4>(cx)?);
+
+        if version != self.version {
+            return Err(cx.marked_message(
+                mark,
+                HeaderMismatch::Version {
+                    expected: self.version,
+                    actual: version,
+                },
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+enum HeaderMismatch {
+    Magic { expected: [u8; 4], actual: [u8; 4] },
+    Version { expected: u32, actual: u32 },
+}
+
+impl fmt::Display for HeaderMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeaderMismatch::Magic { expected, actual } => write!(
+                f,
+                "Header magic {} does not match the expected {}",
+                Magic(actual),
+                Magic(expected)
+            ),
+            HeaderMismatch::Version { expected, actual } => write!(
+                f,
+                "Header version {actual} does not match the expected version {expected}"
+            ),
+        }
+    }
+}
+
+struct Magic<'a>(&'a [u8; 4]);
+
+impl fmt::Display for Magic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.0.escape_ascii())
+    }
+}
@@ -0,0 +1,118 @@
+use core::fmt;
+
+use rust_alloc::boxed::Box;
+use rust_alloc::vec::Vec;
+
+/// The structure of a wire message, as produced by [`inspect`].
+///
+/// Every node corresponds to one type tag in the message, and since the wire
+/// format is self-descriptive this can be produced without access to the types
+/// which were used to encode it. This is intended to help debug payloads, the
+/// [`Display`] implementation prints the node as an indented tree.
+///
+/// [`inspect`]: super::inspect
+/// [`Display`]: fmt::Display
+///
+/// # Examples
+///
+/// ```
+/// use musli::wire::{self, Node};
+///
+/// let data = wire::to_vec(&(42u32, String::from("Aristotle")))?;
+/// let node = wire::inspect(&data)?;
+///
+/// let Node::Sequence { items, .. } = &node else {
+///     panic!("expected a sequence");
+/// };
+///
+/// assert_eq!(items.len(), 2);
+/// assert_eq!(node.to_string(), "sequence(2) [\n  continuation 42\n  prefix(9) \"Aristotle\"\n]");
+/// # Ok::<_, musli::wire::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Node {
+    /// A fixed number of bytes, such as a string or a fixed-size number.
+    Prefix {
+        /// Whether the length was embedded in the tag.
+        embedded: bool,
+        /// The raw bytes of the element.
+        bytes: Vec<u8>,
+    },
+    /// A sequence whose length was encoded up front. Structs, maps and enums
+    /// are all encoded as sequences.
+    Sequence {
+        /// Whether the length was embedded in the tag.
+        embedded: bool,
+        /// The items of the sequence.
+        items: Vec<Node>,
+    },
+    /// A sequence of unknown length which is terminated by an end marker.
+    Stream {
+        /// The items of the sequence.
+        items: Vec<Node>,
+    },
+    /// An application-defined extension.
+    Extension {
+        /// The type id of the extension.
+        id: u32,
+        /// The value of the extension.
+        value: Box<Node>,
+    },
+    /// A continuation-encoded number.
+    ///
+    /// This is the raw value as it was encoded, so signed numbers are still
+    /// zigzag-encoded.
+    Continuation {
+        /// Whether the value was embedded in the tag.
+        embedded: bool,
+        /// The encoded value.
+        value: u128,
+    },
+}
+
+impl Node {
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        match self {
+            Node::Prefix { bytes, .. } => {
+                write!(f, "prefix({}) \"{}\"", bytes.len(), bytes.escape_ascii())
+            }
+            Node::Sequence { items, .. } => {
+                write!(f, "sequence({})", items.len())?;
+                fmt_items(f, items, indent)
+            }
+            Node::Stream { items } => {
+                write!(f, "stream({})", items.len())?;
+                fmt_items(f, items, indent)
+            }
+            Node::Continuation { value, .. } => write!(f, "continuation {value}"),
+            Node::Extension { id, value } => {
+                write!(f, "extension({id}) ")?;
+                value.fmt_indented(f, indent)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Node {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+fn fmt_items(f: &mut fmt::Formatter<'_>, items: &[Node], indent: usize) -> fmt::Result {
+    if items.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(f, " [")?;
+
+    for item in items {
+        write!(f, "{:1$}", "", (indent + 1) * 2)?;
+        item.fmt_indented(f, indent + 1)?;
+        writeln!(f)?;
+    }
+
+    write!(f, "{:1$}]", "", indent * 2)
+}
@@ -1,3 +1,4 @@
+use crate::fixed::FixedBytes;
 use crate::int::continuation as c;
 use crate::int::zigzag as zig;
 use crate::int::{Signed, Unsigned, UnsignedOps};
@@ -51,23 +52,26 @@ where
 pub(crate) fn decode_length<'de, C, R, const OPT: Options>(
     cx: &C,
     mut reader: R,
+    max_continuation: usize,
 ) -> Result<usize, C::Error>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
 {
+    let start = cx.mark();
+
     match crate::options::length::<OPT>() {
         crate::options::Integer::Variable => {
             let tag = Tag::from_byte(reader.read_byte(cx)?);
 
             if tag.kind() != Kind::Continuation {
-                return Err(cx.message("Expected continuation"));
+                return Err(cx.marked_message(start, "Expected continuation"));
             }
 
             if let Some(data) = tag.data() {
                 Ok(usize::from_byte(data))
             } else {
-                c::decode(cx, reader)
+                c::decode_bounded(cx, reader, max_continuation)
             }
         }
         _ => {
@@ -78,16 +82,16 @@ where
             let tag = Tag::from_byte(reader.read_byte(cx)?);
 
             if tag != Tag::new(Kind::Prefix, bytes) {
-                return Err(cx.message(format_args!(
-                    "Expected fixed {} bytes prefix tag, but got {tag:?}",
-                    bytes
-                )));
+                return Err(cx.marked_message(
+                    start,
+                    format_args!("Expected fixed {} bytes prefix tag, but got {tag:?}", bytes),
+                ));
             }
 
             macro_rules! fixed {
                 ($ty:ty) => {{
                     let Ok(value) = usize::try_from(<$ty>::read_bytes(cx, reader, bo)?) else {
-                        return Err(cx.message("Value type out of bounds for usize"));
+                        return Err(cx.marked_message(start, "Value type out of bounds for usize"));
                     };
 
                     Ok(value)
@@ -133,31 +137,34 @@ where
 pub(crate) fn decode_unsigned<'de, C, R, T, const OPT: Options>(
     cx: &C,
     mut reader: R,
+    max_continuation: usize,
 ) -> Result<T, C::Error>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
     T: UnsignedOps,
 {
+    let start = cx.mark();
+
     match crate::options::integer::<OPT>() {
         crate::options::Integer::Variable => {
             let tag = Tag::from_byte(reader.read_byte(cx)?);
 
             if tag.kind() != Kind::Continuation {
-                return Err(cx.message("Expected continuation"));
+                return Err(cx.marked_message(start, "Expected continuation"));
             }
 
             if let Some(data) = tag.data() {
                 Ok(T::from_byte(data))
             } else {
-                c::decode(cx, reader)
+                c::decode_bounded(cx, reader, max_continuation)
             }
         }
         crate::options::Integer::Fixed => {
             let bo = crate::options::byteorder::<OPT>();
 
             if Tag::from_byte(reader.read_byte(cx)?) != Tag::new(Kind::Prefix, T::BYTES) {
-                return Err(cx.message("Expected fixed integer"));
+                return Err(cx.marked_message(start, "Expected fixed integer"));
             }
 
             T::read_bytes(cx, reader, bo)
@@ -165,6 +172,79 @@ where
     }
 }
 
+/// The size of the buffer used to stage fixed-width integers which are being
+/// encoded or decoded in bulk.
+const FIXED_CHUNK: usize = 256;
+
+/// Encode a sequence of unsigned integers as fixed-width integers, which is
+/// identical to calling [`encode_unsigned`] for each of them when fixed
+/// integers are in use.
+///
+/// The values are staged in a buffer on the stack, so that the writer is only
+/// called once for every chunk of values.
+pub(crate) fn encode_fixed_slice<C, W, T, const OPT: Options>(
+    cx: &C,
+    mut writer: W,
+    values: impl IntoIterator<Item = T>,
+) -> Result<(), C::Error>
+where
+    C: ?Sized + Context,
+    W: Writer,
+    T: UnsignedOps,
+{
+    let tag = Tag::new(Kind::Prefix, T::BYTES).byte();
+    let mut buf = FixedBytes::<FIXED_CHUNK>::new();
+
+    for value in values {
+        if buf.remaining() <= usize::from(T::BYTES) {
+            writer.write_bytes(cx, buf.as_slice())?;
+            buf.clear();
+        }
+
+        buf.write_byte(cx, tag)?;
+        value.write_bytes(cx, &mut buf, crate::options::byteorder::<OPT>())?;
+    }
+
+    writer.write_bytes(cx, buf.as_slice())
+}
+
+/// Decode fixed-width integers into every element of `out`, which is identical
+/// to calling [`decode_unsigned`] for each of them when fixed integers are in
+/// use.
+///
+/// The input is read one chunk at a time into a buffer on the stack.
+pub(crate) fn decode_fixed_slice<'de, C, R, T, O, const OPT: Options>(
+    cx: &C,
+    mut reader: R,
+    out: &mut [O],
+    map: impl Fn(T) -> O,
+) -> Result<(), C::Error>
+where
+    C: ?Sized + Context,
+    R: Reader<'de>,
+    T: UnsignedOps,
+{
+    let tag = Tag::new(Kind::Prefix, T::BYTES);
+    let width = 1 + usize::from(T::BYTES);
+    let mut buf = [0u8; FIXED_CHUNK];
+
+    for chunk in out.chunks_mut(FIXED_CHUNK / width) {
+        let bytes = &mut buf[..chunk.len() * width];
+        reader.read(cx, bytes)?;
+
+        for (value, bytes) in chunk.iter_mut().zip(bytes.chunks_exact(width)) {
+            if Tag::from_byte(bytes[0]) != tag {
+                return Err(cx.message("Expected fixed integer"));
+            }
+
+            let bo = crate::options::byteorder::<OPT>();
+            *value = map(T::read_bytes(cx, &bytes[1..], bo)?);
+        }
+    }
+
+    Ok(())
+}
+
 /// Governs how signed integers are encoded into a [`Writer`].
 #[inline]
 pub(crate) fn encode_signed<C, W, T, const OPT: Options>(
@@ -187,6 +267,7 @@ where
 pub(crate) fn decode_signed<'de, C, R, T, const OPT: Options>(
     cx: &C,
     reader: R,
+    max_continuation: usize,
 ) -> Result<T, C::Error>
 where
     C: ?Sized + Context,
@@ -194,6 +275,6 @@ where
     T: Signed,
     T::Unsigned: UnsignedOps,
 {
-    let value = decode_unsigned::<C, R, T::Unsigned, OPT>(cx, reader)?;
+    let value = decode_unsigned::<C, R, T::Unsigned, OPT>(cx, reader, max_continuation)?;
     Ok(zig::decode(value))
 }
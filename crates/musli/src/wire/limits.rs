@@ -0,0 +1,204 @@
+use core::cell::Cell;
+use core::fmt;
+
+use crate::Context;
+
+/// Limits applied to untrusted input when decoding.
+#[derive(Clone, Copy)]
+pub(crate) struct Limits {
+    pub(crate) max_element_length: usize,
+    pub(crate) max_sequence_count: usize,
+    pub(crate) max_allocation: usize,
+    pub(crate) max_continuation: usize,
+    /// Reject unknown fields and variants instead of skipping over them.
+    pub(crate) strict: bool,
+}
+
+impl Limits {
+    /// Limits which accept any input.
+    pub(crate) const UNLIMITED: Self = Self {
+        max_element_length: usize::MAX,
+        max_sequence_count: usize::MAX,
+        max_allocation: usize::MAX,
+        max_continuation: usize::MAX,
+        strict: false,
+    };
+}
+
+/// Keeps track of how much of the [`Limits`] has been used up by a single
+/// decode.
+pub(crate) struct Budget {
+    limits: Limits,
+    allocated: Cell<usize>,
+    trusted: bool,
+}
+
+impl Budget {
+    #[inline]
+    pub(crate) const fn new(limits: Limits) -> Self {
+        Self {
+            limits,
+            allocated: Cell::new(0),
+            trusted: false,
+        }
+    }
+
+    /// A budget for input which is trusted to be well-formed, which applies no
+    /// limits and allows validation to be skipped.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the input being decoded was produced by
+    /// encoding a value, since strings are no longer checked to be valid
+    /// UTF-8.
+    #[inline]
+    pub(crate) const unsafe fn trusted() -> Self {
+        Self {
+            limits: Limits::UNLIMITED,
+            allocated: Cell::new(0),
+            trusted: true,
+        }
+    }
+
+    /// Test if the input is trusted, in which case validation which isn't
+    /// needed for memory safety can be skipped.
+    #[inline]
+    pub(crate) fn is_trusted(&self) -> bool {
+        self.trusted
+    }
+
+    /// Test if unknown fields and variants should be rejected.
+    #[inline]
+    pub(crate) fn is_strict(&self) -> bool {
+        self.limits.strict
+    }
+
+    /// The maximum number of bytes in a continuation-encoded number.
+    #[inline]
+    pub(crate) fn max_continuation(&self) -> usize {
+        self.limits.max_continuation
+    }
+
+    /// Check the length of an element without counting it towards the total
+    /// allocation, which is used when the element is skipped over.
+    #[inline]
+    pub(crate) fn check_element<C>(&self, cx: &C, mark: C::Mark, len: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let max = self.limits.max_element_length;
+
+        if len > max {
+            return Err(cx.marked_message(
+                mark,
+                LimitExceeded {
+                    what: "Element length",
+                    actual: len,
+                    max,
+                },
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Check the number of items in a sequence without counting it towards
+    /// the total allocation, which is used when the sequence is skipped over.
+    #[inline]
+    pub(crate) fn check_sequence<C>(
+        &self,
+        cx: &C,
+        mark: C::Mark,
+        len: usize,
+    ) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let max = self.limits.max_sequence_count;
+
+        if len > max {
+            return Err(cx.marked_message(
+                mark,
+                LimitExceeded {
+                    what: "Sequence count",
+                    actual: len,
+                    max,
+                },
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Check the length of an element which is about to be decoded.
+    #[inline]
+    pub(crate) fn element<C>(&self, cx: &C, mark: C::Mark, len: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.check_element(cx, mark, len)?;
+        self.allocate(cx, mark, len)
+    }
+
+    /// Check the number of items in a sequence which is about to be decoded.
+    #[inline]
+    pub(crate) fn sequence<C>(&self, cx: &C, mark: C::Mark, len: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.check_sequence(cx, mark, len)?;
+        self.allocate(cx, mark, len)
+    }
+
+    /// Account for the next item of a sequence whose length wasn't known up
+    /// front, where `count` is the number of items including this one.
+    #[inline]
+    pub(crate) fn sequence_item<C>(
+        &self,
+        cx: &C,
+        mark: C::Mark,
+        count: usize,
+    ) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.check_sequence(cx, mark, count)?;
+        self.allocate(cx, mark, 1)
+    }
+
+    #[inline]
+    fn allocate<C>(&self, cx: &C, mark: C::Mark, len: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let max = self.limits.max_allocation;
+        let allocated = self.allocated.get().saturating_add(len);
+
+        if allocated > max {
+            return Err(cx.marked_message(
+                mark,
+                LimitExceeded {
+                    what: "Total allocation",
+                    actual: allocated,
+                    max,
+                },
+            ));
+        }
+
+        self.allocated.set(allocated);
+        Ok(())
+    }
+}
+
+struct LimitExceeded {
+    what: &'static str,
+    actual: usize,
+    max: usize,
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { what, actual, max } = *self;
+        write!(f, "{what} {actual} exceeds the maximum of {max}")
+    }
+}
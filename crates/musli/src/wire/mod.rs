@@ -90,11 +90,27 @@
 #[cfg(test)]
 mod tests;
 
+pub(crate) mod checksum;
+#[cfg(feature = "tokio-util")]
+mod codec;
+#[cfg(any(feature = "zstd", feature = "lz4"))]
+mod compression;
 mod de;
 mod en;
 mod encoding;
 mod error;
+mod header;
+#[cfg(feature = "alloc")]
+mod inspect;
 mod int;
+mod limits;
+mod padding;
+#[cfg(feature = "alloc")]
+mod patch;
+mod project;
+mod raw_value;
+#[cfg(all(feature = "std", feature = "alloc"))]
+mod stream;
 mod tag;
 
 #[cfg(feature = "test")]
@@ -108,15 +124,53 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 #[doc(inline)]
-pub use self::encoding::to_vec;
+pub use self::encoding::{diff, inspect, patch, to_vec, to_vec_in};
 #[doc(inline)]
 #[cfg(feature = "std")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 pub use self::encoding::to_writer;
 #[doc(inline)]
-pub use self::encoding::{decode, encode, from_slice, to_fixed_bytes, Encoding, DEFAULT, OPTIONS};
+#[cfg(all(feature = "std", feature = "alloc"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "alloc"))))]
+pub use self::encoding::decode_stream;
+#[doc(inline)]
+pub use self::encoding::{
+    decode, decode_into, encode, encoded_len, from_slice, from_slice_partial, from_slice_path,
+    from_slice_unchecked, to_fixed_bytes, Encoding, DEFAULT, FIXED_LENGTHS16, FIXED_LENGTHS_USIZE,
+    OPTIONS,
+};
+#[doc(inline)]
+pub use self::checksum::Checksum;
 #[doc(inline)]
 pub use self::error::Error;
+#[doc(inline)]
+pub use self::header::Header;
+#[doc(inline)]
+pub use self::padding::PackPadding;
+#[doc(inline)]
+pub use self::raw_value::RawValue;
+#[cfg(all(feature = "std", feature = "alloc"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", feature = "alloc"))))]
+#[doc(inline)]
+pub use self::stream::DecodeStream;
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[doc(inline)]
+pub use self::inspect::Node;
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[doc(inline)]
+pub use self::patch::Patch;
+
+#[cfg(feature = "tokio-util")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "tokio-util")))]
+#[doc(inline)]
+pub use self::codec::Codec;
+
+#[cfg(any(feature = "zstd", feature = "lz4"))]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "zstd", feature = "lz4"))))]
+#[doc(inline)]
+pub use self::compression::Compression;
 
 /// The maximum length that can be inlined in the tag without adding additional
 /// data to the wire format.
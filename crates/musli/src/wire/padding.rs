@@ -0,0 +1,42 @@
+/// How the content of packs is padded.
+///
+/// Padding is written as zeros after the content of a pack and is included in
+/// its length prefix. Decoders skip whatever remains of a pack once its
+/// content has been decoded, so a padded pack decodes the same way regardless
+/// of which padding it was encoded with.
+///
+/// See [`Encoding::with_pack_padding`].
+///
+/// [`Encoding::with_pack_padding`]: super::Encoding::with_pack_padding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PackPadding {
+    /// Prefix packs with their exact length. This is the default.
+    Exact,
+    /// Pad packs to a multiple of the given number of bytes. A multiple of `0`
+    /// or `1` is the same as [`PackPadding::Exact`].
+    Multiple(usize),
+    /// Pad packs which are at least `threshold` bytes long to the next power
+    /// of two. Shorter packs are prefixed with their exact length.
+    PowerOfTwo {
+        /// The length from which packs are padded.
+        threshold: usize,
+    },
+}
+
+impl PackPadding {
+    /// Calculate the length of a pack with `len` bytes of content once it's
+    /// been padded, or `None` if it would overflow.
+    #[inline]
+    pub(crate) const fn padded_len(self, len: usize) -> Option<usize> {
+        match self {
+            PackPadding::Exact | PackPadding::Multiple(0 | 1) => Some(len),
+            PackPadding::Multiple(multiple) => match len % multiple {
+                0 => Some(len),
+                rem => len.checked_add(multiple - rem),
+            },
+            PackPadding::PowerOfTwo { threshold } if len < threshold => Some(len),
+            PackPadding::PowerOfTwo { .. } => len.checked_next_power_of_two(),
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use core::fmt;
+
+use rust_alloc::collections::BTreeMap;
+use rust_alloc::vec;
+use rust_alloc::vec::Vec;
+
+use crate::de::{Decode, Decoder, UnsizedVisitor};
+use crate::en::{Encode, Encoder};
+use crate::int::continuation as c;
+use crate::Context;
+
+/// A patch which turns one wire message into another, as produced by
+/// [`diff`].
+///
+/// The patch is computed structurally, so values which are unchanged between
+/// the two messages are copied from the old message while only the values
+/// which changed are included in the patch. This makes it suitable for
+/// synchronizing large values over the network by only sending what changed.
+///
+/// A patch can only be applied to the exact message it was computed from,
+/// which is checked when it's applied. It can be sent on its own through
+/// [`Patch::as_bytes`] or as part of another message, since it implements
+/// [`Encode`] and [`Decode`].
+///
+/// [`diff`]: super::diff
+///
+/// # Examples
+///
+/// ```
+/// use musli::wire;
+///
+/// let mut state = vec![String::from("Aristotle"); 100];
+/// let old = wire::to_vec(&state)?;
+///
+/// state[42] = String::from("Plato");
+/// let new = wire::to_vec(&state)?;
+///
+/// let patch = wire::diff(&old, &new)?;
+/// assert!(patch.as_bytes().len() < 32);
+///
+/// let patched = wire::patch(&old, &patch)?;
+/// assert_eq!(patched, new);
+/// # Ok::<_, musli::wire::Error>(())
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Patch {
+    data: Vec<u8>,
+}
+
+impl Patch {
+    /// Construct a patch from the bytes returned by [`Patch::as_bytes`].
+    ///
+    /// The bytes are validated when the patch is applied.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            data: bytes.to_vec(),
+        }
+    }
+
+    /// Get the bytes of the patch.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Apply the patch to `old`.
+    pub(crate) fn apply<C>(&self, cx: &C, old: &[u8]) -> Result<Vec<u8>, C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let mut reader = self.data.as_slice();
+        let expected = c::decode::<_, _, usize>(cx, &mut reader)?;
+
+        if expected != old.len() {
+            return Err(cx.message(format_args!(
+                "Patch applies to a message of {expected} bytes, but was {}",
+                old.len()
+            )));
+        }
+
+        let mut out = Vec::new();
+
+        while !reader.is_empty() {
+            let op = c::decode::<_, _, usize>(cx, &mut reader)?;
+            let len = op >> 1;
+
+            if op & INSERT == INSERT {
+                if reader.len() < len {
+                    return Err(cx.message("Patch inserts more bytes than it contains"));
+                }
+
+                let (bytes, rest) = reader.split_at(len);
+                out.extend_from_slice(bytes);
+                reader = rest;
+            } else {
+                let offset = c::decode::<_, _, usize>(cx, &mut reader)?;
+
+                let Some(bytes) = offset.checked_add(len).and_then(|end| old.get(offset..end))
+                else {
+                    return Err(cx.message("Patch copies from outside of the message"));
+                };
+
+                out.extend_from_slice(bytes);
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+impl fmt::Debug for Patch {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Patch(\"{}\")", self.data.escape_ascii())
+    }
+}
+
+impl<M> Encode<M> for Patch {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_bytes(&self.data)
+    }
+}
+
+impl<'de, M> Decode<'de, M> for Patch {
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        struct Visitor;
+
+        impl<'de, C> UnsizedVisitor<'de, C, [u8]> for Visitor
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = Patch;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a patch")
+            }
+
+            #[inline]
+            fn visit_ref(self, _: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+                Ok(Patch::from_bytes(bytes))
+            }
+        }
+
+        decoder.decode_bytes(Visitor)
+    }
+}
+
+/// Set in the operation of a patch if bytes are inserted rather than copied
+/// from the old message.
+const INSERT: usize = 1;
+
+/// Where a value and the values nested in it are located in a message.
+pub(crate) struct Span {
+    /// The offset of the tag of the value.
+    pub(crate) start: usize,
+    /// The offset of the first nested value, following the tag and anything
+    /// which comes with it such as a length.
+    pub(crate) items_start: usize,
+    /// The offset following the value, including any end marker.
+    pub(crate) end: usize,
+    /// The nested values.
+    pub(crate) items: Vec<Span>,
+}
+
+impl Span {
+    /// A value which doesn't contain any other values.
+    #[inline]
+    pub(crate) fn leaf(start: usize, end: usize) -> Self {
+        Self {
+            start,
+            items_start: end,
+            end,
+            items: Vec::new(),
+        }
+    }
+
+    /// A whole message of length `len`, which contains a single value that
+    /// might be surrounded by a header and a checksum.
+    #[inline]
+    pub(crate) fn message(len: usize, value: Span) -> Self {
+        Self {
+            start: 0,
+            items_start: value.start,
+            end: len,
+            items: vec![value],
+        }
+    }
+
+    /// The range following the last nested value.
+    #[inline]
+    fn tail(&self) -> (usize, usize) {
+        let start = self.items.last().map_or(self.items_start, |item| item.end);
+        (start, self.end)
+    }
+}
+
+/// Compute the patch which turns `old` into `new`.
+pub(crate) fn diff<C>(
+    cx: &C,
+    old: &[u8],
+    old_span: &Span,
+    new: &[u8],
+    new_span: &Span,
+) -> Result<Patch, C::Error>
+where
+    C: ?Sized + Context,
+{
+    let mut differ = Differ {
+        old,
+        new,
+        ops: Vec::new(),
+    };
+
+    differ.span(old_span, new_span);
+
+    let mut data = Vec::new();
+    c::encode(cx, &mut data, old.len())?;
+
+    for op in differ.ops {
+        match op {
+            Op::Copy { offset, len } => {
+                c::encode(cx, &mut data, len << 1)?;
+                c::encode(cx, &mut data, offset)?;
+            }
+            Op::Insert { start, end } => {
+                c::encode(cx, &mut data, ((end - start) << 1) | INSERT)?;
+                data.extend_from_slice(&new[start..end]);
+            }
+        }
+    }
+
+    Ok(Patch { data })
+}
+
+/// An operation which produces the next bytes of the new message.
+enum Op {
+    /// Copy `len` bytes at `offset` in the old message.
+    Copy { offset: usize, len: usize },
+    /// Insert the given range of the new message.
+    Insert { start: usize, end: usize },
+}
+
+struct Differ<'a> {
+    old: &'a [u8],
+    new: &'a [u8],
+    ops: Vec<Op>,
+}
+
+impl Differ<'_> {
+    fn span(&mut self, old: &Span, new: &Span) {
+        if self.old[old.start..old.end] == self.new[new.start..new.end] {
+            self.copy(old.start, new.end - new.start);
+            return;
+        }
+
+        // Only values which contain other values are worth descending into,
+        // anything else is replaced wholesale.
+        if old.items.is_empty() || new.items.is_empty() {
+            self.insert(new.start, new.end);
+            return;
+        }
+
+        self.range((old.start, old.items_start), (new.start, new.items_start));
+        self.items(&old.items, &new.items);
+        self.range(old.tail(), new.tail());
+    }
+
+    fn items(&mut self, old: &[Span], new: &[Span]) {
+        // Items might have been added, removed or moved, so look for identical
+        // items anywhere before comparing them by position.
+        let mut index = BTreeMap::new();
+
+        for item in old {
+            index
+                .entry(&self.old[item.start..item.end])
+                .or_insert(item.start);
+        }
+
+        for (n, item) in new.iter().enumerate() {
+            let bytes = &self.new[item.start..item.end];
+
+            let same = old
+                .get(n)
+                .filter(|old| self.old[old.start..old.end] == *bytes)
+                .map(|old| old.start);
+
+            if let Some(offset) = same.or_else(|| index.get(bytes).copied()) {
+                self.copy(offset, bytes.len());
+            } else if let Some(old) = old.get(n) {
+                self.span(old, item);
+            } else {
+                self.insert(item.start, item.end);
+            }
+        }
+    }
+
+    fn range(&mut self, (old_start, old_end): (usize, usize), (start, end): (usize, usize)) {
+        if self.old[old_start..old_end] == self.new[start..end] {
+            self.copy(old_start, end - start);
+        } else {
+            self.insert(start, end);
+        }
+    }
+
+    fn copy(&mut self, offset: usize, len: usize) {
+        if len == 0 {
+            return;
+        }
+
+        if let Some(Op::Copy {
+            offset: last,
+            len: last_len,
+        }) = self.ops.last_mut()
+        {
+            if *last + *last_len == offset {
+                *last_len += len;
+                return;
+            }
+        }
+
+        self.ops.push(Op::Copy { offset, len });
+    }
+
+    fn insert(&mut self, start: usize, end: usize) {
+        if start == end {
+            return;
+        }
+
+        if let Some(Op::Insert { end: last, .. }) = self.ops.last_mut() {
+            if *last == start {
+                *last = end;
+                return;
+            }
+        }
+
+        self.ops.push(Op::Insert { start, end });
+    }
+}
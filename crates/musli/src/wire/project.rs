@@ -0,0 +1,110 @@
+use crate::alloc::Vec;
+use crate::de::{Decode, Decoder, EntryDecoder, MapDecoder};
+use crate::en::{Encode, Encoder};
+use crate::{Context, Options, Writer};
+
+use super::en::{CanonicalWriter, WireEncoder};
+use super::padding::PackPadding;
+use super::raw_value::RawValue;
+
+/// Decode the value found by following `path` through nested maps, skipping
+/// over everything else.
+///
+/// Keys are matched by comparing their encoded bytes against the encoding of
+/// each element in the path, so path elements only need to encode in the same
+/// way as the field tags they correspond to.
+pub(crate) fn project<'de, D, K, T, const OPT: Options>(
+    cx: &D::Cx,
+    decoder: D,
+    canonical: bool,
+    padding: PackPadding,
+    path: &[K],
+) -> Result<Option<T>, D::Error>
+where
+    D: Decoder<'de>,
+    K: Encode<D::Mode>,
+    T: Decode<'de, D::Mode>,
+{
+    let Some((head, rest)) = path.split_first() else {
+        return decoder.decode().map(Some);
+    };
+
+    decoder.decode_map(|map| {
+        while let Some(mut entry) = map.decode_entry()? {
+            let key = entry.decode_key()?.decode::<RawValue<'de>>()?;
+
+            let mut writer = CanonicalWriter::new(cx, canonical, MatchWriter::new(key.as_bytes()))?;
+            WireEncoder::<_, OPT, _>::new(cx, canonical, padding, &mut writer).encode(head)?;
+            let writer = writer.finish(cx)?;
+
+            if writer.is_match() {
+                return project::<_, _, _, OPT>(
+                    cx,
+                    entry.decode_value()?,
+                    canonical,
+                    padding,
+                    rest,
+                );
+            }
+
+            entry.decode_value()?.skip()?;
+        }
+
+        Ok(None)
+    })
+}
+
+/// A writer which compares what's written against an expected sequence of
+/// bytes.
+///
+/// This doesn't advance the context, since it's used while decoding.
+struct MatchWriter<'a> {
+    expected: &'a [u8],
+    matches: bool,
+}
+
+impl<'a> MatchWriter<'a> {
+    #[inline]
+    fn new(expected: &'a [u8]) -> Self {
+        Self {
+            expected,
+            matches: true,
+        }
+    }
+
+    /// Test if exactly the expected bytes were written.
+    #[inline]
+    fn is_match(&self) -> bool {
+        self.matches && self.expected.is_empty()
+    }
+}
+
+impl Writer for MatchWriter<'_> {
+    type Mut<'this> = &'this mut Self where Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn extend<C>(&mut self, cx: &C, buffer: Vec<'_, u8, C::Allocator>) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.write_bytes(cx, buffer.as_slice())
+    }
+
+    #[inline]
+    fn write_bytes<C>(&mut self, _: &C, bytes: &[u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        match self.expected.strip_prefix(bytes) {
+            Some(rest) if self.matches => self.expected = rest,
+            _ => self.matches = false,
+        }
+
+        Ok(())
+    }
+}
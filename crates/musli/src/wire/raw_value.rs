@@ -0,0 +1,111 @@
+use core::fmt;
+
+use crate::de::{Decode, Decoder, UnsizedVisitor};
+use crate::mode::Binary;
+use crate::Context;
+
+use super::Error;
+
+/// The raw bytes of a value which is decoded on demand.
+///
+/// Decoding a `RawValue` skips over the value it's decoded from while keeping
+/// hold of the bytes which make it up. This is useful for routing messages
+/// based on a header while deferring decoding of the body until its type is
+/// known.
+///
+/// Since the bytes are borrowed, this can only be decoded from a slice. The
+/// captured value doesn't include any [`Header`] or [`Checksum`] of the
+/// enclosing message, so if it was encoded with options other than the
+/// default it should be decoded with an [`Encoding`] that has the same options
+/// but no header or checksum.
+///
+/// [`Header`]: super::Header
+/// [`Checksum`]: super::Checksum
+/// [`Encoding`]: super::Encoding
+///
+/// # Examples
+///
+/// ```
+/// use musli::{Decode, Encode};
+/// use musli::wire::{self, RawValue};
+///
+/// #[derive(Encode)]
+/// struct Envelope<T> {
+///     kind: u32,
+///     body: T,
+/// }
+///
+/// #[derive(Decode)]
+/// struct Routed<'de> {
+///     kind: u32,
+///     body: RawValue<'de>,
+/// }
+///
+/// let data = wire::to_vec(&Envelope { kind: 1, body: String::from("Aristotle") })?;
+/// let routed: Routed<'_> = wire::from_slice(&data)?;
+///
+/// match routed.kind {
+///     1 => assert_eq!(routed.body.decode::<String>()?, "Aristotle"),
+///     _ => panic!("unknown message"),
+/// }
+/// # Ok::<_, musli::wire::Error>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawValue<'de> {
+    bytes: &'de [u8],
+}
+
+impl<'de> RawValue<'de> {
+    /// Get the encoded bytes of the value.
+    #[inline]
+    pub fn as_bytes(&self) -> &'de [u8] {
+        self.bytes
+    }
+
+    /// Decode the value as `T` using the [`DEFAULT`] encoding.
+    ///
+    /// [`DEFAULT`]: super::DEFAULT
+    #[inline]
+    pub fn decode<T>(&self) -> Result<T, Error>
+    where
+        T: Decode<'de, Binary>,
+    {
+        super::from_slice(self.bytes)
+    }
+}
+
+impl fmt::Debug for RawValue<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RawValue(\"{}\")", self.bytes.escape_ascii())
+    }
+}
+
+impl<'de, M> Decode<'de, M> for RawValue<'de> {
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        struct Visitor;
+
+        impl<'de, C> UnsizedVisitor<'de, C, [u8]> for Visitor
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = RawValue<'de>;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a raw value")
+            }
+
+            #[inline]
+            fn visit_borrowed(self, _: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
+                Ok(RawValue { bytes })
+            }
+        }
+
+        decoder.decode_raw(Visitor)
+    }
+}
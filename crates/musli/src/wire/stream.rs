@@ -0,0 +1,85 @@
+use core::iter::FusedIterator;
+use core::marker;
+
+use std::io;
+
+use crate::de::DecodeOwned;
+use crate::io::IoReader;
+use crate::mode::Binary;
+use crate::Options;
+
+use super::encoding::{Encoding, OPTIONS};
+use super::error::Error;
+
+/// An iterator over values which have been encoded back to back, as produced
+/// by [`Encoding::decode_stream`].
+///
+/// The iterator ends once the underlying reader has reached its end without
+/// any bytes left over. If it ends in the middle of a value or a value fails
+/// to decode, an error is produced after which the iterator ends.
+pub struct DecodeStream<R, T, const OPT: Options = OPTIONS, M = Binary>
+where
+    M: 'static,
+{
+    encoding: Encoding<OPT, M>,
+    reader: IoReader<R>,
+    done: bool,
+    _marker: marker::PhantomData<fn() -> T>,
+}
+
+impl<R, T, const OPT: Options, M> DecodeStream<R, T, OPT, M>
+where
+    M: 'static,
+{
+    #[inline]
+    pub(crate) fn new(encoding: Encoding<OPT, M>, reader: R) -> Self {
+        Self {
+            encoding,
+            reader: IoReader::new(reader),
+            done: false,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Coerce into the buffered reader, which holds on to any bytes which have
+    /// been read but not yet decoded.
+    #[inline]
+    pub fn into_reader(self) -> IoReader<R> {
+        self.reader
+    }
+}
+
+impl<R, T, const OPT: Options, M> Iterator for DecodeStream<R, T, OPT, M>
+where
+    R: io::Read,
+    T: DecodeOwned<M>,
+    M: 'static,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = match self.reader.at_end() {
+            Ok(true) => {
+                self.done = true;
+                return None;
+            }
+            Ok(false) => self.encoding.decode_reader(&mut self.reader),
+            Err(error) => Err(Error::from(error)),
+        };
+
+        self.done = result.is_err();
+        Some(result)
+    }
+}
+
+impl<R, T, const OPT: Options, M> FusedIterator for DecodeStream<R, T, OPT, M>
+where
+    R: io::Read,
+    T: DecodeOwned<M>,
+    M: 'static,
+{
+}
@@ -15,9 +15,10 @@ pub(crate) const DATA_MASK: u8 = 0b00_111111;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub(crate) enum Kind {
-    /// A reserved value.
-    #[allow(unused)]
-    Reserved = 0b00_000000,
+    /// A marker which delimits a sequence of unknown length or an extension.
+    /// Data is [`STREAM`] at the start of such a sequence and [`END`] at its
+    /// end, or [`EXTENSION`] ahead of an application-defined value.
+    Marker = 0b00_000000,
     /// A fixed element where data indicates how many bytes it consists of. Data
     /// contains the prefix length unless it's set to all 1s after which a
     /// continuation sequence indicating the length should be decoded.
@@ -32,6 +33,18 @@ pub(crate) enum Kind {
     Continuation = 0b11_000000,
 }
 
+/// Marks the start of a sequence which is terminated by [`END`] instead of
+/// being prefixed with its length.
+pub(crate) const STREAM: Tag = Tag::new(Kind::Marker, 1);
+
+/// Marks the end of a sequence started with [`STREAM`].
+pub(crate) const END: Tag = Tag::new(Kind::Marker, 0);
+
+/// Marks an application-defined extension. It's followed by a
+/// continuation-encoded type id and then by the value of the extension, which
+/// allows it to be skipped without knowing what it is.
+pub(crate) const EXTENSION: Tag = Tag::new(Kind::Marker, 2);
+
 /// A type tag.
 ///
 /// The [Kind] of the element is indicates by its 2 MSBs, and remaining 6 bits
@@ -30,4 +30,4 @@ impl<const N: usize> Typed<N> {
     }
 }
 
-crate::macros::test_fns!(Binary, "wire");
+crate::macros::test_fns!(Binary, "wire", #[inspect]);
@@ -1,7 +1,14 @@
+use crate::options::{self, ByteOrder, Integer, Options, Width};
 use crate::wire::tag::{Kind, Tag};
 use crate::wire::MAX_INLINE_LEN;
 use crate::{Decode, Encode};
 
+const FIXED_LENGTH: Options = options::new()
+    .with_length(Integer::Fixed)
+    .with_length_width(Width::U32)
+    .with_byte_order(ByteOrder::Little)
+    .build();
+
 #[derive(Debug, PartialEq, Encode, Decode)]
 #[musli(crate, name_type = usize)]
 struct From<const N: usize> {
@@ -61,3 +68,49 @@ fn pack_inline_max() {
     test!(23, 23);
     test!(MAX_INLINE_LEN, 62);
 }
+
+#[test]
+fn pack_reserved_prefix() {
+    const ENCODING: crate::wire::Encoding<FIXED_LENGTH> =
+        crate::wire::Encoding::new().with_options();
+
+    // Short packs embed their length in the tag regardless of the writer.
+    let field = Field { value: [1, 2, 3] };
+
+    let bytes = ENCODING.to_vec(&field).unwrap();
+    assert_eq!(bytes, [Tag::new(Kind::Prefix, 3).byte(), 1, 2, 3]);
+
+    let fixed = ENCODING.to_fixed_bytes::<16, _>(&field).unwrap();
+    assert_eq!(fixed.as_slice(), bytes);
+    assert_eq!(ENCODING.from_slice::<Field<3>>(&bytes).unwrap(), field);
+
+    // Longer packs written to a vector reserve a fixed-width prefix and fill
+    // it in once the pack is finished, which is identical to buffering them.
+    let field = Field { value: [1; 100] };
+
+    let bytes = ENCODING.to_vec(&field).unwrap();
+    assert_eq!(bytes[..5], [Tag::empty(Kind::Prefix).byte(), 100, 0, 0, 0]);
+
+    let fixed = ENCODING.to_fixed_bytes::<128, _>(&field).unwrap();
+    assert_eq!(fixed.as_slice(), bytes);
+    assert_eq!(ENCODING.from_slice::<Field<100>>(&bytes).unwrap(), field);
+
+    let value = From {
+        prefix: Some(10),
+        field: Field { value: [1; 100] },
+        suffix: Some(20),
+    };
+
+    let bytes = ENCODING.to_vec(&value).unwrap();
+    assert_eq!(ENCODING.from_slice::<From<100>>(&bytes).unwrap(), value);
+
+    let to: To = ENCODING.from_slice(&bytes).unwrap();
+
+    assert_eq!(
+        to,
+        To {
+            prefix: Some(10),
+            suffix: Some(20)
+        }
+    );
+}
@@ -47,6 +47,30 @@ pub trait Writer {
     {
         self.write_bytes(cx, &[b])
     }
+
+    /// Reserve `len` zeroed bytes which can be filled in later through
+    /// [`Writer::reserved_mut`], returning the offset they were reserved at.
+    ///
+    /// Returns `None` if the writer can't go back to modify data which has
+    /// already been written.
+    #[doc(hidden)]
+    #[inline]
+    fn reserve<C>(&mut self, cx: &C, len: usize) -> Result<Option<usize>, C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        _ = (cx, len);
+        Ok(None)
+    }
+
+    /// Access everything that has been written since the given offset, which
+    /// was previously returned from [`Writer::reserve`].
+    #[doc(hidden)]
+    #[inline]
+    fn reserved_mut(&mut self, at: usize) -> &mut [u8] {
+        _ = at;
+        &mut []
+    }
 }
 
 impl<W> Writer for &mut W
@@ -83,6 +107,19 @@ where
     {
         (*self).write_byte(cx, b)
     }
+
+    #[inline]
+    fn reserve<C>(&mut self, cx: &C, len: usize) -> Result<Option<usize>, C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        (*self).reserve(cx, len)
+    }
+
+    #[inline]
+    fn reserved_mut(&mut self, at: usize) -> &mut [u8] {
+        (*self).reserved_mut(at)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -122,6 +159,66 @@ impl Writer for rust_alloc::vec::Vec<u8> {
         cx.advance(1);
         Ok(())
     }
+
+    #[inline]
+    fn reserve<C>(&mut self, cx: &C, len: usize) -> Result<Option<usize>, C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let at = self.len();
+        self.resize(at + len, 0);
+        cx.advance(len);
+        Ok(Some(at))
+    }
+
+    #[inline]
+    fn reserved_mut(&mut self, at: usize) -> &mut [u8] {
+        &mut self[at..]
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Writer for bytes::BytesMut {
+    type Mut<'this> = &'this mut Self where Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn extend<C>(&mut self, cx: &C, buffer: Vec<'_, u8, C::Allocator>) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.write_bytes(cx, buffer.as_slice())
+    }
+
+    #[inline]
+    fn write_bytes<C>(&mut self, cx: &C, bytes: &[u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.extend_from_slice(bytes);
+        cx.advance(bytes.len());
+        Ok(())
+    }
+
+    #[inline]
+    fn reserve<C>(&mut self, cx: &C, len: usize) -> Result<Option<usize>, C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let at = self.len();
+        self.resize(at + len, 0);
+        cx.advance(len);
+        Ok(Some(at))
+    }
+
+    #[inline]
+    fn reserved_mut(&mut self, at: usize) -> &mut [u8] {
+        &mut self[at..]
+    }
 }
 
 impl Writer for &mut [u8] {
@@ -201,6 +298,21 @@ where
     pub fn into_inner(self) -> Vec<'a, u8, A> {
         self.buf
     }
+
+    /// Get the number of bytes written so far.
+    pub(crate) fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Get the bytes written so far.
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        self.buf.as_slice()
+    }
+
+    /// Clear the buffer, keeping its capacity.
+    pub(crate) fn clear(&mut self) {
+        self.buf.clear();
+    }
 }
 
 impl<'a, A> Writer for BufWriter<'a, A>
@@ -239,6 +351,97 @@ where
 
         Ok(())
     }
+
+    #[inline(always)]
+    fn reserve<C>(&mut self, cx: &C, len: usize) -> Result<Option<usize>, C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let at = self.buf.len();
+
+        for _ in 0..len {
+            if !self.buf.push(0) {
+                return Err(cx.message("Buffer overflow"));
+            }
+        }
+
+        Ok(Some(at))
+    }
+
+    #[inline(always)]
+    fn reserved_mut(&mut self, at: usize) -> &mut [u8] {
+        &mut self.buf.as_mut_slice()[at..]
+    }
+}
+
+/// A writer which discards everything written to it, keeping track of how many
+/// bytes would have been written.
+///
+/// This is used to compute the exact encoded length of a value without
+/// allocating, see for example [`wire::Encoding::encoded_len`].
+///
+/// [`wire::Encoding::encoded_len`]: crate::wire::Encoding::encoded_len
+#[derive(Debug, Default, Clone, Copy)]
+#[non_exhaustive]
+pub struct CountWriter {
+    len: usize,
+}
+
+impl CountWriter {
+    /// Construct a new counting writer.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { len: 0 }
+    }
+
+    /// The number of bytes written so far.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test if no bytes have been written.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Writer for CountWriter {
+    type Mut<'this> = &'this mut Self where Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn extend<C>(&mut self, cx: &C, buffer: Vec<'_, u8, C::Allocator>) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.write_bytes(cx, buffer.as_slice())
+    }
+
+    #[inline]
+    fn write_bytes<C>(&mut self, cx: &C, bytes: &[u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.len += bytes.len();
+        cx.advance(bytes.len());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_byte<C>(&mut self, cx: &C, _: u8) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.len += 1;
+        cx.advance(1);
+        Ok(())
+    }
 }
 
 /// Overflow when trying to write to a slice.
@@ -0,0 +1,192 @@
+use musli::context;
+use musli::{Decode, Encode};
+
+#[derive(Encode)]
+#[musli(name_all = "name")]
+struct PartialServer {
+    name: String,
+}
+
+#[derive(Encode)]
+#[musli(name_all = "name")]
+struct PartialConfig {
+    server: PartialServer,
+}
+
+#[derive(Debug, Decode)]
+#[musli(name_all = "name")]
+struct Server {
+    name: String,
+    port: u16,
+    #[musli(default)]
+    timeout: u32,
+    host: String,
+}
+
+#[derive(Debug, Decode)]
+#[musli(name_all = "name")]
+struct Config {
+    server: Server,
+}
+
+fn partial() -> Vec<u8> {
+    musli::json::to_vec(&PartialConfig {
+        server: PartialServer {
+            name: String::from("api"),
+        },
+    })
+    .unwrap()
+}
+
+#[test]
+fn first_error_only() {
+    let cx = context::new();
+    let result = musli::json::DEFAULT.from_slice_with::<_, Config>(&cx, &partial());
+    assert!(result.is_err());
+
+    let errors = cx.errors().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("Expected tag: \"port\""));
+}
+
+#[test]
+fn accumulate_missing_fields() {
+    let mut cx = context::new();
+    cx.accumulate();
+
+    let result = musli::json::DEFAULT.from_slice_with::<_, Config>(&cx, &partial());
+    assert!(result.is_err());
+
+    let errors = cx.errors().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(errors[0].starts_with(".server: Expected tag: \"port\""));
+    assert!(errors[1].starts_with(".server: Expected tag: \"host\""));
+}
+
+#[derive(Debug, Decode)]
+#[musli(name_all = "name")]
+struct Named {
+    name: String,
+    server: Server,
+}
+
+#[test]
+fn accumulate_nested() {
+    let mut cx = context::new();
+    cx.accumulate();
+
+    let result = musli::json::DEFAULT.from_slice_with::<_, Named>(&cx, &partial());
+    assert!(result.is_err());
+
+    let errors = cx.errors().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(errors.len(), 3, "{errors:?}");
+    assert!(errors[0].starts_with(".server: Expected tag: \"port\""));
+    assert!(errors[1].starts_with(".server: Expected tag: \"host\""));
+    assert!(errors[2].contains("Expected tag: \"name\""));
+}
+
+#[test]
+fn accumulate_single() {
+    #[derive(Encode)]
+    #[musli(name_all = "name")]
+    struct PartialPort {
+        name: String,
+    }
+
+    #[derive(Debug, Decode)]
+    #[musli(name_all = "name")]
+    struct Port {
+        name: String,
+        port: u16,
+    }
+
+    let data = musli::json::to_vec(&PartialPort {
+        name: String::from("api"),
+    })
+    .unwrap();
+
+    let mut cx = context::new();
+    cx.accumulate();
+
+    let result = musli::json::DEFAULT.from_slice_with::<_, Port>(&cx, &data);
+    assert!(result.is_err());
+
+    let errors = cx.errors().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(errors[0].contains("Expected tag: \"port\""));
+}
+
+#[test]
+fn accumulate_unknown() {
+    #[derive(Encode)]
+    #[musli(name_all = "name")]
+    struct Extra {
+        name: String,
+        extra: u32,
+    }
+
+    #[derive(Debug, Decode)]
+    #[musli(name_all = "name", deny_unknown_fields)]
+    struct Strict {
+        name: String,
+        port: u16,
+    }
+
+    let data = musli::json::to_vec(&Extra {
+        name: String::from("api"),
+        extra: 42,
+    })
+    .unwrap();
+
+    let mut cx = context::new();
+    cx.accumulate();
+
+    let result = musli::json::DEFAULT.from_slice_with::<_, Strict>(&cx, &data);
+    assert!(result.is_err());
+
+    let errors = cx.errors().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(errors[0].contains("Invalid field tag"));
+    assert!(errors[1].contains("Expected tag: \"port\""));
+}
+
+/// Errors inside of a sequence leave it partially decoded, so decoding of the
+/// enclosing struct stops there.
+#[test]
+fn accumulate_stops_in_sequence() {
+    #[derive(Encode)]
+    #[musli(name_all = "name")]
+    struct PartialServers {
+        servers: Vec<PartialServer>,
+    }
+
+    #[derive(Debug, Decode)]
+    #[musli(name_all = "name")]
+    struct Servers {
+        servers: Vec<Server>,
+        name: String,
+    }
+
+    let data = musli::json::to_vec(&PartialServers {
+        servers: vec![
+            PartialServer {
+                name: String::from("a"),
+            },
+            PartialServer {
+                name: String::from("b"),
+            },
+        ],
+    })
+    .unwrap();
+
+    let mut cx = context::new();
+    cx.accumulate();
+
+    let result = musli::json::DEFAULT.from_slice_with::<_, Servers>(&cx, &data);
+    assert!(result.is_err());
+
+    let errors = cx.errors().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(errors[0].contains("Expected tag: \"port\""));
+    assert!(errors[1].contains("Expected tag: \"host\""));
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "test")]
+
+use musli::mode::Binary;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Person {
+    #[musli(alias = "fullname", alias = "full_name")]
+    name: String,
+    age: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+enum Message {
+    #[musli(name_all = "name", alias = "Notify")]
+    Notification {
+        #[musli(alias = "name")]
+        method: String,
+    },
+    #[musli(alias = "Close")]
+    Shutdown,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Indexed {
+    #[musli(mode = Binary, alias = 10)]
+    value: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", case_insensitive)]
+struct Config {
+    #[musli(alias = "log_level")]
+    level: Level,
+    verbose: bool,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", case_insensitive)]
+enum Level {
+    Debug,
+    #[musli(alias = "warning")]
+    Warn,
+}
+
+#[test]
+fn field_alias() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Person {
+            name: String::from("Aristotle"),
+            age: 62,
+        },
+        json = r#"{"name":"Aristotle","age":62}"#
+    );
+
+    for json in [
+        r#"{"fullname":"Aristotle","age":62}"#,
+        r#"{"full_name":"Aristotle","age":62}"#,
+    ] {
+        let person: Person = musli::json::from_str(json).unwrap();
+
+        assert_eq!(
+            person,
+            Person {
+                name: String::from("Aristotle"),
+                age: 62,
+            }
+        );
+    }
+
+    let bytes = musli::wire::to_vec(&Indexed { value: 42 }).unwrap();
+    let actual: Indexed = musli::wire::from_slice(&bytes).unwrap();
+    assert_eq!(actual, Indexed { value: 42 });
+}
+
+#[test]
+fn variant_alias() {
+    let actual: Message = musli::json::from_str(r#"{"Notify":{"name":"ping"}}"#).unwrap();
+
+    assert_eq!(
+        actual,
+        Message::Notification {
+            method: String::from("ping"),
+        }
+    );
+
+    let actual: Message = musli::json::from_str(r#"{"Close":{}}"#).unwrap();
+    assert_eq!(actual, Message::Shutdown);
+
+    let json = musli::json::to_string(&Message::Shutdown).unwrap();
+    assert_eq!(json, r#"{"Shutdown":{}}"#);
+}
+
+#[test]
+fn case_insensitive() {
+    let actual: Config = musli::json::from_str(r#"{"LEVEL":"debug","Verbose":true}"#).unwrap();
+
+    assert_eq!(
+        actual,
+        Config {
+            level: Level::Debug,
+            verbose: true,
+        }
+    );
+
+    let actual: Config =
+        musli::json::from_str(r#"{"Log_Level":"WARNING","verbose":false}"#).unwrap();
+
+    assert_eq!(
+        actual,
+        Config {
+            level: Level::Warn,
+            verbose: false,
+        }
+    );
+
+    // Names are matched exactly unless `case_insensitive` is used.
+    assert!(musli::json::from_str::<Person>(r#"{"NAME":"Aristotle","age":62}"#).is_err());
+}
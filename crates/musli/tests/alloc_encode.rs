@@ -0,0 +1,60 @@
+use core::fmt::Write;
+
+use musli::alloc::{String, Vec};
+
+#[test]
+fn alloc_types_encode_like_std() {
+    musli::alloc::default!(|alloc| {
+        let mut values = Vec::new_in(alloc);
+
+        for name in ["Aristotle", "Plato"] {
+            let mut string = String::new_in(alloc);
+            write!(string, "{name}").unwrap();
+            assert!(values.push(string));
+        }
+
+        let expected = vec!["Aristotle", "Plato"];
+
+        let bytes = musli::storage::to_vec(&values).unwrap();
+        assert_eq!(bytes, musli::storage::to_vec(&expected).unwrap());
+
+        let actual: std::vec::Vec<std::string::String> =
+            musli::storage::from_slice(&bytes).unwrap();
+        assert_eq!(actual, expected);
+    });
+}
+
+#[test]
+fn alloc_types_decode_in_allocator() {
+    use musli::alloc::{ArrayBuffer, Slice};
+    use musli::storage::Encoding;
+
+    const ENCODING: Encoding = Encoding::new();
+
+    let mut buf = ArrayBuffer::new();
+    let alloc = Slice::new(&mut buf);
+
+    let bytes = ENCODING.to_vec(&[1u32, 2, 3]).unwrap();
+    let values: Vec<'_, u32, _> = ENCODING.from_slice_in(&alloc, &bytes).unwrap();
+    assert_eq!(values.as_slice(), [1, 2, 3]);
+
+    let bytes = ENCODING.to_vec("Aristotle").unwrap();
+    let string: String<'_, _> = ENCODING.from_slice_in(&alloc, &bytes).unwrap();
+    assert_eq!(&*string, "Aristotle");
+    assert_eq!(ENCODING.to_vec(&string).unwrap(), bytes);
+}
+
+#[test]
+fn alloc_types_decode_out_of_memory() {
+    use musli::alloc::{ArrayBuffer, Slice};
+    use musli::storage::Encoding;
+
+    const ENCODING: Encoding = Encoding::new();
+
+    let mut buf = ArrayBuffer::<16>::with_size();
+    let alloc = Slice::new(&mut buf);
+
+    let bytes = ENCODING.to_vec(&[0u64; 16]).unwrap();
+    let result: Result<Vec<'_, u64, _>, _> = ENCODING.from_slice_in(&alloc, &bytes);
+    assert!(result.is_err());
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+enum Cell {
+    Empty,
+    Wall(u8),
+    Door { open: bool },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Grid {
+    corners: [Point; 2],
+    cells: [[Cell; 2]; 2],
+    labels: [String; 3],
+    empty: [Point; 0],
+}
+
+#[test]
+fn arrays_of_structs_and_enums() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        [Point { x: 1, y: 2 }, Point { x: 3, y: 4 }],
+        json = r#"[{"x":1,"y":2},{"x":3,"y":4}]"#
+    );
+
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        [Cell::Empty, Cell::Wall(1), Cell::Door { open: true }]
+    );
+
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Grid {
+            corners: [Point { x: 0, y: 0 }, Point { x: 10, y: 10 }],
+            cells: [
+                [Cell::Empty, Cell::Wall(2)],
+                [Cell::Door { open: false }, Cell::Empty],
+            ],
+            labels: [String::from("a"), String::from("b"), String::from("c")],
+            empty: [],
+        }
+    );
+}
+
+#[test]
+fn array_length_mismatch() {
+    let error = musli::json::from_str::<[Point; 2]>(r#"[{"x":1,"y":2}]"#).unwrap_err();
+    assert!(error.to_string().contains("does not have the expected 2"));
+
+    let error =
+        musli::json::from_str::<[Point; 1]>(r#"[{"x":1,"y":2},{"x":3,"y":4}]"#).unwrap_err();
+    assert!(error.to_string().contains("more than the expected 1"));
+}
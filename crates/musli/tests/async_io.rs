@@ -0,0 +1,206 @@
+use std::future::Future;
+use std::io;
+use std::pin::{pin, Pin};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use musli::io::{AsyncRead, AsyncReader};
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Person {
+    name: String,
+    age: u32,
+    tags: Vec<String>,
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+
+        thread::park();
+    }
+}
+
+/// A reader which hands out a single byte at a time, and is pending every
+/// other poll.
+struct Trickle<'a> {
+    data: &'a [u8],
+    pending: bool,
+}
+
+impl AsyncRead for Trickle<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        self.pending = !self.pending;
+
+        if self.pending {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        let Some((&first, tail)) = self.data.split_first() else {
+            return Poll::Ready(Ok(0));
+        };
+
+        buf[0] = first;
+        self.data = tail;
+        Poll::Ready(Ok(1))
+    }
+}
+
+fn people() -> [Person; 2] {
+    [
+        Person {
+            name: String::from("Aristotle"),
+            age: 61,
+            tags: vec![String::from("philosopher")],
+        },
+        Person {
+            name: String::from("Plato"),
+            age: 80,
+            tags: Vec::new(),
+        },
+    ]
+}
+
+#[test]
+fn consecutive() {
+    let people = people();
+    let mut data = Vec::new();
+
+    for person in &people {
+        block_on(musli::wire::DEFAULT.encode_async(&mut data, person)).unwrap();
+    }
+
+    let mut reader = AsyncReader::new(Trickle {
+        data: &data,
+        pending: false,
+    });
+
+    for expected in &people {
+        let actual: Person = block_on(musli::wire::DEFAULT.decode_async(&mut reader)).unwrap();
+        assert_eq!(&actual, expected);
+    }
+
+    assert!(reader.buffer().is_empty());
+    assert!(block_on(musli::wire::DEFAULT.decode_async::<_, Person>(&mut reader)).is_err());
+}
+
+#[test]
+fn other_formats() {
+    let people = people();
+
+    let mut data = Vec::new();
+    block_on(musli::storage::DEFAULT.encode_async(&mut data, &people)).unwrap();
+    let mut reader = AsyncReader::new(&data[..]);
+    let actual: [Person; 2] = block_on(musli::storage::DEFAULT.decode_async(&mut reader)).unwrap();
+    assert_eq!(actual, people);
+
+    let mut data = Vec::new();
+    block_on(musli::descriptive::DEFAULT.encode_async(&mut data, &people)).unwrap();
+    let mut reader = AsyncReader::new(&data[..]);
+    let actual: [Person; 2] =
+        block_on(musli::descriptive::DEFAULT.decode_async(&mut reader)).unwrap();
+    assert_eq!(actual, people);
+}
+
+#[test]
+fn buffered_remainder() {
+    let mut data = musli::wire::to_vec(&42u32).unwrap();
+    data.extend_from_slice(&[0xde, 0xad]);
+
+    let mut reader = AsyncReader::new(&data[..]);
+    let value: u32 = block_on(musli::wire::DEFAULT.decode_async(&mut reader)).unwrap();
+    assert_eq!(value, 42);
+    assert_eq!(reader.buffer(), &[0xde, 0xad]);
+}
+
+/// A reader which hands out the given bytes, followed by zeros forever.
+struct Endless<'a>(&'a [u8]);
+
+impl AsyncRead for Endless<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let n = buf.len().min(self.0.len());
+        buf[..n].copy_from_slice(&self.0[..n]);
+        buf[n..].fill(0);
+        self.0 = &self.0[n..];
+        Poll::Ready(Ok(buf.len()))
+    }
+}
+
+/// A reader which is always ready, but hands out a few bytes at a time.
+struct Chunked<'a>(&'a [u8]);
+
+impl AsyncRead for Chunked<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let n = buf.len().min(self.0.len()).min(7);
+        buf[..n].copy_from_slice(&self.0[..n]);
+        self.0 = &self.0[n..];
+        Poll::Ready(Ok(n))
+    }
+}
+
+#[test]
+fn large_value() {
+    let value = "x".repeat(1 << 18);
+    let data = musli::wire::to_vec(&value).unwrap();
+
+    let mut reader = AsyncReader::new(Trickle {
+        data: &data,
+        pending: false,
+    });
+
+    let actual: String = block_on(musli::wire::DEFAULT.decode_async(&mut reader)).unwrap();
+    assert_eq!(actual, value);
+
+    let values = (0..1u32 << 16).collect::<Vec<_>>();
+    let data = musli::wire::to_vec(&values).unwrap();
+
+    let mut reader = AsyncReader::new(Chunked(&data));
+    let actual: Vec<u32> = block_on(musli::wire::DEFAULT.decode_async(&mut reader)).unwrap();
+    assert_eq!(actual, values);
+}
+
+#[test]
+fn max_buffered() {
+    // Only the length prefix of a value which is larger than the limit.
+    let data = musli::wire::to_vec(&"x".repeat(1 << 20)).unwrap();
+    let prefix = &data[..data.len() - (1 << 20)];
+
+    let mut reader = AsyncReader::new(Endless(prefix)).with_max_buffered(1024);
+    assert_eq!(reader.max_buffered(), 1024);
+
+    let result = block_on(musli::wire::DEFAULT.decode_async::<_, String>(&mut reader));
+    assert!(result.is_err());
+    assert!(reader.buffer().len() <= 1024);
+}
@@ -0,0 +1,86 @@
+use core::fmt;
+
+use musli::de::{Decode, Decoder, Visitor};
+use musli::value::{Number, Value};
+use musli::Context;
+
+/// A number captured either as a native integer or as its raw digits.
+#[derive(Debug, PartialEq)]
+enum BigInt {
+    Native(u128),
+    Digits(String),
+}
+
+impl<'de, M> Decode<'de, M> for BigInt {
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        struct BigIntVisitor;
+
+        #[musli::visitor]
+        impl<'de, C> Visitor<'de, C> for BigIntVisitor
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = BigInt;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "an integer")
+            }
+
+            fn visit_u8(self, _: &C, value: u8) -> Result<Self::Ok, C::Error> {
+                Ok(BigInt::Native(value.into()))
+            }
+
+            fn visit_u128(self, _: &C, value: u128) -> Result<Self::Ok, C::Error> {
+                Ok(BigInt::Native(value))
+            }
+
+            fn visit_big_number(self, _: &C, number: &str) -> Result<Self::Ok, C::Error> {
+                Ok(BigInt::Digits(number.to_owned()))
+            }
+        }
+
+        decoder.decode_number(BigIntVisitor)
+    }
+}
+
+#[test]
+fn big_numbers() {
+    let actual: BigInt = musli::json::from_str("42").unwrap();
+    assert_eq!(actual, BigInt::Native(42));
+
+    let max = u128::MAX.to_string();
+    let actual: BigInt = musli::json::from_str(&max).unwrap();
+    assert_eq!(actual, BigInt::Native(u128::MAX));
+
+    for number in [
+        "340282366920938463463374607431768211456",
+        "123456789012345678901234567890123456789012345678901234567890",
+        "-170141183460469231731687303715884105729",
+        "0.1000000000000000000000000000000000000000001",
+        "1234567890123456789012345678901234567890e-10",
+    ] {
+        let actual: BigInt = musli::json::from_str(&format!(" {number} ")).unwrap();
+        assert_eq!(actual, BigInt::Digits(number.to_owned()));
+    }
+
+    let actual: Vec<BigInt> =
+        musli::json::from_str("[1, 100000000000000000000000000000000000000000]").unwrap();
+    assert_eq!(
+        actual,
+        [
+            BigInt::Native(1),
+            BigInt::Digits(String::from("100000000000000000000000000000000000000000")),
+        ]
+    );
+}
+
+#[test]
+fn big_numbers_default_to_float() {
+    let number = "123456789012345678901234567890123456789012345678901234567890";
+    let value: Value = musli::json::from_str(number).unwrap();
+    let expected = number.parse::<f64>().unwrap();
+    assert_eq!(value, Value::Number(Number::F64(expected)));
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "test")]
+
+use std::borrow::Cow;
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Message<'a, 'de> {
+    #[musli(skip)]
+    context: Option<&'a str>,
+    #[musli(borrow)]
+    name: &'de str,
+    #[musli(borrow)]
+    body: Cow<'de, str>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Packet<'a, 'de> {
+    #[musli(skip)]
+    context: Option<&'a str>,
+    #[musli(borrow, bytes)]
+    data: &'de [u8],
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+enum Event<'a, 'de> {
+    Skipped {
+        #[musli(skip)]
+        context: Option<&'a str>,
+    },
+    Named {
+        #[musli(borrow)]
+        name: &'de str,
+    },
+}
+
+#[test]
+fn borrow_fields() {
+    let message = Message {
+        context: None,
+        name: "greeting",
+        body: Cow::Borrowed("Hello World"),
+    };
+
+    let bytes = musli::storage::to_vec(&message).unwrap();
+    let actual: Message<'_, '_> = musli::storage::from_slice(&bytes).unwrap();
+    assert_eq!(actual, message);
+    assert!(matches!(actual.body, Cow::Borrowed(..)));
+
+    let json = musli::json::to_string(&message).unwrap();
+    let actual: Message<'_, '_> = musli::json::from_str(&json).unwrap();
+    assert_eq!(actual.name, "greeting");
+    assert!(matches!(actual.body, Cow::Borrowed("Hello World")));
+
+    let packet = Packet {
+        context: None,
+        data: &[1, 2, 3],
+    };
+
+    let bytes = musli::storage::to_vec(&packet).unwrap();
+    let actual: Packet<'_, '_> = musli::storage::from_slice(&bytes).unwrap();
+    assert_eq!(actual, packet);
+}
+
+#[test]
+fn borrow_variant_fields() {
+    let event = Event::Named { name: "start" };
+    let bytes = musli::wire::to_vec(&event).unwrap();
+    let actual: Event<'_, '_> = musli::wire::from_slice(&bytes).unwrap();
+    assert_eq!(actual, event);
+}
@@ -0,0 +1,71 @@
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Message {
+    id: u32,
+    body: String,
+}
+
+macro_rules! test_reuse {
+    ($name:ident, $what:ident) => {
+        #[test]
+        fn $name() {
+            let mut buf = Vec::new();
+
+            let long = Message {
+                id: 1,
+                body: "x".repeat(256),
+            };
+
+            musli::$what::to_vec_in(&mut buf, &long).unwrap();
+            assert_eq!(buf, musli::$what::to_vec(&long).unwrap());
+
+            let capacity = buf.capacity();
+            let ptr = buf.as_ptr();
+
+            let short = Message {
+                id: 2,
+                body: String::from("hello"),
+            };
+
+            musli::$what::to_vec_in(&mut buf, &short).unwrap();
+            assert_eq!(buf, musli::$what::to_vec(&short).unwrap());
+            assert_eq!(buf.capacity(), capacity);
+            assert_eq!(buf.as_ptr(), ptr);
+
+            let actual: Message = musli::$what::from_slice(&buf).unwrap();
+            assert_eq!(actual, short);
+        }
+    };
+}
+
+test_reuse!(storage, storage);
+test_reuse!(wire, wire);
+test_reuse!(descriptive, descriptive);
+test_reuse!(json, json);
+
+#[test]
+fn wire_configured() {
+    use musli::wire::{Checksum, Encoding, Header};
+
+    const ENCODING: Encoding = Encoding::new()
+        .with_header(Header::new(*b"TEST", 1))
+        .with_checksum(Checksum::Crc32);
+
+    let mut buf = Vec::with_capacity(1024);
+    let ptr = buf.as_ptr();
+
+    for id in 0..4 {
+        let message = Message {
+            id,
+            body: "x".repeat(id as usize * 16),
+        };
+
+        ENCODING.to_vec_in(&mut buf, &message).unwrap();
+        assert_eq!(buf, ENCODING.to_vec(&message).unwrap());
+        assert_eq!(buf.as_ptr(), ptr);
+
+        let actual: Message = ENCODING.from_slice(&buf).unwrap();
+        assert_eq!(actual, message);
+    }
+}
@@ -47,3 +47,38 @@ fn bytes_compat() {
         }
     );
 }
+
+#[derive(Debug, PartialEq, Decode, Encode)]
+struct Plain {
+    vec: Vec<u8>,
+    slice: [u8; 4],
+}
+
+#[test]
+fn plain_byte_vectors() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Plain {
+            vec: vec![0, 1, 2, 3],
+            slice: [4, 5, 6, 7],
+        },
+        json = r#"{"vec":[0,1,2,3],"slice":[4,5,6,7]}"#
+    );
+
+    // Sequences of bytes are written as a single blob in storage, which is
+    // identical to encoding them as bytes.
+    musli::macros::assert_encoding_eq!(storage, vec![1u8, 2, 3], [3, 1, 2, 3]);
+    musli::macros::assert_encoding_eq!(storage, Bytes(vec![1u8, 2, 3]), [3, 1, 2, 3]);
+
+    // But wire keeps encoding each element, since the two are distinct.
+    musli::macros::assert_encoding_eq!(
+        wire,
+        vec![1u8, 2, 3],
+        [
+            0b10_000000 | 3,
+            0b11_000000 | 1,
+            0b11_000000 | 2,
+            0b11_000000 | 3
+        ]
+    );
+}
@@ -0,0 +1,50 @@
+use musli::context::{Compact, ErrorCode};
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_type = usize)]
+enum Animal {
+    Cat,
+    Dog,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_type = usize)]
+enum MoreAnimals {
+    Cat,
+    Dog,
+    Bird,
+}
+
+#[test]
+fn unexpected_end() {
+    let cx = Compact::new();
+
+    let error = musli::storage::Encoding::new()
+        .from_slice_with::<_, (u32, u32)>(&cx, &[1])
+        .unwrap_err();
+
+    assert_eq!(error.code(), ErrorCode::UnexpectedEnd);
+    assert_eq!(error.offset(), 1);
+
+    let bytes = musli::wire::to_vec(&1u32).unwrap();
+
+    let error = musli::wire::Encoding::new()
+        .from_slice_with::<_, u32>(&cx, &bytes[..bytes.len() - 1])
+        .unwrap_err();
+
+    assert_eq!(error.code(), ErrorCode::UnexpectedEnd);
+}
+
+#[test]
+fn invalid_variant_tag() {
+    let cx = Compact::new();
+
+    let bytes = musli::storage::to_vec(&MoreAnimals::Bird).unwrap();
+
+    let error = musli::storage::Encoding::new()
+        .from_slice_with::<_, Animal>(&cx, &bytes)
+        .unwrap_err();
+
+    assert_eq!(error.code(), ErrorCode::InvalidVariantTag);
+}
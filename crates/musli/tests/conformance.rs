@@ -0,0 +1,118 @@
+//! Conformance tests which check that encoded bytes stay the same across
+//! releases.
+//!
+//! Fixtures are committed in `tests/conformance`, and a missing fixture is a
+//! failure. Run with `MUSLI_UPDATE_SNAPSHOTS=1` to create or update them. See
+//! [`musli::macros::assert_conformance!`] for details.
+
+#![cfg(feature = "test")]
+
+use std::collections::BTreeMap;
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Primitives {
+    boolean: bool,
+    character: char,
+    unsigned8: u8,
+    unsigned16: u16,
+    unsigned32: u32,
+    unsigned64: u64,
+    unsigned128: u128,
+    signed8: i8,
+    signed16: i16,
+    signed32: i32,
+    signed64: i64,
+    signed128: i128,
+    float32: f32,
+    float64: f64,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+enum Shape {
+    Empty,
+    Circle(f64),
+    Rectangle { width: u32, height: u32 },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Document {
+    name: String,
+    #[musli(bytes)]
+    payload: Vec<u8>,
+    tags: Vec<String>,
+    points: Vec<Point>,
+    shapes: Vec<Shape>,
+    attributes: BTreeMap<String, u64>,
+    parent: Option<Box<Document>>,
+}
+
+fn primitives() -> Primitives {
+    Primitives {
+        boolean: true,
+        character: 'ü',
+        unsigned8: u8::MAX,
+        unsigned16: 0x1234,
+        unsigned32: 0xdeadbeef,
+        unsigned64: u64::MAX - 1,
+        unsigned128: u128::MAX / 3,
+        signed8: i8::MIN,
+        signed16: -1234,
+        signed32: i32::MAX,
+        signed64: i64::MIN + 1,
+        signed128: -(1 << 100),
+        float32: 1.5,
+        float64: -1024.125,
+    }
+}
+
+fn document() -> Document {
+    let parent = Document {
+        name: String::from("parent"),
+        payload: Vec::new(),
+        tags: Vec::new(),
+        points: Vec::new(),
+        shapes: vec![Shape::Empty],
+        attributes: BTreeMap::new(),
+        parent: None,
+    };
+
+    Document {
+        name: String::from("Aristotle"),
+        payload: vec![0, 1, 2, 3, 0xff],
+        tags: vec![String::from("philosopher"), String::from("greek")],
+        points: vec![Point { x: 1, y: -1 }, Point { x: 300, y: 70000 }],
+        shapes: vec![
+            Shape::Empty,
+            Shape::Circle(2.5),
+            Shape::Rectangle {
+                width: 10,
+                height: 20,
+            },
+        ],
+        attributes: BTreeMap::from([(String::from("age"), 61), (String::from("works"), 200)]),
+        parent: Some(Box::new(parent)),
+    }
+}
+
+macro_rules! conformance {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                musli::macros::assert_conformance!($name, "primitives", primitives());
+                musli::macros::assert_conformance!($name, "document", document());
+            }
+        )*
+    };
+}
+
+conformance!(storage, wire, descriptive, json);
@@ -0,0 +1,53 @@
+use std::borrow::Cow;
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Message<'a> {
+    name: Cow<'a, str>,
+    #[musli(bytes)]
+    payload: Cow<'a, [u8]>,
+}
+
+fn message() -> Message<'static> {
+    Message {
+        name: Cow::Borrowed("hello"),
+        payload: Cow::Borrowed(&[1, 2, 3, 4]),
+    }
+}
+
+#[track_caller]
+fn assert_borrowed(actual: &Message<'_>) {
+    assert_eq!(*actual, message());
+    assert!(matches!(actual.name, Cow::Borrowed(..)));
+    assert!(matches!(actual.payload, Cow::Borrowed(..)));
+}
+
+#[test]
+fn binary_borrows() {
+    let data = musli::wire::to_vec(&message()).unwrap();
+    assert_borrowed(&musli::wire::from_slice(&data).unwrap());
+
+    let data = musli::storage::to_vec(&message()).unwrap();
+    assert_borrowed(&musli::storage::from_slice(&data).unwrap());
+
+    let data = musli::descriptive::to_vec(&message()).unwrap();
+    assert_borrowed(&musli::descriptive::from_slice(&data).unwrap());
+}
+
+#[test]
+fn json_borrows_when_possible() {
+    let actual: Cow<'_, str> = musli::json::from_str("\"hello\"").unwrap();
+    assert!(matches!(actual, Cow::Borrowed("hello")));
+
+    let actual: Cow<'_, str> = musli::json::from_str("\"hello\\nworld\"").unwrap();
+    assert!(matches!(actual, Cow::Owned(..)));
+    assert_eq!(actual, "hello\nworld");
+
+    let expected = message();
+    let data = musli::json::to_vec(&expected).unwrap();
+    let actual: Message<'_> = musli::json::from_slice(&data).unwrap();
+    assert_eq!(actual, expected);
+    assert!(matches!(actual.name, Cow::Borrowed(..)));
+    assert!(matches!(actual.payload, Cow::Owned(..)));
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "test")]
+
+mod facade {
+    pub mod nested {
+        pub use musli as renamed;
+    }
+}
+
+use facade::nested::renamed::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(crate = facade::nested::renamed, name_all = "name")]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(crate = facade::nested::renamed, name_all = "name")]
+enum Animal {
+    #[musli(name_all = "name")]
+    Cat {
+        lives: u32,
+    },
+    Dog,
+}
+
+#[test]
+fn renamed_crate() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Person {
+            name: String::from("Aristotle"),
+            age: 62,
+        },
+        json = r#"{"name":"Aristotle","age":62}"#
+    );
+
+    musli::macros::assert_roundtrip_eq!(full, Animal::Cat { lives: 9 });
+    musli::macros::assert_roundtrip_eq!(full, Animal::Dog);
+}
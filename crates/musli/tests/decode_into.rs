@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, Default, PartialEq, Encode, Decode)]
+struct Message {
+    id: u32,
+    body: String,
+    tags: Vec<String>,
+    #[musli(default)]
+    reply_to: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Encode)]
+struct Partial {
+    id: u32,
+    body: String,
+}
+
+#[test]
+fn reuses_allocations() {
+    let long = vec!["a".repeat(64), "b".repeat(64)];
+    let short = vec![String::from("c")];
+
+    let mut value = Vec::<String>::new();
+    let data = musli::storage::to_vec(&long).unwrap();
+    musli::storage::decode_into(&mut value, &data[..]).unwrap();
+    assert_eq!(value, long);
+
+    let ptr = value.as_ptr();
+    let first = value[0].as_ptr();
+
+    let data = musli::json::to_vec(&short).unwrap();
+    musli::json::decode_into(&mut value, &data[..]).unwrap();
+    assert_eq!(value, short);
+    assert_eq!(value.as_ptr(), ptr);
+    assert_eq!(value[0].as_ptr(), first);
+    assert!(value[0].capacity() >= 64);
+}
+
+#[test]
+fn pod() {
+    let mut value = Vec::<u32>::new();
+    let data = musli::wire::to_vec(&vec![1u32; 128]).unwrap();
+    musli::wire::decode_into(&mut value, &data[..]).unwrap();
+    let ptr = value.as_ptr();
+
+    let data = musli::descriptive::to_vec(&vec![2u32; 4]).unwrap();
+    musli::descriptive::decode_into(&mut value, &data[..]).unwrap();
+    assert_eq!(value, [2u32; 4]);
+    assert_eq!(value.as_ptr(), ptr);
+}
+
+#[test]
+fn option_and_map() {
+    let mut value = Some(String::with_capacity(64));
+    let data = musli::storage::to_vec(&Some(String::from("hello"))).unwrap();
+    musli::storage::decode_into(&mut value, &data[..]).unwrap();
+    assert_eq!(value.as_deref(), Some("hello"));
+    assert!(value.as_ref().unwrap().capacity() >= 64);
+
+    let data = musli::json::to_vec(&None::<String>).unwrap();
+    musli::json::decode_into(&mut value, &data[..]).unwrap();
+    assert_eq!(value, None);
+
+    let mut map = HashMap::new();
+    map.insert(String::from("stale"), 1u32);
+
+    let fresh = HashMap::from([(String::from("fresh"), 2u32)]);
+    let data = musli::wire::to_vec(&fresh).unwrap();
+    musli::wire::decode_into(&mut map, &data[..]).unwrap();
+    assert_eq!(map, fresh);
+}
+
+#[test]
+fn derived_struct() {
+    let first = Message {
+        id: 1,
+        body: "x".repeat(256),
+        tags: vec!["y".repeat(32)],
+        reply_to: Some(4),
+    };
+
+    let second = Message {
+        id: 2,
+        body: String::from("hello"),
+        tags: vec![String::from("a"), String::from("b")],
+        reply_to: None,
+    };
+
+    let mut value = Message::default();
+    let data = musli::descriptive::to_vec(&first).unwrap();
+    musli::descriptive::decode_into(&mut value, &data[..]).unwrap();
+    assert_eq!(value, first);
+
+    let body = value.body.as_ptr();
+    let tag = value.tags[0].as_ptr();
+
+    let data = musli::json::to_vec(&second).unwrap();
+    musli::json::decode_into(&mut value, &data[..]).unwrap();
+    assert_eq!(value, second);
+    assert_eq!(value.body.as_ptr(), body);
+    assert_eq!(value.tags[0].as_ptr(), tag);
+}
+
+#[test]
+fn derived_missing_field() {
+    let partial = Partial {
+        id: 1,
+        body: String::from("hello"),
+    };
+
+    let mut value = Message::default();
+
+    let data = musli::storage::to_vec(&partial).unwrap();
+    assert!(musli::storage::decode_into(&mut value, &data[..]).is_err());
+
+    let data = musli::wire::to_vec(&partial).unwrap();
+    assert!(musli::wire::decode_into(&mut value, &data[..]).is_err());
+
+    let data = musli::json::to_vec(&partial).unwrap();
+    assert!(musli::json::decode_into(&mut value, &data[..]).is_err());
+}
@@ -58,6 +58,20 @@ struct StructSkipValue<'a> {
     country: &'a str,
 }
 
+#[derive(Debug, PartialEq, Encode, Decode)]
+enum Animal<'a> {
+    Cat { name: &'a str },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+enum AnimalDefaultValue<'a> {
+    Cat {
+        name: &'a str,
+        #[musli(default = default_age)]
+        age: u32,
+    },
+}
+
 fn is_zero(value: &u32) -> bool {
     *value == 0
 }
@@ -194,3 +208,30 @@ fn decode_with_default() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+// Fields added to variants can be given a default value as well.
+#[test]
+fn decode_variant_with_default() {
+    static NAME: &str = "Aristotle";
+
+    musli::macros::assert_decode_eq!(
+        full,
+        Animal::Cat { name: NAME },
+        AnimalDefaultValue::Cat {
+            name: NAME,
+            age: 180
+        },
+    );
+
+    musli::macros::assert_decode_eq!(
+        full,
+        AnimalDefaultValue::Cat {
+            name: NAME,
+            age: 170
+        },
+        AnimalDefaultValue::Cat {
+            name: NAME,
+            age: 170
+        },
+    );
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Version2 {
+    name: String,
+    age: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Lenient {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", deny_unknown_fields)]
+struct Strict {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Version2Indexed {
+    name: String,
+    age: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct LenientIndexed {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(deny_unknown_fields)]
+struct StrictIndexed {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", deny_unknown_fields)]
+enum StrictEnum {
+    #[musli(name_all = "name")]
+    Person { name: String },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+enum Version2Enum {
+    #[musli(name_all = "name")]
+    Person { name: String, age: u32 },
+}
+
+#[test]
+fn deny_unknown_fields() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Strict {
+            name: String::from("Aristotle"),
+        }
+    );
+
+    let json = musli::json::to_string(&Version2 {
+        name: String::from("Aristotle"),
+        age: 62,
+    })
+    .unwrap();
+
+    let actual: Lenient = musli::json::from_str(&json).unwrap();
+    assert_eq!(actual.name, "Aristotle");
+
+    let error = musli::json::from_str::<Strict>(&json).unwrap_err();
+    assert!(error.to_string().contains("Invalid field tag"));
+
+    let bytes = musli::wire::to_vec(&Version2Indexed {
+        name: String::from("Aristotle"),
+        age: 62,
+    })
+    .unwrap();
+
+    let actual: LenientIndexed = musli::wire::from_slice(&bytes).unwrap();
+    assert_eq!(actual.name, "Aristotle");
+    assert!(musli::wire::from_slice::<StrictIndexed>(&bytes).is_err());
+}
+
+#[test]
+fn deny_unknown_variant_fields() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        StrictEnum::Person {
+            name: String::from("Aristotle"),
+        }
+    );
+
+    let json = musli::json::to_string(&Version2Enum::Person {
+        name: String::from("Aristotle"),
+        age: 62,
+    })
+    .unwrap();
+
+    let error = musli::json::from_str::<StrictEnum>(&json).unwrap_err();
+    assert!(error.to_string().contains("Invalid variant field tag"));
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use musli::context::{self, Compact, ErrorCode};
+use musli::mode::Binary;
+use musli::value::Value;
+use musli::{Context, Decode, Encode};
+
+fn nested_json(depth: usize) -> String {
+    let mut string = "[".repeat(depth);
+    string.push_str(&"]".repeat(depth));
+    string
+}
+
+fn nested_value(depth: usize) -> Value {
+    let mut value = Value::Sequence(Vec::new());
+
+    for _ in 1..depth {
+        value = Value::Sequence(vec![value]);
+    }
+
+    value
+}
+
+#[test]
+fn json_hostile_input() {
+    let error = musli::json::from_str::<Value>(&nested_json(100_000)).unwrap_err();
+    assert!(error.to_string().contains("maximum of 128"), "{error}");
+
+    let value = musli::json::from_str::<Value>(&nested_json(64)).unwrap();
+    assert_eq!(value, nested_value(64));
+}
+
+#[test]
+fn descriptive_hostile_input() {
+    let bytes = musli::descriptive::to_vec(&nested_value(200)).unwrap();
+    assert!(musli::descriptive::from_slice::<Value>(&bytes).is_err());
+
+    let bytes = musli::descriptive::to_vec(&nested_value(64)).unwrap();
+    let value = musli::descriptive::from_slice::<Value>(&bytes).unwrap();
+    assert_eq!(value, nested_value(64));
+}
+
+#[test]
+fn configured_max_depth() {
+    let encoding = musli::json::Encoding::new();
+
+    let mut cx = context::new();
+    cx.max_depth(8);
+
+    let value = encoding
+        .from_str_with::<_, Value>(&cx, &nested_json(4))
+        .unwrap();
+    assert_eq!(value, nested_value(4));
+
+    cx.clear();
+    assert!(encoding
+        .from_str_with::<_, Value>(&cx, &nested_json(16))
+        .is_err());
+    let error = cx.errors().next().unwrap();
+    assert!(error.to_string().contains("maximum of 8"), "{error}");
+
+    cx.clear();
+    cx.max_depth(32);
+    assert!(encoding
+        .from_str_with::<_, Value>(&cx, &nested_json(16))
+        .is_ok());
+}
+
+#[test]
+fn compact() {
+    let mut cx = Compact::new();
+    cx.max_depth(4);
+
+    let error = musli::json::Encoding::new()
+        .from_str_with::<_, Value>(&cx, &nested_json(16))
+        .unwrap_err();
+    assert_eq!(error.code(), ErrorCode::DepthExceeded);
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Config {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct ConfigWithExtra {
+    name: String,
+    extra: HashMap<String, Value>,
+}
+
+#[test]
+fn skipped_values() {
+    let json = format!(r#"{{"name": "server", "extra": {}}}"#, nested_json(100_000));
+    assert!(musli::json::from_str::<Config>(&json).is_err());
+
+    let json = format!(r#"{{"name": "server", "extra": {}}}"#, nested_json(16));
+    let config = musli::json::from_str::<Config>(&json).unwrap();
+    assert_eq!(config.name, "server");
+
+    let config = ConfigWithExtra {
+        name: String::from("server"),
+        extra: HashMap::from([(String::from("nested"), nested_value(200))]),
+    };
+
+    let bytes = musli::descriptive::to_vec(&config).unwrap();
+    assert!(musli::descriptive::from_slice::<Config>(&bytes).is_ok());
+    assert!(musli::descriptive::from_slice::<ConfigWithExtra>(&bytes).is_err());
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Node {
+    value: u32,
+    next: Option<Box<Node>>,
+}
+
+fn list(len: u32) -> Node {
+    let mut node = Node {
+        value: 0,
+        next: None,
+    };
+
+    for value in 1..len {
+        node = Node {
+            value,
+            next: Some(Box::new(node)),
+        };
+    }
+
+    node
+}
+
+#[test]
+fn only_containers_count() {
+    let node = list(120);
+
+    let bytes = musli::storage::to_vec(&node).unwrap();
+    assert_eq!(musli::storage::from_slice::<Node>(&bytes).unwrap(), node);
+
+    let bytes = musli::wire::to_vec(&node).unwrap();
+    assert_eq!(musli::wire::from_slice::<Node>(&bytes).unwrap(), node);
+
+    let bytes = musli::descriptive::to_vec(&node).unwrap();
+    assert_eq!(
+        musli::descriptive::from_slice::<Node>(&bytes).unwrap(),
+        node
+    );
+
+    let json = musli::json::to_string(&node).unwrap();
+    assert_eq!(musli::json::from_str::<Node>(&json).unwrap(), node);
+
+    let bytes = musli::storage::to_vec(&list(200)).unwrap();
+    assert!(musli::storage::from_slice::<Node>(&bytes).is_err());
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", untagged)]
+enum Shape {
+    #[musli(transparent)]
+    Leaf(u32),
+    #[musli(transparent)]
+    Branch(Vec<Value>),
+}
+
+#[test]
+fn untagged() {
+    let encoding = musli::json::Encoding::new();
+
+    let mut cx = context::new();
+    cx.max_depth(8);
+
+    let shape = encoding.from_str_with::<_, Shape>(&cx, "[[[1]]]").unwrap();
+    let inner = musli::json::from_str::<Value>("[[1]]").unwrap();
+    assert_eq!(shape, Shape::Branch(vec![inner]));
+
+    cx.clear();
+    let json = format!("{}1{}", "[".repeat(16), "]".repeat(16));
+    assert!(encoding.from_str_with::<_, Shape>(&cx, &json).is_err());
+
+    // Values are buffered without counting their depth, so the limit is only
+    // reached while trying the variants. It's reported as is instead of as no
+    // variant matching.
+    let value = musli::json::from_str::<Value>(&json).unwrap();
+
+    let mut cx = Compact::<Binary, _>::new();
+    cx.max_depth(8);
+
+    let error = musli::value::decode_with::<_, Shape>(&cx, &value).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::DepthExceeded);
+}
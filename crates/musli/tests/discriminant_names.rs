@@ -0,0 +1,61 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[repr(u8)]
+#[musli(name_all = "discriminant")]
+enum Opcode {
+    Nop = 0x10,
+    Load,
+    Store = 0x20,
+    #[musli(name = 0xff)]
+    Halt,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[repr(u16)]
+#[musli(name_all = "discriminant")]
+enum Frame {
+    Ping = 1,
+    #[musli(packed)]
+    Data(u32) = 5,
+    Close {
+        code: u16,
+    },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "discriminant")]
+enum Signed {
+    Low = -1,
+    Zero,
+    High = 10,
+}
+
+#[test]
+fn discriminant_names() {
+    musli::macros::assert_roundtrip_eq!(full, Opcode::Nop, json = "16");
+    musli::macros::assert_roundtrip_eq!(full, Opcode::Load, json = "17");
+    musli::macros::assert_roundtrip_eq!(full, Opcode::Store, json = "32");
+    musli::macros::assert_roundtrip_eq!(full, Opcode::Halt, json = "255");
+}
+
+#[test]
+fn discriminant_names_with_data() {
+    musli::macros::assert_roundtrip_eq!(full, Frame::Ping, json = r#"{"1":{}}"#);
+    musli::macros::assert_roundtrip_eq!(full, Frame::Data(42), json = r#"{"5":[42]}"#);
+
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Frame::Close { code: 1000 },
+        json = r#"{"6":{"code":1000}}"#
+    );
+}
+
+#[test]
+fn signed_discriminant_names() {
+    musli::macros::assert_roundtrip_eq!(full, Signed::Low, json = "-1");
+    musli::macros::assert_roundtrip_eq!(full, Signed::Zero, json = "0");
+    musli::macros::assert_roundtrip_eq!(full, Signed::High, json = "10");
+}
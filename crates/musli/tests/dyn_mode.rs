@@ -0,0 +1,105 @@
+use musli::{descriptive, storage, wire};
+use musli::{Decode, DynMode, Encode};
+
+enum Compact {}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(mode = Compact, packed)]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+fn person() -> Person {
+    Person {
+        name: String::from("Aristotle"),
+        age: 61,
+    }
+}
+
+fn storage(
+    second: bool,
+) -> DynMode<storage::Encoding<{ storage::OPTIONS }, Compact>, storage::Encoding> {
+    if second {
+        DynMode::Second(storage::Encoding::new())
+    } else {
+        DynMode::First(storage::Encoding::new().with_mode())
+    }
+}
+
+fn wire(second: bool) -> DynMode<wire::Encoding<{ wire::OPTIONS }, Compact>, wire::Encoding> {
+    if second {
+        DynMode::Second(wire::Encoding::new())
+    } else {
+        DynMode::First(wire::Encoding::new().with_mode())
+    }
+}
+
+fn descriptive(
+    second: bool,
+) -> DynMode<descriptive::Encoding<{ descriptive::OPTIONS }, Compact>, descriptive::Encoding> {
+    if second {
+        DynMode::Second(descriptive::Encoding::new())
+    } else {
+        DynMode::First(descriptive::Encoding::new().with_mode())
+    }
+}
+
+#[test]
+fn binary() {
+    let compact = storage(false).to_vec(&person()).unwrap();
+    let full = storage(true).to_vec(&person()).unwrap();
+    assert_eq!(
+        compact,
+        storage::Encoding::new()
+            .with_mode::<Compact>()
+            .to_vec(&person())
+            .unwrap()
+    );
+    assert_eq!(full, storage::to_vec(&person()).unwrap());
+    assert_ne!(compact, full);
+
+    for second in [false, true] {
+        let data = storage(second).to_vec(&person()).unwrap();
+        assert_eq!(
+            storage(second).from_slice::<Person>(&data).unwrap(),
+            person()
+        );
+
+        let data = wire(second).to_vec(&person()).unwrap();
+        assert_eq!(wire(second).from_slice::<Person>(&data).unwrap(), person());
+
+        let data = descriptive(second).to_vec(&person()).unwrap();
+        assert_eq!(
+            descriptive(second).from_slice::<Person>(&data).unwrap(),
+            person()
+        );
+    }
+}
+
+#[test]
+fn json() {
+    use musli::json::Encoding;
+
+    fn encoding(verbose: bool) -> DynMode<Encoding<Compact>, Encoding> {
+        if verbose {
+            DynMode::Second(Encoding::new())
+        } else {
+            DynMode::First(Encoding::new().with_mode())
+        }
+    }
+
+    let data = encoding(false).to_vec(&person()).unwrap();
+    assert_eq!(data, br#"["Aristotle",61]"#);
+    assert_eq!(
+        encoding(false).from_slice::<Person>(&data).unwrap(),
+        person()
+    );
+
+    let data = encoding(true).to_vec(&person()).unwrap();
+    assert_eq!(data, br#"{"name":"Aristotle","age":61}"#);
+    assert_eq!(
+        encoding(true).from_slice::<Person>(&data).unwrap(),
+        person()
+    );
+}
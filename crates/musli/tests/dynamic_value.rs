@@ -0,0 +1,78 @@
+use std::mem::size_of;
+
+use musli::value::{Number, SmallBytes, SmallString, Value};
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Person {
+    name: String,
+    age: u32,
+    tags: Vec<String>,
+}
+
+#[test]
+fn accessors() {
+    let person = Person {
+        name: String::from("Aristotle"),
+        age: 61,
+        tags: vec![String::from("philosopher")],
+    };
+
+    let bytes = musli::descriptive::to_vec(&person).unwrap();
+    let value: Value = musli::descriptive::from_slice(&bytes).unwrap();
+
+    assert_eq!(value.get("name").and_then(Value::as_str), Some("Aristotle"));
+    assert_eq!(
+        value
+            .get("age")
+            .and_then(Value::as_number)
+            .and_then(Number::as_u64),
+        Some(61)
+    );
+
+    let tags = value.get("tags").and_then(Value::as_sequence).unwrap();
+    assert_eq!(tags, [Value::from("philosopher")]);
+    assert!(value.get("missing").is_none());
+
+    let bytes = musli::descriptive::to_vec(&value).unwrap();
+    let actual: Person = musli::descriptive::from_slice(&bytes).unwrap();
+    assert_eq!(actual, person);
+}
+
+#[test]
+fn numbers() {
+    assert_eq!(Number::from(u64::MAX).as_u64(), Some(u64::MAX));
+    assert_eq!(Number::from(u64::MAX).as_i64(), None);
+    assert_eq!(Number::from(-5i16).as_i64(), Some(-5));
+    assert_eq!(Number::from(-5i16).as_u64(), None);
+    assert_eq!(Number::from(2.5f64).as_u64(), None);
+    assert_eq!(Number::from(2.5f64).as_f64(), 2.5);
+    assert_eq!(Value::from(1u8), Value::Number(Number::U8(1)));
+}
+
+#[test]
+fn nested_options() {
+    let value = musli::value::encode(Some(Some(1u32))).unwrap();
+
+    let expected = Value::Option(Some(Box::new(Value::Option(Some(Box::new(
+        Value::Number(Number::U32(1)),
+    ))))));
+
+    assert_eq!(value, expected);
+}
+
+#[test]
+fn empty_values() {
+    let value = musli::value::encode(vec![(), ()]).unwrap();
+    assert_eq!(value, Value::Sequence(vec![Value::Unit, Value::Unit]));
+}
+
+/// Small strings and bytes are stored inline without making values any larger
+/// than they would be when holding a `String` or a `Vec<u8>`.
+#[test]
+fn value_size() {
+    assert_eq!(size_of::<SmallString>(), size_of::<String>());
+    assert_eq!(size_of::<SmallBytes>(), size_of::<Vec<u8>>());
+    assert_eq!(size_of::<Value>(), size_of::<String>() + size_of::<usize>());
+}
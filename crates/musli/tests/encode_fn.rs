@@ -0,0 +1,64 @@
+#![cfg(feature = "test")]
+
+use musli::en::{EntriesEncoder, MapEncoder};
+use musli::hint::MapHint;
+use musli::{Decode, Encode, Encoder};
+
+#[derive(Debug, PartialEq, Decode)]
+#[musli(name_all = "name")]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+/// Encodes like [`Person`] through [`Encoder::encode_map_fn`].
+#[derive(Debug, PartialEq)]
+struct MapFn;
+
+impl<M> Encode<M> for MapFn {
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_map_fn(&MapHint::with_size(2), |map| {
+            map.insert_entry("name", "Aristotle")?;
+            map.insert_entry("age", 61u32)?;
+            Ok(())
+        })
+    }
+}
+
+/// Encodes like [`Person`] through [`Encoder::encode_map_entries_fn`].
+#[derive(Debug, PartialEq)]
+struct EntriesFn;
+
+impl<M> Encode<M> for EntriesFn {
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_map_entries_fn(&MapHint::with_size(2), |entries| {
+            entries.insert_entry("name", "Aristotle")?;
+            entries.encode_entry_key()?.encode("age")?;
+            entries.encode_entry_value()?.encode(61u32)?;
+            Ok(())
+        })
+    }
+}
+
+fn person() -> Person {
+    Person {
+        name: String::from("Aristotle"),
+        age: 61,
+    }
+}
+
+#[test]
+fn map_fn() {
+    musli::macros::assert_decode_eq!(full, MapFn, person());
+}
+
+#[test]
+fn map_entries_fn() {
+    musli::macros::assert_decode_eq!(full, EntriesFn, person());
+}
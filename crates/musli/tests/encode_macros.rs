@@ -0,0 +1,132 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode, Encoder};
+
+#[derive(Debug, PartialEq, Decode)]
+#[musli(name_all = "name")]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+struct Manual {
+    first: &'static str,
+    last: &'static str,
+    age: u32,
+}
+
+impl<M> Encode<M> for Manual {
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let name = format!("{} {}", self.first, self.last);
+
+        musli::macros::encode_struct!(encoder, {
+            "name" => &name,
+            "age" => self.age,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Decode)]
+#[musli(name_all = "name")]
+enum Shape {
+    #[musli(name_all = "name")]
+    Circle { radius: u32 },
+    #[musli(name_all = "name")]
+    Rectangle { width: u32, height: u32 },
+}
+
+enum ManualShape {
+    Circle(u32),
+    Square(u32),
+}
+
+impl<M> Encode<M> for ManualShape {
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        match self {
+            ManualShape::Circle(radius) => musli::macros::encode_variant!(encoder, "Circle", {
+                "radius" => radius,
+            }),
+            ManualShape::Square(side) => musli::macros::encode_variant!(encoder, "Rectangle", {
+                "width" => side,
+                "height" => side,
+            }),
+        }
+    }
+}
+
+struct Span {
+    start: u32,
+    end: u32,
+}
+
+#[derive(Debug, PartialEq, Decode)]
+#[musli(name_all = "name")]
+struct DecodedSpan {
+    start: u32,
+    len: u32,
+}
+
+musli::macros::impl_struct_encode!(Span => |this| {
+    "start" => this.start,
+    "len" => this.end - this.start,
+});
+
+#[test]
+fn encode_struct() {
+    let manual = Manual {
+        first: "Aristotle",
+        last: "of Stagira",
+        age: 61,
+    };
+
+    let expected = Person {
+        name: String::from("Aristotle of Stagira"),
+        age: 61,
+    };
+
+    let json = musli::json::to_string(&manual).unwrap();
+    assert_eq!(json, r#"{"name":"Aristotle of Stagira","age":61}"#);
+    assert_eq!(musli::json::from_str::<Person>(&json).unwrap(), expected);
+
+    let bytes = musli::descriptive::to_vec(&manual).unwrap();
+    let actual: Person = musli::descriptive::from_slice(&bytes).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn encode_variant() {
+    let json = musli::json::to_string(&ManualShape::Circle(2)).unwrap();
+    assert_eq!(json, r#"{"Circle":{"radius":2}}"#);
+    assert_eq!(
+        musli::json::from_str::<Shape>(&json).unwrap(),
+        Shape::Circle { radius: 2 }
+    );
+
+    let bytes = musli::descriptive::to_vec(&ManualShape::Square(3)).unwrap();
+    let actual: Shape = musli::descriptive::from_slice(&bytes).unwrap();
+    assert_eq!(
+        actual,
+        Shape::Rectangle {
+            width: 3,
+            height: 3
+        }
+    );
+}
+
+#[test]
+fn impl_struct_encode() {
+    let span = Span { start: 4, end: 10 };
+
+    let json = musli::json::to_string(&span).unwrap();
+    assert_eq!(json, r#"{"start":4,"len":6}"#);
+
+    let bytes = musli::descriptive::to_vec(&span).unwrap();
+    let actual: DecodedSpan = musli::descriptive::from_slice(&bytes).unwrap();
+    assert_eq!(actual, DecodedSpan { start: 4, len: 6 });
+}
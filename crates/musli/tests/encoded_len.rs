@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use musli::options::{self, Integer, Options};
+use musli::{Decode, Encode};
+
+const FIXED: Options = options::new()
+    .with_length(Integer::Fixed)
+    .with_integer(Integer::Fixed)
+    .build();
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Inner {
+    values: Vec<u64>,
+    bytes: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed)]
+struct Packed {
+    a: u32,
+    b: u64,
+    c: Option<i8>,
+    d: [u64; 8],
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Record {
+    name: String,
+    inner: Inner,
+    packed: Packed,
+    map: HashMap<u32, String>,
+    float: f64,
+}
+
+fn record() -> Record {
+    Record {
+        name: String::from("Aristotle"),
+        inner: Inner {
+            values: vec![0, 1, 127, 128, u64::MAX],
+            bytes: vec![0xde, 0xad, 0xbe, 0xef],
+        },
+        packed: Packed {
+            a: 42,
+            b: u64::MAX / 3,
+            c: Some(-1),
+            d: [u64::MAX; 8],
+        },
+        map: [(1, String::from("one")), (1024, String::from("many"))]
+            .into_iter()
+            .collect(),
+        float: 3.5,
+    }
+}
+
+#[test]
+fn matches_to_vec() {
+    let value = record();
+
+    let len = musli::wire::encoded_len(&value).unwrap();
+    assert_eq!(len, musli::wire::to_vec(&value).unwrap().len());
+
+    let len = musli::storage::encoded_len(&value).unwrap();
+    assert_eq!(len, musli::storage::to_vec(&value).unwrap().len());
+
+    let len = musli::descriptive::encoded_len(&value).unwrap();
+    assert_eq!(len, musli::descriptive::to_vec(&value).unwrap().len());
+
+    let len = musli::json::encoded_len(&value).unwrap();
+    assert_eq!(len, musli::json::to_vec(&value).unwrap().len());
+}
+
+/// Packs long enough to need a separate length prefix are back-patched when
+/// written to a vector, which mustn't change how long they are.
+#[test]
+fn wire_fixed() {
+    let encoding = musli::wire::Encoding::new().with_options::<FIXED>();
+    let value = record();
+    let len = encoding.encoded_len(&value).unwrap();
+    let data = encoding.to_vec(&value).unwrap();
+    assert_eq!(len, data.len());
+}
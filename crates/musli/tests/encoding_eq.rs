@@ -0,0 +1,75 @@
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed)]
+struct Packed {
+    value: u32,
+    name: String,
+}
+
+#[test]
+fn primitives() {
+    musli::macros::assert_encoding_eq!(storage, 42u32, [42]);
+    musli::macros::assert_encoding_eq!(wire, 42u32, [0b11_000000 | 42]);
+    musli::macros::assert_encoding_eq!(storage, "Hi", [2, b'H', b'i']);
+    musli::macros::assert_encoding_eq!(wire, "Hi", [0b01_000000 | 2, b'H', b'i']);
+    musli::macros::assert_encoding_eq!(json, "Hi", *b"\"Hi\"");
+}
+
+#[test]
+fn roundtrip_with_bytes() {
+    musli::macros::assert_roundtrip_eq! {
+        full,
+        Packed {
+            value: 42,
+            name: String::from("Hi"),
+        },
+        storage = [42, 2, b'H', b'i'],
+    };
+}
+
+#[test]
+#[should_panic = "first difference at offset 2"]
+fn mismatch() {
+    musli::macros::assert_encoding_eq!(storage, "Hi", [2, b'H', b'o']);
+}
+
+#[test]
+fn snapshots() {
+    let value = (42u32, String::from("Aristotle"), vec![Some(1i64), None]);
+
+    musli::macros::assert_snapshot!(storage, "tuple", &value);
+    musli::macros::assert_snapshot!(wire, "tuple", &value);
+    musli::macros::assert_snapshot!(descriptive, "tuple", &value);
+    musli::macros::assert_snapshot!(json, "tuple", &value);
+}
+
+#[test]
+#[should_panic = "snapshot is missing"]
+fn missing_snapshot() {
+    // Updating snapshots would create it.
+    if std::env::var_os("MUSLI_UPDATE_SNAPSHOTS").is_some_and(|value| value == "1") {
+        panic!("snapshot is missing");
+    }
+
+    musli::macros::assert_snapshot!(wire, "missing", &42u32);
+}
+
+#[test]
+#[should_panic = "+   prefix(5) \"Plato\""]
+fn structure_mismatch() {
+    let expected =
+        musli::wire::to_vec(&(42u32, String::from("Aristotle"), vec![1u64, 2, 3])).unwrap();
+    musli::macros::assert_encoding_eq!(
+        wire,
+        (42u32, String::from("Plato"), vec![1u64, 2, 3]),
+        expected
+    );
+}
+
+#[test]
+#[should_panic = "The structure is the same, only the bytes differ"]
+fn same_structure() {
+    // The same number, but not embedded in the tag.
+    musli::macros::assert_encoding_eq!(wire, 1u32, [0xff, 0x01]);
+}
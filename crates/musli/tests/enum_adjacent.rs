@@ -138,3 +138,13 @@ fn named_with_skip() {
         json = r#"{"type":"Struct","content":{"string":"Hello World","number":42}}"#,
     };
 }
+
+#[test]
+fn content_before_tag() {
+    let error = musli::json::from_str::<NamedWithSkip>(
+        r#"{"content":{"string":"Hello World","number":42},"type":"Struct"}"#,
+    )
+    .unwrap_err();
+
+    assert!(error.to_string().contains("Missing adjacent tag"));
+}
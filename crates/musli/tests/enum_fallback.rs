@@ -91,3 +91,38 @@ fn enum_pattern() {
         json = r#"3"#,
     );
 }
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub enum Version2 {
+    Cat,
+    #[musli(name_all = "name")]
+    Bird {
+        wings: u32,
+    },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub enum Version1 {
+    Cat,
+    #[musli(default)]
+    Unknown,
+}
+
+#[test]
+fn enum_named_fallback() {
+    musli::macros::assert_decode_eq!(
+        upgrade_stable,
+        Version2::Cat,
+        Version1::Cat,
+        json = r#"{"Cat":{}}"#,
+    );
+
+    musli::macros::assert_decode_eq!(
+        upgrade_stable,
+        Version2::Bird { wings: 2 },
+        Version1::Unknown,
+        json = r#"{"Bird":{"wings":2}}"#,
+    );
+}
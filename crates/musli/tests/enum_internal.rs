@@ -98,3 +98,48 @@ fn indexed() {
     test_case!(usize);
     test_case!(isize);
 }
+
+#[test]
+fn transparent_variant() {
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    #[musli(name_all = "name")]
+    pub struct Request {
+        id: u32,
+        method: String,
+    }
+
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    #[musli(tag = "type", name_all = "name")]
+    pub enum Message {
+        #[musli(transparent)]
+        Request(Request),
+        #[musli(name_all = "name")]
+        Notification {
+            method: String,
+        },
+        Ping,
+    }
+
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        Message::Request(Request {
+            id: 1,
+            method: String::from("get"),
+        }),
+        json = r#"{"type":"Request","id":1,"method":"get"}"#
+    };
+
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        Message::Notification {
+            method: String::from("exit"),
+        },
+        json = r#"{"type":"Notification","method":"exit"}"#
+    };
+
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        Message::Ping,
+        json = r#"{"type":"Ping"}"#
+    };
+}
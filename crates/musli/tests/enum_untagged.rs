@@ -0,0 +1,141 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", untagged)]
+enum Value {
+    #[musli(name_all = "name")]
+    Point {
+        x: i32,
+        y: i32,
+    },
+    #[musli(transparent)]
+    Number(u32),
+    #[musli(transparent)]
+    Text(String),
+    Nothing,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", untagged)]
+enum Message {
+    #[musli(name_all = "name")]
+    Response { id: u32, result: String },
+    #[musli(name_all = "name")]
+    Notification { method: String },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", untagged)]
+enum Ordered {
+    #[musli(name_all = "name")]
+    Long { a: u32, b: u32 },
+    #[musli(name_all = "name")]
+    Short { a: u32 },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", untagged)]
+enum Lenient {
+    #[musli(transparent)]
+    Number(u32),
+    #[musli(default)]
+    Other,
+}
+
+#[test]
+fn untagged_roundtrip() {
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        Value::Point { x: 1, y: 2 },
+        json = r#"{"x":1,"y":2}"#
+    };
+
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        Value::Number(42),
+        json = r#"42"#
+    };
+
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        Value::Text(String::from("Hello")),
+        json = r#""Hello""#
+    };
+
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        Value::Nothing,
+        json = r#"null"#
+    };
+}
+
+#[test]
+fn untagged_json_interop() {
+    let actual: Message = musli::json::from_str(r#"{"result":"ok","id":1}"#).unwrap();
+
+    assert_eq!(
+        actual,
+        Message::Response {
+            id: 1,
+            result: String::from("ok"),
+        }
+    );
+
+    let actual: Message = musli::json::from_str(r#"{"method":"ping"}"#).unwrap();
+
+    assert_eq!(
+        actual,
+        Message::Notification {
+            method: String::from("ping"),
+        }
+    );
+
+    let actual: Vec<Value> = musli::json::from_str(r#"[{"x":1,"y":2},42,"Hello",null]"#).unwrap();
+
+    assert_eq!(
+        actual,
+        [
+            Value::Point { x: 1, y: 2 },
+            Value::Number(42),
+            Value::Text(String::from("Hello")),
+            Value::Nothing,
+        ]
+    );
+}
+
+#[test]
+fn untagged_variant_order() {
+    // Variants are tried in the order they are declared, so the variant with
+    // more fields has to come first.
+    let actual: Ordered = musli::json::from_str(r#"{"a":1,"b":2}"#).unwrap();
+    assert_eq!(actual, Ordered::Long { a: 1, b: 2 });
+
+    let actual: Ordered = musli::json::from_str(r#"{"a":1}"#).unwrap();
+    assert_eq!(actual, Ordered::Short { a: 1 });
+}
+
+#[test]
+fn untagged_no_matching_variant() {
+    let error = musli::json::from_str::<Value>("true").unwrap_err();
+    assert!(error.to_string().contains("did not match any variant"));
+
+    let error = musli::json::from_str::<Message>(r#"{"id":1}"#).unwrap_err();
+    assert!(error.to_string().contains("did not match any variant"));
+}
+
+#[test]
+fn untagged_fallback() {
+    let actual: Vec<Lenient> = musli::json::from_str(r#"[42,true,"Hello",null]"#).unwrap();
+
+    assert_eq!(
+        actual,
+        [
+            Lenient::Number(42),
+            Lenient::Other,
+            Lenient::Other,
+            Lenient::Other,
+        ]
+    );
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "test")]
+
+use std::collections::HashMap;
+
+use musli::erased::{self, DynEncode};
+use musli::mode::Binary;
+use musli::value::Value;
+use musli::{Decode, Decoder, Encode, Encoder};
+
+/// Encodes and decodes the wrapped value through type-erased encoders and
+/// decoders.
+#[derive(Debug, PartialEq)]
+struct Erased<T>(T);
+
+impl<M, T> Encode<M> for Erased<T>
+where
+    M: 'static,
+    T: Encode<M>,
+{
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        erased::erase_encoder(cx, encoder, |encoder| encoder.encode(&self.0))
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Erased<T>
+where
+    M: 'static,
+    T: Decode<'de, M>,
+{
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        erased::erase_decoder(cx, decoder, |decoder| decoder.decode()).map(Erased)
+    }
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+enum Shape {
+    Circle { radius: f32 },
+    Square(u32),
+    Empty,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Drawing {
+    title: String,
+    author: Option<String>,
+    shapes: Vec<Shape>,
+    tags: HashMap<String, u64>,
+    bytes: Vec<u8>,
+    #[musli(bytes)]
+    hash: [u8; 16],
+}
+
+fn drawing() -> Drawing {
+    Drawing {
+        title: String::from("Sketch"),
+        author: Some(String::from("Aristotle")),
+        shapes: vec![
+            Shape::Circle { radius: 1.5 },
+            Shape::Square(4),
+            Shape::Empty,
+        ],
+        tags: HashMap::from([(String::from("layer"), 2)]),
+        bytes: vec![1, 2, 3],
+        hash: [7; 16],
+    }
+}
+
+#[test]
+fn roundtrip() {
+    musli::macros::assert_roundtrip_eq!(full, Erased(drawing()));
+    musli::macros::assert_decode_eq!(full, Erased(drawing()), drawing());
+    musli::macros::assert_decode_eq!(full, drawing(), Erased(drawing()));
+}
+
+#[test]
+fn same_encoding() {
+    let expected = musli::storage::to_vec(&drawing()).unwrap();
+    assert_eq!(
+        musli::storage::to_vec(&Erased(drawing())).unwrap(),
+        expected
+    );
+
+    let expected = musli::json::to_vec(&drawing()).unwrap();
+    assert_eq!(musli::json::to_vec(&Erased(drawing())).unwrap(), expected);
+}
+
+#[test]
+fn same_errors() {
+    let bytes = musli::storage::to_vec(&drawing()).unwrap();
+    let bytes = &bytes[..bytes.len() - 1];
+    let erased = musli::storage::from_slice::<Erased<Drawing>>(bytes).unwrap_err();
+    let direct = musli::storage::from_slice::<Drawing>(bytes).unwrap_err();
+    assert_eq!(erased.to_string(), direct.to_string());
+
+    let bytes = musli::json::to_vec(&drawing()).unwrap();
+    let bytes = &bytes[..bytes.len() - 1];
+    let erased = musli::json::from_slice::<Erased<Drawing>>(bytes).unwrap_err();
+    let direct = musli::json::from_slice::<Drawing>(bytes).unwrap_err();
+    assert_eq!(erased.to_string(), direct.to_string());
+}
+
+#[test]
+fn decode_any() {
+    let bytes = musli::descriptive::to_vec(&drawing()).unwrap();
+    let Erased(actual) = musli::descriptive::from_slice::<Erased<Value>>(&bytes).unwrap();
+    let direct: Value = musli::descriptive::from_slice(&bytes).unwrap();
+    assert_eq!(actual, direct);
+
+    let json = musli::json::to_vec(&drawing()).unwrap();
+    let Erased(actual) = musli::json::from_slice::<Erased<Value>>(&json).unwrap();
+    let direct: Value = musli::json::from_slice(&json).unwrap();
+    assert_eq!(actual, direct);
+}
+
+#[test]
+fn dyn_encode() {
+    let values: Vec<Box<dyn DynEncode<Binary>>> = vec![Box::new(1u32), Box::new(2u32)];
+    let expected = musli::storage::to_vec(&vec![1u32, 2u32]).unwrap();
+    assert_eq!(musli::storage::to_vec(&values).unwrap(), expected);
+
+    let values: Vec<Box<dyn DynEncode<Binary> + Send + Sync>> =
+        vec![Box::new(String::from("Hello")), Box::new("World")];
+    let expected = musli::wire::to_vec(&vec!["Hello", "World"]).unwrap();
+    assert_eq!(musli::wire::to_vec(&values).unwrap(), expected);
+}
+
+/// A byte array which is encoded with [`Encoder::encode_array`].
+#[derive(Debug, PartialEq)]
+struct Digest<const N: usize>([u8; N]);
+
+impl<M, const N: usize> Encode<M> for Digest<N> {
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_array(&self.0)
+    }
+}
+
+impl<'de, M, const N: usize> Decode<'de, M> for Digest<N> {
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_array().map(Digest)
+    }
+}
+
+#[test]
+fn arrays() {
+    musli::macros::assert_roundtrip_eq!(full, Erased(Digest([1; 33])));
+    musli::macros::assert_roundtrip_eq!(full, Erased(Digest([2; 100])));
+    musli::macros::assert_roundtrip_eq!(full, Erased(Digest([3; 1000])));
+    musli::macros::assert_decode_eq!(full, Digest([4; 100]), Erased(Digest([4; 100])));
+
+    let bytes = musli::wire::to_vec(&Digest([5; 100])).unwrap();
+    assert!(musli::wire::from_slice::<Erased<Digest<99>>>(&bytes).is_err());
+}
@@ -0,0 +1,81 @@
+use std::ops::Range;
+
+use musli::context;
+use musli::{Decode, Encode};
+
+#[derive(Debug, Encode, Decode)]
+struct Message {
+    id: u32,
+    name: String,
+}
+
+fn message() -> Message {
+    Message {
+        id: 42,
+        name: String::from("ab"),
+    }
+}
+
+/// Corrupt the string, which is the last element of the payload and consists
+/// of a single byte prefix followed by its two bytes, returning the range it
+/// occupies.
+fn corrupt(data: &mut [u8]) -> Range<usize> {
+    let len = data.len();
+    data[len - 2..].copy_from_slice(&[0xff, 0xfe]);
+    len - 3..len
+}
+
+#[test]
+fn nested_string() {
+    let mut data = musli::wire::to_vec(&message()).unwrap();
+    let range = corrupt(&mut data);
+    let cx = context::new();
+    let result = musli::wire::DEFAULT.from_slice_with::<_, Message>(&cx, &data);
+    assert!(result.is_err());
+    let error = cx.errors().next().expect("expected an error");
+    assert_eq!(error.range(), range, "wire: {error}");
+
+    let mut data = musli::storage::to_vec(&message()).unwrap();
+    let range = corrupt(&mut data);
+    let cx = context::new();
+    let result = musli::storage::DEFAULT.from_slice_with::<_, Message>(&cx, &data);
+    assert!(result.is_err());
+    let error = cx.errors().next().expect("expected an error");
+    assert_eq!(error.range(), range, "storage: {error}");
+
+    let mut data = musli::descriptive::to_vec(&message()).unwrap();
+    let range = corrupt(&mut data);
+    let cx = context::new();
+    let result = musli::descriptive::DEFAULT.from_slice_with::<_, Message>(&cx, &data);
+    assert!(result.is_err());
+    let error = cx.errors().next().expect("expected an error");
+    assert_eq!(error.range(), range, "descriptive: {error}");
+}
+
+#[test]
+fn visited_slice() {
+    // A string with a single byte length prefix, followed by two bytes which
+    // aren't valid UTF-8.
+    let data = [2, 0xff, 0xfe];
+
+    let cx = context::new();
+    let result = musli::storage::DEFAULT.from_slice_with::<_, String>(&cx, &data);
+    assert!(result.is_err());
+
+    let error = cx.errors().next().expect("expected an error");
+    assert_eq!(error.range(), 0..3, "{error}");
+}
+
+#[test]
+fn bad_boolean() {
+    let mut data = musli::wire::to_vec(&(1u32, true)).unwrap();
+    let len = data.len();
+    data[len - 1] = 0xff;
+
+    let cx = context::new();
+    let result = musli::wire::DEFAULT.from_slice_with::<_, (u32, bool)>(&cx, &data);
+    assert!(result.is_err());
+
+    let error = cx.errors().next().expect("expected an error");
+    assert_eq!(error.range(), len - 1..len, "{error}");
+}
@@ -0,0 +1,139 @@
+#![cfg(feature = "test")]
+
+use std::cmp::Ordering;
+
+use musli::{Decode, Decoder, Encode, Encoder};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Comparison {
+    #[musli(with = self::ordering)]
+    ordering: Ordering,
+    #[musli(with = self::reversed)]
+    values: Vec<u32>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+enum Outcome {
+    Compared(#[musli(with = self::ordering)] Ordering),
+    Incomparable,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Reversed {
+    values: Vec<u32>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Settings {
+    #[musli(encode_with = self::encode_uppercase)]
+    name: String,
+    #[musli(decode_with = self::decode_clamped)]
+    level: u8,
+}
+
+fn encode_uppercase<E>(name: &str, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+where
+    E: Encoder,
+{
+    encoder.encode_string(&name.to_uppercase())
+}
+
+fn decode_clamped<'de, D>(_: &D::Cx, decoder: D) -> Result<u8, D::Error>
+where
+    D: Decoder<'de>,
+{
+    Ok(decoder.decode_u8()?.min(10))
+}
+
+mod ordering {
+    use std::cmp::Ordering;
+
+    use musli::{Context, Decoder, Encoder};
+
+    pub fn encode<E>(ordering: &Ordering, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder,
+    {
+        encoder.encode_i8(*ordering as i8)
+    }
+
+    pub fn decode<'de, D>(cx: &D::Cx, decoder: D) -> Result<Ordering, D::Error>
+    where
+        D: Decoder<'de>,
+    {
+        match decoder.decode_i8()? {
+            -1 => Ok(Ordering::Less),
+            0 => Ok(Ordering::Equal),
+            1 => Ok(Ordering::Greater),
+            n => Err(cx.message(format_args!("Invalid ordering {n}"))),
+        }
+    }
+}
+
+mod reversed {
+    use musli::{Decode, Decoder, Encode, Encoder};
+
+    pub fn encode<E, T>(values: &[T], cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder,
+        T: Encode<E::Mode>,
+    {
+        let values = values.iter().rev().collect::<Vec<_>>();
+        values.encode(cx, encoder)
+    }
+
+    pub fn decode<'de, D, T>(_: &D::Cx, decoder: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Decoder<'de>,
+        T: Decode<'de, D::Mode>,
+    {
+        let mut values: Vec<T> = decoder.decode()?;
+        values.reverse();
+        Ok(values)
+    }
+}
+
+#[test]
+fn with_module() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Comparison {
+            ordering: Ordering::Greater,
+            values: vec![1, 2, 3],
+        }
+    );
+
+    musli::macros::assert_roundtrip_eq!(full, Outcome::Compared(Ordering::Less));
+    musli::macros::assert_roundtrip_eq!(full, Outcome::Incomparable);
+}
+
+#[test]
+fn with_module_representation() {
+    let bytes = musli::storage::to_vec(&Comparison {
+        ordering: Ordering::Equal,
+        values: vec![1, 2, 3],
+    })
+    .unwrap();
+
+    let actual: Reversed = musli::storage::from_slice(&bytes[1..]).unwrap();
+    assert_eq!(actual.values, vec![3, 2, 1]);
+}
+
+#[test]
+fn with_functions() {
+    let bytes = musli::storage::to_vec(&Settings {
+        name: String::from("server"),
+        level: 200,
+    })
+    .unwrap();
+
+    let actual: Settings = musli::storage::from_slice(&bytes).unwrap();
+
+    assert_eq!(
+        actual,
+        Settings {
+            name: String::from("SERVER"),
+            level: 10,
+        }
+    );
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "test")]
+
+use std::collections::BTreeMap;
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Header {
+    id: u32,
+    version: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Trailer {
+    checksum: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Request {
+    #[musli(flatten)]
+    header: Header,
+    method: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Framed {
+    #[musli(flatten)]
+    header: Header,
+    body: String,
+    #[musli(flatten)]
+    trailer: Trailer,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct FlatRequest {
+    id: u32,
+    version: u32,
+    method: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Totals {
+    total: u32,
+    #[musli(flatten)]
+    counts: BTreeMap<String, u32>,
+}
+
+#[test]
+fn flatten_struct() {
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        Request {
+            header: Header { id: 1, version: 2 },
+            method: String::from("GET"),
+        },
+        json = r#"{"method":"GET","id":1,"version":2}"#
+    };
+
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        Framed {
+            header: Header { id: 1, version: 2 },
+            body: String::from("Hello"),
+            trailer: Trailer { checksum: 3 },
+        },
+        json = r#"{"body":"Hello","id":1,"version":2,"checksum":3}"#
+    };
+}
+
+#[test]
+fn flatten_same_as_flat() {
+    let request = Request {
+        header: Header { id: 1, version: 2 },
+        method: String::from("GET"),
+    };
+
+    let bytes = musli::storage::to_vec(&request).unwrap();
+    let flat: FlatRequest = musli::storage::from_slice(&bytes).unwrap();
+
+    assert_eq!(
+        flat,
+        FlatRequest {
+            id: 1,
+            version: 2,
+            method: String::from("GET"),
+        }
+    );
+
+    let json = musli::json::to_string(&flat).unwrap();
+    let actual: Request = musli::json::from_str(&json).unwrap();
+    assert_eq!(actual, request);
+}
+
+#[test]
+fn flatten_map() {
+    let totals = Totals {
+        total: 3,
+        counts: BTreeMap::from([(String::from("a"), 1), (String::from("b"), 2)]),
+    };
+
+    let json = musli::json::to_string(&totals).unwrap();
+    assert_eq!(json, r#"{"total":3,"a":1,"b":2}"#);
+
+    // The flattened map only sees the entries which don't belong to other
+    // fields.
+    let actual: Totals = musli::json::from_str(&json).unwrap();
+    assert_eq!(actual, totals);
+
+    let bytes = musli::descriptive::to_vec(&totals).unwrap();
+    let actual: Totals = musli::descriptive::from_slice(&bytes).unwrap();
+    assert_eq!(actual, totals);
+}
@@ -1,3 +1,6 @@
+use std::marker::PhantomData;
+
+use musli::de::DecodeOwned;
 use musli::mode::{Binary, Text};
 use musli::{Decode, Encode};
 
@@ -8,6 +11,22 @@ pub struct GenericWithBound<T> {
     value: T,
 }
 
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+#[musli(mode = Binary, bound = {T: Encode<Binary> + DecodeOwned<Binary>})]
+#[musli(mode = Text, bound = {T: Encode<Text> + DecodeOwned<Text>})]
+pub struct GenericWithSharedBound<T> {
+    value: T,
+}
+
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct PhantomGeneric<T> {
+    id: u32,
+    marker: PhantomData<T>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct NotEncodable;
+
 #[test]
 fn generic_with_bound() {
     musli::macros::assert_roundtrip_eq!(
@@ -17,3 +36,24 @@ fn generic_with_bound() {
         }
     );
 }
+
+#[test]
+fn generic_with_shared_bound() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        GenericWithSharedBound {
+            value: String::from("Hello"),
+        }
+    );
+}
+
+#[test]
+fn phantom_generic() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        PhantomGeneric::<NotEncodable> {
+            id: 42,
+            marker: PhantomData,
+        }
+    );
+}
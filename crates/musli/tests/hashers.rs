@@ -0,0 +1,75 @@
+#![cfg(feature = "test")]
+
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, BuildHasherDefault, Hasher};
+
+use musli::{Decode, Encode};
+
+/// A simple FNV-1a hasher, standing in for third-party hashers.
+struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= u64::from(b);
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+type FnvBuildHasher = BuildHasherDefault<Fnv>;
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Index {
+    names: HashMap<u32, String, FnvBuildHasher>,
+    tags: HashSet<String, FnvBuildHasher>,
+}
+
+fn index() -> Index {
+    let mut names = HashMap::default();
+    names.insert(1, String::from("one"));
+    names.insert(2, String::from("two"));
+
+    let mut tags = HashSet::default();
+    tags.insert(String::from("a"));
+    tags.insert(String::from("b"));
+
+    Index { names, tags }
+}
+
+#[test]
+fn roundtrip() {
+    musli::macros::assert_roundtrip_eq!(full, index());
+}
+
+/// A hasher with per-instance state, which cannot be constructed through
+/// `Default`.
+struct Seeded(u64);
+
+impl BuildHasher for Seeded {
+    type Hasher = Fnv;
+
+    fn build_hasher(&self) -> Fnv {
+        Fnv(self.0)
+    }
+}
+
+#[test]
+fn encode_without_default_hasher() {
+    let mut map = HashMap::with_hasher(Seeded(42));
+    map.insert(1u32, 2u32);
+
+    let data = musli::storage::to_vec(&map).unwrap();
+    let actual: HashMap<u32, u32> = musli::storage::from_slice(&data).unwrap();
+    assert_eq!(actual.get(&1), Some(&2));
+}
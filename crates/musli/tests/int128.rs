@@ -0,0 +1,61 @@
+#![cfg(feature = "test")]
+
+use musli::options::{self, Integer, Options};
+use musli::{Decode, Encode};
+
+const FIXED: Options = options::new().with_integer(Integer::Fixed).build();
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Counters {
+    unsigned: Vec<u128>,
+    signed: Vec<i128>,
+}
+
+fn counters() -> Counters {
+    Counters {
+        unsigned: vec![0, 1, u64::MAX as u128, u64::MAX as u128 + 1, u128::MAX],
+        signed: vec![
+            0,
+            -1,
+            i64::MIN as i128,
+            i64::MIN as i128 - 1,
+            i128::MIN,
+            i128::MAX,
+        ],
+    }
+}
+
+#[test]
+fn roundtrip() {
+    musli::macros::assert_roundtrip_eq!(full, counters());
+}
+
+#[test]
+fn fixed_integers() {
+    let expected = counters();
+
+    let encoding = musli::wire::Encoding::new().with_options::<FIXED>();
+    let data = encoding.to_vec(&expected).unwrap();
+    assert_eq!(encoding.from_slice::<Counters>(&data).unwrap(), expected);
+
+    let encoding = musli::storage::Encoding::new().with_options::<FIXED>();
+    let data = encoding.to_vec(&expected).unwrap();
+    assert_eq!(encoding.from_slice::<Counters>(&data).unwrap(), expected);
+
+    let encoding = musli::descriptive::Encoding::new().with_options::<FIXED>();
+    let data = encoding.to_vec(&expected).unwrap();
+    assert_eq!(encoding.from_slice::<Counters>(&data).unwrap(), expected);
+}
+
+#[test]
+fn value() {
+    use musli::value::{Number, Value};
+
+    let data = musli::descriptive::to_vec(&u128::MAX).unwrap();
+    let value: Value = musli::descriptive::from_slice(&data).unwrap();
+    assert_eq!(value.as_number().and_then(Number::as_u128), Some(u128::MAX));
+
+    let data = musli::descriptive::to_vec(&i128::MIN).unwrap();
+    let value: Value = musli::descriptive::from_slice(&data).unwrap();
+    assert_eq!(value.as_number().and_then(Number::as_i128), Some(i128::MIN));
+}
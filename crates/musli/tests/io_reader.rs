@@ -0,0 +1,111 @@
+use std::io::{self, Read};
+
+use musli::io::IoReader;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Person {
+    name: String,
+    age: u32,
+    tags: Vec<String>,
+}
+
+/// A reader which hands out a single byte at a time, and is interrupted every
+/// other read.
+struct Trickle<'a> {
+    data: &'a [u8],
+    interrupted: bool,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.interrupted = !self.interrupted;
+
+        if self.interrupted {
+            return Err(io::Error::from(io::ErrorKind::Interrupted));
+        }
+
+        let Some((&first, tail)) = self.data.split_first() else {
+            return Ok(0);
+        };
+
+        buf[0] = first;
+        self.data = tail;
+        Ok(1)
+    }
+}
+
+fn people() -> [Person; 2] {
+    [
+        Person {
+            name: String::from("Aristotle"),
+            age: 61,
+            tags: vec![String::from("philosopher")],
+        },
+        Person {
+            name: String::from("Plato"),
+            age: 80,
+            tags: Vec::new(),
+        },
+    ]
+}
+
+#[test]
+fn consecutive() {
+    let people = people();
+    let mut data = Vec::new();
+
+    for person in &people {
+        musli::wire::to_writer(&mut data, person).unwrap();
+    }
+
+    let mut reader = IoReader::new(Trickle {
+        data: &data,
+        interrupted: false,
+    });
+
+    for expected in &people {
+        let actual: Person = musli::wire::DEFAULT.decode_reader(&mut reader).unwrap();
+        assert_eq!(&actual, expected);
+    }
+
+    assert!(reader.buffer().is_empty());
+    assert!(musli::wire::DEFAULT
+        .decode_reader::<_, Person>(&mut reader)
+        .is_err());
+}
+
+#[test]
+fn from_reader() {
+    let people = people();
+
+    let data = musli::wire::to_vec(&people).unwrap();
+    let actual: [Person; 2] = musli::wire::DEFAULT.from_reader(&data[..]).unwrap();
+    assert_eq!(actual, people);
+
+    let data = musli::storage::to_vec(&people).unwrap();
+    let actual: [Person; 2] = musli::storage::DEFAULT.from_reader(&data[..]).unwrap();
+    assert_eq!(actual, people);
+
+    let data = musli::descriptive::to_vec(&people).unwrap();
+    let actual: [Person; 2] = musli::descriptive::DEFAULT.from_reader(&data[..]).unwrap();
+    assert_eq!(actual, people);
+}
+
+#[test]
+fn buffered_remainder() {
+    let mut data = musli::wire::to_vec(&42u32).unwrap();
+    data.extend_from_slice(&[0xde, 0xad]);
+
+    let mut reader = IoReader::new(&data[..]);
+    let value: u32 = musli::wire::DEFAULT.decode_reader(&mut reader).unwrap();
+    assert_eq!(value, 42);
+    assert_eq!(reader.buffer(), &[0xde, 0xad]);
+}
+
+#[test]
+fn truncated() {
+    let data = musli::wire::to_vec(&String::from("Hello World")).unwrap();
+    let result = musli::wire::DEFAULT.from_reader::<_, String>(&data[..data.len() - 1]);
+    assert!(result.is_err());
+}
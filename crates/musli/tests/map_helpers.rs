@@ -0,0 +1,90 @@
+#![cfg(feature = "test")]
+
+use std::collections::BTreeMap;
+
+use musli::de::MapDecoder;
+use musli::{Decode, Decoder, Encode};
+
+/// Collects a map through [`MapDecoder::entries`].
+#[derive(Debug, PartialEq)]
+struct Entries(Vec<(String, u32)>);
+
+impl<'de, M> Decode<'de, M> for Entries {
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_map(|map| Ok(Entries(map.entries().collect::<Result<_, _>>()?)))
+    }
+}
+
+/// Picks out individual fields through [`MapDecoder::decode_map_fn`].
+#[derive(Debug, Default, PartialEq)]
+struct Picked {
+    first: Option<u32>,
+    second: Option<String>,
+}
+
+impl<'de, M> Decode<'de, M> for Picked {
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_map(|map| {
+            let mut picked = Picked::default();
+
+            map.decode_map_fn(|key: String, value| {
+                match key.as_str() {
+                    "first" => picked.first = Some(value.decode()?),
+                    "second" => picked.second = Some(value.decode()?),
+                    _ => value.skip()?,
+                }
+
+                Ok(())
+            })?;
+
+            Ok(picked)
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Encode)]
+#[musli(name_all = "name")]
+struct Source {
+    first: u32,
+    ignored: Vec<u64>,
+    second: String,
+}
+
+#[test]
+fn entries() {
+    musli::macros::assert_decode_eq!(
+        upgrade_stable,
+        BTreeMap::from([
+            (String::from("a"), 1u32),
+            (String::from("b"), 2u32),
+            (String::from("c"), 3u32),
+        ]),
+        Entries(vec![
+            (String::from("a"), 1),
+            (String::from("b"), 2),
+            (String::from("c"), 3),
+        ])
+    );
+}
+
+#[test]
+fn decode_map_fn() {
+    musli::macros::assert_decode_eq!(
+        upgrade_stable,
+        Source {
+            first: 42,
+            ignored: vec![1, 2, 3],
+            second: String::from("hello"),
+        },
+        Picked {
+            first: Some(42),
+            second: Some(String::from("hello")),
+        }
+    );
+}
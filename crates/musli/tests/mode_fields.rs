@@ -0,0 +1,65 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Event {
+    id: u32,
+    #[musli(mode = Text, name = "user-name")]
+    user: String,
+    #[musli(mode = Binary, skip)]
+    note: String,
+    #[musli(mode = Text, decode_only, skip)]
+    session: u32,
+}
+
+#[test]
+fn text_mode_fields() {
+    let json = musli::json::to_string(&Event {
+        id: 1,
+        user: String::from("Aristotle"),
+        note: String::from("Logged in"),
+        session: 42,
+    })
+    .unwrap();
+
+    assert_eq!(
+        json,
+        r#"{"id":1,"user-name":"Aristotle","note":"Logged in","session":42}"#
+    );
+
+    let actual: Event = musli::json::from_str(&json).unwrap();
+
+    assert_eq!(
+        actual,
+        Event {
+            id: 1,
+            user: String::from("Aristotle"),
+            note: String::from("Logged in"),
+            session: 0,
+        }
+    );
+}
+
+#[test]
+fn binary_mode_fields() {
+    let bytes = musli::wire::to_vec(&Event {
+        id: 1,
+        user: String::from("Aristotle"),
+        note: String::from("Logged in"),
+        session: 42,
+    })
+    .unwrap();
+
+    let actual: Event = musli::wire::from_slice(&bytes).unwrap();
+
+    assert_eq!(
+        actual,
+        Event {
+            id: 1,
+            user: String::from("Aristotle"),
+            note: String::new(),
+            session: 42,
+        }
+    );
+}
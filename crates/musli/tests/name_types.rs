@@ -75,3 +75,61 @@ fn variant_names() {
     test_case!(isize);
     test_case!(usize);
 }
+
+#[test]
+fn default_index_names() {
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    #[musli(name_type = u16)]
+    struct Struct {
+        first: u32,
+        second: u32,
+    }
+
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    #[musli(name_type = u8)]
+    enum Enum {
+        First,
+        #[musli(packed)]
+        Second(u32),
+    }
+
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Struct {
+            first: 42,
+            second: 43
+        },
+        json = r#"{"0":42,"1":43}"#
+    );
+
+    musli::macros::assert_roundtrip_eq!(full, Enum::First, json = r#"{"0":{}}"#);
+    musli::macros::assert_roundtrip_eq!(full, Enum::Second(42), json = r#"{"1":[42]}"#);
+}
+
+#[test]
+fn enum_names() {
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    #[musli(name_all = "kebab-case")]
+    enum FieldTag {
+        UserName,
+        Age,
+    }
+
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    #[musli(name_type = FieldTag)]
+    struct User {
+        #[musli(name = FieldTag::UserName)]
+        name: String,
+        #[musli(name = FieldTag::Age)]
+        age: u32,
+    }
+
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        User {
+            name: String::from("Aristotle"),
+            age: 62,
+        },
+        json = r#"{"user-name":"Aristotle","age":62}"#
+    );
+}
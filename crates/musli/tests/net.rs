@@ -0,0 +1,59 @@
+#![cfg(feature = "test")]
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Peer {
+    v4: Ipv4Addr,
+    v6: Ipv6Addr,
+    ip: IpAddr,
+    other_ip: IpAddr,
+    socket_v4: SocketAddrV4,
+    socket_v6: SocketAddrV6,
+    socket: SocketAddr,
+    other_socket: SocketAddr,
+}
+
+fn peer() -> Peer {
+    Peer {
+        v4: Ipv4Addr::LOCALHOST,
+        v6: Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+        ip: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)),
+        other_ip: IpAddr::V6(Ipv6Addr::LOCALHOST),
+        socket_v4: SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 80),
+        socket_v6: SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 7, 3),
+        socket: SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::BROADCAST, 443)),
+        other_socket: SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 22, 0, 0)),
+    }
+}
+
+#[test]
+fn roundtrip() {
+    musli::macros::assert_roundtrip_eq!(full, peer());
+}
+
+#[test]
+fn binary_is_compact() {
+    let data = musli::storage::to_vec(&Ipv4Addr::new(127, 0, 0, 1)).unwrap();
+    assert_eq!(data, [127, 0, 0, 1]);
+
+    let data = musli::storage::to_vec(&Ipv6Addr::LOCALHOST).unwrap();
+    assert_eq!(data, Ipv6Addr::LOCALHOST.octets());
+}
+
+#[test]
+fn text_uses_standard_notation() {
+    let string = musli::json::to_string(&Ipv4Addr::LOCALHOST).unwrap();
+    assert_eq!(string, "\"127.0.0.1\"");
+
+    let addr = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 0, 0);
+    let string = musli::json::to_string(&addr).unwrap();
+    assert_eq!(string, "\"[::1]:8080\"");
+
+    let actual: SocketAddrV6 = musli::json::from_str(&string).unwrap();
+    assert_eq!(actual, addr);
+
+    assert!(musli::json::from_str::<Ipv4Addr>("\"not an address\"").is_err());
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "test")]
+
+use core::num::{NonZeroI128, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize};
+
+use musli::context;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Ids {
+    small: NonZeroI8,
+    port: NonZeroU16,
+    id: NonZeroU32,
+    parent: Option<NonZeroU64>,
+    len: NonZeroUsize,
+    counter: NonZeroI128,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct RawId {
+    id: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Id {
+    id: NonZeroU32,
+}
+
+#[test]
+fn roundtrip() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Ids {
+            small: NonZeroI8::new(-1).unwrap(),
+            port: NonZeroU16::new(8080).unwrap(),
+            id: NonZeroU32::MAX,
+            parent: None,
+            len: NonZeroUsize::MIN,
+            counter: NonZeroI128::new(i128::MIN).unwrap(),
+        }
+    );
+}
+
+#[test]
+fn zero() {
+    let cx = context::new();
+    let data = musli::storage::to_vec(&RawId { id: 0 }).unwrap();
+    let result = musli::storage::DEFAULT.from_slice_with::<_, Id>(&cx, &data);
+    assert!(result.is_err());
+    let binary = cx.errors().next().expect("expected an error").to_string();
+
+    let cx = context::new();
+    let data = musli::json::to_vec(&RawId { id: 0 }).unwrap();
+    let result = musli::json::DEFAULT.from_slice_with::<_, Id>(&cx, &data);
+    assert!(result.is_err());
+    let text = cx.errors().next().expect("expected an error").to_string();
+
+    for error in [binary, text] {
+        assert!(
+            error.contains("NonZeroU32: unsupported non-zero value `0`"),
+            "{error}"
+        );
+    }
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "test")]
+
+use std::num::{Saturating, Wrapping};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, AtomicUsize, Ordering};
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Counters {
+    wrapping: Wrapping<u8>,
+    saturating: Saturating<i16>,
+}
+
+#[derive(Debug, Encode, Decode)]
+struct Metrics {
+    running: AtomicBool,
+    requests: AtomicU32,
+    balance: AtomicI64,
+    bytes: AtomicUsize,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct PlainMetrics {
+    running: bool,
+    requests: u32,
+    balance: i64,
+    bytes: usize,
+}
+
+#[test]
+fn wrappers() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Counters {
+            wrapping: Wrapping(250u8) + Wrapping(10),
+            saturating: Saturating(i16::MAX) + Saturating(1),
+        }
+    );
+}
+
+fn metrics() -> Metrics {
+    let metrics = Metrics {
+        running: AtomicBool::new(false),
+        requests: AtomicU32::new(0),
+        balance: AtomicI64::new(0),
+        bytes: AtomicUsize::new(0),
+    };
+
+    metrics.running.store(true, Ordering::SeqCst);
+    metrics.requests.fetch_add(3, Ordering::SeqCst);
+    metrics.balance.fetch_sub(100, Ordering::SeqCst);
+    metrics.bytes.fetch_add(4096, Ordering::SeqCst);
+    metrics
+}
+
+#[track_caller]
+fn assert_metrics(actual: &Metrics) {
+    assert!(actual.running.load(Ordering::SeqCst));
+    assert_eq!(actual.requests.load(Ordering::SeqCst), 3);
+    assert_eq!(actual.balance.load(Ordering::SeqCst), -100);
+    assert_eq!(actual.bytes.load(Ordering::SeqCst), 4096);
+}
+
+#[test]
+fn atomics() {
+    let data = musli::storage::to_vec(&metrics()).unwrap();
+    assert_metrics(&musli::storage::from_slice(&data).unwrap());
+
+    let data = musli::json::to_vec(&metrics()).unwrap();
+    assert_metrics(&musli::json::from_slice(&data).unwrap());
+}
+
+#[test]
+fn atomics_encode_as_plain() {
+    let plain = PlainMetrics {
+        running: true,
+        requests: 3,
+        balance: -100,
+        bytes: 4096,
+    };
+
+    assert_eq!(
+        musli::storage::to_vec(&metrics()).unwrap(),
+        musli::storage::to_vec(&plain).unwrap()
+    );
+
+    assert_eq!(
+        musli::json::to_vec(&metrics()).unwrap(),
+        musli::json::to_vec(&plain).unwrap()
+    );
+}
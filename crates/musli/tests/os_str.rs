@@ -0,0 +1,48 @@
+#![cfg(feature = "test")]
+
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Entry {
+    path: PathBuf,
+    name: OsString,
+    symbol: CString,
+    boxed_path: Box<Path>,
+    shared_name: Arc<OsStr>,
+    boxed_symbol: Box<CStr>,
+}
+
+fn entry(name: OsString) -> Entry {
+    Entry {
+        path: PathBuf::from("/var/lib/musli/data.bin"),
+        name: name.clone(),
+        symbol: CString::new("musli_init").unwrap(),
+        boxed_path: Path::new("relative/path").into(),
+        shared_name: Arc::from(name.as_os_str()),
+        boxed_symbol: CString::new("").unwrap().into_boxed_c_str(),
+    }
+}
+
+#[cfg(unix)]
+fn non_utf8() -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+    OsString::from_vec(vec![b'f', 0xff, b'o', 0xfe])
+}
+
+#[cfg(windows)]
+fn non_utf8() -> OsString {
+    use std::os::windows::ffi::OsStringExt;
+    // An unpaired surrogate.
+    OsString::from_wide(&[u16::from(b'f'), 0xd800, u16::from(b'o')])
+}
+
+#[test]
+fn roundtrip() {
+    for name in [OsString::from("data.bin"), non_utf8()] {
+        musli::macros::assert_roundtrip_eq!(full, entry(name.clone()));
+    }
+}
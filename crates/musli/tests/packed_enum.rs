@@ -0,0 +1,46 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed)]
+enum Command {
+    Reset,
+    Move { x: u8, y: u8 },
+    Write(u16, u8),
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed, name_all = "discriminant")]
+#[repr(u8)]
+enum Frame {
+    Ping = 1,
+    Data { id: u16, value: u32 } = 4,
+}
+
+#[test]
+fn packed_enum() {
+    musli::macros::assert_roundtrip_eq!(full, Command::Reset, json = r#"["Reset"]"#);
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Command::Move { x: 1, y: 2 },
+        json = r#"["Move",1,2]"#
+    );
+    musli::macros::assert_roundtrip_eq!(full, Command::Write(300, 4), json = r#"["Write",300,4]"#);
+}
+
+#[test]
+fn packed_enum_bytes() {
+    let bytes = musli::storage::to_vec(&Frame::Ping).unwrap();
+    assert_eq!(bytes, [1]);
+
+    let bytes = musli::storage::to_vec(&Frame::Data { id: 2, value: 3 }).unwrap();
+    assert_eq!(bytes, [4, 2, 3]);
+
+    let frames = vec![Frame::Data { id: 2, value: 3 }, Frame::Ping];
+    let bytes = musli::storage::to_vec(&frames).unwrap();
+    let actual: Vec<Frame> = musli::storage::from_slice(&bytes).unwrap();
+    assert_eq!(actual, frames);
+
+    assert!(musli::storage::from_slice::<Frame>(&[2]).is_err());
+}
@@ -0,0 +1,128 @@
+use std::collections::VecDeque;
+
+use musli::context;
+use musli::options::{self, ByteOrder, Integer, Options};
+use musli::{Decode, Encode};
+
+const NATIVE: Options = options::new().with_integer(Integer::Fixed).build();
+
+const SWAPPED: Options = options::new()
+    .with_integer(Integer::Fixed)
+    .with_byte_order(if matches!(ByteOrder::NATIVE, ByteOrder::Little) {
+        ByteOrder::Big
+    } else {
+        ByteOrder::Little
+    })
+    .build();
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Numbers {
+    unsigned: Vec<u32>,
+    signed: Vec<i16>,
+    floats: Vec<f64>,
+    array: [u64; 3],
+}
+
+fn numbers() -> Numbers {
+    Numbers {
+        unsigned: vec![1, 2, 0xdeadbeef],
+        signed: vec![-1, 0, i16::MAX],
+        floats: vec![0.5, -1.25, 1024.0],
+        array: [u64::MAX, 7, 0],
+    }
+}
+
+#[test]
+fn fixed_native() {
+    let storage = musli::storage::Encoding::new().with_options::<NATIVE>();
+    let bytes = storage.to_vec(&vec![1u32, 2, 0xdeadbeef]).unwrap();
+
+    let mut expected = vec![3];
+
+    for n in [1u32, 2, 0xdeadbeef] {
+        expected.extend_from_slice(&n.to_ne_bytes());
+    }
+
+    assert_eq!(bytes, expected);
+
+    let value = numbers();
+    let bytes = storage.to_vec(&value).unwrap();
+    let actual: Numbers = storage.from_slice(&bytes).unwrap();
+    assert_eq!(actual, value);
+}
+
+#[test]
+fn fixed_swapped() {
+    let storage = musli::storage::Encoding::new().with_options::<SWAPPED>();
+    let bytes = storage.to_vec(&vec![1u32, 2, 0xdeadbeef]).unwrap();
+
+    let mut expected = vec![3];
+
+    for n in [1u32, 2, 0xdeadbeef] {
+        expected.extend_from_slice(&n.swap_bytes().to_ne_bytes());
+    }
+
+    assert_eq!(bytes, expected);
+
+    let value = numbers();
+    let bytes = storage.to_vec(&value).unwrap();
+    let actual: Numbers = storage.from_slice(&bytes).unwrap();
+    assert_eq!(actual, value);
+}
+
+#[test]
+fn fixed_truncated() {
+    let storage = musli::storage::Encoding::new().with_options::<NATIVE>();
+    let mut bytes = storage.to_vec(&vec![1u32, 2, 3]).unwrap();
+    bytes.pop();
+
+    assert!(storage.from_slice::<Vec<u32>>(&bytes).is_err());
+}
+
+#[test]
+fn variable() {
+    musli::macros::assert_roundtrip_eq!(full, numbers());
+    musli::macros::assert_encoding_eq!(storage, vec![1u32, 2, 300], [3, 1, 2, 0xac, 0x02]);
+}
+
+#[test]
+fn wire_fixed() {
+    let wire = musli::wire::Encoding::new().with_options::<NATIVE>();
+
+    let values = (0..1000u32)
+        .map(|n| n.wrapping_mul(0x9e3779b9))
+        .collect::<Vec<_>>();
+    let bytes = wire.to_vec(&values).unwrap();
+    let deque = wire
+        .to_vec(&values.iter().copied().collect::<VecDeque<_>>())
+        .unwrap();
+    assert_eq!(bytes, deque);
+
+    let actual: Vec<u32> = wire.from_slice(&bytes).unwrap();
+    assert_eq!(actual, values);
+
+    let value = numbers();
+    let bytes = wire.to_vec(&value).unwrap();
+    let actual: Numbers = wire.from_slice(&bytes).unwrap();
+    assert_eq!(actual, value);
+
+    let wire = musli::wire::Encoding::new().with_options::<SWAPPED>();
+    let bytes = wire.to_vec(&value).unwrap();
+    let actual: Numbers = wire.from_slice(&bytes).unwrap();
+    assert_eq!(actual, value);
+}
+
+#[test]
+fn fallback_index() {
+    let storage = musli::storage::Encoding::new();
+    let mut bytes = storage.to_vec(&vec![1u32, 2, 300]).unwrap();
+    bytes.pop();
+
+    musli::alloc::default!(|alloc| {
+        let cx = context::with_alloc(alloc);
+        assert!(storage.from_slice_with::<_, Vec<u32>>(&cx, &bytes).is_err());
+
+        let error = cx.errors().next().unwrap().to_string();
+        assert!(error.starts_with("[2]"), "{error}");
+    });
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+#[musli(from = Rgb, into = Rgb)]
+struct Color(u32);
+
+impl From<Rgb> for Color {
+    fn from(rgb: Rgb) -> Self {
+        Color(u32::from_be_bytes([0, rgb.r, rgb.g, rgb.b]))
+    }
+}
+
+impl From<Color> for Rgb {
+    fn from(color: Color) -> Self {
+        let [_, r, g, b] = color.0.to_be_bytes();
+        Rgb { r, g, b }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+#[musli(from = String, into = String)]
+enum Protocol {
+    Http,
+    Other(String),
+}
+
+impl From<String> for Protocol {
+    fn from(string: String) -> Self {
+        match string.as_str() {
+            "http" => Protocol::Http,
+            _ => Protocol::Other(string),
+        }
+    }
+}
+
+impl From<Protocol> for String {
+    fn from(protocol: Protocol) -> Self {
+        match protocol {
+            Protocol::Http => String::from("http"),
+            Protocol::Other(string) => string,
+        }
+    }
+}
+
+#[test]
+fn proxy_struct() {
+    musli::macros::assert_roundtrip_eq!(full, Color(0x102030), json = r#"{"r":16,"g":32,"b":48}"#);
+
+    let bytes = musli::storage::to_vec(&Color(0x102030)).unwrap();
+    let rgb: Rgb = musli::storage::from_slice(&bytes).unwrap();
+    assert_eq!(
+        rgb,
+        Rgb {
+            r: 16,
+            g: 32,
+            b: 48
+        }
+    );
+}
+
+#[test]
+fn proxy_enum() {
+    musli::macros::assert_roundtrip_eq!(full, Protocol::Http, json = r#""http""#);
+
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Protocol::Other(String::from("ftp")),
+        json = r#""ftp""#
+    );
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "test")]
+
+use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Intervals {
+    range: Range<u32>,
+    inclusive: RangeInclusive<i64>,
+    from: RangeFrom<u8>,
+    to: RangeTo<u16>,
+    to_inclusive: RangeToInclusive<i32>,
+    full: RangeFull,
+    start: Bound<String>,
+    end: Bound<u64>,
+    unbounded: Bound<u64>,
+}
+
+fn intervals() -> Intervals {
+    Intervals {
+        range: 10..20,
+        inclusive: -5..=5,
+        from: 3..,
+        to: ..1000,
+        to_inclusive: ..=-1,
+        full: ..,
+        start: Bound::Included(String::from("key/a")),
+        end: Bound::Excluded(u64::MAX),
+        unbounded: Bound::Unbounded,
+    }
+}
+
+#[test]
+fn roundtrip() {
+    musli::macros::assert_roundtrip_eq!(full, intervals());
+}
+
+#[test]
+fn json_bound_names() {
+    let string = musli::json::to_string(&Bound::Included(1u32)).unwrap();
+    assert_eq!(string, "{\"included\":1}");
+
+    let actual: Bound<u32> = musli::json::from_str(&string).unwrap();
+    assert_eq!(actual, Bound::Included(1));
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "test")]
+
+use std::marker::PhantomData;
+
+use musli::reflect::{FieldMetadata, MetadataKind, Reflect};
+use musli::{Decode, Encode};
+
+#[derive(Encode, Decode)]
+#[musli(reflect)]
+struct Person<'a, T> {
+    name: &'a str,
+    #[musli(skip)]
+    cache: Vec<u8>,
+    marker: PhantomData<T>,
+}
+
+#[derive(Encode, Decode)]
+#[musli(reflect)]
+struct Pair(u32, String);
+
+#[derive(Encode, Decode)]
+#[musli(reflect, name_all = "name")]
+enum Message {
+    Ping,
+    #[musli(name_all = "name")]
+    Text {
+        body: String,
+    },
+    Data(Vec<u8>),
+}
+
+fn fields(fields: &[FieldMetadata]) -> Vec<(&str, usize, &str)> {
+    fields.iter().map(|f| (f.name, f.index, f.ty)).collect()
+}
+
+#[test]
+fn reflect_struct() {
+    let metadata = <Person<'_, u32>>::METADATA;
+    assert_eq!(metadata.name, "Person");
+
+    let MetadataKind::Struct { fields: f } = metadata.kind else {
+        panic!("expected a struct");
+    };
+
+    assert_eq!(
+        fields(f),
+        [
+            ("name", 0, "&'a str"),
+            ("cache", 1, "Vec<u8>"),
+            ("marker", 2, "PhantomData<T>"),
+        ]
+    );
+
+    let MetadataKind::Struct { fields: f } = Pair::METADATA.kind else {
+        panic!("expected a struct");
+    };
+
+    assert_eq!(fields(f), [("0", 0, "u32"), ("1", 1, "String")]);
+}
+
+#[test]
+fn reflect_enum() {
+    let metadata = Message::METADATA;
+    assert_eq!(metadata.name, "Message");
+
+    let MetadataKind::Enum { variants } = metadata.kind else {
+        panic!("expected an enum");
+    };
+
+    let names = variants
+        .iter()
+        .map(|v| (v.name, v.index))
+        .collect::<Vec<_>>();
+
+    assert_eq!(names, [("Ping", 0), ("Text", 1), ("Data", 2)]);
+    assert!(variants[0].fields.is_empty());
+    assert_eq!(fields(variants[1].fields), [("body", 0, "String")]);
+    assert_eq!(fields(variants[2].fields), [("0", 0, "Vec<u8>")]);
+}
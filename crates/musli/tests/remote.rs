@@ -0,0 +1,106 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+mod other {
+    #[derive(Debug, PartialEq)]
+    pub struct Point {
+        pub x: u32,
+        pub y: u32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum Shape {
+        Circle { radius: u32 },
+        Square(u32),
+        Empty,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Tagged<'a> {
+        pub tag: &'a str,
+        pub value: u32,
+    }
+}
+
+#[derive(Encode, Decode)]
+#[musli(remote = other::Point, name_all = "name")]
+struct PointDef {
+    x: u32,
+    y: u32,
+}
+
+#[derive(Encode, Decode)]
+#[musli(remote = other::Shape, name_all = "name")]
+enum ShapeDef {
+    #[musli(name_all = "name")]
+    Circle {
+        radius: u32,
+    },
+    Square(u32),
+    Empty,
+}
+
+#[derive(Encode, Decode)]
+#[musli(remote = other::Tagged, name_all = "name")]
+struct TaggedDef<'a> {
+    tag: &'a str,
+    value: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Drawing {
+    #[musli(with = PointDef)]
+    origin: other::Point,
+    #[musli(with = ShapeDef)]
+    shape: other::Shape,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Labelled<'a> {
+    #[musli(with = TaggedDef)]
+    tagged: other::Tagged<'a>,
+}
+
+#[test]
+fn remote_struct_and_enum() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Drawing {
+            origin: other::Point { x: 1, y: 2 },
+            shape: other::Shape::Circle { radius: 3 },
+        },
+        json = r#"{"origin":{"x":1,"y":2},"shape":{"Circle":{"radius":3}}}"#
+    );
+
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Drawing {
+            origin: other::Point { x: 0, y: 0 },
+            shape: other::Shape::Square(4),
+        }
+    );
+
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Drawing {
+            origin: other::Point { x: 0, y: 0 },
+            shape: other::Shape::Empty,
+        }
+    );
+}
+
+#[test]
+fn remote_borrowed() {
+    let value = Labelled {
+        tagged: other::Tagged {
+            tag: "answer",
+            value: 42,
+        },
+    };
+
+    let bytes = musli::storage::to_vec(&value).unwrap();
+    let actual: Labelled<'_> = musli::storage::from_slice(&bytes).unwrap();
+    assert_eq!(actual, value);
+}
@@ -0,0 +1,174 @@
+use musli::schema::{
+    self, Direction, Enum, Field, Format, IssueKind, Name, Schema, Struct, Variant,
+};
+
+fn person(fields: impl IntoIterator<Item = Field>) -> Schema {
+    Schema::Struct(Struct::new("Person", fields))
+}
+
+#[test]
+fn added_default_field() {
+    let old = person([Field::new("name", Schema::String)]);
+    let new = person([
+        Field::new("name", Schema::String),
+        Field::new("age", Schema::U32).with_default(),
+    ]);
+
+    for format in [Format::Wire, Format::Descriptive, Format::Json] {
+        assert!(schema::check(format, &old, &new).is_compatible());
+    }
+
+    let report = schema::check(Format::Storage, &old, &new);
+    assert!(!report.is_backward_compatible());
+    assert!(!report.is_forward_compatible());
+}
+
+#[test]
+fn added_required_field() {
+    let old = person([Field::new("name", Schema::String)]);
+    let new = person([
+        Field::new("name", Schema::String),
+        Field::new("age", Schema::U32),
+    ]);
+
+    let report = schema::check(Format::Wire, &old, &new);
+    assert!(report.is_forward_compatible());
+
+    let issues = report.issues();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].direction, Direction::Backward);
+    assert_eq!(issues[0].path, "Person");
+    assert_eq!(issues[0].kind, IssueKind::MissingField(Name::from("age")));
+}
+
+#[test]
+fn changed_field_type() {
+    let old = person([Field::new("age", Schema::U32)]);
+    let new = person([Field::new("age", Schema::String)]);
+
+    let report = schema::check(Format::Wire, &old, &new);
+    assert_eq!(report.issues().len(), 2);
+    assert_eq!(report.issues()[0].path, "Person.age");
+    assert_eq!(
+        report.issues()[0].kind,
+        IssueKind::TypeChanged {
+            encoded: "u32",
+            decoded: "string"
+        }
+    );
+}
+
+#[test]
+fn changed_tag_type() {
+    let old = person([Field::new(0usize, Schema::U32)]);
+    let new = person([Field::new("age", Schema::U32)]);
+
+    assert!(!schema::check(Format::Wire, &old, &new).is_compatible());
+}
+
+#[test]
+fn widened_integer() {
+    let old = person([Field::new("age", Schema::U32)]);
+    let new = person([Field::new("age", Schema::U64)]);
+
+    for format in [Format::Wire, Format::Descriptive, Format::Json] {
+        let report = schema::check(format, &old, &new);
+        assert!(report.is_backward_compatible(), "{format:?}");
+        assert!(!report.is_forward_compatible(), "{format:?}");
+    }
+
+    let report = schema::check(Format::Storage, &old, &new);
+    assert!(!report.is_backward_compatible());
+    assert!(!report.is_forward_compatible());
+}
+
+#[test]
+fn widened_pointer_sized_integer() {
+    let old = person([Field::new("age", Schema::U32)]);
+    let new = person([Field::new("age", Schema::Usize)]);
+
+    let report = schema::check(Format::Wire, &old, &new);
+    assert!(report.is_backward_compatible());
+    assert_eq!(report.is_forward_compatible(), usize::BITS <= 32);
+
+    let old = person([Field::new("age", Schema::I32)]);
+    let new = person([Field::new("age", Schema::Isize)]);
+
+    assert!(!schema::check(Format::Wire, &old, &new).is_backward_compatible());
+    assert!(schema::check(Format::Descriptive, &old, &new).is_backward_compatible());
+}
+
+#[test]
+fn added_variant() {
+    let old = Schema::Enum(Enum::new(
+        "Animal",
+        [Variant::new("Cat", Struct::new("Cat", []))],
+    ));
+
+    let new = Schema::Enum(Enum::new(
+        "Animal",
+        [
+            Variant::new("Cat", Struct::new("Cat", [])),
+            Variant::new("Dog", Struct::new("Dog", [])),
+        ],
+    ));
+
+    let report = schema::check(Format::Wire, &old, &new);
+    assert!(report.is_backward_compatible());
+    assert!(!report.is_forward_compatible());
+
+    let Schema::Enum(old) = old else {
+        unreachable!();
+    };
+
+    let old = Schema::Enum(old.with_fallback("Cat"));
+    assert!(schema::check(Format::Wire, &old, &new).is_compatible());
+}
+
+#[test]
+fn schema_roundtrip() {
+    let schema = person([
+        Field::new("name", Schema::String),
+        Field::new("tags", Schema::Sequence(Box::new(Schema::String))).with_default(),
+    ]);
+
+    let bytes = musli::wire::to_vec(&schema).unwrap();
+    let decoded: Schema = musli::wire::from_slice(&bytes).unwrap();
+    assert_eq!(decoded, schema);
+}
+
+#[test]
+fn generate_rust() {
+    let schema = person([
+        Field::new("name", Schema::String),
+        Field::new("userAge", Schema::Option(Box::new(Schema::U32))).with_default(),
+    ]);
+
+    let expected = r#"use musli::{Decode, Encode};
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+#[musli(name_type = str)]
+pub struct Person {
+    pub name: String,
+    #[musli(name = "userAge", default)]
+    pub user_age: Option<u32>,
+}
+"#;
+
+    assert_eq!(schema::to_rust(&schema), expected);
+}
+
+#[test]
+fn described() {
+    use musli::schema::Described;
+
+    assert_eq!(
+        <Vec<Option<u32>>>::schema(),
+        Schema::Sequence(Box::new(Schema::Option(Box::new(Schema::U32))))
+    );
+
+    assert_eq!(
+        <(u8, String)>::schema(),
+        Schema::Tuple(vec![Schema::U8, Schema::String])
+    );
+}
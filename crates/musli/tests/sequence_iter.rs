@@ -0,0 +1,76 @@
+#![cfg(feature = "test")]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use musli::de::SequenceDecoder;
+use musli::{Decode, Decoder};
+
+/// Sums a sequence of numbers without collecting it first.
+#[derive(Debug, PartialEq)]
+struct Sum {
+    count: usize,
+    total: u64,
+}
+
+impl<'de, M> Decode<'de, M> for Sum {
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_sequence(|seq| {
+            let mut count = 0;
+            let mut total = 0;
+
+            for value in seq.iter::<u32>() {
+                count += 1;
+                total += u64::from(value?);
+            }
+
+            Ok(Sum { count, total })
+        })
+    }
+}
+
+static FUSED: AtomicBool = AtomicBool::new(false);
+
+/// Checks that the iterator stops after the first error.
+struct Fused;
+
+impl<'de, M> Decode<'de, M> for Fused {
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_sequence(|seq| {
+            let mut iter = seq.iter::<u32>();
+            assert!(matches!(iter.next(), Some(Ok(1))));
+            assert!(matches!(iter.next(), Some(Err(..))));
+            assert!(iter.next().is_none());
+            assert!(iter.next().is_none());
+            FUSED.store(true, Ordering::SeqCst);
+            Ok(Fused)
+        })
+    }
+}
+
+#[test]
+fn sum() {
+    musli::macros::assert_decode_eq!(
+        full,
+        (1..=100).collect::<Vec<u32>>(),
+        Sum {
+            count: 100,
+            total: 5050,
+        }
+    );
+
+    musli::macros::assert_decode_eq!(full, Vec::<u32>::new(), Sum { count: 0, total: 0 });
+}
+
+#[test]
+fn stops_after_error() {
+    // The sequence is left partially decoded, so whether decoding as a whole
+    // succeeds is up to the format.
+    let _ = musli::json::from_slice::<Fused>(&b"[1, \"two\", 3]"[..]);
+    assert!(FUSED.load(Ordering::SeqCst));
+}
@@ -296,3 +296,71 @@ fn musli_json() {
 fn musli_value() {
     build_test!(value);
 }
+
+#[test]
+fn serde_wrapper() {
+    use musli::serde::Serde;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Address {
+        street: String,
+        tags: HashMap<String, u32>,
+    }
+
+    let address = Address {
+        street: String::from("Main St."),
+        tags: HashMap::from([(String::from("zip"), 12345)]),
+    };
+
+    let bytes = musli::wire::to_vec(&Serde(&address)).unwrap();
+    let Serde(actual) = musli::wire::from_slice::<Serde<Address>>(&bytes).unwrap();
+    assert_eq!(actual, address);
+
+    let bytes = musli::storage::to_vec(&Serde(&address)).unwrap();
+    let Serde(actual) = musli::storage::from_slice::<Serde<Address>>(&bytes).unwrap();
+    assert_eq!(actual, address);
+}
+
+#[test]
+fn serde_unsized_collections() {
+    use musli::serde::Serde;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Inner {
+        zip: u32,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Address {
+        street: String,
+        #[serde(flatten)]
+        inner: Inner,
+    }
+
+    struct Iter;
+
+    impl Serialize for Iter {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serializer.collect_seq((1u32..=5).filter(|n| n % 2 == 1))
+        }
+    }
+
+    let address = Address {
+        street: String::from("Main St."),
+        inner: Inner { zip: 12345 },
+    };
+
+    let bytes = musli::wire::to_vec(&Serde(&address)).unwrap();
+    let Serde(actual) = musli::wire::from_slice::<Serde<Address>>(&bytes).unwrap();
+    assert_eq!(actual, address);
+
+    let json = musli::json::to_string(&Serde(&address)).unwrap();
+    assert_eq!(json, r#"{"street":"Main St.","zip":12345}"#);
+
+    let bytes = musli::wire::to_vec(&Serde(&Iter)).unwrap();
+    let actual = musli::wire::from_slice::<Vec<u32>>(&bytes).unwrap();
+    assert_eq!(actual, [1, 3, 5]);
+}
@@ -18,6 +18,35 @@ pub struct AllSkipped {
     complex_field: Option<Inner>,
 }
 
+/// Runtime-only state which can't be encoded or decoded.
+#[derive(Debug, Default, PartialEq)]
+struct Handle {
+    id: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Connection {
+    address: String,
+    #[musli(skip)]
+    handle: Handle,
+    #[musli(skip, default = skip_cache)]
+    cache: Vec<u32>,
+    port: u16,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+enum Resource {
+    File {
+        path: String,
+        #[musli(skip)]
+        handle: Handle,
+    },
+}
+
+fn skip_cache() -> Vec<u32> {
+    vec![1, 2, 3]
+}
+
 fn skip_default() -> u32 {
     42
 }
@@ -53,3 +82,35 @@ fn skip() {
         json = r#"{}"#,
     );
 }
+
+#[test]
+fn skip_runtime_state() {
+    musli::macros::assert_decode_eq!(
+        full,
+        Connection {
+            address: String::from("localhost"),
+            handle: Handle { id: Some(7) },
+            cache: vec![4, 5],
+            port: 8080,
+        },
+        Connection {
+            address: String::from("localhost"),
+            handle: Handle::default(),
+            cache: vec![1, 2, 3],
+            port: 8080,
+        },
+        json = r#"{"address":"localhost","port":8080}"#,
+    );
+
+    musli::macros::assert_decode_eq!(
+        full,
+        Resource::File {
+            path: String::from("/tmp/file"),
+            handle: Handle { id: Some(7) },
+        },
+        Resource::File {
+            path: String::from("/tmp/file"),
+            handle: Handle::default(),
+        },
+    );
+}
@@ -12,6 +12,22 @@ pub struct SkipSerializeOuter {
     pub inner: Option<SkipSerializeInner>,
 }
 
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Sparse {
+    pub name: String,
+    #[musli(default, skip_encoding_if = Vec::is_empty)]
+    pub tags: Vec<String>,
+    #[musli(default, skip_encoding_if = Option::is_none)]
+    pub parent: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Dense {
+    pub name: String,
+    pub tags: Vec<String>,
+    pub parent: Option<u32>,
+}
+
 #[test]
 fn skip_serializing_if_outer() {
     musli::macros::assert_roundtrip_eq!(
@@ -32,3 +48,42 @@ fn skip_serializing_if_outer() {
         json = r#"{"flag":false}"#,
     );
 }
+
+#[test]
+fn skip_empty_collections() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Sparse {
+            name: String::from("root"),
+            tags: Vec::new(),
+            parent: None,
+        },
+        json = r#"{"name":"root"}"#,
+    );
+
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Sparse {
+            name: String::from("leaf"),
+            tags: vec![String::from("green")],
+            parent: Some(1),
+        },
+        json = r#"{"name":"leaf","tags":["green"],"parent":1}"#,
+    );
+
+    let sparse = musli::descriptive::to_vec(&Sparse {
+        name: String::from("root"),
+        tags: Vec::new(),
+        parent: None,
+    })
+    .unwrap();
+
+    let dense = musli::descriptive::to_vec(&Dense {
+        name: String::from("root"),
+        tags: Vec::new(),
+        parent: None,
+    })
+    .unwrap();
+
+    assert!(sparse.len() < dense.len());
+}
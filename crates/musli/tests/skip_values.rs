@@ -0,0 +1,136 @@
+#![cfg(feature = "test")]
+
+use std::collections::BTreeMap;
+
+use musli::context;
+use musli::de::{EntryDecoder, MapDecoder, SequenceDecoder};
+use musli::{Context, Decode, Decoder, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Nested {
+    values: Vec<Option<f64>>,
+    map: BTreeMap<String, (u8, String)>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Record {
+    name: String,
+    tags: Vec<String>,
+    nested: Nested,
+    id: u32,
+    trailer: bool,
+}
+
+fn record() -> Record {
+    let mut map = BTreeMap::new();
+    map.insert(String::from("first"), (1, String::from("one")));
+    map.insert(String::from("second"), (2, String::from("two")));
+
+    Record {
+        name: String::from("Aristotle"),
+        tags: vec![String::from("a"), String::from("b")],
+        nested: Nested {
+            values: vec![Some(1.5), None, Some(-2.0)],
+            map,
+        },
+        id: 42,
+        trailer: true,
+    }
+}
+
+/// Projection which only decodes the `id` field, skipping everything else.
+#[derive(Debug, PartialEq)]
+struct Id(u32);
+
+impl<'de, M> Decode<'de, M> for Id {
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_map(|map| {
+            let mut id = None;
+
+            while let Some(mut entry) = map.decode_entry()? {
+                let found = entry
+                    .decode_key()?
+                    .decode_unsized(|key: &str| Ok(key == "id"))?;
+
+                if found {
+                    id = Some(entry.decode_value()?.decode()?);
+                } else {
+                    entry.decode_value()?.skip()?;
+                }
+            }
+
+            match id {
+                Some(id) => Ok(Id(id)),
+                None => Err(cx.message("Missing id")),
+            }
+        })
+    }
+}
+
+/// Projection which only decodes the second element of a sequence.
+#[derive(Debug, PartialEq)]
+struct Second(String);
+
+impl<'de, M> Decode<'de, M> for Second {
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_sequence(|seq| {
+            let Some(first) = seq.try_decode_next()? else {
+                return Err(cx.message("Missing first element"));
+            };
+
+            first.skip()?;
+
+            let Some(second) = seq.try_decode_next()? else {
+                return Err(cx.message("Missing second element"));
+            };
+
+            let second = second.decode()?;
+
+            while let Some(rest) = seq.try_decode_next()? {
+                rest.skip()?;
+            }
+
+            Ok(Second(second))
+        })
+    }
+}
+
+#[test]
+fn skip_map_entries() {
+    musli::macros::assert_decode_eq!(upgrade_stable, record(), Id(42));
+}
+
+#[test]
+fn skip_sequence_elements() {
+    musli::macros::assert_decode_eq!(upgrade_stable, ["a", "b", "c"], Second(String::from("b")));
+}
+
+#[test]
+fn value() {
+    let value = musli::value::encode(record()).unwrap();
+    let id: Id = musli::value::decode(&value).unwrap();
+    assert_eq!(id, Id(42));
+}
+
+#[test]
+fn storage_is_unsupported() {
+    let data = musli::storage::to_vec(&record()).unwrap();
+
+    let cx = context::new();
+    let result = musli::storage::DEFAULT.from_slice_with::<_, Id>(&cx, &data);
+    assert!(result.is_err());
+
+    let error = cx.errors().next().expect("expected an error");
+    assert!(
+        error.to_string().contains("Skipping is not supported"),
+        "{error}"
+    );
+}
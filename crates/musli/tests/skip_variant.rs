@@ -0,0 +1,64 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+enum State {
+    Idle,
+    #[musli(skip)]
+    Poisoned,
+    Running,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+enum Event {
+    #[musli(name_all = "name")]
+    Click {
+        x: u32,
+        y: u32,
+    },
+    #[musli(skip)]
+    Internal(Vec<u8>),
+    Key(char),
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+enum StateV2 {
+    Idle,
+    Poisoned,
+    Running,
+}
+
+#[test]
+fn skip_variant() {
+    musli::macros::assert_roundtrip_eq!(full, State::Idle, json = r#""Idle""#);
+    musli::macros::assert_roundtrip_eq!(full, State::Running, json = r#""Running""#);
+    musli::macros::assert_roundtrip_eq!(full, Event::Click { x: 1, y: 2 });
+    musli::macros::assert_roundtrip_eq!(full, Event::Key('a'));
+}
+
+#[test]
+fn skip_variant_encode_error() {
+    let error = musli::json::to_string(&State::Poisoned).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("skipped variant State::Poisoned"));
+
+    let error = musli::json::to_string(&Event::Internal(vec![1, 2, 3])).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("skipped variant Event::Internal"));
+}
+
+#[test]
+fn skip_variant_never_decoded() {
+    let json = musli::json::to_string(&StateV2::Poisoned).unwrap();
+    assert!(musli::json::from_str::<State>(&json).is_err());
+
+    let json = musli::json::to_string(&StateV2::Running).unwrap();
+    let state: State = musli::json::from_str(&json).unwrap();
+    assert_eq!(state, State::Running);
+}
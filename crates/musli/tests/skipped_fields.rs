@@ -0,0 +1,181 @@
+use std::cell::RefCell;
+use std::fmt;
+
+use musli::alloc::System;
+use musli::context::{ContextError, Same};
+use musli::{Context, Decode, Encode};
+
+/// A context which records the fields which were skipped.
+struct Telemetry<M, E>
+where
+    E: ContextError,
+{
+    inner: Same<M, E, &'static System>,
+    skipped: RefCell<Vec<(&'static str, String)>>,
+}
+
+impl<M, E> Telemetry<M, E>
+where
+    E: ContextError,
+{
+    fn new() -> Self {
+        Self {
+            inner: Same::new(),
+            skipped: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl<M, E> Context for Telemetry<M, E>
+where
+    M: 'static,
+    E: ContextError,
+{
+    type Mode = M;
+    type Error = E;
+    type Mark = ();
+    type Allocator = &'static System;
+    type String<'this> = musli::alloc::String<'this, &'static System> where Self: 'this;
+
+    fn clear(&self) {
+        self.skipped.borrow_mut().clear();
+    }
+
+    fn alloc(&self) -> &Self::Allocator {
+        self.inner.alloc()
+    }
+
+    fn collect_string<T>(&self, value: &T) -> Result<Self::String<'_>, Self::Error>
+    where
+        T: ?Sized + fmt::Display,
+    {
+        self.inner.collect_string(value)
+    }
+
+    fn custom<T>(&self, error: T) -> Self::Error
+    where
+        T: 'static + Send + Sync + std::error::Error,
+    {
+        self.inner.custom(error)
+    }
+
+    fn message<T>(&self, message: T) -> Self::Error
+    where
+        T: fmt::Display,
+    {
+        self.inner.message(message)
+    }
+
+    fn skipped_field<T>(&self, name: &'static str, tag: &T)
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.skipped.borrow_mut().push((name, format!("{tag:?}")));
+    }
+}
+
+#[derive(Encode)]
+#[musli(name_all = "name")]
+struct Version2 {
+    name: String,
+    age: u32,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Decode)]
+#[musli(name_all = "name")]
+struct Version1 {
+    name: String,
+}
+
+#[derive(Encode)]
+struct IndexedVersion2 {
+    name: String,
+    age: u32,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Decode)]
+struct IndexedVersion1 {
+    name: String,
+}
+
+#[test]
+fn wire() {
+    let data = musli::wire::to_vec(&IndexedVersion2 {
+        name: String::from("Aristotle"),
+        age: 61,
+        tags: vec![String::from("philosopher")],
+    })
+    .unwrap();
+
+    let cx = Telemetry::<_, musli::wire::Error>::new();
+    let value: IndexedVersion1 = musli::wire::DEFAULT.from_slice_with(&cx, &data).unwrap();
+
+    assert_eq!(
+        value,
+        IndexedVersion1 {
+            name: String::from("Aristotle")
+        }
+    );
+
+    assert_eq!(
+        *cx.skipped.borrow(),
+        [
+            ("IndexedVersion1", String::from("1")),
+            ("IndexedVersion1", String::from("2"))
+        ]
+    );
+}
+
+#[test]
+fn json() {
+    let json = musli::json::to_string(&Version2 {
+        name: String::from("Aristotle"),
+        age: 61,
+        tags: vec![String::from("philosopher")],
+    })
+    .unwrap();
+
+    let cx = Telemetry::<_, musli::json::Error>::new();
+    let value: Version1 = musli::json::DEFAULT.from_str_with(&cx, &json).unwrap();
+
+    assert_eq!(
+        value,
+        Version1 {
+            name: String::from("Aristotle")
+        }
+    );
+
+    assert_eq!(
+        *cx.skipped.borrow(),
+        [
+            ("Version1", String::from("\"age\"")),
+            ("Version1", String::from("\"tags\""))
+        ]
+    );
+}
+
+#[test]
+fn strict_wire() {
+    const ENCODING: musli::wire::Encoding = musli::wire::Encoding::new().with_strict(true);
+
+    let data = ENCODING
+        .to_vec(&IndexedVersion2 {
+            name: String::from("Aristotle"),
+            age: 61,
+            tags: Vec::new(),
+        })
+        .unwrap();
+
+    let cx = Telemetry::<_, musli::wire::Error>::new();
+    assert!(ENCODING
+        .from_slice_with::<_, IndexedVersion1>(&cx, &data)
+        .is_err());
+
+    // The field is reported before the decoder refuses to skip it.
+    assert_eq!(
+        *cx.skipped.borrow(),
+        [("IndexedVersion1", String::from("1"))]
+    );
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "test")]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Model {
+    boxed: Box<u32>,
+    boxed_str: Box<str>,
+    boxed_slice: Box<[u32]>,
+    shared: Arc<String>,
+    shared_str: Arc<str>,
+    shared_slice: Arc<[String]>,
+    local: Rc<u64>,
+    local_str: Rc<str>,
+    local_slice: Rc<[u8]>,
+    #[musli(bytes)]
+    local_bytes: Rc<[u8]>,
+}
+
+fn model() -> Model {
+    Model {
+        boxed: Box::new(42),
+        boxed_str: "boxed".into(),
+        boxed_slice: vec![1, 2, 3].into(),
+        shared: Arc::new(String::from("shared")),
+        shared_str: "shared str".into(),
+        shared_slice: vec![String::from("a"), String::from("b")].into(),
+        local: Rc::new(u64::MAX),
+        local_str: "".into(),
+        local_slice: vec![4, 5, 6].into(),
+        local_bytes: vec![7, 8, 9].into(),
+    }
+}
+
+#[test]
+fn roundtrip() {
+    musli::macros::assert_roundtrip_eq!(full, model());
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "test")]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use musli::context;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Event {
+    timeout: Duration,
+    elapsed: Duration,
+    at: SystemTime,
+    before: SystemTime,
+    epoch: SystemTime,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct RawDuration {
+    timeout: (u64, u32),
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Timeout {
+    timeout: Duration,
+}
+
+fn event() -> Event {
+    Event {
+        timeout: Duration::new(u64::MAX, 999_999_999),
+        elapsed: Duration::from_millis(1500),
+        at: UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789),
+        before: UNIX_EPOCH - Duration::new(86_400, 250_000_000),
+        epoch: UNIX_EPOCH,
+    }
+}
+
+#[test]
+fn roundtrip() {
+    musli::macros::assert_roundtrip_eq!(full, event());
+}
+
+#[test]
+fn nanos_out_of_range() {
+    let raw = RawDuration {
+        timeout: (1, 1_000_000_000),
+    };
+
+    let cx = context::new();
+    let data = musli::storage::to_vec(&raw).unwrap();
+    let result = musli::storage::DEFAULT.from_slice_with::<_, Timeout>(&cx, &data);
+    assert!(result.is_err());
+    let binary = cx.errors().next().expect("expected an error").to_string();
+
+    let cx = context::new();
+    let data = musli::json::to_vec(&raw).unwrap();
+    let result = musli::json::DEFAULT.from_slice_with::<_, Timeout>(&cx, &data);
+    assert!(result.is_err());
+    let text = cx.errors().next().expect("expected an error").to_string();
+
+    for error in [binary, text] {
+        assert!(
+            error.contains("Nanoseconds `1000000000` out of range"),
+            "{error}"
+        );
+    }
+}
+
+#[test]
+fn before_epoch_representation() {
+    let time = UNIX_EPOCH - Duration::new(1, 250_000_000);
+    let data = musli::storage::to_vec(&time).unwrap();
+    let raw: (i64, u32) = musli::storage::from_slice(&data).unwrap();
+    assert_eq!(raw, (-2, 750_000_000));
+}
@@ -0,0 +1,49 @@
+use musli::io::{AsyncReader, Tokio};
+use musli::wire::Encoding;
+use musli::{Decode, Encode};
+
+const ENCODING: Encoding = Encoding::new();
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Message {
+    id: u32,
+    body: String,
+}
+
+fn messages() -> Vec<Message> {
+    (0..16)
+        .map(|id| Message {
+            id,
+            body: format!("Message #{id}"),
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn duplex() {
+    // A small buffer makes sure that the writer has to wait for the reader.
+    let (client, server) = tokio::io::duplex(16);
+
+    let writer = tokio::spawn(async move {
+        let mut client = Tokio::new(client);
+
+        for message in messages() {
+            ENCODING.encode_async(&mut client, &message).await.unwrap();
+        }
+    });
+
+    let mut reader = AsyncReader::new(Tokio::new(server));
+
+    for expected in messages() {
+        let actual: Message = ENCODING.decode_async(&mut reader).await.unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    writer.await.unwrap();
+
+    assert!(reader.buffer().is_empty());
+    assert!(ENCODING
+        .decode_async::<_, Message>(&mut reader)
+        .await
+        .is_err());
+}
@@ -55,3 +55,23 @@ fn trace_no_std() {
 
     panic!("Expected decoding to error");
 }
+
+#[test]
+fn trace_no_std_out_of_memory() {
+    let mut buf = ArrayBuffer::<0>::with_size();
+    let alloc = Slice::new(&mut buf);
+    let cx = context::with_alloc(&alloc);
+
+    let encoding = musli::storage::Encoding::new();
+
+    let Err(..) = encoding.from_slice_with::<_, (u32, u64)>(&cx, &[1]) else {
+        panic!("Expected decoding to error");
+    };
+
+    assert_eq!(cx.errors().count(), 0);
+    assert_eq!(cx.dropped(), 1);
+    assert_eq!(
+        cx.report().to_string(),
+        "*one error dropped due to running out of memory*\n"
+    );
+}
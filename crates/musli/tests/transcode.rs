@@ -0,0 +1,55 @@
+use musli::value::Value;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+enum Shape {
+    Circle { radius: f32 },
+    Square(u32),
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Drawing {
+    title: String,
+    author: Option<String>,
+    shapes: Vec<Shape>,
+    bytes: Vec<u8>,
+}
+
+fn drawing() -> Drawing {
+    Drawing {
+        title: String::from("Sketch"),
+        author: None,
+        shapes: vec![Shape::Circle { radius: 1.5 }, Shape::Square(4)],
+        bytes: vec![1, 2, 3],
+    }
+}
+
+#[test]
+fn descriptive_to_json() {
+    let expected = drawing();
+    let bytes = musli::descriptive::to_vec(&expected).unwrap();
+
+    let json = musli::json::to_string(&musli::descriptive::DEFAULT.transcode(&bytes)).unwrap();
+    let actual: Drawing = musli::json::from_str(&json).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_to_descriptive() {
+    let expected = drawing();
+    let json = musli::json::to_vec(&expected).unwrap();
+
+    // JSON arrays and objects don't know their length, so they're buffered.
+    let bytes = musli::descriptive::to_vec(&musli::json::DEFAULT.transcode(&json)).unwrap();
+    let actual: Value = musli::descriptive::from_slice(&bytes).unwrap();
+    let direct: Value = musli::json::from_slice(&json).unwrap();
+    assert_eq!(actual, direct);
+}
+
+#[test]
+fn storage_is_not_self_descriptive() {
+    let bytes = musli::storage::to_vec(&drawing()).unwrap();
+    assert!(musli::json::to_string(&musli::storage::DEFAULT.transcode(&bytes)).is_err());
+}
@@ -1,5 +1,7 @@
 #![cfg(feature = "test")]
 
+use std::marker::PhantomData;
+
 use musli::{Decode, Encode};
 
 #[derive(Debug, PartialEq, Encode, Decode)]
@@ -12,6 +14,14 @@ struct TransparentStruct {
 #[musli(transparent)]
 struct TransparentTuple(String);
 
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(transparent)]
+struct Id<T> {
+    value: u64,
+    #[musli(skip)]
+    _marker: PhantomData<T>,
+}
+
 #[derive(Debug, PartialEq, Encode, Decode)]
 enum TransparentEnum {
     NotTransparent {
@@ -66,3 +76,27 @@ fn transparent_enum() {
     musli::macros::assert_roundtrip_eq!(full, TransparentEnum::Transparent(42));
     musli::macros::assert_roundtrip_eq!(full, TransparentEnum::NotTransparent { a: 1, b: 2 });
 }
+
+#[test]
+fn transparent_skipped_fields() {
+    #[derive(Debug, PartialEq)]
+    struct User;
+
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Id::<User> {
+            value: 42,
+            _marker: PhantomData,
+        }
+    );
+
+    musli::macros::assert_decode_eq!(
+        full,
+        Id::<User> {
+            value: 42,
+            _marker: PhantomData,
+        },
+        42u64,
+        json = r#"42"#,
+    );
+}
@@ -10,6 +10,72 @@ fn tuples() {
     musli::macros::assert_roundtrip_eq!(full, (1, 2, 3, 4));
 }
 
+type Largest = (
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    f32,
+    f64,
+    String,
+    Option<u32>,
+    Vec<u32>,
+    bool,
+);
+
+macro_rules! largest_tuple {
+    ($name:ident, $what:ident) => {
+        #[test]
+        fn $name() {
+            let value: Largest = (
+                1,
+                2,
+                3,
+                4,
+                5,
+                -6,
+                -7,
+                -8,
+                -9,
+                -10,
+                11.5,
+                12.5,
+                String::from("13"),
+                Some(14),
+                vec![15],
+                true,
+            );
+
+            let data = musli::$what::to_vec(&value).unwrap();
+            let actual: Largest = musli::$what::from_slice(&data).unwrap();
+
+            // Standard traits like `PartialEq` are only implemented for tuples
+            // with up to 12 elements, so compare the two halves separately.
+            let (a0, a1, a2, a3, a4, a5, a6, a7, a8, a9, a10, a11, a12, a13, a14, a15) = actual;
+            let (e0, e1, e2, e3, e4, e5, e6, e7, e8, e9, e10, e11, e12, e13, e14, e15) = value;
+            assert_eq!(
+                (a0, a1, a2, a3, a4, a5, a6, a7),
+                (e0, e1, e2, e3, e4, e5, e6, e7)
+            );
+            assert_eq!(
+                (a8, a9, a10, a11, a12, a13, a14, a15),
+                (e8, e9, e10, e11, e12, e13, e14, e15)
+            );
+        }
+    };
+}
+
+largest_tuple!(largest_wire, wire);
+largest_tuple!(largest_storage, storage);
+largest_tuple!(largest_descriptive, descriptive);
+largest_tuple!(largest_json, json);
+
 #[derive(Debug, PartialEq, Encode, Decode)]
 struct TupleStruct(u32, u32);
 
@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+
+use musli::de::{SizeHint, TypeHint};
+use musli::{Decode, Decoder, Encode};
+
+/// Records the type hint of a value, and skips over it.
+#[derive(Debug, PartialEq)]
+struct Hint(TypeHint);
+
+impl<'de, M> Decode<'de, M> for Hint {
+    fn decode<D>(_: &D::Cx, mut decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        let hint = decoder.type_hint()?;
+        decoder.skip()?;
+        Ok(Hint(hint))
+    }
+}
+
+#[derive(Encode)]
+struct Everything {
+    unit: (),
+    boolean: bool,
+    character: char,
+    number: u32,
+    float: f64,
+    string: String,
+    #[musli(bytes)]
+    bytes: Vec<u8>,
+    option: Option<u32>,
+    sequence: Vec<u32>,
+    map: BTreeMap<String, u32>,
+    variant: Variant,
+}
+
+#[derive(Encode)]
+enum Variant {
+    Value(u32),
+}
+
+#[derive(Debug, PartialEq, Decode)]
+struct Hints {
+    unit: Hint,
+    boolean: Hint,
+    character: Hint,
+    number: Hint,
+    float: Hint,
+    string: Hint,
+    bytes: Hint,
+    option: Hint,
+    sequence: Hint,
+    map: Hint,
+    variant: Hint,
+}
+
+fn everything() -> Everything {
+    let mut map = BTreeMap::new();
+    map.insert(String::from("a"), 1);
+
+    Everything {
+        unit: (),
+        boolean: true,
+        character: 'a',
+        number: 42,
+        float: 1.5,
+        string: String::from("hello"),
+        bytes: vec![1, 2, 3],
+        option: Some(1),
+        sequence: vec![1, 2, 3],
+        map,
+        variant: Variant::Value(1),
+    }
+}
+
+#[test]
+fn descriptive() {
+    let data = musli::descriptive::to_vec(&everything()).unwrap();
+    let hints: Hints = musli::descriptive::from_slice(&data).unwrap();
+
+    assert_eq!(
+        hints,
+        Hints {
+            unit: Hint(TypeHint::Empty),
+            boolean: Hint(TypeHint::Bool),
+            character: Hint(TypeHint::Char),
+            number: Hint(TypeHint::Number),
+            float: Hint(TypeHint::Number),
+            string: Hint(TypeHint::String(SizeHint::exact(5))),
+            bytes: Hint(TypeHint::Bytes(SizeHint::exact(3))),
+            option: Hint(TypeHint::Option),
+            sequence: Hint(TypeHint::Sequence(SizeHint::exact(3))),
+            map: Hint(TypeHint::Map(SizeHint::exact(1))),
+            variant: Hint(TypeHint::Variant),
+        }
+    );
+}
+
+#[test]
+fn json() {
+    let data = musli::json::to_vec(&everything()).unwrap();
+    let hints: Hints = musli::json::from_slice(&data).unwrap();
+
+    assert_eq!(
+        hints,
+        Hints {
+            unit: Hint(TypeHint::Empty),
+            boolean: Hint(TypeHint::Bool),
+            character: Hint(TypeHint::String(SizeHint::any())),
+            number: Hint(TypeHint::Number),
+            float: Hint(TypeHint::Number),
+            string: Hint(TypeHint::String(SizeHint::any())),
+            bytes: Hint(TypeHint::Sequence(SizeHint::any())),
+            option: Hint(TypeHint::Number),
+            sequence: Hint(TypeHint::Sequence(SizeHint::any())),
+            map: Hint(TypeHint::Map(SizeHint::any())),
+            variant: Hint(TypeHint::Map(SizeHint::any())),
+        }
+    );
+}
+
+#[test]
+fn value() {
+    let value = musli::value::encode(everything()).unwrap();
+    let hints: Hints = musli::value::decode(&value).unwrap();
+
+    assert_eq!(hints.unit, Hint(TypeHint::Empty));
+    assert_eq!(hints.number, Hint(TypeHint::Number));
+    assert_eq!(hints.string, Hint(TypeHint::String(SizeHint::exact(5))));
+    assert_eq!(hints.option, Hint(TypeHint::Option));
+    assert_eq!(hints.sequence, Hint(TypeHint::Sequence(SizeHint::exact(3))));
+    assert_eq!(hints.map, Hint(TypeHint::Map(SizeHint::exact(1))));
+    assert_eq!(hints.variant, Hint(TypeHint::Variant));
+}
+
+#[test]
+fn storage_is_unknown() {
+    let data = musli::storage::to_vec(&42u32).unwrap();
+
+    struct Probe(TypeHint);
+
+    impl<'de, M> Decode<'de, M> for Probe {
+        fn decode<D>(_: &D::Cx, mut decoder: D) -> Result<Self, D::Error>
+        where
+            D: Decoder<'de, Mode = M>,
+        {
+            let hint = decoder.type_hint()?;
+            decoder.decode::<u32>()?;
+            Ok(Probe(hint))
+        }
+    }
+
+    let Probe(hint) = musli::storage::from_slice(&data).unwrap();
+    assert_eq!(hint, TypeHint::Unknown);
+}
@@ -0,0 +1,18 @@
+use musli::{Decode, Encode};
+
+#[derive(Encode, Decode)]
+struct Owned {
+    #[musli(borrow)]
+    name: String,
+}
+
+#[derive(Encode, Decode)]
+struct Mixed<'a, 'b> {
+    #[musli(borrow)]
+    first: &'a str,
+    #[musli(borrow)]
+    second: &'b str,
+}
+
+fn main() {
+}
@@ -0,0 +1,10 @@
+use musli::{Encode, Decode};
+
+#[derive(Encode, Decode)]
+#[musli(name_all = "discriminant")]
+struct Struct {
+    field: u32,
+}
+
+fn main() {
+}
@@ -18,5 +18,13 @@ enum Enum2 {
     Fallback2,
 }
 
+/// Skipped fallback variant.
+#[derive(Encode, Decode)]
+enum Enum3 {
+    Variant,
+    #[musli(skip, default)]
+    Fallback,
+}
+
 fn main() {
 }
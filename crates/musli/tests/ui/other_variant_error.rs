@@ -0,0 +1,11 @@
+use musli::{Encode, Decode};
+
+#[derive(Encode, Decode)]
+enum Enum {
+    Variant,
+    #[musli(other)]
+    Unknown,
+}
+
+fn main() {
+}
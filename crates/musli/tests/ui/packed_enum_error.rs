@@ -0,0 +1,21 @@
+use musli::{Decode, Encode};
+
+#[derive(Encode, Decode)]
+#[musli(packed)]
+enum Command {
+    Reset,
+    Move { x: u8, y: u8 },
+    #[musli(default)]
+    Unknown,
+}
+
+#[derive(Encode, Decode)]
+#[musli(packed)]
+enum Wrapper {
+    Reset,
+    #[musli(transparent)]
+    Value(u32),
+}
+
+fn main() {
+}
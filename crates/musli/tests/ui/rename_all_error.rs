@@ -0,0 +1,10 @@
+use musli::{Encode, Decode};
+
+#[derive(Encode, Decode)]
+#[musli(rename_all = "camelCase")]
+struct Struct {
+    field_name: u32,
+}
+
+fn main() {
+}
@@ -0,0 +1,16 @@
+use musli::{Decode, Encode};
+
+#[derive(Encode, Decode)]
+#[musli(upgrade = {1 => Config::from_v1})]
+struct Config {
+    name: String,
+}
+
+#[derive(Encode, Decode)]
+#[musli(version = 2, upgrade = {2 => Other::from_v2})]
+struct Other {
+    name: String,
+}
+
+fn main() {
+}
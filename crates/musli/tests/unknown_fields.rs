@@ -0,0 +1,102 @@
+#![cfg(feature = "test")]
+
+use std::collections::BTreeMap;
+
+use musli::value::Value;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Version2 {
+    name: String,
+    age: u32,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct Proxy {
+    name: String,
+    #[musli(unknown_fields)]
+    unknown: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Version2Indexed {
+    name: String,
+    age: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct ProxyIndexed {
+    name: String,
+    #[musli(unknown_fields)]
+    unknown: BTreeMap<usize, Value>,
+}
+
+#[test]
+fn unknown_fields_json() {
+    let json = musli::json::to_string(&Version2 {
+        name: String::from("Aristotle"),
+        age: 62,
+        tags: vec![String::from("philosopher")],
+    })
+    .unwrap();
+
+    let proxy: Proxy = musli::json::from_str(&json).unwrap();
+    assert_eq!(proxy.name, "Aristotle");
+    assert_eq!(proxy.unknown.len(), 2);
+    assert!(proxy.unknown.contains_key("age"));
+    assert!(proxy.unknown.contains_key("tags"));
+
+    let forwarded = musli::json::to_string(&proxy).unwrap();
+    assert_eq!(forwarded, json);
+
+    let actual: Version2 = musli::json::from_str(&forwarded).unwrap();
+
+    assert_eq!(
+        actual,
+        Version2 {
+            name: String::from("Aristotle"),
+            age: 62,
+            tags: vec![String::from("philosopher")],
+        }
+    );
+}
+
+#[test]
+fn unknown_fields_empty() {
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        Proxy {
+            name: String::from("Aristotle"),
+            unknown: BTreeMap::new(),
+        },
+        json = r#"{"name":"Aristotle"}"#
+    };
+}
+
+#[test]
+fn unknown_fields_indexed() {
+    let bytes = musli::descriptive::to_vec(&Version2Indexed {
+        name: String::from("Aristotle"),
+        age: 62,
+    })
+    .unwrap();
+
+    let proxy: ProxyIndexed = musli::descriptive::from_slice(&bytes).unwrap();
+    assert_eq!(proxy.name, "Aristotle");
+    assert_eq!(proxy.unknown.keys().copied().collect::<Vec<_>>(), [1]);
+
+    let forwarded = musli::descriptive::to_vec(&proxy).unwrap();
+
+    let actual: Version2Indexed = musli::descriptive::from_slice(&forwarded).unwrap();
+
+    assert_eq!(
+        actual,
+        Version2Indexed {
+            name: String::from("Aristotle"),
+            age: 62,
+        }
+    );
+}
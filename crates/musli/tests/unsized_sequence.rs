@@ -0,0 +1,102 @@
+use musli::{Decode, Decoder, Encode, Encoder};
+
+/// Encodes the items of an iterator without knowing how many there are.
+#[derive(Clone)]
+struct Stream<I>(I);
+
+impl<M, I> Encode<M> for Stream<I>
+where
+    I: Clone + Iterator,
+    I::Item: Encode<M>,
+{
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_sequence_iter(self.0.clone())
+    }
+}
+
+/// Skips over whatever value is being decoded.
+struct Skipped;
+
+impl<'de, M> Decode<'de, M> for Skipped {
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.skip()?;
+        Ok(Skipped)
+    }
+}
+
+fn evens() -> Stream<impl Clone + Iterator<Item = u32>> {
+    Stream((0..100u32).filter(|n| n % 2 == 0))
+}
+
+#[test]
+fn wire() {
+    let data = musli::wire::to_vec(&evens()).unwrap();
+    let expected = (0..100u32).filter(|n| n % 2 == 0).collect::<Vec<_>>();
+
+    let value: Vec<u32> = musli::wire::from_slice(&data).unwrap();
+    assert_eq!(value, expected);
+
+    // An iterator which knows its length is length-prefixed as usual.
+    let data = musli::wire::to_vec(&Stream(expected.iter())).unwrap();
+    assert_eq!(data, musli::wire::to_vec(&expected).unwrap());
+}
+
+#[test]
+fn wire_nested() {
+    let nested = Stream((0..8u32).map(|n| Stream((0..n).filter(|m| m % 3 != 0))));
+
+    let data = musli::wire::to_vec(&nested).unwrap();
+
+    let value: Vec<Vec<u32>> = musli::wire::from_slice(&data).unwrap();
+    let expected = (0..8u32)
+        .map(|n| (0..n).filter(|m| m % 3 != 0).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(value, expected);
+}
+
+#[test]
+fn wire_skip() {
+    let nested = Stream((0..8u32).map(|n| (n, Stream((0..n).filter(|m| m % 3 != 0)))));
+
+    let data = musli::wire::to_vec(&(&nested, 42u32, &evens())).unwrap();
+    let (Skipped, value, Skipped): (Skipped, u32, Skipped) =
+        musli::wire::from_slice(&data).unwrap();
+    assert_eq!(value, 42);
+}
+
+#[test]
+fn wire_limits() {
+    const ENCODING: musli::wire::Encoding = musli::wire::Encoding::new().with_max_sequence_count(8);
+
+    let data = ENCODING
+        .to_vec(&Stream((0..8u32).filter(|_| true)))
+        .unwrap();
+    assert!(ENCODING.from_slice::<Vec<u32>>(&data).is_ok());
+
+    let data = ENCODING
+        .to_vec(&Stream((0..9u32).filter(|_| true)))
+        .unwrap();
+    assert!(ENCODING.from_slice::<Vec<u32>>(&data).is_err());
+    assert!(ENCODING.from_slice::<Skipped>(&data).is_err());
+}
+
+#[test]
+fn json() {
+    let json = musli::json::to_string(&evens()).unwrap();
+    let value: Vec<u32> = musli::json::from_str(&json).unwrap();
+    assert_eq!(
+        value,
+        (0..100u32).filter(|n| n % 2 == 0).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn storage_unsupported() {
+    assert!(musli::storage::to_vec(&evens()).is_err());
+}
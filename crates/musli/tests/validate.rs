@@ -0,0 +1,61 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", validate = Range::validate)]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {
+    fn validate(&self) -> Result<(), &'static str> {
+        if self.start > self.end {
+            return Err("start must not be greater than end");
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", validate = validate_level)]
+enum Level {
+    Low,
+    Custom(u8),
+}
+
+fn validate_level(level: &Level) -> Result<(), String> {
+    match level {
+        Level::Custom(n) if *n > 100 => Err(format!("level {n} out of range")),
+        _ => Ok(()),
+    }
+}
+
+#[test]
+fn validate_struct() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Range { start: 1, end: 2 },
+        json = r#"{"start":1,"end":2}"#
+    );
+
+    let error = musli::json::from_str::<Range>(r#"{"start":2,"end":1}"#).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("start must not be greater than end"));
+
+    let bytes = musli::storage::to_vec(&Range { start: 2, end: 1 }).unwrap();
+    assert!(musli::storage::from_slice::<Range>(&bytes).is_err());
+}
+
+#[test]
+fn validate_enum() {
+    musli::macros::assert_roundtrip_eq!(full, Level::Low);
+    musli::macros::assert_roundtrip_eq!(full, Level::Custom(100));
+
+    let bytes = musli::wire::to_vec(&Level::Custom(101)).unwrap();
+    let error = musli::wire::from_slice::<Level>(&bytes).unwrap_err();
+    assert!(error.to_string().contains("level 101 out of range"));
+}
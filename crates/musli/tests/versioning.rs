@@ -0,0 +1,109 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct ConfigV0 {
+    host: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+struct ConfigV1 {
+    host: String,
+    port: u16,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", version = 2, upgrade = {0 => Config::from_v0, 1 => Config::from_v1})]
+struct Config {
+    host: String,
+    port: u16,
+    secure: bool,
+}
+
+impl Config {
+    fn from_v0(old: ConfigV0) -> Self {
+        Self::from_v1(ConfigV1 {
+            host: old.host,
+            port: 80,
+        })
+    }
+
+    fn from_v1(old: ConfigV1) -> Self {
+        Self {
+            host: old.host,
+            port: old.port,
+            secure: false,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(version = 1)]
+enum Shape {
+    Circle(u32),
+    Square { side: u32 },
+}
+
+/// The encoding of older versions of `Config`.
+#[derive(Encode)]
+#[musli(name_type = u32)]
+enum Envelope {
+    #[musli(name = 0, transparent)]
+    V0(ConfigV0),
+    #[musli(name = 3, transparent)]
+    V3(ConfigV1),
+}
+
+#[test]
+fn versioned_roundtrip() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Config {
+            host: String::from("localhost"),
+            port: 8080,
+            secure: true,
+        },
+        json = r#"{"2":{"host":"localhost","port":8080,"secure":true}}"#
+    );
+
+    musli::macros::assert_roundtrip_eq!(full, Shape::Circle(2));
+    musli::macros::assert_roundtrip_eq!(full, Shape::Square { side: 3 });
+}
+
+#[test]
+fn versioned_upgrade() {
+    let expected = Config {
+        host: String::from("localhost"),
+        port: 80,
+        secure: false,
+    };
+
+    let bytes = musli::storage::to_vec(&Envelope::V0(ConfigV0 {
+        host: String::from("localhost"),
+    }))
+    .unwrap();
+
+    let actual: Config = musli::storage::from_slice(&bytes).unwrap();
+    assert_eq!(actual, expected);
+
+    let actual: Config = musli::json::from_str(r#"{"1":{"host":"localhost","port":80}}"#).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn versioned_unsupported() {
+    let bytes = musli::storage::to_vec(&Envelope::V3(ConfigV1 {
+        host: String::from("localhost"),
+        port: 80,
+    }))
+    .unwrap();
+
+    let error = musli::storage::from_slice::<Config>(&bytes).unwrap_err();
+    assert!(error.to_string().contains("Unsupported version 3"));
+
+    let error = musli::json::from_str::<Config>(r#"{"3":{}}"#).unwrap_err();
+    assert!(error.to_string().contains("Unsupported version 3"));
+}
@@ -41,7 +41,7 @@ impl<'de, M> Decode<'de, M> for BytesReference<'de> {
 
 #[test]
 fn bytes_reference() {
-    let value = musli::value::Value::Bytes(vec![0, 1, 2, 3]);
+    let value = musli::value::Value::Bytes(vec![0, 1, 2, 3].into());
 
     assert_eq!(
         musli::value::decode::<BytesReference>(&value).unwrap(),
@@ -98,7 +98,7 @@ impl<'de, M> Decode<'de, M> for StringReference<'de> {
 
 #[test]
 fn string_reference() {
-    let value = musli::value::Value::String(String::from("Hello!"));
+    let value = musli::value::Value::String(String::from("Hello!").into());
 
     assert_eq!(
         musli::value::decode::<StringReference>(&value).unwrap(),
@@ -136,6 +136,6 @@ impl<'de, M> Decode<'de, M> for OwnedFn {
 
 #[test]
 fn owned_fn() {
-    let value = musli::value::Value::String("A".to_string());
+    let value = musli::value::Value::String("A".into());
     assert_eq!(musli::value::decode::<OwnedFn>(&value).unwrap(), OwnedFn::A);
 }
@@ -0,0 +1,59 @@
+use bytes::{Buf, Bytes, BytesMut};
+use musli::reader::BufReader;
+use musli::wire::Encoding;
+
+const ENCODING: Encoding = Encoding::new();
+
+#[test]
+fn encode_into_bytes_mut() {
+    let mut buf = BytesMut::new();
+    ENCODING.encode(&mut buf, &(42u32, "Aristotle")).unwrap();
+    assert_eq!(
+        &buf[..],
+        &ENCODING.to_vec(&(42u32, "Aristotle")).unwrap()[..]
+    );
+
+    let value: (u32, String) = ENCODING.from_slice(&buf).unwrap();
+    assert_eq!(value, (42, String::from("Aristotle")));
+}
+
+#[test]
+fn bytes_fields() {
+    let value = (Bytes::from(vec![1, 2, 3]), BytesMut::from(&[4u8, 5][..]));
+    let data = ENCODING.to_vec(&value).unwrap();
+
+    let decoded: (Bytes, BytesMut) = ENCODING.from_slice(&data).unwrap();
+    assert_eq!(decoded, value);
+
+    // Since they're encoded as bytes, they can be borrowed from a shared
+    // buffer and sliced out of it without copying.
+    let data = Bytes::from(data);
+    let (first, _): (&[u8], &[u8]) = ENCODING.from_slice(&data).unwrap();
+    assert_eq!(data.slice_ref(first), &[1u8, 2, 3][..]);
+}
+
+#[test]
+fn decode_from_buf() {
+    let mut data = ENCODING.to_vec(&String::from("Aristotle")).unwrap();
+    data.extend(ENCODING.to_vec(&vec![1u32, 2, 3]).unwrap());
+
+    for split in 0..data.len() {
+        let (head, tail) = data.split_at(split);
+        let mut reader = BufReader::new(head.chain(tail));
+
+        let first: String = ENCODING.decode(&mut reader).unwrap();
+        assert_eq!(first, "Aristotle");
+
+        let second: Vec<u32> = ENCODING.decode(&mut reader).unwrap();
+        assert_eq!(second, [1, 2, 3]);
+
+        assert!(!reader.into_inner().has_remaining());
+    }
+}
+
+#[test]
+fn truncated_buf() {
+    let data = ENCODING.to_vec(&String::from("Aristotle")).unwrap();
+    let mut reader = BufReader::new(&data[..4]);
+    assert!(ENCODING.decode::<_, String>(&mut reader).is_err());
+}
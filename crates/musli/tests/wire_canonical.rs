@@ -0,0 +1,112 @@
+use std::collections::{BTreeMap, HashMap};
+
+use musli::options::{self, Options, Width};
+use musli::wire::{Checksum, Encoding};
+
+const CANONICAL: Encoding = Encoding::new().with_canonical(true);
+
+const FIXED: Options = options::new().with_length_width(Width::U32).build();
+
+#[test]
+fn maps_are_sorted() {
+    let forward = (0..64u32)
+        .map(|n| (n, n.to_string()))
+        .collect::<HashMap<_, _>>();
+
+    let backward = (0..64u32)
+        .rev()
+        .map(|n| (n, n.to_string()))
+        .collect::<HashMap<_, _>>();
+
+    let data = CANONICAL.to_vec(&forward).unwrap();
+    assert_eq!(data, CANONICAL.to_vec(&backward).unwrap());
+
+    let sorted = forward.iter().collect::<BTreeMap<_, _>>();
+    assert_eq!(data, CANONICAL.to_vec(&sorted).unwrap());
+
+    let value: HashMap<u32, String> = CANONICAL.from_slice(&data).unwrap();
+    assert_eq!(value, forward);
+}
+
+#[test]
+fn nested_maps_are_sorted() {
+    let value = (0..16u32)
+        .map(|n| {
+            let inner = (0..n).map(|m| (m.to_string(), m)).collect();
+            (n.to_string(), inner)
+        })
+        .collect::<HashMap<String, HashMap<String, u32>>>();
+
+    let data = CANONICAL.to_vec(&value).unwrap();
+
+    for _ in 0..8 {
+        let copy = value.clone().into_iter().collect::<HashMap<_, _>>();
+        assert_eq!(data, CANONICAL.to_vec(&copy).unwrap());
+    }
+
+    let decoded: HashMap<String, HashMap<String, u32>> = CANONICAL.from_slice(&data).unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn nan_is_normalized() {
+    let nan = f64::from_bits(f64::NAN.to_bits() | 1);
+    assert!(nan.is_nan());
+
+    assert_ne!(
+        musli::wire::to_vec(&nan).unwrap(),
+        musli::wire::to_vec(&f64::NAN).unwrap()
+    );
+
+    assert_eq!(
+        CANONICAL.to_vec(&nan).unwrap(),
+        CANONICAL.to_vec(&f64::NAN).unwrap()
+    );
+}
+
+fn nested() -> HashMap<String, HashMap<u32, Vec<u8>>> {
+    (0..16u32)
+        .map(|n| {
+            let inner = (0..n).map(|m| (m, vec![m as u8; m as usize * 8])).collect();
+            (n.to_string(), inner)
+        })
+        .collect()
+}
+
+fn same_across_writers<const OPT: Options>(encoding: Encoding<OPT>) {
+    let value = nested();
+    let data = encoding.to_vec(&value).unwrap();
+
+    let fixed = encoding.to_fixed_bytes::<8192, _>(&value).unwrap();
+    assert_eq!(fixed.as_slice(), data);
+
+    let mut written = Vec::new();
+    encoding.to_writer(&mut written, &value).unwrap();
+    assert_eq!(written, data);
+
+    assert_eq!(encoding.encoded_len(&value).unwrap(), data.len());
+
+    let decoded: HashMap<String, HashMap<u32, Vec<u8>>> = encoding.from_slice(&data).unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn writers() {
+    same_across_writers(CANONICAL);
+    same_across_writers(CANONICAL.with_checksum(Checksum::Crc32));
+    same_across_writers(CANONICAL.with_options::<FIXED>());
+    same_across_writers(
+        CANONICAL
+            .with_options::<FIXED>()
+            .with_checksum(Checksum::Crc32),
+    );
+}
+
+/// Values are recursive, so encoding them mustn't nest writer types for each
+/// level of maps.
+#[test]
+fn dynamic_value() {
+    let value = musli::value::encode(nested()).unwrap();
+    let data = CANONICAL.to_vec(&value).unwrap();
+    assert_eq!(data, CANONICAL.to_vec(&nested()).unwrap());
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use musli::wire::{Checksum, Encoding};
+
+const PLAIN: Encoding = Encoding::new();
+const CHECKED: Encoding = Encoding::new().with_checksum(Checksum::Crc32);
+
+fn value() -> HashMap<String, Vec<u32>> {
+    (0..8u32)
+        .map(|n| (n.to_string(), (0..n).collect()))
+        .collect()
+}
+
+#[test]
+fn roundtrip() {
+    let value = value();
+
+    let plain = PLAIN.to_vec(&value).unwrap();
+    let data = CHECKED.to_vec(&value).unwrap();
+    assert_eq!(data.len(), plain.len() + 4);
+    assert_eq!(&data[..plain.len()], &plain[..]);
+
+    let decoded: HashMap<String, Vec<u32>> = CHECKED.from_slice(&data).unwrap();
+    assert_eq!(decoded, value);
+}
+
+/// Bitwise reference implementation of CRC-32.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for &b in bytes {
+        crc ^= b as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                0xedb88320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+#[test]
+fn trailer() {
+    assert_eq!(crc32(b"123456789"), 0xcbf43926);
+
+    let data = CHECKED.to_vec(&value()).unwrap();
+    let (message, trailer) = data.split_at(data.len() - 4);
+    assert_eq!(trailer, crc32(message).to_le_bytes());
+}
+
+#[test]
+fn corruption_is_detected() {
+    let data = CHECKED.to_vec(&value()).unwrap();
+
+    for n in 0..data.len() {
+        let mut corrupted = data.clone();
+        corrupted[n] ^= 0b1;
+        assert!(
+            CHECKED
+                .from_slice::<HashMap<String, Vec<u32>>>(&corrupted)
+                .is_err(),
+            "corruption at {n} was not detected"
+        );
+    }
+}
+
+#[test]
+fn mismatch_message() {
+    let mut data = CHECKED.to_vec(&String::from("Aristotle")).unwrap();
+    data[1] ^= 0b100;
+
+    let error = CHECKED.from_slice::<String>(&data).unwrap_err();
+    assert!(error.to_string().contains("Checksum mismatch"), "{error}");
+}
+
+#[test]
+fn missing_trailer() {
+    let data = PLAIN.to_vec(&42u32).unwrap();
+    assert!(CHECKED.from_slice::<u32>(&data).is_err());
+}
@@ -0,0 +1,65 @@
+use bytes::BytesMut;
+use musli::wire::Codec;
+use musli::{Decode, Encode};
+use tokio_util::codec::{Decoder, Encoder};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Message {
+    id: u32,
+    body: String,
+}
+
+#[test]
+fn roundtrip() {
+    let mut codec = Codec::<Message>::new();
+    let mut buf = BytesMut::new();
+
+    for id in 0..4 {
+        let message = Message {
+            id,
+            body: format!("Message #{id}"),
+        };
+
+        codec.encode(&message, &mut buf).unwrap();
+    }
+
+    for id in 0..4 {
+        let actual = codec.decode(&mut buf).unwrap();
+
+        let expected = Message {
+            id,
+            body: format!("Message #{id}"),
+        };
+
+        assert_eq!(actual, Some(expected));
+    }
+
+    assert!(buf.is_empty());
+    assert_eq!(codec.decode(&mut buf).unwrap(), None);
+}
+
+#[test]
+fn partial_frame() {
+    let mut codec = Codec::<String>::new();
+    let mut buf = BytesMut::new();
+    codec.encode(String::from("Hello World"), &mut buf).unwrap();
+
+    let mut partial = BytesMut::from(&buf[..buf.len() - 1]);
+    assert_eq!(codec.decode(&mut partial).unwrap(), None);
+
+    partial.extend_from_slice(&buf[buf.len() - 1..]);
+    let actual = codec.decode(&mut partial).unwrap();
+    assert_eq!(actual.as_deref(), Some("Hello World"));
+}
+
+#[test]
+fn max_frame_length() {
+    let mut codec = Codec::<String>::new().with_max_frame_length(4);
+    let mut buf = BytesMut::new();
+
+    assert!(codec.encode(String::from("Hello World"), &mut buf).is_err());
+
+    let mut large = Codec::<String>::new();
+    large.encode(String::from("Hello World"), &mut buf).unwrap();
+    assert!(codec.decode(&mut buf).is_err());
+}
@@ -0,0 +1,62 @@
+#![cfg(any(feature = "zstd", feature = "lz4"))]
+
+use std::collections::HashMap;
+
+use musli::wire::{Checksum, Compression, Encoding};
+
+#[cfg(feature = "zstd")]
+const ZSTD: Encoding = Encoding::new().with_compression(Compression::Zstd { level: 0 });
+#[cfg(feature = "lz4")]
+const LZ4: Encoding = Encoding::new().with_compression(Compression::Lz4);
+
+fn value() -> HashMap<String, String> {
+    (0..64u32)
+        .map(|n| (n.to_string(), "Aristotle".repeat(n as usize)))
+        .collect()
+}
+
+fn roundtrip(encoding: Encoding) {
+    let value = value();
+
+    let plain = encoding.to_vec(&value).unwrap();
+    let data = encoding.to_vec_compressed(&value).unwrap();
+    assert!(data.len() < plain.len());
+
+    let decoded: HashMap<String, String> = encoding.from_slice_compressed(&data).unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+#[cfg(feature = "zstd")]
+fn zstd() {
+    roundtrip(ZSTD);
+    roundtrip(ZSTD.with_checksum(Checksum::Crc32));
+}
+
+#[test]
+#[cfg(feature = "lz4")]
+fn lz4() {
+    roundtrip(LZ4);
+    roundtrip(LZ4.with_checksum(Checksum::Crc32));
+}
+
+#[test]
+fn uncompressed() {
+    const ENCODING: Encoding = Encoding::new();
+
+    let value = value();
+    let data = ENCODING.to_vec_compressed(&value).unwrap();
+    assert_eq!(data, ENCODING.to_vec(&value).unwrap());
+
+    let decoded: HashMap<String, String> = ENCODING.from_slice_compressed(&data).unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+#[cfg(all(feature = "zstd", feature = "lz4"))]
+fn mismatched_compression() {
+    let data = ZSTD.to_vec_compressed(&value()).unwrap();
+    assert!(LZ4
+        .from_slice_compressed::<HashMap<String, String>>(&data)
+        .is_err());
+}
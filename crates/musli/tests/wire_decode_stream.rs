@@ -0,0 +1,107 @@
+use std::io::{self, Read};
+
+use musli::wire::{Checksum, Encoding};
+
+/// A reader which only hands out a single byte at a time.
+struct Trickle<'a>(&'a [u8]);
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let Some((&b, rest)) = self.0.split_first() else {
+            return Ok(0);
+        };
+
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        buf[0] = b;
+        self.0 = rest;
+        Ok(1)
+    }
+}
+
+fn log(encoding: Encoding, count: u32) -> Vec<u8> {
+    let mut data = Vec::new();
+
+    for n in 0..count {
+        encoding.to_writer(&mut data, &(n, n.to_string())).unwrap();
+    }
+
+    data
+}
+
+#[test]
+fn consecutive() {
+    let data = log(musli::wire::DEFAULT, 100);
+
+    let values = musli::wire::decode_stream::<_, (u32, String)>(&data[..])
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    let expected = (0..100).map(|n| (n, n.to_string())).collect::<Vec<_>>();
+    assert_eq!(values, expected);
+
+    let values = musli::wire::decode_stream::<_, (u32, String)>(Trickle(&data))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn empty() {
+    let mut stream = musli::wire::decode_stream::<_, u32>(&[][..]);
+    assert!(stream.next().is_none());
+    assert!(stream.next().is_none());
+}
+
+#[test]
+fn framed() {
+    const ENCODING: Encoding = Encoding::new().with_checksum(Checksum::Crc32);
+
+    let data = log(ENCODING, 10);
+
+    let values = ENCODING
+        .decode_stream::<_, (u32, String)>(&data[..])
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(values.len(), 10);
+
+    let mut corrupt = data.clone();
+    corrupt[20] ^= 1;
+
+    let results = ENCODING
+        .decode_stream::<_, (u32, String)>(&corrupt[..])
+        .collect::<Vec<_>>();
+
+    assert!(results.last().unwrap().is_err());
+    assert!(results[..results.len() - 1].iter().all(Result::is_ok));
+}
+
+#[test]
+fn truncated() {
+    let data = log(musli::wire::DEFAULT, 3);
+
+    let mut stream = musli::wire::decode_stream::<_, (u32, String)>(&data[..data.len() - 1]);
+
+    assert_eq!(stream.next().unwrap().unwrap(), (0, String::from("0")));
+    assert_eq!(stream.next().unwrap().unwrap(), (1, String::from("1")));
+    assert!(stream.next().unwrap().is_err());
+    assert!(stream.next().is_none());
+}
+
+#[test]
+fn into_reader() {
+    let mut data = log(musli::wire::DEFAULT, 2);
+    data.extend_from_slice(b"rest");
+
+    let mut stream = musli::wire::decode_stream::<_, (u32, String)>(&data[..]);
+    assert_eq!(stream.next().unwrap().unwrap(), (0, String::from("0")));
+
+    let mut reader = stream.into_reader();
+    let value: (u32, String) = musli::wire::DEFAULT.decode_reader(&mut reader).unwrap();
+    assert_eq!(value, (1, String::from("1")));
+    assert_eq!(reader.buffer(), b"rest");
+}
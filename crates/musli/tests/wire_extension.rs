@@ -0,0 +1,105 @@
+use musli::wire::Node;
+use musli::{Decode, Decoder, Encode, Encoder};
+
+/// A decimal number with four fractional digits.
+#[derive(Debug, PartialEq)]
+struct Decimal(i64);
+
+impl<M> Encode<M> for Decimal {
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_extension(7, &self.0)
+    }
+}
+
+impl<'de, M> Decode<'de, M> for Decimal {
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        Ok(Decimal(decoder.decode_extension(7)?))
+    }
+}
+
+/// The same representation as a different extension.
+#[derive(Debug)]
+struct Timestamp;
+
+impl<'de, M> Decode<'de, M> for Timestamp {
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_extension::<i64>(8)?;
+        Ok(Timestamp)
+    }
+}
+
+/// Skips over whatever value is being decoded.
+struct Skipped;
+
+impl<'de, M> Decode<'de, M> for Skipped {
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.skip()?;
+        Ok(Skipped)
+    }
+}
+
+#[test]
+fn roundtrip() {
+    let data = musli::wire::to_vec(&(Decimal(-12_3456), 42u32)).unwrap();
+    let value: (Decimal, u32) = musli::wire::from_slice(&data).unwrap();
+    assert_eq!(value, (Decimal(-12_3456), 42));
+}
+
+#[test]
+fn skip() {
+    let data = musli::wire::to_vec(&(Decimal(10), 42u32)).unwrap();
+    let (Skipped, value): (Skipped, u32) = musli::wire::from_slice(&data).unwrap();
+    assert_eq!(value, 42);
+}
+
+#[test]
+fn mismatch() {
+    let data = musli::wire::to_vec(&Decimal(10)).unwrap();
+
+    let error = musli::wire::from_slice::<Timestamp>(&data).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Extension type 7 does not match the expected 8"),
+        "{error}"
+    );
+
+    let data = musli::wire::to_vec(&10i64).unwrap();
+    assert!(musli::wire::from_slice::<Decimal>(&data).is_err());
+}
+
+#[test]
+fn inspect() {
+    let data = musli::wire::to_vec(&Decimal(10)).unwrap();
+    let node = musli::wire::inspect(&data).unwrap();
+
+    let Node::Extension { id, value } = &node else {
+        panic!("expected an extension, got {node:?}");
+    };
+
+    assert_eq!(*id, 7);
+    assert!(matches!(**value, Node::Continuation { value: 20, .. }));
+    assert_eq!(node.to_string(), "extension(7) continuation 20");
+}
+
+#[test]
+fn other_formats() {
+    let json = musli::json::to_string(&Decimal(10)).unwrap();
+    assert_eq!(json, "10");
+    assert_eq!(
+        musli::json::from_str::<Decimal>(&json).unwrap(),
+        Decimal(10)
+    );
+}
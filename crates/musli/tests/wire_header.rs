@@ -0,0 +1,78 @@
+use musli::options::{self, Integer, Options};
+use musli::wire::{Checksum, Encoding, Header};
+
+const FIXED: Options = options::new().with_integer(Integer::Fixed).build();
+
+const V1: Encoding = Encoding::new().with_header(Header::new(*b"TEST", 1));
+const V2: Encoding<FIXED> = Encoding::new()
+    .with_options()
+    .with_header(Header::new(*b"TEST", 2));
+
+#[test]
+fn roundtrip() {
+    let data = V1.to_vec(&String::from("Aristotle")).unwrap();
+    assert_eq!(&data[..4], b"TEST");
+    assert_eq!(&data[4..8], 1u32.to_le_bytes());
+    assert_eq!(&data[8..], musli::wire::to_vec("Aristotle").unwrap());
+
+    let value: String = V1.from_slice(&data).unwrap();
+    assert_eq!(value, "Aristotle");
+
+    let data = V1
+        .with_checksum(Checksum::Crc32)
+        .to_vec(&String::from("Aristotle"))
+        .unwrap();
+    let value: String = V1.with_checksum(Checksum::Crc32).from_slice(&data).unwrap();
+    assert_eq!(value, "Aristotle");
+}
+
+#[test]
+fn version_mismatch() {
+    let data = V1.to_vec(&42u32).unwrap();
+
+    let error = V2.from_slice::<u32>(&data).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Header version 1 does not match the expected version 2"),
+        "{error}"
+    );
+}
+
+#[test]
+fn magic_mismatch() {
+    const OTHER: Encoding = Encoding::new().with_header(Header::new(*b"ELSE", 1));
+
+    let data = OTHER.to_vec(&42u32).unwrap();
+
+    let error = V1.from_slice::<u32>(&data).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains(r#"Header magic "ELSE" does not match the expected "TEST""#),
+        "{error}"
+    );
+
+    let data = musli::wire::to_vec(&42u32).unwrap();
+    assert!(V1.from_slice::<u32>(&data).is_err());
+}
+
+#[test]
+fn peek() {
+    let v1 = V1.to_vec(&42u32).unwrap();
+    let v2 = V2.to_vec(&42u32).unwrap();
+
+    assert_eq!(Header::peek(&v1), Some(Header::new(*b"TEST", 1)));
+    assert_eq!(Header::peek(&v2), Some(Header::new(*b"TEST", 2)));
+    assert_eq!(Header::peek(&v1[..7]), None);
+
+    for data in [v1, v2] {
+        let value: u32 = match Header::peek(&data).map(|h| h.version()) {
+            Some(1) => V1.from_slice(&data).unwrap(),
+            Some(2) => V2.from_slice(&data).unwrap(),
+            _ => panic!("unsupported version"),
+        };
+
+        assert_eq!(value, 42);
+    }
+}
@@ -0,0 +1,92 @@
+use musli::wire::{self, Checksum, Encoding, Header, Node};
+
+#[test]
+fn structure() {
+    let data = wire::to_vec(&(42u32, String::from("Aristotle"), vec![-1i32; 70])).unwrap();
+    let node = wire::inspect(&data).unwrap();
+
+    let Node::Sequence { embedded, items } = &node else {
+        panic!("expected a sequence, got {node:?}");
+    };
+
+    assert!(embedded);
+    assert_eq!(items.len(), 3);
+
+    assert_eq!(
+        items[0],
+        Node::Continuation {
+            embedded: true,
+            value: 42
+        }
+    );
+
+    assert_eq!(
+        items[1],
+        Node::Prefix {
+            embedded: true,
+            bytes: b"Aristotle".to_vec()
+        }
+    );
+
+    let Node::Sequence { embedded, items } = &items[2] else {
+        panic!("expected a sequence, got {:?}", items[2]);
+    };
+
+    assert!(!embedded);
+    assert_eq!(items.len(), 70);
+    assert!(items.iter().all(|item| *item
+        == Node::Continuation {
+            embedded: true,
+            value: 1
+        }));
+}
+
+#[test]
+fn display() {
+    let data = wire::to_vec(&(1u32, (2u32, "a"), Vec::<u32>::new())).unwrap();
+    let node = wire::inspect(&data).unwrap();
+
+    assert_eq!(
+        node.to_string(),
+        "sequence(3) [\n  continuation 1\n  sequence(2) [\n    continuation 2\n    prefix(1) \"a\"\n  ]\n  sequence(0)\n]"
+    );
+}
+
+#[test]
+fn configured() {
+    const ENCODING: Encoding = Encoding::new()
+        .with_header(Header::new(*b"TEST", 1))
+        .with_checksum(Checksum::Crc32);
+
+    let data = ENCODING.to_vec(&(1u32, 2u32)).unwrap();
+    let node = ENCODING.inspect(&data).unwrap();
+    assert_eq!(
+        node,
+        wire::inspect(&wire::to_vec(&(1u32, 2u32)).unwrap()).unwrap()
+    );
+
+    assert!(wire::inspect(&data).is_err());
+}
+
+#[test]
+fn invalid() {
+    // A sequence of two items which is cut short.
+    assert!(wire::inspect(&[0b10_000010, 0b11_000001]).is_err());
+
+    // Nesting beyond the maximum depth.
+    let data = vec![0b10_000001; 1024];
+    assert!(wire::inspect(&data).is_err());
+}
+
+#[test]
+fn partial() {
+    const ENCODING: Encoding = Encoding::new().with_checksum(Checksum::Crc32);
+
+    let mut data = ENCODING.to_vec(&(1u32, 2u32)).unwrap();
+    let len = data.len();
+    data.extend_from_slice(b"rest");
+
+    let (node, n) = ENCODING.inspect_partial(&data).unwrap();
+    assert_eq!(node, ENCODING.inspect(&data).unwrap());
+    assert_eq!(n, len);
+}
@@ -0,0 +1,30 @@
+use musli::wire::{Encoding, FIXED_LENGTHS16, FIXED_LENGTHS_USIZE};
+
+#[test]
+fn fixed_lengths16() {
+    const ENCODING: Encoding<FIXED_LENGTHS16> = Encoding::new().with_fixed_lengths16();
+
+    let value = "a".repeat(100);
+
+    let data = ENCODING.to_vec(&value).unwrap();
+    assert_eq!(data.len(), 1 + 2 + value.len());
+
+    let decoded: String = ENCODING.from_slice(&data).unwrap();
+    assert_eq!(decoded, value);
+
+    let value = "a".repeat(usize::from(u16::MAX) + 1);
+    assert!(ENCODING.to_vec(&value).is_err());
+}
+
+#[test]
+fn fixed_lengths_usize() {
+    const ENCODING: Encoding<FIXED_LENGTHS_USIZE> = Encoding::new().with_fixed_lengths_usize();
+
+    let value = "a".repeat(100);
+
+    let data = ENCODING.to_vec(&value).unwrap();
+    assert_eq!(data.len(), 1 + core::mem::size_of::<usize>() + value.len());
+
+    let decoded: String = ENCODING.from_slice(&data).unwrap();
+    assert_eq!(decoded, value);
+}
@@ -0,0 +1,129 @@
+use std::io;
+
+use musli::context::{Compact, ErrorCode};
+use musli::wire::Encoding;
+
+const ENCODING: Encoding = Encoding::new()
+    .with_max_element_length(16)
+    .with_max_sequence_count(8)
+    .with_max_allocation(64);
+
+/// A string whose length prefix claims to be much longer than the data which
+/// follows it.
+fn hostile_string() -> Vec<u8> {
+    let mut data = musli::wire::to_vec(&"a".repeat(1 << 20)).unwrap();
+    data.truncate(8);
+    data
+}
+
+#[test]
+fn element_length() {
+    let data = ENCODING.to_vec(&"a".repeat(16)).unwrap();
+    assert_eq!(
+        ENCODING.from_slice::<String>(&data).unwrap(),
+        "a".repeat(16)
+    );
+
+    let data = ENCODING.to_vec(&"a".repeat(17)).unwrap();
+    let error = ENCODING.from_slice::<String>(&data).unwrap_err();
+    assert!(
+        error.to_string().contains("exceeds the maximum of 16"),
+        "{error}"
+    );
+}
+
+#[test]
+fn sequence_count() {
+    let data = ENCODING.to_vec(&vec![1u32; 8]).unwrap();
+    assert_eq!(
+        ENCODING.from_slice::<Vec<u32>>(&data).unwrap(),
+        vec![1u32; 8]
+    );
+
+    let data = ENCODING.to_vec(&vec![1u32; 9]).unwrap();
+    let error = ENCODING.from_slice::<Vec<u32>>(&data).unwrap_err();
+    assert!(
+        error.to_string().contains("exceeds the maximum of 8"),
+        "{error}"
+    );
+}
+
+#[test]
+fn total_allocation() {
+    let value = vec![String::from("abcdefghijklmnop"); 3];
+    let data = ENCODING.to_vec(&value).unwrap();
+    assert_eq!(ENCODING.from_slice::<Vec<String>>(&data).unwrap(), value);
+
+    let value = vec![String::from("abcdefghijklmnop"); 4];
+    let data = ENCODING.to_vec(&value).unwrap();
+    let error = ENCODING.from_slice::<Vec<String>>(&data).unwrap_err();
+    assert!(
+        error.to_string().contains("exceeds the maximum of 64"),
+        "{error}"
+    );
+}
+
+#[test]
+fn limits_are_per_message() {
+    let data = ENCODING
+        .to_vec(&vec![String::from("abcdefghijklmnop"); 3])
+        .unwrap();
+
+    for _ in 0..4 {
+        assert!(ENCODING.from_slice::<Vec<String>>(&data).is_ok());
+    }
+}
+
+#[test]
+fn unlimited_by_default() {
+    let value = "a".repeat(1024);
+    let data = musli::wire::to_vec(&value).unwrap();
+    assert_eq!(musli::wire::from_slice::<String>(&data).unwrap(), value);
+}
+
+#[test]
+fn hostile_length_prefix() {
+    let data = hostile_string();
+
+    let error = ENCODING.from_slice::<String>(&data).unwrap_err();
+    assert!(error.to_string().contains("maximum of 16"), "{error}");
+
+    // The reader gives up as soon as it sees the prefix, rather than
+    // buffering until it has received all of the claimed data.
+    let reader = io::Read::chain(&data[..], io::repeat(b'a'));
+    let error = ENCODING.from_reader::<_, String>(reader).unwrap_err();
+    assert!(error.to_string().contains("maximum of 16"), "{error}");
+}
+
+#[test]
+fn continuation_bytes() {
+    const ENCODING: Encoding = Encoding::new().with_max_continuation_bytes(2);
+
+    let data = ENCODING.to_vec(&1000u32).unwrap();
+    assert_eq!(ENCODING.from_slice::<u32>(&data).unwrap(), 1000);
+
+    let data = ENCODING.to_vec(&100_000u32).unwrap();
+    let error = ENCODING.from_slice::<u32>(&data).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Continuation sequence exceeds the maximum of 2 bytes"),
+        "{error}"
+    );
+
+    // Length prefixes are bounded in the same way.
+    let data = ENCODING.to_vec(&"a".repeat(1 << 14)).unwrap();
+    assert!(ENCODING.from_slice::<String>(&data).is_err());
+    assert!(ENCODING.from_slice::<musli::value::Value>(&data).is_err());
+
+    // Malformed input with an endless continuation sequence.
+    let mut data = vec![0b11_111111];
+    data.extend([0xff; 64]);
+
+    musli::alloc::default!(|alloc| {
+        let cx = Compact::with_alloc(alloc);
+        let error = ENCODING.from_slice_with::<_, u64>(&cx, &data).unwrap_err();
+        assert_eq!(error.code(), ErrorCode::ContinuationOverflow);
+        assert_eq!(error.offset(), 1);
+    });
+}
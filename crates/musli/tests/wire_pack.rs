@@ -0,0 +1,185 @@
+use musli::de::SequenceDecoder;
+use musli::en::SequenceEncoder;
+use musli::options::{self, Options, Width};
+use musli::wire::{Encoding, PackPadding};
+use musli::{Decode, Decoder, Encode, Encoder};
+
+const U16: Options = options::new().with_length_width(Width::U16).build();
+
+#[derive(Debug, PartialEq)]
+struct Packed<const N: usize>([u8; N]);
+
+impl<M, const N: usize> Encode<M> for Packed<N> {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let mut pack = encoder.encode_pack()?;
+
+        for b in &self.0 {
+            pack.push(b)?;
+        }
+
+        pack.finish_sequence()
+    }
+}
+
+impl<'de, M, const N: usize> Decode<'de, M> for Packed<N> {
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_pack(|pack| {
+            let mut out = [0; N];
+
+            for b in &mut out {
+                *b = pack.next()?;
+            }
+
+            Ok(Packed(out))
+        })
+    }
+}
+
+/// Packs are prefixed with their exact length, so they should take up as much
+/// space as a string of the same size.
+fn exact<const N: usize>() {
+    let mut bytes = [0u8; N];
+
+    for (n, b) in bytes.iter_mut().enumerate() {
+        *b = n as u8;
+    }
+
+    let string = "a".repeat(N);
+
+    let data = musli::wire::to_vec(&Packed(bytes)).unwrap();
+    let expected = musli::wire::to_vec(&string).unwrap();
+    assert_eq!(data.len(), expected.len(), "pack of {N}");
+
+    let decoded: Packed<N> = musli::wire::from_slice(&data).unwrap();
+    assert_eq!(decoded, Packed(bytes));
+
+    const ENCODING: Encoding<U16> = Encoding::new().with_options();
+
+    let data = ENCODING.to_vec(&Packed(bytes)).unwrap();
+    let expected = ENCODING.to_vec(&string).unwrap();
+    assert_eq!(data.len(), expected.len(), "fixed pack of {N}");
+
+    let decoded: Packed<N> = ENCODING.from_slice(&data).unwrap();
+    assert_eq!(decoded, Packed(bytes));
+}
+
+#[test]
+fn exact_length() {
+    exact::<0>();
+    exact::<1>();
+    exact::<33>();
+    exact::<62>();
+    exact::<63>();
+    exact::<64>();
+    exact::<65>();
+    exact::<100>();
+    exact::<129>();
+    exact::<300>();
+}
+
+/// The encoding of a pack doesn't depend on the writer, even though some
+/// writers allow the length prefix to be filled in after the fact.
+fn writers<const N: usize>() {
+    const ENCODING: Encoding<U16> = Encoding::new().with_options();
+
+    let mut bytes = [0u8; N];
+
+    for (n, b) in bytes.iter_mut().enumerate() {
+        *b = n as u8;
+    }
+
+    let value = (1u32, Packed(bytes), 2u32);
+
+    let data = ENCODING.to_vec(&value).unwrap();
+
+    let fixed = ENCODING.to_fixed_bytes::<512, _>(&value).unwrap();
+    assert_eq!(fixed.as_slice(), data, "fixed bytes for pack of {N}");
+
+    let mut written = Vec::new();
+    ENCODING.to_writer(&mut written, &value).unwrap();
+    assert_eq!(written, data, "writer for pack of {N}");
+
+    let len = ENCODING.encoded_len(&value).unwrap();
+    assert_eq!(len, data.len(), "encoded length for pack of {N}");
+}
+
+#[test]
+fn same_across_writers() {
+    writers::<0>();
+    writers::<1>();
+    writers::<62>();
+    writers::<63>();
+    writers::<64>();
+    writers::<300>();
+}
+
+/// Packs are padded with zeros up to `expected` bytes, which is accounted for
+/// in their length prefix and skipped when decoding.
+fn padded<const N: usize>(padding: PackPadding, expected: usize) {
+    let mut bytes = [0u8; N];
+
+    for (n, b) in bytes.iter_mut().enumerate() {
+        *b = n as u8;
+    }
+
+    let string = "a".repeat(expected);
+
+    let encoding = Encoding::new().with_pack_padding(padding);
+    let data = encoding.to_vec(&Packed(bytes)).unwrap();
+    assert_eq!(
+        data.len(),
+        musli::wire::to_vec(&string).unwrap().len(),
+        "pack of {N}"
+    );
+    assert!(data[data.len() - (expected - N)..].iter().all(|&b| b == 0));
+
+    let decoded: Packed<N> = musli::wire::from_slice(&data).unwrap();
+    assert_eq!(decoded, Packed(bytes));
+
+    let encoding = Encoding::new()
+        .with_fixed_lengths16()
+        .with_pack_padding(padding);
+    let value = (1u32, Packed(bytes), 2u32);
+
+    let data = encoding.to_vec(&value).unwrap();
+    let expected_data = encoding.to_vec(&(1u32, string, 2u32)).unwrap();
+    assert_eq!(data.len(), expected_data.len(), "fixed pack of {N}");
+
+    let fixed = encoding.to_fixed_bytes::<1024, _>(&value).unwrap();
+    assert_eq!(fixed.as_slice(), data, "fixed bytes for pack of {N}");
+
+    let mut written = Vec::new();
+    encoding.to_writer(&mut written, &value).unwrap();
+    assert_eq!(written, data, "writer for pack of {N}");
+
+    let len = encoding.encoded_len(&value).unwrap();
+    assert_eq!(len, data.len(), "encoded length for pack of {N}");
+
+    let decoded: (u32, Packed<N>, u32) = encoding.from_slice(&data).unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn padding() {
+    padded::<0>(PackPadding::Multiple(8), 0);
+    padded::<1>(PackPadding::Multiple(8), 8);
+    padded::<8>(PackPadding::Multiple(8), 8);
+    padded::<63>(PackPadding::Multiple(8), 64);
+    padded::<100>(PackPadding::Multiple(1), 100);
+    padded::<100>(PackPadding::Multiple(0), 100);
+
+    padded::<3>(PackPadding::PowerOfTwo { threshold: 0 }, 4);
+    padded::<3>(PackPadding::PowerOfTwo { threshold: 4 }, 3);
+    padded::<65>(PackPadding::PowerOfTwo { threshold: 64 }, 128);
+    padded::<300>(PackPadding::PowerOfTwo { threshold: 64 }, 512);
+
+    padded::<300>(PackPadding::Exact, 300);
+}
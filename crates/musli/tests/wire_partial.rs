@@ -0,0 +1,48 @@
+use musli::wire::{Checksum, Encoding, Header};
+
+#[test]
+fn concatenated() {
+    let mut data = Vec::new();
+
+    for n in 0..10u32 {
+        data.extend(musli::wire::to_vec(&(n, n.to_string())).unwrap());
+    }
+
+    let mut rest = &data[..];
+    let mut values = Vec::new();
+
+    while !rest.is_empty() {
+        let (value, n): ((u32, String), _) = musli::wire::from_slice_partial(rest).unwrap();
+        values.push(value);
+        rest = &rest[n..];
+    }
+
+    let expected = (0..10u32).map(|n| (n, n.to_string())).collect::<Vec<_>>();
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn framing() {
+    const ENCODING: Encoding = Encoding::new()
+        .with_checksum(Checksum::Crc32)
+        .with_header(Header::new(*b"TEST", 1));
+
+    let first = ENCODING.to_vec(&String::from("Aristotle")).unwrap();
+    let second = ENCODING.to_vec(&vec![1u32, 2, 3]).unwrap();
+
+    let data = [&first[..], &second[..]].concat();
+
+    let (value, n) = ENCODING.from_slice_partial::<String>(&data).unwrap();
+    assert_eq!(value, "Aristotle");
+    assert_eq!(n, first.len());
+
+    let (value, n) = ENCODING.from_slice_partial::<Vec<u32>>(&data[n..]).unwrap();
+    assert_eq!(value, [1, 2, 3]);
+    assert_eq!(n, second.len());
+}
+
+#[test]
+fn truncated() {
+    let data = musli::wire::to_vec(&String::from("Aristotle")).unwrap();
+    assert!(musli::wire::from_slice_partial::<String>(&data[..4]).is_err());
+}
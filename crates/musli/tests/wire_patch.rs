@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+
+use musli::wire::{Checksum, Encoding, Header, Patch};
+
+#[test]
+fn roundtrip() {
+    let mut state = BTreeMap::new();
+
+    for n in 0..100u32 {
+        state.insert(n, (n.to_string(), vec![n; 10]));
+    }
+
+    let old = musli::wire::to_vec(&state).unwrap();
+
+    state.get_mut(&42).unwrap().1[3] = 1000;
+    state.remove(&10);
+    state.insert(1000, (String::from("Aristotle"), Vec::new()));
+
+    let new = musli::wire::to_vec(&state).unwrap();
+
+    let patch = musli::wire::diff(&old, &new).unwrap();
+    assert!(patch.as_bytes().len() < new.len() / 10, "{patch:?}");
+
+    let patched = musli::wire::patch(&old, &patch).unwrap();
+    assert_eq!(patched, new);
+
+    let decoded: BTreeMap<u32, (String, Vec<u32>)> = musli::wire::from_slice(&patched).unwrap();
+    assert_eq!(decoded, state);
+}
+
+#[test]
+fn identical() {
+    let data = musli::wire::to_vec(&vec![String::from("Aristotle"); 100]).unwrap();
+    let patch = musli::wire::diff(&data, &data).unwrap();
+    assert!(patch.as_bytes().len() < 8, "{patch:?}");
+    assert_eq!(musli::wire::patch(&data, &patch).unwrap(), data);
+}
+
+#[test]
+fn different_types() {
+    let old = musli::wire::to_vec(&vec![1u32, 2, 3]).unwrap();
+    let new = musli::wire::to_vec(&String::from("Aristotle")).unwrap();
+
+    let patch = musli::wire::diff(&old, &new).unwrap();
+    assert_eq!(musli::wire::patch(&old, &patch).unwrap(), new);
+}
+
+#[test]
+fn framed() {
+    const ENCODING: Encoding = Encoding::new()
+        .with_checksum(Checksum::Crc32)
+        .with_header(Header::new(*b"TEST", 1));
+
+    let mut state = vec![(1u32, String::from("Aristotle")); 100];
+    let old = ENCODING.to_vec(&state).unwrap();
+
+    state[50].1 = String::from("Plato");
+    let new = ENCODING.to_vec(&state).unwrap();
+
+    let patch = ENCODING.diff(&old, &new).unwrap();
+    assert!(patch.as_bytes().len() < 32, "{patch:?}");
+    assert_eq!(ENCODING.patch(&old, &patch).unwrap(), new);
+
+    let mut corrupt = old.clone();
+    *corrupt.last_mut().unwrap() ^= 1;
+    assert!(ENCODING.diff(&corrupt, &new).is_err());
+}
+
+#[test]
+fn wrong_base() {
+    let a = musli::wire::to_vec(&vec![1u32, 2, 3]).unwrap();
+    let b = musli::wire::to_vec(&vec![1u32, 2, 4]).unwrap();
+    let c = musli::wire::to_vec(&vec![1u32, 2, 3, 4]).unwrap();
+
+    let patch = musli::wire::diff(&a, &b).unwrap();
+    assert!(musli::wire::patch(&c, &patch).is_err());
+}
+
+#[test]
+fn encoded() {
+    let old = musli::wire::to_vec(&vec![1u32, 2, 3]).unwrap();
+    let new = musli::wire::to_vec(&vec![1u32, 2, 3, 4]).unwrap();
+
+    let patch = musli::wire::diff(&old, &new).unwrap();
+
+    let data = musli::wire::to_vec(&patch).unwrap();
+    let decoded: Patch = musli::wire::from_slice(&data).unwrap();
+    assert_eq!(decoded, patch);
+
+    assert_eq!(Patch::from_bytes(patch.as_bytes()), patch);
+    assert_eq!(musli::wire::patch(&old, &decoded).unwrap(), new);
+}
+
+#[test]
+fn malformed() {
+    let old = musli::wire::to_vec(&vec![1u32, 2, 3]).unwrap();
+
+    // Copies past the end of the message.
+    let patch = Patch::from_bytes(&[old.len() as u8, 50 << 1, 0]);
+    assert!(musli::wire::patch(&old, &patch).is_err());
+
+    // Inserts more bytes than there are.
+    let patch = Patch::from_bytes(&[old.len() as u8, (10 << 1) | 1, 0]);
+    assert!(musli::wire::patch(&old, &patch).is_err());
+
+    // Produces something which isn't a message.
+    let patch = Patch::from_bytes(&[old.len() as u8, 1 << 1, 0]);
+    assert!(musli::wire::patch(&old, &patch).is_err());
+}
@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use musli::wire::{Checksum, Encoding};
+
+fn message() -> BTreeMap<String, BTreeMap<u32, Vec<u32>>> {
+    let mut header = BTreeMap::new();
+    header.insert(1, vec![42]);
+    header.insert(2, vec![1, 2, 3]);
+
+    let mut body = BTreeMap::new();
+    body.insert(1, (0..1024).collect());
+
+    let mut message = BTreeMap::new();
+    message.insert(String::from("header"), header);
+    message.insert(String::from("body"), body);
+    message
+}
+
+#[test]
+fn project() {
+    let data = musli::wire::to_vec(&message()).unwrap();
+
+    let header: Option<BTreeMap<u32, Vec<u32>>> =
+        musli::wire::from_slice_path(&data, &["header"]).unwrap();
+    assert_eq!(header.as_ref(), message().get("header"));
+
+    let entire: Option<BTreeMap<String, BTreeMap<u32, Vec<u32>>>> =
+        musli::wire::from_slice_path::<_, &str>(&data, &[]).unwrap();
+    assert_eq!(entire, Some(message()));
+}
+
+#[test]
+fn nested_keys() {
+    let mut inner = BTreeMap::new();
+    inner.insert(7u32, String::from("Aristotle"));
+    inner.insert(300u32, String::from("Plato"));
+
+    let mut outer = BTreeMap::new();
+    outer.insert(1u32, inner.clone());
+    outer.insert(2u32, inner);
+
+    let data = musli::wire::to_vec(&outer).unwrap();
+
+    let value: Option<String> = musli::wire::from_slice_path(&data, &[2u32, 300]).unwrap();
+    assert_eq!(value.as_deref(), Some("Plato"));
+
+    // Tags of a different integer type encode in the same way.
+    let value: Option<String> = musli::wire::from_slice_path(&data, &[1u64, 7]).unwrap();
+    assert_eq!(value.as_deref(), Some("Aristotle"));
+
+    let value: Option<String> = musli::wire::from_slice_path(&data, &[1u32, 8]).unwrap();
+    assert_eq!(value, None);
+
+    let value: Option<String> = musli::wire::from_slice_path(&data, &[3u32, 7]).unwrap();
+    assert_eq!(value, None);
+}
+
+#[test]
+fn checksum() {
+    const ENCODING: Encoding = Encoding::new().with_checksum(Checksum::Crc32);
+
+    let data = ENCODING.to_vec(&message()).unwrap();
+
+    let value: Option<BTreeMap<u32, Vec<u32>>> =
+        ENCODING.from_slice_path(&data, &["header"]).unwrap();
+    assert_eq!(value.as_ref(), message().get("header"));
+
+    let mut corrupt = data.clone();
+    let last = corrupt.len() - 5;
+    corrupt[last] ^= 1;
+    assert!(ENCODING
+        .from_slice_path::<BTreeMap<u32, Vec<u32>>, _>(&corrupt, &["header"])
+        .is_err());
+}
+
+#[test]
+fn not_a_map() {
+    let data = musli::wire::to_vec(&42u32).unwrap();
+    assert!(musli::wire::from_slice_path::<u32, _>(&data, &[0u32]).is_err());
+}
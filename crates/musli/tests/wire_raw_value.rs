@@ -0,0 +1,40 @@
+use musli::wire::{Checksum, Encoding, RawValue};
+
+#[test]
+fn deferred() {
+    let data = musli::wire::to_vec(&(1u32, String::from("Aristotle"), 42u64)).unwrap();
+
+    let (kind, body, trailer): (u32, RawValue<'_>, u64) = musli::wire::from_slice(&data).unwrap();
+
+    assert_eq!(kind, 1);
+    assert_eq!(trailer, 42);
+    assert_eq!(body.as_bytes(), musli::wire::to_vec("Aristotle").unwrap());
+    assert_eq!(body.decode::<String>().unwrap(), "Aristotle");
+    assert!(body.decode::<Vec<u32>>().is_err());
+}
+
+#[test]
+fn nested() {
+    let body = vec![vec![1u32, 2], vec![], vec![3, 4, 5]];
+    let data = musli::wire::to_vec(&(7u32, &body)).unwrap();
+
+    let (_, raw): (u32, RawValue<'_>) = musli::wire::from_slice(&data).unwrap();
+    assert_eq!(raw.as_bytes(), musli::wire::to_vec(&body).unwrap());
+    assert_eq!(raw.decode::<Vec<Vec<u32>>>().unwrap(), body);
+}
+
+#[test]
+fn checksum() {
+    const ENCODING: Encoding = Encoding::new().with_checksum(Checksum::Crc32);
+
+    let data = ENCODING.to_vec(&(1u32, String::from("Aristotle"))).unwrap();
+    let (_, raw): (u32, RawValue<'_>) = ENCODING.from_slice(&data).unwrap();
+    assert_eq!(raw.decode::<String>().unwrap(), "Aristotle");
+}
+
+#[test]
+fn unsupported() {
+    let json = musli::json::to_vec(&1u32).unwrap();
+    let error = musli::json::from_slice::<RawValue<'_>>(&json).unwrap_err();
+    assert!(error.to_string().contains("raw value"), "{error}");
+}
@@ -0,0 +1,83 @@
+use musli::wire::Encoding;
+use musli::{Decode, Encode};
+
+const LENIENT: Encoding = Encoding::new();
+const STRICT: Encoding = Encoding::new().with_strict(true);
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Version2 {
+    name: String,
+    age: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Version1 {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+enum Version2Enum {
+    Person { name: String },
+    Animal { species: String },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+enum Version1Enum {
+    Person {
+        name: String,
+    },
+    #[musli(default)]
+    Unknown,
+}
+
+#[test]
+fn unknown_fields() {
+    let data = LENIENT
+        .to_vec(&Version2 {
+            name: String::from("Aristotle"),
+            age: 61,
+        })
+        .unwrap();
+
+    let expected = Version1 {
+        name: String::from("Aristotle"),
+    };
+
+    assert_eq!(LENIENT.from_slice::<Version1>(&data).unwrap(), expected);
+    assert!(STRICT.from_slice::<Version1>(&data).is_err());
+    assert!(STRICT.from_slice::<Version2>(&data).is_ok());
+}
+
+#[test]
+fn unknown_variants() {
+    let data = LENIENT
+        .to_vec(&Version2Enum::Animal {
+            species: String::from("Cat"),
+        })
+        .unwrap();
+
+    assert_eq!(
+        LENIENT.from_slice::<Version1Enum>(&data).unwrap(),
+        Version1Enum::Unknown
+    );
+
+    assert!(STRICT.from_slice::<Version1Enum>(&data).is_err());
+}
+
+#[test]
+fn known_values() {
+    let data = STRICT
+        .to_vec(&Version2Enum::Person {
+            name: String::from("Plato"),
+        })
+        .unwrap();
+
+    assert_eq!(
+        STRICT.from_slice::<Version1Enum>(&data).unwrap(),
+        Version1Enum::Person {
+            name: String::from("Plato"),
+        }
+    );
+}
@@ -0,0 +1,51 @@
+use musli::wire::{Checksum, Encoding, Header};
+
+#[test]
+fn roundtrip() {
+    let value = vec![
+        (String::from("Aristotle"), 61u32),
+        (String::from("Plato"), 80),
+    ];
+    let data = musli::wire::to_vec(&value).unwrap();
+
+    // SAFETY: The data was encoded above.
+    let decoded: Vec<(String, u32)> = unsafe { musli::wire::from_slice_unchecked(&data).unwrap() };
+    assert_eq!(decoded, value);
+
+    // SAFETY: The data was encoded above.
+    let borrowed: Vec<(&str, u32)> = unsafe { musli::wire::from_slice_unchecked(&data).unwrap() };
+    assert_eq!(borrowed, [("Aristotle", 61), ("Plato", 80)]);
+}
+
+#[test]
+fn skips_limits_and_checksum() {
+    const ENCODING: Encoding = Encoding::new()
+        .with_max_allocation(4)
+        .with_checksum(Checksum::Crc32)
+        .with_header(Header::new(*b"TEST", 1));
+
+    let data = ENCODING.to_vec(&vec![1u32; 16]).unwrap();
+    assert!(ENCODING.from_slice::<Vec<u32>>(&data).is_err());
+
+    // SAFETY: The data was encoded above.
+    let value: Vec<u32> = unsafe { ENCODING.from_slice_unchecked(&data).unwrap() };
+    assert_eq!(value, vec![1u32; 16]);
+
+    // The header is still verified.
+    let mut corrupt = data.clone();
+    corrupt[4] ^= 0xff;
+
+    // SAFETY: Only the version in the header differs from the data encoded
+    // above, and the header is verified before anything else is decoded.
+    let error = unsafe { ENCODING.from_slice_unchecked::<Vec<u32>>(&corrupt) }.unwrap_err();
+    assert!(error.to_string().contains("version"), "{error}");
+}
+
+#[test]
+fn truncated() {
+    let data = musli::wire::to_vec(&String::from("Aristotle")).unwrap();
+
+    // SAFETY: The data is a truncated encoding of a `String`.
+    let result = unsafe { musli::wire::from_slice_unchecked::<String>(&data[..4]) };
+    assert!(result.is_err());
+}
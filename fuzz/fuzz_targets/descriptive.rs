@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use musli::value::Value;
+use tests::models::{Allocated, LargeStruct, MediumEnum, Primitives, PrimitivesPacked};
+
+fuzz_target!(|data: &[u8]| {
+    _ = musli::descriptive::from_slice::<Primitives>(data);
+    _ = musli::descriptive::from_slice::<PrimitivesPacked>(data);
+    _ = musli::descriptive::from_slice::<Allocated>(data);
+    _ = musli::descriptive::from_slice::<MediumEnum>(data);
+    _ = musli::descriptive::from_slice::<LargeStruct>(data);
+    // Descriptive payloads can be decoded without knowing their type.
+    _ = musli::descriptive::from_slice::<Value>(data);
+});
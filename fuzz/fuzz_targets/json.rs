@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use musli::value::Value;
+use tests::models::{Allocated, LargeStruct, MediumEnum, Primitives, PrimitivesPacked};
+
+fuzz_target!(|data: &[u8]| {
+    _ = musli::json::from_slice::<Primitives>(data);
+    _ = musli::json::from_slice::<PrimitivesPacked>(data);
+    _ = musli::json::from_slice::<Allocated>(data);
+    _ = musli::json::from_slice::<MediumEnum>(data);
+    _ = musli::json::from_slice::<LargeStruct>(data);
+    _ = musli::json::from_slice::<Value>(data);
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tests::models::{Allocated, LargeStruct, MediumEnum, Primitives, PrimitivesPacked};
+
+fuzz_target!(|data: &[u8]| {
+    _ = musli::wire::from_slice::<Primitives>(data);
+    _ = musli::wire::from_slice::<PrimitivesPacked>(data);
+    _ = musli::wire::from_slice::<Allocated>(data);
+    _ = musli::wire::from_slice::<MediumEnum>(data);
+    _ = musli::wire::from_slice::<LargeStruct>(data);
+});
@@ -0,0 +1,72 @@
+#![no_main]
+
+use core::mem::align_of;
+
+use libfuzzer_sys::fuzz_target;
+use musli_zerocopy::{endian, Buf, Ref, ZeroCopy};
+use tests::models::{Primitives, PrimitivesPacked};
+use tests::AlignedBuf;
+
+/// Enums with sparse discriminants, both on their own and nested in other
+/// enums and structs, which exercise discriminant validation.
+///
+/// Values of these types are only ever loaded, never constructed.
+#[allow(dead_code)]
+mod enums {
+    use musli_zerocopy::ZeroCopy;
+
+    #[derive(ZeroCopy)]
+    #[repr(u8)]
+    pub enum Flag {
+        A = 1,
+        B = 3,
+        C = u8::MAX,
+    }
+
+    #[derive(ZeroCopy)]
+    #[repr(u8)]
+    pub enum Inner {
+        Flag(Flag),
+        Boolean(bool),
+        Character(char) = 0x80,
+    }
+
+    #[derive(ZeroCopy)]
+    #[repr(u32)]
+    pub enum Shape {
+        Empty,
+        Point(u32, u32) = 10,
+        Flagged { flag: Flag, value: u64 } = 0x1000_0000,
+        Nested(Inner),
+        Deep { inner: Inner, flags: [Flag; 3] } = u32::MAX,
+    }
+
+    #[derive(ZeroCopy)]
+    #[repr(C)]
+    pub struct Enums {
+        flag: Flag,
+        inner: Inner,
+        shape: Shape,
+        shapes: [Shape; 2],
+    }
+}
+
+/// Load a value of type `T` from the given bytes, which exercises the
+/// validation performed when coercing bytes into a reference.
+fn load<T>(data: &[u8])
+where
+    T: ZeroCopy,
+{
+    let mut buf = AlignedBuf::new(align_of::<T>());
+    buf.extend_from_slice(data);
+    _ = Buf::new(buf.as_slice()).load(Ref::<T, endian::Native, usize>::zero());
+}
+
+fuzz_target!(|data: &[u8]| {
+    load::<Primitives>(data);
+    load::<PrimitivesPacked>(data);
+    load::<enums::Flag>(data);
+    load::<enums::Inner>(data);
+    load::<enums::Shape>(data);
+    load::<enums::Enums>(data);
+});
@@ -0,0 +1,119 @@
+//! Generate a seed corpus for the fuzzing targets by encoding randomly
+//! generated models with each format.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use musli::mode::{Binary, Text};
+use musli::Encode;
+use tests::models::{Allocated, LargeStruct, MediumEnum, Primitives, PrimitivesPacked};
+use tests::{Generate, Rng};
+
+const COUNT: usize = 16;
+
+/// Encode `value` with every byte-oriented format, writing a seed for each of
+/// the corresponding targets.
+fn write_seeds<T>(corpus: &Path, name: &str, index: usize, value: &T) -> Result<()>
+where
+    T: Encode<Binary> + Encode<Text>,
+{
+    let seeds = [
+        ("storage", musli::storage::to_vec(value)?),
+        ("wire", musli::wire::to_vec(value)?),
+        ("descriptive", musli::descriptive::to_vec(value)?),
+        ("json", musli::json::to_vec(value)?),
+    ];
+
+    for (target, bytes) in seeds {
+        let dir = corpus.join(target);
+        fs::create_dir_all(&dir).with_context(|| dir.display().to_string())?;
+        let path = dir.join(format!("{name}-{index}"));
+        fs::write(&path, bytes).with_context(|| path.display().to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Write the raw memory of a zero-copy value as a seed.
+fn write_zerocopy<T>(corpus: &Path, name: &str, index: usize, value: &T) -> Result<()>
+where
+    T: musli_zerocopy::ZeroCopy,
+{
+    let mut buf = musli_zerocopy::OwnedBuf::new();
+    buf.store(value);
+
+    let dir = corpus.join("zerocopy");
+    fs::create_dir_all(&dir).with_context(|| dir.display().to_string())?;
+    let path = dir.join(format!("{name}-{index}"));
+    fs::write(&path, &buf[..]).with_context(|| path.display().to_string())?;
+    Ok(())
+}
+
+fn generate<T>(rng: &mut Rng, corpus: &Path, name: &str, count: usize) -> Result<()>
+where
+    T: Generate + Encode<Binary> + Encode<Text>,
+{
+    for index in 0..count {
+        let value = rng.next::<T>();
+        write_seeds(corpus, name, index, &value)?;
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let root = env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("fuzz"));
+
+    let mut it = env::args().skip(1);
+
+    let mut count = COUNT;
+    let mut seed = tests::RNG_SEED;
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--count" => {
+                count = it
+                    .next()
+                    .context("missing argument for `--count`")?
+                    .parse()
+                    .context("bad argument to --count")?;
+            }
+            "--seed" => {
+                seed = it
+                    .next()
+                    .context("missing argument for `--seed`")?
+                    .parse()
+                    .context("bad argument to --seed")?;
+            }
+            other => {
+                bail!("Bad argument: {other}");
+            }
+        }
+    }
+
+    let corpus = root.join("corpus");
+    let mut rng = tests::rng_with_seed(seed);
+
+    generate::<Primitives>(&mut rng, &corpus, "primitives", count)?;
+    generate::<PrimitivesPacked>(&mut rng, &corpus, "primpacked", count)?;
+    generate::<Allocated>(&mut rng, &corpus, "allocated", count)?;
+    generate::<MediumEnum>(&mut rng, &corpus, "medium_enum", count)?;
+    generate::<LargeStruct>(&mut rng, &corpus, "large", count)?;
+
+    for index in 0..count {
+        write_zerocopy(&corpus, "primitives", index, &rng.next::<Primitives>())?;
+        write_zerocopy(
+            &corpus,
+            "primpacked",
+            index,
+            &rng.next::<PrimitivesPacked>(),
+        )?;
+    }
+
+    println!("Wrote seeds to {}", corpus.display());
+    Ok(())
+}
@@ -166,6 +166,7 @@ fn criterion_benchmark(c: &mut Criterion) {
     }
 
     tests::types!(setup);
+    tests::payloads!(setup);
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -93,6 +93,14 @@ macro_rules! if_supported {
     (zerocopy, allocated, $($tt:tt)*) => {};
     (zerocopy, medium_enum, $($tt:tt)*) => {};
 
+    (musli_zerocopy, payload_small, $($tt:tt)*) => {};
+    (musli_zerocopy, payload_medium, $($tt:tt)*) => {};
+    (musli_zerocopy, payload_large, $($tt:tt)*) => {};
+
+    (zerocopy, payload_small, $($tt:tt)*) => {};
+    (zerocopy, payload_medium, $($tt:tt)*) => {};
+    (zerocopy, payload_large, $($tt:tt)*) => {};
+
     ($framework:ident, $test:ident, $($tt:tt)*) => { $($tt)* };
 }
 
@@ -108,6 +116,20 @@ macro_rules! types {
     };
 }
 
+/// Call the given macro with payloads of increasing size, which are used to
+/// compare how each framework scales with the amount of data.
+#[macro_export]
+macro_rules! payloads {
+    ($call:path) => {
+        #[cfg(feature = "alloc")]
+        $call!(payload_small, SmallPayload, SMALL_PAYLOADS, 1000);
+        #[cfg(feature = "alloc")]
+        $call!(payload_medium, MediumPayload, MEDIUM_PAYLOADS, 100000);
+        #[cfg(feature = "alloc")]
+        $call!(payload_large, LargePayload, LARGE_PAYLOADS, 10000000);
+    };
+}
+
 /// Build common RNG with custom seed.
 pub fn rng_with_seed(seed: u64) -> generate::Rng {
     generate::Rng::from_seed(seed)
@@ -36,6 +36,9 @@ miri! {
     pub const PRIMITIVES_RANGE: Range<usize> = 10..100, 1..3;
     pub const MEDIUM_RANGE: Range<usize> = 10..100, 1..3;
     pub const SMALL_FIELDS: Range<usize> = 1..3, 1..2;
+    pub const SMALL_PAYLOAD_RANGE: Range<usize> = 1..2, 1..2;
+    pub const MEDIUM_PAYLOAD_RANGE: Range<usize> = 100..101, 2..3;
+    pub const LARGE_PAYLOAD_RANGE: Range<usize> = 10000..10001, 3..4;
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Generate)]
@@ -399,3 +402,60 @@ impl PartialEq<LargeStruct> for &LargeStruct {
         *other == **self
     }
 }
+
+macro_rules! payload {
+    ($(#[doc = $doc:literal])* $name:ident, $archived:ident, $range:ident) => {
+        $(#[doc = $doc])*
+        #[cfg(feature = "alloc")]
+        #[derive(Debug, Clone, PartialEq, Generate)]
+        #[cfg_attr(feature = "musli", derive(Encode, Decode))]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "bitcode-derive", derive(bitcode::Encode, bitcode::Decode))]
+        #[cfg_attr(
+            feature = "rkyv",
+            derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+            archive(compare(PartialEq), check_bytes),
+            archive_attr(derive(Debug))
+        )]
+        #[cfg_attr(feature = "musli", musli(mode = Packed, packed))]
+        #[cfg_attr(
+            feature = "miniserde",
+            derive(miniserde::Serialize, miniserde::Deserialize)
+        )]
+        pub struct $name {
+            #[generate(range = $range)]
+            records: Vec<Primitives>,
+        }
+
+        #[cfg(all(feature = "alloc", feature = "rkyv"))]
+        impl PartialEq<$name> for &$archived {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                *other == **self
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl PartialEq<$name> for &$name {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                *other == **self
+            }
+        }
+    };
+}
+
+payload! {
+    /// A payload holding a single record.
+    SmallPayload, ArchivedSmallPayload, SMALL_PAYLOAD_RANGE
+}
+
+payload! {
+    /// A payload holding a hundred records.
+    MediumPayload, ArchivedMediumPayload, MEDIUM_PAYLOAD_RANGE
+}
+
+payload! {
+    /// A payload holding ten thousand records.
+    LargePayload, ArchivedLargePayload, LARGE_PAYLOAD_RANGE
+}
@@ -154,6 +154,10 @@ struct ArgsReport {
     /// Skip size comparisons.
     #[arg(long)]
     no_size: bool,
+    /// Write a machine-readable JSON report of all benchmark estimates and
+    /// size samples to the given path.
+    #[arg(long)]
+    json: Option<PathBuf>,
     /// Reference graphics from the given branch.
     #[arg(long)]
     branch: Option<String>,
@@ -286,6 +290,7 @@ fn main() -> Result<()> {
             }
 
             let mut used_footnotes = BTreeSet::new();
+            let mut json = JsonReport::default();
 
             let mut o = String::new();
 
@@ -418,6 +423,18 @@ fn main() -> Result<()> {
                             let mean = &data.mean;
                             let interval = &mean.confidence_interval;
 
+                            json.benchmarks.push(JsonBenchmark {
+                                report: report.id.clone(),
+                                kind: kind.id.clone(),
+                                group: group.id.clone(),
+                                framework: file_name.clone(),
+                                mean: mean.point_estimate,
+                                standard_error: mean.standard_error,
+                                lower_bound: interval.lower_bound,
+                                upper_bound: interval.upper_bound,
+                                median: data.median.point_estimate,
+                            });
+
                             write!(o, "| `{}/{}/{file_name}`", kind.id, group.id)?;
 
                             if let Some(footnotes) = manifest.crate_footnotes.get(&file_name) {
@@ -456,6 +473,19 @@ fn main() -> Result<()> {
                 writeln!(o)?;
             }
 
+            if a.json.is_some() {
+                for (report, sets) in &size_sets {
+                    for set in sets {
+                        json.sizes.push(JsonSize {
+                            report: report.id.clone(),
+                            framework: set.framework.clone(),
+                            suite: set.suite.clone(),
+                            samples: set.samples.clone(),
+                        });
+                    }
+                }
+            }
+
             size_comparisons(&mut o, &manifest, size_sets, &mut used_footnotes)?;
 
             if !used_footnotes.is_empty() {
@@ -481,6 +511,12 @@ fn main() -> Result<()> {
 
             println!("Writing: {}", report.display());
             fs::write(report, o.as_bytes())?;
+
+            if let Some(path) = &a.json {
+                println!("Writing: {}", path.display());
+                let f = File::create(path).with_context(|| path.display().to_string())?;
+                serde_json::to_writer_pretty(f, &json)?;
+            }
         }
         Cmd::Clippy(a) => {
             let mut remaining = Vec::new();
@@ -1230,6 +1266,35 @@ struct SizeSet {
     samples: Vec<i64>,
 }
 
+/// Machine-readable report written by `report --json`.
+#[derive(Default, Serialize)]
+struct JsonReport {
+    benchmarks: Vec<JsonBenchmark>,
+    sizes: Vec<JsonSize>,
+}
+
+/// A single benchmark estimate, with all timings in nanoseconds.
+#[derive(Serialize)]
+struct JsonBenchmark {
+    report: String,
+    kind: String,
+    group: String,
+    framework: String,
+    mean: f64,
+    standard_error: f64,
+    lower_bound: f64,
+    upper_bound: f64,
+    median: f64,
+}
+
+#[derive(Serialize)]
+struct JsonSize {
+    report: String,
+    framework: String,
+    suite: String,
+    samples: Vec<i64>,
+}
+
 #[derive(Debug, Deserialize)]
 #[allow(unused)]
 struct Estimates {